@@ -4,20 +4,24 @@ use prelude::FluentBuilder as _;
 use serde::Deserialize;
 use std::{sync::Arc, time::Duration};
 use story::{
-    ButtonStory, CalendarStory, DropdownStory, IconStory, ImageStory, InputStory, ListStory,
-    ModalStory, PopupStory, ProgressStory, ResizableStory, ScrollableStory, StoryContainer,
-    SwitchStory, TableStory, TextStory, TooltipStory,
+    ButtonStory, CalendarStory, CarouselStory, ChartStory, DndStory, DropdownStory, FileStory,
+    FormStory, IconStory, ImageStory, InputStory, ListStory, ModalStory, PopupStory, ProgressStory,
+    RatingStory, ResizableStory, ScrollableStory, SettingsStory, StatusBarStory, StoryContainer,
+    SwitchStory, TableStory, TextStory, TooltipStory, TreeStory,
 };
 use ui::{
     button::{Button, ButtonStyled as _},
     color_picker::{ColorPicker, ColorPickerEvent},
-    dock::{DockArea, DockAreaState, DockEvent, DockItem, PanelView},
+    dock::{
+        DockArea, DockAreaState, DockEvent, DockItem, DockLayout, PanelView, ReopenClosedPanel,
+        SwitchLayout,
+    },
     h_flex,
     popup_menu::PopupMenuExt,
     theme::{ActiveTheme, Colorize as _, Theme},
-    ContextModal, IconName, Root, Sizable,
+    title_bar::TitleBar,
+    v_flex, ContextModal, IconName, Root, Sizable,
 };
-use workspace::TitleBar;
 
 use crate::app_state::AppState;
 
@@ -60,8 +64,27 @@ impl StoryWorkspace {
         })
         .detach();
 
-        let dock_area =
-            cx.new_view(|cx| DockArea::new(MAIN_DOCK_AREA.id, Some(MAIN_DOCK_AREA.version), cx));
+        let dock_area = cx.new_view(|cx| {
+            DockArea::new(MAIN_DOCK_AREA.id, Some(MAIN_DOCK_AREA.version), cx)
+                .lazy_panels(true)
+                .empty_state(|cx| {
+                    v_flex()
+                        .items_center()
+                        .gap_3()
+                        .child(
+                            ui::Icon::new(IconName::Inbox)
+                                .size(px(40.))
+                                .text_color(cx.theme().muted_foreground),
+                        )
+                        .child("All the stories are closed.")
+                        .child(
+                            Button::new("reopen-closed-panel")
+                                .small()
+                                .label("Reopen Closed Story")
+                                .on_click(|_, cx| cx.dispatch_action(Box::new(ReopenClosedPanel))),
+                        )
+                })
+        });
         let weak_dock_area = dock_area.downgrade();
 
         match Self::load_layout(dock_area.clone(), cx) {
@@ -74,8 +97,27 @@ impl StoryWorkspace {
             }
         };
 
+        // The named layouts to switch in the title bar, the "Focus" layout hides the docks.
+        dock_area.update(cx, |dock_area, cx| {
+            let state = dock_area.dump(cx);
+            let focus = DockAreaState {
+                left_dock: None,
+                right_dock: None,
+                bottom_dock: None,
+                ..state.clone()
+            };
+            dock_area.set_layouts(
+                vec![
+                    DockLayout::new("Default", state),
+                    DockLayout::new("Focus", focus),
+                ],
+                cx,
+            );
+        });
+
         cx.subscribe(&dock_area, |this, dock_area, ev: &DockEvent, cx| match ev {
             DockEvent::LayoutChanged => this.save_layout(dock_area, cx),
+            DockEvent::ActiveLayoutChanged => {}
         })
         .detach();
 
@@ -126,6 +168,15 @@ impl StoryWorkspace {
         }
     }
 
+    fn on_action_switch_layout(&mut self, action: &SwitchLayout, cx: &mut ViewContext<Self>) {
+        let result = self
+            .dock_area
+            .update(cx, |dock_area, cx| dock_area.load_layout(&action.0, cx));
+        if let Err(err) = result {
+            eprintln!("switch layout error: {:?}", err);
+        }
+    }
+
     fn save_layout(&mut self, dock_area: View<DockArea>, cx: &mut ViewContext<Self>) {
         self._save_layout_task = Some(cx.spawn(|this, mut cx| async move {
             Timer::after(Duration::from_secs(10)).await;
@@ -221,11 +272,20 @@ impl StoryWorkspace {
                     Arc::new(StoryContainer::panel::<SwitchStory>(cx)),
                     Arc::new(StoryContainer::panel::<ProgressStory>(cx)),
                     Arc::new(StoryContainer::panel::<TableStory>(cx)),
+                    Arc::new(StoryContainer::panel::<TreeStory>(cx)),
                     Arc::new(StoryContainer::panel::<ImageStory>(cx)),
+                    Arc::new(StoryContainer::panel::<ChartStory>(cx)),
+                    Arc::new(StoryContainer::panel::<CarouselStory>(cx)),
+                    Arc::new(StoryContainer::panel::<RatingStory>(cx)),
+                    Arc::new(StoryContainer::panel::<DndStory>(cx)),
                     Arc::new(StoryContainer::panel::<IconStory>(cx)),
                     Arc::new(StoryContainer::panel::<TooltipStory>(cx)),
                     Arc::new(StoryContainer::panel::<ProgressStory>(cx)),
                     Arc::new(StoryContainer::panel::<CalendarStory>(cx)),
+                    Arc::new(StoryContainer::panel::<FileStory>(cx)),
+                    Arc::new(StoryContainer::panel::<FormStory>(cx)),
+                    Arc::new(StoryContainer::panel::<SettingsStory>(cx)),
+                    Arc::new(StoryContainer::panel::<StatusBarStory>(cx)),
                     Arc::new(StoryContainer::panel::<ResizableStory>(cx)),
                     Arc::new(StoryContainer::panel::<ScrollableStory>(cx)),
                 ],
@@ -306,6 +366,7 @@ impl Render for StoryWorkspace {
         let notifications_count = cx.notifications().len();
 
         div()
+            .on_action(cx.listener(Self::on_action_switch_layout))
             .font_family(".SystemUIFont")
             .relative()
             .size_full()
@@ -315,13 +376,6 @@ impl Render for StoryWorkspace {
             .text_color(cx.theme().foreground)
             .child(
                 TitleBar::new("main-title", Box::new(CloseWindow))
-                    .when(cfg!(not(windows)), |this| {
-                        this.on_click(|event, cx| {
-                            if event.up.click_count == 2 {
-                                cx.zoom_window();
-                            }
-                        })
-                    })
                     // left side
                     .child(div().flex().items_center().child("GPUI App"))
                     .child(
@@ -331,6 +385,17 @@ impl Render for StoryWorkspace {
                             .justify_end()
                             .px_2()
                             .gap_2()
+                            .child(
+                                Button::new("layouts")
+                                    .small()
+                                    .ghost()
+                                    .label("Layouts")
+                                    .popup_menu({
+                                        let dock_area = self.dock_area.clone();
+                                        move |menu, cx| dock_area.read(cx).layout_menu(menu)
+                                    })
+                                    .anchor(AnchorCorner::TopRight),
+                            )
                             .child(self.theme_color_picker.clone())
                             .child(
                                 Button::new("theme-mode")
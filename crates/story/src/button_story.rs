@@ -1,14 +1,18 @@
 use gpui::{
-    px, ClickEvent, FocusableView, IntoElement, ParentElement as _, Render, Styled as _, View,
+    div, px, ClickEvent, FocusableView, IntoElement, ParentElement as _, Render, Styled as _, View,
     ViewContext, VisualContext as _, WindowContext,
 };
 
 use ui::{
+    badge::Badge,
     button::{Button, ButtonCustomStyle, ButtonStyled as _},
     button_group::ButtonGroup,
+    card::Card,
     checkbox::Checkbox,
     h_flex,
+    pagination::Pagination,
     prelude::FluentBuilder,
+    tag::{Tag, TagList},
     theme::{ActiveTheme, Theme},
     v_flex, Disableable as _, Icon, IconName, Selectable as _, Sizable as _,
 };
@@ -22,6 +26,9 @@ pub struct ButtonStory {
     selected: bool,
     compact: bool,
     toggle_multiple: bool,
+    page: usize,
+    page_size: usize,
+    selected_plan: usize,
 }
 
 impl ButtonStory {
@@ -33,6 +40,9 @@ impl ButtonStory {
             selected: false,
             compact: false,
             toggle_multiple: false,
+            page: 1,
+            page_size: 20,
+            selected_plan: 0,
         })
     }
 
@@ -83,7 +93,7 @@ impl Render for ButtonStory {
                         Checkbox::new("disabled-button")
                             .label("Disabled")
                             .checked(self.disabled)
-                            .on_click(cx.listener(|view, _, cx| {
+                            .on_change(cx.listener(|view, _, cx| {
                                 view.disabled = !view.disabled;
                                 cx.notify();
                             })),
@@ -92,7 +102,7 @@ impl Render for ButtonStory {
                         Checkbox::new("loading-button")
                             .label("Loading")
                             .checked(self.loading)
-                            .on_click(cx.listener(|view, _, cx| {
+                            .on_change(cx.listener(|view, _, cx| {
                                 view.loading = !view.loading;
                                 cx.notify();
                             })),
@@ -101,7 +111,7 @@ impl Render for ButtonStory {
                         Checkbox::new("selected-button")
                             .label("Selected")
                             .checked(self.selected)
-                            .on_click(cx.listener(|view, _, cx| {
+                            .on_change(cx.listener(|view, _, cx| {
                                 view.selected = !view.selected;
                                 cx.notify();
                             })),
@@ -110,7 +120,7 @@ impl Render for ButtonStory {
                         Checkbox::new("compact-button")
                             .label("Compact")
                             .checked(self.compact)
-                            .on_click(cx.listener(|view, _, cx| {
+                            .on_change(cx.listener(|view, _, cx| {
                                 view.compact = !view.compact;
                                 cx.notify();
                             })),
@@ -119,7 +129,7 @@ impl Render for ButtonStory {
                         Checkbox::new("shadow-button")
                             .label("Shadow")
                             .checked(cx.theme().shadow)
-                            .on_click(cx.listener(|_, _, cx| {
+                            .on_change(cx.listener(|_, _, cx| {
                                 let mut theme = cx.theme().clone();
                                 theme.shadow = !theme.shadow;
                                 cx.set_global::<Theme>(theme);
@@ -506,7 +516,7 @@ impl Render for ButtonStory {
                                 Checkbox::new("multiple-button")
                                     .label("Multiple")
                                     .checked(toggle_multiple)
-                                    .on_click(cx.listener(|view, _, cx| {
+                                    .on_change(cx.listener(|view, _, cx| {
                                         view.toggle_multiple = !view.toggle_multiple;
                                         cx.notify();
                                     })),
@@ -667,5 +677,88 @@ impl Render for ButtonStory {
                             .when(compact, |this| this.compact()),
                     ),
             )
+            .child(
+                section("Badge", cx)
+                    .child(Badge::new().count(3))
+                    .child(Badge::new().count(120))
+                    .child(Badge::new().label("New").color(ui::blue_500()))
+                    .child(Badge::new().dot().color(ui::green_500()))
+                    .child(
+                        Badge::new()
+                            .count(8)
+                            .child(Button::new("badge-button-1").icon(IconName::Inbox)),
+                    )
+                    .child(
+                        Badge::new()
+                            .dot()
+                            .child(Button::new("badge-button-2").icon(IconName::Bell).ghost()),
+                    ),
+            )
+            .child(
+                section("Tag", cx).child(
+                    TagList::new()
+                        .child(Tag::new("tag-1").child("Secondary"))
+                        .child(Tag::new("tag-2").primary().child("Primary"))
+                        .child(
+                            Tag::new("tag-3")
+                                .success()
+                                .icon(IconName::Check)
+                                .child("Success"),
+                        )
+                        .child(Tag::new("tag-4").warning().child("Warning"))
+                        .child(
+                            Tag::new("tag-5")
+                                .danger()
+                                .child("Removable")
+                                .on_remove(|_, _| println!("Tag removed")),
+                        )
+                        .child(Tag::new("tag-6").outline().small().child("Outline"))
+                        .child(Tag::new("tag-7").color(ui::violet_500()).child("Custom")),
+                ),
+            )
+            .child(
+                section("Card", cx).items_start().children(
+                    [
+                        ("Starter", "For personal projects.", "$0 / month"),
+                        ("Team", "For small teams to collaborate.", "$20 / month"),
+                    ]
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, (title, description, price))| {
+                        Card::new(("plan", ix))
+                            .w_64()
+                            .title(title)
+                            .description(description)
+                            .child(div().text_2xl().child(price))
+                            .footer(
+                                Button::new(("choose-plan", ix))
+                                    .small()
+                                    .label("Choose")
+                                    .primary(),
+                            )
+                            .selected(self.selected_plan == ix)
+                            .on_click(cx.listener(move |this, _, cx| {
+                                this.selected_plan = ix;
+                                cx.notify();
+                            }))
+                    }),
+                ),
+            )
+            .child(
+                section("Pagination", cx).child(
+                    Pagination::new("pagination")
+                        .page(self.page)
+                        .total_items(1000, self.page_size)
+                        .on_page_change(cx.listener(|this, page, cx| {
+                            this.page = *page;
+                            cx.notify();
+                        }))
+                        .on_page_size_change(cx.listener(|this, page_size, cx| {
+                            this.page_size = *page_size;
+                            this.page = 1;
+                            cx.notify();
+                        })),
+                ),
+            )
     }
 }
@@ -1,10 +1,13 @@
-use chrono::Days;
+use chrono::{Datelike as _, Days, Weekday};
 use gpui::{
-    px, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext,
+    div, px, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext,
     VisualContext as _, WindowContext,
 };
 use ui::{
-    date_picker::{DatePicker, DatePickerEvent},
+    date_picker::{DatePicker, DatePickerEvent, DateRangePreset},
+    date_time_picker::{DateTimePicker, DateTimePickerEvent},
+    time_picker::TimePicker,
+    time_zone::{TimeZonePicker, TimeZonePickerEvent, Tz},
     v_flex, Sizable as _,
 };
 
@@ -15,6 +18,14 @@ pub struct CalendarStory {
     date_picker_value: Option<String>,
     date_range_picker: View<DatePicker>,
     default_range_mode_picker: View<DatePicker>,
+    limited_date_picker: View<DatePicker>,
+    preset_range_picker: View<DatePicker>,
+    preset_range_value: Option<String>,
+    time_picker: View<TimePicker>,
+    date_time_picker: View<DateTimePicker>,
+    date_time_value: Option<String>,
+    time_zone_picker: View<TimeZonePicker>,
+    time_zone_value: Option<String>,
 }
 
 impl super::Story for CalendarStory {
@@ -94,13 +105,76 @@ impl CalendarStory {
         })
         .detach();
 
+        let limited_date_picker = cx.new_view(|cx| {
+            DatePicker::new("limited_date_picker", cx)
+                .width(px(300.))
+                .placeholder("Weekdays in 30 days")
+                .min_date(now)
+                .max_date(now.checked_add_days(Days::new(30)).unwrap())
+                .disabled_dates(|date| date.weekday().num_days_from_monday() >= 5)
+                .week_start(Weekday::Mon)
+        });
+
+        let preset_range_picker = cx.new_view(|cx| {
+            DatePicker::range_picker("preset_range_picker", cx)
+                .width(px(300.))
+                .number_of_months(2)
+                .presets(DateRangePreset::defaults())
+                .cleanable()
+        });
+        cx.subscribe(&preset_range_picker, |this, _, ev, _| match ev {
+            DatePickerEvent::Change(date) => {
+                this.preset_range_value = date.format("%Y-%m-%d").map(|s| s.to_string());
+            }
+        })
+        .detach();
+
+        let time_picker = cx.new_view(|cx| {
+            TimePicker::new("time_picker", cx)
+                .use_12_hour(true)
+                .minute_step(15)
+        });
+        let date_time_picker = cx.new_view(|cx| {
+            DateTimePicker::new("date_time_picker", cx)
+                .width(px(540.))
+                .cleanable()
+                .time_zone(Tz::Asia__Shanghai)
+                .time_zone_selector(cx)
+        });
+        cx.subscribe(&date_time_picker, |this, _, ev, _| {
+            if let DateTimePickerEvent::ZonedChange(date_time) = ev {
+                this.date_time_value = date_time.map(|dt| dt.to_rfc3339());
+            }
+        })
+        .detach();
+
+        let time_zone_picker = cx.new_view(|cx| {
+            let mut picker = TimeZonePicker::new("time_zone_picker", cx);
+            picker.set_time_zone(Some(Tz::America__New_York), cx);
+            picker
+        });
+        cx.subscribe(&time_zone_picker, |this, _, ev, _| match ev {
+            TimeZonePickerEvent::Change(tz) => {
+                this.time_zone_value = tz.map(|tz| tz.name().to_string());
+            }
+        })
+        .detach();
+
         Self {
             date_picker,
             date_picker_large,
             date_picker_small,
             date_range_picker,
             default_range_mode_picker,
+            limited_date_picker,
+            preset_range_picker,
+            preset_range_value: None,
+            time_picker,
+            date_time_picker,
+            date_time_value: None,
             date_picker_value: None,
+            time_zone_picker,
+            time_zone_value: None,
         }
     }
 }
@@ -120,6 +194,14 @@ impl Render for CalendarStory {
             .child(self.date_picker_large.clone())
             .child(self.date_range_picker.clone())
             .child(self.default_range_mode_picker.clone())
+            .child(self.limited_date_picker.clone())
+            .child(self.preset_range_picker.clone())
+            .child(format!("Date range value: {:?}", self.preset_range_value).into_element())
+            .child(div().w(px(180.)).child(self.time_picker.clone()))
+            .child(self.date_time_picker.clone())
+            .child(format!("Date time value: {:?}", self.date_time_value).into_element())
             .child(format!("Date picker value: {:?}", self.date_picker_value).into_element())
+            .child(div().w(px(300.)).child(self.time_zone_picker.clone()))
+            .child(format!("Time zone value: {:?}", self.time_zone_value).into_element())
     }
 }
@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use gpui::{
+    div, hsla, px, IntoElement, ParentElement as _, Render, SharedString, Styled as _, View,
+    ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{carousel::Carousel, v_flex, StyledExt as _};
+
+use crate::section;
+
+const SLIDES: [(&str, f32); 5] = [
+    ("Mountains", 0.58),
+    ("Forest", 0.36),
+    ("Desert", 0.1),
+    ("Ocean", 0.55),
+    ("Sunset", 0.98),
+];
+
+pub struct CarouselStory {
+    focus_handle: gpui::FocusHandle,
+    carousel: View<Carousel>,
+    autoplay_carousel: View<Carousel>,
+}
+
+impl super::Story for CarouselStory {
+    fn title() -> &'static str {
+        "Carousel"
+    }
+
+    fn description() -> &'static str {
+        "A carousel with the slide animation, navigate by the buttons, the indicators or the arrow keys."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl CarouselStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let carousel = cx.new_view(|cx| Self::slides(Carousel::new(cx).looping(false)));
+        let autoplay_carousel = cx.new_view(|cx| {
+            let mut carousel = Self::slides(Carousel::new(cx));
+            carousel.set_autoplay(Some(Duration::from_secs(3)), cx);
+            carousel
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            carousel,
+            autoplay_carousel,
+        }
+    }
+
+    fn slides(carousel: Carousel) -> Carousel {
+        SLIDES.iter().fold(carousel, |carousel, (title, hue)| {
+            let title = SharedString::from(*title);
+            let hue = *hue;
+            carousel.item(move |_| {
+                v_flex()
+                    .size_full()
+                    .items_center()
+                    .justify_center()
+                    .bg(hsla(hue, 0.6, 0.45, 1.))
+                    .text_color(gpui::white())
+                    .text_2xl()
+                    .font_semibold()
+                    .child(title.clone())
+            })
+        })
+    }
+}
+
+impl gpui::FocusableView for CarouselStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CarouselStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Carousel", cx)
+                    .child(div().w_full().h(px(240.)).child(self.carousel.clone())),
+            )
+            .child(
+                section("Autoplay (paused on hover)", cx).child(
+                    div()
+                        .w_full()
+                        .h(px(240.))
+                        .child(self.autoplay_carousel.clone()),
+                ),
+            )
+    }
+}
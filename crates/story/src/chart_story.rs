@@ -0,0 +1,173 @@
+use gpui::{
+    px, FocusHandle, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    chart::{
+        AreaChart, AxisChart as _, BarChart, Gauge, LineChart, PieChart, Series, Sparkline, Stat,
+    },
+    h_flex, v_flex, IconName,
+};
+
+use crate::section;
+
+const MONTHS: [&str; 6] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun"];
+
+pub struct ChartStory {
+    focus_handle: FocusHandle,
+}
+
+impl super::Story for ChartStory {
+    fn title() -> &'static str {
+        "Chart"
+    }
+
+    fn description() -> &'static str {
+        "Line, bar, area and pie charts with the legend and tooltip, the sparklines, gauges and stat cards."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl ChartStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+        }
+    }
+}
+
+impl gpui::FocusableView for ChartStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ChartStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let desktop = Series::new("Desktop", [186., 305., 237., 73., 209., 214.]);
+        let mobile = Series::new("Mobile", [80., 200., 120., 190., 130., 140.]);
+
+        v_flex()
+            .gap_6()
+            .child(
+                section("Line Chart", cx).child(
+                    LineChart::new("line-chart")
+                        .labels(MONTHS)
+                        .series(desktop.clone())
+                        .series(mobile.clone()),
+                ),
+            )
+            .child(
+                section("Bar Chart", cx).child(
+                    BarChart::new("bar-chart")
+                        .labels(MONTHS)
+                        .series(desktop.clone())
+                        .series(mobile.clone())
+                        .y_format(|value| format!("${}", value).into()),
+                ),
+            )
+            .child(
+                section("Stacked Bar Chart", cx).child(
+                    BarChart::new("stacked-bar-chart")
+                        .labels(MONTHS)
+                        .series(desktop.clone())
+                        .series(mobile.clone())
+                        .stacked(true),
+                ),
+            )
+            .child(
+                section("Area Chart", cx).child(
+                    AreaChart::new("area-chart")
+                        .labels(MONTHS)
+                        .series(desktop.clone())
+                        .series(mobile.clone())
+                        .stacked(true)
+                        .h(px(300.)),
+                ),
+            )
+            .child(
+                section("Pie Chart", cx)
+                    .child(
+                        PieChart::new("pie-chart")
+                            .labels(MONTHS)
+                            .series(desktop.clone())
+                            .w(px(320.)),
+                    )
+                    .child(
+                        PieChart::donut("donut-chart")
+                            .labels(MONTHS)
+                            .series(mobile.clone())
+                            .w(px(320.)),
+                    ),
+            )
+            .child(
+                section("Gauge", cx)
+                    .child(
+                        Gauge::new(72.)
+                            .label("CPU")
+                            .threshold(60., ui::yellow_500())
+                            .threshold(85., ui::red_500())
+                            .value_format(|value| format!("{}%", value).into()),
+                    )
+                    .child(
+                        Gauge::new(3.2)
+                            .max(8.)
+                            .label("Memory (GB)")
+                            .threshold(4., ui::yellow_500())
+                            .threshold(6., ui::red_500())
+                            .bands(true),
+                    ),
+            )
+            .child(
+                section("Stat", cx).child(
+                    h_flex()
+                        .w_full()
+                        .gap_4()
+                        .child(
+                            Stat::new("revenue")
+                                .flex_1()
+                                .title("Total Revenue")
+                                .value("$45,231.89")
+                                .icon(IconName::Star)
+                                .delta(20.1)
+                                .description("from last month")
+                                .sparkline(Sparkline::from(desktop.clone()).area(true)),
+                        )
+                        .child(
+                            Stat::new("visitors")
+                                .flex_1()
+                                .title("Visitors")
+                                .value("1,240")
+                                .change(1240., 1450.)
+                                .description("from last month")
+                                .sparkline(Sparkline::from(mobile.clone())),
+                        )
+                        .child(
+                            Stat::new("bounce-rate")
+                                .flex_1()
+                                .title("Bounce Rate")
+                                .value("32.4%")
+                                .delta(-4.2)
+                                .inverted(true)
+                                .description("from last month"),
+                        ),
+                ),
+            )
+            .child(
+                section("Sparkline", cx).child(
+                    h_flex()
+                        .gap_6()
+                        .child(Sparkline::from(desktop))
+                        .child(Sparkline::from(mobile).area(true))
+                        .child(Sparkline::new([3., 5., 2., 8., 6., 9., 4.]).w(px(120.))),
+                ),
+            )
+    }
+}
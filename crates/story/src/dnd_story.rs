@@ -0,0 +1,265 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, Axis, DragMoveEvent, Entity as _,
+    InteractiveElement as _, IntoElement, ParentElement as _, Render, ScrollHandle, SharedString,
+    StatefulInteractiveElement as _, Styled as _, View, ViewContext, VisualContext as _,
+    WindowContext,
+};
+use ui::{
+    dnd::{self, AutoScroll, DragItem, DraggableExt as _, DroppableExt as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, StyledExt as _,
+};
+
+/// The max number of the cards in the "In Progress" column.
+const WIP_LIMIT: usize = 3;
+
+/// The column and the index of the dragging card.
+type DragCard = DragItem<(usize, usize)>;
+
+struct Column {
+    title: SharedString,
+    cards: Vec<SharedString>,
+    limit: Option<usize>,
+    scroll_handle: ScrollHandle,
+    auto_scroll: AutoScroll,
+}
+
+impl Column {
+    fn new(title: &str, cards: &[&str]) -> Self {
+        Self {
+            title: title.to_string().into(),
+            cards: cards.iter().map(|card| card.to_string().into()).collect(),
+            limit: None,
+            scroll_handle: ScrollHandle::new(),
+            auto_scroll: AutoScroll::new(),
+        }
+    }
+
+    fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn is_full(&self) -> bool {
+        self.limit.is_some_and(|limit| self.cards.len() >= limit)
+    }
+}
+
+pub struct DndStory {
+    focus_handle: gpui::FocusHandle,
+    columns: Vec<Column>,
+    /// The column and the index to insert the dragging card.
+    drop_target: Option<(usize, usize)>,
+}
+
+impl super::Story for DndStory {
+    fn title() -> &'static str {
+        "Drag and Drop"
+    }
+
+    fn description() -> &'static str {
+        "A kanban board built on the `ui::dnd`, the In Progress column accepts 3 cards at most."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl DndStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            columns: vec![
+                Column::new(
+                    "Todo",
+                    &[
+                        "Write the release notes",
+                        "Update the screenshots",
+                        "Review the theme colors",
+                        "Add the Rating story",
+                        "Fix the scrollbar flicker",
+                        "Translate the new labels",
+                        "Profile the table scrolling",
+                        "Clean up the examples",
+                    ],
+                ),
+                Column::new("In Progress", &["Drag and drop", "Carousel"]).limit(WIP_LIMIT),
+                Column::new("Done", &["Charts", "Image viewer"]),
+            ],
+            drop_target: None,
+        }
+    }
+
+    /// Update the drop target by the position of the dragging card over the card at `ix`.
+    fn on_card_drag_move(
+        &mut self,
+        col: usize,
+        ix: usize,
+        event: &DragMoveEvent<DragCard>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if !event.drag(cx).is_from(cx.entity_id()) || !event.bounds.contains(&event.event.position)
+        {
+            return;
+        }
+
+        let ix = if event.event.position.y < event.bounds.center().y {
+            ix
+        } else {
+            ix + 1
+        };
+        if self.drop_target != Some((col, ix)) {
+            self.drop_target = Some((col, ix));
+            cx.notify();
+        }
+    }
+
+    /// Scroll the column near the edges, and append to the end when entering the column.
+    fn on_column_drag_move(
+        &mut self,
+        col: usize,
+        event: &DragMoveEvent<DragCard>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if !event.drag(cx).is_from(cx.entity_id()) {
+            return;
+        }
+
+        self.columns[col].auto_scroll.update(
+            Axis::Vertical,
+            event.event.position,
+            event.bounds,
+            cx,
+            move |this, distance, _| {
+                dnd::scroll_by(&this.columns[col].scroll_handle, Axis::Vertical, distance)
+            },
+        );
+
+        if event.bounds.contains(&event.event.position)
+            && self.drop_target.map(|(target, _)| target) != Some(col)
+        {
+            self.drop_target = Some((col, self.columns[col].cards.len()));
+            cx.notify();
+        }
+    }
+
+    fn move_card(&mut self, drag: &DragCard, col: usize, cx: &mut ViewContext<Self>) {
+        let (from_col, from_ix) = drag.value;
+        let mut to_ix = self
+            .drop_target
+            .take()
+            .filter(|(target, _)| *target == col)
+            .map_or(self.columns[col].cards.len(), |(_, ix)| ix);
+        for column in self.columns.iter_mut() {
+            column.auto_scroll.stop();
+        }
+
+        if from_col == col && to_ix > from_ix {
+            to_ix -= 1;
+        }
+        let card = self.columns[from_col].cards.remove(from_ix);
+        let to_ix = to_ix.min(self.columns[col].cards.len());
+        self.columns[col].cards.insert(to_ix, card);
+        cx.notify();
+    }
+
+    fn render_card(&self, col: usize, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let title = self.columns[col].cards[ix].clone();
+        let board_id = cx.entity_id();
+
+        div()
+            .id(SharedString::from(format!("card-{}-{}", col, ix)))
+            .flex_shrink_0()
+            .p_3()
+            .rounded(px(cx.theme().radius))
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().background)
+            .text_sm()
+            .cursor_grab()
+            .when(self.drop_target == Some((col, ix)), |this| {
+                this.border_t_2().border_color(cx.theme().drag_border)
+            })
+            .child(title.clone())
+            .on_drag_item(DragItem::new(board_id, (col, ix)), move |_, _| {
+                div()
+                    .w_48()
+                    .p_3()
+                    .text_sm()
+                    .child(title.clone())
+                    .into_any_element()
+            })
+            .on_drag_move(
+                cx.listener(move |this, event: &DragMoveEvent<DragCard>, cx| {
+                    this.on_card_drag_move(col, ix, event, cx)
+                }),
+            )
+    }
+
+    fn render_column(&self, col: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let column = &self.columns[col];
+        let board_id = cx.entity_id();
+        let full = column.is_full();
+        let count = column.cards.len();
+        let title = match column.limit {
+            Some(limit) => format!("{} ({}/{})", column.title, count, limit),
+            None => format!("{} ({})", column.title, count),
+        };
+
+        v_flex()
+            .id(("column", col))
+            .flex_1()
+            .h(px(360.))
+            .gap_2()
+            .p_2()
+            .rounded(px(cx.theme().radius))
+            .bg(cx.theme().muted)
+            .child(div().px_1().text_sm().font_semibold().child(title))
+            .child(
+                v_flex()
+                    .id(("column-cards", col))
+                    .flex_1()
+                    .gap_2()
+                    .overflow_y_scroll()
+                    .track_scroll(&column.scroll_handle)
+                    .children((0..count).map(|ix| self.render_card(col, ix, cx)))
+                    .when(self.drop_target == Some((col, count)), |this| {
+                        this.child(div().flex_shrink_0().h(px(2.)).bg(cx.theme().drag_border))
+                    }),
+            )
+            .on_drag_move(
+                cx.listener(move |this, event: &DragMoveEvent<DragCard>, cx| {
+                    this.on_column_drag_move(col, event, cx)
+                }),
+            )
+            .drop_target(
+                move |drag: &DragCard, _| drag.is_from(board_id) && (drag.value.0 == col || !full),
+                cx.listener(move |this, drag: &DragCard, cx| this.move_card(drag, col, cx)),
+            )
+    }
+}
+
+impl gpui::FocusableView for DndStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for DndStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if !cx.has_active_drag() {
+            self.drop_target = None;
+        }
+
+        h_flex()
+            .items_start()
+            .gap_4()
+            .children((0..self.columns.len()).map(|col| self.render_column(col, cx)))
+    }
+}
@@ -6,6 +6,7 @@ use gpui::{
 use ui::{
     dropdown::{Dropdown, DropdownEvent, DropdownItem, SearchableVec},
     h_flex,
+    select::{MultiSelect, MultiSelectEvent, Select, SelectEvent, SelectGroup, SelectOption},
     theme::ActiveTheme,
     v_flex, FocusableCycle, IconName, Sizable,
 };
@@ -53,6 +54,8 @@ pub struct DropdownStory {
     simple_dropdown2: View<Dropdown<SearchableVec<SharedString>>>,
     simple_dropdown3: View<Dropdown<Vec<SharedString>>>,
     disabled_dropdown: View<Dropdown<Vec<SharedString>>>,
+    language_select: View<Select<&'static str>>,
+    tags_select: View<MultiSelect<SharedString>>,
 }
 
 impl super::Story for DropdownStory {
@@ -110,9 +113,59 @@ impl DropdownStory {
                 .menu_width(px(320.))
         });
 
+        let language_select = cx.new_view(|cx| {
+            Select::grouped(
+                "select-language",
+                [
+                    SelectGroup::new(
+                        "Systems",
+                        [
+                            SelectOption::new("rust", "Rust"),
+                            SelectOption::new("cpp", "C++"),
+                            SelectOption::new("zig", "Zig").disabled(true),
+                        ],
+                    ),
+                    SelectGroup::new(
+                        "Web",
+                        [
+                            SelectOption::new("js", "JavaScript"),
+                            SelectOption::new("ts", "TypeScript"),
+                        ],
+                    ),
+                ],
+                cx,
+            )
+            .placeholder("Select a language")
+            .cleanable()
+            .width(px(200.))
+        });
+
+        let tags_select = cx.new_view(|cx| {
+            MultiSelect::new(
+                "select-tags",
+                ["Bug", "Feature", "Docs", "Performance", "Refactor"]
+                    .into_iter()
+                    .map(|tag| SelectOption::new(SharedString::from(tag), tag)),
+                cx,
+            )
+            .placeholder("Select tags")
+            .select_all(true)
+            .max_selected(3)
+            .cleanable()
+            .width(px(320.))
+        });
+
         cx.new_view(|cx| {
             cx.subscribe(&country_dropdown, Self::on_dropdown_event)
                 .detach();
+            cx.subscribe(&language_select, |_, _, event, _| match event {
+                SelectEvent::Change(value) => println!("Selected language: {:?}", value),
+            })
+            .detach();
+            cx.subscribe(&tags_select, |_, _, event, _| match event {
+                MultiSelectEvent::Change(values) => println!("Selected tags: {:?}", values),
+            })
+            .detach();
 
             Self {
                 country_dropdown,
@@ -160,6 +213,8 @@ impl DropdownStory {
                         .small()
                         .disabled(true)
                 }),
+                language_select,
+                tags_select,
             }
         })
     }
@@ -201,6 +256,8 @@ impl FocusableCycle for DropdownStory {
             self.simple_dropdown1.focus_handle(cx),
             self.simple_dropdown2.focus_handle(cx),
             self.simple_dropdown3.focus_handle(cx),
+            self.language_select.focus_handle(cx),
+            self.tags_select.focus_handle(cx),
         ]
     }
 }
@@ -220,7 +277,9 @@ impl Render for DropdownStory {
                     .items_center()
                     .gap_4()
                     .child(self.country_dropdown.clone())
-                    .child(self.fruit_dropdown.clone()),
+                    .child(self.fruit_dropdown.clone())
+                    .child(self.language_select.clone())
+                    .child(self.tags_select.clone()),
             )
             .child(
                 v_flex()
@@ -248,6 +307,10 @@ impl Render for DropdownStory {
                         "Language: {:?}",
                         self.simple_dropdown2.read(cx).selected_value()
                     ))
+                    .child(format!(
+                        "Select: {:?}",
+                        self.language_select.read(cx).value()
+                    ))
                     .child("This is other text."),
             )
             .child(
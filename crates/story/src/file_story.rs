@@ -0,0 +1,128 @@
+use std::path::PathBuf;
+
+use gpui::{
+    px, IntoElement, ParentElement, Render, Styled, View, ViewContext, VisualContext as _,
+    WindowContext,
+};
+use ui::{
+    button::Button,
+    file::{DropZone, FileFilter, FilePicker},
+    h_flex,
+    label::Label,
+    v_flex,
+};
+
+pub struct FileStory {
+    focus_handle: gpui::FocusHandle,
+    paths: Vec<PathBuf>,
+}
+
+impl super::Story for FileStory {
+    fn title() -> &'static str {
+        "File"
+    }
+
+    fn description() -> &'static str {
+        "The file picker dialogs and the drop zone to accept the files."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl FileStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            paths: vec![],
+        }
+    }
+
+    fn set_paths(&mut self, paths: Vec<PathBuf>, cx: &mut ViewContext<Self>) {
+        self.paths = paths;
+        cx.notify();
+    }
+
+    fn pick(&mut self, picker: FilePicker, cx: &mut ViewContext<Self>) {
+        let task = picker.pick(cx);
+        cx.spawn(|this, mut cx| async move {
+            if let Some(paths) = task.await {
+                _ = this.update(&mut cx, |this, cx| this.set_paths(paths, cx));
+            }
+        })
+        .detach();
+    }
+
+    fn save(&mut self, cx: &mut ViewContext<Self>) {
+        let directory = std::env::current_dir().unwrap_or_default();
+        let task = FilePicker::new()
+            .filter(FileFilter::new("CSV", ["csv"]))
+            .save(&directory, cx);
+        cx.spawn(|this, mut cx| async move {
+            if let Some(path) = task.await {
+                _ = this.update(&mut cx, |this, cx| this.set_paths(vec![path], cx));
+            }
+        })
+        .detach();
+    }
+}
+
+impl gpui::FocusableView for FileStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for FileStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+
+        v_flex()
+            .gap_6()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Button::new("open-files")
+                            .label("Open Images...")
+                            .on_click(cx.listener(|this, _, cx| {
+                                let picker = FilePicker::new()
+                                    .multiple(true)
+                                    .filter(FileFilter::new("Images", ["png", "jpg", "svg"]));
+                                this.pick(picker, cx);
+                            })),
+                    )
+                    .child(Button::new("open-folder").label("Open Folder...").on_click(
+                        cx.listener(|this, _, cx| {
+                            this.pick(FilePicker::new().directories(), cx);
+                        }),
+                    ))
+                    .child(
+                        Button::new("save-file")
+                            .label("Save As CSV...")
+                            .on_click(cx.listener(|this, _, cx| this.save(cx))),
+                    ),
+            )
+            .child(
+                DropZone::new("drop-zone")
+                    .w(px(400.))
+                    .h(px(160.))
+                    .picker(FilePicker::new().multiple(true))
+                    .on_drop(move |paths, cx| {
+                        view.update(cx, |this, cx| this.set_paths(paths.to_vec(), cx));
+                    }),
+            )
+            .child(
+                v_flex().gap_1().children(
+                    self.paths
+                        .iter()
+                        .map(|path| Label::new(path.display().to_string())),
+                ),
+            )
+    }
+}
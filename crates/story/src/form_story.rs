@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use gpui::{
+    prelude::FluentBuilder as _, px, IntoElement, ParentElement, Render, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use ui::{
+    button::Button,
+    form::{Form, FormCheckbox, FormEvent, FormField, Validator},
+    input::TextInput,
+    label::Label,
+    select::{Select, SelectOption},
+    time::date_picker::DatePicker,
+    v_flex,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Profile {
+    username: String,
+    email: String,
+    bio: String,
+    role: Option<String>,
+    birthday: Option<String>,
+    subscribe: bool,
+}
+
+pub struct FormStory {
+    focus_handle: gpui::FocusHandle,
+    form: View<Form>,
+    submitted: Option<Profile>,
+}
+
+impl super::Story for FormStory {
+    fn title() -> &'static str {
+        "Form"
+    }
+
+    fn description() -> &'static str {
+        "The form to layout the fields and validate them before submitting."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl FormStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let username = cx.new_view(|cx| TextInput::new(cx).placeholder("jason_lee"));
+        let email = cx.new_view(|cx| TextInput::new(cx).placeholder("jason@example.com"));
+        let bio = cx.new_view(|cx| TextInput::new(cx).placeholder("Tell us about yourself"));
+        let role = cx.new_view(|cx| {
+            Select::new(
+                "role",
+                vec![
+                    SelectOption::new("developer".to_string(), "Developer"),
+                    SelectOption::new("designer".to_string(), "Designer"),
+                    SelectOption::new("manager".to_string(), "Manager"),
+                ],
+                cx,
+            )
+            .placeholder("Select a role")
+        });
+        let birthday = cx.new_view(|cx| DatePicker::new("birthday", cx));
+        let subscribe =
+            cx.new_view(|cx| FormCheckbox::new("subscribe", cx).label("Subscribe to newsletter"));
+
+        let form = cx.new_view(|cx| {
+            Form::new(cx)
+                .field(
+                    FormField::new("username", username)
+                        .label("Username")
+                        .validator(Validator::required())
+                        .validator(Validator::min(3.))
+                        .validator(
+                            Validator::pattern(Regex::new("^[a-z0-9_]+$").unwrap())
+                                .message("Only a-z, 0-9 and _ are allowed."),
+                        )
+                        .validator(Validator::custom_async(|value, cx| {
+                            // Simulate to check the username on the server.
+                            let timer = cx.background_executor().timer(Duration::from_millis(500));
+                            cx.background_executor().spawn(async move {
+                                timer.await;
+                                match value.as_str() {
+                                    Some("admin") => Err("The username is already taken.".into()),
+                                    _ => Ok(()),
+                                }
+                            })
+                        })),
+                    cx,
+                )
+                .field(
+                    FormField::new("email", email)
+                        .label("Email")
+                        .validator(Validator::required())
+                        .validator(
+                            Validator::pattern(Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap())
+                                .message("Invalid email address."),
+                        ),
+                    cx,
+                )
+                .field(
+                    FormField::new("bio", bio)
+                        .label("Bio")
+                        .description("At most 100 characters.")
+                        .validator(Validator::max(100.)),
+                    cx,
+                )
+                .field(
+                    FormField::new("role", role)
+                        .label("Role")
+                        .validator(Validator::required()),
+                    cx,
+                )
+                .field(FormField::new("birthday", birthday).label("Birthday"), cx)
+                .field(FormField::new("subscribe", subscribe), cx)
+                .submit_label("Sign Up")
+        });
+
+        cx.subscribe(&form, |this, _, event: &FormEvent, cx| match event {
+            FormEvent::Submit(values) => {
+                this.submitted = serde_json::from_value(values.clone()).ok();
+                cx.notify();
+            }
+        })
+        .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            form,
+            submitted: None,
+        }
+    }
+
+    fn load(&mut self, cx: &mut ViewContext<Self>) {
+        let profile = Profile {
+            username: "jason_lee".into(),
+            email: "jason@example.com".into(),
+            bio: "Rust developer.".into(),
+            role: Some("developer".into()),
+            birthday: Some("1990-01-01".into()),
+            subscribe: true,
+        };
+        self.form.update(cx, |form, cx| {
+            _ = form.set_values(&profile, cx);
+        });
+    }
+}
+
+impl gpui::FocusableView for FormStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for FormStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .w(px(400.))
+            .gap_6()
+            .child(
+                Button::new("load")
+                    .label("Load Profile")
+                    .on_click(cx.listener(|this, _, cx| this.load(cx))),
+            )
+            .child(self.form.clone())
+            .when_some(self.submitted.as_ref(), |this, profile| {
+                this.child(Label::new(format!("Submitted: {:?}", profile)))
+            })
+    }
+}
@@ -1,5 +1,14 @@
-use gpui::{px, ParentElement as _, Render, Styled, View, VisualContext as _, WindowContext};
-use ui::{h_flex, svg_img, v_flex, SvgImg};
+use gpui::{
+    px, ParentElement as _, PathPromptOptions, Render, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    avatar::{Avatar, AvatarGroup, AvatarStatus},
+    button::Button,
+    h_flex,
+    image_viewer::ImageViewer,
+    svg_img, v_flex, Sizable as _, SvgImg,
+};
 
 const GOOGLE_LOGO: &str = include_str!("./fixtures/google.svg");
 const PIE_JSON: &str = include_str!("./fixtures/pie.json");
@@ -9,6 +18,7 @@ pub struct ImageStory {
     google_logo: SvgImg,
     pie_chart: SvgImg,
     inbox_img: SvgImg,
+    image_viewer: View<ImageViewer>,
 }
 
 impl super::Story for ImageStory {
@@ -30,9 +40,33 @@ impl ImageStory {
             google_logo: svg_img().source(GOOGLE_LOGO.as_bytes(), px(300.), px(300.)),
             pie_chart: svg_img().source(chart.svg().unwrap().as_bytes(), px(400.), px(400.)),
             inbox_img: svg_img().source("icons/inbox.svg", px(300.), px(300.)),
+            image_viewer: cx.new_view(ImageViewer::new),
         }
     }
 
+    fn open_image(&mut self, cx: &mut ViewContext<Self>) {
+        let rx = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+        });
+
+        let image_viewer = self.image_viewer.clone();
+        cx.spawn(|_, mut cx| async move {
+            let Some(path) = rx
+                .await
+                .ok()
+                .flatten()
+                .and_then(|paths| paths.into_iter().next())
+            else {
+                return;
+            };
+
+            _ = image_viewer.update(&mut cx, |viewer, cx| viewer.load_path(path, cx));
+        })
+        .detach();
+    }
+
     pub fn view(cx: &mut WindowContext) -> View<Self> {
         cx.new_view(|cx| Self::new(cx))
     }
@@ -45,7 +79,7 @@ impl gpui::FocusableView for ImageStory {
 }
 
 impl Render for ImageStory {
-    fn render(&mut self, _cx: &mut gpui::ViewContext<Self>) -> impl gpui::IntoElement {
+    fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl gpui::IntoElement {
         v_flex()
             .gap_4()
             .size_full()
@@ -59,7 +93,47 @@ impl Render for ImageStory {
                     .child(self.google_logo.clone().size_12().flex_grow())
                     .child(self.google_logo.clone().w(px(300.)).h(px(300.))),
             )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Avatar::new()
+                            .src("https://avatars.githubusercontent.com/u/5518?v=4")
+                            .name("Jason Lee")
+                            .large()
+                            .status(AvatarStatus::Online),
+                    )
+                    .child(Avatar::new().name("Jason Lee").status(AvatarStatus::Busy))
+                    .child(Avatar::new().name("Floyd Wang").small())
+                    .child(Avatar::new().xsmall())
+                    .child(
+                        AvatarGroup::new()
+                            .max(3)
+                            .child(Avatar::new().name("Jason Lee"))
+                            .child(Avatar::new().name("Floyd Wang"))
+                            .child(Avatar::new().name("Mike Chen"))
+                            .child(Avatar::new().name("Anna Smith"))
+                            .child(Avatar::new().name("Zed")),
+                    ),
+            )
             .child(self.inbox_img.clone().w(px(80.)).h(px(80.)))
             .child(self.pie_chart.clone().size_full())
+            .child(
+                v_flex()
+                    .w_full()
+                    .gap_2()
+                    .child(
+                        Button::new("open-image")
+                            .label("Open Image...")
+                            .small()
+                            .on_click(cx.listener(|this, _, cx| this.open_image(cx))),
+                    )
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .h(px(400.))
+                            .child(self.image_viewer.clone()),
+                    ),
+            )
     }
 }
@@ -1,15 +1,23 @@
 use gpui::{
-    actions, div, px, AppContext, FocusHandle, InteractiveElement, IntoElement, KeyBinding,
-    ParentElement as _, Render, SharedString, Styled, View, ViewContext, VisualContext,
+    actions, div, px, AppContext, FocusHandle, HighlightStyle, InteractiveElement, IntoElement,
+    KeyBinding, ParentElement as _, Render, SharedString, Styled, View, ViewContext, VisualContext,
     WindowContext,
 };
 
+use std::{ops::Range, time::Duration};
+
 use crate::section;
 use ui::{
     button::Button,
     checkbox::Checkbox,
+    editable_label::{EditableLabel, EditableLabelEvent},
+    editor::CodeEditor,
     h_flex,
-    input::{InputEvent, OtpInput, TextInput},
+    highlighter::Highlighter,
+    input::{
+        password_strength, Autocomplete, AutocompleteEvent, InputEvent, NumberInput,
+        NumberInputEvent, OtpInput, Suggestion, TagInput, TagInputEvent, TextArea, TextInput,
+    },
     prelude::FluentBuilder as _,
     scroll::ScrollbarAxis,
     v_flex, FocusableCycle, IconName, Sizable, StyledExt,
@@ -19,6 +27,54 @@ actions!(input_story, [Tab, TabPrev]);
 
 const CONTEXT: &str = "InputStory";
 
+/// A tiny highlighter for the example, it highlights the strings, numbers and keywords of JSON.
+struct JsonHighlighter;
+
+impl Highlighter for JsonHighlighter {
+    fn highlight(
+        &self,
+        _: &str,
+        code: &str,
+        _: &WindowContext,
+    ) -> Vec<(Range<usize>, HighlightStyle)> {
+        let mut highlights = vec![];
+        let mut ix = 0;
+        while ix < code.len() {
+            let rest = &code[ix..];
+            let (len, color) = if rest.starts_with('"') {
+                let len = rest[1..]
+                    .find(['"', '\n'])
+                    .map_or(rest.len(), |end| end + 2);
+                (len, ui::green_500())
+            } else if rest.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_digit() && !matches!(c, '.' | '-' | 'e' | 'E'))
+                    .unwrap_or(rest.len());
+                (len, ui::blue_500())
+            } else if let Some(keyword) = ["true", "false", "null"]
+                .iter()
+                .find(|keyword| rest.starts_with(*keyword))
+            {
+                (keyword.len(), ui::orange_500())
+            } else {
+                ix += rest.chars().next().map_or(1, char::len_utf8);
+                continue;
+            };
+
+            highlights.push((
+                ix..ix + len,
+                HighlightStyle {
+                    color: Some(color),
+                    ..Default::default()
+                },
+            ));
+            ix += len;
+        }
+
+        highlights
+    }
+}
+
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([
         KeyBinding::new("shift-tab", TabPrev, Some(CONTEXT)),
@@ -42,8 +98,34 @@ pub struct InputStory {
     otp_input_small: View<OtpInput>,
     otp_input_large: View<OtpInput>,
     opt_input_sized: View<OtpInput>,
+    editable_label: View<EditableLabel>,
+    autocomplete: View<Autocomplete>,
+    tag_input: View<TagInput>,
+    number_input: View<NumberInput>,
+    price_input: View<NumberInput>,
+    phone_input: View<TextInput>,
+    date_input: View<TextInput>,
+    textarea: View<TextArea>,
+    code_editor: View<CodeEditor>,
+    username_input: View<TextInput>,
+    loading_input: View<TextInput>,
 }
 
+const COUNTRIES: [(&str, &str); 12] = [
+    ("Australia", "AU"),
+    ("Brazil", "BR"),
+    ("Canada", "CA"),
+    ("China", "CN"),
+    ("France", "FR"),
+    ("Germany", "DE"),
+    ("India", "IN"),
+    ("Japan", "JP"),
+    ("South Korea", "KR"),
+    ("Spain", "ES"),
+    ("United Kingdom", "GB"),
+    ("United States", "US"),
+];
+
 impl super::Story for InputStory {
     fn title() -> &'static str {
         "Input"
@@ -64,6 +146,24 @@ impl InputStory {
     }
 
     fn new(cx: &mut ViewContext<Self>) -> Self {
+        let editable_label = cx.new_view(|cx| {
+            EditableLabel::new("Untitled Document", cx)
+                .placeholder("Enter a title")
+                .validate(|text| {
+                    if text.trim().is_empty() {
+                        Err("The title can't be empty.".into())
+                    } else {
+                        Ok(())
+                    }
+                })
+        });
+        cx.subscribe(&editable_label, |_, _, event: &EditableLabelEvent, _| {
+            if let EditableLabelEvent::Change(text) = event {
+                println!("Rename to: {}", text);
+            }
+        })
+        .detach();
+
         let input1 = cx.new_view(|cx| {
             let mut input = TextInput::new(cx).cleanable();
             input.set_text(
@@ -75,13 +175,19 @@ impl InputStory {
 
         cx.subscribe(&input1, Self::on_input_event).detach();
 
-        let input2 = cx.new_view(|cx| TextInput::new(cx).placeholder("Enter text here..."));
+        let input2 = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .placeholder("Enter text here...")
+                .debounce(Duration::from_millis(300))
+        });
 
         cx.subscribe(&input2, Self::on_input_event).detach();
 
         let mask_input = cx.new_view(|cx| {
-            let mut input = TextInput::new(cx).cleanable();
-            input.set_masked(true, cx);
+            let mut input = TextInput::new(cx)
+                .password()
+                .strength_meter(password_strength)
+                .cleanable();
             input.set_text("this-is-password", cx);
             input
         });
@@ -107,16 +213,109 @@ impl InputStory {
                 .placeholder("This input have prefix and suffix.")
         });
 
-        let otp_input = cx.new_view(|cx| OtpInput::new(6, cx).masked(true));
-        cx.subscribe(&otp_input, |this, _, ev: &InputEvent, cx| match ev {
-            InputEvent::Change(text) => {
-                this.otp_value = Some(text.clone());
-                cx.notify();
+        let view = cx.view().downgrade();
+        let otp_input = cx.new_view(|cx| {
+            OtpInput::new(6, cx)
+                .masked(true)
+                .on_complete(move |code, cx| {
+                    _ = view.update(cx, |this, cx| {
+                        this.otp_value = Some(code.clone());
+                        cx.notify();
+                    });
+                })
+        });
+
+        let autocomplete = cx.new_view(|cx| {
+            Autocomplete::new(cx)
+                .placeholder("Search a country...")
+                .provider(|query, cx| {
+                    let query = query.to_lowercase();
+                    let executor = cx.background_executor().clone();
+                    cx.background_executor().spawn(async move {
+                        // Simulate a slow remote search.
+                        executor.timer(Duration::from_millis(100)).await;
+                        COUNTRIES
+                            .iter()
+                            .filter(|(name, _)| name.to_lowercase().contains(&query))
+                            .map(|(name, code)| Suggestion::new(*name).description(*code))
+                            .collect()
+                    })
+                })
+        });
+        cx.subscribe(&autocomplete, |_, _, event: &AutocompleteEvent, _| {
+            let AutocompleteEvent::Accept(suggestion) = event;
+            println!("Accept: {}", suggestion.text);
+        })
+        .detach();
+
+        let tag_input = cx.new_view(|cx| {
+            let mut input = TagInput::new(cx)
+                .placeholder("Add tags, separated by comma...")
+                .validate(|tag, tags| {
+                    tag.len() <= 20 && !tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+                });
+            input.set_tags(vec!["Rust".into(), "GPUI".into()], cx);
+            input
+        });
+        cx.subscribe(&tag_input, |_, _, event: &TagInputEvent, _| {
+            let TagInputEvent::Change(tags) = event;
+            println!("Tags: {:?}", tags);
+        })
+        .detach();
+
+        let number_input = cx.new_view(|cx| {
+            let mut input = NumberInput::new(cx)
+                .min(0.)
+                .max(100.)
+                .placeholder("0 - 100");
+            input.set_value(Some(10.), cx);
+            input
+        });
+        let price_input = cx.new_view(|cx| {
+            let mut input = NumberInput::new(cx).step(0.5).precision(2).small();
+            input.set_value(Some(1234567.8), cx);
+            input
+        });
+        cx.subscribe(&price_input, |_, _, event: &NumberInputEvent, _| {
+            let NumberInputEvent::Change(value) = event;
+            println!("Price: {:?}", value);
+        })
+        .detach();
+
+        let phone_input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .mask("(999) 999-9999")
+                .placeholder("(555) 123-4567")
+        });
+        cx.subscribe(&phone_input, |_, input, event: &InputEvent, cx| {
+            if let InputEvent::Change(text) = event {
+                println!("Phone: {} ({})", text, input.read(cx).raw_text());
             }
-            _ => {}
         })
         .detach();
 
+        let textarea = cx.new_view(|cx| {
+            TextArea::new(cx)
+                .rows(3)
+                .max_rows(6)
+                .placeholder("Enter a message, press Cmd/Ctrl+Enter to send...")
+        });
+        cx.subscribe(&textarea, |_, input, event: &InputEvent, cx| {
+            if let InputEvent::PressEnter = event {
+                println!("Send: {}", input.read(cx).text(cx));
+            }
+        })
+        .detach();
+
+        let code_editor = cx.new_view(|cx| {
+            let mut editor = CodeEditor::new(cx).highlighter("json", JsonHighlighter);
+            editor.set_text(
+                "{\n    \"name\": \"gpui-component\",\n    \"version\": 1.0,\n    \"private\": true,\n    \"keywords\": [\"gpui\", \"ui\", \"components\"],\n    \"license\": null\n}",
+                cx,
+            );
+            editor
+        });
+
         Self {
             input1,
             input2,
@@ -161,6 +360,34 @@ impl InputStory {
                     .default_value("654321")
                     .with_size(px(55.))
             }),
+            editable_label,
+            autocomplete,
+            tag_input,
+            number_input,
+            price_input,
+            phone_input,
+            textarea,
+            code_editor,
+            loading_input: cx.new_view(|cx| {
+                let mut input = TextInput::new(cx)
+                    .suffix(|_| div().child(IconName::Info).mr_3())
+                    .loading(true)
+                    .cleanable();
+                input.set_text("Searching...", cx);
+                input
+            }),
+            username_input: cx.new_view(|cx| {
+                TextInput::new(cx)
+                    .filter(|c| c.is_ascii_alphanumeric() || c == '_')
+                    .max_length(16)
+                    .show_counter(true)
+                    .placeholder("Username, only [a-zA-Z0-9_] allowed.")
+            }),
+            date_input: cx.new_view(|cx| {
+                TextInput::new(cx)
+                    .mask("9999-99-99")
+                    .placeholder("YYYY-MM-DD")
+            }),
         }
     }
 
@@ -180,9 +407,11 @@ impl InputStory {
     ) {
         match event {
             InputEvent::Change(text) => println!("Change: {}", text),
+            InputEvent::Input(text) => println!("Input: {}", text),
             InputEvent::PressEnter => println!("PressEnter"),
             InputEvent::Focus => println!("Focus"),
             InputEvent::Blur => println!("Blur"),
+            InputEvent::Cleared => println!("Cleared"),
         };
     }
 
@@ -206,11 +435,19 @@ impl FocusableCycle for InputStory {
             self.input2.focus_handle(cx),
             self.disabled_input.focus_handle(cx),
             self.mash_input.focus_handle(cx),
+            self.username_input.focus_handle(cx),
             self.prefix_input1.focus_handle(cx),
             self.both_input1.focus_handle(cx),
             self.suffix_input1.focus_handle(cx),
             self.large_input.focus_handle(cx),
             self.small_input.focus_handle(cx),
+            self.autocomplete.focus_handle(cx),
+            self.tag_input.focus_handle(cx),
+            self.number_input.focus_handle(cx),
+            self.price_input.focus_handle(cx),
+            self.phone_input.focus_handle(cx),
+            self.date_input.focus_handle(cx),
+            self.textarea.focus_handle(cx),
             self.otp_input.focus_handle(cx),
         ]
         .to_vec()
@@ -246,7 +483,8 @@ impl Render for InputStory {
                     .child(
                         section("Input State", cx)
                             .child(self.disabled_input.clone())
-                            .child(self.mash_input.clone()),
+                            .child(self.mash_input.clone())
+                            .child(self.username_input.clone()),
                     ),
             )
             .child(
@@ -257,14 +495,35 @@ impl Render for InputStory {
                         section("Prefix and Suffix", cx)
                             .child(self.prefix_input1.clone())
                             .child(self.both_input1.clone())
-                            .child(self.suffix_input1.clone()),
+                            .child(self.suffix_input1.clone())
+                            .child(self.loading_input.clone()),
                     )
                     .child(
                         section("Input Size", cx)
                             .child(self.large_input.clone())
                             .child(self.small_input.clone()),
+                    )
+                    .child(section("Editable Label", cx).child(self.editable_label.clone())),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_start()
+                    .child(section("Autocomplete", cx).child(self.autocomplete.clone()))
+                    .child(section("Tag Input", cx).child(self.tag_input.clone()))
+                    .child(
+                        section("Number Input", cx)
+                            .child(self.number_input.clone())
+                            .child(self.price_input.clone()),
+                    )
+                    .child(
+                        section("Input Mask", cx)
+                            .child(self.phone_input.clone())
+                            .child(self.date_input.clone()),
                     ),
             )
+            .child(section("Text Area", cx).child(self.textarea.clone()))
+            .child(section("Code Editor", cx).child(self.code_editor.clone()))
             .child(
                 section(
                     h_flex()
@@ -275,7 +534,7 @@ impl Render for InputStory {
                             Checkbox::new("otp-mask")
                                 .label("Masked")
                                 .checked(self.otp_masked)
-                                .on_click(cx.listener(Self::toggle_opt_masked)),
+                                .on_change(cx.listener(Self::toggle_opt_masked)),
                         ),
                     cx,
                 )
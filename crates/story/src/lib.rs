@@ -1,6 +1,11 @@
 mod button_story;
 mod calendar_story;
+mod carousel_story;
+mod chart_story;
+mod dnd_story;
 mod dropdown_story;
+mod file_story;
+mod form_story;
 mod icon_story;
 mod image_story;
 mod input_story;
@@ -8,17 +13,26 @@ mod list_story;
 mod modal_story;
 mod popup_story;
 mod progress_story;
+mod rating_story;
 mod resizable_story;
 mod scrollable_story;
+mod settings_story;
+mod status_bar_story;
 mod switch_story;
 mod table_story;
 mod text_story;
 mod tooltip_story;
+mod tree_story;
 mod webview_story;
 
 pub use button_story::ButtonStory;
 pub use calendar_story::CalendarStory;
+pub use carousel_story::CarouselStory;
+pub use chart_story::ChartStory;
+pub use dnd_story::DndStory;
 pub use dropdown_story::DropdownStory;
+pub use file_story::FileStory;
+pub use form_story::FormStory;
 pub use icon_story::IconStory;
 pub use image_story::ImageStory;
 pub use input_story::InputStory;
@@ -26,58 +40,45 @@ pub use list_story::ListStory;
 pub use modal_story::ModalStory;
 pub use popup_story::PopupStory;
 pub use progress_story::ProgressStory;
+pub use rating_story::RatingStory;
 pub use resizable_story::ResizableStory;
 pub use scrollable_story::ScrollableStory;
+pub use settings_story::SettingsStory;
+pub use status_bar_story::StatusBarStory;
 use serde::{Deserialize, Serialize};
 pub use switch_story::SwitchStory;
 pub use table_story::TableStory;
 pub use text_story::TextStory;
 pub use tooltip_story::TooltipStory;
+pub use tree_story::TreeStory;
 pub use webview_story::WebViewStory;
 
 use gpui::{
     actions, div, prelude::FluentBuilder as _, px, AnyElement, AnyView, AppContext, Div,
     EventEmitter, FocusableView, Hsla, InteractiveElement, IntoElement, ParentElement, Render,
-    SharedString, Styled as _, View, ViewContext, VisualContext, WindowContext,
+    SharedString, Styled as _, View, ViewContext, VisualContext, WeakView, WindowContext,
 };
 
 use ui::{
+    button::{Button, ButtonStyled as _},
     divider::Divider,
-    dock::{register_panel, DockItemInfo, DockItemState, Panel, PanelEvent, TitleStyle},
+    dock::{
+        DockArea, DockItemInfo, DockItemState, LoadPanel, Panel, PanelDrop, PanelEvent, TitleStyle,
+    },
     h_flex,
     label::Label,
     notification::Notification,
     popup_menu::PopupMenu,
     theme::ActiveTheme,
-    v_flex, ContextModal,
+    v_flex, ContextModal, IconName, Sizable as _,
 };
 
-const PANEL_NAME: &str = "StoryContainer";
-
 pub fn init(cx: &mut AppContext) {
     input_story::init(cx);
     dropdown_story::init(cx);
     popup_story::init(cx);
 
-    register_panel(cx, PANEL_NAME, |_, _, info, cx| {
-        let story_state = match info {
-            DockItemInfo::Panel(value) => StoryState::from_value(value.clone()),
-            _ => {
-                unreachable!("Invalid DockItemInfo: {:?}", info)
-            }
-        };
-
-        let view = cx.new_view(|cx| {
-            let (title, description, closeable, zoomable, story) = story_state.to_story(cx);
-            let mut container = StoryContainer::new(cx).story(story, story_state.story_klass);
-            container.name = title.into();
-            container.description = description.into();
-            container.closeable = closeable;
-            container.zoomable = zoomable;
-            container
-        });
-        Box::new(view)
-    });
+    ui::register_panel!(cx, StoryContainer);
 }
 
 actions!(story, [PanelInfo]);
@@ -238,7 +239,12 @@ impl StoryState {
         match self.story_klass.to_string().as_str() {
             "ButtonStory" => story!(ButtonStory),
             "CalendarStory" => story!(CalendarStory),
+            "CarouselStory" => story!(CarouselStory),
+            "ChartStory" => story!(ChartStory),
+            "DndStory" => story!(DndStory),
             "DropdownStory" => story!(DropdownStory),
+            "FileStory" => story!(FileStory),
+            "FormStory" => story!(FormStory),
             "IconStory" => story!(IconStory),
             "ImageStory" => story!(ImageStory),
             "InputStory" => story!(InputStory),
@@ -246,12 +252,16 @@ impl StoryState {
             "ModalStory" => story!(ModalStory),
             "PopupStory" => story!(PopupStory),
             "ProgressStory" => story!(ProgressStory),
+            "RatingStory" => story!(RatingStory),
             "ResizableStory" => story!(ResizableStory),
             "ScrollableStory" => story!(ScrollableStory),
+            "SettingsStory" => story!(SettingsStory),
+            "StatusBarStory" => story!(StatusBarStory),
             "SwitchStory" => story!(SwitchStory),
             "TableStory" => story!(TableStory),
             "TextStory" => story!(TextStory),
             "TooltipStory" => story!(TooltipStory),
+            "TreeStory" => story!(TreeStory),
             "WebViewStory" => story!(WebViewStory),
             _ => {
                 unreachable!("Invalid story klass: {}", self.story_klass)
@@ -260,15 +270,46 @@ impl StoryState {
     }
 }
 
+impl LoadPanel for StoryContainer {
+    const PANEL_NAME: &'static str = "StoryContainer";
+
+    fn load(
+        state: &DockItemState,
+        _: WeakView<DockArea>,
+        cx: &mut WindowContext,
+    ) -> View<Self> {
+        let story_state = match &state.info {
+            DockItemInfo::Panel(value) => StoryState::from_value(value.clone()),
+            info => {
+                unreachable!("Invalid DockItemInfo: {:?}", info)
+            }
+        };
+
+        cx.new_view(|cx| {
+            let (title, description, closeable, zoomable, story) = story_state.to_story(cx);
+            let mut container = StoryContainer::new(cx).story(story, story_state.story_klass);
+            container.name = title.into();
+            container.description = description.into();
+            container.closeable = closeable;
+            container.zoomable = zoomable;
+            container
+        })
+    }
+}
+
 impl Panel for StoryContainer {
     fn panel_name(&self) -> &'static str {
-        "StoryContainer"
+        Self::PANEL_NAME
     }
 
     fn title(&self, _cx: &WindowContext) -> AnyElement {
         self.name.clone().into_any_element()
     }
 
+    fn title_text(&self, _cx: &AppContext) -> SharedString {
+        self.name.clone()
+    }
+
     fn title_style(&self, cx: &WindowContext) -> Option<TitleStyle> {
         if let Some(bg) = self.title_bg {
             Some(TitleStyle {
@@ -293,6 +334,30 @@ impl Panel for StoryContainer {
             .menu("Info", Box::new(PanelInfo))
     }
 
+    fn toolbar_buttons(&mut self, cx: &mut ViewContext<Self>) -> Vec<AnyElement> {
+        vec![Button::new("info")
+            .icon(IconName::Info)
+            .xsmall()
+            .ghost()
+            .tooltip("Info")
+            .on_click(cx.listener(|this, _, cx| this.on_action_panel_info(&PanelInfo, cx)))
+            .into_any_element()]
+    }
+
+    fn can_accept_drop(&self, drop: &PanelDrop, _cx: &WindowContext) -> bool {
+        matches!(drop, PanelDrop::Paths(_))
+    }
+
+    fn handle_drop(&mut self, drop: &PanelDrop, cx: &mut ViewContext<Self>) {
+        if let PanelDrop::Paths(paths) = drop {
+            cx.push_notification(Notification::new(format!(
+                "Dropped {} files on: {}",
+                paths.len(),
+                self.name
+            )));
+        }
+    }
+
     fn dump(&self, _cx: &AppContext) -> DockItemState {
         let mut state = DockItemState::new(self);
         let story_state = StoryState {
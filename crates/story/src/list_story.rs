@@ -3,16 +3,18 @@ use core::time;
 use fake::Fake;
 use gpui::{
     actions, div, px, relative, AnyElement, ElementId, FocusHandle, FocusableView,
-    InteractiveElement, IntoElement, ParentElement, Render, RenderOnce, Styled, Task, Timer, View,
-    ViewContext, VisualContext, WindowContext,
+    InteractiveElement, IntoElement, ParentElement, Render, RenderOnce, SharedString, Styled,
+    Timer, View, ViewContext, VisualContext, WindowContext,
 };
 
 use ui::{
+    button::{Button, ButtonStyled as _},
+    fuzzy::StringMatch,
     h_flex,
     label::Label,
     list::{List, ListDelegate, ListItem},
     theme::{hsl, ActiveTheme},
-    v_flex,
+    v_flex, IconName, Sizable as _,
 };
 
 actions!(list_story, [SelectedCompany]);
@@ -41,13 +43,22 @@ struct CompanyListItem {
     base: ListItem,
     ix: usize,
     company: Company,
+    /// The positions of the matched characters in the name.
+    positions: Vec<usize>,
     selected: bool,
 }
 
 impl CompanyListItem {
-    pub fn new(id: impl Into<ElementId>, company: Company, ix: usize, selected: bool) -> Self {
+    pub fn new(
+        id: impl Into<ElementId>,
+        company: Company,
+        positions: Vec<usize>,
+        ix: usize,
+        selected: bool,
+    ) -> Self {
         CompanyListItem {
             company,
+            positions,
             ix,
             base: ListItem::new(id),
             selected,
@@ -82,6 +93,11 @@ impl RenderOnce for CompanyListItem {
             .py_1()
             .overflow_x_hidden()
             .bg(bg_color)
+            .actions([Button::new("watch")
+                .ghost()
+                .xsmall()
+                .icon(IconName::Star)
+                .tooltip("Add to Watchlist")])
             .child(
                 h_flex()
                     .items_center()
@@ -94,7 +110,11 @@ impl RenderOnce for CompanyListItem {
                             .max_w(px(500.))
                             .overflow_x_hidden()
                             .flex_nowrap()
-                            .child(Label::new(self.company.name.clone()).whitespace_nowrap())
+                            .child(
+                                Label::new(self.company.name.clone())
+                                    .highlight_positions(self.positions)
+                                    .whitespace_nowrap(),
+                            )
                             .child(
                                 div().text_sm().overflow_x_hidden().child(
                                     Label::new(self.company.industry.clone())
@@ -133,6 +153,7 @@ impl RenderOnce for CompanyListItem {
 struct CompanyListDelegate {
     companies: Vec<Company>,
     matched_companies: Vec<Company>,
+    matched_positions: Vec<Vec<usize>>,
     selected_index: usize,
     confirmed_index: Option<usize>,
 }
@@ -148,15 +169,29 @@ impl ListDelegate for CompanyListDelegate {
         self.confirmed_index
     }
 
-    fn perform_search(&mut self, query: &str, _: &mut ViewContext<List<Self>>) -> Task<()> {
-        self.matched_companies = self
-            .companies
+    fn multi_select(&self) -> bool {
+        true
+    }
+
+    fn move_item(&mut self, from: usize, to: usize, _: &mut ViewContext<List<Self>>) {
+        let company = self.matched_companies.remove(from);
+        self.matched_companies.insert(to, company);
+        let positions = self.matched_positions.remove(from);
+        self.matched_positions.insert(to, positions);
+    }
+
+    fn match_candidates(&self) -> Vec<SharedString> {
+        self.companies
             .iter()
-            .filter(|company| company.name.to_lowercase().contains(&query.to_lowercase()))
-            .cloned()
-            .collect();
+            .map(|company| company.name.clone().into())
+            .collect()
+    }
 
-        Task::Ready(Some(()))
+    fn set_matches(&mut self, matches: Vec<StringMatch>, _: &mut ViewContext<List<Self>>) {
+        (self.matched_companies, self.matched_positions) = matches
+            .into_iter()
+            .map(|m| (self.companies[m.candidate_id].clone(), m.positions))
+            .unzip();
     }
 
     fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
@@ -199,6 +234,17 @@ impl ListDelegate for CompanyListDelegate {
         Some(element)
     }
 
+    fn render_footer(&self, _: &mut ViewContext<List<Self>>) -> Option<AnyElement> {
+        Some(
+            div()
+                .child(format!(
+                    "{} companies · Enter to select",
+                    self.matched_companies.len()
+                ))
+                .into_any_element(),
+        )
+    }
+
     fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
         if let Some(ix) = ix {
             self.selected_index = ix;
@@ -209,7 +255,14 @@ impl ListDelegate for CompanyListDelegate {
     fn render_item(&self, ix: usize, _cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
         let selected = ix == self.selected_index || Some(ix) == self.confirmed_index;
         if let Some(company) = self.matched_companies.get(ix) {
-            return Some(CompanyListItem::new(ix, company.clone(), ix, selected));
+            let positions = self.matched_positions.get(ix).cloned().unwrap_or_default();
+            return Some(CompanyListItem::new(
+                ix,
+                company.clone(),
+                positions,
+                ix,
+                selected,
+            ));
         }
 
         None
@@ -248,7 +301,7 @@ impl ListStory {
     }
 
     fn new(cx: &mut ViewContext<Self>) -> Self {
-        let companies = (0..1_000)
+        let companies = (0..10_000)
             .map(|_| random_company())
             .collect::<Vec<Company>>();
 
@@ -256,12 +309,14 @@ impl ListStory {
             List::new(
                 CompanyListDelegate {
                     matched_companies: companies.clone(),
+                    matched_positions: vec![],
                     companies,
                     selected_index: 0,
                     confirmed_index: None,
                 },
                 cx,
             )
+            .reorderable()
         });
 
         // Spawn a background to random refresh the list
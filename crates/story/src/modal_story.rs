@@ -62,7 +62,7 @@ impl ListDelegate for ListItemDeletegate {
         })
     }
 
-    fn render_item(&self, ix: usize, _: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
         let confirmed = Some(ix) == self.confirmed_index;
         let selected = Some(ix) == self.selected_index;
 
@@ -73,12 +73,14 @@ impl ListDelegate for ListItemDeletegate {
                 .selected(selected)
                 .py_1()
                 .px_3()
+                .leading(Icon::new(IconName::Globe).text_color(cx.theme().muted_foreground))
                 .child(
                     h_flex()
                         .items_center()
                         .justify_between()
                         .child(item.to_string()),
                 )
+                .description(format!("Item #{} in the list", ix + 1))
                 .suffix(|_| {
                     Button::new("like")
                         .icon(IconName::Heart)
@@ -398,6 +400,26 @@ impl ModalStory {
         self.input1.focus_handle(cx).focus(cx);
     }
 
+    fn show_modeless(&mut self, cx: &mut ViewContext<Self>) {
+        let input = self.input1.clone();
+
+        cx.open_modeless("find-replace", move |modal, _| {
+            modal
+                .title("Find and Replace")
+                .width(px(360.))
+                .margin_top(px(120.))
+                .child(input.clone())
+                .footer(
+                    h_flex().gap_3().child(
+                        Button::new("find-next")
+                            .primary()
+                            .label("Find Next")
+                            .on_click(|_, cx| cx.push_notification("Find next clicked.")),
+                    ),
+                )
+        });
+    }
+
     fn on_action_test_action(&mut self, _: &TestAction, cx: &mut ViewContext<Self>) {
         cx.push_notification("You have clicked the TestAction.");
     }
@@ -427,7 +449,7 @@ impl Render for ModalStory {
                                 Checkbox::new("modal-overlay")
                                     .label("Modal Overlay")
                                     .checked(self.modal_overlay)
-                                    .on_click(cx.listener(|view, _, cx| {
+                                    .on_change(cx.listener(|view, _, cx| {
                                         view.modal_overlay = !view.modal_overlay;
                                         cx.notify();
                                     })),
@@ -436,7 +458,7 @@ impl Render for ModalStory {
                                 Checkbox::new("modal-show-close")
                                     .label("Model Close Button")
                                     .checked(self.model_show_close)
-                                    .on_click(cx.listener(|view, _, cx| {
+                                    .on_change(cx.listener(|view, _, cx| {
                                         view.model_show_close = !view.model_show_close;
                                         cx.notify();
                                     })),
@@ -445,7 +467,7 @@ impl Render for ModalStory {
                                 Checkbox::new("modal-padding")
                                     .label("Model Padding")
                                     .checked(self.model_padding)
-                                    .on_click(cx.listener(|view, _, cx| {
+                                    .on_change(cx.listener(|view, _, cx| {
                                         view.model_padding = !view.model_padding;
                                         cx.notify();
                                     })),
@@ -509,9 +531,18 @@ impl Render for ModalStory {
                         )
                     })
                     .child(
-                        Button::new("show-modal")
-                            .label("Open Modal...")
-                            .on_click(cx.listener(|this, _, cx| this.show_modal(cx))),
+                        h_flex()
+                            .gap_3()
+                            .child(
+                                Button::new("show-modal")
+                                    .label("Open Modal...")
+                                    .on_click(cx.listener(|this, _, cx| this.show_modal(cx))),
+                            )
+                            .child(
+                                Button::new("show-modeless")
+                                    .label("Open Modeless Dialog...")
+                                    .on_click(cx.listener(|this, _, cx| this.show_modeless(cx))),
+                            ),
                     )
                     .child(
                         h_flex()
@@ -23,7 +23,7 @@ struct Info(usize);
 
 actions!(
     popover_story,
-    [Copy, Paste, Cut, SearchAll, ToggleWindowMode]
+    [Copy, Paste, Cut, Delete, SearchAll, ToggleWindowMode]
 );
 impl_actions!(popover_story, [Info]);
 
@@ -176,6 +176,8 @@ impl Render for PopupStory {
                         .menu("Cut", Box::new(Cut))
                         .menu("Copy", Box::new(Copy))
                         .menu("Paste", Box::new(Paste))
+                        .menu_with_disabled("Delete", true, Box::new(Delete))
+                        .tooltip("Nothing is selected to delete")
                         .separator()
                         .separator()
                         .submenu("Settings", cx, move |menu, _| {
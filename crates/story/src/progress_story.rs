@@ -20,6 +20,8 @@ pub struct ProgressStory {
     slider1_value: f32,
     slider2: View<Slider>,
     slider2_value: f32,
+    slider3: View<Slider>,
+    slider3_value: (f32, f32),
 }
 
 impl super::Story for ProgressStory {
@@ -45,30 +47,58 @@ impl ProgressStory {
                 .default_value(15.)
                 .step(15.)
         });
-        cx.subscribe(&slider1, |this, _, event: &SliderEvent, cx| match event {
-            SliderEvent::Change(value) => {
+        cx.subscribe(&slider1, |this, _, event: &SliderEvent, cx| {
+            if let SliderEvent::Change(value) = event {
                 this.slider1_value = *value;
                 cx.notify();
             }
         })
         .detach();
 
-        let slider2 = cx.new_view(|_| Slider::horizontal().min(0.).max(5.).step(1.0));
-        cx.subscribe(&slider2, |this, _, event: &SliderEvent, cx| match event {
-            SliderEvent::Change(value) => {
+        let slider2 = cx.new_view(|_| {
+            Slider::horizontal()
+                .min(0.)
+                .max(5.)
+                .step(1.0)
+                .ticks(true)
+                .mark(0., "0")
+                .mark(5., "5")
+        });
+        cx.subscribe(&slider2, |this, _, event: &SliderEvent, cx| {
+            if let SliderEvent::Change(value) = event {
                 this.slider2_value = *value;
                 cx.notify();
             }
         })
         .detach();
 
+        let slider3 = cx.new_view(|_| {
+            Slider::horizontal()
+                .min(0.)
+                .max(1000.)
+                .step(10.)
+                .range(200., 800.)
+                .mark(0., "$0")
+                .mark(500., "$500")
+                .mark(1000., "$1000")
+        });
+        cx.subscribe(&slider3, |this, _, event: &SliderEvent, cx| {
+            if let SliderEvent::ChangeRange(start, end) = event {
+                this.slider3_value = (*start, *end);
+                cx.notify();
+            }
+        })
+        .detach();
+
         Self {
             focus_handle: cx.focus_handle(),
             value: 50.,
             slider1_value: 15.,
             slider2_value: 1.,
+            slider3_value: (200., 800.),
             slider1,
             slider2,
+            slider3,
         }
     }
 
@@ -163,6 +193,16 @@ impl Render for ProgressStory {
                     .child(self.slider2.clone())
                     .child(format!("Slider 2: {}", self.slider2_value)),
             )
+            .child(
+                v_flex()
+                    .gap_3()
+                    .w(px(300.))
+                    .child(self.slider3.clone())
+                    .child(format!(
+                        "Price range: {} - {}",
+                        self.slider3_value.0, self.slider3_value.1
+                    )),
+            )
             .child(
                 h_flex()
                     .mt_5()
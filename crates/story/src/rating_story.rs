@@ -0,0 +1,117 @@
+use gpui::{
+    IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext, VisualContext as _,
+    WindowContext,
+};
+use ui::{
+    h_flex,
+    rating::{Rating, RatingEvent},
+    v_flex, IconName, Sizable as _,
+};
+
+use crate::section;
+
+pub struct RatingStory {
+    focus_handle: gpui::FocusHandle,
+    rating: View<Rating>,
+    rating_value: f32,
+    half_rating: View<Rating>,
+    half_rating_value: f32,
+    heart_rating: View<Rating>,
+    readonly_rating: View<Rating>,
+}
+
+impl super::Story for RatingStory {
+    fn title() -> &'static str {
+        "Rating"
+    }
+
+    fn description() -> &'static str {
+        "A star rating input, click or use the arrow keys to change the value."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl RatingStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let rating = cx.new_view(|_| Rating::new().default_value(3.));
+        cx.subscribe(&rating, |this, _, event: &RatingEvent, cx| {
+            let RatingEvent::Change(value) = event;
+            this.rating_value = *value;
+            cx.notify();
+        })
+        .detach();
+
+        let half_rating = cx.new_view(|_| Rating::new().half(true).large().default_value(2.5));
+        cx.subscribe(&half_rating, |this, _, event: &RatingEvent, cx| {
+            let RatingEvent::Change(value) = event;
+            this.half_rating_value = *value;
+            cx.notify();
+        })
+        .detach();
+
+        let heart_rating = cx.new_view(|_| {
+            Rating::new()
+                .max(10)
+                .icon(IconName::Heart)
+                .color(ui::red_500())
+                .small()
+                .default_value(7.)
+        });
+        let readonly_rating = cx.new_view(|_| Rating::new().readonly(true).default_value(4.3));
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            rating,
+            rating_value: 3.,
+            half_rating,
+            half_rating_value: 2.5,
+            heart_rating,
+            readonly_rating,
+        }
+    }
+}
+
+impl gpui::FocusableView for RatingStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for RatingStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Rating", cx).child(
+                    h_flex()
+                        .gap_3()
+                        .child(self.rating.clone())
+                        .child(format!("{}", self.rating_value)),
+                ),
+            )
+            .child(
+                section("Half Star", cx).child(
+                    h_flex()
+                        .gap_3()
+                        .child(self.half_rating.clone())
+                        .child(format!("{}", self.half_rating_value)),
+                ),
+            )
+            .child(section("Custom Icon", cx).child(self.heart_rating.clone()))
+            .child(
+                section("Readonly", cx).child(
+                    h_flex()
+                        .gap_3()
+                        .child(self.readonly_rating.clone())
+                        .child("4.3 (128 reviews)"),
+                ),
+            )
+    }
+}
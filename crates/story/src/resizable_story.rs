@@ -4,7 +4,11 @@ use gpui::{
 };
 use ui::theme::ActiveTheme;
 use ui::{
-    resizable::{h_resizable, resizable_panel, v_resizable, ResizablePanelGroup},
+    button::Button,
+    h_flex,
+    resizable::{
+        h_resizable, resizable_panel, v_resizable, ResizablePanelEvent, ResizablePanelGroup,
+    },
     v_flex,
 };
 
@@ -12,6 +16,8 @@ pub struct ResizableStory {
     focus_handle: gpui::FocusHandle,
     group1: View<ResizablePanelGroup>,
     group2: View<ResizablePanelGroup>,
+    group3: View<ResizablePanelGroup>,
+    saved_ratios: Vec<f32>,
 }
 
 impl super::Story for ResizableStory {
@@ -39,7 +45,7 @@ impl ResizableStory {
         cx.new_view(|cx| Self::new(cx))
     }
 
-    fn new(cx: &mut WindowContext) -> Self {
+    fn new(cx: &mut ViewContext<Self>) -> Self {
         fn panel_box(content: impl Into<SharedString>, cx: &WindowContext) -> AnyElement {
             div()
                 .p_4()
@@ -94,7 +100,8 @@ impl ResizableStory {
                 .child(
                     resizable_panel()
                         .size(px(300.))
-                        .content(|cx| panel_box("Left 2", cx)),
+                        .fixed(true)
+                        .content(|cx| panel_box("Left 2 (Fixed)", cx)),
                     cx,
                 )
                 .child(
@@ -104,19 +111,81 @@ impl ResizableStory {
                     cx,
                 )
         });
+
+        let group3 = cx.new_view(|cx| {
+            h_resizable(cx)
+                .child(
+                    resizable_panel()
+                        .size(px(200.))
+                        .min_size(px(120.))
+                        .max_size(px(320.))
+                        .collapsible(true)
+                        .content(|cx| {
+                            panel_box("Sidebar (120px - 320px, double-click to collapse)", cx)
+                        }),
+                    cx,
+                )
+                .child(resizable_panel().content(|cx| panel_box("Content", cx)), cx)
+                .child(
+                    resizable_panel()
+                        .size(px(200.))
+                        .collapsible(true)
+                        .collapsed(true)
+                        .content(|cx| panel_box("Outline (collapsed)", cx)),
+                    cx,
+                )
+        });
+        cx.subscribe(&group3, |this, group, _: &ResizablePanelEvent, cx| {
+            this.saved_ratios = group.read(cx).ratios(cx);
+            cx.notify();
+        })
+        .detach();
+
         Self {
             focus_handle: cx.focus_handle(),
             group1,
             group2,
+            group3,
+            saved_ratios: vec![],
         }
     }
 }
 
 impl Render for ResizableStory {
-    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let ratios = self
+            .saved_ratios
+            .iter()
+            .map(|ratio| format!("{:.2}", ratio))
+            .collect::<Vec<_>>()
+            .join(", ");
+
         v_flex()
             .gap_6()
             .child(self.group1.clone())
             .child(self.group2.clone())
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Button::new("restore-ratios")
+                            .label("Restore Ratios")
+                            .on_click(cx.listener(|this, _, cx| {
+                                let ratios = this.saved_ratios.clone();
+                                this.group3
+                                    .update(cx, |group, cx| group.set_ratios(&ratios, cx));
+                            })),
+                    )
+                    .child(
+                        Button::new("reset-ratios")
+                            .label("Reset Ratios")
+                            .on_click(cx.listener(|this, _, cx| {
+                                this.group3
+                                    .update(cx, |group, cx| group.set_ratios(&[0.2, 0.6, 0.2], cx));
+                            })),
+                    )
+                    .child(format!("Saved ratios: [{}]", ratios)),
+            )
+            .child(div().h(px(200.)).child(self.group3.clone()))
     }
 }
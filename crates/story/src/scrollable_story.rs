@@ -7,7 +7,7 @@ use gpui::{
 };
 use ui::button::Button;
 use ui::divider::Divider;
-use ui::scroll::{Scrollbar, ScrollbarAxis, ScrollbarState};
+use ui::scroll::{Scrollbar, ScrollbarAxis, ScrollbarState, StickyHeader, StickyHeaderState};
 use ui::theme::ActiveTheme;
 use ui::{h_flex, v_flex, StyledExt as _};
 
@@ -16,6 +16,7 @@ pub struct ScrollableStory {
     scroll_handle: ScrollHandle,
     scroll_size: gpui::Size<Pixels>,
     scroll_state: Rc<Cell<ScrollbarState>>,
+    header_state: Rc<Cell<StickyHeaderState>>,
     items: Vec<String>,
     test_width: Pixels,
     axis: ScrollbarAxis,
@@ -27,6 +28,7 @@ impl ScrollableStory {
             focus_handle: cx.focus_handle(),
             scroll_handle: ScrollHandle::new(),
             scroll_state: Rc::new(Cell::new(ScrollbarState::default())),
+            header_state: Rc::new(Cell::new(StickyHeaderState::default())),
             scroll_size: gpui::Size::default(),
             items: (0..500).map(|i| format!("Item {}", i)).collect::<Vec<_>>(),
             test_width: px(3000.),
@@ -160,6 +162,7 @@ impl Render for ScrollableStory {
                                     .id("scroll-story")
                                     .overflow_scroll()
                                     .p_4()
+                                    .pt_12()
                                     .size_full()
                                     .track_scroll(&self.scroll_handle)
                                     .child(
@@ -184,6 +187,17 @@ impl Render for ScrollableStory {
                                             }),
                                     ),
                             )
+                            .child(
+                                StickyHeader::new(
+                                    "scroll-story-header",
+                                    &self.scroll_handle,
+                                    self.header_state.clone(),
+                                )
+                                .px_4()
+                                .flex()
+                                .items_center()
+                                .child("Scroll down to hide this toolbar, scroll up to show it."),
+                            )
                             .child(
                                 div()
                                     .absolute()
@@ -0,0 +1,145 @@
+use gpui::{
+    px, IntoElement, ParentElement, Render, Styled, View, ViewContext, VisualContext as _,
+    WindowContext,
+};
+use serde_json::json;
+use ui::{
+    label::Label,
+    settings::{SettingItem, SettingSection, SettingsEvent, SettingsView},
+    theme::ActiveTheme as _,
+    v_flex, IconName,
+};
+
+pub struct SettingsStory {
+    focus_handle: gpui::FocusHandle,
+    settings: View<SettingsView>,
+    saved: Option<String>,
+}
+
+impl super::Story for SettingsStory {
+    fn title() -> &'static str {
+        "Settings"
+    }
+
+    fn description() -> &'static str {
+        "A settings view with sections, search, dirty state and persistence."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl SettingsStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let settings = cx.new_view(|cx| {
+            let mut view = SettingsView::new(cx)
+                .section(
+                    SettingSection::new("Appearance")
+                        .icon(IconName::Palette)
+                        .item(
+                            SettingItem::select(
+                                "appearance.theme",
+                                "Theme",
+                                [("light", "Light"), ("dark", "Dark"), ("system", "System")],
+                            )
+                            .description("The color theme of the application.")
+                            .default_value("system"),
+                        )
+                        .item(
+                            SettingItem::slider("appearance.font_size", "Font Size", 10., 24., 1.)
+                                .default_value(14),
+                        )
+                        .item(
+                            SettingItem::toggle("appearance.compact", "Compact Mode")
+                                .description("Reduce the padding of the panels."),
+                        ),
+                    cx,
+                )
+                .section(
+                    SettingSection::new("Editor")
+                        .icon(IconName::File)
+                        .item(
+                            SettingItem::toggle("editor.word_wrap", "Word Wrap")
+                                .description("Wrap the lines that exceed the viewport width.")
+                                .default_value(true),
+                        )
+                        .item(SettingItem::toggle(
+                            "editor.format_on_save",
+                            "Format On Save",
+                        ))
+                        .item(
+                            SettingItem::slider("editor.tab_size", "Tab Size", 2., 8., 2.)
+                                .default_value(4),
+                        ),
+                    cx,
+                )
+                .section(
+                    SettingSection::new("Keymap")
+                        .icon(IconName::Menu)
+                        .item(
+                            SettingItem::keybinding("keymap.command_palette", "Command Palette")
+                                .default_value("cmd-shift-p"),
+                        )
+                        .item(SettingItem::keybinding("keymap.save", "Save").default_value("cmd-s"))
+                        .item(SettingItem::keybinding(
+                            "keymap.toggle_sidebar",
+                            "Toggle Sidebar",
+                        )),
+                    cx,
+                )
+                .on_save(|values, _| {
+                    println!("Save settings: {}", values);
+                    Ok(())
+                });
+            // Load the values from the storage.
+            view.set_values(&json!({ "appearance.theme": "dark" }), cx);
+            view
+        });
+
+        cx.subscribe(&settings, |this, _, event: &SettingsEvent, cx| {
+            if let SettingsEvent::Save(values) = event {
+                this.saved = Some(values.to_string());
+                cx.notify();
+            }
+        })
+        .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            settings,
+            saved: None,
+        }
+    }
+}
+
+impl gpui::FocusableView for SettingsStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for SettingsStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_4()
+            .child(
+                v_flex()
+                    .h(px(480.))
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded(px(cx.theme().radius))
+                    .overflow_hidden()
+                    .child(self.settings.clone()),
+            )
+            .children(
+                self.saved
+                    .clone()
+                    .map(|saved| Label::new(format!("Saved: {}", saved))),
+            )
+    }
+}
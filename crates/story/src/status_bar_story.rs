@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use gpui::{
+    impl_actions, px, InteractiveElement as _, IntoElement, ParentElement, Render, SharedString,
+    Styled, Task, View, ViewContext, VisualContext as _, WindowContext,
+};
+use serde::Deserialize;
+use ui::{
+    button::Button,
+    h_flex,
+    notification::Notification,
+    status_bar::{StatusBar, StatusItem, StatusSlot},
+    theme::ActiveTheme as _,
+    v_flex, ContextModal as _, IconName,
+};
+
+#[derive(Clone, PartialEq, Deserialize)]
+struct SelectLanguage(SharedString);
+
+impl_actions!(status_bar_story, [SelectLanguage]);
+
+const LANGUAGES: [&str; 3] = ["Rust", "TOML", "Markdown"];
+
+pub struct StatusBarStory {
+    focus_handle: gpui::FocusHandle,
+    status_bar: View<StatusBar>,
+    _progress_task: Option<Task<()>>,
+}
+
+impl super::Story for StatusBarStory {
+    fn title() -> &'static str {
+        "StatusBar"
+    }
+
+    fn description() -> &'static str {
+        "A status bar with the items, message and progress at the bottom of the window."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl StatusBarStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let status_bar = cx.new_view(|cx| {
+            StatusBar::new(cx)
+                .left(
+                    StatusItem::new("branch")
+                        .icon(IconName::GitHub)
+                        .label("main")
+                        .tooltip("Current branch"),
+                )
+                .left(
+                    StatusItem::new("problems")
+                        .icon(IconName::TriangleAlert)
+                        .label("0"),
+                )
+                .center(StatusItem::new("position").label("Ln 12, Col 8"))
+                .right(Self::language_item("Rust"))
+                .right(StatusItem::new("notifications").icon(IconName::Bell))
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            status_bar,
+            _progress_task: None,
+        }
+    }
+
+    fn language_item(current: &'static str) -> StatusItem {
+        StatusItem::new("language")
+            .label(current)
+            .popup_menu(move |menu, _| {
+                LANGUAGES.iter().fold(menu, |menu, language| {
+                    menu.menu_with_check(
+                        *language,
+                        *language == current,
+                        Box::new(SelectLanguage((*language).into())),
+                    )
+                })
+            })
+    }
+
+    fn on_select_language(&mut self, action: &SelectLanguage, cx: &mut ViewContext<Self>) {
+        let Some(language) = LANGUAGES.iter().find(|language| **language == action.0) else {
+            return;
+        };
+        self.status_bar.update(cx, |status_bar, cx| {
+            status_bar.set_items(
+                StatusSlot::Right,
+                [
+                    Self::language_item(language),
+                    StatusItem::new("notifications").icon(IconName::Bell),
+                ],
+                cx,
+            );
+        });
+    }
+
+    fn start_progress(&mut self, cx: &mut ViewContext<Self>) {
+        let status_bar = self.status_bar.clone();
+        self._progress_task = Some(cx.spawn(|_, mut cx| async move {
+            for value in (0..=100).step_by(5) {
+                _ = status_bar.update(&mut cx, |status_bar, cx| {
+                    status_bar.set_progress("Indexing...", value as f32, cx);
+                });
+                cx.background_executor()
+                    .timer(Duration::from_millis(100))
+                    .await;
+            }
+            _ = status_bar.update(&mut cx, |status_bar, cx| status_bar.clear_progress(cx));
+        }));
+    }
+}
+
+impl gpui::FocusableView for StatusBarStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for StatusBarStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_select_language))
+            .gap_6()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Button::new("set-message")
+                            .label("Set Message")
+                            .on_click(cx.listener(|this, _, cx| {
+                                this.status_bar.update(cx, |status_bar, cx| {
+                                    status_bar.set_message(Some("Saved 3 files.".into()), cx)
+                                });
+                            })),
+                    )
+                    .child(
+                        Button::new("start-progress")
+                            .label("Start Progress")
+                            .on_click(cx.listener(|this, _, cx| this.start_progress(cx))),
+                    )
+                    .child(
+                        Button::new("route-notifications")
+                            .label("Route Notifications")
+                            .on_click(cx.listener(|this, _, cx| {
+                                StatusBar::route_notifications(&this.status_bar, cx);
+                            })),
+                    )
+                    .child(
+                        Button::new("push-notification")
+                            .label("Push Notification")
+                            .on_click(cx.listener(|_, _, cx| {
+                                cx.push_notification(Notification::success(
+                                    "The file has been formatted.",
+                                ));
+                            })),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .h(px(200.))
+                    .justify_end()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .child(self.status_bar.clone()),
+            )
+    }
+}
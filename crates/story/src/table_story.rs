@@ -16,7 +16,7 @@ use ui::{
     label::Label,
     popup_menu::PopupMenuExt,
     prelude::FluentBuilder as _,
-    table::{ColFixed, ColSort, Table, TableDelegate, TableEvent},
+    table::{CellFormat, CellValue, ColSort, Table, TableDelegate, TableEvent},
     v_flex, Selectable, Size, StyleSized as _,
 };
 
@@ -310,18 +310,6 @@ impl TableDelegate for StockTableDelegate {
         }
     }
 
-    fn col_fixed(&self, col_ix: usize) -> Option<ui::table::ColFixed> {
-        if !self.fixed_cols {
-            return None;
-        }
-
-        if col_ix < 4 {
-            Some(ColFixed::Left)
-        } else {
-            None
-        }
-    }
-
     fn can_resize_col(&self, col_ix: usize) -> bool {
         return self.col_resize && col_ix > 1;
     }
@@ -404,6 +392,39 @@ impl TableDelegate for StockTableDelegate {
         }
     }
 
+    fn col_format(&self, col_ix: usize) -> Option<CellFormat> {
+        let col = self.columns.get(col_ix)?;
+
+        match col.id.as_ref() {
+            "open" | "prev_close" => Some(CellFormat::currency("$")),
+            "year_change_percent" | "pre_market_percent" | "post_market_percent" => {
+                Some(CellFormat::percent(2))
+            }
+            "turnover_rate" | "rise_rate" => Some(CellFormat::Progress),
+            "shares" | "shares_float" => Some(CellFormat::number(0)),
+            _ => None,
+        }
+    }
+
+    fn cell_value(&self, row_ix: usize, col_ix: usize) -> CellValue {
+        let (Some(stock), Some(col)) = (self.stocks.get(row_ix), self.columns.get(col_ix)) else {
+            return CellValue::Empty;
+        };
+
+        match col.id.as_ref() {
+            "open" => stock.open.into(),
+            "prev_close" => stock.prev_close.into(),
+            "year_change_percent" => stock.year_change_percent.into(),
+            "pre_market_percent" => stock.pre_market_percent.into(),
+            "post_market_percent" => stock.post_market_percent.into(),
+            "turnover_rate" => (stock.turnover_rate * 100.0).into(),
+            "rise_rate" => (stock.rise_rate * 100.0).into(),
+            "shares" => (stock.shares as f64).into(),
+            "shares_float" => (stock.shares_float as f64).into(),
+            _ => CellValue::Empty,
+        }
+    }
+
     fn can_loop_select(&self) -> bool {
         self.loop_selection
     }
@@ -475,6 +496,7 @@ pub struct TableStory {
     table: View<Table<StockTableDelegate>>,
     num_stocks_input: View<TextInput>,
     stripe: bool,
+    cell_selectable: bool,
     refresh_data: bool,
     size: Size,
 }
@@ -558,6 +580,7 @@ impl TableStory {
             table,
             num_stocks_input,
             stripe: false,
+            cell_selectable: false,
             refresh_data: false,
             size: Size::default(),
         }
@@ -629,11 +652,18 @@ impl TableStory {
         });
     }
 
+    fn toggle_cell_selectable(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
+        self.cell_selectable = *checked;
+        let cell_selectable = self.cell_selectable;
+        self.table.update(cx, |table, cx| {
+            table.set_cell_selectable(cell_selectable, cx);
+        });
+    }
+
     fn toggle_fixed_cols(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
         self.table.update(cx, |table, cx| {
             table.delegate_mut().fixed_cols = *checked;
-            table.refresh(cx);
-            cx.notify();
+            table.set_fixed_cols(if *checked { 4 } else { 0 }, cx);
         });
     }
 
@@ -662,6 +692,10 @@ impl TableStory {
             }
             TableEvent::SelectCol(ix) => println!("Select col: {}", ix),
             TableEvent::SelectRow(ix) => println!("Select row: {}", ix),
+            TableEvent::SelectCell(row_ix, col_ix) => {
+                println!("Select cell: {}, {}", row_ix, col_ix)
+            }
+            TableEvent::LayoutChanged(state) => println!("Layout changed: {:?}", state),
         }
     }
 }
@@ -713,49 +747,55 @@ impl Render for TableStory {
                         Checkbox::new("loop-selection")
                             .label("Loop Selection")
                             .selected(delegate.loop_selection)
-                            .on_click(cx.listener(Self::toggle_loop_selection)),
+                            .on_change(cx.listener(Self::toggle_loop_selection)),
                     )
                     .child(
                         Checkbox::new("col-resize")
                             .label("Column Resize")
                             .selected(delegate.col_resize)
-                            .on_click(cx.listener(Self::toggle_col_resize)),
+                            .on_change(cx.listener(Self::toggle_col_resize)),
                     )
                     .child(
                         Checkbox::new("col-order")
                             .label("Column Order")
                             .selected(delegate.col_order)
-                            .on_click(cx.listener(Self::toggle_col_order)),
+                            .on_change(cx.listener(Self::toggle_col_order)),
                     )
                     .child(
                         Checkbox::new("col-sort")
                             .label("Column Sort")
                             .selected(delegate.col_sort)
-                            .on_click(cx.listener(Self::toggle_col_sort)),
+                            .on_change(cx.listener(Self::toggle_col_sort)),
                     )
                     .child(
                         Checkbox::new("col-selection")
                             .label("Column Selection")
                             .selected(delegate.col_selection)
-                            .on_click(cx.listener(Self::toggle_col_selection)),
+                            .on_change(cx.listener(Self::toggle_col_selection)),
                     )
                     .child(
                         Checkbox::new("stripe")
                             .label("Stripe")
                             .selected(self.stripe)
-                            .on_click(cx.listener(Self::toggle_stripe)),
+                            .on_change(cx.listener(Self::toggle_stripe)),
+                    )
+                    .child(
+                        Checkbox::new("cell-selection")
+                            .label("Cell Selection")
+                            .selected(self.cell_selectable)
+                            .on_change(cx.listener(Self::toggle_cell_selectable)),
                     )
                     .child(
                         Checkbox::new("fixed-cols")
                             .label("Fixed Columns")
                             .selected(delegate.fixed_cols)
-                            .on_click(cx.listener(Self::toggle_fixed_cols)),
+                            .on_change(cx.listener(Self::toggle_fixed_cols)),
                     )
                     .child(
                         Checkbox::new("refresh-data")
                             .label("Refresh Data")
                             .selected(self.refresh_data)
-                            .on_click(cx.listener(Self::toggle_refresh_data)),
+                            .on_change(cx.listener(Self::toggle_refresh_data)),
                     ),
             )
             .child(
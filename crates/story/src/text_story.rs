@@ -8,9 +8,12 @@ use ui::{
     checkbox::Checkbox,
     clipboard::Clipboard,
     h_flex,
+    input::{InputEvent, TextArea},
     label::Label,
     link::Link,
+    markdown::MarkdownView,
     radio::Radio,
+    text::{RichText, TextSpan},
     v_flex, Disableable as _, IconName, StyledExt,
 };
 
@@ -24,8 +27,33 @@ pub struct TextStory {
     radio_check1: bool,
     radio_check2: bool,
     masked: bool,
+    markdown_input: View<TextArea>,
+    markdown: View<MarkdownView>,
 }
 
+const MARKDOWN_EXAMPLE: &str = r#"## Markdown
+
+This is a **markdown** view, edit the text on the left to see the changes.
+
+- [x] Headings, lists and [links](https://github.com/huacnlee/gpui-component)
+- [ ] Task list
+  1. Ordered list
+  2. Nested list
+
+> A block quote with *italic* text.
+
+```rust
+fn main() {
+    println!("Hello, world!");
+}
+```
+
+| Component | Status |
+|-----------|:------:|
+| Markdown  | Done   |
+| `code`    | Done   |
+"#;
+
 impl super::Story for TextStory {
     fn title() -> &'static str {
         "Text"
@@ -41,7 +69,21 @@ impl super::Story for TextStory {
 }
 
 impl TextStory {
-    pub(crate) fn new(cx: &mut WindowContext) -> Self {
+    pub(crate) fn new(cx: &mut ViewContext<Self>) -> Self {
+        let markdown = cx.new_view(|cx| MarkdownView::new(MARKDOWN_EXAMPLE, cx));
+        let markdown_input = cx.new_view(|cx| {
+            let mut input = TextArea::new(cx).rows(20).max_rows(20);
+            input.set_text(MARKDOWN_EXAMPLE, cx);
+            input
+        });
+        cx.subscribe(&markdown_input, |this, _, event: &InputEvent, cx| {
+            if let InputEvent::Change(text) = event {
+                this.markdown
+                    .update(cx, |markdown, cx| markdown.set_source(text.clone(), cx));
+            }
+        })
+        .detach();
+
         Self {
             focus_handle: cx.focus_handle(),
             check1: false,
@@ -50,6 +92,8 @@ impl TextStory {
             radio_check1: false,
             radio_check2: true,
             masked: false,
+            markdown_input,
+            markdown,
         }
     }
 
@@ -108,6 +152,36 @@ impl Render for TextStory {
                         .child(div().w(px(250.)).child(Link::new("link4").child("https://github.com/huacnlee/gpui-component").href("https://github.com/huacnlee/gpui-component")))
                 )
             )
+            .child(
+                section("Rich Text", cx).child(
+                    v_flex()
+                        .w(px(400.))
+                        .gap_3()
+                        .child(RichText::new("rich-text1").markdown(
+                            "This is **bold**, *italic*, ***both*** and `code span`, \
+                            visit [GitHub](https://github.com/huacnlee/gpui-component) for more details.",
+                        ))
+                        .child(
+                            RichText::new("rich-text2")
+                                .span("Build with ")
+                                .span(TextSpan::new("builder").bold())
+                                .span(", click ")
+                                .span(TextSpan::new("this link").link("story://rich-text"))
+                                .span(" to handle it by yourself.")
+                                .on_click(|url, _| println!("Clicked link: {}", url)),
+                        ),
+                ),
+            )
+            .child(
+                section("Markdown", cx).child(
+                    h_flex()
+                        .w_full()
+                        .items_start()
+                        .gap_4()
+                        .child(div().flex_1().child(self.markdown_input.clone()))
+                        .child(div().flex_1().child(self.markdown.clone())),
+                ),
+            )
             .child(
                 section("Maksed Label", cx).child(
                     v_flex()
@@ -141,7 +215,7 @@ impl Render for TextStory {
                         .child(
                             Checkbox::new("check1")
                                 .checked(self.check1)
-                                .on_click(cx.listener(|v, _, _| {
+                                .on_change(cx.listener(|v, _, _| {
                                     v.check1 = !v.check1;
                                 })),
                         )
@@ -149,7 +223,7 @@ impl Render for TextStory {
                             Checkbox::new("check2")
                                 .checked(self.check2)
                                 .label("Subscribe to newsletter")
-                                .on_click(cx.listener(|v, _, _| {
+                                .on_change(cx.listener(|v, _, _| {
                                     v.check2 = !v.check2;
                                 })),
                         )
@@ -157,10 +231,23 @@ impl Render for TextStory {
                             Checkbox::new("check3")
                                 .checked(self.check3)
                                 .label("Remember me")
-                                .on_click(cx.listener(|v, _, _| {
+                                .on_change(cx.listener(|v, _, _| {
                                     v.check3 = !v.check3;
                                 })),
                         )
+                        .child(
+                            Checkbox::new("check-all")
+                                .label("Select all")
+                                .checked(self.check1 && self.check2 && self.check3)
+                                .indeterminate(
+                                    !(self.check1 == self.check2 && self.check2 == self.check3),
+                                )
+                                .on_change(cx.listener(|v, checked: &bool, _| {
+                                    v.check1 = *checked;
+                                    v.check2 = *checked;
+                                    v.check3 = *checked;
+                                })),
+                        )
                         .child(
                             div().w(px(300.)).child(
                                 Checkbox::new("longlong-checkbox")
@@ -1,12 +1,13 @@
 use gpui::{
-    div, CursorStyle, InteractiveElement, ParentElement, Render, StatefulInteractiveElement,
-    Styled, View, ViewContext, VisualContext as _, WindowContext,
+    div, CursorStyle, InteractiveElement, Keystroke, ParentElement, Render,
+    StatefulInteractiveElement, Styled, View, ViewContext, VisualContext as _, WindowContext,
 };
 
 use ui::{
     button::{Button, ButtonStyle},
     checkbox::Checkbox,
     h_flex,
+    kbd::Kbd,
     label::Label,
     tooltip::Tooltip,
     v_flex,
@@ -77,5 +78,24 @@ impl Render for TooltipStory {
                     .id("tooltip-4")
                     .tooltip(|cx| Tooltip::new("Checked!", cx)),
             )
+            .child(
+                div()
+                    .cursor(CursorStyle::PointingHand)
+                    .child(Button::new("copy").label("Copy"))
+                    .id("tooltip-5")
+                    .tooltip(|cx| {
+                        Tooltip::for_action("Copy to clipboard", &crate::popup_story::Copy, cx)
+                    }),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(Kbd::new(Keystroke::parse("cmd-s").unwrap()))
+                    .child(Kbd::new(Keystroke::parse("ctrl-shift-p").unwrap()))
+                    .child(Kbd::from_keystrokes([
+                        Keystroke::parse("cmd-k").unwrap(),
+                        Keystroke::parse("cmd-s").unwrap(),
+                    ])),
+            )
     }
 }
@@ -0,0 +1,261 @@
+use std::{collections::HashSet, time::Duration};
+
+use anyhow::{anyhow, Result};
+use gpui::{
+    div, prelude::FluentBuilder as _, px, IntoElement, ParentElement, Render, SharedString, Styled,
+    Task, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    checkbox::Checkbox,
+    h_flex,
+    input::{InputEvent, TextInput},
+    label::Label,
+    theme::ActiveTheme as _,
+    tree::{DropPosition, TreeDelegate, TreeEvent, TreeNode, TreeView},
+    v_flex, IconName,
+};
+
+struct FileTreeDelegate {
+    /// The folders failed to load once, to show the retry.
+    failed: HashSet<SharedString>,
+    multi_select: bool,
+}
+
+fn folder(path: &str, children: impl IntoIterator<Item = TreeNode>) -> TreeNode {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    TreeNode::new(path.to_string(), name.to_string())
+        .icon(IconName::Folder)
+        .expanded_icon(IconName::FolderOpen)
+        .children(children)
+}
+
+fn file(path: &str) -> TreeNode {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    TreeNode::new(path.to_string(), name.to_string()).icon(IconName::File)
+}
+
+fn lazy_folder(path: &str) -> TreeNode {
+    folder(path, []).lazy()
+}
+
+impl TreeDelegate for FileTreeDelegate {
+    fn root_nodes(&self) -> Vec<TreeNode> {
+        vec![
+            folder(
+                "crates",
+                [
+                    folder(
+                        "crates/story",
+                        [
+                            folder(
+                                "crates/story/src",
+                                [
+                                    file("crates/story/src/lib.rs"),
+                                    file("crates/story/src/list_story.rs"),
+                                    file("crates/story/src/table_story.rs"),
+                                    file("crates/story/src/tree_story.rs"),
+                                ],
+                            ),
+                            file("crates/story/Cargo.toml"),
+                        ],
+                    ),
+                    folder(
+                        "crates/ui",
+                        [
+                            folder(
+                                "crates/ui/src",
+                                [
+                                    folder(
+                                        "crates/ui/src/list",
+                                        [
+                                            file("crates/ui/src/list/list.rs"),
+                                            file("crates/ui/src/list/list_item.rs"),
+                                            file("crates/ui/src/list/mod.rs"),
+                                        ],
+                                    ),
+                                    folder(
+                                        "crates/ui/src/tree",
+                                        [
+                                            file("crates/ui/src/tree/mod.rs"),
+                                            file("crates/ui/src/tree/node.rs"),
+                                            file("crates/ui/src/tree/tree.rs"),
+                                        ],
+                                    ),
+                                    file("crates/ui/src/lib.rs"),
+                                    file("crates/ui/src/table.rs"),
+                                ],
+                            ),
+                            file("crates/ui/Cargo.toml"),
+                        ],
+                    ),
+                ],
+            ),
+            folder("assets", [file("assets/icons/folder.svg")]),
+            lazy_folder("node_modules"),
+            lazy_folder("target"),
+            file("Cargo.toml"),
+            file("README.md"),
+        ]
+    }
+
+    fn multi_select(&self) -> bool {
+        self.multi_select
+    }
+
+    fn can_drop(&self, _: &TreeNode, dst: &TreeNode, position: DropPosition) -> bool {
+        // Only the folders (with the expanded icon) can contain the dropped nodes.
+        position != DropPosition::Inside || dst.expanded_icon.is_some()
+    }
+
+    fn load_children(
+        &mut self,
+        node: &TreeNode,
+        cx: &mut ViewContext<TreeView<Self>>,
+    ) -> Task<Result<Vec<TreeNode>>> {
+        let path = node.id.to_string();
+        // Simulate a failure of the first time to load the `build` folder.
+        let fail = path.ends_with("/build") && self.failed.insert(node.id.clone());
+        let timer = cx.background_executor().timer(Duration::from_secs(1));
+        cx.background_executor().spawn(async move {
+            timer.await;
+            if fail {
+                return Err(anyhow!("Permission denied"));
+            }
+
+            // A huge folder with 100,000 files.
+            if path == "node_modules" {
+                return Ok((0..2000)
+                    .map(|i| {
+                        let package = format!("{}/package-{}", path, i);
+                        let files = (0..50).map(|j| file(&format!("{}/index-{}.js", package, j)));
+                        folder(&package, files)
+                    })
+                    .collect());
+            }
+
+            Ok(vec![
+                lazy_folder(&format!("{}/debug", path)),
+                lazy_folder(&format!("{}/build", path)),
+                file(&format!("{}/.rustc_info.json", path)),
+            ])
+        })
+    }
+}
+
+pub struct TreeStory {
+    focus_handle: gpui::FocusHandle,
+    tree: View<TreeView<FileTreeDelegate>>,
+    filter_input: View<TextInput>,
+    selected: Option<SharedString>,
+    selected_count: usize,
+}
+
+impl super::Story for TreeStory {
+    fn title() -> &'static str {
+        "Tree"
+    }
+
+    fn description() -> &'static str {
+        "A tree view to show the hierarchical nodes."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl TreeStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let delegate = FileTreeDelegate {
+            failed: HashSet::new(),
+            multi_select: false,
+        };
+        let tree = cx.new_view(|cx| TreeView::new(delegate, cx).draggable());
+        cx.subscribe(&tree, |this, _, event: &TreeEvent, cx| match event {
+            TreeEvent::Select(id) => {
+                this.selected = Some(id.clone());
+                cx.notify();
+            }
+            TreeEvent::SelectionChanged(ids) => {
+                this.selected_count = ids.len();
+                cx.notify();
+            }
+            _ => {}
+        })
+        .detach();
+
+        let filter_input = cx.new_view(|cx| TextInput::new(cx).placeholder("Filter files..."));
+        cx.subscribe(&filter_input, |this, _, event: &InputEvent, cx| {
+            if let InputEvent::Change(text) = event {
+                this.tree
+                    .update(cx, |tree, cx| tree.set_filter(text.clone(), cx));
+            }
+        })
+        .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            tree,
+            filter_input,
+            selected: None,
+            selected_count: 0,
+        }
+    }
+
+    fn toggle_multi_select(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
+        self.tree.update(cx, |tree, cx| {
+            tree.delegate_mut().multi_select = *checked;
+            cx.notify();
+        });
+    }
+}
+
+impl gpui::FocusableView for TreeStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TreeStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let multi_select = self.tree.read(cx).delegate().multi_select;
+
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(div().w(px(320.)).child(self.filter_input.clone()))
+                    .child(
+                        Checkbox::new("multi-select")
+                            .label("Multi Select")
+                            .selected(multi_select)
+                            .on_change(cx.listener(Self::toggle_multi_select)),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .w(px(320.))
+                    .h(px(400.))
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded_md()
+                    .overflow_hidden()
+                    .child(self.tree.clone()),
+            )
+            .child(Label::new(format!(
+                "Selected: {}",
+                self.selected.clone().unwrap_or_default()
+            )))
+            .when(multi_select, |this| {
+                this.child(Label::new(format!(
+                    "{} nodes selected",
+                    self.selected_count
+                )))
+            })
+    }
+}
@@ -1,3 +1,17 @@
+use std::{rc::Rc, time::Duration};
+
+use gpui::{
+    point, px, Animation, AnimationElement, AnimationExt as _, AppContext, ElementId, Global,
+    IntoElement, Pixels, Point, Styled,
+};
+
+/// The duration for the small elements, e.g.: the notification, the drawer.
+pub const DURATION_FAST: Duration = Duration::from_millis(150);
+/// The duration for the medium elements, e.g.: the modal.
+pub const DURATION_NORMAL: Duration = Duration::from_millis(250);
+/// The duration for the large movements, e.g.: the carousel slide.
+pub const DURATION_SLOW: Duration = Duration::from_millis(300);
+
 /// A cubic bezier function like CSS `cubic-bezier`.
 ///
 /// Builder:
@@ -17,3 +31,213 @@ pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> impl Fn(f32) -> f32 {
         y
     }
 }
+
+/// The standard curve, for the elements moving within the screen.
+pub fn ease_standard() -> impl Fn(f32) -> f32 {
+    cubic_bezier(0.4, 0., 0.2, 1.)
+}
+
+/// The decelerate curve with a fast start, for the overlays entering the screen.
+pub fn ease_emphasized() -> impl Fn(f32) -> f32 {
+    cubic_bezier(0.32, 0.72, 0., 1.)
+}
+
+/// The accelerate curve, for the elements leaving the screen.
+pub fn ease_in() -> impl Fn(f32) -> f32 {
+    |t: f32| t * t
+}
+
+/// The decelerate curve, for the elements entering the screen.
+pub fn ease_out() -> impl Fn(f32) -> f32 {
+    |t: f32| 1. - (1. - t) * (1. - t)
+}
+
+#[derive(Default)]
+struct ReduceMotion(bool);
+
+impl Global for ReduceMotion {}
+
+/// Turn on to skip the animations of the built-in components, they are shown at the final state immediately.
+pub fn set_reduce_motion(reduce: bool, cx: &mut AppContext) {
+    cx.set_global(ReduceMotion(reduce));
+    cx.refresh();
+}
+
+/// Return true if the reduce motion is on, default: false
+pub fn reduce_motion(cx: &AppContext) -> bool {
+    cx.try_global::<ReduceMotion>()
+        .map_or(false, |reduce| reduce.0)
+}
+
+/// Create an [`Animation`] with the `easing`, that ends immediately when the reduce motion is on.
+///
+/// Use this instead of `Animation::new` for the animations of the components.
+pub fn motion(
+    duration: Duration,
+    easing: impl Fn(f32) -> f32 + 'static,
+    cx: &AppContext,
+) -> Animation {
+    if reduce_motion(cx) {
+        Animation::new(duration).with_easing(|_| 1.)
+    } else {
+        Animation::new(duration).with_easing(easing)
+    }
+}
+
+/// The property to animate by the [`Transition`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransitionProperty {
+    /// Fade from transparent to opaque.
+    Opacity,
+    /// Move from the offset to the origin, by the `left` and `top` insets.
+    Translate(Point<Pixels>),
+    /// Expand the height from 0 to the height, the overflow is hidden.
+    Height(Pixels),
+}
+
+/// Create a [`Transition`] of the `property`, more properties can be added by [`Transition::and`].
+///
+/// ```ignore
+/// div()
+///     .child("Saved")
+///     .with_transition(
+///         "toast",
+///         transition(TransitionProperty::Opacity, DURATION_FAST)
+///             .and(TransitionProperty::Translate(point(px(0.), px(-20.)))),
+///         cx,
+///     )
+/// ```
+pub fn transition(property: TransitionProperty, duration: Duration) -> Transition {
+    Transition::new(property, duration)
+}
+
+/// The transition of the element entering, or leaving by [`Transition::exit`].
+#[derive(Clone)]
+pub struct Transition {
+    properties: Vec<TransitionProperty>,
+    duration: Duration,
+    easing: Rc<dyn Fn(f32) -> f32>,
+    exit: bool,
+}
+
+impl Transition {
+    pub fn new(property: TransitionProperty, duration: Duration) -> Self {
+        Self {
+            properties: vec![property],
+            duration,
+            easing: Rc::new(ease_standard()),
+            exit: false,
+        }
+    }
+
+    /// Animate one more property at the same time.
+    pub fn and(mut self, property: TransitionProperty) -> Self {
+        self.properties.push(property);
+        self
+    }
+
+    /// Set the easing function, default: [`ease_standard`]
+    pub fn easing(mut self, easing: impl Fn(f32) -> f32 + 'static) -> Self {
+        self.easing = Rc::new(easing);
+        self
+    }
+
+    /// Play the transition in reverse, from the origin to the properties, for the element leaving.
+    pub fn exit(mut self) -> Self {
+        self.exit = true;
+        self
+    }
+
+    /// The progress of the properties, 0.0 at the start values and 1.0 at the origin.
+    fn progress(&self, delta: f32) -> f32 {
+        if self.exit {
+            1. - delta
+        } else {
+            delta
+        }
+    }
+
+    fn apply<E: Styled>(&self, mut el: E, delta: f32) -> E {
+        let progress = self.progress(delta);
+        for property in self.properties.iter() {
+            el = match *property {
+                TransitionProperty::Opacity => el.opacity(progress),
+                TransitionProperty::Translate(offset) => el
+                    .left(offset.x * (1. - progress))
+                    .top(offset.y * (1. - progress)),
+                TransitionProperty::Height(height) => el.h(height * progress).overflow_hidden(),
+            };
+        }
+        el
+    }
+}
+
+/// The enter transition of the overlays, fade in and slide down from the `offset` above.
+pub fn overlay_enter(offset: Pixels, duration: Duration) -> Transition {
+    transition(TransitionProperty::Opacity, duration)
+        .and(TransitionProperty::Translate(point(px(0.), -offset)))
+        .easing(ease_emphasized())
+}
+
+/// The exit transition of the overlays, fade out and slide to the right by the `offset`.
+pub fn overlay_exit(offset: Pixels, duration: Duration) -> Transition {
+    transition(TransitionProperty::Opacity, duration)
+        .and(TransitionProperty::Translate(point(offset, px(0.))))
+        .easing(ease_in())
+        .exit()
+}
+
+/// Extends the element to play a [`Transition`].
+pub trait TransitionExt: Styled + IntoElement + 'static {
+    /// Play the `transition` when the element is first rendered, change the `id` to play again.
+    fn with_transition(
+        self,
+        id: impl Into<ElementId>,
+        transition: Transition,
+        cx: &AppContext,
+    ) -> AnimationElement<Self> {
+        let easing = transition.easing.clone();
+        let animation = motion(transition.duration, move |delta| easing(delta), cx);
+
+        self.with_animation(id, animation, move |this, delta| {
+            transition.apply(this, delta)
+        })
+    }
+}
+
+impl<E: Styled + IntoElement + 'static> TransitionExt for E {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        ease_emphasized, ease_in, ease_out, ease_standard, transition, TransitionProperty,
+    };
+
+    #[test]
+    fn test_easing_presets() {
+        for easing in [
+            Box::new(ease_standard()) as Box<dyn Fn(f32) -> f32>,
+            Box::new(ease_emphasized()),
+            Box::new(ease_in()),
+            Box::new(ease_out()),
+        ] {
+            assert_eq!(easing(0.), 0.);
+            assert_eq!(easing(1.), 1.);
+        }
+
+        assert!(ease_out()(0.5) > ease_in()(0.5));
+    }
+
+    #[test]
+    fn test_transition_progress() {
+        let enter = transition(TransitionProperty::Opacity, Duration::from_millis(100));
+        assert_eq!(enter.progress(0.), 0.);
+        assert_eq!(enter.progress(1.), 1.);
+
+        let exit = enter.exit();
+        assert_eq!(exit.progress(0.), 1.);
+        assert_eq!(exit.progress(1.), 0.);
+    }
+}
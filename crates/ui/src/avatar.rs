@@ -0,0 +1,295 @@
+use gpui::{
+    div, img, prelude::FluentBuilder as _, px, relative, AnyElement, Div, Hsla, ImageSource,
+    IntoElement, ParentElement as _, Pixels, RenderOnce, SharedString, Styled, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, Sizable, Size};
+
+/// The online status of the [`Avatar`], displayed as a dot on the bottom right corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarStatus {
+    Online,
+    Away,
+    Busy,
+    Offline,
+}
+
+impl AvatarStatus {
+    fn color(&self) -> Hsla {
+        match self {
+            Self::Online => crate::green_500(),
+            Self::Away => crate::yellow_500(),
+            Self::Busy => crate::red_500(),
+            Self::Offline => crate::gray_400(),
+        }
+    }
+}
+
+/// An Avatar to display a user image, fallback to the colored initials of the name.
+#[derive(IntoElement)]
+pub struct Avatar {
+    base: Div,
+    src: Option<ImageSource>,
+    name: Option<SharedString>,
+    status: Option<AvatarStatus>,
+    size: Size,
+}
+
+impl Avatar {
+    pub fn new() -> Self {
+        Self {
+            base: div(),
+            src: None,
+            name: None,
+            status: None,
+            size: Size::Medium,
+        }
+    }
+
+    /// Set the image source, a URL or an asset path.
+    pub fn src(mut self, src: impl Into<ImageSource>) -> Self {
+        self.src = Some(src.into());
+        self
+    }
+
+    /// Set the name of the user, used to display the initials if the image is missing or failed to load.
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the online status to display a status dot.
+    pub fn status(mut self, status: AvatarStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    fn avatar_size(&self) -> Pixels {
+        avatar_size(self.size)
+    }
+}
+
+impl Default for Avatar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn avatar_size(size: Size) -> Pixels {
+    match size {
+        Size::XSmall => px(20.),
+        Size::Small => px(24.),
+        Size::Medium => px(32.),
+        Size::Large => px(48.),
+        Size::Size(size) => size,
+    }
+}
+
+/// Returns the initials of the name, at most 2 characters, e.g.: `Jason Lee` -> `JL`.
+fn initials(name: &str) -> String {
+    let words = name.split_whitespace().collect::<Vec<_>>();
+    let chars: Vec<char> = match words.as_slice() {
+        [] => vec![],
+        [word] => word.chars().take(2).collect(),
+        [first, .., last] => first.chars().take(1).chain(last.chars().take(1)).collect(),
+    };
+
+    chars.into_iter().flat_map(|c| c.to_uppercase()).collect()
+}
+
+/// Pick a stable background color by the name.
+fn initials_color(name: &str) -> Hsla {
+    let colors = [
+        crate::red_500(),
+        crate::orange_500(),
+        crate::amber_500(),
+        crate::green_500(),
+        crate::teal_500(),
+        crate::sky_500(),
+        crate::blue_500(),
+        crate::indigo_500(),
+        crate::violet_500(),
+        crate::pink_500(),
+    ];
+
+    let hash = name.chars().fold(0usize, |hash, c| {
+        hash.wrapping_mul(31).wrapping_add(c as usize)
+    });
+    colors[hash % colors.len()]
+}
+
+impl Sizable for Avatar {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for Avatar {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Avatar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let size = self.avatar_size();
+        let name = self.name.clone().unwrap_or_default();
+        let status_size = (size * 0.3).max(px(6.));
+
+        self.base
+            .relative()
+            .flex_none()
+            .size(size)
+            .child(
+                // The initials is always rendered under the image, so it is visible when the image failed to load.
+                h_flex()
+                    .absolute()
+                    .size_full()
+                    .justify_center()
+                    .rounded_full()
+                    .overflow_hidden()
+                    .line_height(relative(1.))
+                    .text_size(size * 0.4)
+                    .map(|this| {
+                        if name.is_empty() {
+                            this.bg(cx.theme().muted)
+                        } else {
+                            this.bg(initials_color(&name))
+                                .text_color(crate::white())
+                                .child(initials(&name))
+                        }
+                    }),
+            )
+            .when_some(self.src, |this, src| {
+                this.child(img(src).absolute().size_full().rounded_full())
+            })
+            .when_some(self.status, |this, status| {
+                this.child(
+                    div()
+                        .absolute()
+                        .right_0()
+                        .bottom_0()
+                        .size(status_size)
+                        .rounded_full()
+                        .border_2()
+                        .border_color(cx.theme().background)
+                        .bg(status.color()),
+                )
+            })
+    }
+}
+
+/// A group of [`Avatar`]s that overlapped, with a `+N` chip for the overflowed avatars.
+#[derive(IntoElement)]
+pub struct AvatarGroup {
+    base: Div,
+    avatars: Vec<Avatar>,
+    max: Option<usize>,
+    size: Size,
+}
+
+impl AvatarGroup {
+    pub fn new() -> Self {
+        Self {
+            base: div(),
+            avatars: Vec::new(),
+            max: None,
+            size: Size::Medium,
+        }
+    }
+
+    /// Add an avatar to the group.
+    pub fn child(mut self, avatar: Avatar) -> Self {
+        self.avatars.push(avatar);
+        self
+    }
+
+    /// Add avatars to the group.
+    pub fn children(mut self, avatars: impl IntoIterator<Item = Avatar>) -> Self {
+        self.avatars.extend(avatars);
+        self
+    }
+
+    /// Set the max number of the avatars to display, the rest will be displayed as `+N`.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl Default for AvatarGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sizable for AvatarGroup {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for AvatarGroup {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for AvatarGroup {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let size = avatar_size(self.size);
+        let overlap = size * 0.25;
+        let max = self.max.unwrap_or(self.avatars.len());
+        let overflow = self.avatars.len().saturating_sub(max);
+
+        let mut items: Vec<AnyElement> = self
+            .avatars
+            .into_iter()
+            .take(max)
+            .map(|avatar| avatar.with_size(self.size).into_any_element())
+            .collect();
+
+        if overflow > 0 {
+            items.push(
+                h_flex()
+                    .size(size)
+                    .flex_none()
+                    .justify_center()
+                    .rounded_full()
+                    .bg(cx.theme().muted)
+                    .text_color(cx.theme().muted_foreground)
+                    .text_size(size * 0.35)
+                    .child(format!("+{}", overflow))
+                    .into_any_element(),
+            );
+        }
+
+        self.base
+            .flex()
+            .items_center()
+            .children(items.into_iter().enumerate().map(|(ix, item)| {
+                div()
+                    .rounded_full()
+                    .border_2()
+                    .border_color(cx.theme().background)
+                    .when(ix > 0, |this| this.ml(-overlap))
+                    .child(item)
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::initials;
+
+    #[test]
+    fn test_initials() {
+        assert_eq!(initials(""), "");
+        assert_eq!(initials("jason"), "JA");
+        assert_eq!(initials("Jason Lee"), "JL");
+        assert_eq!(initials("  Jason  Huacnlee Lee "), "JL");
+        assert_eq!(initials("李华"), "李华");
+    }
+}
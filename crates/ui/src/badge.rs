@@ -0,0 +1,190 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, AnyElement, Div, Hsla, IntoElement,
+    ParentElement, RenderOnce, SharedString, Styled, WindowContext,
+};
+use smallvec::SmallVec;
+
+use crate::{h_flex, theme::ActiveTheme as _, Sizable, Size};
+
+/// A Badge to display a count, a short label or a status dot.
+///
+/// If the Badge has children, the badge will be attached to the top right corner of the children,
+/// e.g.: on a dock tab title or a button icon.
+///
+/// ```ignore
+/// Badge::new().count(3).child(Icon::new(IconName::Inbox))
+/// ```
+#[derive(IntoElement)]
+pub struct Badge {
+    base: Div,
+    count: Option<usize>,
+    max: usize,
+    label: Option<SharedString>,
+    dot: bool,
+    color: Option<Hsla>,
+    size: Size,
+    children: SmallVec<[AnyElement; 1]>,
+}
+
+impl Badge {
+    pub fn new() -> Self {
+        Self {
+            base: div(),
+            count: None,
+            max: 99,
+            label: None,
+            dot: false,
+            color: None,
+            size: Size::default(),
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Set the count of the Badge, the Badge will be hidden if the count is 0.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Set the max count to display, the count greater than it will be displayed as `{max}+`, default: 99
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set a short label to display, e.g.: "New".
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Display as a status dot, without the count or label.
+    pub fn dot(mut self) -> Self {
+        self.dot = true;
+        self
+    }
+
+    /// Set the background color of the Badge, default is the destructive color.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Returns the text to display, None if the Badge should be hidden or is a dot.
+    fn text(&self) -> Option<SharedString> {
+        if let Some(label) = self.label.clone() {
+            return Some(label);
+        }
+
+        match self.count {
+            Some(0) => None,
+            Some(count) if count > self.max => Some(format!("{}+", self.max).into()),
+            Some(count) => Some(count.to_string().into()),
+            None => None,
+        }
+    }
+
+    fn is_visible(&self) -> bool {
+        self.dot || self.label.is_some() || self.count.map_or(false, |count| count > 0)
+    }
+
+    fn render_badge(&self, cx: &WindowContext) -> Div {
+        let color = self.color.unwrap_or(cx.theme().destructive);
+
+        let (height, dot_size) = match self.size {
+            Size::XSmall | Size::Small => (px(14.), px(6.)),
+            Size::Large => (px(20.), px(10.)),
+            _ => (px(16.), px(8.)),
+        };
+
+        if self.dot {
+            return div().size(dot_size).rounded_full().bg(color);
+        }
+
+        h_flex()
+            .h(height)
+            .min_w(height)
+            .px(px(4.))
+            .justify_center()
+            .rounded_full()
+            .bg(color)
+            .text_color(cx.theme().destructive_foreground)
+            .text_size(height * 0.7)
+            .line_height(relative(1.))
+            .whitespace_nowrap()
+            .children(self.text())
+    }
+}
+
+impl Default for Badge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sizable for Badge {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for Badge {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for Badge {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for Badge {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let visible = self.is_visible();
+        let badge = visible.then(|| self.render_badge(cx));
+
+        if self.children.is_empty() {
+            return self.base.flex_none().children(badge);
+        }
+
+        let is_dot = self.dot;
+        self.base
+            .relative()
+            .flex_none()
+            .children(self.children)
+            .when_some(badge, |this, badge| {
+                // Move the badge to the corner, the dot is closer than the count.
+                let offset = if is_dot { px(-2.) } else { px(-6.) };
+                this.child(
+                    badge
+                        .absolute()
+                        .top(offset)
+                        .right(offset)
+                        .border_1()
+                        .border_color(cx.theme().background),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Badge;
+
+    #[test]
+    fn test_badge_text() {
+        assert_eq!(Badge::new().count(0).text(), None);
+        assert_eq!(Badge::new().count(5).text(), Some("5".into()));
+        assert_eq!(Badge::new().count(100).text(), Some("99+".into()));
+        assert_eq!(Badge::new().count(10).max(9).text(), Some("9+".into()));
+        assert_eq!(
+            Badge::new().count(10).label("New").text(),
+            Some("New".into())
+        );
+        assert!(!Badge::new().count(0).is_visible());
+        assert!(Badge::new().dot().is_visible());
+    }
+}
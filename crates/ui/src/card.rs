@@ -0,0 +1,185 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, ClickEvent, Div, ElementId,
+    InteractiveElement as _, IntoElement, ParentElement, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled, WindowContext,
+};
+use smallvec::SmallVec;
+
+use crate::{h_flex, theme::ActiveTheme as _, v_flex, Selectable, StyledExt as _};
+
+/// A Card is a bordered surface with the optional header, content and footer slots.
+///
+/// ```ignore
+/// Card::new("card")
+///     .title("Notifications")
+///     .description("You have 3 unread messages.")
+///     .child(content)
+///     .footer(Button::new("mark").label("Mark all as read"))
+/// ```
+#[derive(IntoElement)]
+pub struct Card {
+    id: ElementId,
+    base: Div,
+    title: Option<SharedString>,
+    description: Option<SharedString>,
+    header: Option<AnyElement>,
+    footer: Option<AnyElement>,
+    hoverable: bool,
+    selected: bool,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl Card {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            title: None,
+            description: None,
+            header: None,
+            footer: None,
+            hoverable: false,
+            selected: false,
+            on_click: None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Set the title in the header.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the description under the title in the header.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set a custom header element, it will be displayed after the title and description.
+    pub fn header(mut self, header: impl IntoElement) -> Self {
+        self.header = Some(header.into_any_element());
+        self
+    }
+
+    /// Set the content of the Card, same as `child`.
+    pub fn content(self, content: impl IntoElement) -> Self {
+        self.child(content)
+    }
+
+    /// Set the footer element, it will be displayed at the bottom with a top border.
+    pub fn footer(mut self, footer: impl IntoElement) -> Self {
+        self.footer = Some(footer.into_any_element());
+        self
+    }
+
+    /// Set true to highlight the Card when the mouse is hovered.
+    pub fn hoverable(mut self, hoverable: bool) -> Self {
+        self.hoverable = hoverable;
+        self
+    }
+
+    /// Set the click handler, the Card will be hoverable if this is set.
+    pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Selectable for Card {
+    fn element_id(&self) -> &ElementId {
+        &self.id
+    }
+
+    fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+}
+
+impl Styled for Card {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for Card {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for Card {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme();
+        let hoverable = self.hoverable || self.on_click.is_some();
+        let has_header =
+            self.title.is_some() || self.description.is_some() || self.header.is_some();
+        let has_content = !self.children.is_empty();
+
+        self.base
+            .id(self.id)
+            .flex()
+            .flex_col()
+            .rounded(px(theme.radius * 2.))
+            .border_1()
+            .border_color(theme.border)
+            .bg(theme.card)
+            .text_color(theme.card_foreground)
+            .overflow_hidden()
+            .when(theme.shadow, |this| this.shadow_sm())
+            .when(hoverable && !self.selected, |this| {
+                this.hover(|this| this.border_color(theme.muted_foreground))
+                    .when(theme.shadow, |this| this.hover(|this| this.shadow_md()))
+            })
+            .when(self.selected, |this| this.border_color(theme.ring))
+            .when_some(self.on_click, |this, on_click| {
+                this.cursor_pointer()
+                    .on_click(move |event, cx| on_click(event, cx))
+            })
+            .when(has_header, |this| {
+                this.child(
+                    v_flex()
+                        .gap_1()
+                        .p_4()
+                        .when_some(self.title, |this, title| {
+                            this.child(div().text_lg().font_semibold().child(title))
+                        })
+                        .when_some(self.description, |this, description| {
+                            this.child(
+                                div()
+                                    .text_sm()
+                                    .text_color(theme.muted_foreground)
+                                    .child(description),
+                            )
+                        })
+                        .children(self.header),
+                )
+            })
+            .when(has_content, |this| {
+                this.child(
+                    v_flex()
+                        .flex_1()
+                        .px_4()
+                        .pb_4()
+                        .when(!has_header, |this| this.pt_4())
+                        .children(self.children),
+                )
+            })
+            .when_some(self.footer, |this, footer| {
+                this.child(
+                    h_flex()
+                        .gap_2()
+                        .px_4()
+                        .py_3()
+                        .border_t_1()
+                        .border_color(theme.border)
+                        .child(footer),
+                )
+            })
+    }
+}
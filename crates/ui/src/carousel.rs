@@ -0,0 +1,403 @@
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, relative, AnimationExt as _, AnyElement,
+    AppContext, ElementId, EventEmitter, FocusHandle, FocusableView, InteractiveElement as _,
+    IntoElement, KeyBinding, MouseButton, ParentElement as _, Render,
+    StatefulInteractiveElement as _, Styled as _, Task, ViewContext, WindowContext,
+};
+use smol::Timer;
+
+use crate::{
+    animation::{ease_standard, motion, DURATION_SLOW},
+    button::{Button, ButtonRounded, ButtonStyled as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    Disableable as _, IconName, Sizable as _,
+};
+
+actions!(carousel, [Prev, Next, First, Last]);
+
+const CONTEXT: &str = "Carousel";
+const SLIDE_DURATION: Duration = DURATION_SLOW;
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", Prev, Some(CONTEXT)),
+        KeyBinding::new("right", Next, Some(CONTEXT)),
+        KeyBinding::new("home", First, Some(CONTEXT)),
+        KeyBinding::new("end", Last, Some(CONTEXT)),
+    ]);
+}
+
+type ItemBuilder = Rc<dyn Fn(&mut WindowContext) -> AnyElement>;
+
+pub enum CarouselEvent {
+    /// Emitted when the selected index is changed.
+    Change(usize),
+}
+
+/// The slide animation from the previous item to the selected item.
+#[derive(Clone, Copy)]
+struct Transition {
+    from: usize,
+    forward: bool,
+    started_at: Instant,
+}
+
+/// The index moved by `delta` from `ix`, wrapped around if `looping`, otherwise clamped.
+fn step_index(ix: usize, count: usize, delta: isize, looping: bool) -> usize {
+    if count == 0 {
+        return 0;
+    }
+
+    let ix = ix as isize + delta;
+    if looping {
+        ix.rem_euclid(count as isize) as usize
+    } else {
+        ix.clamp(0, count as isize - 1) as usize
+    }
+}
+
+/// A carousel to show the items one at a time, with the slide animation.
+///
+/// - Use the prev and next buttons, the dot indicators, or the Left, Right, Home and End keys to navigate.
+/// - The autoplay is paused when the mouse is hovered.
+///
+/// ```ignore
+/// let carousel = cx.new_view(|cx| {
+///     let mut carousel = Carousel::new(cx)
+///         .item(|_| div().child("Slide 1"))
+///         .item(|_| div().child("Slide 2"));
+///     carousel.set_autoplay(Some(Duration::from_secs(5)), cx);
+///     carousel
+/// });
+/// ```
+pub struct Carousel {
+    focus_handle: FocusHandle,
+    items: Vec<ItemBuilder>,
+    selected_ix: usize,
+    looping: bool,
+    arrows: bool,
+    indicators: bool,
+    transition: Option<Transition>,
+    /// The count of the transitions, to restart the animation.
+    transition_count: usize,
+    autoplay: Option<Duration>,
+    hovered: bool,
+    /// The time of the last change, to restart the autoplay interval.
+    changed_at: Instant,
+    _autoplay_task: Option<Task<()>>,
+}
+
+impl Carousel {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            items: vec![],
+            selected_ix: 0,
+            looping: true,
+            arrows: true,
+            indicators: true,
+            transition: None,
+            transition_count: 0,
+            autoplay: None,
+            hovered: false,
+            changed_at: Instant::now(),
+            _autoplay_task: None,
+        }
+    }
+
+    /// Add an item, the `builder` is called to render the item when it's visible.
+    pub fn item<E: IntoElement>(
+        mut self,
+        builder: impl Fn(&mut WindowContext) -> E + 'static,
+    ) -> Self {
+        self.items
+            .push(Rc::new(move |cx| builder(cx).into_any_element()));
+        self
+    }
+
+    /// Wrap around from the last item to the first item, default is true.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Show the prev and next buttons, default is true.
+    pub fn arrows(mut self, arrows: bool) -> Self {
+        self.arrows = arrows;
+        self
+    }
+
+    /// Show the dot indicators at the bottom, default is true.
+    pub fn indicators(mut self, indicators: bool) -> Self {
+        self.indicators = indicators;
+        self
+    }
+
+    pub fn items_count(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected_ix
+    }
+
+    /// Select the item at the `ix`, the slide direction follows the index.
+    pub fn set_selected_index(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let forward = ix > self.selected_ix;
+        self.select(ix, forward, cx);
+    }
+
+    pub fn next(&mut self, cx: &mut ViewContext<Self>) {
+        let ix = step_index(self.selected_ix, self.items.len(), 1, self.looping);
+        self.select(ix, true, cx);
+    }
+
+    pub fn prev(&mut self, cx: &mut ViewContext<Self>) {
+        let ix = step_index(self.selected_ix, self.items.len(), -1, self.looping);
+        self.select(ix, false, cx);
+    }
+
+    /// Set the interval to go to the next item automatically, `None` to stop.
+    pub fn set_autoplay(&mut self, interval: Option<Duration>, cx: &mut ViewContext<Self>) {
+        self.autoplay = interval;
+        self.changed_at = Instant::now();
+        self._autoplay_task = interval.map(|interval| {
+            cx.spawn(|this, mut cx| async move {
+                let mut wait = interval;
+                loop {
+                    Timer::after(wait).await;
+                    let Ok(next_wait) = this.update(&mut cx, |this, cx| {
+                        let elapsed = this.changed_at.elapsed();
+                        if this.hovered {
+                            interval
+                        } else if elapsed >= interval {
+                            this.next(cx);
+                            interval
+                        } else {
+                            interval - elapsed
+                        }
+                    }) else {
+                        break;
+                    };
+                    wait = next_wait;
+                }
+            })
+        });
+    }
+
+    pub fn is_autoplay(&self) -> bool {
+        self.autoplay.is_some()
+    }
+
+    fn select(&mut self, ix: usize, forward: bool, cx: &mut ViewContext<Self>) {
+        if ix == self.selected_ix || ix >= self.items.len() {
+            return;
+        }
+
+        self.transition = Some(Transition {
+            from: self.selected_ix,
+            forward,
+            started_at: Instant::now(),
+        });
+        self.transition_count += 1;
+        self.selected_ix = ix;
+        self.changed_at = Instant::now();
+        cx.emit(CarouselEvent::Change(ix));
+        cx.notify();
+    }
+
+    fn on_action_prev(&mut self, _: &Prev, cx: &mut ViewContext<Self>) {
+        self.prev(cx);
+    }
+
+    fn on_action_next(&mut self, _: &Next, cx: &mut ViewContext<Self>) {
+        self.next(cx);
+    }
+
+    fn on_action_first(&mut self, _: &First, cx: &mut ViewContext<Self>) {
+        self.set_selected_index(0, cx);
+    }
+
+    fn on_action_last(&mut self, _: &Last, cx: &mut ViewContext<Self>) {
+        self.set_selected_index(self.items.len().saturating_sub(1), cx);
+    }
+
+    fn on_hover(&mut self, hovered: &bool, cx: &mut ViewContext<Self>) {
+        self.hovered = *hovered;
+        // Restart the interval, to avoid going to the next item immediately after leaving.
+        self.changed_at = Instant::now();
+        cx.notify();
+    }
+
+    fn render_slide(&self, ix: usize, cx: &mut WindowContext) -> impl IntoElement {
+        div()
+            .size_full()
+            .flex_shrink_0()
+            .overflow_hidden()
+            .children(self.items.get(ix).map(|builder| builder(cx)))
+    }
+
+    fn render_slides(&self, cx: &mut ViewContext<Self>) -> AnyElement {
+        let transition = self
+            .transition
+            .filter(|transition| transition.started_at.elapsed() < SLIDE_DURATION);
+        let Some(transition) = transition else {
+            return self.render_slide(self.selected_ix, cx).into_any_element();
+        };
+
+        // Place the two items side by side, and slide the row to the selected item.
+        let forward = transition.forward;
+        let (left, right) = if forward {
+            (transition.from, self.selected_ix)
+        } else {
+            (self.selected_ix, transition.from)
+        };
+
+        h_flex()
+            .relative()
+            .size_full()
+            .child(self.render_slide(left, cx))
+            .child(self.render_slide(right, cx))
+            .with_animation(
+                ElementId::NamedInteger("carousel-slide".into(), self.transition_count),
+                motion(SLIDE_DURATION, ease_standard(), cx),
+                move |this, delta| {
+                    let offset = if forward { -delta } else { delta - 1. };
+                    this.left(relative(offset))
+                },
+            )
+            .into_any_element()
+    }
+
+    fn render_arrow(&self, forward: bool, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let (id, icon) = if forward {
+            ("carousel-next", IconName::ChevronRight)
+        } else {
+            ("carousel-prev", IconName::ChevronLeft)
+        };
+        let disabled = !self.looping
+            && if forward {
+                self.selected_ix + 1 >= self.items.len()
+            } else {
+                self.selected_ix == 0
+            };
+
+        h_flex()
+            .absolute()
+            .top_0()
+            .bottom_0()
+            .when(forward, |this| this.right_2())
+            .when(!forward, |this| this.left_2())
+            .child(
+                Button::new(id)
+                    .outline()
+                    .small()
+                    .rounded(ButtonRounded::Size(px(999.)))
+                    .icon(icon)
+                    .disabled(disabled)
+                    .on_click(cx.listener(move |this, _, cx| {
+                        if forward {
+                            this.next(cx);
+                        } else {
+                            this.prev(cx);
+                        }
+                    })),
+            )
+    }
+
+    fn render_indicators(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let color = cx.theme().primary;
+
+        h_flex()
+            .absolute()
+            .left_0()
+            .right_0()
+            .bottom_2()
+            .justify_center()
+            .gap_1p5()
+            .children((0..self.items.len()).map(|ix| {
+                let selected = ix == self.selected_ix;
+                div()
+                    .id(("carousel-indicator", ix))
+                    .h_2()
+                    .rounded_full()
+                    .cursor_pointer()
+                    .map(|this| {
+                        if selected {
+                            this.w_4().bg(color)
+                        } else {
+                            this.w_2()
+                                .bg(color.opacity(0.3))
+                                .hover(|this| this.bg(color.opacity(0.6)))
+                        }
+                    })
+                    .on_click(cx.listener(move |this, _, cx| {
+                        this.set_selected_index(ix, cx);
+                    }))
+            }))
+    }
+}
+
+impl EventEmitter<CarouselEvent> for Carousel {}
+
+impl FocusableView for Carousel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Carousel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let has_many = self.items.len() > 1;
+
+        div()
+            .id("carousel")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_action_prev))
+            .on_action(cx.listener(Self::on_action_next))
+            .on_action(cx.listener(Self::on_action_first))
+            .on_action(cx.listener(Self::on_action_last))
+            .on_hover(cx.listener(Self::on_hover))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|this, _, cx| cx.focus(&this.focus_handle)),
+            )
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .rounded(px(cx.theme().radius))
+            .child(self.render_slides(cx))
+            .when(self.arrows && has_many, |this| {
+                this.child(self.render_arrow(false, cx))
+                    .child(self.render_arrow(true, cx))
+            })
+            .when(self.indicators && has_many, |this| {
+                this.child(self.render_indicators(cx))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::step_index;
+
+    #[test]
+    fn test_step_index() {
+        assert_eq!(step_index(0, 3, 1, false), 1);
+        assert_eq!(step_index(2, 3, 1, false), 2);
+        assert_eq!(step_index(0, 3, -1, false), 0);
+
+        assert_eq!(step_index(2, 3, 1, true), 0);
+        assert_eq!(step_index(0, 3, -1, true), 2);
+        assert_eq!(step_index(1, 3, 4, true), 2);
+
+        assert_eq!(step_index(0, 0, 1, true), 0);
+    }
+}
@@ -0,0 +1,107 @@
+use gpui::{point, px, ElementId, Hsla, IntoElement, Path, StyleRefinement, Styled, WindowContext};
+
+use super::{
+    paint::paint_polyline, paint_crosshair, series::base_value, AxisChart, AxisChartElement,
+    AxisOptions, ChartKind, Plot,
+};
+
+/// An area chart, the area under the line of each [`super::Series`] is filled.
+///
+/// ```ignore
+/// AreaChart::new("traffic")
+///     .labels(["Jan", "Feb", "Mar", "Apr"])
+///     .series(Series::new("Direct", [120., 132., 101., 134.]))
+///     .series(Series::new("Search", [220., 182., 191., 234.]))
+///     .stacked(true)
+/// ```
+pub struct AreaChart {
+    options: AxisOptions,
+    stacked: bool,
+}
+
+impl AreaChart {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            options: AxisOptions::new(id),
+            stacked: false,
+        }
+        .w_full()
+        .h(px(240.))
+    }
+
+    /// Stack the areas of the series on top of each other, default is false.
+    pub fn stacked(mut self, stacked: bool) -> Self {
+        self.stacked = stacked;
+        self
+    }
+}
+
+impl AxisChart for AreaChart {
+    fn options_mut(&mut self) -> &mut AxisOptions {
+        &mut self.options
+    }
+}
+
+impl Styled for AreaChart {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.options.style
+    }
+}
+
+impl IntoElement for AreaChart {
+    type Element = AxisChartElement;
+
+    fn into_element(self) -> Self::Element {
+        AxisChartElement::new(
+            self.options,
+            ChartKind::Area {
+                stacked: self.stacked,
+            },
+        )
+    }
+}
+
+pub(super) fn paint(
+    plot: &Plot,
+    values: &[Vec<f64>],
+    colors: &[Hsla],
+    stacked: bool,
+    hovered_ix: Option<usize>,
+    cx: &mut WindowContext,
+) {
+    for (series_ix, (series_values, color)) in values.iter().zip(colors).enumerate() {
+        if series_values.is_empty() {
+            continue;
+        }
+
+        let points = series_values
+            .iter()
+            .enumerate()
+            .map(|(ix, value)| point(plot.x(ix), plot.y(*value)))
+            .collect::<Vec<_>>();
+        let base = |ix: usize| {
+            let value = if stacked {
+                base_value(values, series_ix, ix)
+            } else {
+                0.
+            };
+            point(plot.x(ix), plot.y(value))
+        };
+
+        // The polygon from the line to the base from right to left.
+        let mut path = Path::new(points[0]);
+        for point in &points[1..] {
+            path.line_to(*point);
+        }
+        for ix in (0..points.len()).rev() {
+            path.line_to(base(ix));
+        }
+        cx.paint_path(path, color.opacity(0.3));
+
+        paint_polyline(&points, px(2.), *color, cx);
+    }
+
+    if let Some(ix) = hovered_ix {
+        paint_crosshair(plot, ix, values, colors, cx);
+    }
+}
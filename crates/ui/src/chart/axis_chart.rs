@@ -0,0 +1,416 @@
+use std::rc::Rc;
+
+use gpui::{
+    fill, point, px, size, Bounds, Element, ElementId, GlobalElementId, Hitbox, Hsla, IntoElement,
+    LayoutId, Pixels, Point, Refineable as _, SharedString, Style, StyleRefinement, WindowContext,
+};
+
+use crate::theme::ActiveTheme as _;
+
+use super::{
+    area_chart, bar_chart, format_number, line_chart,
+    paint::{
+        hovered_state, paint_dot, paint_legend, paint_tooltip, shape_text, text_size, track_hover,
+    },
+    scale::Scale,
+    series::{plot_values, series_colors},
+    Series,
+};
+
+const TICK_COUNT: usize = 5;
+const LABEL_GAP: Pixels = px(8.);
+
+/// The kind of the chart to paint the series.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ChartKind {
+    Line { dots: bool },
+    Bar { stacked: bool },
+    Area { stacked: bool },
+}
+
+impl ChartKind {
+    /// The bars are placed in the middle of the bands, and the points are placed on the ticks.
+    fn banded(&self) -> bool {
+        matches!(self, Self::Bar { .. })
+    }
+
+    fn stacked(&self) -> bool {
+        matches!(
+            self,
+            Self::Bar { stacked: true } | Self::Area { stacked: true }
+        )
+    }
+}
+
+type ValueFormat = Rc<dyn Fn(f64) -> SharedString>;
+
+/// The options of the charts with the x and y axes, set by the [`AxisChart`] methods.
+pub struct AxisOptions {
+    pub(crate) id: ElementId,
+    pub(crate) style: StyleRefinement,
+    pub(crate) labels: Vec<SharedString>,
+    pub(crate) series: Vec<Series>,
+    pub(crate) y_format: Option<ValueFormat>,
+    pub(crate) legend: bool,
+    pub(crate) grid: bool,
+    pub(crate) tooltip: bool,
+}
+
+impl AxisOptions {
+    pub(crate) fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            labels: vec![],
+            series: vec![],
+            y_format: None,
+            legend: true,
+            grid: true,
+            tooltip: true,
+        }
+    }
+
+    fn format(&self, value: f64) -> SharedString {
+        match &self.y_format {
+            Some(format) => format(value),
+            None => format_number(value),
+        }
+    }
+
+    /// The number of the values on the x axis.
+    fn count(&self) -> usize {
+        self.series
+            .iter()
+            .map(|series| series.values.len())
+            .max()
+            .unwrap_or(0)
+            .max(self.labels.len())
+    }
+}
+
+/// The builder methods of the charts with the x and y axes: [`super::LineChart`], [`super::BarChart`] and [`super::AreaChart`].
+pub trait AxisChart: Sized {
+    #[doc(hidden)]
+    fn options_mut(&mut self) -> &mut AxisOptions;
+
+    /// Set the labels of the x axis, e.g.: the months.
+    fn labels<T: Into<SharedString>>(mut self, labels: impl IntoIterator<Item = T>) -> Self {
+        self.options_mut().labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Add a series to the chart.
+    fn series(mut self, series: Series) -> Self {
+        self.options_mut().series.push(series);
+        self
+    }
+
+    /// Set the format of the values on the y axis and the tooltip, default is [`format_number`].
+    fn y_format(mut self, format: impl Fn(f64) -> SharedString + 'static) -> Self {
+        self.options_mut().y_format = Some(Rc::new(format));
+        self
+    }
+
+    /// Show the legend of the series at the top, default is true.
+    fn legend(mut self, legend: bool) -> Self {
+        self.options_mut().legend = legend;
+        self
+    }
+
+    /// Show the grid lines of the y axis ticks, default is true.
+    fn grid(mut self, grid: bool) -> Self {
+        self.options_mut().grid = grid;
+        self
+    }
+
+    /// Show the crosshair and the values tooltip when hovering, default is true.
+    fn tooltip(mut self, tooltip: bool) -> Self {
+        self.options_mut().tooltip = tooltip;
+        self
+    }
+}
+
+/// The area to plot the series, maps the index and the value to the position.
+pub(crate) struct Plot {
+    pub(crate) bounds: Bounds<Pixels>,
+    scale: Scale,
+    count: usize,
+    banded: bool,
+}
+
+impl Plot {
+    /// The width between two adjacent indexes.
+    pub(crate) fn band_width(&self) -> Pixels {
+        let width = self.bounds.size.width;
+        if self.banded {
+            width / self.count.max(1) as f32
+        } else if self.count > 1 {
+            width / (self.count - 1) as f32
+        } else {
+            width
+        }
+    }
+
+    pub(crate) fn x(&self, ix: usize) -> Pixels {
+        if self.banded {
+            self.bounds.left() + self.band_width() * (ix as f32 + 0.5)
+        } else if self.count > 1 {
+            self.bounds.left() + self.band_width() * ix as f32
+        } else {
+            self.bounds.center().x
+        }
+    }
+
+    pub(crate) fn y(&self, value: f64) -> Pixels {
+        self.bounds.bottom() - self.bounds.size.height * self.scale.ratio(value)
+    }
+
+    /// The index nearest to the `x` position.
+    fn index_at(&self, x: Pixels) -> Option<usize> {
+        if self.count == 0 || x < self.bounds.left() || x > self.bounds.right() {
+            return None;
+        }
+
+        let offset = (x - self.bounds.left()) / self.band_width();
+        let ix = if self.banded {
+            offset.floor()
+        } else {
+            offset.round()
+        };
+        Some((ix as usize).min(self.count - 1))
+    }
+}
+
+/// Paint the vertical line at the hovered index, and the dots of the values on it.
+pub(crate) fn paint_crosshair(
+    plot: &Plot,
+    ix: usize,
+    values: &[Vec<f64>],
+    colors: &[Hsla],
+    cx: &mut WindowContext,
+) {
+    let x = plot.x(ix);
+    let line_color = cx.theme().muted_foreground.opacity(0.5);
+    let background = cx.theme().background;
+    cx.paint_quad(fill(
+        Bounds {
+            origin: point(x, plot.bounds.top()),
+            size: size(px(1.), plot.bounds.size.height),
+        },
+        line_color,
+    ));
+
+    for (values, color) in values.iter().zip(colors) {
+        if let Some(value) = values.get(ix) {
+            let center = point(x, plot.y(*value));
+            paint_dot(center, px(10.), background, cx);
+            paint_dot(center, px(6.), *color, cx);
+        }
+    }
+}
+
+/// The element of the [`AxisChart`] to paint the axes, the legend, the series and the tooltip.
+pub struct AxisChartElement {
+    options: AxisOptions,
+    kind: ChartKind,
+}
+
+impl AxisChartElement {
+    pub(crate) fn new(options: AxisOptions, kind: ChartKind) -> Self {
+        Self { options, kind }
+    }
+
+    fn paint_x_labels(&self, plot: &Plot, color: Hsla, cx: &mut WindowContext) {
+        let (font_size, line_height) = text_size(cx);
+        let labels = self
+            .options
+            .labels
+            .iter()
+            .map(|label| shape_text(label.clone(), color, font_size, cx))
+            .collect::<Vec<_>>();
+        let max_width = labels
+            .iter()
+            .flatten()
+            .fold(px(0.), |width, label| width.max(label.width));
+
+        // Skip the labels to avoid overlapping.
+        let step = ((max_width + LABEL_GAP) / plot.band_width()).ceil().max(1.) as usize;
+        let y = plot.bounds.bottom() + px(4.);
+        for (ix, label) in labels.into_iter().enumerate().step_by(step) {
+            if let Some(label) = label {
+                _ = label.paint(point(plot.x(ix) - label.width / 2., y), line_height, cx);
+            }
+        }
+    }
+
+    fn paint_tooltip(
+        &self,
+        position: Point<Pixels>,
+        ix: usize,
+        colors: &[Hsla],
+        bounds: Bounds<Pixels>,
+        cx: &mut WindowContext,
+    ) {
+        let title = self
+            .options
+            .labels
+            .get(ix)
+            .cloned()
+            .unwrap_or_else(|| (ix + 1).to_string().into());
+        let rows = self
+            .options
+            .series
+            .iter()
+            .zip(colors)
+            .filter_map(|(series, color)| {
+                let value = series.values.get(ix)?;
+                let text = format!("{}: {}", series.name, self.options.format(*value));
+                Some((*color, text.into()))
+            })
+            .collect::<Vec<_>>();
+
+        paint_tooltip(position, bounds, title, &rows, cx);
+    }
+}
+
+impl IntoElement for AxisChartElement {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for AxisChartElement {
+    type RequestLayoutState = ();
+    type PrepaintState = Hitbox;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.options.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut style = Style::default();
+        style.refine(&self.options.style);
+
+        (cx.request_layout(style, None), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        cx.insert_hitbox(bounds, false)
+    }
+
+    fn paint(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let hovered = hovered_state(global_id.unwrap(), cx);
+
+        let colors = series_colors(&self.options.series, cx.theme());
+        let (muted_foreground, border) = (cx.theme().muted_foreground, cx.theme().border);
+        let (font_size, line_height) = text_size(cx);
+
+        let values = plot_values(&self.options.series, self.kind.stacked());
+        let (min, max) = values
+            .iter()
+            .flatten()
+            .fold((0f64, 0f64), |(min, max), value| {
+                (min.min(*value), max.max(*value))
+            });
+        let scale = Scale::new(min, max, TICK_COUNT);
+
+        let mut top = bounds.top();
+        if self.options.legend && !self.options.series.is_empty() {
+            let items = self
+                .options
+                .series
+                .iter()
+                .zip(&colors)
+                .map(|(series, color)| (series.name.clone(), *color))
+                .collect::<Vec<_>>();
+            paint_legend(bounds.origin, &items, cx);
+            top += line_height + LABEL_GAP;
+        }
+
+        let y_labels = scale
+            .ticks()
+            .filter_map(|tick| {
+                let label = shape_text(self.options.format(tick), muted_foreground, font_size, cx)?;
+                Some((tick, label))
+            })
+            .collect::<Vec<_>>();
+        let y_axis_width = y_labels
+            .iter()
+            .fold(px(0.), |width, (_, label)| width.max(label.width));
+
+        let plot = Plot {
+            bounds: Bounds::from_corners(
+                // Keep the half line height for the label of the top tick.
+                point(
+                    bounds.left() + y_axis_width + LABEL_GAP,
+                    top + line_height / 2.,
+                ),
+                point(bounds.right(), bounds.bottom() - line_height - px(4.)),
+            ),
+            scale,
+            count: self.options.count(),
+            banded: self.kind.banded(),
+        };
+        if plot.bounds.size.width <= px(0.) || plot.bounds.size.height <= px(0.) {
+            return;
+        }
+
+        for (tick, label) in y_labels {
+            let y = plot.y(tick);
+            if self.options.grid {
+                cx.paint_quad(fill(
+                    Bounds {
+                        origin: point(plot.bounds.left(), y),
+                        size: size(plot.bounds.size.width, px(1.)),
+                    },
+                    border,
+                ));
+            }
+            let origin = point(
+                plot.bounds.left() - LABEL_GAP - label.width,
+                y - line_height / 2.,
+            );
+            _ = label.paint(origin, line_height, cx);
+        }
+        self.paint_x_labels(&plot, muted_foreground, cx);
+
+        let position = hovered.get().filter(|_| self.options.tooltip);
+        let hovered_ix = position.and_then(|position| plot.index_at(position.x));
+        match self.kind {
+            ChartKind::Line { dots } => {
+                line_chart::paint(&plot, &values, &colors, dots, hovered_ix, cx)
+            }
+            ChartKind::Bar { stacked } => {
+                bar_chart::paint(&plot, &values, &colors, stacked, hovered_ix, cx)
+            }
+            ChartKind::Area { stacked } => {
+                area_chart::paint(&plot, &values, &colors, stacked, hovered_ix, cx)
+            }
+        }
+
+        if let (Some(position), Some(ix)) = (position, hovered_ix) {
+            self.paint_tooltip(position, ix, &colors, bounds, cx);
+        }
+
+        track_hover(hitbox, hovered, cx);
+    }
+}
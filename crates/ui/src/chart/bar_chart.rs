@@ -0,0 +1,125 @@
+use gpui::{
+    fill, point, px, size, Bounds, Corners, ElementId, Hsla, IntoElement, StyleRefinement, Styled,
+    WindowContext,
+};
+
+use crate::theme::ActiveTheme as _;
+
+use super::{series::base_value, AxisChart, AxisChartElement, AxisOptions, ChartKind, Plot};
+
+/// The ratio of the bars in the band, the rest is the gap between the bands.
+const BAR_RATIO: f32 = 0.7;
+
+/// A bar chart, the values of the [`super::Series`] are grouped side by side, or stacked.
+///
+/// ```ignore
+/// BarChart::new("sales")
+///     .labels(["Q1", "Q2", "Q3", "Q4"])
+///     .series(Series::new("2023", [120., 200., 150., 80.]))
+///     .series(Series::new("2024", [140., 220., 180., 120.]))
+///     .y_format(|value| format!("${}", value).into())
+/// ```
+pub struct BarChart {
+    options: AxisOptions,
+    stacked: bool,
+}
+
+impl BarChart {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            options: AxisOptions::new(id),
+            stacked: false,
+        }
+        .w_full()
+        .h(px(240.))
+    }
+
+    /// Stack the bars of the series on top of each other, default is false.
+    pub fn stacked(mut self, stacked: bool) -> Self {
+        self.stacked = stacked;
+        self
+    }
+}
+
+impl AxisChart for BarChart {
+    fn options_mut(&mut self) -> &mut AxisOptions {
+        &mut self.options
+    }
+}
+
+impl Styled for BarChart {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.options.style
+    }
+}
+
+impl IntoElement for BarChart {
+    type Element = AxisChartElement;
+
+    fn into_element(self) -> Self::Element {
+        AxisChartElement::new(
+            self.options,
+            ChartKind::Bar {
+                stacked: self.stacked,
+            },
+        )
+    }
+}
+
+pub(super) fn paint(
+    plot: &Plot,
+    values: &[Vec<f64>],
+    colors: &[Hsla],
+    stacked: bool,
+    hovered_ix: Option<usize>,
+    cx: &mut WindowContext,
+) {
+    let band_width = plot.band_width();
+    if let Some(ix) = hovered_ix {
+        let highlight = cx.theme().muted.opacity(0.6);
+        cx.paint_quad(fill(
+            Bounds {
+                origin: point(plot.x(ix) - band_width / 2., plot.bounds.top()),
+                size: size(band_width, plot.bounds.size.height),
+            },
+            highlight,
+        ));
+    }
+
+    let group_count = if stacked { 1 } else { values.len().max(1) };
+    let bar_width = band_width * BAR_RATIO / group_count as f32;
+    let radius = (bar_width / 4.).min(px(4.));
+
+    for (series_ix, (series_values, color)) in values.iter().zip(colors).enumerate() {
+        for (ix, value) in series_values.iter().enumerate() {
+            let (base, group_ix) = if stacked {
+                (base_value(values, series_ix, ix), 0)
+            } else {
+                (0., series_ix)
+            };
+
+            let left = plot.x(ix) - band_width * BAR_RATIO / 2. + bar_width * group_ix as f32;
+            let (y, base_y) = (plot.y(*value), plot.y(base));
+            let bounds = Bounds::from_corners(
+                point(left, y.min(base_y)),
+                point(left + bar_width, y.max(base_y)),
+            );
+
+            // Round the corners at the end of the bar.
+            let corner_radii = if *value >= base {
+                Corners {
+                    top_left: radius,
+                    top_right: radius,
+                    ..Default::default()
+                }
+            } else {
+                Corners {
+                    bottom_left: radius,
+                    bottom_right: radius,
+                    ..Default::default()
+                }
+            };
+            cx.paint_quad(fill(bounds, *color).corner_radii(corner_radii));
+        }
+    }
+}
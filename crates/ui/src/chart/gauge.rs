@@ -0,0 +1,249 @@
+use std::{f32::consts::PI, rc::Rc};
+
+use gpui::{
+    canvas, div, prelude::FluentBuilder as _, px, Hsla, IntoElement, ParentElement as _, Pixels,
+    Refineable as _, RenderOnce, SharedString, StyleRefinement, Styled, WindowContext,
+};
+
+use crate::{theme::ActiveTheme as _, v_flex, StyledExt as _};
+
+use super::{format_number, paint::paint_sector};
+
+/// The gauge starts from the bottom left, and sweeps 270 degrees clockwise to the bottom right.
+const START_ANGLE: f32 = -PI * 0.75;
+const SWEEP_ANGLE: f32 = PI * 1.5;
+/// The width of the color bands outside the gauge arc.
+const BAND_WIDTH: Pixels = px(3.);
+
+/// A radial gauge to show the value in the range, e.g.: the CPU usage.
+///
+/// The color of the value is changed by the thresholds, and the thresholds can be shown as the color bands.
+///
+/// ```ignore
+/// Gauge::new(72.)
+///     .label("CPU")
+///     .threshold(60., crate::yellow_500())
+///     .threshold(85., crate::red_500())
+///     .value_format(|value| format!("{}%", value).into())
+/// ```
+#[derive(IntoElement)]
+pub struct Gauge {
+    style: StyleRefinement,
+    value: f64,
+    min: f64,
+    max: f64,
+    color: Option<Hsla>,
+    thresholds: Vec<(f64, Hsla)>,
+    bands: bool,
+    thickness: Pixels,
+    label: Option<SharedString>,
+    value_format: Option<Rc<dyn Fn(f64) -> SharedString>>,
+}
+
+impl Gauge {
+    pub fn new(value: impl Into<f64>) -> Self {
+        Self {
+            style: StyleRefinement::default(),
+            value: value.into(),
+            min: 0.,
+            max: 100.,
+            color: None,
+            thresholds: vec![],
+            bands: false,
+            thickness: px(12.),
+            label: None,
+            value_format: None,
+        }
+        .size(px(160.))
+    }
+
+    /// Set the min value of the range, default is 0.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the max value of the range, default is 100.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the color of the value below all the thresholds, default is the `chart_1` color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Use the `color` for the value from the `value`, until the next threshold.
+    pub fn threshold(mut self, value: f64, color: impl Into<Hsla>) -> Self {
+        self.thresholds.push((value, color.into()));
+        self.thresholds.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self
+    }
+
+    /// Show the thresholds as the color bands outside the arc, default is false.
+    pub fn bands(mut self, bands: bool) -> Self {
+        self.bands = bands;
+        self
+    }
+
+    /// Set the thickness of the arc, default is 12px.
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = thickness.into();
+        self
+    }
+
+    /// Set the label under the value.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the format of the value, default is [`format_number`].
+    pub fn value_format(mut self, format: impl Fn(f64) -> SharedString + 'static) -> Self {
+        self.value_format = Some(Rc::new(format));
+        self
+    }
+}
+
+/// The position of the value in the range, clamped to 0..1.
+fn gauge_ratio(value: f64, min: f64, max: f64) -> f32 {
+    if max <= min || !value.is_finite() {
+        return 0.;
+    }
+
+    ((value - min) / (max - min)).clamp(0., 1.) as f32
+}
+
+/// The color of the last threshold not greater than the value, the thresholds are sorted.
+fn threshold_color(value: f64, thresholds: &[(f64, Hsla)], color: Hsla) -> Hsla {
+    thresholds
+        .iter()
+        .rev()
+        .find(|(threshold, _)| value >= *threshold)
+        .map_or(color, |(_, color)| *color)
+}
+
+impl Styled for Gauge {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Gauge {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme();
+        let base_color = self.color.unwrap_or(theme.chart_1);
+        let color = threshold_color(self.value, &self.thresholds, base_color);
+        let track = theme.muted;
+        let ratio = gauge_ratio(self.value, self.min, self.max);
+        let text = match &self.value_format {
+            Some(format) => format(self.value),
+            None => format_number(self.value),
+        };
+
+        // The ratio ranges of the bands, from the min or a threshold to the next.
+        let bands = self.bands.then(|| {
+            let mut starts = vec![(self.min, base_color)];
+            starts.extend(self.thresholds.iter().copied());
+            starts
+                .iter()
+                .enumerate()
+                .map(|(ix, (start, color))| {
+                    let end = starts.get(ix + 1).map_or(self.max, |(end, _)| *end);
+                    (
+                        gauge_ratio(*start, self.min, self.max),
+                        gauge_ratio(end, self.min, self.max),
+                        *color,
+                    )
+                })
+                .collect::<Vec<_>>()
+        });
+        let thickness = self.thickness;
+
+        div()
+            .relative()
+            .map(|mut this| {
+                this.style().refine(&self.style);
+                this
+            })
+            .child(
+                canvas(
+                    |_, _| {},
+                    move |bounds, _, cx| {
+                        let center = bounds.center();
+                        let mut radius = bounds.size.width.min(bounds.size.height) / 2.;
+                        if let Some(bands) = bands {
+                            for (start, end, color) in bands {
+                                let angles = (
+                                    START_ANGLE + SWEEP_ANGLE * start,
+                                    START_ANGLE + SWEEP_ANGLE * end,
+                                );
+                                paint_sector(
+                                    center,
+                                    radius - BAND_WIDTH,
+                                    radius,
+                                    angles,
+                                    color,
+                                    cx,
+                                );
+                            }
+                            radius -= BAND_WIDTH * 2.;
+                        }
+
+                        let inner_radius = (radius - thickness).max(px(0.));
+                        let track_angles = (START_ANGLE, START_ANGLE + SWEEP_ANGLE);
+                        paint_sector(center, inner_radius, radius, track_angles, track, cx);
+                        if ratio > 0. {
+                            let angles = (START_ANGLE, START_ANGLE + SWEEP_ANGLE * ratio);
+                            paint_sector(center, inner_radius, radius, angles, color, cx);
+                        }
+                    },
+                )
+                .absolute()
+                .size_full(),
+            )
+            .child(
+                v_flex()
+                    .absolute()
+                    .size_full()
+                    .items_center()
+                    .justify_center()
+                    .child(div().text_2xl().font_bold().child(text))
+                    .when_some(self.label, |this, label| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(label),
+                        )
+                    }),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{blue_500, red_500, yellow_500};
+
+    use super::{gauge_ratio, threshold_color};
+
+    #[test]
+    fn test_gauge_ratio() {
+        assert_eq!(gauge_ratio(50., 0., 200.), 0.25);
+        assert_eq!(gauge_ratio(-10., 0., 100.), 0.);
+        assert_eq!(gauge_ratio(120., 0., 100.), 1.);
+        assert_eq!(gauge_ratio(10., 100., 100.), 0.);
+        assert_eq!(gauge_ratio(f64::NAN, 0., 100.), 0.);
+    }
+
+    #[test]
+    fn test_threshold_color() {
+        let thresholds = [(60., yellow_500()), (85., red_500())];
+        assert_eq!(threshold_color(10., &thresholds, blue_500()), blue_500());
+        assert_eq!(threshold_color(60., &thresholds, blue_500()), yellow_500());
+        assert_eq!(threshold_color(84.9, &thresholds, blue_500()), yellow_500());
+        assert_eq!(threshold_color(100., &thresholds, blue_500()), red_500());
+    }
+}
@@ -0,0 +1,85 @@
+use gpui::{point, px, ElementId, Hsla, IntoElement, StyleRefinement, Styled, WindowContext};
+
+use super::{
+    paint::{paint_dot, paint_polyline},
+    paint_crosshair, AxisChart, AxisChartElement, AxisOptions, ChartKind, Plot,
+};
+
+/// A line chart, the values of each [`super::Series`] are connected by a line.
+///
+/// ```ignore
+/// LineChart::new("visitors")
+///     .labels(["Mon", "Tue", "Wed", "Thu", "Fri"])
+///     .series(Series::new("Desktop", [186., 305., 237., 73., 209.]))
+///     .series(Series::new("Mobile", [80., 200., 120., 190., 130.]))
+///     .h(px(300.))
+/// ```
+pub struct LineChart {
+    options: AxisOptions,
+    dots: bool,
+}
+
+impl LineChart {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            options: AxisOptions::new(id),
+            dots: true,
+        }
+        .w_full()
+        .h(px(240.))
+    }
+
+    /// Show the dots at the values, default is true.
+    pub fn dots(mut self, dots: bool) -> Self {
+        self.dots = dots;
+        self
+    }
+}
+
+impl AxisChart for LineChart {
+    fn options_mut(&mut self) -> &mut AxisOptions {
+        &mut self.options
+    }
+}
+
+impl Styled for LineChart {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.options.style
+    }
+}
+
+impl IntoElement for LineChart {
+    type Element = AxisChartElement;
+
+    fn into_element(self) -> Self::Element {
+        AxisChartElement::new(self.options, ChartKind::Line { dots: self.dots })
+    }
+}
+
+pub(super) fn paint(
+    plot: &Plot,
+    values: &[Vec<f64>],
+    colors: &[Hsla],
+    dots: bool,
+    hovered_ix: Option<usize>,
+    cx: &mut WindowContext,
+) {
+    for (values, color) in values.iter().zip(colors) {
+        let points = values
+            .iter()
+            .enumerate()
+            .map(|(ix, value)| point(plot.x(ix), plot.y(*value)))
+            .collect::<Vec<_>>();
+
+        paint_polyline(&points, px(2.), *color, cx);
+        if dots {
+            for point in points {
+                paint_dot(point, px(6.), *color, cx);
+            }
+        }
+    }
+
+    if let Some(ix) = hovered_ix {
+        paint_crosshair(plot, ix, values, colors, cx);
+    }
+}
@@ -0,0 +1,22 @@
+mod area_chart;
+mod axis_chart;
+mod bar_chart;
+mod gauge;
+mod line_chart;
+mod paint;
+mod pie_chart;
+mod scale;
+mod series;
+mod sparkline;
+mod stat;
+
+pub use area_chart::*;
+pub use axis_chart::*;
+pub use bar_chart::*;
+pub use gauge::*;
+pub use line_chart::*;
+pub use pie_chart::*;
+pub use scale::format_number;
+pub use series::*;
+pub use sparkline::*;
+pub use stat::*;
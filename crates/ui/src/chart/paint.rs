@@ -0,0 +1,249 @@
+use std::{cell::Cell, f32::consts::PI, rc::Rc};
+
+use gpui::{
+    fill, point, px, quad, rems, size, Bounds, GlobalElementId, Hitbox, Hsla, MouseMoveEvent, Path,
+    Pixels, Point, ShapedLine, SharedString, TextRun, WindowContext,
+};
+
+use crate::theme::ActiveTheme as _;
+
+const SWATCH_SIZE: Pixels = px(8.);
+
+/// Shape the text in a single line to paint in the chart.
+pub(crate) fn shape_text(
+    text: impl Into<SharedString>,
+    color: Hsla,
+    font_size: Pixels,
+    cx: &WindowContext,
+) -> Option<ShapedLine> {
+    let text = text.into();
+    let run = TextRun {
+        len: text.len(),
+        font: cx.text_style().font(),
+        color,
+        background_color: None,
+        underline: None,
+        strikethrough: None,
+    };
+
+    cx.text_system().shape_line(text, font_size, &[run]).ok()
+}
+
+/// Paint the polyline through the points with the `width`.
+pub(crate) fn paint_polyline(
+    points: &[Point<Pixels>],
+    width: Pixels,
+    color: Hsla,
+    cx: &mut WindowContext,
+) {
+    let half = width.0 / 2.;
+
+    // Paint each segment as a quadrilateral, and the dots to fill the gaps at the joints.
+    for segment in points.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let (dx, dy) = ((to.x - from.x).0, (to.y - from.y).0);
+        let len = (dx * dx + dy * dy).sqrt();
+        if len == 0. {
+            continue;
+        }
+
+        let normal = point(px(-dy / len * half), px(dx / len * half));
+        let mut path = Path::new(from + normal);
+        path.line_to(to + normal);
+        path.line_to(to - normal);
+        path.line_to(from - normal);
+        cx.paint_path(path, color);
+    }
+
+    if points.len() > 2 {
+        for point in &points[1..points.len() - 1] {
+            paint_dot(*point, width, color, cx);
+        }
+    }
+}
+
+/// Paint a round dot at the center with the `diameter`.
+pub(crate) fn paint_dot(
+    center: Point<Pixels>,
+    diameter: Pixels,
+    color: Hsla,
+    cx: &mut WindowContext,
+) {
+    let bounds = Bounds {
+        origin: center - point(diameter / 2., diameter / 2.),
+        size: size(diameter, diameter),
+    };
+    cx.paint_quad(fill(bounds, color).corner_radii(diameter / 2.));
+}
+
+/// The font size and the line height of the text in the chart.
+pub(crate) fn text_size(cx: &WindowContext) -> (Pixels, Pixels) {
+    let font_size = rems(0.75).to_pixels(cx.rem_size());
+    (font_size, font_size * 1.5)
+}
+
+/// The mouse position when hovering the chart, kept in the element state.
+#[derive(Default)]
+struct HoverState {
+    position: Rc<Cell<Option<Point<Pixels>>>>,
+}
+
+/// Get the hovered position of the chart element.
+pub(crate) fn hovered_state(
+    global_id: &GlobalElementId,
+    cx: &mut WindowContext,
+) -> Rc<Cell<Option<Point<Pixels>>>> {
+    cx.with_element_state::<HoverState, _>(global_id, |state, _| {
+        let state = state.unwrap_or_default();
+        (state.position.clone(), state)
+    })
+}
+
+/// Update the hovered position by the mouse move, and refresh the window if it is changed.
+pub(crate) fn track_hover(
+    hitbox: &Hitbox,
+    hovered: Rc<Cell<Option<Point<Pixels>>>>,
+    cx: &mut WindowContext,
+) {
+    let hitbox = hitbox.clone();
+    cx.on_mouse_event(move |event: &MouseMoveEvent, phase, cx| {
+        if !phase.bubble() {
+            return;
+        }
+
+        let position = hitbox.is_hovered(cx).then_some(event.position);
+        if hovered.get() != position {
+            hovered.set(position);
+            cx.refresh();
+        }
+    });
+}
+
+/// Paint the legend items with the color swatches in a row.
+pub(crate) fn paint_legend(
+    origin: Point<Pixels>,
+    items: &[(SharedString, Hsla)],
+    cx: &mut WindowContext,
+) {
+    let (font_size, line_height) = text_size(cx);
+    let foreground = cx.theme().foreground;
+    let mut x = origin.x;
+    for (name, color) in items {
+        paint_swatch(point(x, origin.y), line_height, *color, cx);
+        x += SWATCH_SIZE + px(6.);
+
+        if let Some(label) = shape_text(name.clone(), foreground, font_size, cx) {
+            _ = label.paint(point(x, origin.y), line_height, cx);
+            x += label.width + px(12.);
+        }
+    }
+}
+
+/// Paint the color swatch at the left of the line.
+fn paint_swatch(origin: Point<Pixels>, line_height: Pixels, color: Hsla, cx: &mut WindowContext) {
+    let bounds = Bounds {
+        origin: point(origin.x, origin.y + (line_height - SWATCH_SIZE) / 2.),
+        size: size(SWATCH_SIZE, SWATCH_SIZE),
+    };
+    cx.paint_quad(fill(bounds, color).corner_radii(px(2.)));
+}
+
+/// Paint the tooltip near the mouse `position`, with the title and the rows of the values.
+///
+/// It is placed on the right of the cursor, or the left if there is no enough space in the `bounds`.
+pub(crate) fn paint_tooltip(
+    position: Point<Pixels>,
+    bounds: Bounds<Pixels>,
+    title: SharedString,
+    rows: &[(Hsla, SharedString)],
+    cx: &mut WindowContext,
+) {
+    let (font_size, line_height) = text_size(cx);
+    let theme = cx.theme();
+    let (foreground, muted_foreground, popover, border, radius) = (
+        theme.popover_foreground,
+        theme.muted_foreground,
+        theme.popover,
+        theme.border,
+        px(theme.radius),
+    );
+    let padding = px(8.);
+
+    let Some(title) = shape_text(title, muted_foreground, font_size, cx) else {
+        return;
+    };
+    let rows = rows
+        .iter()
+        .filter_map(|(color, text)| {
+            Some((*color, shape_text(text.clone(), foreground, font_size, cx)?))
+        })
+        .collect::<Vec<_>>();
+    if rows.is_empty() {
+        return;
+    }
+
+    let width = rows.iter().fold(title.width, |width, (_, line)| {
+        width.max(SWATCH_SIZE + px(6.) + line.width)
+    }) + padding * 2.;
+    let height = line_height * (rows.len() + 1) as f32 + padding * 2.;
+
+    let mut origin = point(position.x + px(12.), position.y - height / 2.);
+    if origin.x + width > bounds.right() {
+        origin.x = position.x - px(12.) - width;
+    }
+    origin.y = origin.y.min(bounds.bottom() - height).max(bounds.top());
+
+    cx.paint_quad(quad(
+        Bounds {
+            origin,
+            size: size(width, height),
+        },
+        radius,
+        popover,
+        px(1.),
+        border,
+    ));
+
+    let x = origin.x + padding;
+    let mut y = origin.y + padding;
+    _ = title.paint(point(x, y), line_height, cx);
+    for (color, line) in rows {
+        y += line_height;
+        paint_swatch(point(x, y), line_height, color, cx);
+        _ = line.paint(point(x + SWATCH_SIZE + px(6.), y), line_height, cx);
+    }
+}
+
+/// Paint the sector of the ring between the `start` and `end` angles, e.g.: a slice of the pie.
+///
+/// The angles are in radians clockwise from the top, the sector is a pie slice if `inner_radius` is 0.
+pub(crate) fn paint_sector(
+    center: Point<Pixels>,
+    inner_radius: Pixels,
+    outer_radius: Pixels,
+    (start, end): (f32, f32),
+    color: Hsla,
+    cx: &mut WindowContext,
+) {
+    // Approximate the arcs by the segments of at most 2 degrees.
+    let steps = ((end - start) / (PI / 90.)).ceil().max(1.) as usize;
+    let angle = |step: usize| start + (end - start) * step as f32 / steps as f32;
+
+    let mut path = Path::new(point_at(center, outer_radius, start));
+    for step in 1..=steps {
+        path.line_to(point_at(center, outer_radius, angle(step)));
+    }
+    if inner_radius > px(0.) {
+        for step in (0..=steps).rev() {
+            path.line_to(point_at(center, inner_radius, angle(step)));
+        }
+    } else {
+        path.line_to(center);
+    }
+    cx.paint_path(path, color);
+}
+
+/// The point on the circle at the angle in radians clockwise from the top.
+pub(crate) fn point_at(center: Point<Pixels>, radius: Pixels, angle: f32) -> Point<Pixels> {
+    center + point(radius * angle.sin(), -radius * angle.cos())
+}
@@ -0,0 +1,309 @@
+use std::{f32::consts::PI, rc::Rc};
+
+use gpui::{
+    point, px, white, Bounds, Element, ElementId, GlobalElementId, Hitbox, IntoElement, LayoutId,
+    Pixels, Refineable as _, SharedString, Style, StyleRefinement, Styled, WindowContext,
+};
+
+use crate::theme::ActiveTheme as _;
+
+use super::{
+    format_number,
+    paint::{
+        hovered_state, paint_legend, paint_sector, paint_tooltip, point_at, shape_text, text_size,
+        track_hover,
+    },
+    series::palette,
+    Series,
+};
+
+/// The radius added to the hovered slice.
+const EMPHASIS: Pixels = px(6.);
+/// The min angle of the slice to show the percentage.
+const LABEL_MIN_ANGLE: f32 = 0.3;
+
+/// A pie chart, each value of the [`Series`] is a slice with the label at the same index.
+///
+/// Use [`PieChart::donut`] to create a donut chart with a hole in the center.
+///
+/// ```ignore
+/// PieChart::new("browsers")
+///     .labels(["Chrome", "Safari", "Firefox", "Edge"])
+///     .series(Series::new("Visitors", [275., 200., 187., 173.]))
+/// ```
+pub struct PieChart {
+    id: ElementId,
+    style: StyleRefinement,
+    labels: Vec<SharedString>,
+    series: Option<Series>,
+    /// The ratio of the inner radius to the outer radius.
+    inner_radius: f32,
+    value_format: Option<Rc<dyn Fn(f64) -> SharedString>>,
+    legend: bool,
+    tooltip: bool,
+}
+
+impl PieChart {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            labels: vec![],
+            series: None,
+            inner_radius: 0.,
+            value_format: None,
+            legend: true,
+            tooltip: true,
+        }
+        .w_full()
+        .h(px(240.))
+    }
+
+    /// Create a donut chart, that is a pie chart with a hole in the center.
+    pub fn donut(id: impl Into<ElementId>) -> Self {
+        Self::new(id).inner_radius(0.6)
+    }
+
+    /// Set the labels of the slices.
+    pub fn labels<T: Into<SharedString>>(mut self, labels: impl IntoIterator<Item = T>) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the values of the slices, the negative values are ignored.
+    pub fn series(mut self, series: Series) -> Self {
+        self.series = Some(series);
+        self
+    }
+
+    /// Set the ratio of the hole radius to the pie radius in 0..1, default is 0.
+    pub fn inner_radius(mut self, ratio: f32) -> Self {
+        self.inner_radius = ratio.clamp(0., 0.95);
+        self
+    }
+
+    /// Set the format of the values in the tooltip, default is [`format_number`].
+    pub fn value_format(mut self, format: impl Fn(f64) -> SharedString + 'static) -> Self {
+        self.value_format = Some(Rc::new(format));
+        self
+    }
+
+    /// Show the legend of the labels at the top, default is true.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Emphasize the hovered slice and show the tooltip, default is true.
+    pub fn tooltip(mut self, tooltip: bool) -> Self {
+        self.tooltip = tooltip;
+        self
+    }
+
+    fn label(&self, ix: usize) -> SharedString {
+        self.labels
+            .get(ix)
+            .cloned()
+            .unwrap_or_else(|| (ix + 1).to_string().into())
+    }
+
+    fn format(&self, value: f64) -> SharedString {
+        match &self.value_format {
+            Some(format) => format(value),
+            None => format_number(value),
+        }
+    }
+}
+
+impl Styled for PieChart {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl IntoElement for PieChart {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+/// The start and end angles of the slices, in radians clockwise from the top.
+fn slice_angles(values: &[f64]) -> Vec<(f32, f32)> {
+    let values = values
+        .iter()
+        .map(|value| if value.is_finite() { value.max(0.) } else { 0. })
+        .collect::<Vec<_>>();
+    let total: f64 = values.iter().sum();
+    if total <= 0. {
+        return vec![];
+    }
+
+    let mut start = 0.;
+    values
+        .iter()
+        .map(|value| {
+            let end = start + (value / total) as f32 * PI * 2.;
+            let angles = (start, end);
+            start = end;
+            angles
+        })
+        .collect()
+}
+
+/// The index of the slice at the angle.
+fn slice_at(angles: &[(f32, f32)], angle: f32) -> Option<usize> {
+    angles
+        .iter()
+        .position(|(start, end)| angle >= *start && angle < *end)
+}
+
+impl Element for PieChart {
+    type RequestLayoutState = ();
+    type PrepaintState = Hitbox;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut style = Style::default();
+        style.refine(&self.style);
+
+        (cx.request_layout(style, None), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        cx.insert_hitbox(bounds, false)
+    }
+
+    fn paint(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let hovered = hovered_state(global_id.unwrap(), cx);
+        let Some(series) = self.series.as_ref() else {
+            return;
+        };
+
+        let palette = palette(cx.theme());
+        let colors = (0..series.values.len())
+            .map(|ix| palette[ix % palette.len()])
+            .collect::<Vec<_>>();
+        let (font_size, line_height) = text_size(cx);
+
+        let mut top = bounds.top();
+        if self.legend {
+            let items = colors
+                .iter()
+                .enumerate()
+                .map(|(ix, color)| (self.label(ix), *color))
+                .collect::<Vec<_>>();
+            paint_legend(bounds.origin, &items, cx);
+            top += line_height + px(8.);
+        }
+
+        let area = Bounds::from_corners(point(bounds.left(), top), bounds.lower_right());
+        let center = area.center();
+        let radius = area.size.width.min(area.size.height) / 2. - EMPHASIS;
+        if radius <= px(0.) {
+            return;
+        }
+        let inner_radius = radius * self.inner_radius;
+        let angles = slice_angles(&series.values);
+
+        let position = hovered.get().filter(|_| self.tooltip);
+        let hovered_ix = position.and_then(|position| {
+            let (dx, dy) = ((position.x - center.x).0, (position.y - center.y).0);
+            let distance = px((dx * dx + dy * dy).sqrt());
+            if distance < inner_radius || distance > radius + EMPHASIS {
+                return None;
+            }
+
+            let angle = dx.atan2(-dy);
+            slice_at(&angles, if angle < 0. { angle + PI * 2. } else { angle })
+        });
+
+        for (ix, (angles, color)) in angles.iter().zip(&colors).enumerate() {
+            let outer_radius = if hovered_ix == Some(ix) {
+                radius + EMPHASIS
+            } else {
+                radius
+            };
+            paint_sector(center, inner_radius, outer_radius, *angles, *color, cx);
+        }
+
+        // The percentage in the middle of the slice.
+        for (start, end) in angles.iter() {
+            if end - start < LABEL_MIN_ANGLE {
+                continue;
+            }
+
+            let percent = format!("{:.0}%", (end - start) / (PI * 2.) * 100.);
+            if let Some(label) = shape_text(percent, white(), font_size, cx) {
+                let mid = point_at(center, (inner_radius + radius) / 2., (start + end) / 2.);
+                let origin = mid - point(label.width / 2., line_height / 2.);
+                _ = label.paint(origin, line_height, cx);
+            }
+        }
+
+        if let (Some(position), Some(ix)) = (position, hovered_ix) {
+            let (start, end) = angles[ix];
+            let text = format!(
+                "{}: {} ({:.1}%)",
+                series.name,
+                self.format(series.values[ix]),
+                (end - start) / (PI * 2.) * 100.
+            );
+            paint_tooltip(
+                position,
+                bounds,
+                self.label(ix),
+                &[(colors[ix], text.into())],
+                cx,
+            );
+        }
+
+        track_hover(hitbox, hovered, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::{slice_angles, slice_at};
+
+    #[test]
+    fn test_slice_angles() {
+        let angles = slice_angles(&[1., 1., -5., 2.]);
+        assert_eq!(
+            angles,
+            vec![(0., PI / 2.), (PI / 2., PI), (PI, PI), (PI, PI * 2.)]
+        );
+
+        assert_eq!(slice_at(&angles, 0.), Some(0));
+        assert_eq!(slice_at(&angles, PI * 0.75), Some(1));
+        // The empty slice is never hovered.
+        assert_eq!(slice_at(&angles, PI), Some(3));
+        assert_eq!(slice_at(&angles, PI * 2.), None);
+
+        assert!(slice_angles(&[0., 0.]).is_empty());
+        assert!(slice_angles(&[]).is_empty());
+    }
+}
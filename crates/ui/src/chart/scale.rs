@@ -0,0 +1,119 @@
+use gpui::SharedString;
+
+/// A linear scale of the values, the range is extended to the nice ticks, e.g.: 0, 20, 40.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Scale {
+    pub(crate) min: f64,
+    pub(crate) max: f64,
+    pub(crate) step: f64,
+}
+
+impl Scale {
+    /// Create a scale for the values in `min..=max` with about `tick_count` ticks, the 0 is always included.
+    pub(crate) fn new(min: f64, max: f64, tick_count: usize) -> Self {
+        let (mut min, mut max) = if min.is_finite() && max.is_finite() {
+            (min.min(0.), max.max(0.))
+        } else {
+            (0., 0.)
+        };
+        if max - min <= f64::EPSILON {
+            max = min + 1.;
+        }
+
+        let step = nice_step((max - min) / tick_count.max(1) as f64);
+        min = (min / step).floor() * step;
+        max = (max / step).ceil() * step;
+
+        Self { min, max, step }
+    }
+
+    /// The tick values from the min to the max.
+    pub(crate) fn ticks(&self) -> impl Iterator<Item = f64> {
+        let Self { min, step, .. } = *self;
+        let count = ((self.max - self.min) / step).round() as usize;
+        (0..=count).map(move |ix| min + ix as f64 * step)
+    }
+
+    /// The position of the value in the range, 0 at the min and 1 at the max.
+    pub(crate) fn ratio(&self, value: f64) -> f32 {
+        ((value - self.min) / (self.max - self.min)) as f32
+    }
+}
+
+/// Round the step to 1, 2, 5 times the power of 10.
+fn nice_step(step: f64) -> f64 {
+    let base = 10f64.powf(step.log10().floor());
+    let nice = match step / base {
+        f if f <= 1. => 1.,
+        f if f <= 2. => 2.,
+        f if f <= 5. => 5.,
+        _ => 10.,
+    };
+
+    nice * base
+}
+
+/// Format the number in short, with at most 2 decimals, e.g.: `0.25`, `120`, `1.5K`, `2M`, `3B`.
+///
+/// This is the default format of the chart values.
+pub fn format_number(value: f64) -> SharedString {
+    fn trim(value: f64) -> String {
+        let text = format!("{:.2}", value);
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        if text == "-0" {
+            "0".to_string()
+        } else {
+            text.to_string()
+        }
+    }
+
+    let abs = value.abs();
+    let text = if abs >= 1e9 {
+        format!("{}B", trim(value / 1e9))
+    } else if abs >= 1e6 {
+        format!("{}M", trim(value / 1e6))
+    } else if abs >= 1e3 {
+        format!("{}K", trim(value / 1e3))
+    } else {
+        trim(value)
+    };
+
+    text.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_number, Scale};
+
+    #[test]
+    fn test_scale() {
+        let scale = Scale::new(3., 97., 5);
+        assert_eq!((scale.min, scale.max, scale.step), (0., 100., 20.));
+        assert_eq!(
+            scale.ticks().collect::<Vec<_>>(),
+            vec![0., 20., 40., 60., 80., 100.]
+        );
+        assert_eq!(scale.ratio(50.), 0.5);
+
+        let scale = Scale::new(-12., 30., 4);
+        assert_eq!((scale.min, scale.max, scale.step), (-20., 40., 20.));
+
+        // The empty or the flat data.
+        let scale = Scale::new(0., 0., 5);
+        assert_eq!((scale.min, scale.max), (0., 1.));
+        let scale = Scale::new(f64::INFINITY, f64::NEG_INFINITY, 5);
+        assert_eq!((scale.min, scale.max), (0., 1.));
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(0.).to_string(), "0");
+        assert_eq!(format_number(-0.001).to_string(), "0");
+        assert_eq!(format_number(0.25).to_string(), "0.25");
+        assert_eq!(format_number(0.30000000000000004).to_string(), "0.3");
+        assert_eq!(format_number(120.).to_string(), "120");
+        assert_eq!(format_number(1500.).to_string(), "1.5K");
+        assert_eq!(format_number(-2_000_000.).to_string(), "-2M");
+        assert_eq!(format_number(3_250_000_000.).to_string(), "3.25B");
+    }
+}
@@ -0,0 +1,116 @@
+use gpui::{Hsla, SharedString};
+
+use crate::theme::Theme;
+
+/// A named series of the values, the values are aligned with the labels of the x axis.
+#[derive(Debug, Clone)]
+pub struct Series {
+    pub(crate) name: SharedString,
+    pub(crate) values: Vec<f64>,
+    pub(crate) color: Option<Hsla>,
+}
+
+impl Series {
+    pub fn new<T: Into<f64>>(
+        name: impl Into<SharedString>,
+        values: impl IntoIterator<Item = T>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            values: values.into_iter().map(Into::into).collect(),
+            color: None,
+        }
+    }
+
+    /// Set the color of the series, default is the `chart_*` color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// The `chart_*` colors of the theme.
+pub(crate) fn palette(theme: &Theme) -> [Hsla; 5] {
+    [
+        theme.chart_1,
+        theme.chart_2,
+        theme.chart_3,
+        theme.chart_4,
+        theme.chart_5,
+    ]
+}
+
+/// The colors of the series, the theme palette is repeated if there are more series.
+pub(crate) fn series_colors(series: &[Series], theme: &Theme) -> Vec<Hsla> {
+    let palette = palette(theme);
+
+    series
+        .iter()
+        .enumerate()
+        .map(|(ix, series)| series.color.unwrap_or(palette[ix % palette.len()]))
+        .collect()
+}
+
+/// The values to plot of each series, the values are accumulated by the index if `stacked`.
+pub(crate) fn plot_values(series: &[Series], stacked: bool) -> Vec<Vec<f64>> {
+    let mut totals: Vec<f64> = vec![];
+
+    series
+        .iter()
+        .map(|series| {
+            if !stacked {
+                return series.values.clone();
+            }
+
+            series
+                .values
+                .iter()
+                .enumerate()
+                .map(|(ix, value)| {
+                    if totals.len() <= ix {
+                        totals.resize(ix + 1, 0.);
+                    }
+                    totals[ix] += value;
+                    totals[ix]
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The base value of the stacked series at the index, that is the total of the previous series.
+pub(crate) fn base_value(values: &[Vec<f64>], series_ix: usize, ix: usize) -> f64 {
+    values[..series_ix]
+        .iter()
+        .rev()
+        .find_map(|values| values.get(ix))
+        .copied()
+        .unwrap_or(0.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{base_value, plot_values, Series};
+
+    #[test]
+    fn test_plot_values() {
+        let series = vec![
+            Series::new("a", [1., 2., 3.]),
+            Series::new("b", [10., 20.]),
+            Series::new("c", [100., 200., 300.]),
+        ];
+
+        assert_eq!(plot_values(&series, false)[1], vec![10., 20.]);
+
+        let values = plot_values(&series, true);
+        assert_eq!(values[0], vec![1., 2., 3.]);
+        assert_eq!(values[1], vec![11., 22.]);
+        assert_eq!(values[2], vec![111., 222., 303.]);
+
+        assert_eq!(base_value(&values, 0, 1), 0.);
+        assert_eq!(base_value(&values, 1, 1), 2.);
+        assert_eq!(base_value(&values, 2, 1), 22.);
+        // The previous series is shorter.
+        assert_eq!(base_value(&values, 2, 2), 3.);
+    }
+}
@@ -0,0 +1,138 @@
+use gpui::{
+    canvas, point, prelude::FluentBuilder as _, px, Bounds, Hsla, IntoElement, Path, Pixels,
+    Refineable as _, RenderOnce, StyleRefinement, Styled, WindowContext,
+};
+
+use crate::theme::ActiveTheme as _;
+
+use super::{
+    paint::{paint_dot, paint_polyline},
+    Series,
+};
+
+/// A tiny line chart without the axes, to show the trend in a table cell or the status bar.
+///
+/// ```ignore
+/// Sparkline::new([3., 5., 2., 8., 6.]).area(true)
+/// ```
+#[derive(IntoElement)]
+pub struct Sparkline {
+    style: StyleRefinement,
+    values: Vec<f64>,
+    color: Option<Hsla>,
+    area: bool,
+}
+
+impl Sparkline {
+    pub fn new<T: Into<f64>>(values: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            style: StyleRefinement::default(),
+            values: values.into_iter().map(Into::into).collect(),
+            color: None,
+            area: false,
+        }
+        .w(px(80.))
+        .h(px(24.))
+    }
+
+    /// Set the color of the line, default is the `chart_1` color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Fill the area under the line, default is false.
+    pub fn area(mut self, area: bool) -> Self {
+        self.area = area;
+        self
+    }
+}
+
+impl From<Series> for Sparkline {
+    fn from(series: Series) -> Self {
+        let color = series.color;
+        Self::new(series.values).when_some(color, |this, color| this.color(color))
+    }
+}
+
+impl Styled for Sparkline {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Sparkline {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let color = self.color.unwrap_or(cx.theme().chart_1);
+        let values = self.values;
+        let area = self.area;
+
+        canvas(
+            |_, _| {},
+            move |bounds, _, cx| paint_sparkline(bounds, &values, color, area, cx),
+        )
+        .map(|mut this| {
+            this.style().refine(&self.style);
+            this
+        })
+    }
+}
+
+fn paint_sparkline(
+    bounds: Bounds<Pixels>,
+    values: &[f64],
+    color: Hsla,
+    area: bool,
+    cx: &mut WindowContext,
+) {
+    let values = values
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .collect::<Vec<_>>();
+    if values.is_empty() {
+        return;
+    }
+
+    let (min, max) = values
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(min, max), value| {
+            (min.min(*value), max.max(*value))
+        });
+
+    // Keep the space for the line width and the dot.
+    let inset = px(2.);
+    let (left, width) = (bounds.left() + inset, bounds.size.width - inset * 2.);
+    let (bottom, height) = (bounds.bottom() - inset, bounds.size.height - inset * 2.);
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(ix, value)| {
+            let x = if values.len() > 1 {
+                left + width * (ix as f32 / (values.len() - 1) as f32)
+            } else {
+                left + width
+            };
+            let ratio = if max > min {
+                ((value - min) / (max - min)) as f32
+            } else {
+                0.5
+            };
+            point(x, bottom - height * ratio)
+        })
+        .collect::<Vec<_>>();
+
+    if area && points.len() > 1 {
+        let mut path = Path::new(point(points[0].x, bottom));
+        for point in &points {
+            path.line_to(*point);
+        }
+        path.line_to(point(points[points.len() - 1].x, bottom));
+        cx.paint_path(path, color.opacity(0.2));
+    }
+
+    paint_polyline(&points, px(1.5), color, cx);
+    if let Some(last) = points.last() {
+        paint_dot(*last, px(4.), color, cx);
+    }
+}
@@ -0,0 +1,185 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, ElementId, IntoElement, ParentElement as _, RenderOnce,
+    SharedString, StyleRefinement, Styled, WindowContext,
+};
+
+use crate::{
+    card::Card, h_flex, theme::ActiveTheme as _, v_flex, Icon, IconName, Sizable as _,
+    StyledExt as _,
+};
+
+use super::Sparkline;
+
+/// A card to show a key metric, with the change to the previous period and the trend.
+///
+/// ```ignore
+/// Stat::new("revenue")
+///     .title("Total Revenue")
+///     .value("$45,231.89")
+///     .delta(20.1)
+///     .description("from last month")
+///     .sparkline(Sparkline::new([12., 18., 15., 24., 29.]))
+/// ```
+#[derive(IntoElement)]
+pub struct Stat {
+    base: Card,
+    title: SharedString,
+    value: SharedString,
+    delta: Option<f64>,
+    inverted: bool,
+    description: Option<SharedString>,
+    icon: Option<Icon>,
+    sparkline: Option<Sparkline>,
+}
+
+impl Stat {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            base: Card::new(id),
+            title: SharedString::default(),
+            value: SharedString::default(),
+            delta: None,
+            inverted: false,
+            description: None,
+            icon: None,
+            sparkline: None,
+        }
+    }
+
+    /// Set the title of the metric.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the formatted value of the metric.
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Set the percent change, e.g.: `12.5` for +12.5%.
+    pub fn delta(mut self, percent: f64) -> Self {
+        self.delta = Some(percent).filter(|percent| percent.is_finite());
+        self
+    }
+
+    /// Set the percent change from the previous value to the current value, see [`percent_change`].
+    pub fn change(mut self, current: f64, previous: f64) -> Self {
+        self.delta = percent_change(current, previous);
+        self
+    }
+
+    /// Set true if the decrease is good, e.g.: the bounce rate, default is false.
+    pub fn inverted(mut self, inverted: bool) -> Self {
+        self.inverted = inverted;
+        self
+    }
+
+    /// Set the description after the delta, e.g.: "from last month".
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the icon at the right of the title.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the sparkline at the right of the value to show the trend.
+    pub fn sparkline(mut self, sparkline: impl Into<Sparkline>) -> Self {
+        self.sparkline = Some(sparkline.into());
+        self
+    }
+}
+
+/// The percent change from the `previous` to the `current`, `None` if the `previous` is zero.
+pub fn percent_change(current: f64, previous: f64) -> Option<f64> {
+    if previous == 0. || !previous.is_finite() || !current.is_finite() {
+        return None;
+    }
+
+    Some((current - previous) / previous.abs() * 100.)
+}
+
+impl Styled for Stat {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Stat {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme();
+        let muted_foreground = theme.muted_foreground;
+
+        let delta = self.delta.map(|delta| {
+            let (icon, color) = if delta == 0. {
+                (IconName::Minus, muted_foreground)
+            } else if (delta > 0.) != self.inverted {
+                (icon_for(delta), crate::green_500())
+            } else {
+                (icon_for(delta), crate::red_500())
+            };
+
+            h_flex()
+                .gap_0p5()
+                .text_color(color)
+                .child(Icon::new(icon).xsmall())
+                .child(format!("{:+.1}%", delta))
+        });
+
+        self.base.child(
+            v_flex()
+                .gap_2()
+                .child(
+                    h_flex()
+                        .justify_between()
+                        .gap_2()
+                        .text_color(muted_foreground)
+                        .child(div().text_sm().font_medium().child(self.title))
+                        .when_some(self.icon, |this, icon| this.child(icon.small())),
+                )
+                .child(
+                    h_flex()
+                        .items_end()
+                        .justify_between()
+                        .gap_4()
+                        .child(div().text_2xl().font_bold().child(self.value))
+                        .children(self.sparkline),
+                )
+                .when(delta.is_some() || self.description.is_some(), |this| {
+                    this.child(h_flex().gap_1().text_xs().children(delta).when_some(
+                        self.description,
+                        |this, description| {
+                            this.child(div().text_color(muted_foreground).child(description))
+                        },
+                    ))
+                }),
+        )
+    }
+}
+
+fn icon_for(delta: f64) -> IconName {
+    if delta > 0. {
+        IconName::ArrowUp
+    } else {
+        IconName::ArrowDown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::percent_change;
+
+    #[test]
+    fn test_percent_change() {
+        assert_eq!(percent_change(120., 100.), Some(20.));
+        assert_eq!(percent_change(75., 100.), Some(-25.));
+        assert_eq!(percent_change(-50., -100.), Some(50.));
+        assert_eq!(percent_change(10., 0.), None);
+        assert_eq!(percent_change(f64::NAN, 10.), None);
+    }
+}
@@ -1,7 +1,9 @@
+use std::rc::Rc;
+
 use gpui::{
-    div, prelude::FluentBuilder as _, relative, svg, ElementId, InteractiveElement, IntoElement,
-    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled as _,
-    WindowContext,
+    actions, div, prelude::FluentBuilder as _, relative, svg, AppContext, ElementId, FocusHandle,
+    InteractiveElement, IntoElement, KeyBinding, ParentElement, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled as _, WindowContext,
 };
 
 use crate::{
@@ -10,13 +12,26 @@ use crate::{
     v_flex, Disableable, IconName, Selectable,
 };
 
+actions!(checkbox, [Toggle]);
+
+const CONTEXT: &str = "Checkbox";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([KeyBinding::new("space", Toggle, Some(CONTEXT))]);
+}
+
+type OnChange = Rc<dyn Fn(&bool, &mut WindowContext) + 'static>;
+
+/// A Checkbox element, supports checked, unchecked and indeterminate states.
 #[derive(IntoElement)]
 pub struct Checkbox {
     id: ElementId,
     checked: bool,
+    indeterminate: bool,
     disabled: bool,
     label: Option<SharedString>,
-    on_click: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
+    focus_handle: Option<FocusHandle>,
+    on_change: Option<OnChange>,
 }
 
 impl Checkbox {
@@ -24,9 +39,11 @@ impl Checkbox {
         Self {
             id: id.into(),
             checked: false,
+            indeterminate: false,
             disabled: false,
             label: None,
-            on_click: None,
+            focus_handle: None,
+            on_change: None,
         }
     }
 
@@ -40,10 +57,33 @@ impl Checkbox {
         self
     }
 
-    pub fn on_click(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
-        self.on_click = Some(Box::new(handler));
+    /// Set the checkbox to the indeterminate state, default: false
+    ///
+    /// The indeterminate state takes precedence over `checked` when rendering,
+    /// toggling an indeterminate checkbox will always change it to checked.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
         self
     }
+
+    /// Set the focus handle to allow toggling the checkbox by the keyboard (Space).
+    pub fn focus_handle(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
+    /// Set the callback to be called when the checked state changes.
+    ///
+    /// The first argument is the new checked state.
+    pub fn on_change(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Returns the next checked state after toggling.
+    fn toggled(&self) -> bool {
+        self.indeterminate || !self.checked
+    }
 }
 
 impl Disableable for Checkbox {
@@ -68,6 +108,12 @@ impl RenderOnce for Checkbox {
         let theme = cx.theme();
 
         let group_id = format!("checkbox_group_{:?}", self.id);
+        let filled = self.checked || self.indeterminate;
+        let new_checked = self.toggled();
+        let is_focused = self
+            .focus_handle
+            .as_ref()
+            .map_or(false, |handle| handle.is_focused(cx));
 
         let (color, icon_color) = if self.disabled {
             (
@@ -78,12 +124,24 @@ impl RenderOnce for Checkbox {
             (theme.primary, theme.primary_foreground)
         };
 
+        let icon = if self.indeterminate {
+            Some(IconName::Minus)
+        } else if self.checked {
+            Some(IconName::Check)
+        } else {
+            None
+        };
+
         h_flex()
             .id(self.id)
+            .key_context(CONTEXT)
             .group(group_id.clone())
             .gap_2()
             .items_center()
             .line_height(relative(1.))
+            .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                this.track_focus(focus_handle)
+            })
             .child(
                 v_flex()
                     .relative()
@@ -92,10 +150,13 @@ impl RenderOnce for Checkbox {
                     .rounded_sm()
                     .size_4()
                     .flex_shrink_0()
-                    .map(|this| match self.checked {
+                    .map(|this| match filled {
                         false => this.bg(theme.transparent),
                         _ => this.bg(color),
                     })
+                    .when(is_focused && !self.disabled, |this| {
+                        this.border_color(theme.ring)
+                    })
                     .group_hover(group_id, |this| {
                         if self.disabled {
                             return this;
@@ -110,10 +171,7 @@ impl RenderOnce for Checkbox {
                             .left_px()
                             .size_3()
                             .text_color(icon_color)
-                            .map(|this| match self.checked {
-                                true => this.path(IconName::Check.path()),
-                                _ => this,
-                            }),
+                            .when_some(icon, |this, icon| this.path(icon.path())),
                     ),
             )
             .map(|this| {
@@ -135,11 +193,17 @@ impl RenderOnce for Checkbox {
                     .text_color(cx.theme().muted_foreground)
             })
             .when_some(
-                self.on_click.filter(|_| !self.disabled),
-                |this, on_click| {
-                    this.on_click(move |_, cx| {
-                        let checked = !self.checked;
-                        on_click(&checked, cx);
+                self.on_change.filter(|_| !self.disabled),
+                |this, on_change| {
+                    this.on_action({
+                        let on_change = on_change.clone();
+                        move |_: &Toggle, cx| {
+                            on_change(&new_checked, cx);
+                            cx.refresh()
+                        }
+                    })
+                    .on_click(move |_, cx| {
+                        on_change(&new_checked, cx);
                         cx.refresh()
                     })
                 },
@@ -7,7 +7,7 @@ use gpui::{
     Position, Stateful, Style, View, ViewContext, WindowContext,
 };
 
-use crate::popup_menu::PopupMenu;
+use crate::{popup_menu::PopupMenu, tab::Tab};
 
 pub fn init(_cx: &mut AppContext) {}
 
@@ -22,6 +22,7 @@ pub trait ContextMenuExt: ParentElement + Sized {
 
 impl<E> ContextMenuExt for Stateful<E> where E: ParentElement {}
 impl<E> ContextMenuExt for Focusable<E> where E: ParentElement {}
+impl ContextMenuExt for Tab {}
 
 pub struct ContextMenu {
     id: ElementId,
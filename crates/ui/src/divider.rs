@@ -1,15 +1,22 @@
 use gpui::{
-    div, prelude::FluentBuilder as _, px, Axis, Div, IntoElement, ParentElement, RenderOnce,
-    SharedString, Styled,
+    div, prelude::FluentBuilder as _, px, Axis, Div, Hsla, IntoElement, ParentElement, Pixels,
+    RenderOnce, SharedString, Styled,
 };
 
 use crate::theme::ActiveTheme;
 
+/// A horizontal or vertical line to separate the content, with an optional centered label.
+///
+/// ```ignore
+/// Divider::horizontal().label("OR")
+/// ```
 #[derive(IntoElement)]
 pub struct Divider {
     base: Div,
     label: Option<SharedString>,
     axis: Axis,
+    inset: Pixels,
+    color: Option<Hsla>,
 }
 
 impl Divider {
@@ -18,6 +25,8 @@ impl Divider {
             base: div(),
             axis: Axis::Vertical,
             label: None,
+            inset: px(0.),
+            color: None,
         }
     }
 
@@ -26,6 +35,8 @@ impl Divider {
             base: div(),
             axis: Axis::Horizontal,
             label: None,
+            inset: px(0.),
+            color: None,
         }
     }
 
@@ -33,6 +44,18 @@ impl Divider {
         self.label = Some(label.into());
         self
     }
+
+    /// Set the inset of the line on both ends, e.g.: to align with the indented content.
+    pub fn inset(mut self, inset: impl Into<Pixels>) -> Self {
+        self.inset = inset.into();
+        self
+    }
+
+    /// Set the color of the line, default is the border color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
 }
 
 impl Styled for Divider {
@@ -44,36 +67,51 @@ impl Styled for Divider {
 impl RenderOnce for Divider {
     fn render(self, cx: &mut gpui::WindowContext) -> impl gpui::IntoElement {
         let theme = cx.theme();
+        let color = self.color.unwrap_or(theme.border);
+        let axis = self.axis;
+        let inset = self.inset;
+        let has_label = self.label.is_some();
+
+        // The inset is applied to the lines, so it won't override the padding of the Divider.
+        let line = move |first: bool, last: bool| {
+            div()
+                .flex_1()
+                .map(|this| match axis {
+                    Axis::Vertical => this
+                        .w(px(1.))
+                        .when(first, |this| this.mt(inset))
+                        .when(last, |this| this.mb(inset)),
+                    Axis::Horizontal => this
+                        .h(px(1.))
+                        .when(first, |this| this.ml(inset))
+                        .when(last, |this| this.mr(inset)),
+                })
+                .bg(color)
+        };
 
         self.base
             .flex()
             .flex_shrink_0()
             .items_center()
             .justify_center()
-            .map(|this| match self.axis {
-                Axis::Vertical => this.h_full(),
+            .map(|this| match axis {
+                Axis::Vertical => this.flex_col().h_full(),
                 Axis::Horizontal => this.w_full(),
             })
-            .child(
-                div()
-                    .absolute()
-                    .map(|this| match self.axis {
-                        Axis::Vertical => this.w(px(1.)).h_full(),
-                        Axis::Horizontal => this.h(px(1.)).w_full(),
-                    })
-                    .bg(cx.theme().border),
-            )
+            .child(line(true, !has_label))
             .when_some(self.label, |this, label| {
                 this.child(
                     div()
-                        .px_2()
-                        .py_1()
-                        .mx_auto()
+                        .flex_none()
+                        .map(|this| match axis {
+                            Axis::Vertical => this.py_2(),
+                            Axis::Horizontal => this.px_2(),
+                        })
                         .text_xs()
-                        .bg(cx.theme().background)
                         .text_color(theme.muted_foreground)
                         .child(label),
                 )
+                .child(line(false, true))
             })
     }
 }
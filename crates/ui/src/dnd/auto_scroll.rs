@@ -0,0 +1,140 @@
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use gpui::{point, px, Axis, Bounds, Pixels, Point, ScrollHandle, Task, ViewContext};
+use smol::Timer;
+
+/// The distance to the edges of the container to start auto scrolling while dragging.
+const EDGE_SIZE: Pixels = Pixels(32.);
+/// The max distance to scroll per frame while dragging near the edges.
+const MAX_SPEED: Pixels = Pixels(12.);
+const FRAME_DURATION: Duration = Duration::from_millis(16);
+
+/// Return the distance to scroll per frame by the `position` near the `start` or `end` edges,
+/// negative to scroll to the start, zero if not near the edges.
+///
+/// The position out of the edges is also near, to keep scrolling when the cursor overshoots a little.
+fn edge_speed(position: Pixels, start: Pixels, end: Pixels) -> Pixels {
+    if position < start - EDGE_SIZE || position > end + EDGE_SIZE {
+        px(0.)
+    } else if position < start + EDGE_SIZE {
+        -MAX_SPEED * ((start + EDGE_SIZE - position) / EDGE_SIZE).min(1.)
+    } else if position > end - EDGE_SIZE {
+        MAX_SPEED * ((position - end + EDGE_SIZE) / EDGE_SIZE).min(1.)
+    } else {
+        px(0.)
+    }
+}
+
+/// Scroll a container while dragging near its edges, faster when closer to the edges.
+///
+/// Call [`AutoScroll::update`] in the drag move listener of the container,
+/// the scrolling is stopped when leaving the edges, or the drag is ended.
+pub struct AutoScroll {
+    /// The distance to scroll per frame, shared with the scrolling task.
+    speed: Rc<Cell<Pixels>>,
+    _task: Task<()>,
+}
+
+impl AutoScroll {
+    pub fn new() -> Self {
+        Self {
+            speed: Rc::new(Cell::new(px(0.))),
+            _task: Task::ready(()),
+        }
+    }
+
+    /// Update the speed by the drag `position` over the `bounds` of the container.
+    ///
+    /// The `scroll` is called each frame with the distance along the `axis`, positive to scroll down or right.
+    pub fn update<V: 'static>(
+        &mut self,
+        axis: Axis,
+        position: Point<Pixels>,
+        bounds: Bounds<Pixels>,
+        cx: &mut ViewContext<V>,
+        scroll: impl Fn(&mut V, Pixels, &mut ViewContext<V>) + 'static,
+    ) {
+        // Only scroll by the position in front of the container, not beside it.
+        let speed = match axis {
+            Axis::Vertical if position.x >= bounds.left() && position.x <= bounds.right() => {
+                edge_speed(position.y, bounds.top(), bounds.bottom())
+            }
+            Axis::Horizontal if position.y >= bounds.top() && position.y <= bounds.bottom() => {
+                edge_speed(position.x, bounds.left(), bounds.right())
+            }
+            _ => px(0.),
+        };
+
+        let was_scrolling = self.is_scrolling();
+        self.speed.set(speed);
+        if was_scrolling || speed == px(0.) {
+            return;
+        }
+
+        let speed = self.speed.clone();
+        self._task = cx.spawn(|this, mut cx| async move {
+            loop {
+                Timer::after(FRAME_DURATION).await;
+                let scrolling = this.update(&mut cx, |this, cx| {
+                    let distance = speed.get();
+                    if distance == px(0.) || !cx.has_active_drag() {
+                        return false;
+                    }
+
+                    scroll(this, distance, cx);
+                    cx.notify();
+                    true
+                });
+                if !matches!(scrolling, Ok(true)) {
+                    speed.set(px(0.));
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Stop scrolling, e.g.: after dropped.
+    pub fn stop(&mut self) {
+        self.speed.set(px(0.));
+    }
+
+    pub fn is_scrolling(&self) -> bool {
+        self.speed.get() != px(0.)
+    }
+}
+
+impl Default for AutoScroll {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Scroll the `handle` by the distance along the `axis`, positive to scroll down or right.
+///
+/// The offset is clamped to the content size when the scrolled element is painted.
+pub fn scroll_by(handle: &ScrollHandle, axis: Axis, distance: Pixels) {
+    let offset = handle.offset();
+    let offset = match axis {
+        Axis::Vertical => point(offset.x, (offset.y - distance).min(px(0.))),
+        Axis::Horizontal => point((offset.x - distance).min(px(0.)), offset.y),
+    };
+    handle.set_offset(offset);
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::edge_speed;
+
+    #[test]
+    fn test_edge_speed() {
+        assert_eq!(edge_speed(px(100.), px(0.), px(200.)), px(0.));
+        assert_eq!(edge_speed(px(16.), px(0.), px(200.)), px(-6.));
+        assert_eq!(edge_speed(px(-20.), px(0.), px(200.)), px(-12.));
+        assert_eq!(edge_speed(px(184.), px(0.), px(200.)), px(6.));
+        assert_eq!(edge_speed(px(220.), px(0.), px(200.)), px(12.));
+        assert_eq!(edge_speed(px(-50.), px(0.), px(200.)), px(0.));
+        assert_eq!(edge_speed(px(300.), px(0.), px(200.)), px(0.));
+    }
+}
@@ -0,0 +1,44 @@
+use std::rc::Rc;
+
+use gpui::{AnyElement, EntityId, StatefulInteractiveElement, VisualContext as _, WindowContext};
+
+use super::DragPreview;
+
+/// A typed value being dragged, with the entity that the drag is started from.
+///
+/// The drag move and drop events are received by all the listeners of the same type,
+/// use [`DragItem::is_from`] to ignore the items dragged from the other components.
+#[derive(Clone)]
+pub struct DragItem<T> {
+    pub source: EntityId,
+    pub value: T,
+}
+
+impl<T> DragItem<T> {
+    pub fn new(source: EntityId, value: T) -> Self {
+        Self { source, value }
+    }
+
+    /// Return true if the item is dragged from the entity.
+    pub fn is_from(&self, entity_id: EntityId) -> bool {
+        self.source == entity_id
+    }
+}
+
+/// Extends the stateful elements to start dragging a [`DragItem`].
+pub trait DraggableExt: StatefulInteractiveElement + Sized {
+    /// Drag the `item`, with a [`DragPreview`] of the `preview` content following the cursor.
+    fn on_drag_item<T: Clone + 'static>(
+        self,
+        item: DragItem<T>,
+        preview: impl Fn(&T, &mut WindowContext) -> AnyElement + 'static,
+    ) -> Self {
+        let preview = Rc::new(preview);
+        self.on_drag(item, move |drag, cx| {
+            let (value, preview) = (drag.value.clone(), preview.clone());
+            cx.new_view(|_| DragPreview::new(move |cx| preview(&value, cx)))
+        })
+    }
+}
+
+impl<E: StatefulInteractiveElement> DraggableExt for E {}
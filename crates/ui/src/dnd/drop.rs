@@ -0,0 +1,43 @@
+use std::rc::Rc;
+
+use gpui::{InteractiveElement, Styled as _, WindowContext};
+
+use crate::theme::ActiveTheme as _;
+
+/// Extends the elements to accept the dropped values.
+pub trait DroppableExt: InteractiveElement + Sized {
+    /// Call `on_drop` with the dropped value of the type `T`, if `can_drop` returns true.
+    fn on_drop_item<T: 'static>(
+        self,
+        can_drop: impl Fn(&T, &WindowContext) -> bool + 'static,
+        on_drop: impl Fn(&T, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_drop(move |value: &T, cx| {
+            if can_drop(value, cx) {
+                on_drop(value, cx);
+            }
+        })
+    }
+
+    /// Same as [`DroppableExt::on_drop_item`], and highlight the element while dragging over if `can_drop` returns true.
+    fn drop_target<T: 'static>(
+        self,
+        can_drop: impl Fn(&T, &WindowContext) -> bool + 'static,
+        on_drop: impl Fn(&T, &mut WindowContext) + 'static,
+    ) -> Self {
+        let can_drop = Rc::new(can_drop);
+        self.drag_over::<T>({
+            let can_drop = can_drop.clone();
+            move |this, value, cx| {
+                if can_drop(value, cx) {
+                    this.bg(cx.theme().drop_target)
+                } else {
+                    this
+                }
+            }
+        })
+        .on_drop_item(move |value, cx| can_drop(value, cx), on_drop)
+    }
+}
+
+impl<E: InteractiveElement> DroppableExt for E {}
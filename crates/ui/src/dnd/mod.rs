@@ -0,0 +1,23 @@
+//! The drag and drop shared by the components, e.g.: reorder the list items, move the tree nodes and the dock tabs.
+//!
+//! ```ignore
+//! div()
+//!     .id(("card", ix))
+//!     .on_drag_item(DragItem::new(board_id, ix), |ix, _| {
+//!         div().child(format!("Card {}", ix)).into_any_element()
+//!     })
+//!     .drop_target(
+//!         move |drag: &DragItem<usize>, _| drag.is_from(board_id),
+//!         cx.listener(|this, drag: &DragItem<usize>, cx| this.move_card(drag.value, cx)),
+//!     )
+//! ```
+mod auto_scroll;
+mod drag;
+mod drop;
+mod preview;
+
+pub use auto_scroll::*;
+pub use drag::*;
+pub use drop::*;
+pub use preview::DragPreview;
+pub(crate) use preview::{DropAnimation, DROP_ANIMATION_DURATION};
@@ -0,0 +1,114 @@
+use std::{rc::Rc, time::Duration};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnimationExt as _, AnyElement, Bounds, ElementId,
+    IntoElement, ParentElement as _, Pixels, Point, Render, Size, Styled as _, ViewContext,
+    WindowContext,
+};
+
+use crate::{
+    animation::{ease_emphasized, motion},
+    theme::ActiveTheme,
+};
+
+/// The duration of the drop animation.
+pub(crate) const DROP_ANIMATION_DURATION: Duration = Duration::from_millis(200);
+
+type ContentBuilder = Rc<dyn Fn(&mut WindowContext) -> AnyElement + 'static>;
+
+/// A semi-transparent preview of the dragged element, that follows the cursor while dragging.
+///
+/// Use it as the view of `on_drag`, or use [`super::DraggableExt::on_drag_item`] that creates it:
+///
+/// ```ignore
+/// div().on_drag(DragFile(path), |drag, cx| {
+///     let name = drag.name();
+///     cx.new_view(|_| DragPreview::new(move |_| div().child(name.clone()).into_any_element()))
+/// })
+/// ```
+pub struct DragPreview {
+    content: ContentBuilder,
+    size: Option<Size<Pixels>>,
+}
+
+impl DragPreview {
+    pub fn new(content: impl Fn(&mut WindowContext) -> AnyElement + 'static) -> Self {
+        Self {
+            content: Rc::new(content),
+            size: None,
+        }
+    }
+
+    /// Set the size of the preview, default is the size of the content.
+    pub fn size(mut self, size: Size<Pixels>) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl Render for DragPreview {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .cursor_grabbing()
+            .opacity(0.75)
+            .rounded(px(cx.theme().radius))
+            .bg(cx.theme().background)
+            .border_1()
+            .border_color(cx.theme().drag_border)
+            .shadow_lg()
+            .overflow_hidden()
+            .when_some(self.size, |this, size| this.w(size.width).h(size.height))
+            .child((self.content)(cx))
+    }
+}
+
+/// A preview that flying from the drop position to the final position after dropped,
+/// or snapping back to the source position after the drag is canceled.
+///
+/// This is rendered by the [`crate::Root`], see [`crate::ContextModal::animate_drop`].
+#[derive(Clone)]
+pub(crate) struct DropAnimation {
+    pub(crate) id: usize,
+    pub(crate) from: Point<Pixels>,
+    pub(crate) to: Bounds<Pixels>,
+    pub(crate) content: ContentBuilder,
+}
+
+impl DropAnimation {
+    pub(crate) fn new(
+        id: usize,
+        from: Point<Pixels>,
+        to: Bounds<Pixels>,
+        content: impl Fn(&mut WindowContext) -> AnyElement + 'static,
+    ) -> Self {
+        Self {
+            id,
+            from,
+            to,
+            content: Rc::new(content),
+        }
+    }
+
+    pub(crate) fn render(&self, cx: &mut WindowContext) -> impl IntoElement {
+        let (from, to) = (self.from, self.to);
+
+        div()
+            .absolute()
+            .w(to.size.width)
+            .h(to.size.height)
+            .overflow_hidden()
+            .rounded(px(cx.theme().radius))
+            .bg(cx.theme().background)
+            .shadow_lg()
+            .child((self.content)(cx))
+            .with_animation(
+                ElementId::NamedInteger("drop-animation".into(), self.id),
+                motion(DROP_ANIMATION_DURATION, ease_emphasized(), cx),
+                move |this, delta| {
+                    this.left(from.x + (to.origin.x - from.x) * delta)
+                        .top(from.y + (to.origin.y - from.y) * delta)
+                        .opacity(0.75 + 0.25 * delta)
+                },
+            )
+    }
+}
@@ -1,12 +1,12 @@
 //! Dock is a fixed container that places at left, bottom, right of the Windows.
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use gpui::{
-    div, prelude::FluentBuilder as _, px, Axis, Element, InteractiveElement as _, IntoElement,
-    MouseMoveEvent, MouseUpEvent, ParentElement as _, Pixels, Point, Render,
-    StatefulInteractiveElement, Style, Styled as _, View, ViewContext, VisualContext as _,
-    WeakView, WindowContext,
+    deferred, div, prelude::FluentBuilder as _, px, relative, AnyElement, Axis, Element,
+    InteractiveElement as _, IntoElement, MouseMoveEvent, MouseUpEvent, ParentElement as _, Pixels,
+    Point, Render, StatefulInteractiveElement, Style, Styled as _, Task, View, ViewContext,
+    VisualContext as _, WeakView, WindowContext,
 };
 use serde::{Deserialize, Serialize};
 
@@ -16,7 +16,10 @@ use crate::{
     AxisExt as _, StyledExt,
 };
 
-use super::{DockArea, PanelView, TabPanel};
+use super::{DockArea, PanelView, TabPanel, ToggleAutoHide};
+
+/// The delay to hide the flyout after the mouse leaves the strip.
+const FLYOUT_HIDE_DELAY: Duration = Duration::from_millis(300);
 
 #[derive(Clone, Render)]
 struct ResizePanel;
@@ -62,7 +65,14 @@ pub struct Dock {
     /// The size is means the width or height of the Dock, if the placement is left or right, the size is width, otherwise the size is height.
     pub(super) size: Pixels,
     pub(super) open: bool,
+    /// If this is true, the Dock collapses to a strip at the edge,
+    /// and the panel flies out over the content on hover or click.
+    pub(super) auto_hide: bool,
+    /// The panel is flying out in auto-hide mode.
+    flyout: bool,
+    flyout_hovered: bool,
     is_resizing: bool,
+    _hide_task: Option<Task<()>>,
 }
 
 impl Dock {
@@ -85,7 +95,11 @@ impl Dock {
             panel,
             open: true,
             size: px(200.0),
+            auto_hide: false,
+            flyout: false,
+            flyout_hovered: false,
             is_resizing: false,
+            _hide_task: None,
         }
     }
 
@@ -107,9 +121,13 @@ impl Dock {
         size: Pixels,
         panel: View<TabPanel>,
         open: bool,
+        auto_hide: bool,
         cx: &mut WindowContext,
     ) -> Self {
         Self::subscribe_panel_events(dock_area.clone(), panel.clone(), cx);
+        panel.update(cx, |tab_panel, cx| {
+            tab_panel.set_visible(open && !auto_hide, cx);
+        });
 
         Self {
             placement,
@@ -117,7 +135,11 @@ impl Dock {
             panel,
             open,
             size,
+            auto_hide,
+            flyout: false,
+            flyout_hovered: false,
             is_resizing: false,
+            _hide_task: None,
         }
     }
 
@@ -137,9 +159,10 @@ impl Dock {
     }
 
     pub fn set_panels(&mut self, panels: Vec<Arc<dyn PanelView>>, cx: &mut ViewContext<Self>) {
-        self.panel.update(cx, |tab_panel, _| {
+        self.panel.update(cx, |tab_panel, cx| {
             tab_panel.panels = panels;
             tab_panel.active_ix = 0;
+            tab_panel.sync_active_panel(cx);
         });
         cx.notify();
     }
@@ -150,6 +173,7 @@ impl Dock {
 
     pub fn toggle_open(&mut self, cx: &mut ViewContext<Self>) {
         self.open = !self.open;
+        self.sync_visible(cx);
         cx.notify();
     }
 
@@ -169,9 +193,169 @@ impl Dock {
     /// Set the open state of the Dock.
     pub fn set_open(&mut self, open: bool, cx: &mut ViewContext<Self>) {
         self.open = open;
+        self.sync_visible(cx);
+        cx.notify();
+    }
+
+    /// The panels are visible if the Dock is open, and flying out in auto-hide mode.
+    fn is_visible(&self) -> bool {
+        self.open && (!self.auto_hide || self.flyout)
+    }
+
+    /// Notify the panels of the visibility, see [`super::PanelEvent::Shown`].
+    fn sync_visible(&self, cx: &mut ViewContext<Self>) {
+        let visible = self.is_visible();
+        self.panel
+            .update(cx, |tab_panel, cx| tab_panel.set_visible(visible, cx));
+    }
+
+    /// Returns true if the Dock is in auto-hide mode.
+    pub fn is_auto_hide(&self) -> bool {
+        self.auto_hide
+    }
+
+    /// Set the auto-hide mode, the Dock collapses to a strip and flies out on hover or click.
+    pub fn set_auto_hide(&mut self, auto_hide: bool, cx: &mut ViewContext<Self>) {
+        self.auto_hide = auto_hide;
+        self.flyout = false;
+        self._hide_task = None;
+        self.sync_visible(cx);
         cx.notify();
     }
 
+    fn on_action_toggle_auto_hide(&mut self, _: &ToggleAutoHide, cx: &mut ViewContext<Self>) {
+        self.set_auto_hide(!self.auto_hide, cx);
+        if let Some(dock_area) = self.dock_area.upgrade() {
+            dock_area.update(cx, |_, cx| cx.emit(super::DockEvent::LayoutChanged));
+        }
+    }
+
+    fn show_flyout(&mut self, cx: &mut ViewContext<Self>) {
+        self._hide_task = None;
+        self.flyout = true;
+        self.sync_visible(cx);
+        cx.notify();
+    }
+
+    /// Hide the flyout later, unless the mouse moves into the flyout.
+    fn hide_flyout_later(&mut self, cx: &mut ViewContext<Self>) {
+        self._hide_task = Some(cx.spawn(|this, mut cx| async move {
+            cx.background_executor().timer(FLYOUT_HIDE_DELAY).await;
+            _ = this.update(&mut cx, |this, cx| {
+                if !this.flyout_hovered {
+                    this.flyout = false;
+                    this.sync_visible(cx);
+                    cx.notify();
+                }
+            });
+        }));
+    }
+
+    /// Render the strip with the panel titles, and the flyout panel over the content.
+    fn render_auto_hide(&mut self, cx: &mut ViewContext<Self>) -> AnyElement {
+        let placement = self.placement;
+        let (panels, active_ix) = {
+            let tab_panel = self.panel.read(cx);
+            (tab_panel.panels.clone(), tab_panel.active_ix)
+        };
+
+        let strip = div()
+            .id("dock-strip")
+            .flex()
+            .gap_1()
+            .p_1()
+            .text_xs()
+            .bg(cx.theme().tab_bar)
+            .border_color(cx.theme().border)
+            .map(|this| match placement {
+                DockPlacement::Left => this.flex_col().h_full().max_w(px(120.)).border_r_1(),
+                DockPlacement::Right => this.flex_col().h_full().max_w(px(120.)).border_l_1(),
+                DockPlacement::Bottom => this.flex_row().w_full().border_t_1(),
+            })
+            .on_hover(cx.listener(|this, hovered: &bool, cx| {
+                if *hovered {
+                    this.show_flyout(cx);
+                } else {
+                    this.hide_flyout_later(cx);
+                }
+            }))
+            .children(panels.iter().enumerate().map(|(ix, panel)| {
+                div()
+                    .id(("dock-strip-item", ix))
+                    .px_1p5()
+                    .py_0p5()
+                    .rounded_md()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .cursor_pointer()
+                    .text_color(cx.theme().tab_foreground)
+                    .when(self.flyout && ix == active_ix, |this| {
+                        this.bg(cx.theme().tab_active)
+                    })
+                    .hover(|this| this.bg(cx.theme().tab_active))
+                    .child(panel.title(cx))
+                    .on_click(cx.listener(move |this, _, cx| {
+                        this.panel.update(cx, |tab_panel, cx| {
+                            tab_panel.set_active_ix(ix, cx);
+                        });
+                        this.show_flyout(cx);
+                    }))
+            }));
+
+        let flyout = self.flyout.then(|| {
+            deferred(
+                div()
+                    .id("dock-flyout")
+                    .occlude()
+                    .absolute()
+                    .bg(cx.theme().background)
+                    .border_color(cx.theme().border)
+                    .shadow_lg()
+                    .map(|this| match placement {
+                        DockPlacement::Left => this
+                            .top_0()
+                            .left(relative(1.))
+                            .h_full()
+                            .w(self.size)
+                            .border_r_1(),
+                        DockPlacement::Right => this
+                            .top_0()
+                            .right(relative(1.))
+                            .h_full()
+                            .w(self.size)
+                            .border_l_1(),
+                        DockPlacement::Bottom => this
+                            .left_0()
+                            .bottom(relative(1.))
+                            .w_full()
+                            .h(self.size)
+                            .border_t_1(),
+                    })
+                    .on_hover(cx.listener(|this, hovered: &bool, cx| {
+                        this.flyout_hovered = *hovered;
+                        if !*hovered {
+                            this.hide_flyout_later(cx);
+                        }
+                    }))
+                    .child(self.panel.clone()),
+            )
+            .with_priority(1)
+        });
+
+        div()
+            .relative()
+            .flex()
+            .on_action(cx.listener(Self::on_action_toggle_auto_hide))
+            .map(|this| match placement {
+                DockPlacement::Left | DockPlacement::Right => this.h_full(),
+                DockPlacement::Bottom => this.w_full(),
+            })
+            .child(strip)
+            .children(flyout)
+            .into_any_element()
+    }
+
     fn render_resize_handle(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let axis = self.placement.axis();
         let neg_offset = -HANDLE_PADDING;
@@ -251,13 +435,18 @@ impl Dock {
 
 impl Render for Dock {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
+        if self.auto_hide {
+            return self.render_auto_hide(cx);
+        }
+
         if !self.open && !self.placement.is_bottom() {
-            return div();
+            return div().into_any_element();
         }
 
         div()
             .relative()
             .overflow_hidden()
+            .on_action(cx.listener(Self::on_action_toggle_auto_hide))
             .map(|this| match self.placement {
                 DockPlacement::Left | DockPlacement::Right => this.h_flex().h_full().w(self.size),
                 DockPlacement::Bottom => this.w_full().h(self.size),
@@ -271,6 +460,7 @@ impl Render for Dock {
             .child(DockElement {
                 view: cx.view().clone(),
             })
+            .into_any_element()
     }
 }
 
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use gpui::{
+    AnyWindowHandle, AppContext, Bounds, FocusHandle, FocusableView, InteractiveElement as _,
+    IntoElement, ParentElement as _, Pixels, Render, Styled as _, Subscription, View, ViewContext,
+    VisualContext as _, WeakView,
+};
+
+use crate::{theme::ActiveTheme as _, v_flex};
+
+use super::{DockArea, DockEvent, DockItemState, PanelEvent, PanelView, TabPanel, ToggleFloating};
+
+/// The root view of the floating window, it holds the panels that detached from the [`DockArea`].
+///
+/// Use [`DockArea::float_panel`] to open it, and the `ToggleFloating` action or close the window to dock the panels back.
+pub struct FloatingPanel {
+    focus_handle: FocusHandle,
+    dock_area: WeakView<DockArea>,
+    pub(super) tab_panel: View<TabPanel>,
+    /// The bounds of the window on the screen.
+    pub(super) bounds: Bounds<Pixels>,
+    pub(super) window: AnyWindowHandle,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl FloatingPanel {
+    pub(super) fn new(
+        panels: Vec<Arc<dyn PanelView>>,
+        active_ix: usize,
+        bounds: Bounds<Pixels>,
+        dock_area: WeakView<DockArea>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let tab_panel = cx.new_view(|cx| {
+            let mut tab_panel = TabPanel::new(None, dock_area.clone(), cx);
+            tab_panel.floating = true;
+            tab_panel.add_panels(panels, active_ix, cx);
+            tab_panel
+        });
+
+        let _subscriptions = vec![
+            cx.subscribe(&tab_panel, |this, tab_panel, event: &PanelEvent, cx| {
+                if let PanelEvent::LayoutChanged = event {
+                    // All the panels have been closed, close the window.
+                    if tab_panel.read(cx).panels.is_empty() {
+                        this.remove_from_dock_area(cx);
+                        cx.remove_window();
+                    }
+                    this.emit_layout_changed(cx);
+                }
+            }),
+            cx.observe_window_bounds(|this, cx| {
+                this.bounds = cx.bounds();
+                this.emit_layout_changed(cx);
+            }),
+        ];
+
+        cx.on_window_should_close({
+            let view = cx.view().downgrade();
+            move |cx| {
+                _ = view.update(cx, |this, cx| this.dock_back(cx));
+                true
+            }
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            dock_area,
+            tab_panel,
+            bounds,
+            window: cx.window_handle(),
+            _subscriptions,
+        }
+    }
+
+    /// Dump the panels with the window bounds.
+    pub(super) fn dump(&self, cx: &AppContext) -> DockItemState {
+        let mut state = self.tab_panel.dump(cx);
+        state.floating_bounds = Some(self.bounds);
+        state
+    }
+
+    /// Move the panels back to the [`DockArea`].
+    fn dock_back(&mut self, cx: &mut ViewContext<Self>) {
+        let panels = self.tab_panel.update(cx, |tab_panel, _| {
+            tab_panel.active_ix = 0;
+            std::mem::take(&mut tab_panel.panels)
+        });
+
+        self.remove_from_dock_area(cx);
+        _ = self.dock_area.update(cx, |dock_area, cx| {
+            dock_area.dock_panels(panels, cx);
+        });
+    }
+
+    fn remove_from_dock_area(&mut self, cx: &mut ViewContext<Self>) {
+        let entity_id = cx.view().entity_id();
+        _ = self.dock_area.update(cx, |dock_area, _| {
+            dock_area
+                .floating_panels
+                .retain(|view| view.entity_id() != entity_id);
+        });
+    }
+
+    fn emit_layout_changed(&self, cx: &mut ViewContext<Self>) {
+        _ = self
+            .dock_area
+            .update(cx, |_, cx| cx.emit(DockEvent::LayoutChanged));
+    }
+
+    fn on_action_toggle_floating(&mut self, _: &ToggleFloating, cx: &mut ViewContext<Self>) {
+        self.dock_back(cx);
+        cx.remove_window();
+    }
+}
+
+impl FocusableView for FloatingPanel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for FloatingPanel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .id("floating-panel")
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_action_toggle_floating))
+            .size_full()
+            .bg(cx.theme().background)
+            .child(self.tab_panel.clone())
+    }
+}
@@ -5,20 +5,23 @@ use gpui::{
 
 use crate::theme::ActiveTheme as _;
 
-use super::{DockItemInfo, DockItemState, Panel, PanelEvent};
+use super::{DockItemState, Panel, PanelEvent};
 
+/// The placeholder of the panel that is not registered in the [`super::PanelRegistry`].
+///
+/// It keeps the original state, so the panel is not lost when the layout is saved again.
 pub(crate) struct InvalidPanel {
     name: SharedString,
     focus_handle: FocusHandle,
-    info: DockItemInfo,
+    state: DockItemState,
 }
 
 impl InvalidPanel {
-    pub(crate) fn new(name: &str, info: DockItemInfo, cx: &mut WindowContext) -> Self {
+    pub(crate) fn new(state: DockItemState, cx: &mut WindowContext) -> Self {
         Self {
             focus_handle: cx.focus_handle(),
-            name: SharedString::from(name.to_owned()),
-            info,
+            name: SharedString::from(state.panel_name.clone()),
+            state,
         }
     }
 }
@@ -28,9 +31,7 @@ impl Panel for InvalidPanel {
     }
 
     fn dump(&self, _cx: &AppContext) -> super::DockItemState {
-        let mut state = DockItemState::new(self);
-        state.info = self.info.clone();
-        state
+        self.state.clone()
     }
 }
 impl EventEmitter<PanelEvent> for InvalidPanel {}
@@ -0,0 +1,60 @@
+use gpui::{
+    div, AnyElement, AppContext, EventEmitter, FocusHandle, FocusableView, IntoElement as _,
+    Render, SharedString, Styled as _, WindowContext,
+};
+use rust_i18n::t;
+
+use super::{DockItemState, Panel, PanelEvent};
+
+/// The placeholder of a panel that has not been activated yet, see [`super::DockArea::lazy_panels`].
+///
+/// It shows the title saved in the state, and is replaced by the panel built from
+/// the [`super::PanelRegistry`] when the tab is first activated.
+pub(crate) struct LazyPanel {
+    focus_handle: FocusHandle,
+    pub(crate) state: DockItemState,
+}
+
+impl LazyPanel {
+    pub(crate) fn new(state: DockItemState, cx: &mut WindowContext) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            state,
+        }
+    }
+}
+
+impl Panel for LazyPanel {
+    fn panel_name(&self) -> &'static str {
+        "LazyPanel"
+    }
+
+    fn title(&self, cx: &WindowContext) -> AnyElement {
+        self.title_text(cx).into_any_element()
+    }
+
+    fn title_text(&self, _cx: &AppContext) -> SharedString {
+        match &self.state.title {
+            Some(title) => title.clone().into(),
+            None => t!("Dock.Unnamed").into(),
+        }
+    }
+
+    fn dump(&self, _cx: &AppContext) -> DockItemState {
+        self.state.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for LazyPanel {}
+
+impl FocusableView for LazyPanel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for LazyPanel {
+    fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl gpui::IntoElement {
+        div().size_full()
+    }
+}
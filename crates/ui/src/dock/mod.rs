@@ -1,28 +1,88 @@
 mod dock;
+mod floating_panel;
 mod invalid_panel;
+mod lazy_panel;
 mod panel;
 mod stack_panel;
 mod state;
 mod tab_panel;
+mod tabs_menu;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context as _, Result};
 pub use dock::*;
+pub use floating_panel::*;
 use gpui::{
-    actions, canvas, div, prelude::FluentBuilder, AnyElement, AnyView, AppContext, Axis, Bounds,
-    EventEmitter, InteractiveElement as _, IntoElement, ParentElement as _, Pixels, Render,
-    SharedString, Styled, Subscription, View, ViewContext, VisualContext, WeakView, WindowContext,
+    actions, canvas, div, impl_actions, point, prelude::FluentBuilder, px, size, AnyElement,
+    AnyView, AppContext, Axis, Bounds, EventEmitter, FocusHandle, FocusableView as _,
+    InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _, Pixels, Render,
+    SharedString, Styled, Subscription, View, ViewContext, VisualContext, WeakView, WindowBounds,
+    WindowContext, WindowKind, WindowOptions,
 };
+pub(crate) use lazy_panel::*;
 pub use panel::*;
+use rust_i18n::t;
+use serde::Deserialize;
 pub use stack_panel::*;
 pub use state::*;
-use std::sync::Arc;
+use std::{collections::VecDeque, rc::Rc, sync::Arc};
 pub use tab_panel::*;
+pub(crate) use tabs_menu::*;
+
+use crate::{popup_menu::PopupMenu, theme::ActiveTheme as _, v_flex, Root};
+
+const CONTEXT: &str = "DockArea";
 
 pub fn init(cx: &mut AppContext) {
-    cx.set_global(PanelRegistry::new());
+    PanelRegistry::init(cx);
+    cx.bind_keys([
+        KeyBinding::new("f6", FocusNextPanel, Some(CONTEXT)),
+        KeyBinding::new("shift-f6", FocusPrevPanel, Some(CONTEXT)),
+        KeyBinding::new("ctrl-tab", NextTab, Some(tab_panel::CONTEXT)),
+        KeyBinding::new("ctrl-shift-tab", PrevTab, Some(tab_panel::CONTEXT)),
+        KeyBinding::new("ctrl-alt-=", GrowPanel, Some(tab_panel::CONTEXT)),
+        KeyBinding::new("ctrl-alt--", ShrinkPanel, Some(tab_panel::CONTEXT)),
+    ]);
 }
 
-actions!(dock, [ToggleZoom, ClosePanel]);
+actions!(
+    dock,
+    [
+        ToggleZoom,
+        ClosePanel,
+        CloseOtherPanels,
+        ClosePanelsToRight,
+        ReopenClosedPanel,
+        ToggleFloating,
+        ToggleAutoHide,
+        FocusNextPanel,
+        FocusPrevPanel,
+        NextTab,
+        PrevTab,
+        GrowPanel,
+        ShrinkPanel
+    ]
+);
+
+/// Switch to the named layout, see [`DockArea::layout_menu`].
+///
+/// The DockArea doesn't handle this action, handle it in the app by [`DockArea::load_layout`]
+/// to decide what to do if the layout fails to load.
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct SwitchLayout(pub SharedString);
+
+impl_actions!(dock, [SwitchLayout]);
+
+type MigrateFn = Rc<dyn Fn(DockAreaState, &mut WindowContext) -> Result<DockAreaState>>;
+type EmptyStateBuilder = Rc<dyn Fn(&mut WindowContext) -> AnyElement>;
+
+/// The max number of the recently closed panels to reopen.
+const MAX_CLOSED_PANELS: usize = 10;
+
+/// A panel closed by the user, it is reopened in the TabPanel if it still exists.
+struct ClosedPanel {
+    state: DockItemState,
+    tab_panel: WeakView<TabPanel>,
+}
 
 pub enum DockEvent {
     /// The layout of the dock has changed, subscribers this to save the layout.
@@ -30,11 +90,14 @@ pub enum DockEvent {
     /// This event is emitted when every time the layout of the dock has changed,
     /// So it emits may be too frequently, you may want to debounce the event.
     LayoutChanged,
+    /// The active named layout has changed, see [`DockArea::active_layout`].
+    ActiveLayoutChanged,
 }
 
 /// The main area of the dock.
 pub struct DockArea {
     id: SharedString,
+    focus_handle: FocusHandle,
     /// The version is used to special the default layout, this is like the `panel_version` in `trait Panel`.
     version: Option<usize>,
     pub(crate) bounds: Bounds<Pixels>,
@@ -50,6 +113,22 @@ pub struct DockArea {
     right_dock: Option<View<Dock>>,
     /// The top zoom view of the dockarea, if any.
     zoom_view: Option<AnyView>,
+    /// The panels that detached into the floating windows.
+    pub(crate) floating_panels: Vec<View<FloatingPanel>>,
+    /// Increased when the floating windows are closed, the windows still opening with an older
+    /// generation are closed when they are ready, see [`DockArea::open_floating_window`].
+    floating_generation: usize,
+    /// The recently closed panels, the last is the most recent.
+    closed_panels: VecDeque<ClosedPanel>,
+    /// Upgrade the state saved with a different `version` before loading.
+    migrate: Option<MigrateFn>,
+    /// The element to render in the center when all the panels are closed.
+    empty_state: Option<EmptyStateBuilder>,
+    /// Build the panels of the inactive tabs on the first activation when loading the state.
+    lazy_panels: bool,
+    /// The named layouts to switch between, see [`DockArea::save_layout`].
+    layouts: Vec<DockLayout>,
+    active_layout: Option<SharedString>,
 
     _subscriptions: Vec<Subscription>,
 }
@@ -92,6 +171,31 @@ impl DockItem {
         sizes: Vec<Option<Pixels>>,
         dock_area: &WeakView<DockArea>,
         cx: &mut WindowContext,
+    ) -> Self {
+        Self::split_with_policies(axis, items, sizes, vec![], dock_area, cx)
+    }
+
+    /// Create DockItem with split layout, each item of panel have specified size and [`SizePolicy`].
+    ///
+    /// The missing policies are [`SizePolicy::Proportional`], e.g.: keep the sidebar width when the window grows:
+    ///
+    /// ```ignore
+    /// DockItem::split_with_policies(
+    ///     Axis::Horizontal,
+    ///     vec![sidebar, editor],
+    ///     vec![Some(px(240.)), None],
+    ///     vec![SizePolicy::Fixed, SizePolicy::Proportional],
+    ///     &dock_area,
+    ///     cx,
+    /// )
+    /// ```
+    pub fn split_with_policies(
+        axis: Axis,
+        items: Vec<DockItem>,
+        sizes: Vec<Option<Pixels>>,
+        policies: Vec<SizePolicy>,
+        dock_area: &WeakView<DockArea>,
+        cx: &mut WindowContext,
     ) -> Self {
         let mut items = items;
         let stack_panel = cx.new_view(|cx| {
@@ -108,6 +212,12 @@ impl DockItem {
                 stack_panel.add_panel(view.clone(), size, dock_area.clone(), cx)
             }
 
+            for (i, policy) in policies.iter().enumerate() {
+                if *policy != SizePolicy::Proportional {
+                    stack_panel.set_size_policy(i, *policy, cx);
+                }
+            }
+
             stack_panel
         });
 
@@ -162,10 +272,7 @@ impl DockItem {
         let active_ix = active_ix.unwrap_or(0);
         let tab_panel = cx.new_view(|cx| {
             let mut tab_panel = TabPanel::new(None, dock_area.clone(), cx);
-            for item in items.iter() {
-                tab_panel.add_panel(item.clone(), cx)
-            }
-            tab_panel.active_ix = active_ix;
+            tab_panel.add_panels(items.clone(), active_ix, cx);
             tab_panel
         });
 
@@ -212,10 +319,19 @@ impl DockArea {
 
         let mut this = Self {
             id: id.into(),
+            focus_handle: cx.focus_handle(),
             version,
             bounds: Bounds::default(),
             items: dock_item,
             zoom_view: None,
+            floating_panels: vec![],
+            floating_generation: 0,
+            closed_panels: VecDeque::new(),
+            migrate: None,
+            empty_state: None,
+            lazy_panels: false,
+            layouts: vec![],
+            active_layout: None,
             left_dock: None,
             right_dock: None,
             bottom_dock: None,
@@ -227,6 +343,46 @@ impl DockArea {
         this
     }
 
+    /// Set the migration hook to upgrade the state saved with a different `version`, it is called by [`DockArea::load`].
+    ///
+    /// Return an error to give up the saved state, e.g.: to reset to the default layout.
+    ///
+    /// ```ignore
+    /// DockArea::new("main", Some(2), cx).on_migrate(|mut state, _| {
+    ///     if state.version < Some(2) {
+    ///         state.bottom_dock = None;
+    ///     }
+    ///     Ok(state)
+    /// })
+    /// ```
+    pub fn on_migrate(
+        mut self,
+        f: impl Fn(DockAreaState, &mut WindowContext) -> Result<DockAreaState> + 'static,
+    ) -> Self {
+        self.migrate = Some(Rc::new(f));
+        self
+    }
+
+    /// Set the element to render in the center when all the panels are closed, e.g.: a logo and the actions to open something.
+    ///
+    /// The tabs can still be dragged into the empty center.
+    pub fn empty_state<E: IntoElement>(
+        mut self,
+        f: impl Fn(&mut WindowContext) -> E + 'static,
+    ) -> Self {
+        self.empty_state = Some(Rc::new(move |cx| f(cx).into_any_element()));
+        self
+    }
+
+    /// Set true to build the panels of the inactive tabs when they are first activated in [`DockArea::load`], default is false.
+    ///
+    /// Until then, the tab shows the title saved in the state, and the panel is kept unchanged in the dump.
+    /// The unloaded panels can be found by [`DockArea::activate_panel`], but not by [`DockArea::panel`].
+    pub fn lazy_panels(mut self, lazy: bool) -> Self {
+        self.lazy_panels = lazy;
+        self
+    }
+
     /// Set version of the dock area.
     pub fn set_version(&mut self, version: usize, cx: &mut ViewContext<Self>) {
         self.version = Some(version);
@@ -237,6 +393,7 @@ impl DockArea {
     ///
     /// This is used to render at the Center of the DockArea.
     pub fn set_root(&mut self, item: DockItem, cx: &mut ViewContext<Self>) {
+        let item = Self::wrap_root_tabs(item, cx);
         self.subscribe_item(&item, cx);
         self.items = item;
 
@@ -327,39 +484,330 @@ impl DockArea {
         }
     }
 
-    /// Load the state of the DockArea from the DockAreaState.
+    fn dock_mut(&mut self, placement: DockPlacement) -> &mut Option<View<Dock>> {
+        match placement {
+            DockPlacement::Left => &mut self.left_dock,
+            DockPlacement::Bottom => &mut self.bottom_dock,
+            DockPlacement::Right => &mut self.right_dock,
+        }
+    }
+
+    /// Add a panel to the dock of the `placement` and activate it, `None` to add to the center.
     ///
-    /// See also [DockeArea::dump].
-    pub fn load(&mut self, state: DockAreaState, cx: &mut ViewContext<Self>) -> Result<()> {
-        self.version = state.version;
+    /// The dock is created if it does not exist, and opened if it is closed.
+    /// In the center, the panel is added to the first tabs.
+    pub fn add_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        placement: Option<DockPlacement>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(placement) = placement else {
+            let first_tab_panel = self.center_tab_panels(cx).into_iter().next();
+            match first_tab_panel {
+                Some(tab_panel) => tab_panel.update(cx, |tab_panel, cx| {
+                    tab_panel.add_panel(panel, cx);
+                }),
+                None => self.dock_panels(vec![panel], cx),
+            }
+            return;
+        };
+
         let weak_self = cx.view().downgrade();
+        let dock = self
+            .dock_mut(placement)
+            .get_or_insert_with(|| cx.new_view(|cx| Dock::new(weak_self, placement, cx)))
+            .clone();
+
+        dock.update(cx, |dock, cx| {
+            dock.panel
+                .update(cx, |tab_panel, cx| tab_panel.add_panel(panel, cx));
+            dock.set_open(true, cx);
+        });
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Remove the first panel with the `panel_name`, returns false if not found.
+    pub fn remove_panel(&mut self, panel_name: &str, cx: &mut ViewContext<Self>) -> bool {
+        let Some((tab_panel, ix)) = self.find_panel_by_name(panel_name, cx) else {
+            return false;
+        };
+
+        let panel = tab_panel.update(cx, |tab_panel, cx| {
+            let panel = tab_panel.panels[ix].clone();
+            tab_panel.remove_panel(panel.clone(), cx);
+            panel
+        });
+        panel.emit_event(PanelEvent::Closed, cx);
+        true
+    }
+
+    /// Activate the first panel with the `panel_name` and focus it, returns false if not found.
+    ///
+    /// The dock of the panel is opened if it is closed.
+    pub fn activate_panel(&mut self, panel_name: &str, cx: &mut ViewContext<Self>) -> bool {
+        let Some((tab_panel, ix)) = self.find_panel_by_name(panel_name, cx) else {
+            return false;
+        };
+
+        let dock = [&self.left_dock, &self.bottom_dock, &self.right_dock]
+            .into_iter()
+            .flatten()
+            .find(|dock| dock.read(cx).panel == tab_panel)
+            .cloned();
+        if let Some(dock) = dock {
+            dock.update(cx, |dock, cx| dock.set_open(true, cx));
+        }
+
+        tab_panel.update(cx, |tab_panel, cx| tab_panel.set_active_ix(ix, cx));
+        true
+    }
+
+    /// Returns the first panel of the type `T` in the dock area, including the docks and floating windows.
+    ///
+    /// ```ignore
+    /// if let Some(panel) = dock_area.read(cx).panel::<SearchPanel>(cx) {
+    ///     panel.update(cx, |panel, cx| panel.search("foo", cx));
+    /// }
+    /// ```
+    pub fn panel<T: Panel>(&self, cx: &AppContext) -> Option<View<T>> {
+        self.tab_panels(cx).into_iter().find_map(|tab_panel| {
+            tab_panel
+                .read(cx)
+                .panels
+                .iter()
+                .find_map(|panel| panel.view().downcast::<T>().ok())
+        })
+    }
+
+    /// Returns the TabPanel and the index of the first panel with the `panel_name`.
+    fn find_panel_by_name(
+        &self,
+        panel_name: &str,
+        cx: &WindowContext,
+    ) -> Option<(View<TabPanel>, usize)> {
+        self.tab_panels(cx).into_iter().find_map(|tab_panel| {
+            let ix = tab_panel.read(cx).panels.iter().position(|panel| {
+                panel.panel_name(cx) == panel_name
+                    || panel
+                        .view()
+                        .downcast::<LazyPanel>()
+                        .is_ok_and(|lazy_panel| lazy_panel.read(cx).state.panel_name == panel_name)
+            })?;
+            Some((tab_panel, ix))
+        })
+    }
+
+    /// All the TabPanels in the center, docks and floating windows.
+    fn tab_panels(&self, cx: &AppContext) -> Vec<View<TabPanel>> {
+        let mut tab_panels = self.center_tab_panels(cx);
+        tab_panels.extend(
+            [&self.left_dock, &self.bottom_dock, &self.right_dock]
+                .into_iter()
+                .flatten()
+                .map(|dock| dock.read(cx).panel.clone()),
+        );
+        tab_panels.extend(
+            self.floating_panels
+                .iter()
+                .map(|floating_panel| floating_panel.read(cx).tab_panel.clone()),
+        );
+        tab_panels
+    }
 
-        if let Some(left_dock) = state.left_dock {
-            match left_dock.to_dock(weak_self.clone(), cx) {
-                Ok(dock) => self.left_dock = Some(dock),
-                Err(err) => bail!("failed to load left dock: {}", err),
+    fn center_tab_panels(&self, cx: &AppContext) -> Vec<View<TabPanel>> {
+        fn collect(view: AnyView, cx: &AppContext, tab_panels: &mut Vec<View<TabPanel>>) {
+            match view.downcast::<TabPanel>() {
+                Ok(tab_panel) => tab_panels.push(tab_panel),
+                Err(view) => {
+                    if let Ok(stack_panel) = view.downcast::<StackPanel>() {
+                        for panel in stack_panel.read(cx).panels.iter() {
+                            collect(panel.view(), cx, tab_panels);
+                        }
+                    }
+                }
             }
         }
 
-        if let Some(right_dock) = state.right_dock {
-            match right_dock.to_dock(weak_self.clone(), cx) {
-                Ok(dock) => self.right_dock = Some(dock),
-                Err(err) => bail!("failed to load right dock: {}", err),
+        let mut tab_panels = vec![];
+        collect(self.items.view().view(), cx, &mut tab_panels);
+        tab_panels
+    }
+
+    /// Load the state of the DockArea from the DockAreaState.
+    ///
+    /// See also [DockeArea::dump].
+    ///
+    /// The unregistered panels are rendered as placeholders and kept in the next dump.
+    /// If the state `version` is different, it is upgraded by the [`DockArea::on_migrate`] hook first.
+    ///
+    /// Nothing is changed if an error is returned, so the caller can fall back to the default layout.
+    pub fn load(&mut self, mut state: DockAreaState, cx: &mut ViewContext<Self>) -> Result<()> {
+        if state.schema_version > DockAreaState::SCHEMA_VERSION {
+            bail!(
+                "unsupported layout schema version: {}, expected <= {}",
+                state.schema_version,
+                DockAreaState::SCHEMA_VERSION
+            );
+        }
+
+        if state.version != self.version {
+            if let Some(migrate) = self.migrate.clone() {
+                state = migrate(state, cx).context("failed to migrate layout")?;
+                state.version = self.version;
             }
         }
 
-        if let Some(bottom_dock) = state.bottom_dock {
-            match bottom_dock.to_dock(weak_self.clone(), cx) {
-                Ok(dock) => self.bottom_dock = Some(dock),
-                Err(err) => bail!("failed to load bottom dock: {}", err),
+        let weak_self = cx.view().downgrade();
+        let lazy = self.lazy_panels;
+        let mut load_dock = |dock: Option<DockState>, name: &str| -> Result<Option<View<Dock>>> {
+            dock.map(|dock| dock.build_dock(weak_self.clone(), lazy, cx))
+                .transpose()
+                .with_context(|| format!("failed to load {} dock", name))
+        };
+
+        let left_dock = load_dock(state.left_dock, "left")?;
+        let right_dock = load_dock(state.right_dock, "right")?;
+        let bottom_dock = load_dock(state.bottom_dock, "bottom")?;
+
+        // The panels in the floating windows are replaced by the state.
+        self.close_floating_windows(cx);
+
+        self.version = state.version;
+        self.left_dock = left_dock;
+        self.right_dock = right_dock;
+        self.bottom_dock = bottom_dock;
+
+        let item = state.center.build_item(weak_self.clone(), lazy, cx);
+        self.items = Self::wrap_root_tabs(item, cx);
+
+        for item_state in state.floating {
+            let active_ix = item_state.info.active_index().unwrap_or(0);
+            if let DockItem::Tabs { items, .. } = item_state.build_item(weak_self.clone(), lazy, cx)
+            {
+                self.open_floating_window(items, active_ix, item_state.floating_bounds, cx);
             }
         }
 
-        self.items = state.center.to_item(weak_self, cx);
+        Ok(())
+    }
 
+    fn close_floating_windows(&mut self, cx: &mut ViewContext<Self>) {
+        // Discard the windows that are not opened yet.
+        self.floating_generation += 1;
+        for floating_panel in std::mem::take(&mut self.floating_panels) {
+            let window = floating_panel.read(cx).window;
+            _ = window.update(cx, |_, cx| cx.remove_window());
+        }
+    }
+
+    /// Save the current layout with the `name`, and make it the active layout.
+    ///
+    /// The layout with the same name is replaced, see [`DockArea::load_layout`] to switch to it.
+    pub fn save_layout(&mut self, name: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        let name = name.into();
+        let state = self.dump(cx);
+        match self.layouts.iter_mut().find(|layout| layout.name == name) {
+            Some(layout) => layout.state = state,
+            None => self.layouts.push(DockLayout::new(name.clone(), state)),
+        }
+        self.set_active_layout(Some(name), cx);
+    }
+
+    /// Load the layout saved by [`DockArea::save_layout`], and make it the active layout.
+    pub fn load_layout(&mut self, name: &str, cx: &mut ViewContext<Self>) -> Result<()> {
+        let Some(layout) = self
+            .layouts
+            .iter()
+            .find(|layout| layout.name.as_ref() == name)
+        else {
+            bail!("layout `{}` not found", name);
+        };
+
+        let name = layout.name.clone();
+        self.load(layout.state.clone(), cx)?;
+        self.set_active_layout(Some(name), cx);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
         Ok(())
     }
 
+    /// Remove the layout, returns false if it does not exist.
+    pub fn remove_layout(&mut self, name: &str, cx: &mut ViewContext<Self>) -> bool {
+        let len = self.layouts.len();
+        self.layouts.retain(|layout| layout.name.as_ref() != name);
+        if self.active_layout.as_deref() == Some(name) {
+            self.set_active_layout(None, cx);
+        }
+        cx.notify();
+        self.layouts.len() != len
+    }
+
+    /// Returns the named layouts, e.g.: to save them with the app settings.
+    pub fn layouts(&self) -> &[DockLayout] {
+        &self.layouts
+    }
+
+    /// Replace the named layouts, e.g.: restore them from the app settings, the current layout is not changed.
+    pub fn set_layouts(&mut self, layouts: Vec<DockLayout>, cx: &mut ViewContext<Self>) {
+        self.layouts = layouts;
+        if let Some(name) = self.active_layout.clone() {
+            if !self.layouts.iter().any(|layout| layout.name == name) {
+                self.set_active_layout(None, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// The name of the layout last saved or loaded.
+    pub fn active_layout(&self) -> Option<&SharedString> {
+        self.active_layout.as_ref()
+    }
+
+    fn set_active_layout(&mut self, name: Option<SharedString>, cx: &mut ViewContext<Self>) {
+        if self.active_layout != name {
+            self.active_layout = name;
+            cx.emit(DockEvent::ActiveLayoutChanged);
+        }
+    }
+
+    /// Add the named layouts to the menu to switch between them, the active one is checked.
+    ///
+    /// The menu items dispatch the [`SwitchLayout`] action, handle it to load the layout:
+    ///
+    /// ```ignore
+    /// Button::new("layouts")
+    ///     .label("Layouts")
+    ///     .popup_menu(move |menu, cx| dock_area.read(cx).layout_menu(menu))
+    ///
+    /// div().on_action(cx.listener(|this, action: &SwitchLayout, cx| {
+    ///     let result = this.dock_area.update(cx, |dock_area, cx| dock_area.load_layout(&action.0, cx));
+    /// }))
+    /// ```
+    pub fn layout_menu(&self, menu: PopupMenu) -> PopupMenu {
+        self.layouts
+            .iter()
+            .fold(menu.track_focus(&self.focus_handle), |menu, layout| {
+                menu.menu_with_check(
+                    layout.name.clone(),
+                    self.active_layout.as_ref() == Some(&layout.name),
+                    Box::new(SwitchLayout(layout.name.clone())),
+                )
+            })
+    }
+
+    /// The tabs must be in a stack to split by drag and drop, so wrap the root tabs into a stack.
+    fn wrap_root_tabs(item: DockItem, cx: &mut ViewContext<Self>) -> DockItem {
+        match item {
+            DockItem::Tabs { .. } => {
+                let weak_self = cx.view().downgrade();
+                DockItem::split(Axis::Horizontal, vec![item], &weak_self, cx)
+            }
+            item => item,
+        }
+    }
+
     /// Dump the dock panels layout to DockItemState.
     ///
     /// See also [DockArea::load].
@@ -380,15 +828,217 @@ impl DockArea {
             .as_ref()
             .map(|dock| DockState::new(dock.clone(), cx));
 
+        let floating = self
+            .floating_panels
+            .iter()
+            .map(|floating_panel| floating_panel.read(cx).dump(cx))
+            .collect();
+
         DockAreaState {
+            schema_version: DockAreaState::SCHEMA_VERSION,
             version: self.version,
             center,
             left_dock,
             right_dock,
             bottom_dock,
+            floating,
         }
     }
 
+    /// Detach the panel into a floating window.
+    ///
+    /// The `bounds` is the window bounds on the screen, if `None` the window is placed near the current window.
+    pub fn float_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        bounds: Option<Bounds<Pixels>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.open_floating_window(vec![panel], 0, bounds, cx);
+    }
+
+    fn open_floating_window(
+        &mut self,
+        panels: Vec<Arc<dyn PanelView>>,
+        active_ix: usize,
+        bounds: Option<Bounds<Pixels>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let bounds = bounds.unwrap_or_else(|| {
+            Bounds::new(
+                cx.bounds().origin + point(px(64.), px(64.)),
+                size(px(480.), px(360.)),
+            )
+        });
+        let dock_area = cx.view().downgrade();
+        let generation = self.floating_generation;
+
+        // Open the window after the current update, the panels may be still rendering in this window.
+        cx.spawn(|this, mut cx| async move {
+            let options = WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                kind: WindowKind::Normal,
+                ..Default::default()
+            };
+
+            let mut floating_panel = None;
+            let window = cx.open_window(options, |cx| {
+                let view =
+                    cx.new_view(|cx| FloatingPanel::new(panels, active_ix, bounds, dock_area, cx));
+                floating_panel = Some(view.clone());
+                cx.new_view(|cx| Root::new(view.into(), cx))
+            });
+
+            if let (Ok(window), Some(floating_panel)) = (window, floating_panel) {
+                _ = this.update(&mut cx, |this, cx| {
+                    // The floating windows have been closed (e.g.: by `load`) while opening.
+                    if this.floating_generation != generation {
+                        _ = window.update(cx, |_, cx| cx.remove_window());
+                        return;
+                    }
+
+                    this.floating_panels.push(floating_panel);
+                    cx.emit(DockEvent::LayoutChanged);
+                });
+            }
+        })
+        .detach();
+    }
+
+    /// Remember the closed panel to reopen by [`Self::reopen_last_closed`].
+    pub(crate) fn push_closed_panel(
+        &mut self,
+        state: DockItemState,
+        tab_panel: WeakView<TabPanel>,
+    ) {
+        if self.closed_panels.len() >= MAX_CLOSED_PANELS {
+            self.closed_panels.pop_front();
+        }
+        self.closed_panels
+            .push_back(ClosedPanel { state, tab_panel });
+    }
+
+    /// Returns true if there is any closed panel to reopen.
+    pub fn has_closed_panels(&self) -> bool {
+        !self.closed_panels.is_empty()
+    }
+
+    /// Reopen the most recently closed panel, it is restored by the [`PanelRegistry`].
+    ///
+    /// Returns false if there is no closed panel.
+    pub fn reopen_last_closed(&mut self, cx: &mut ViewContext<Self>) -> bool {
+        let Some(closed) = self.closed_panels.pop_back() else {
+            return false;
+        };
+
+        let weak_self = cx.view().downgrade();
+        let DockItem::Tabs { items, .. } = closed.state.to_item(weak_self, cx) else {
+            return false;
+        };
+
+        // Reopen in the original TabPanel if it is still in the layout.
+        if let Some(tab_panel) = closed
+            .tab_panel
+            .upgrade()
+            .filter(|tab_panel| !tab_panel.read(cx).panels.is_empty())
+        {
+            tab_panel.update(cx, |tab_panel, cx| {
+                for panel in items {
+                    tab_panel.add_panel(panel, cx);
+                }
+            });
+        } else {
+            self.dock_panels(items, cx);
+        }
+
+        cx.emit(DockEvent::LayoutChanged);
+        true
+    }
+
+    /// Move the focus to the next (`step` > 0) or previous TabPanel in the center and the open docks.
+    fn cycle_focus(&mut self, step: isize, cx: &mut ViewContext<Self>) {
+        let mut tab_panels = self.center_tab_panels(cx);
+        tab_panels.extend(
+            [&self.left_dock, &self.bottom_dock, &self.right_dock]
+                .into_iter()
+                .flatten()
+                .filter(|dock| dock.read(cx).is_open())
+                .map(|dock| dock.read(cx).panel.clone()),
+        );
+        if tab_panels.is_empty() {
+            return;
+        }
+
+        let len = tab_panels.len() as isize;
+        let ix = match tab_panels
+            .iter()
+            .position(|tab_panel| tab_panel.focus_handle(cx).contains_focused(cx))
+        {
+            Some(ix) => (ix as isize + step).rem_euclid(len) as usize,
+            None if step > 0 => 0,
+            None => len as usize - 1,
+        };
+
+        for tab_panel in &tab_panels {
+            tab_panel.update(cx, |tab_panel, _| tab_panel.show_focus_ring = false);
+        }
+        tab_panels[ix].update(cx, |tab_panel, cx| {
+            tab_panel.show_focus_ring = true;
+            tab_panel.focus_active_panel(cx);
+            cx.notify();
+        });
+    }
+
+    fn on_action_focus_next_panel(&mut self, _: &FocusNextPanel, cx: &mut ViewContext<Self>) {
+        self.cycle_focus(1, cx);
+    }
+
+    fn on_action_focus_prev_panel(&mut self, _: &FocusPrevPanel, cx: &mut ViewContext<Self>) {
+        self.cycle_focus(-1, cx);
+    }
+
+    fn on_action_reopen_closed_panel(&mut self, _: &ReopenClosedPanel, cx: &mut ViewContext<Self>) {
+        self.reopen_last_closed(cx);
+    }
+
+    /// Dock the panels from the floating window back to the center.
+    pub(crate) fn dock_panels(
+        &mut self,
+        panels: Vec<Arc<dyn PanelView>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if panels.is_empty() {
+            return;
+        }
+
+        let weak_self = cx.view().downgrade();
+        match &self.items {
+            DockItem::Split { view, .. } => {
+                let tab_panel = cx.new_view(|cx| {
+                    let mut tab_panel = TabPanel::new(None, weak_self.clone(), cx);
+                    for panel in panels {
+                        tab_panel.add_panel(panel, cx);
+                    }
+                    tab_panel
+                });
+
+                view.update(cx, |stack_panel, cx| {
+                    stack_panel.add_panel(Arc::new(tab_panel), None, weak_self, cx);
+                });
+            }
+            DockItem::Tabs { view, .. } => {
+                view.update(cx, |tab_panel, cx| {
+                    for panel in panels {
+                        tab_panel.add_panel(panel, cx);
+                    }
+                });
+            }
+        }
+
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
     /// Subscribe event on the panels
     #[allow(clippy::only_used_in_recursion)]
     fn subscribe_item(&mut self, item: &DockItem, cx: &mut ViewContext<Self>) {
@@ -440,11 +1090,33 @@ impl DockArea {
                 .detach()
             }
             PanelEvent::LayoutChanged => cx.emit(DockEvent::LayoutChanged),
+            _ => {}
         });
 
         self._subscriptions.push(subscription);
     }
 
+    /// Observe the layout tree, the `f` is called with the dumped state every time the layout has changed.
+    ///
+    /// ```ignore
+    /// let _subscription = DockArea::subscribe_layout(&dock_area, cx, |state, _| {
+    ///     println!("center: {:?}", state.center);
+    /// });
+    /// ```
+    pub fn subscribe_layout(
+        view: &View<Self>,
+        cx: &mut WindowContext,
+        f: impl Fn(&DockAreaState, &mut WindowContext) + 'static,
+    ) -> Subscription {
+        cx.subscribe(view, move |dock_area, event: &DockEvent, cx| match event {
+            DockEvent::LayoutChanged => {
+                let state = dock_area.read(cx).dump(cx);
+                f(&state, cx);
+            }
+            DockEvent::ActiveLayoutChanged => {}
+        })
+    }
+
     /// Returns the ID of the dock area.
     pub fn id(&self) -> SharedString {
         self.id.clone()
@@ -460,6 +1132,44 @@ impl DockArea {
         cx.notify();
     }
 
+    /// Returns true if there is no panel in the center.
+    fn is_center_empty(&self, cx: &AppContext) -> bool {
+        self.center_tab_panels(cx)
+            .iter()
+            .all(|tab_panel| tab_panel.read(cx).panels.is_empty())
+    }
+
+    /// Move the dragged panel into the empty center.
+    fn on_drop_to_empty(&mut self, drag: &DragTab, cx: &mut ViewContext<Self>) {
+        let panel = drag.value.panel.clone();
+        drag.value.tab_panel.update(cx, |tab_panel, cx| {
+            tab_panel.detach_panel(panel.clone(), cx);
+            tab_panel.remove_self_if_empty(cx);
+            cx.emit(PanelEvent::LayoutChanged);
+        });
+        self.dock_panels(vec![panel], cx);
+    }
+
+    fn render_empty_state(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .id("dock-area-empty")
+            .size_full()
+            .items_center()
+            .justify_center()
+            .gap_2()
+            .text_color(cx.theme().muted_foreground)
+            .map(|this| match self.empty_state.clone() {
+                Some(builder) => this.child(builder(cx)),
+                None => this.child(t!("Dock.No Panels")),
+            })
+            .drag_over::<DragTab>(|this, _, cx| {
+                this.bg(cx.theme().drop_target)
+                    .border_1()
+                    .border_color(cx.theme().drag_border)
+            })
+            .on_drop(cx.listener(Self::on_drop_to_empty))
+    }
+
     fn render_items(&self, _cx: &mut ViewContext<Self>) -> AnyElement {
         match &self.items {
             DockItem::Split { view, .. } => view.clone().into_any_element(),
@@ -474,6 +1184,11 @@ impl Render for DockArea {
 
         div()
             .id("dock-area")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_action_reopen_closed_panel))
+            .on_action(cx.listener(Self::on_action_focus_next_panel))
+            .on_action(cx.listener(Self::on_action_focus_prev_panel))
             .relative()
             .size_full()
             .overflow_hidden()
@@ -506,12 +1221,13 @@ impl Render for DockArea {
                                     .flex_col()
                                     .overflow_hidden()
                                     // Top center
-                                    .child(
-                                        div()
-                                            .flex_1()
-                                            .overflow_hidden()
-                                            .child(self.render_items(cx)),
-                                    )
+                                    .child(div().flex_1().overflow_hidden().map(|this| {
+                                        if self.is_center_empty(cx) {
+                                            this.child(self.render_empty_state(cx))
+                                        } else {
+                                            this.child(self.render_items(cx))
+                                        }
+                                    }))
                                     // Bottom Dock
                                     .when_some(self.bottom_dock.clone(), |this, dock| {
                                         this.child(dock)
@@ -1,9 +1,9 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{any::Any, collections::HashMap, path::PathBuf, rc::Rc, sync::Arc};
 
 use crate::popup_menu::PopupMenu;
 use gpui::{
-    AnyElement, AnyView, AppContext, EventEmitter, FocusHandle, FocusableView, Global, Hsla,
-    IntoElement, SharedString, View, WeakView, WindowContext,
+    AnyElement, AnyView, AppContext, EventEmitter, ExternalPaths, FocusHandle, FocusableView,
+    Global, Hsla, IntoElement, Pixels, SharedString, View, ViewContext, WeakView, WindowContext,
 };
 
 use rust_i18n::t;
@@ -14,6 +14,57 @@ pub enum PanelEvent {
     ZoomIn,
     ZoomOut,
     LayoutChanged,
+    /// The panel became the active tab of its TabPanel.
+    Activated,
+    /// The panel is no longer the active tab of its TabPanel.
+    Deactivated,
+    /// The panel became visible, e.g.: activated, or the Dock has been opened.
+    Shown,
+    /// The panel became invisible, e.g.: behind another tab, or the Dock has been closed.
+    ///
+    /// Pause the expensive work (timers, file watchers) until it is shown again.
+    Hidden,
+    /// The panel has been closed by the user, or removed by [`DockArea::remove_panel`].
+    Closed,
+}
+
+/// The content dropped onto a panel, see [`Panel::can_accept_drop`].
+pub enum PanelDrop<'a> {
+    /// The files dragged from the OS, e.g.: the Finder or Explorer.
+    Paths(&'a [PathBuf]),
+    /// The value dragged in the app by [`DragPayload`].
+    Payload(&'a DragPayload),
+}
+
+impl<'a> PanelDrop<'a> {
+    pub(crate) fn from_any(value: &'a dyn Any) -> Option<Self> {
+        if let Some(paths) = value.downcast_ref::<ExternalPaths>() {
+            Some(Self::Paths(paths.paths()))
+        } else {
+            value.downcast_ref::<DragPayload>().map(Self::Payload)
+        }
+    }
+}
+
+/// The value to drag in the app and drop onto the panels, e.g.: the file in a tree.
+///
+/// ```ignore
+/// div()
+///     .id("file")
+///     .on_drag(DragPayload::new(path.clone()), |_, cx| cx.new_view(|_| DragFile))
+/// ```
+#[derive(Clone)]
+pub struct DragPayload(Rc<dyn Any>);
+
+impl DragPayload {
+    pub fn new<T: 'static>(value: T) -> Self {
+        Self(Rc::new(value))
+    }
+
+    /// Returns the value if it is the type `T`.
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.0.downcast_ref::<T>()
+    }
 }
 
 pub struct TitleStyle {
@@ -33,6 +84,11 @@ pub trait Panel: EventEmitter<PanelEvent> + FocusableView {
         SharedString::from(t!("Dock.Unnamed")).into_any_element()
     }
 
+    /// The plain text of the title, used to search the panel in the tabs dropdown.
+    fn title_text(&self, _cx: &AppContext) -> SharedString {
+        t!("Dock.Unnamed").into()
+    }
+
     /// The theme of the panel title, default is `None`.
     fn title_style(&self, _cx: &WindowContext) -> Option<TitleStyle> {
         None
@@ -53,11 +109,45 @@ pub trait Panel: EventEmitter<PanelEvent> + FocusableView {
         false
     }
 
+    /// The minimum size of the panel along the axis of the split, default is `None` to use the default minimum size.
+    fn min_size(&self, _cx: &WindowContext) -> Option<Pixels> {
+        None
+    }
+
+    /// The maximum size of the panel along the axis of the split, default is `None`.
+    fn max_size(&self, _cx: &WindowContext) -> Option<Pixels> {
+        None
+    }
+
     /// The addition popup menu of the panel, default is `None`.
     fn popup_menu(&self, this: PopupMenu, _cx: &WindowContext) -> PopupMenu {
         this
     }
 
+    /// The buttons rendered at the right end of the tab bar when the panel is active, e.g.: refresh, add, filter.
+    ///
+    /// ```ignore
+    /// fn toolbar_buttons(&mut self, cx: &mut ViewContext<Self>) -> Vec<AnyElement> {
+    ///     vec![Button::new("refresh")
+    ///         .icon(IconName::Loader)
+    ///         .xsmall()
+    ///         .ghost()
+    ///         .on_click(cx.listener(|this, _, cx| this.refresh(cx)))
+    ///         .into_any_element()]
+    /// }
+    /// ```
+    fn toolbar_buttons(&mut self, _cx: &mut ViewContext<Self>) -> Vec<AnyElement> {
+        vec![]
+    }
+
+    /// Return true if the panel accepts the drop, the panel is highlighted while dragging over, default is `false`.
+    fn can_accept_drop(&self, _drop: &PanelDrop, _cx: &WindowContext) -> bool {
+        false
+    }
+
+    /// Handle the drop accepted by [`Panel::can_accept_drop`], e.g.: open the dropped files.
+    fn handle_drop(&mut self, _drop: &PanelDrop, _cx: &mut ViewContext<Self>) {}
+
     /// Dump the panel, used to serialize the panel.
     fn dump(&self, _cx: &AppContext) -> DockItemState {
         DockItemState::new(self)
@@ -67,14 +157,22 @@ pub trait Panel: EventEmitter<PanelEvent> + FocusableView {
 pub trait PanelView: 'static + Send + Sync {
     fn panel_name(&self, _cx: &WindowContext) -> &'static str;
     fn title(&self, _cx: &WindowContext) -> AnyElement;
+    fn title_text(&self, cx: &AppContext) -> SharedString;
     fn title_style(&self, _cx: &WindowContext) -> Option<TitleStyle>;
     fn closeable(&self, cx: &WindowContext) -> bool;
     fn zoomable(&self, cx: &WindowContext) -> bool;
     fn collapsible(&self, cx: &WindowContext) -> bool;
+    fn min_size(&self, cx: &WindowContext) -> Option<Pixels>;
+    fn max_size(&self, cx: &WindowContext) -> Option<Pixels>;
     fn popup_menu(&self, menu: PopupMenu, cx: &WindowContext) -> PopupMenu;
+    fn toolbar_buttons(&self, cx: &mut WindowContext) -> Vec<AnyElement>;
+    fn can_accept_drop(&self, drop: &PanelDrop, cx: &WindowContext) -> bool;
+    fn handle_drop(&self, drop: &PanelDrop, cx: &mut WindowContext);
     fn view(&self) -> AnyView;
     fn focus_handle(&self, cx: &AppContext) -> FocusHandle;
     fn dump(&self, cx: &AppContext) -> DockItemState;
+    /// Emit the lifecycle event on the panel.
+    fn emit_event(&self, event: PanelEvent, cx: &mut WindowContext);
 }
 
 impl<T: Panel> PanelView for View<T> {
@@ -86,6 +184,10 @@ impl<T: Panel> PanelView for View<T> {
         self.read(cx).title(cx)
     }
 
+    fn title_text(&self, cx: &AppContext) -> SharedString {
+        self.read(cx).title_text(cx)
+    }
+
     fn title_style(&self, cx: &WindowContext) -> Option<TitleStyle> {
         self.read(cx).title_style(cx)
     }
@@ -102,10 +204,30 @@ impl<T: Panel> PanelView for View<T> {
         self.read(cx).collapsible(cx)
     }
 
+    fn min_size(&self, cx: &WindowContext) -> Option<Pixels> {
+        self.read(cx).min_size(cx)
+    }
+
+    fn max_size(&self, cx: &WindowContext) -> Option<Pixels> {
+        self.read(cx).max_size(cx)
+    }
+
     fn popup_menu(&self, menu: PopupMenu, cx: &WindowContext) -> PopupMenu {
         self.read(cx).popup_menu(menu, cx)
     }
 
+    fn toolbar_buttons(&self, cx: &mut WindowContext) -> Vec<AnyElement> {
+        self.update(cx, |this, cx| this.toolbar_buttons(cx))
+    }
+
+    fn can_accept_drop(&self, drop: &PanelDrop, cx: &WindowContext) -> bool {
+        self.read(cx).can_accept_drop(drop, cx)
+    }
+
+    fn handle_drop(&self, drop: &PanelDrop, cx: &mut WindowContext) {
+        self.update(cx, |this, cx| this.handle_drop(drop, cx))
+    }
+
     fn view(&self) -> AnyView {
         self.clone().into()
     }
@@ -115,7 +237,16 @@ impl<T: Panel> PanelView for View<T> {
     }
 
     fn dump(&self, cx: &AppContext) -> DockItemState {
-        self.read(cx).dump(cx)
+        let mut state = self.read(cx).dump(cx);
+        // Keep the title to show before the panel is loaded, see `DockArea::lazy_panels`.
+        if state.title.is_none() && matches!(state.info, DockItemInfo::Panel(_)) {
+            state.title = Some(self.title_text(cx).to_string());
+        }
+        state
+    }
+
+    fn emit_event(&self, event: PanelEvent, cx: &mut WindowContext) {
+        self.update(cx, |_, cx| cx.emit(event));
     }
 }
 
@@ -156,11 +287,58 @@ impl PanelRegistry {
             items: HashMap::new(),
         }
     }
+
+    pub(crate) fn init(cx: &mut AppContext) {
+        cx.set_global(PanelRegistry::new());
+    }
 }
 impl Global for PanelRegistry {}
 
-/// Register the Panel init by panel_name to global registry.
-pub fn register_panel<F>(cx: &mut AppContext, panel_name: &str, deserialize: F)
+/// The panel that can be restored from the [`DockItemState`], register it by [`crate::register_panel!`].
+pub trait LoadPanel: Panel + Sized {
+    /// The name to register, must be the same as the [`Panel::panel_name`].
+    const PANEL_NAME: &'static str;
+
+    /// Create the panel from the dumped state, see [`Panel::dump`].
+    fn load(
+        state: &DockItemState,
+        dock_area: WeakView<DockArea>,
+        cx: &mut WindowContext,
+    ) -> View<Self>;
+}
+
+/// Register the [`LoadPanel`] type to the global [`PanelRegistry`], see [`register_panel`].
+///
+/// ```ignore
+/// impl LoadPanel for MyPanel {
+///     const PANEL_NAME: &'static str = "MyPanel";
+///
+///     fn load(state: &DockItemState, _: WeakView<DockArea>, cx: &mut WindowContext) -> View<Self> {
+///         cx.new_view(|cx| MyPanel::new(state, cx))
+///     }
+/// }
+///
+/// ui::register_panel!(cx, MyPanel);
+/// ```
+#[macro_export]
+macro_rules! register_panel {
+    ($cx:expr, $panel:ty) => {
+        $crate::dock::register_panel(
+            $cx,
+            <$panel as $crate::dock::LoadPanel>::PANEL_NAME,
+            |dock_area, state, _, cx| {
+                Box::new(<$panel as $crate::dock::LoadPanel>::load(
+                    state, dock_area, cx,
+                ))
+            },
+        )
+    };
+}
+
+/// Register the Panel init by panel_name to global registry, the `ui::init` must be called before.
+///
+/// The previous one is replaced if the `panel_name` has been registered, and returns `true`.
+pub fn register_panel<F>(cx: &mut AppContext, panel_name: &str, deserialize: F) -> bool
 where
     F: Fn(
             WeakView<DockArea>,
@@ -170,11 +348,8 @@ where
         ) -> Box<dyn PanelView>
         + 'static,
 {
-    if let None = cx.try_global::<PanelRegistry>() {
-        cx.set_global(PanelRegistry::new());
-    }
-
     cx.global_mut::<PanelRegistry>()
         .items
-        .insert(panel_name.to_string(), Arc::new(deserialize));
+        .insert(panel_name.to_string(), Arc::new(deserialize))
+        .is_some()
 }
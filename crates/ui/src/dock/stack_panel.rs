@@ -15,10 +15,23 @@ use super::{DockArea, DockItemState, Panel, PanelEvent, PanelView, TabPanel};
 use gpui::{
     prelude::FluentBuilder as _, AppContext, Axis, DismissEvent, Entity, EventEmitter, FocusHandle,
     FocusableView, IntoElement, ParentElement, Pixels, Render, Styled, Subscription, View,
-    ViewContext, VisualContext, WeakView,
+    ViewContext, VisualContext, WeakView, WindowContext,
 };
+use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 
+/// How the panel in a [`StackPanel`] is sized when the window is resized.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SizePolicy {
+    /// Keep the size ratio in the stack.
+    #[default]
+    #[serde(rename = "proportional")]
+    Proportional,
+    /// Keep the pixel size, e.g.: a sidebar keeps its width when the window grows.
+    #[serde(rename = "fixed")]
+    Fixed,
+}
+
 pub struct StackPanel {
     pub(super) parent: Option<View<StackPanel>>,
     pub(super) axis: Axis,
@@ -39,10 +52,12 @@ impl Panel for StackPanel {
 
     fn dump(&self, cx: &AppContext) -> DockItemState {
         let sizes = self.panel_group.read(cx).sizes();
+        let policies = self.size_policies(cx);
         let mut state = DockItemState::new(self);
         for panel in &self.panels {
             state.add_child(panel.dump(cx));
-            state.info = DockItemInfo::stack(sizes.clone(), self.axis);
+            state.info =
+                DockItemInfo::stack_with_policies(sizes.clone(), policies.clone(), self.axis);
         }
 
         state
@@ -52,11 +67,12 @@ impl Panel for StackPanel {
 impl StackPanel {
     pub fn new(axis: Axis, cx: &mut ViewContext<Self>) -> Self {
         let panel_group = cx.new_view(|cx| {
-            if axis == Axis::Horizontal {
+            let group = if axis == Axis::Horizontal {
                 h_resizable(cx)
             } else {
                 v_resizable(cx)
-            }
+            };
+            group.equalize_on_double_click(true)
         });
 
         // Bubble up the resize event.
@@ -154,10 +170,17 @@ impl StackPanel {
         self.insert_panel(panel, ix + 1, size, dock_area, cx);
     }
 
-    fn new_resizable_panel(panel: Arc<dyn PanelView>, size: Option<Pixels>) -> ResizablePanel {
+    fn new_resizable_panel(
+        panel: Arc<dyn PanelView>,
+        size: Option<Pixels>,
+        cx: &WindowContext,
+    ) -> ResizablePanel {
         resizable_panel()
             .content_view(panel.view())
             .when_some(size, |this, size| this.size(size))
+            .when_some(panel.min_size(cx), |this, size| this.min_size(size))
+            .when_some(panel.max_size(cx), |this, size| this.max_size(size))
+            .collapsible(panel.collapsible(cx))
     }
 
     fn insert_panel(
@@ -204,7 +227,7 @@ impl StackPanel {
 
         self.panels.insert(ix, panel.clone());
         self.panel_group.update(cx, |view, cx| {
-            view.insert_child(Self::new_resizable_panel(panel.clone(), size), ix, cx)
+            view.insert_child(Self::new_resizable_panel(panel.clone(), size, cx), ix, cx)
         });
 
         cx.emit(PanelEvent::LayoutChanged);
@@ -226,6 +249,49 @@ impl StackPanel {
         }
     }
 
+    /// Grow the panel by the `delta` size along the axis, negative to shrink.
+    ///
+    /// If the panel is the only one in this stack, resize this stack in the parent instead.
+    pub(super) fn resize_panel_by(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        delta: Pixels,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if self.panels.len() > 1 {
+            if let Some(ix) = self.index_of_panel(panel) {
+                self.panel_group
+                    .update(cx, |view, cx| view.resize_panel_by(ix, delta, cx));
+            }
+        } else if let Some(parent) = self.parent.clone() {
+            let view = cx.view().clone();
+            parent.update(cx, |parent, cx| {
+                parent.resize_panel_by(Arc::new(view), delta, cx)
+            });
+        }
+    }
+
+    /// Returns the [`SizePolicy`] of the panels.
+    pub fn size_policies(&self, cx: &AppContext) -> Vec<SizePolicy> {
+        let panel_group = self.panel_group.read(cx);
+        (0..self.panels.len())
+            .map(|ix| {
+                if panel_group.is_fixed(ix, cx) {
+                    SizePolicy::Fixed
+                } else {
+                    SizePolicy::Proportional
+                }
+            })
+            .collect()
+    }
+
+    /// Set the [`SizePolicy`] of the panel at the `ix`.
+    pub fn set_size_policy(&mut self, ix: usize, policy: SizePolicy, cx: &mut ViewContext<Self>) {
+        self.panel_group.update(cx, |view, cx| {
+            view.set_fixed(ix, policy == SizePolicy::Fixed, cx)
+        });
+    }
+
     /// Replace the old panel with the new panel at same index.
     pub(super) fn replace_panel(
         &mut self,
@@ -237,7 +303,7 @@ impl StackPanel {
             self.panels[ix] = Arc::new(new_panel.clone());
             self.panel_group.update(cx, |view, cx| {
                 view.replace_child(
-                    Self::new_resizable_panel(Arc::new(new_panel.clone()), None),
+                    Self::new_resizable_panel(Arc::new(new_panel.clone()), None, cx),
                     ix,
                     cx,
                 );
@@ -1,16 +1,24 @@
 use anyhow::{bail, Result};
-use gpui::{AppContext, Axis, Pixels, View, VisualContext as _, WeakView, WindowContext};
+use gpui::{
+    AppContext, Axis, Bounds, Pixels, SharedString, View, VisualContext as _, WeakView,
+    WindowContext,
+};
 use itertools::Itertools as _;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    invalid_panel::InvalidPanel, Dock, DockArea, DockItem, DockPlacement, Panel, PanelRegistry,
-    PanelView, TabPanel,
+    invalid_panel::InvalidPanel, lazy_panel::LazyPanel, Dock, DockArea, DockItem, DockPlacement,
+    Panel, PanelRegistry, PanelView, SizePolicy, TabPanel,
 };
 
 /// Used to serialize and deserialize the DockArea
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DockAreaState {
+    /// The version of the serialization format of this crate, see [`DockAreaState::SCHEMA_VERSION`].
+    ///
+    /// It is `0` for the states saved before the schema is versioned.
+    #[serde(default)]
+    pub schema_version: usize,
     /// The version is used to mark this persisted state is compatible with the current version
     /// For example, some times we many totally changed the structure of the Panel,
     /// then we can compare the version to decide whether we can use the state or ignore.
@@ -20,6 +28,30 @@ pub struct DockAreaState {
     pub left_dock: Option<DockState>,
     pub right_dock: Option<DockState>,
     pub bottom_dock: Option<DockState>,
+    /// The panels in the floating windows, with the `floating_bounds`.
+    #[serde(default)]
+    pub floating: Vec<DockItemState>,
+}
+
+impl DockAreaState {
+    /// The current version of the serialization format, the state with a newer version can not be loaded.
+    pub const SCHEMA_VERSION: usize = 1;
+}
+
+/// A named layout of the DockArea, see [`DockArea::save_layout`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DockLayout {
+    pub name: SharedString,
+    pub state: DockAreaState,
+}
+
+impl DockLayout {
+    pub fn new(name: impl Into<SharedString>, state: DockAreaState) -> Self {
+        Self {
+            name: name.into(),
+            state,
+        }
+    }
 }
 
 /// Used to serialize and deserialize the Dock
@@ -29,6 +61,9 @@ pub struct DockState {
     placement: DockPlacement,
     size: Pixels,
     open: bool,
+    /// The Dock is collapsed to a strip and flies out on hover, unpinned.
+    #[serde(default)]
+    auto_hide: bool,
 }
 
 impl DockState {
@@ -39,6 +74,7 @@ impl DockState {
             placement: dock.placement,
             size: dock.size,
             open: dock.open,
+            auto_hide: dock.auto_hide,
             panel: dock.panel.dump(cx),
         }
     }
@@ -49,7 +85,16 @@ impl DockState {
         dock_area: WeakView<DockArea>,
         cx: &mut WindowContext,
     ) -> Result<View<Dock>> {
-        let view = self.panel.to_item(dock_area.clone(), cx).view();
+        self.build_dock(dock_area, false, cx)
+    }
+
+    pub(super) fn build_dock(
+        &self,
+        dock_area: WeakView<DockArea>,
+        lazy: bool,
+        cx: &mut WindowContext,
+    ) -> Result<View<Dock>> {
+        let view = self.panel.build_item(dock_area.clone(), lazy, cx).view();
         if let Ok(tab_panel) = view.view().downcast::<TabPanel>() {
             let dock = cx.new_view(|cx| {
                 Dock::from_state(
@@ -58,6 +103,7 @@ impl DockState {
                     self.size,
                     tab_panel,
                     self.open,
+                    self.auto_hide,
                     cx,
                 )
             });
@@ -75,6 +121,12 @@ pub struct DockItemState {
     pub panel_name: String,
     pub children: Vec<DockItemState>,
     pub info: DockItemInfo,
+    /// The title text of the panel, used as the placeholder title before a lazy panel is loaded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The window bounds on the screen if the item is in a floating window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub floating_bounds: Option<Bounds<Pixels>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -84,6 +136,9 @@ pub enum DockItemInfo {
         sizes: Vec<Pixels>,
         /// The axis of the stack, 0 is horizontal, 1 is vertical
         axis: usize,
+        /// The size policies of the children, empty if all the children are proportional.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        policies: Vec<SizePolicy>,
     },
     #[serde(rename = "tabs")]
     Tabs { active_index: usize },
@@ -93,9 +148,21 @@ pub enum DockItemInfo {
 
 impl DockItemInfo {
     pub fn stack(sizes: Vec<Pixels>, axis: Axis) -> Self {
+        Self::stack_with_policies(sizes, vec![], axis)
+    }
+
+    pub fn stack_with_policies(sizes: Vec<Pixels>, policies: Vec<SizePolicy>, axis: Axis) -> Self {
+        // Omit the default policies to keep the state compact.
+        let policies = if policies.contains(&SizePolicy::Fixed) {
+            policies
+        } else {
+            vec![]
+        };
+
         Self::Stack {
             sizes,
             axis: if axis == Axis::Horizontal { 0 } else { 1 },
+            policies,
         }
     }
 
@@ -125,6 +192,13 @@ impl DockItemInfo {
         }
     }
 
+    pub fn policies(&self) -> Option<&Vec<SizePolicy>> {
+        match self {
+            Self::Stack { policies, .. } => Some(policies),
+            _ => None,
+        }
+    }
+
     pub fn active_index(&self) -> Option<usize> {
         match self {
             Self::Tabs { active_index } => Some(*active_index),
@@ -139,6 +213,8 @@ impl Default for DockItemState {
             panel_name: "".to_string(),
             children: Vec::new(),
             info: DockItemInfo::Panel(serde_json::Value::Null),
+            title: None,
+            floating_bounds: None,
         }
     }
 }
@@ -156,60 +232,94 @@ impl DockItemState {
     }
 
     pub fn to_item(&self, dock_area: WeakView<DockArea>, cx: &mut WindowContext) -> DockItem {
+        self.build_item(dock_area, false, cx)
+    }
+
+    /// Build the DockItem, if `lazy` is true, the panels in the inactive tabs are built as [`LazyPanel`]s.
+    pub(super) fn build_item(
+        &self,
+        dock_area: WeakView<DockArea>,
+        lazy: bool,
+        cx: &mut WindowContext,
+    ) -> DockItem {
         let info = self.info.clone();
+        let active_index = info
+            .active_index()
+            .map(|ix| ix.min(self.children.len().saturating_sub(1)));
 
         let items: Vec<DockItem> = self
             .children
             .iter()
-            .map(|child| child.to_item(dock_area.clone(), cx))
+            .enumerate()
+            .map(|(ix, child)| {
+                // Only the panels behind the inactive tabs can be lazy, the others are visible.
+                let lazy = match active_index {
+                    Some(active_index) => lazy && ix != active_index,
+                    None => lazy && !matches!(child.info, DockItemInfo::Panel(_)),
+                };
+                child.build_item(dock_area.clone(), lazy, cx)
+            })
             .collect();
 
         match info {
-            DockItemInfo::Stack { sizes, axis } => {
+            DockItemInfo::Stack {
+                sizes,
+                axis,
+                policies,
+            } => {
                 let axis = if axis == 0 {
                     Axis::Horizontal
                 } else {
                     Axis::Vertical
                 };
                 let sizes = sizes.iter().map(|s| Some(*s)).collect_vec();
-                DockItem::split_with_sizes(axis, items, sizes, &dock_area, cx)
+                DockItem::split_with_policies(axis, items, sizes, policies, &dock_area, cx)
             }
             DockItemInfo::Tabs { active_index } => {
                 if items.len() == 1 {
                     return items[0].clone();
                 }
 
+                // Skip the invalid children, the tabs can only contain panels.
                 let items = items
                     .iter()
                     .flat_map(|item| match item {
                         DockItem::Tabs { items, .. } => items.clone(),
-                        _ => {
-                            unreachable!("Invalid DockItem type in DockItemInfo::Tabs")
-                        }
+                        DockItem::Split { .. } => vec![],
                     })
                     .collect_vec();
 
                 DockItem::tabs(items, Some(active_index), &dock_area, cx)
             }
             DockItemInfo::Panel(_) => {
-                let view = if let Some(f) = cx
-                    .global::<PanelRegistry>()
-                    .items
-                    .get(&self.panel_name)
-                    .cloned()
-                {
-                    f(dock_area.clone(), self, &info, cx)
+                let view = if lazy {
+                    Box::new(cx.new_view(|cx| LazyPanel::new(self.clone(), cx)))
                 } else {
-                    // Show an invalid panel if the panel is not registered.
-                    Box::new(
-                        cx.new_view(|cx| InvalidPanel::new(&self.panel_name, info.clone(), cx)),
-                    )
+                    self.build_panel(dock_area.clone(), cx)
                 };
 
                 DockItem::tabs(vec![view.into()], None, &dock_area, cx)
             }
         }
     }
+
+    /// Build the panel by the constructor in the [`PanelRegistry`].
+    pub(super) fn build_panel(
+        &self,
+        dock_area: WeakView<DockArea>,
+        cx: &mut WindowContext,
+    ) -> Box<dyn PanelView> {
+        if let Some(f) = cx
+            .try_global::<PanelRegistry>()
+            .and_then(|registry| registry.items.get(&self.panel_name))
+            .cloned()
+        {
+            f(dock_area, self, &self.info, cx)
+        } else {
+            // Show an invalid panel if the panel is not registered.
+            Box::new(cx.new_view(|cx| InvalidPanel::new(self.clone(), cx)))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -221,6 +331,7 @@ mod tests {
     fn test_deserialize_item_state() {
         let json = include_str!("../../tests/fixtures/layout.json");
         let state: DockAreaState = serde_json::from_str(json).unwrap();
+        assert_eq!(state.schema_version, 0);
         assert_eq!(state.version, None);
         assert_eq!(state.center.panel_name, "StackPanel");
         assert_eq!(state.center.children.len(), 2);
@@ -253,5 +364,87 @@ mod tests {
         assert_eq!(right_dock.panel.panel_name, "TabPanel");
         assert_eq!(right_dock.panel.children.len(), 1);
         assert_eq!(right_dock.panel.children[0].panel_name, "StoryContainer");
+
+        assert!(state.floating.is_empty());
+    }
+
+    #[test]
+    fn test_schema_version() {
+        let json = r#"{
+            "schema_version": 1,
+            "version": 2,
+            "center": { "panel_name": "StackPanel", "children": [], "info": { "stack": { "sizes": [], "axis": 0 } } },
+            "left_dock": null,
+            "right_dock": null,
+            "bottom_dock": null
+        }"#;
+        let state: DockAreaState = serde_json::from_str(json).unwrap();
+        assert_eq!(state.schema_version, DockAreaState::SCHEMA_VERSION);
+        assert_eq!(state.version, Some(2));
+        assert_eq!(state.center.info.axis(), Some(Axis::Horizontal));
+    }
+
+    #[test]
+    fn test_stack_policies() {
+        let info = DockItemInfo::stack_with_policies(
+            vec![px(240.), px(600.)],
+            vec![SizePolicy::Fixed, SizePolicy::Proportional],
+            Axis::Horizontal,
+        );
+        let json = serde_json::to_string(&info).unwrap();
+        assert!(json.contains(r#""policies":["fixed","proportional"]"#));
+        let new_info: DockItemInfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(new_info, info);
+
+        // Omit the policies if all proportional, and default to it when loading.
+        let info = DockItemInfo::stack_with_policies(
+            vec![px(240.), px(600.)],
+            vec![SizePolicy::Proportional; 2],
+            Axis::Horizontal,
+        );
+        let json = serde_json::to_string(&info).unwrap();
+        assert!(!json.contains("policies"));
+        let new_info: DockItemInfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(new_info.policies(), Some(&vec![]));
+    }
+
+    #[test]
+    fn test_floating_bounds() {
+        let state = DockItemState {
+            panel_name: "TabPanel".to_string(),
+            info: DockItemInfo::tabs(0),
+            floating_bounds: Some(Bounds::new(
+                gpui::point(px(100.), px(80.)),
+                gpui::size(px(480.), px(360.)),
+            )),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&state).unwrap();
+        let new_state: DockItemState = serde_json::from_str(&json).unwrap();
+        assert_eq!(new_state, state);
+
+        // Skip the bounds if not floating.
+        let json = serde_json::to_string(&DockItemState::default()).unwrap();
+        assert!(!json.contains("floating_bounds"));
+    }
+
+    #[test]
+    fn test_title() {
+        let state = DockItemState {
+            panel_name: "StoryContainer".to_string(),
+            title: Some("Buttons".to_string()),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&state).unwrap();
+        let new_state: DockItemState = serde_json::from_str(&json).unwrap();
+        assert_eq!(new_state.title.as_deref(), Some("Buttons"));
+
+        // The states saved before have no title.
+        let json = serde_json::to_string(&DockItemState::default()).unwrap();
+        assert!(!json.contains("title"));
+        let new_state: DockItemState = serde_json::from_str(&json).unwrap();
+        assert_eq!(new_state.title, None);
     }
 }
@@ -1,29 +1,42 @@
 use std::sync::Arc;
 
 use gpui::{
-    div, prelude::FluentBuilder, px, rems, AnchorCorner, AppContext, DefiniteLength, DismissEvent,
-    DragMoveEvent, Empty, Entity, EventEmitter, FocusHandle, FocusableView,
-    InteractiveElement as _, IntoElement, ParentElement, Pixels, Render, ScrollHandle,
-    SharedString, StatefulInteractiveElement, Styled, View, ViewContext, VisualContext as _,
-    WeakView, WindowContext,
+    div, prelude::FluentBuilder, px, rems, AnchorCorner, AppContext, Axis, ClickEvent,
+    DefiniteLength, DismissEvent, DragMoveEvent, Empty, Entity, EntityId, EventEmitter,
+    ExternalPaths, FocusHandle, FocusableView, InteractiveElement as _, IntoElement,
+    ModifiersChangedEvent, MouseButton, ParentElement, Pixels, Render, ScrollHandle, SharedString,
+    StatefulInteractiveElement, Styled, View, ViewContext, VisualContext as _, WeakView,
+    WindowContext,
 };
 use rust_i18n::t;
 
 use crate::{
     button::{Button, ButtonStyled as _},
+    context_menu::ContextMenuExt as _,
+    dnd::{self, AutoScroll, DragItem, DraggableExt as _, DroppableExt as _},
     dock::DockItemInfo,
     h_flex,
+    popover::Popover,
     popup_menu::{PopupMenu, PopupMenuExt},
-    tab::{Tab, TabBar},
+    tab::{scrollable_edges, Tab, TabBar},
     theme::ActiveTheme,
     v_flex, AxisExt, IconName, Placement, Selectable, Sizable,
 };
 
 use super::{
-    ClosePanel, DockArea, DockItemState, DockPlacement, Panel, PanelEvent, PanelView, StackPanel,
-    ToggleZoom,
+    CloseOtherPanels, ClosePanel, ClosePanelsToRight, Dock, DockArea, DockItemState, DockPlacement,
+    DragPayload, GrowPanel, LazyPanel, NextTab, Panel, PanelDrop, PanelEvent, PanelView, PrevTab,
+    ShrinkPanel, StackPanel, TabsMenu, ToggleAutoHide, ToggleFloating, ToggleZoom,
 };
 
+/// The size to grow or shrink the panel by the keyboard.
+const RESIZE_STEP: f32 = 20.;
+
+pub(super) const CONTEXT: &str = "TabPanel";
+
+/// The dragging tab, the source is the [`TabPanel`] that the tab is dragged from.
+pub(crate) type DragTab = DragItem<DragPanel>;
+
 #[derive(Clone)]
 pub(crate) struct DragPanel {
     pub(crate) panel: Arc<dyn PanelView>,
@@ -34,25 +47,25 @@ impl DragPanel {
     pub(crate) fn new(panel: Arc<dyn PanelView>, tab_panel: View<TabPanel>) -> Self {
         Self { panel, tab_panel }
     }
-}
 
-impl Render for DragPanel {
-    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        div()
-            .id("drag-panel")
-            .cursor_grab()
-            .py_1()
-            .px_3()
-            .w_24()
-            .overflow_hidden()
-            .whitespace_nowrap()
-            .border_1()
-            .border_color(cx.theme().border)
-            .rounded_md()
-            .text_color(cx.theme().tab_foreground)
-            .bg(cx.theme().tab_active)
-            .opacity(0.75)
-            .child(self.panel.title(cx))
+    /// Start dragging the tab, with the title in the drag preview.
+    fn drag_tab<E: StatefulInteractiveElement>(self, el: E) -> E {
+        el.on_drag_item(
+            DragItem::new(self.tab_panel.entity_id(), self),
+            |drag, cx| {
+                cx.stop_propagation();
+                div()
+                    .py_1()
+                    .px_3()
+                    .w_24()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_color(cx.theme().tab_foreground)
+                    .bg(cx.theme().tab_active)
+                    .child(drag.panel.title(cx))
+                    .into_any_element()
+            },
+        )
     }
 }
 
@@ -64,10 +77,23 @@ pub struct TabPanel {
     pub(crate) panels: Vec<Arc<dyn PanelView>>,
     pub(crate) active_ix: usize,
     tab_bar_scroll_handle: ScrollHandle,
+    tab_bar_auto_scroll: AutoScroll,
     is_zoomed: bool,
     /// If this is true, the Panel closeable will follow the active panel's closeable,
     /// otherwise this TabPanel will not able to close
     pub(crate) closeable: bool,
+    /// If this is true, the TabPanel is in a floating window.
+    pub(super) floating: bool,
+    /// The entity ids of the panels, the most recently activated first.
+    mru: Vec<EntityId>,
+    /// The position in the `mru` while cycling the tabs by `ctrl-tab`, committed when `ctrl` is released.
+    mru_cursor: Option<usize>,
+    /// Show the focus ring after the focus moved here by the keyboard.
+    pub(super) show_focus_ring: bool,
+    /// False if the TabPanel is in a closed or collapsed Dock.
+    visible: bool,
+    /// The active panel that has been notified by [`PanelEvent::Activated`].
+    last_active_panel: Option<Arc<dyn PanelView>>,
 
     /// When drag move, will get the placement of the panel to be split
     will_split_placement: Option<Placement>,
@@ -106,6 +132,22 @@ impl Panel for TabPanel {
             .unwrap_or(false)
     }
 
+    /// The largest minimum size of the panels, to fit all the tabs.
+    fn min_size(&self, cx: &WindowContext) -> Option<Pixels> {
+        self.panels
+            .iter()
+            .filter_map(|panel| panel.min_size(cx))
+            .reduce(Pixels::max)
+    }
+
+    /// The smallest maximum size of the panels.
+    fn max_size(&self, cx: &WindowContext) -> Option<Pixels> {
+        self.panels
+            .iter()
+            .filter_map(|panel| panel.max_size(cx))
+            .reduce(Pixels::min)
+    }
+
     fn popup_menu(&self, menu: PopupMenu, cx: &WindowContext) -> PopupMenu {
         if let Some(panel) = self.active_panel() {
             panel.popup_menu(menu, cx)
@@ -137,9 +179,16 @@ impl TabPanel {
             panels: Vec::new(),
             active_ix: 0,
             tab_bar_scroll_handle: ScrollHandle::new(),
+            tab_bar_auto_scroll: AutoScroll::new(),
             will_split_placement: None,
             is_zoomed: false,
             closeable: true,
+            floating: false,
+            mru: vec![],
+            mru_cursor: None,
+            show_focus_ring: false,
+            visible: true,
+            last_active_panel: None,
         }
     }
 
@@ -152,14 +201,82 @@ impl TabPanel {
         self.panels.get(self.active_ix).cloned()
     }
 
-    fn set_active_ix(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+    pub(super) fn set_active_ix(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.activate_ix(ix, cx);
+        self.commit_mru();
+    }
+
+    fn activate_ix(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
         self.active_ix = ix;
+        self.load_lazy_panel(ix, cx);
         self.tab_bar_scroll_handle.scroll_to_item(ix);
+        // The new added tab has not been laid out yet, scroll to it again after the next frame.
+        cx.on_next_frame(move |this, cx| {
+            if this.active_ix == ix {
+                this.tab_bar_scroll_handle.scroll_to_item(ix);
+                cx.notify();
+            }
+        });
         self.focus_active_panel(cx);
+        self.sync_active_panel(cx);
         cx.emit(PanelEvent::LayoutChanged);
         cx.notify();
     }
 
+    /// Replace the [`LazyPanel`] at the `ix` with the panel built from the [`super::PanelRegistry`].
+    fn load_lazy_panel(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(lazy_panel) = self
+            .panels
+            .get(ix)
+            .and_then(|panel| panel.view().downcast::<LazyPanel>().ok())
+        else {
+            return;
+        };
+
+        let state = lazy_panel.read(cx).state.clone();
+        let panel = state.build_panel(self.dock_area.clone(), cx);
+        self.panels[ix] = panel.into();
+    }
+
+    /// Emit the lifecycle events to the panels if the active panel has changed.
+    pub(super) fn sync_active_panel(&mut self, cx: &mut ViewContext<Self>) {
+        let active_panel = self.active_panel();
+        if active_panel == self.last_active_panel {
+            return;
+        }
+
+        if let Some(panel) = self.last_active_panel.take() {
+            panel.emit_event(PanelEvent::Deactivated, cx);
+            if self.visible {
+                panel.emit_event(PanelEvent::Hidden, cx);
+            }
+        }
+        if let Some(panel) = active_panel.as_ref() {
+            panel.emit_event(PanelEvent::Activated, cx);
+            if self.visible {
+                panel.emit_event(PanelEvent::Shown, cx);
+            }
+        }
+        self.last_active_panel = active_panel;
+    }
+
+    /// Set the visible state by the Dock, and emit [`PanelEvent::Shown`] or [`PanelEvent::Hidden`] to the active panel.
+    pub(super) fn set_visible(&mut self, visible: bool, cx: &mut ViewContext<Self>) {
+        if self.visible == visible {
+            return;
+        }
+
+        self.visible = visible;
+        if let Some(panel) = self.last_active_panel.clone() {
+            let event = if visible {
+                PanelEvent::Shown
+            } else {
+                PanelEvent::Hidden
+            };
+            panel.emit_event(event, cx);
+        }
+    }
+
     /// Add a panel to the end of the tabs
     pub fn add_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
         assert_ne!(
@@ -183,6 +300,28 @@ impl TabPanel {
         cx.notify();
     }
 
+    /// Add the panels and activate the `active_ix`, only the active panel is loaded if they are lazy.
+    pub(super) fn add_panels(
+        &mut self,
+        panels: Vec<Arc<dyn PanelView>>,
+        active_ix: usize,
+        cx: &mut ViewContext<Self>,
+    ) {
+        for panel in panels {
+            if self
+                .panels
+                .iter()
+                .all(|p| p.view().entity_id() != panel.view().entity_id())
+            {
+                self.panels.push(panel);
+            }
+        }
+
+        if !self.panels.is_empty() {
+            self.set_active_ix(active_ix.min(self.panels.len() - 1), cx);
+        }
+    }
+
     /// Add panel to try to split
     pub fn add_panel_at(
         &mut self,
@@ -230,20 +369,23 @@ impl TabPanel {
     pub fn remove_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
         self.detach_panel(panel, cx);
         self.remove_self_if_empty(cx);
+        self.is_zoomed = false;
         cx.emit(PanelEvent::ZoomOut);
         cx.emit(PanelEvent::LayoutChanged);
     }
 
-    fn detach_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+    pub(super) fn detach_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
         let panel_view = panel.view();
         self.panels.retain(|p| p.view() != panel_view);
         if self.active_ix >= self.panels.len() {
             self.set_active_ix(self.panels.len().saturating_sub(1), cx)
+        } else {
+            self.sync_active_panel(cx);
         }
     }
 
     /// Check to remove self from the parent StackPanel, if there is no panel left
-    fn remove_self_if_empty(&self, cx: &mut ViewContext<Self>) {
+    pub(super) fn remove_self_if_empty(&self, cx: &mut ViewContext<Self>) {
         if !self.panels.is_empty() {
             return;
         }
@@ -261,11 +403,59 @@ impl TabPanel {
         self.stack_panel.is_some()
     }
 
+    /// Return true if the panel can be closed by the user.
+    fn is_panel_closeable(&self, panel: &Arc<dyn PanelView>, cx: &WindowContext) -> bool {
+        self.closeable && panel.closeable(cx)
+    }
+
+    /// Close the panel by the user, and remember it to reopen by [`DockArea::reopen_last_closed`].
+    fn close_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+        if !self.is_panel_closeable(&panel, cx) {
+            return;
+        }
+
+        let state = panel.dump(cx);
+        let tab_panel = cx.view().downgrade();
+        _ = self.dock_area.update(cx, |dock_area, _| {
+            dock_area.push_closed_panel(state, tab_panel);
+        });
+        self.remove_panel(panel.clone(), cx);
+        panel.emit_event(PanelEvent::Closed, cx);
+    }
+
+    /// Returns the Dock if this TabPanel is the panel of a Dock.
+    fn dock(&self, cx: &ViewContext<Self>) -> Option<View<Dock>> {
+        let dock_area = self.dock_area.upgrade()?;
+        let dock_area = dock_area.read(cx);
+        let entity_id = cx.view().entity_id();
+
+        [
+            &dock_area.left_dock,
+            &dock_area.bottom_dock,
+            &dock_area.right_dock,
+        ]
+        .into_iter()
+        .flatten()
+        .find(|dock| dock.read(cx).panel.entity_id() == entity_id)
+        .cloned()
+    }
+
+    /// Return true if the panel can be dragged to move.
+    ///
+    /// The panels in the floating window can only be reordered in the window, the drag can't cross the windows,
+    /// use the `ToggleFloating` action or close the window to dock them back.
+    fn can_move(&self) -> bool {
+        self.can_split() || self.floating
+    }
+
     fn render_menu_button(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let closeable = self.closeable(cx);
-        let zoomable = self.zoomable(cx);
+        let zoomable = self.zoomable(cx) && !self.floating;
 
         let is_zoomed = self.is_zoomed && zoomable;
+        let can_float = self.can_move();
+        let is_floating = self.floating;
+        let auto_hide = self.dock(cx).map(|dock| dock.read(cx).is_auto_hide());
         let view = cx.view().clone();
         let build_popup_menu = move |this, cx: &WindowContext| view.read(cx).popup_menu(this, cx);
 
@@ -302,6 +492,22 @@ impl TabPanel {
                                 };
                                 this.separator().menu(name, Box::new(ToggleZoom))
                             })
+                            .when(can_float, |this| {
+                                let name = if is_floating {
+                                    t!("Dock.Dock")
+                                } else {
+                                    t!("Dock.Float")
+                                };
+                                this.menu(name, Box::new(ToggleFloating))
+                            })
+                            .when_some(auto_hide, |this, auto_hide| {
+                                let name = if auto_hide {
+                                    t!("Dock.Pin")
+                                } else {
+                                    t!("Dock.Auto Hide")
+                                };
+                                this.separator().menu(name, Box::new(ToggleAutoHide))
+                            })
                             .when(closeable, |this| {
                                 this.separator()
                                     .menu(t!("Dock.Close"), Box::new(ClosePanel))
@@ -311,6 +517,47 @@ impl TabPanel {
             )
     }
 
+    /// Render the [`Panel::toolbar_buttons`] of the active panel.
+    fn render_toolbar_buttons(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let buttons = self.active_panel()?.toolbar_buttons(cx);
+        if buttons.is_empty() {
+            return None;
+        }
+
+        Some(h_flex().gap_1().occlude().children(buttons))
+    }
+
+    /// Render the dropdown button to list all the tabs, only shown when the tabs overflow the tab bar.
+    fn render_tabs_menu_button(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        // The tabs and the empty space at the end.
+        let (can_scroll_left, can_scroll_right) =
+            scrollable_edges(&self.tab_bar_scroll_handle, self.panels.len() + 1);
+        if !can_scroll_left && !can_scroll_right {
+            return None;
+        }
+
+        let view = cx.view().downgrade();
+        let panels = self.panels.clone();
+        let active_ix = self.active_ix;
+
+        Some(
+            Popover::new("tabs-menu")
+                .anchor(AnchorCorner::TopRight)
+                .trigger(
+                    Button::new("all-tabs")
+                        .icon(IconName::ChevronDown)
+                        .xsmall()
+                        .ghost()
+                        .tooltip(t!("Dock.All Tabs")),
+                )
+                .content(move |cx| {
+                    let view = view.clone();
+                    let panels = panels.clone();
+                    cx.new_view(|cx| TabsMenu::new(view, panels, active_ix, cx))
+                }),
+        )
+    }
+
     fn render_dock_toggle_button(
         &self,
         placement: DockPlacement,
@@ -318,7 +565,7 @@ impl TabPanel {
     ) -> Option<impl IntoElement> {
         let dock_area = self.dock_area.upgrade().expect("BUG: DockArea is missing");
 
-        if self.is_zoomed {
+        if self.is_zoomed || self.floating {
             return None;
         }
 
@@ -494,17 +741,9 @@ impl TabPanel {
                         .text_ellipsis()
                         .whitespace_nowrap()
                         .child(panel.title(cx))
-                        .when(self.can_split(), |this| {
-                            this.on_drag(
-                                DragPanel {
-                                    panel: panel.clone(),
-                                    tab_panel: view,
-                                },
-                                |drag, cx| {
-                                    cx.stop_propagation();
-                                    cx.new_view(|_| drag.clone())
-                                },
-                            )
+                        .on_click(cx.listener(Self::on_tab_bar_click))
+                        .when(self.can_move(), |this| {
+                            DragPanel::new(panel.clone(), view).drag_tab(this)
                         }),
                 )
                 .child(
@@ -512,6 +751,7 @@ impl TabPanel {
                         .flex_shrink_0()
                         .ml_1()
                         .gap_1()
+                        .children(self.render_toolbar_buttons(cx))
                         .child(self.render_menu_button(cx))
                         .children(right_dock_button),
                 )
@@ -522,6 +762,9 @@ impl TabPanel {
 
         TabBar::new("tab-bar")
             .track_scroll(self.tab_bar_scroll_handle.clone())
+            .when(self.can_move(), |this| {
+                this.on_drag_move(cx.listener(Self::on_tab_bar_drag_move))
+            })
             .when(
                 left_dock_button.is_some() || bottom_dock_button.is_some(),
                 |this| {
@@ -543,29 +786,58 @@ impl TabPanel {
             )
             .children(self.panels.iter().enumerate().map(|(ix, panel)| {
                 let active = ix == self.active_ix;
+                let closeable = self.is_panel_closeable(panel, cx);
+
                 Tab::new(("tab", ix), panel.title(cx))
                     .py_2()
+                    .relative()
                     .selected(active)
+                    .when(closeable, |this| {
+                        this.suffix(
+                            Button::new(("close-tab", ix))
+                                .icon(IconName::Close)
+                                .ghost()
+                                .xsmall()
+                                .on_click(cx.listener({
+                                    let panel = panel.clone();
+                                    move |view, _, cx| {
+                                        cx.stop_propagation();
+                                        view.close_panel(panel.clone(), cx);
+                                    }
+                                }))
+                                .into_any_element(),
+                        )
+                    })
                     .on_click(cx.listener(move |view, _, cx| {
                         view.set_active_ix(ix, cx);
                     }))
-                    .when(self.can_split(), |this| {
-                        this.on_drag(DragPanel::new(panel.clone(), view.clone()), |drag, cx| {
-                            cx.stop_propagation();
-                            cx.new_view(|_| drag.clone())
-                        })
-                        .drag_over::<DragPanel>(|this, _, cx| {
-                            this.rounded_l_none()
-                                .border_l_2()
-                                .border_r_0()
-                                .border_color(cx.theme().drag_border)
+                    // Activate the tab to let the context menu actions apply to it.
+                    .on_mouse_down(
+                        MouseButton::Right,
+                        cx.listener(move |view, _, cx| {
+                            view.set_active_ix(ix, cx);
+                        }),
+                    )
+                    .context_menu(move |menu, _| {
+                        menu.when(closeable, |menu| {
+                            menu.menu(t!("Dock.Close"), Box::new(ClosePanel))
                         })
-                        .on_drop(cx.listener(
-                            move |this, drag: &DragPanel, cx| {
+                        .menu(t!("Dock.Close Others"), Box::new(CloseOtherPanels))
+                        .menu(t!("Dock.Close to the Right"), Box::new(ClosePanelsToRight))
+                    })
+                    .when(self.can_move(), |this| {
+                        DragPanel::new(panel.clone(), view.clone())
+                            .drag_tab(this)
+                            .drag_over::<DragTab>(|this, _, cx| {
+                                this.rounded_l_none()
+                                    .border_l_2()
+                                    .border_r_0()
+                                    .border_color(cx.theme().drag_border)
+                            })
+                            .on_drop(cx.listener(move |this, drag: &DragTab, cx| {
                                 this.will_split_placement = None;
-                                this.on_drop(drag, Some(ix), cx)
-                            },
-                        ))
+                                this.on_drop(&drag.value, Some(ix), cx)
+                            }))
                     })
             }))
             .child(
@@ -575,19 +847,22 @@ impl TabPanel {
                     .h_full()
                     .flex_grow()
                     .min_w_16()
-                    .when(self.can_split(), |this| {
-                        this.drag_over::<DragPanel>(|this, _, cx| this.bg(cx.theme().drop_target))
-                            .on_drop(cx.listener(move |this, drag: &DragPanel, cx| {
+                    .on_click(cx.listener(Self::on_tab_bar_click))
+                    .when(self.can_move(), |this| {
+                        this.drop_target(
+                            |_: &DragTab, _| true,
+                            cx.listener(move |this, drag: &DragTab, cx| {
                                 this.will_split_placement = None;
 
-                                let ix = if drag.tab_panel == view {
+                                let ix = if drag.is_from(view.entity_id()) {
                                     Some(tabs_count - 1)
                                 } else {
                                     None
                                 };
 
-                                this.on_drop(drag, ix, cx)
-                            }))
+                                this.on_drop(&drag.value, ix, cx)
+                            }),
+                        )
                     }),
             )
             .suffix(
@@ -602,6 +877,8 @@ impl TabPanel {
                     .bg(cx.theme().tab_bar)
                     .px_2()
                     .gap_1()
+                    .children(self.render_toolbar_buttons(cx))
+                    .children(self.render_tabs_menu_button(cx))
                     .child(self.render_menu_button(cx))
                     .when_some(right_dock_button, |this, btn| this.child(btn)),
             )
@@ -618,6 +895,7 @@ impl TabPanel {
                     .overflow_x_hidden()
                     .flex_1()
                     .child(panel.view())
+                    .child(Self::render_external_drop_area(panel.clone(), cx))
                     .when(self.can_split(), |this| {
                         this.on_drag_move(cx.listener(Self::on_panel_drag_move))
                             .child(
@@ -625,6 +903,8 @@ impl TabPanel {
                                     .invisible()
                                     .absolute()
                                     .bg(cx.theme().drop_target)
+                                    .border_1()
+                                    .border_color(cx.theme().drag_border)
                                     .map(|this| match self.will_split_placement {
                                         Some(placement) => {
                                             let size = DefiniteLength::Fraction(0.35);
@@ -645,9 +925,9 @@ impl TabPanel {
                                         }
                                         None => this.top_0().left_0().size_full(),
                                     })
-                                    .group_drag_over::<DragPanel>("", |this| this.visible())
-                                    .on_drop(cx.listener(|this, drag: &DragPanel, cx| {
-                                        this.on_drop(drag, None, cx)
+                                    .group_drag_over::<DragTab>("", |this| this.visible())
+                                    .on_drop(cx.listener(|this, drag: &DragTab, cx| {
+                                        this.on_drop(&drag.value, None, cx)
                                     })),
                             )
                     })
@@ -656,11 +936,67 @@ impl TabPanel {
             .unwrap_or(Empty {}.into_any_element())
     }
 
+    /// The drop zone of the OS files and [`DragPayload`]s, visible while dragging over if the panel accepts.
+    fn render_external_drop_area(
+        panel: Arc<dyn PanelView>,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        div()
+            .invisible()
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full()
+            .bg(cx.theme().drop_target)
+            .border_1()
+            .border_color(cx.theme().drag_border)
+            .can_drop({
+                let panel = panel.clone();
+                move |value, cx| {
+                    PanelDrop::from_any(value).is_some_and(|drop| panel.can_accept_drop(&drop, cx))
+                }
+            })
+            .group_drag_over::<ExternalPaths>("", |this| this.visible())
+            .group_drag_over::<DragPayload>("", |this| this.visible())
+            .on_drop({
+                let panel = panel.clone();
+                move |paths: &ExternalPaths, cx| {
+                    panel.handle_drop(&PanelDrop::Paths(paths.paths()), cx);
+                }
+            })
+            .on_drop(move |payload: &DragPayload, cx| {
+                panel.handle_drop(&PanelDrop::Payload(payload), cx);
+            })
+    }
+
+    /// Scroll the tab bar while dragging a tab near its left or right edges.
+    fn on_tab_bar_drag_move(&mut self, event: &DragMoveEvent<DragTab>, cx: &mut ViewContext<Self>) {
+        let bounds = self.tab_bar_scroll_handle.bounds();
+        self.tab_bar_auto_scroll.update(
+            Axis::Horizontal,
+            event.event.position,
+            bounds,
+            cx,
+            |this, distance, _| {
+                dnd::scroll_by(&this.tab_bar_scroll_handle, Axis::Horizontal, distance)
+            },
+        );
+    }
+
     /// Calculate the split direction based on the current mouse position
-    fn on_panel_drag_move(&mut self, drag: &DragMoveEvent<DragPanel>, cx: &mut ViewContext<Self>) {
+    fn on_panel_drag_move(&mut self, drag: &DragMoveEvent<DragTab>, cx: &mut ViewContext<Self>) {
         let bounds = drag.bounds;
         let position = drag.event.position;
 
+        // The drag move event is received by all the tab panels, only handle the hovered one.
+        if !bounds.contains(&position) {
+            if self.will_split_placement.is_some() {
+                self.will_split_placement = None;
+                cx.notify();
+            }
+            return;
+        }
+
         // Check the mouse position to determine the split direction
         if position.x < bounds.left() + bounds.size.width * 0.35 {
             self.will_split_placement = Some(Placement::Left);
@@ -678,6 +1014,7 @@ impl TabPanel {
     }
 
     fn on_drop(&mut self, drag: &DragPanel, ix: Option<usize>, cx: &mut ViewContext<Self>) {
+        self.tab_bar_auto_scroll.stop();
         let panel = drag.panel.clone();
         let is_same_tab = drag.tab_panel == *cx.view();
 
@@ -716,8 +1053,10 @@ impl TabPanel {
             }
         }
 
+        self.will_split_placement = None;
         self.remove_self_if_empty(cx);
         cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
     }
 
     /// Add panel with split placement
@@ -813,14 +1152,105 @@ impl TabPanel {
         cx.emit(PanelEvent::LayoutChanged);
     }
 
-    fn focus_active_panel(&self, cx: &mut ViewContext<Self>) {
+    /// Move the active panel to the front of the MRU list, and stop cycling.
+    fn commit_mru(&mut self) {
+        self.mru_cursor = None;
+        let Some(active_id) = self.active_panel().map(|panel| panel.view().entity_id()) else {
+            return;
+        };
+
+        let panels = &self.panels;
+        self.mru.retain(|id| {
+            *id != active_id && panels.iter().any(|panel| panel.view().entity_id() == *id)
+        });
+        self.mru.insert(0, active_id);
+    }
+
+    /// The indexes of the panels in MRU order, the panels never activated are at the end in tab order.
+    fn mru_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = self
+            .mru
+            .iter()
+            .filter_map(|id| {
+                self.panels
+                    .iter()
+                    .position(|panel| panel.view().entity_id() == *id)
+            })
+            .collect();
+        for ix in 0..self.panels.len() {
+            if !order.contains(&ix) {
+                order.push(ix);
+            }
+        }
+        order
+    }
+
+    /// Activate the next (`step` > 0) or previous tab in MRU order.
+    fn cycle_tabs(&mut self, step: isize, cx: &mut ViewContext<Self>) {
+        if self.panels.len() < 2 {
+            return;
+        }
+
+        let order = self.mru_order();
+        let cursor = self.mru_cursor.unwrap_or(0) as isize;
+        let cursor = (cursor + step).rem_euclid(order.len() as isize) as usize;
+        self.mru_cursor = Some(cursor);
+        self.activate_ix(order[cursor], cx);
+    }
+
+    fn on_action_next_tab(&mut self, _: &NextTab, cx: &mut ViewContext<Self>) {
+        self.cycle_tabs(1, cx);
+    }
+
+    fn on_action_prev_tab(&mut self, _: &PrevTab, cx: &mut ViewContext<Self>) {
+        self.cycle_tabs(-1, cx);
+    }
+
+    fn on_modifiers_changed(&mut self, event: &ModifiersChangedEvent, _: &mut ViewContext<Self>) {
+        if self.mru_cursor.is_some() && !event.modifiers.control {
+            self.commit_mru();
+        }
+    }
+
+    /// Grow the panel by the `delta` size in the parent split, or resize the dock.
+    fn resize_by(&mut self, delta: Pixels, cx: &mut ViewContext<Self>) {
+        if let Some(dock) = self.dock(cx) {
+            dock.update(cx, |dock, cx| {
+                let size = dock.size();
+                dock.set_size(size + delta, cx);
+            });
+        } else if let Some(stack_panel) = self.stack_panel.clone() {
+            let view = cx.view().clone();
+            stack_panel.update(cx, |stack_panel, cx| {
+                stack_panel.resize_panel_by(Arc::new(view), delta, cx);
+            });
+        }
+    }
+
+    fn on_action_grow_panel(&mut self, _: &GrowPanel, cx: &mut ViewContext<Self>) {
+        self.resize_by(px(RESIZE_STEP), cx);
+    }
+
+    fn on_action_shrink_panel(&mut self, _: &ShrinkPanel, cx: &mut ViewContext<Self>) {
+        self.resize_by(px(-RESIZE_STEP), cx);
+    }
+
+    pub(super) fn focus_active_panel(&self, cx: &mut ViewContext<Self>) {
         if let Some(active_panel) = self.active_panel() {
             active_panel.focus_handle(cx).focus(cx);
         }
     }
 
+    /// Double-click on the title or the empty space of the tab bar to toggle zoom.
+    fn on_tab_bar_click(&mut self, event: &ClickEvent, cx: &mut ViewContext<Self>) {
+        if event.up.click_count == 2 {
+            self.on_action_toggle_zoom(&ToggleZoom, cx);
+        }
+    }
+
     fn on_action_toggle_zoom(&mut self, _: &ToggleZoom, cx: &mut ViewContext<Self>) {
-        if !self.zoomable(cx) {
+        // The floating window is not in the DockArea, so it can't be zoomed.
+        if !self.zoomable(cx) || self.floating {
             return;
         }
 
@@ -832,9 +1262,60 @@ impl TabPanel {
         self.is_zoomed = !self.is_zoomed;
     }
 
+    fn on_action_toggle_floating(&mut self, _: &ToggleFloating, cx: &mut ViewContext<Self>) {
+        // Let the FloatingPanel to dock the panels back.
+        if self.floating {
+            cx.propagate();
+            return;
+        }
+
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+
+        self.detach_panel(panel.clone(), cx);
+        self.remove_self_if_empty(cx);
+        _ = self.dock_area.update(cx, |dock_area, cx| {
+            dock_area.float_panel(panel, None, cx);
+        });
+        cx.emit(PanelEvent::LayoutChanged);
+    }
+
     fn on_action_close_panel(&mut self, _: &ClosePanel, cx: &mut ViewContext<Self>) {
         if let Some(panel) = self.active_panel() {
-            self.remove_panel(panel, cx);
+            self.close_panel(panel, cx);
+        }
+    }
+
+    fn on_action_close_other_panels(&mut self, _: &CloseOtherPanels, cx: &mut ViewContext<Self>) {
+        let Some(active_panel) = self.active_panel() else {
+            return;
+        };
+
+        let panels = self
+            .panels
+            .iter()
+            .filter(|panel| **panel != active_panel)
+            .cloned()
+            .collect::<Vec<_>>();
+        for panel in panels {
+            self.close_panel(panel, cx);
+        }
+    }
+
+    fn on_action_close_panels_to_right(
+        &mut self,
+        _: &ClosePanelsToRight,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let panels = self
+            .panels
+            .iter()
+            .skip(self.active_ix + 1)
+            .cloned()
+            .collect::<Vec<_>>();
+        for panel in panels {
+            self.close_panel(panel, cx);
         }
     }
 }
@@ -854,15 +1335,40 @@ impl Render for TabPanel {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
         let focus_handle = self.focus_handle(cx);
 
+        let show_focus_ring = self.show_focus_ring && focus_handle.contains_focused(cx);
+
         v_flex()
             .id("tab-panel")
+            .key_context(CONTEXT)
             .track_focus(&focus_handle)
             .on_action(cx.listener(Self::on_action_toggle_zoom))
+            .on_action(cx.listener(Self::on_action_next_tab))
+            .on_action(cx.listener(Self::on_action_prev_tab))
+            .on_action(cx.listener(Self::on_action_grow_panel))
+            .on_action(cx.listener(Self::on_action_shrink_panel))
+            .on_modifiers_changed(cx.listener(Self::on_modifiers_changed))
+            // Any click means the user is back to the mouse.
+            .on_any_mouse_down(cx.listener(|this, _, _| this.show_focus_ring = false))
             .on_action(cx.listener(Self::on_action_close_panel))
+            .on_action(cx.listener(Self::on_action_close_other_panels))
+            .on_action(cx.listener(Self::on_action_close_panels_to_right))
+            .on_action(cx.listener(Self::on_action_toggle_floating))
+            .relative()
             .size_full()
             .overflow_hidden()
             .bg(cx.theme().background)
             .child(self.render_tabs(cx))
             .child(self.render_active_panel(cx))
+            .when(show_focus_ring, |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .size_full()
+                        .border_2()
+                        .border_color(cx.theme().ring),
+                )
+            })
     }
 }
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AppContext, DismissEvent, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
+    StatefulInteractiveElement as _, Styled as _, Subscription, View, ViewContext,
+    VisualContext as _, WeakView,
+};
+use rust_i18n::t;
+
+use crate::{
+    h_flex,
+    input::{InputEvent, TextInput},
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Sizable as _, StyledExt as _,
+};
+
+use super::{PanelView, TabPanel};
+
+/// Show the search input if the number of the tabs is more than this.
+const SEARCHABLE_TABS_COUNT: usize = 8;
+
+/// The dropdown to list all the tabs of a [`TabPanel`], used when the tabs overflow the tab bar.
+pub(crate) struct TabsMenu {
+    focus_handle: FocusHandle,
+    tab_panel: WeakView<TabPanel>,
+    panels: Vec<Arc<dyn PanelView>>,
+    active_ix: usize,
+    search_input: Option<View<TextInput>>,
+    query: SharedString,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TabsMenu {
+    pub(crate) fn new(
+        tab_panel: WeakView<TabPanel>,
+        panels: Vec<Arc<dyn PanelView>>,
+        active_ix: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let mut _subscriptions = vec![];
+        let search_input = (panels.len() > SEARCHABLE_TABS_COUNT).then(|| {
+            let input = cx.new_view(|cx| {
+                TextInput::new(cx)
+                    .placeholder(t!("Dock.Search Tabs"))
+                    .cleanable()
+                    .small()
+            });
+            _subscriptions.push(cx.subscribe(&input, |this, input, event: &InputEvent, cx| {
+                if let InputEvent::Change(_) = event {
+                    this.query = input.read(cx).text().trim().to_lowercase().into();
+                    cx.notify();
+                }
+            }));
+            input
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            tab_panel,
+            panels,
+            active_ix,
+            search_input,
+            query: SharedString::default(),
+            _subscriptions,
+        }
+    }
+
+    fn activate(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        _ = self.tab_panel.update(cx, |tab_panel, cx| {
+            tab_panel.set_active_ix(ix, cx);
+        });
+        cx.emit(DismissEvent);
+    }
+}
+
+impl EventEmitter<DismissEvent> for TabsMenu {}
+
+impl FocusableView for TabsMenu {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        match &self.search_input {
+            Some(input) => input.focus_handle(cx),
+            None => self.focus_handle.clone(),
+        }
+    }
+}
+
+impl Render for TabsMenu {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let query = self.query.clone();
+
+        v_flex()
+            .id("tabs-menu")
+            .track_focus(&self.focus_handle)
+            .gap_1()
+            .min_w(px(200.))
+            .max_w(px(320.))
+            .when_some(self.search_input.clone(), |this, input| this.child(input))
+            .child(
+                v_flex()
+                    .id("tabs-menu-items")
+                    .max_h(px(360.))
+                    .overflow_y_scroll()
+                    .children(
+                        self.panels
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, panel)| {
+                                query.is_empty()
+                                    || panel.title_text(cx).to_lowercase().contains(query.as_ref())
+                            })
+                            .map(|(ix, panel)| {
+                                let active = ix == self.active_ix;
+
+                                h_flex()
+                                    .id(("tabs-menu-item", ix))
+                                    .gap_2()
+                                    .px_2()
+                                    .py_1()
+                                    .rounded(px(cx.theme().radius))
+                                    .text_sm()
+                                    .cursor_pointer()
+                                    .hover(|this| this.bg(cx.theme().accent))
+                                    .child(div().w_4().when(active, |this| {
+                                        this.child(Icon::new(IconName::Check).small())
+                                    }))
+                                    .child(
+                                        div()
+                                            .flex_1()
+                                            .overflow_hidden()
+                                            .whitespace_nowrap()
+                                            .text_ellipsis()
+                                            .when(active, |this| this.font_semibold())
+                                            .child(panel.title(cx)),
+                                    )
+                                    .on_click(cx.listener(move |this, _, cx| this.activate(ix, cx)))
+                            }),
+                    ),
+            )
+    }
+}
@@ -1,13 +1,14 @@
-use std::{rc::Rc, time::Duration};
+use std::rc::Rc;
 
 use gpui::{
-    anchored, div, point, prelude::FluentBuilder as _, px, Animation, AnimationExt as _,
-    AnyElement, ClickEvent, DefiniteLength, DismissEvent, Div, EventEmitter, FocusHandle,
+    anchored, div, point, prelude::FluentBuilder as _, px, AnimationExt as _, AnyElement,
+    ClickEvent, DefiniteLength, DismissEvent, Div, EventEmitter, FocusHandle,
     InteractiveElement as _, IntoElement, MouseButton, ParentElement, Pixels, RenderOnce, Styled,
     WindowContext,
 };
 
 use crate::{
+    animation::{ease_standard, motion, DURATION_FAST},
     button::{Button, ButtonStyled as _},
     h_flex,
     modal::overlay_color,
@@ -212,7 +213,7 @@ impl RenderOnce for Drawer {
                             })
                             .with_animation(
                                 "slide",
-                                Animation::new(Duration::from_secs_f64(0.15)),
+                                motion(DURATION_FAST, ease_standard(), cx),
                                 move |this, delta| {
                                     let y = px(-100.) + delta * px(100.);
                                     this.map(|this| match placement {
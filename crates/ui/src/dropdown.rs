@@ -1,23 +1,21 @@
 use gpui::{
-    actions, anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, AnyElement,
-    AppContext, Bounds, ClickEvent, DismissEvent, ElementId, EventEmitter, FocusHandle,
-    FocusableView, InteractiveElement, IntoElement, KeyBinding, Length, ParentElement, Pixels,
-    Render, SharedString, StatefulInteractiveElement, Styled, Task, View, ViewContext,
-    VisualContext, WeakView, WindowContext,
+    actions, div, px, rems, AnyElement, AppContext, DismissEvent, ElementId, EventEmitter,
+    FocusHandle, FocusableView, IntoElement, KeyBinding, Length, ParentElement, Render,
+    SharedString, Styled, Task, ViewContext, VisualContext, WeakView, WindowContext,
 };
 use rust_i18n::t;
 
 use crate::{
     h_flex,
-    input::ClearButton,
-    list::{self, List, ListDelegate, ListItem},
+    list::{List, ListDelegate, ListItem},
+    select::popup::{render_popup, SelectPopup, SelectPopupView},
     theme::ActiveTheme,
-    v_flex, Disableable, Icon, IconName, Sizable, Size, StyleSized, StyledExt,
+    Icon, IconName, Sizable, Size, StyleSized,
 };
 
 actions!(dropdown, [Up, Down, Enter, Escape]);
 
-const CONTEXT: &str = "Dropdown";
+pub(crate) const CONTEXT: &str = "Dropdown";
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([
         KeyBinding::new("up", Up, Some(CONTEXT)),
@@ -106,7 +104,7 @@ impl<T: DropdownItem> DropdownDelegate for Vec<T> {
     }
 }
 
-struct DropdownListDelegate<D: DropdownDelegate + 'static> {
+pub(crate) struct DropdownListDelegate<D: DropdownDelegate + 'static> {
     delegate: D,
     dropdown: WeakView<Dropdown<D>>,
     selected_index: Option<usize>,
@@ -133,7 +131,7 @@ where
         let size = self
             .dropdown
             .upgrade()
-            .map_or(Size::Medium, |dropdown| dropdown.read(cx).size);
+            .map_or(Size::Medium, |dropdown| dropdown.read(cx).popup.size);
 
         if let Some(item) = self.delegate.get(ix) {
             let list_item = ListItem::new(("list-item", ix))
@@ -152,10 +150,7 @@ where
     fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {
         let dropdown = self.dropdown.clone();
         cx.defer(move |_, cx| {
-            _ = dropdown.update(cx, |this, cx| {
-                this.open = false;
-                this.focus(cx);
-            });
+            _ = dropdown.update(cx, |this, cx| this.popup.close(cx));
         });
     }
 
@@ -172,8 +167,7 @@ where
             _ = dropdown.update(cx, |this, cx| {
                 cx.emit(DropdownEvent::Confirm(selected_value.clone()));
                 this.selected_value = selected_value;
-                this.open = false;
-                this.focus(cx);
+                this.popup.close(cx);
             });
         });
     }
@@ -213,22 +207,10 @@ pub enum DropdownEvent<D: DropdownDelegate + 'static> {
 }
 
 pub struct Dropdown<D: DropdownDelegate + 'static> {
-    id: ElementId,
-    focus_handle: FocusHandle,
-    list: View<List<DropdownListDelegate<D>>>,
-    size: Size,
-    icon: Option<IconName>,
-    open: bool,
-    cleanable: bool,
-    placeholder: Option<SharedString>,
+    popup: SelectPopup<DropdownListDelegate<D>>,
     title_prefix: Option<SharedString>,
     selected_value: Option<<D::Item as DropdownItem>::Value>,
     empty: Option<Box<dyn Fn(&WindowContext) -> AnyElement + 'static>>,
-    width: Length,
-    menu_width: Length,
-    /// Store the bounds of the input
-    bounds: Bounds<Pixels>,
-    disabled: bool,
 }
 
 pub struct SearchableVec<T> {
@@ -306,7 +288,6 @@ where
         selected_index: Option<usize>,
         cx: &mut ViewContext<Self>,
     ) -> Self {
-        let focus_handle = cx.focus_handle();
         let delegate = DropdownListDelegate {
             delegate,
             dropdown: cx.view().downgrade(),
@@ -323,25 +304,11 @@ where
             list
         });
 
-        cx.on_blur(&list.focus_handle(cx), Self::on_blur).detach();
-        cx.on_blur(&focus_handle, Self::on_blur).detach();
-
         let mut this = Self {
-            id: id.into(),
-            focus_handle,
-            placeholder: None,
-            list,
-            size: Size::Medium,
-            icon: None,
+            popup: SelectPopup::new(id, list, cx),
             selected_value: None,
-            open: false,
-            cleanable: false,
             title_prefix: None,
             empty: None,
-            width: Length::Auto,
-            menu_width: Length::Auto,
-            bounds: Bounds::default(),
-            disabled: false,
         };
         this.set_selected_index(selected_index, cx);
         this
@@ -349,25 +316,25 @@ where
 
     /// Set the width of the dropdown input, default: Length::Auto
     pub fn width(mut self, width: impl Into<Length>) -> Self {
-        self.width = width.into();
+        self.popup.width = width.into();
         self
     }
 
     /// Set the width of the dropdown menu, default: Length::Auto
     pub fn menu_width(mut self, width: impl Into<Length>) -> Self {
-        self.menu_width = width.into();
+        self.popup.menu_width = width.into();
         self
     }
 
     /// Set the placeholder for display when dropdown value is empty.
     pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
-        self.placeholder = Some(placeholder.into());
+        self.popup.placeholder = Some(placeholder.into());
         self
     }
 
     /// Set the right icon for the dropdown input, instead of the default arrow icon.
     pub fn icon(mut self, icon: impl Into<IconName>) -> Self {
-        self.icon = Some(icon.into());
+        self.popup.icon = Some(icon.into());
         self
     }
 
@@ -383,13 +350,13 @@ where
 
     /// Set true to show the clear button when the input field is not empty.
     pub fn cleanable(mut self) -> Self {
-        self.cleanable = true;
+        self.popup.cleanable = true;
         self
     }
 
     /// Set the disable state for the dropdown.
     pub fn disabled(mut self, disabled: bool) -> Self {
-        self.disabled = disabled;
+        self.popup.disabled = disabled;
         self
     }
 
@@ -407,7 +374,7 @@ where
         selected_index: Option<usize>,
         cx: &mut ViewContext<Self>,
     ) {
-        self.list.update(cx, |list, cx| {
+        self.popup.list.update(cx, |list, cx| {
             list.set_selected_index(selected_index, cx);
         });
         self.update_selected_value(cx);
@@ -420,19 +387,19 @@ where
     ) where
         <<D as DropdownDelegate>::Item as DropdownItem>::Value: PartialEq,
     {
-        let delegate = self.list.read(cx).delegate();
+        let delegate = self.popup.list.read(cx).delegate();
         let selected_index = delegate.delegate.position(selected_value);
         self.set_selected_index(selected_index, cx);
     }
 
     pub fn selected_index(&self, cx: &WindowContext) -> Option<usize> {
-        self.list.read(cx).selected_index()
+        self.popup.list.read(cx).selected_index()
     }
 
     fn update_selected_value(&mut self, cx: &WindowContext) {
         self.selected_value = self
             .selected_index(cx)
-            .and_then(|ix| self.list.read(cx).delegate().delegate.get(ix))
+            .and_then(|ix| self.popup.list.read(cx).delegate().delegate.get(ix))
             .map(|item| item.value().clone());
     }
 
@@ -441,75 +408,13 @@ where
     }
 
     pub fn focus(&self, cx: &mut WindowContext) {
-        self.focus_handle.focus(cx);
-    }
-
-    fn on_blur(&mut self, cx: &mut ViewContext<Self>) {
-        // When the dropdown and dropdown menu are both not focused, close the dropdown menu.
-        if self.list.focus_handle(cx).is_focused(cx) || self.focus_handle.is_focused(cx) {
-            return;
-        }
-
-        self.open = false;
-        cx.notify();
-    }
-
-    fn up(&mut self, _: &Up, cx: &mut ViewContext<Self>) {
-        if !self.open {
-            return;
-        }
-        self.list.focus_handle(cx).focus(cx);
-        cx.dispatch_action(Box::new(list::SelectPrev));
-    }
-
-    fn down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
-        if !self.open {
-            self.open = true;
-        }
-
-        self.list.focus_handle(cx).focus(cx);
-        cx.dispatch_action(Box::new(list::SelectNext));
-    }
-
-    fn enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
-        // Propagate the event to the parent view, for example to the Modal to support ENTER to confirm.
-        cx.propagate();
-
-        if !self.open {
-            self.open = true;
-            cx.notify();
-        } else {
-            self.list.focus_handle(cx).focus(cx);
-            cx.dispatch_action(Box::new(list::Confirm));
-        }
-    }
-
-    fn toggle_menu(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
-        cx.stop_propagation();
-
-        self.open = !self.open;
-        if self.open {
-            self.list.focus_handle(cx).focus(cx);
-        }
-        cx.notify();
-    }
-
-    fn escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
-        // Propagate the event to the parent view, for example to the Modal to support ESC to close.
-        cx.propagate();
-
-        self.open = false;
-        cx.notify();
-    }
-
-    fn clean(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
-        self.set_selected_index(None, cx);
-        cx.emit(DropdownEvent::Confirm(None));
+        self.popup.focus(cx);
     }
 
     fn display_title(&self, cx: &WindowContext) -> impl IntoElement {
-        let title = if let Some(selected_index) = &self.selected_index(cx) {
+        if let Some(selected_index) = &self.selected_index(cx) {
             let title = self
+                .popup
                 .list
                 .read(cx)
                 .delegate()
@@ -527,16 +432,32 @@ where
                 .child(title.clone())
         } else {
             div().text_color(cx.theme().accent_foreground).child(
-                self.placeholder
+                self.popup
+                    .placeholder
                     .clone()
                     .unwrap_or_else(|| t!("Dropdown.placeholder").into()),
             )
-        };
+        }
+    }
+}
+
+impl<D> SelectPopupView for Dropdown<D>
+where
+    D: DropdownDelegate + 'static,
+{
+    type Delegate = DropdownListDelegate<D>;
+
+    fn popup(&self) -> &SelectPopup<Self::Delegate> {
+        &self.popup
+    }
 
-        title.when(self.disabled, |this| {
-            this.cursor_not_allowed()
-                .text_color(cx.theme().muted_foreground)
-        })
+    fn popup_mut(&mut self) -> &mut SelectPopup<Self::Delegate> {
+        &mut self.popup
+    }
+
+    fn on_clean(&mut self, cx: &mut ViewContext<Self>) {
+        self.set_selected_index(None, cx);
+        cx.emit(DropdownEvent::Confirm(None));
     }
 }
 
@@ -545,7 +466,7 @@ where
     D: DropdownDelegate + 'static,
 {
     fn with_size(mut self, size: impl Into<Size>) -> Self {
-        self.size = size.into();
+        self.popup.size = size.into();
         self
     }
 }
@@ -557,11 +478,7 @@ where
     D: DropdownDelegate + 'static,
 {
     fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
-        if self.open {
-            self.list.focus_handle(cx)
-        } else {
-            self.focus_handle.clone()
-        }
+        self.popup.focus_handle(cx)
     }
 }
 
@@ -570,140 +487,9 @@ where
     D: DropdownDelegate + 'static,
 {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        let is_focused = self.focus_handle.is_focused(cx);
-        let show_clean = self.cleanable && self.selected_index(cx).is_some();
-        let view = cx.view().clone();
-        let bounds = self.bounds;
-        let allow_open = !(self.open || self.disabled);
-        let outline_visible = self.open || is_focused && !self.disabled;
-
-        // If the size has change, set size to self.list, to change the QueryInput size.
-        if self.list.read(cx).size != self.size {
-            self.list
-                .update(cx, |this, cx| this.set_size(self.size, cx))
-        }
+        let show_clean = self.popup.cleanable && self.selected_index(cx).is_some();
+        let title = self.display_title(cx);
 
-        div()
-            .id(self.id.clone())
-            .key_context(CONTEXT)
-            .track_focus(&self.focus_handle)
-            .on_action(cx.listener(Self::up))
-            .on_action(cx.listener(Self::down))
-            .on_action(cx.listener(Self::enter))
-            .on_action(cx.listener(Self::escape))
-            .size_full()
-            .relative()
-            .input_text_size(self.size)
-            .child(
-                div()
-                    .id("dropdown-input")
-                    .relative()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .bg(cx.theme().background)
-                    .border_1()
-                    .border_color(cx.theme().input)
-                    .rounded(px(cx.theme().radius))
-                    .when(cx.theme().shadow, |this| this.shadow_sm())
-                    .map(|this| {
-                        if self.disabled {
-                            this.cursor_not_allowed()
-                        } else {
-                            this.cursor_pointer()
-                        }
-                    })
-                    .overflow_hidden()
-                    .input_text_size(self.size)
-                    .map(|this| match self.width {
-                        Length::Definite(l) => this.flex_none().w(l),
-                        Length::Auto => this.w_full(),
-                    })
-                    .when(outline_visible, |this| this.outline(cx))
-                    .input_size(self.size)
-                    .when(allow_open, |this| {
-                        this.on_click(cx.listener(Self::toggle_menu))
-                    })
-                    .child(
-                        h_flex()
-                            .w_full()
-                            .items_center()
-                            .justify_between()
-                            .gap_1()
-                            .child(
-                                div()
-                                    .w_full()
-                                    .overflow_hidden()
-                                    .child(self.display_title(cx)),
-                            )
-                            .when(show_clean, |this| {
-                                this.child(ClearButton::new(cx).map(|this| {
-                                    if self.disabled {
-                                        this.disabled(true)
-                                    } else {
-                                        this.on_click(cx.listener(Self::clean))
-                                    }
-                                }))
-                            })
-                            .when(!show_clean, |this| {
-                                let icon = match self.icon.clone() {
-                                    Some(icon) => icon,
-                                    None => {
-                                        if self.open {
-                                            IconName::ChevronUp
-                                        } else {
-                                            IconName::ChevronDown
-                                        }
-                                    }
-                                };
-
-                                this.child(
-                                    Icon::new(icon)
-                                        .text_color(cx.theme().muted_foreground)
-                                        .when(self.disabled, |this| this.cursor_not_allowed()),
-                                )
-                            }),
-                    )
-                    .child(
-                        canvas(
-                            move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
-                            |_, _, _| {},
-                        )
-                        .absolute()
-                        .size_full(),
-                    ),
-            )
-            .when(self.open, |this| {
-                this.child(
-                    deferred(
-                        anchored().snap_to_window_with_margin(px(8.)).child(
-                            div()
-                                .occlude()
-                                .map(|this| match self.menu_width {
-                                    Length::Auto => this.w(bounds.size.width),
-                                    Length::Definite(w) => this.w(w),
-                                })
-                                .child(
-                                    v_flex()
-                                        .occlude()
-                                        .mt_1p5()
-                                        .bg(cx.theme().background)
-                                        .border_1()
-                                        .border_color(cx.theme().border)
-                                        .rounded(px(cx.theme().radius))
-                                        .shadow_md()
-                                        .on_mouse_down_out(|_, cx| {
-                                            cx.dispatch_action(Box::new(Escape));
-                                        })
-                                        .child(self.list.clone()),
-                                )
-                                .on_mouse_down_out(cx.listener(|this, _, cx| {
-                                    this.escape(&Escape, cx);
-                                })),
-                        ),
-                    )
-                    .with_priority(1),
-                )
-            })
+        render_popup(self, title, show_clean, cx)
     }
 }
@@ -0,0 +1,264 @@
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, AppContext, ClickEvent, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _, Render,
+    SharedString, StatefulInteractiveElement as _, Styled as _, View, ViewContext,
+    VisualContext as _,
+};
+
+use crate::{
+    h_flex,
+    input::{InputEvent, SelectAll, TextInput},
+    theme::ActiveTheme,
+    Sizable as _,
+};
+
+actions!(editable_label, [Edit, Cancel]);
+
+const CONTEXT: &str = "EditableLabel";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("f2", Edit, Some(CONTEXT)),
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+    ]);
+}
+
+pub enum EditableLabelEvent {
+    /// Emitted when the edited text is committed, the text is the new text.
+    Change(SharedString),
+    /// Emitted when the editing is canceled.
+    Cancel,
+}
+
+type Validate = Box<dyn Fn(&str) -> Result<(), SharedString> + 'static>;
+
+/// A label that displays as plain text and turns into a [`TextInput`] to edit on click (or F2).
+///
+/// - Press `Enter` or blur to commit the change.
+/// - Press `Escape` to cancel.
+pub struct EditableLabel {
+    focus_handle: FocusHandle,
+    text: SharedString,
+    input: View<TextInput>,
+    editing: bool,
+    disabled: bool,
+    placeholder: SharedString,
+    validate: Option<Validate>,
+    error: Option<SharedString>,
+}
+
+impl EventEmitter<EditableLabelEvent> for EditableLabel {}
+
+impl EditableLabel {
+    pub fn new(text: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| TextInput::new(cx).small());
+        cx.subscribe(&input, Self::on_input_event).detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            text: text.into(),
+            input,
+            editing: false,
+            disabled: false,
+            placeholder: SharedString::default(),
+            validate: None,
+            error: None,
+        }
+    }
+
+    /// Set the placeholder to display when the text is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the validation of the text, return `Err` with the message to reject the change.
+    pub fn validate(mut self, f: impl Fn(&str) -> Result<(), SharedString> + 'static) -> Self {
+        self.validate = Some(Box::new(f));
+        self
+    }
+
+    /// Set the disabled state, the disabled label can't be edited.
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.disabled = disabled;
+        if disabled && self.editing {
+            self.cancel(cx);
+        }
+        cx.notify();
+    }
+
+    /// Set the text of the label.
+    pub fn set_text(&mut self, text: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        self.text = text.into();
+        cx.notify();
+    }
+
+    pub fn text(&self) -> SharedString {
+        self.text.clone()
+    }
+
+    /// Returns true if the label is in editing.
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Turn the label into the input to edit the text.
+    pub fn edit(&mut self, cx: &mut ViewContext<Self>) {
+        if self.disabled || self.editing {
+            return;
+        }
+
+        self.editing = true;
+        self.error = None;
+        let text = self.text.clone();
+        let placeholder = self.placeholder.clone();
+        self.input.update(cx, |input, cx| {
+            input.set_placeholder(placeholder);
+            input.set_text(text, cx);
+            input.select_all(&SelectAll, cx);
+            input.focus(cx);
+        });
+        cx.notify();
+    }
+
+    /// Commit the editing text, keep editing if the validation fails.
+    ///
+    /// Returns true if committed.
+    pub fn commit(&mut self, cx: &mut ViewContext<Self>) -> bool {
+        if !self.editing {
+            return false;
+        }
+
+        let new_text = self.input.read(cx).text();
+        if let Some(validate) = &self.validate {
+            if let Err(err) = validate(&new_text) {
+                self.error = Some(err);
+                cx.notify();
+                return false;
+            }
+        }
+
+        self.editing = false;
+        self.error = None;
+        if new_text != self.text {
+            self.text = new_text.clone();
+            cx.emit(EditableLabelEvent::Change(new_text));
+        }
+        self.focus_handle.focus(cx);
+        cx.notify();
+        true
+    }
+
+    /// Cancel the editing and restore the text.
+    pub fn cancel(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.editing {
+            return;
+        }
+
+        self.editing = false;
+        self.error = None;
+        self.focus_handle.focus(cx);
+        cx.emit(EditableLabelEvent::Cancel);
+        cx.notify();
+    }
+
+    fn on_action_edit(&mut self, _: &Edit, cx: &mut ViewContext<Self>) {
+        self.edit(cx);
+    }
+
+    fn on_action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        if !self.editing {
+            cx.propagate();
+            return;
+        }
+
+        self.cancel(cx);
+    }
+
+    fn on_click(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.edit(cx);
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::PressEnter => {
+                self.commit(cx);
+            }
+            InputEvent::Blur => {
+                // The invalid text can't be kept after blur, so cancel it.
+                if !self.commit(cx) {
+                    self.cancel(cx);
+                }
+            }
+            InputEvent::Change(_) => {
+                if self.error.take().is_some() {
+                    cx.notify();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl FocusableView for EditableLabel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for EditableLabel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let is_empty = self.text.is_empty();
+
+        div()
+            .id("editable-label")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_action_edit))
+            .on_action(cx.listener(Self::on_action_cancel))
+            .map(|this| {
+                if self.editing {
+                    this.child(self.input.clone())
+                        .when_some(self.error.clone(), |this, error| {
+                            this.child(
+                                div()
+                                    .mt_1()
+                                    .text_xs()
+                                    .text_color(cx.theme().destructive)
+                                    .child(error),
+                            )
+                        })
+                } else {
+                    this.child(
+                        h_flex()
+                            .id("label")
+                            .px_1()
+                            .rounded_md()
+                            .overflow_x_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .when(!self.disabled, |this| {
+                                this.cursor_text()
+                                    .hover(|this| this.bg(cx.theme().accent))
+                                    .on_click(cx.listener(Self::on_click))
+                            })
+                            .when(is_empty, |this| {
+                                this.text_color(cx.theme().muted_foreground)
+                            })
+                            .map(|this| {
+                                if is_empty {
+                                    this.child(self.placeholder.clone())
+                                } else {
+                                    this.child(self.text.clone())
+                                }
+                            }),
+                    )
+                }
+            })
+    }
+}
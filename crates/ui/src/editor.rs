@@ -0,0 +1,142 @@
+use std::rc::Rc;
+
+use gpui::{
+    AppContext, EventEmitter, FocusHandle, FocusableView, IntoElement, Render, SharedString,
+    Subscription, View, ViewContext, VisualContext as _,
+};
+
+use crate::{
+    highlighter::Highlighter,
+    input::{InputEvent, TextInput},
+};
+
+/// A multi-line code editor, with the line numbers, monospace font and the brackets auto-pairing.
+///
+/// - The long lines are scrolled horizontally by default, set [`CodeEditor::soft_wrap`] to wrap them.
+/// - The code is highlighted by the [`Highlighter`] of the language, see [`CodeEditor::highlighter`].
+/// - The [`InputEvent`]s of the inner [`TextInput`] are emitted by the CodeEditor.
+///
+/// ```ignore
+/// cx.new_view(|cx| CodeEditor::new(cx).highlighter("json", JsonHighlighter).rows(20))
+/// ```
+pub struct CodeEditor {
+    input: View<TextInput>,
+    /// The highlighter set by the builder, it's moved to the input on the first render.
+    highlighter: Option<(SharedString, Rc<dyn Highlighter>)>,
+    line_number: bool,
+    soft_wrap: bool,
+    rows: usize,
+    max_rows: usize,
+    _subscription: Subscription,
+}
+
+impl CodeEditor {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .multi_line()
+                .monospace(true)
+                .auto_pair(true)
+        });
+        let _subscription = cx.subscribe(&input, |_, _, event: &InputEvent, cx| {
+            cx.emit(event.clone())
+        });
+
+        Self {
+            input,
+            highlighter: None,
+            line_number: true,
+            soft_wrap: false,
+            rows: 10,
+            max_rows: 30,
+            _subscription,
+        }
+    }
+
+    /// Set the highlighter to highlight the code as the language, e.g.: `json`.
+    pub fn highlighter(
+        mut self,
+        language: impl Into<SharedString>,
+        highlighter: impl Highlighter + 'static,
+    ) -> Self {
+        self.highlighter = Some((language.into(), Rc::new(highlighter)));
+        self
+    }
+
+    /// Replace the highlighter, e.g.: when the language of the file is changed.
+    pub fn set_highlighter(
+        &mut self,
+        language: impl Into<SharedString>,
+        highlighter: impl Highlighter + 'static,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.highlighter = None;
+        let language = language.into();
+        self.input.update(cx, |input, cx| {
+            input.set_highlighter(language, Rc::new(highlighter), cx)
+        });
+    }
+
+    /// Set false to hide the line numbers, default: true
+    pub fn line_number(mut self, line_number: bool) -> Self {
+        self.line_number = line_number;
+        self
+    }
+
+    /// Set true to wrap the long lines instead of scrolling them horizontally, default: false
+    pub fn soft_wrap(mut self, soft_wrap: bool) -> Self {
+        self.soft_wrap = soft_wrap;
+        self
+    }
+
+    /// Set the min visible rows, default: 10
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Set the max visible rows, it scrolls beyond the max rows, default: 30
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Return the code in the editor.
+    pub fn text(&self, cx: &AppContext) -> SharedString {
+        self.input.read(cx).text()
+    }
+
+    /// Set the code in the editor.
+    pub fn set_text(&mut self, text: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        self.input.update(cx, |input, cx| input.set_text(text, cx));
+    }
+
+    pub fn focus(&self, cx: &mut ViewContext<Self>) {
+        self.input.focus_handle(cx).focus(cx);
+    }
+}
+
+impl EventEmitter<InputEvent> for CodeEditor {}
+
+impl FocusableView for CodeEditor {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for CodeEditor {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let highlighter = self.highlighter.take();
+        self.input.update(cx, |input, cx| {
+            input.line_number = self.line_number;
+            input.soft_wrap = self.soft_wrap;
+            input.rows = self.rows;
+            input.max_rows = self.max_rows;
+            if let Some((language, highlighter)) = highlighter {
+                input.set_highlighter(language, highlighter, cx);
+            }
+        });
+
+        self.input.clone()
+    }
+}
@@ -0,0 +1,171 @@
+use std::{path::PathBuf, rc::Rc};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, AnyElement, Div, ElementId, ExternalPaths,
+    InteractiveElement as _, IntoElement, ParentElement, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled, WindowContext,
+};
+use rust_i18n::t;
+use smallvec::SmallVec;
+
+use crate::{theme::ActiveTheme as _, v_flex, Disableable, Icon, IconName};
+
+use super::{filter_paths, FilePicker};
+
+/// An area to accept the files dragged from the OS, and highlight when the files are dragged over.
+///
+/// The default content is an upload icon with the label, use `child` to customize it.
+///
+/// ```ignore
+/// DropZone::new("drop-zone")
+///     .picker(FilePicker::new().multiple(true))
+///     .on_drop(|paths, cx| println!("{:?}", paths))
+/// ```
+#[derive(IntoElement)]
+pub struct DropZone {
+    id: ElementId,
+    base: Div,
+    label: Option<SharedString>,
+    description: Option<SharedString>,
+    picker: Option<FilePicker>,
+    disabled: bool,
+    on_drop: Option<Rc<dyn Fn(&[PathBuf], &mut WindowContext) + 'static>>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl DropZone {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            label: None,
+            description: None,
+            picker: None,
+            disabled: false,
+            on_drop: None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Set the label of the default content, default: "Drop files here".
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the description under the label of the default content.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set a file picker to open when clicked, the dropped paths are also filtered by its filters.
+    pub fn picker(mut self, picker: FilePicker) -> Self {
+        self.picker = Some(picker);
+        self
+    }
+
+    /// Set the handler to receive the dropped or picked paths.
+    pub fn on_drop(mut self, handler: impl Fn(&[PathBuf], &mut WindowContext) + 'static) -> Self {
+        self.on_drop = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Disableable for DropZone {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Styled for DropZone {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for DropZone {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for DropZone {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let filters = self
+            .picker
+            .as_ref()
+            .map(|picker| picker.filters().to_vec())
+            .unwrap_or_default();
+        let label = self.label.unwrap_or_else(|| t!("DropZone.label").into());
+        let description = self.description.or_else(|| {
+            self.picker
+                .as_ref()
+                .map(|_| t!("DropZone.description").into())
+        });
+        let has_children = !self.children.is_empty();
+        let on_drop = self.on_drop.filter(|_| !self.disabled);
+
+        self.base
+            .id(self.id)
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .p_6()
+            .border_2()
+            .border_color(cx.theme().border)
+            .rounded_lg()
+            .when(self.disabled, |this| this.opacity(0.5))
+            .when(!has_children, |this| {
+                this.child(
+                    v_flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            Icon::new(IconName::Upload)
+                                .size_6()
+                                .text_color(cx.theme().muted_foreground),
+                        )
+                        .child(div().text_sm().child(label))
+                        .when_some(description, |this, description| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(description),
+                            )
+                        }),
+                )
+            })
+            .children(self.children)
+            .when_some(on_drop, |this, on_drop| {
+                let on_picked = on_drop.clone();
+                this.drag_over::<ExternalPaths>(|this, _, cx| {
+                    this.border_color(cx.theme().primary)
+                        .bg(cx.theme().drop_target)
+                })
+                .on_drop(move |paths: &ExternalPaths, cx| {
+                    let paths = filter_paths(paths.paths().to_vec(), &filters);
+                    if !paths.is_empty() {
+                        on_drop(&paths, cx);
+                    }
+                })
+                .when_some(self.picker, |this, picker| {
+                    this.cursor_pointer()
+                        .hover(|this| this.bg(cx.theme().accent.opacity(0.5)))
+                        .on_click(move |_, cx| {
+                            let task = picker.clone().pick(cx);
+                            let on_picked = on_picked.clone();
+                            cx.spawn(|mut cx| async move {
+                                if let Some(paths) = task.await {
+                                    _ = cx.update(|cx| on_picked(&paths, cx));
+                                }
+                            })
+                            .detach();
+                        })
+                })
+            })
+    }
+}
@@ -0,0 +1,193 @@
+use std::path::{Path, PathBuf};
+
+use gpui::{AppContext, PathPromptOptions, SharedString, Task};
+
+/// A filter of the file extensions, e.g.: `FileFilter::new("Images", ["png", "jpg"])`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFilter {
+    pub name: SharedString,
+    /// The extensions without the dot, matched case-insensitively.
+    pub extensions: Vec<SharedString>,
+}
+
+impl FileFilter {
+    pub fn new(
+        name: impl Into<SharedString>,
+        extensions: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            extensions: extensions.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Return true if the extension of the path is in the filter.
+    pub fn matches(&self, path: &Path) -> bool {
+        let Some(ext) = path.extension().and_then(|ext| ext.to_str()) else {
+            return false;
+        };
+
+        self.extensions
+            .iter()
+            .any(|filter| filter.eq_ignore_ascii_case(ext))
+    }
+}
+
+/// Keep the paths matched any of the filters, all the paths are kept if there is no filter.
+pub(crate) fn filter_paths(paths: Vec<PathBuf>, filters: &[FileFilter]) -> Vec<PathBuf> {
+    if filters.is_empty() {
+        return paths;
+    }
+
+    paths
+        .into_iter()
+        .filter(|path| filters.iter().any(|filter| filter.matches(path)))
+        .collect()
+}
+
+/// Append the first extension of the filters if the path is not matched any of the filters.
+fn with_default_extension(path: PathBuf, filters: &[FileFilter]) -> PathBuf {
+    if filters.is_empty() || filters.iter().any(|filter| filter.matches(&path)) {
+        return path;
+    }
+
+    match filters.iter().find_map(|filter| filter.extensions.first()) {
+        Some(ext) => {
+            let mut path = path.into_os_string();
+            path.push(".");
+            path.push(ext.as_ref());
+            path.into()
+        }
+        None => path,
+    }
+}
+
+/// The platform file dialogs to open files, folders, or save a file.
+///
+/// The platform dialogs not support the filters on all platforms,
+/// so the picked paths are filtered after the dialog is closed.
+///
+/// ```ignore
+/// let task = FilePicker::new()
+///     .multiple(true)
+///     .filter(FileFilter::new("Images", ["png", "jpg"]))
+///     .pick(cx);
+/// cx.spawn(|_, _| async move {
+///     if let Some(paths) = task.await {
+///         println!("{:?}", paths);
+///     }
+/// })
+/// .detach();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FilePicker {
+    directories: bool,
+    multiple: bool,
+    filters: Vec<FileFilter>,
+}
+
+impl FilePicker {
+    /// Create a file picker to pick the files.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pick the folders instead of the files.
+    pub fn directories(mut self) -> Self {
+        self.directories = true;
+        self
+    }
+
+    /// Set true to allow picking multiple paths, default is false.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Add a filter of the file extensions, the paths matched any of the filters are accepted.
+    pub fn filter(mut self, filter: FileFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Get the filters of the file picker.
+    pub fn filters(&self) -> &[FileFilter] {
+        &self.filters
+    }
+
+    /// Open the platform dialog to pick the paths, returns None if canceled or nothing is matched.
+    pub fn pick(self, cx: &AppContext) -> Task<Option<Vec<PathBuf>>> {
+        let rx = cx.prompt_for_paths(PathPromptOptions {
+            files: !self.directories,
+            directories: self.directories,
+            multiple: self.multiple,
+        });
+
+        cx.background_executor().spawn(async move {
+            let paths = rx.await.ok().flatten()?;
+            let paths = if self.directories {
+                paths
+            } else {
+                filter_paths(paths, &self.filters)
+            };
+
+            (!paths.is_empty()).then_some(paths)
+        })
+    }
+
+    /// Open the platform dialog to save a file in the directory, returns None if canceled.
+    ///
+    /// The first extension of the filters is appended if the path is not matched any of them.
+    pub fn save(self, directory: &Path, cx: &AppContext) -> Task<Option<PathBuf>> {
+        let rx = cx.prompt_for_new_path(directory);
+
+        cx.background_executor().spawn(async move {
+            let path = rx.await.ok().flatten()?;
+            Some(with_default_extension(path, &self.filters))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{filter_paths, with_default_extension, FileFilter};
+
+    #[test]
+    fn test_filter_paths() {
+        let images = FileFilter::new("Images", ["png", "jpg"]);
+        assert!(images.matches(Path::new("a/b.png")));
+        assert!(images.matches(Path::new("a/b.JPG")));
+        assert!(!images.matches(Path::new("a/b.svg")));
+        assert!(!images.matches(Path::new("a/png")));
+
+        let paths = vec![
+            PathBuf::from("a.png"),
+            PathBuf::from("b.txt"),
+            PathBuf::from("c.jpg"),
+        ];
+        assert_eq!(
+            filter_paths(paths.clone(), &[images]),
+            vec![PathBuf::from("a.png"), PathBuf::from("c.jpg")]
+        );
+        assert_eq!(filter_paths(paths.clone(), &[]), paths);
+    }
+
+    #[test]
+    fn test_with_default_extension() {
+        let filters = [FileFilter::new("CSV", ["csv", "tsv"])];
+        assert_eq!(
+            with_default_extension(PathBuf::from("a/report"), &filters),
+            PathBuf::from("a/report.csv")
+        );
+        assert_eq!(
+            with_default_extension(PathBuf::from("a/report.tsv"), &filters),
+            PathBuf::from("a/report.tsv")
+        );
+        assert_eq!(
+            with_default_extension(PathBuf::from("a/report"), &[]),
+            PathBuf::from("a/report")
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod drop_zone;
+mod file_picker;
+
+pub use drop_zone::*;
+pub use file_picker::*;
@@ -0,0 +1,307 @@
+use chrono::NaiveDate;
+use gpui::{
+    prelude::FluentBuilder as _, AnyElement, AppContext, ElementId, EventEmitter, FocusHandle,
+    FocusableView, IntoElement, Render, SharedString, Subscription, View, ViewContext,
+    WindowContext,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{
+    checkbox::Checkbox,
+    input::{InputEvent, TextInput},
+    select::{Select, SelectEvent},
+    time::date_picker::{Date, DatePicker, DatePickerEvent},
+};
+
+use super::Form;
+
+/// A control can be used in a [`super::FormField`], e.g.: `View<TextInput>`.
+///
+/// The value is a [`serde_json::Value`] to validate the field and serialize the form.
+pub trait FormControl: 'static {
+    /// Return the current value of the control.
+    fn value(&self, cx: &AppContext) -> Value;
+
+    /// Set the value of the control, the value in the unexpected type is ignored.
+    fn set_value(&self, value: &Value, cx: &mut WindowContext);
+
+    /// Return the focus handle to focus the control when it is invalid.
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle;
+
+    /// Call the `on_change` when the value of the control is changed by the user.
+    fn observe_change(
+        &self,
+        on_change: Box<dyn Fn(&mut Form, &mut ViewContext<Form>)>,
+        cx: &mut ViewContext<Form>,
+    ) -> Subscription;
+
+    /// Render the control.
+    fn render(&self) -> AnyElement;
+}
+
+/// The value of the [`TextInput`] is a string, the number and bool are also accepted to set.
+impl FormControl for View<TextInput> {
+    fn value(&self, cx: &AppContext) -> Value {
+        Value::String(self.read(cx).text().to_string())
+    }
+
+    fn set_value(&self, value: &Value, cx: &mut WindowContext) {
+        let text = match value {
+            Value::String(text) => text.clone(),
+            Value::Number(number) => number.to_string(),
+            Value::Bool(value) => value.to_string(),
+            Value::Null => String::new(),
+            _ => return,
+        };
+        self.update(cx, |input, cx| input.set_text(text, cx));
+    }
+
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.read(cx).focus_handle(cx)
+    }
+
+    fn observe_change(
+        &self,
+        on_change: Box<dyn Fn(&mut Form, &mut ViewContext<Form>)>,
+        cx: &mut ViewContext<Form>,
+    ) -> Subscription {
+        cx.subscribe(self, move |form, _, event: &InputEvent, cx| {
+            if let InputEvent::Change(_) = event {
+                on_change(form, cx);
+            }
+        })
+    }
+
+    fn render(&self) -> AnyElement {
+        self.clone().into_any_element()
+    }
+}
+
+/// The value of the [`Select`] is the serialized value of the selected option, or `null`.
+impl<T> FormControl for View<Select<T>>
+where
+    T: Clone + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    fn value(&self, cx: &AppContext) -> Value {
+        serde_json::to_value(self.read(cx).value()).unwrap_or_default()
+    }
+
+    fn set_value(&self, value: &Value, cx: &mut WindowContext) {
+        if let Ok(value) = serde_json::from_value::<Option<T>>(value.clone()) {
+            self.update(cx, |select, cx| select.set_value(value, cx));
+        }
+    }
+
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.read(cx).focus_handle(cx)
+    }
+
+    fn observe_change(
+        &self,
+        on_change: Box<dyn Fn(&mut Form, &mut ViewContext<Form>)>,
+        cx: &mut ViewContext<Form>,
+    ) -> Subscription {
+        cx.subscribe(
+            self,
+            move |form, _, event: &SelectEvent<T>, cx| match event {
+                SelectEvent::Change(_) => on_change(form, cx),
+            },
+        )
+    }
+
+    fn render(&self) -> AnyElement {
+        self.clone().into_any_element()
+    }
+}
+
+fn date_to_value(date: Option<NaiveDate>) -> Value {
+    date.map_or(Value::Null, |date| Value::String(date.to_string()))
+}
+
+fn value_to_date(value: &Value) -> Option<Option<NaiveDate>> {
+    match value {
+        Value::Null => Some(None),
+        Value::String(text) if text.is_empty() => Some(None),
+        Value::String(text) => text.parse().ok().map(Some),
+        _ => None,
+    }
+}
+
+/// The value of the [`DatePicker`] is the date in `YYYY-MM-DD` or `null`,
+/// and a `[start, end]` array in the range mode.
+impl FormControl for View<DatePicker> {
+    fn value(&self, cx: &AppContext) -> Value {
+        match self.read(cx).date() {
+            Date::Single(date) => date_to_value(date),
+            Date::Range(start, end) => Value::Array(vec![date_to_value(start), date_to_value(end)]),
+        }
+    }
+
+    fn set_value(&self, value: &Value, cx: &mut WindowContext) {
+        let date = match (self.read(cx).date(), value) {
+            (Date::Single(_), value) => value_to_date(value).map(Date::Single),
+            (Date::Range(..), Value::Null) => Some(Date::Range(None, None)),
+            (Date::Range(..), Value::Array(items)) if items.len() == 2 => {
+                match (value_to_date(&items[0]), value_to_date(&items[1])) {
+                    (Some(start), Some(end)) => Some(Date::Range(start, end)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(date) = date {
+            self.update(cx, |picker, cx| picker.set_date(date, cx));
+        }
+    }
+
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.read(cx).focus_handle(cx)
+    }
+
+    fn observe_change(
+        &self,
+        on_change: Box<dyn Fn(&mut Form, &mut ViewContext<Form>)>,
+        cx: &mut ViewContext<Form>,
+    ) -> Subscription {
+        cx.subscribe(
+            self,
+            move |form, _, event: &DatePickerEvent, cx| match event {
+                DatePickerEvent::Change(_) => on_change(form, cx),
+            },
+        )
+    }
+
+    fn render(&self) -> AnyElement {
+        self.clone().into_any_element()
+    }
+}
+
+pub enum FormCheckboxEvent {
+    Change(bool),
+}
+
+/// A [`Checkbox`] keeps the checked state to be used in a [`super::FormField`], the value is a bool.
+///
+/// ```ignore
+/// let agree = cx.new_view(|cx| FormCheckbox::new("agree", cx).label("I agree to the terms"));
+/// FormField::new("agree", agree).validator(Validator::required())
+/// ```
+pub struct FormCheckbox {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    label: Option<SharedString>,
+    checked: bool,
+}
+
+impl FormCheckbox {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            label: None,
+            checked: false,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Returns the checked state.
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Set the checked state, this will not emit the change event.
+    pub fn set_checked(&mut self, checked: bool, cx: &mut ViewContext<Self>) {
+        self.checked = checked;
+        cx.notify();
+    }
+}
+
+impl EventEmitter<FormCheckboxEvent> for FormCheckbox {}
+
+impl FocusableView for FormCheckbox {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for FormCheckbox {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        Checkbox::new(self.id.clone())
+            .when_some(self.label.clone(), |this, label| this.label(label))
+            .checked(self.checked)
+            .focus_handle(&self.focus_handle)
+            .on_change(cx.listener(|this, checked: &bool, cx| {
+                this.checked = *checked;
+                cx.emit(FormCheckboxEvent::Change(*checked));
+                cx.notify();
+            }))
+    }
+}
+
+impl FormControl for View<FormCheckbox> {
+    fn value(&self, cx: &AppContext) -> Value {
+        Value::Bool(self.read(cx).is_checked())
+    }
+
+    fn set_value(&self, value: &Value, cx: &mut WindowContext) {
+        let checked = match value {
+            Value::Bool(checked) => *checked,
+            Value::Null => false,
+            _ => return,
+        };
+        self.update(cx, |checkbox, cx| checkbox.set_checked(checked, cx));
+    }
+
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.read(cx).focus_handle(cx)
+    }
+
+    fn observe_change(
+        &self,
+        on_change: Box<dyn Fn(&mut Form, &mut ViewContext<Form>)>,
+        cx: &mut ViewContext<Form>,
+    ) -> Subscription {
+        cx.subscribe(
+            self,
+            move |form, _, event: &FormCheckboxEvent, cx| match event {
+                FormCheckboxEvent::Change(_) => on_change(form, cx),
+            },
+        )
+    }
+
+    fn render(&self) -> AnyElement {
+        self.clone().into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use super::{date_to_value, value_to_date};
+
+    #[test]
+    fn test_date_value() {
+        let date = NaiveDate::from_ymd_opt(2024, 8, 3).unwrap();
+        assert_eq!(date_to_value(Some(date)), json!("2024-08-03"));
+        assert_eq!(date_to_value(None), json!(null));
+
+        assert_eq!(value_to_date(&json!("2024-08-03")), Some(Some(date)));
+        assert_eq!(value_to_date(&json!("")), Some(None));
+        assert_eq!(value_to_date(&json!(null)), Some(None));
+        assert_eq!(value_to_date(&json!("08/03/2024")), None);
+        assert_eq!(value_to_date(&json!(20240803)), None);
+    }
+}
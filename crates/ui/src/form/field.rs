@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+use gpui::{AppContext, SharedString};
+
+use super::{FormControl, Validator};
+
+/// A field of the [`super::Form`] with the label, control, description and the validation error.
+pub struct FormField {
+    pub(super) name: SharedString,
+    pub(super) label: Option<SharedString>,
+    pub(super) description: Option<SharedString>,
+    pub(super) control: Rc<dyn FormControl>,
+    pub(super) validators: Vec<Validator>,
+}
+
+impl FormField {
+    /// Create a field with the name and the control, the name is the key of the errors.
+    pub fn new(name: impl Into<SharedString>, control: impl FormControl) -> Self {
+        Self {
+            name: name.into(),
+            label: None,
+            description: None,
+            control: Rc::new(control),
+            validators: vec![],
+        }
+    }
+
+    /// Set the label above the control.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the help text under the control, it is hidden when there is an error.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a validator, the validators are run in order and stop at the first error.
+    pub fn validator(mut self, validator: Validator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Get the name of the field.
+    pub fn name(&self) -> &SharedString {
+        &self.name
+    }
+
+    /// Return true if the field has the required validator, to show the `*` after the label.
+    pub fn is_required(&self) -> bool {
+        self.validators.iter().any(|v| v.is_required())
+    }
+
+    /// Validate the current value of the field by the validators except the async validators.
+    pub(super) fn validate(&self, cx: &AppContext) -> Result<(), SharedString> {
+        let value = self.control.value(cx);
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator.validate(&value))
+    }
+}
@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, AppContext, EventEmitter, FocusHandle, FocusableView,
+    IntoElement, ParentElement as _, Render, SharedString, Styled as _, Subscription, Task,
+    ViewContext,
+};
+use rust_i18n::t;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, Disableable as _,
+};
+
+use super::FormField;
+
+pub enum FormEvent {
+    /// All the fields are valid and the form is submitted, with the values of the fields.
+    Submit(Value),
+}
+
+/// A form to layout the fields and validate them before submitting.
+///
+/// The field is validated when the value is changed, and all the fields are validated on submit,
+/// the first invalid field is focused if the validation is failed.
+///
+/// ```ignore
+/// let form = cx.new_view(|cx| {
+///     Form::new(cx)
+///         .field(
+///             FormField::new("email", email_input)
+///                 .label("Email")
+///                 .validator(Validator::required()),
+///             cx,
+///         )
+///         .submit_label("Sign Up")
+/// });
+/// cx.subscribe(&form, |_, _, FormEvent::Submit(values), _| { ... }).detach();
+/// ```
+///
+/// The values are a JSON object by the field names, use [`Form::set_values`] to fill the fields
+/// from a serializable struct, and [`Form::values_as`] to get it back.
+pub struct Form {
+    focus_handle: FocusHandle,
+    fields: Vec<FormField>,
+    errors: HashMap<SharedString, SharedString>,
+    /// The async validations in progress of the fields.
+    pending: HashMap<SharedString, Task<()>>,
+    /// The values set by code, to skip the validation of the change events caused by them.
+    loaded: HashMap<SharedString, Value>,
+    /// Emit the submit event after the pending validations are finished.
+    submitting: bool,
+    submit_label: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Form {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            fields: vec![],
+            errors: HashMap::new(),
+            pending: HashMap::new(),
+            loaded: HashMap::new(),
+            submitting: false,
+            submit_label: None,
+            _subscriptions: vec![],
+        }
+    }
+
+    /// Add a field to the form.
+    pub fn field(mut self, field: FormField, cx: &mut ViewContext<Self>) -> Self {
+        self.add_field(field, cx);
+        self
+    }
+
+    /// Set the label of the submit button, default: "Submit".
+    pub fn submit_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.submit_label = Some(label.into());
+        self
+    }
+
+    /// Add a field to the form, it is validated when the value of the control is changed.
+    pub fn add_field(&mut self, field: FormField, cx: &mut ViewContext<Self>) {
+        let name = field.name.clone();
+        let subscription = field.control.observe_change(
+            Box::new(move |form, cx| {
+                let Some(ix) = form.fields.iter().position(|f| f.name == name) else {
+                    return;
+                };
+                if let Some(value) = form.loaded.remove(&name) {
+                    if form.fields[ix].control.value(cx) == value {
+                        return;
+                    }
+                }
+                form.validate_field(ix, cx);
+            }),
+            cx,
+        );
+
+        self._subscriptions.push(subscription);
+        self.fields.push(field);
+        cx.notify();
+    }
+
+    /// Get the fields of the form.
+    pub fn fields(&self) -> &[FormField] {
+        &self.fields
+    }
+
+    /// Get the values of the fields as a JSON object by the field names.
+    pub fn values(&self, cx: &AppContext) -> Value {
+        Value::Object(
+            self.fields
+                .iter()
+                .map(|field| (field.name.to_string(), field.control.value(cx)))
+                .collect::<Map<_, _>>(),
+        )
+    }
+
+    /// Deserialize the values of the fields into `T`.
+    pub fn values_as<T: DeserializeOwned>(&self, cx: &AppContext) -> serde_json::Result<T> {
+        serde_json::from_value(self.values(cx))
+    }
+
+    /// Set the values of the fields from a serializable value, e.g.: a struct or a JSON object.
+    ///
+    /// The fields missing in the values are kept, and the errors are cleared.
+    pub fn set_values(
+        &mut self,
+        values: &impl Serialize,
+        cx: &mut ViewContext<Self>,
+    ) -> serde_json::Result<()> {
+        let Value::Object(values) = serde_json::to_value(values)? else {
+            return Err(serde::ser::Error::custom("the values must be an object"));
+        };
+
+        for field in &self.fields {
+            if let Some(value) = values.get(field.name.as_ref()) {
+                field.control.set_value(value, cx);
+                self.loaded
+                    .insert(field.name.clone(), field.control.value(cx));
+            }
+        }
+        self.errors.clear();
+        self.pending.clear();
+        cx.notify();
+        Ok(())
+    }
+
+    /// Get the validation errors of the fields by the name.
+    pub fn errors(&self) -> &HashMap<SharedString, SharedString> {
+        &self.errors
+    }
+
+    /// Get the validation error of the field.
+    pub fn error(&self, name: &str) -> Option<&SharedString> {
+        self.errors.get(name)
+    }
+
+    /// Set the error of the field, e.g.: the error returned by the server after submitting.
+    pub fn set_error(
+        &mut self,
+        name: impl Into<SharedString>,
+        error: Option<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let name = name.into();
+        match error {
+            Some(error) => self.errors.insert(name, error),
+            None => self.errors.remove(&name),
+        };
+        cx.notify();
+    }
+
+    /// Return true if there is no error and no pending async validation.
+    ///
+    /// The fields are validated on change, so the untouched fields are not counted until submit.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.pending.is_empty()
+    }
+
+    /// Return true if the async validations are in progress.
+    pub fn is_validating(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Validate the field by the index, the async validators are started if the others passed.
+    ///
+    /// Returns false if the field is invalid by the sync validators.
+    fn validate_field(&mut self, ix: usize, cx: &mut ViewContext<Self>) -> bool {
+        let Some(field) = self.fields.get(ix) else {
+            return true;
+        };
+        let name = field.name.clone();
+        // Drop to cancel the previous async validation.
+        self.pending.remove(&name);
+
+        if let Err(error) = field.validate(cx) {
+            self.errors.insert(name, error);
+            cx.notify();
+            return false;
+        }
+        self.errors.remove(&name);
+
+        let value = field.control.value(cx);
+        let tasks = field
+            .validators
+            .iter()
+            .filter_map(|validator| validator.validate_async(value.clone(), cx))
+            .collect::<Vec<_>>();
+        if !tasks.is_empty() {
+            let task = cx.spawn({
+                let name = name.clone();
+                move |this, mut cx| async move {
+                    let mut result = Ok(());
+                    for task in tasks {
+                        result = task.await;
+                        if result.is_err() {
+                            break;
+                        }
+                    }
+
+                    _ = this.update(&mut cx, |this, cx| {
+                        this.pending.remove(&name);
+                        if let Err(error) = result {
+                            this.errors.insert(name, error);
+                        }
+                        this.finish_submit(cx);
+                        cx.notify();
+                    });
+                }
+            });
+            self.pending.insert(name, task);
+        }
+
+        cx.notify();
+        true
+    }
+
+    /// Validate all the fields, returns false if any field is invalid by the sync validators.
+    pub fn validate(&mut self, cx: &mut ViewContext<Self>) -> bool {
+        // Validate all the fields to show all the errors.
+        (0..self.fields.len()).fold(true, |valid, ix| self.validate_field(ix, cx) && valid)
+    }
+
+    /// Validate all the fields and emit [`FormEvent::Submit`] if they are valid,
+    /// otherwise focus the first invalid field.
+    pub fn submit(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.validate(cx) {
+            self.focus_first_invalid(cx);
+            return;
+        }
+
+        self.submitting = true;
+        self.finish_submit(cx);
+    }
+
+    /// Emit the submit event when the async validations are finished.
+    fn finish_submit(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.submitting || !self.pending.is_empty() {
+            return;
+        }
+
+        self.submitting = false;
+        if self.errors.is_empty() {
+            cx.emit(FormEvent::Submit(self.values(cx)));
+        } else {
+            self.focus_first_invalid(cx);
+        }
+    }
+
+    fn focus_first_invalid(&self, cx: &mut ViewContext<Self>) {
+        if let Some(field) = self
+            .fields
+            .iter()
+            .find(|field| self.errors.contains_key(&field.name))
+        {
+            field.control.focus_handle(cx).focus(cx);
+        }
+    }
+
+    fn render_field(&self, field: &FormField, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let error = self.errors.get(&field.name).cloned();
+
+        v_flex()
+            .gap_1p5()
+            .when_some(field.label.clone(), |this, label| {
+                this.child(
+                    h_flex()
+                        .gap_0p5()
+                        .text_sm()
+                        .font_weight(gpui::FontWeight::MEDIUM)
+                        .when(error.is_some(), |this| {
+                            this.text_color(cx.theme().destructive)
+                        })
+                        .child(label)
+                        .when(field.is_required(), |this| {
+                            this.child(div().text_color(cx.theme().destructive).child("*"))
+                        }),
+                )
+            })
+            .child(field.control.render())
+            .map(|this| match error {
+                Some(error) => this.child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().destructive)
+                        .child(error),
+                ),
+                None => this.when_some(field.description.clone(), |this, description| {
+                    this.child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(description),
+                    )
+                }),
+            })
+    }
+}
+
+impl EventEmitter<FormEvent> for Form {}
+
+impl FocusableView for Form {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Form {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let submit_label = self
+            .submit_label
+            .clone()
+            .unwrap_or_else(|| t!("Form.submit").into());
+
+        v_flex()
+            .gap_4()
+            .children(
+                self.fields
+                    .iter()
+                    .map(|field| self.render_field(field, cx))
+                    .collect::<Vec<_>>(),
+            )
+            .child(
+                h_flex().child(
+                    Button::new("submit")
+                        .primary()
+                        .label(submit_label)
+                        .loading(self.submitting)
+                        .disabled(!self.is_valid())
+                        .on_click(cx.listener(|this, _, cx| this.submit(cx))),
+                ),
+            )
+    }
+}
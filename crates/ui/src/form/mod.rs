@@ -0,0 +1,9 @@
+mod control;
+mod field;
+mod form;
+mod validator;
+
+pub use control::*;
+pub use field::*;
+pub use form::*;
+pub use validator::*;
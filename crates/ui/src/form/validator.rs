@@ -0,0 +1,203 @@
+use std::rc::Rc;
+
+use gpui::{SharedString, Task, WindowContext};
+use regex::Regex;
+use rust_i18n::t;
+use serde_json::Value;
+
+type SyncCheck = Rc<dyn Fn(&Value) -> Result<(), SharedString>>;
+type AsyncCheck = Rc<dyn Fn(Value, &mut WindowContext) -> Task<Result<(), SharedString>>>;
+
+#[derive(Clone)]
+enum Rule {
+    Required,
+    Min(f64),
+    Max(f64),
+    Pattern(Regex),
+    Custom(SyncCheck),
+    Async(AsyncCheck),
+}
+
+/// A rule to validate the value of a [`super::FormField`].
+///
+/// The `min`, `max` and `pattern` rules skip the empty values, use with `required` to reject them.
+///
+/// ```ignore
+/// FormField::new("name", input)
+///     .validator(Validator::required())
+///     .validator(Validator::min(3.))
+///     .validator(Validator::pattern(Regex::new("^[a-z]+$").unwrap()).message("Only a-z"))
+/// ```
+#[derive(Clone)]
+pub struct Validator {
+    rule: Rule,
+    message: Option<SharedString>,
+}
+
+/// Return true if the value is empty, the `false` is empty to require a checkbox to be checked.
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Bool(value) => !value,
+        Value::String(text) => text.trim().is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        Value::Number(_) => false,
+    }
+}
+
+/// Return the number to compare with the min and max, the length is used for the text and array.
+fn measure(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => Some(text.chars().count() as f64),
+        Value::Array(items) => Some(items.len() as f64),
+        _ => None,
+    }
+}
+
+impl Validator {
+    fn new(rule: Rule) -> Self {
+        Self {
+            rule,
+            message: None,
+        }
+    }
+
+    /// The value must not be empty.
+    pub fn required() -> Self {
+        Self::new(Rule::Required)
+    }
+
+    /// The number must be greater than or equal to `min`, or the length of the text or list.
+    pub fn min(min: f64) -> Self {
+        Self::new(Rule::Min(min))
+    }
+
+    /// The number must be less than or equal to `max`, or the length of the text or list.
+    pub fn max(max: f64) -> Self {
+        Self::new(Rule::Max(max))
+    }
+
+    /// The text must match the regex.
+    pub fn pattern(regex: Regex) -> Self {
+        Self::new(Rule::Pattern(regex))
+    }
+
+    /// Validate the value by a function, return the error message if it is invalid.
+    pub fn custom(check: impl Fn(&Value) -> Result<(), SharedString> + 'static) -> Self {
+        Self::new(Rule::Custom(Rc::new(check)))
+    }
+
+    /// Validate the value in a task, e.g.: check the username is available on the server.
+    ///
+    /// The async validators are run after all the other validators passed.
+    pub fn custom_async(
+        check: impl Fn(Value, &mut WindowContext) -> Task<Result<(), SharedString>> + 'static,
+    ) -> Self {
+        Self::new(Rule::Async(Rc::new(check)))
+    }
+
+    /// Set the error message instead of the default message.
+    pub fn message(mut self, message: impl Into<SharedString>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub(crate) fn is_required(&self) -> bool {
+        matches!(self.rule, Rule::Required)
+    }
+
+    fn error(&self, default: impl FnOnce() -> SharedString) -> SharedString {
+        self.message.clone().unwrap_or_else(default)
+    }
+
+    /// Validate the value, the async rule is always passed, use [`Validator::validate_async`] to run it.
+    pub(crate) fn validate(&self, value: &Value) -> Result<(), SharedString> {
+        let is_text = value.is_string();
+        match &self.rule {
+            Rule::Required if is_empty(value) => Err(self.error(|| t!("Form.required").into())),
+            Rule::Min(min) if !is_empty(value) && measure(value).map_or(false, |v| v < *min) => {
+                Err(self.error(|| {
+                    if is_text {
+                        t!("Form.min_length", min = min).into()
+                    } else {
+                        t!("Form.min", min = min).into()
+                    }
+                }))
+            }
+            Rule::Max(max) if !is_empty(value) && measure(value).map_or(false, |v| v > *max) => {
+                Err(self.error(|| {
+                    if is_text {
+                        t!("Form.max_length", max = max).into()
+                    } else {
+                        t!("Form.max", max = max).into()
+                    }
+                }))
+            }
+            Rule::Pattern(regex) => match value.as_str() {
+                Some(text) if !text.is_empty() && !regex.is_match(text) => {
+                    Err(self.error(|| t!("Form.pattern").into()))
+                }
+                _ => Ok(()),
+            },
+            Rule::Custom(check) => check(value).map_err(|err| self.error(|| err)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Start the async validation, returns None if this is not an async validator.
+    pub(crate) fn validate_async(
+        &self,
+        value: Value,
+        cx: &mut WindowContext,
+    ) -> Option<Task<Result<(), SharedString>>> {
+        match &self.rule {
+            Rule::Async(check) => Some(check(value, cx)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+    use serde_json::json;
+
+    use super::Validator;
+
+    #[test]
+    fn test_validate() {
+        let required = Validator::required();
+        assert!(required.validate(&json!(null)).is_err());
+        assert!(required.validate(&json!("  ")).is_err());
+        assert!(required.validate(&json!(false)).is_err());
+        assert!(required.validate(&json!([])).is_err());
+        assert!(required.validate(&json!("a")).is_ok());
+        assert!(required.validate(&json!(0)).is_ok());
+
+        let min = Validator::min(3.);
+        assert!(min.validate(&json!("ab")).is_err());
+        assert!(min.validate(&json!("abc")).is_ok());
+        assert!(min.validate(&json!(2)).is_err());
+        assert!(min.validate(&json!(3.5)).is_ok());
+        // The empty value is checked by the required.
+        assert!(min.validate(&json!("")).is_ok());
+
+        let max = Validator::max(2.).message("Too many");
+        assert_eq!(max.validate(&json!([1, 2, 3])).unwrap_err(), "Too many");
+        assert!(max.validate(&json!([1, 2])).is_ok());
+
+        let pattern = Validator::pattern(Regex::new(r"^\d+$").unwrap());
+        assert!(pattern.validate(&json!("12a")).is_err());
+        assert!(pattern.validate(&json!("123")).is_ok());
+        assert!(pattern.validate(&json!("")).is_ok());
+
+        let custom = Validator::custom(|value| match value.as_str() {
+            Some("admin") => Err("Reserved".into()),
+            _ => Ok(()),
+        });
+        assert_eq!(custom.validate(&json!("admin")).unwrap_err(), "Reserved");
+        assert!(custom.validate(&json!("jason")).is_ok());
+    }
+}
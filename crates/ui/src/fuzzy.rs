@@ -0,0 +1,211 @@
+//! A fuzzy matcher to filter and sort the candidates by a query, the scoring is similar to the SkimV2 (fzf v2) algorithm.
+
+const SCORE_MATCH: i64 = 16;
+const GAP_START: i64 = -3;
+const GAP_EXTENSION: i64 = -1;
+const BONUS_BOUNDARY: i64 = SCORE_MATCH / 2;
+const BONUS_NON_WORD: i64 = SCORE_MATCH / 2;
+const BONUS_CAMEL: i64 = BONUS_BOUNDARY + GAP_EXTENSION;
+const BONUS_CONSECUTIVE: i64 = -(GAP_START + GAP_EXTENSION);
+const BONUS_FIRST_CHAR_MULTIPLIER: i64 = 2;
+
+/// A matched candidate of [`match_strings`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringMatch {
+    /// The index of the candidate.
+    pub candidate_id: usize,
+    pub score: i64,
+    /// The byte offsets of the matched characters in the candidate.
+    pub positions: Vec<usize>,
+}
+
+/// Return the bonus of matching the `c` after the `prev` character.
+fn bonus(prev: Option<char>, c: char) -> i64 {
+    match prev {
+        None => BONUS_BOUNDARY,
+        Some(prev) if !prev.is_alphanumeric() && c.is_alphanumeric() => BONUS_BOUNDARY,
+        Some(prev)
+            if (prev.is_lowercase() && c.is_uppercase())
+                || (!prev.is_numeric() && c.is_numeric()) =>
+        {
+            BONUS_CAMEL
+        }
+        Some(_) if !c.is_alphanumeric() => BONUS_NON_WORD,
+        Some(_) => 0,
+    }
+}
+
+fn char_eq(a: char, b: char, case_sensitive: bool) -> bool {
+    a == b || (!case_sensitive && a.to_lowercase().eq(b.to_lowercase()))
+}
+
+/// Match the `query` in the `text` by the characters in order,
+/// return the score and the byte offsets of the matched characters, or `None` if not matched.
+///
+/// The match is case-insensitive unless the query contains uppercase characters.
+/// The consecutive matches and the matches at the word boundaries get higher scores, the gaps are penalized.
+pub fn fuzzy_match(text: &str, query: &str) -> Option<(i64, Vec<usize>)> {
+    let query = query.chars().collect::<Vec<_>>();
+    if query.is_empty() {
+        return Some((0, vec![]));
+    }
+
+    let case_sensitive = query.iter().any(|c| c.is_uppercase());
+    let chars = text.char_indices().collect::<Vec<_>>();
+    let (n, m) = (chars.len(), query.len());
+
+    // Quick check that the query is a subsequence of the text.
+    let mut rest = query.iter().peekable();
+    for (_, c) in &chars {
+        if rest
+            .peek()
+            .is_some_and(|q| char_eq(*c, **q, case_sensitive))
+        {
+            rest.next();
+        }
+    }
+    if rest.peek().is_some() {
+        return None;
+    }
+
+    let bonuses = (0..n)
+        .map(|j| bonus(j.checked_sub(1).map(|k| chars[k].1), chars[j].1))
+        .collect::<Vec<_>>();
+
+    // The best score of matching `query[..=i]` with `query[i]` at `chars[j]`,
+    // the bonus of the consecutive chunk, and the previous matched index for backtracking.
+    let mut scores = vec![vec![None::<i64>; n]; m];
+    let mut chunk_bonuses = vec![vec![0; n]; m];
+    let mut prevs = vec![vec![0; n]; m];
+
+    for i in 0..m {
+        // The best score with a gap before `j`: (score, previous index).
+        let mut gapped: Option<(i64, usize)> = None;
+        for j in i..n {
+            if i > 0 && j >= 2 {
+                gapped = gapped.map(|(score, k)| (score + GAP_EXTENSION, k));
+                if let Some(score) = scores[i - 1][j - 2] {
+                    if gapped.map_or(true, |(best, _)| score + GAP_START > best) {
+                        gapped = Some((score + GAP_START, j - 2));
+                    }
+                }
+            }
+
+            if !char_eq(chars[j].1, query[i], case_sensitive) {
+                continue;
+            }
+
+            if i == 0 {
+                scores[0][j] = Some(SCORE_MATCH + bonuses[j] * BONUS_FIRST_CHAR_MULTIPLIER);
+                chunk_bonuses[0][j] = bonuses[j];
+                continue;
+            }
+
+            let consecutive = (j > 0)
+                .then(|| scores[i - 1][j - 1])
+                .flatten()
+                .map(|score| {
+                    let chunk_bonus = chunk_bonuses[i - 1][j - 1]
+                        .max(bonuses[j])
+                        .max(BONUS_CONSECUTIVE);
+                    (score + SCORE_MATCH + chunk_bonus, chunk_bonus, j - 1)
+                });
+            let gapped = gapped.map(|(score, k)| (score + SCORE_MATCH + bonuses[j], bonuses[j], k));
+
+            let best = match (consecutive, gapped) {
+                (Some(a), Some(b)) => Some(if a.0 >= b.0 { a } else { b }),
+                (a, b) => a.or(b),
+            };
+            if let Some((score, chunk_bonus, prev)) = best {
+                scores[i][j] = Some(score);
+                chunk_bonuses[i][j] = chunk_bonus;
+                prevs[i][j] = prev;
+            }
+        }
+    }
+
+    let (mut j, score) = scores[m - 1]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, score)| score.map(|score| (j, score)))
+        .max_by_key(|(j, score)| (*score, std::cmp::Reverse(*j)))?;
+
+    let mut positions = vec![0; m];
+    for i in (0..m).rev() {
+        positions[i] = chars[j].0;
+        j = prevs[i][j];
+    }
+
+    Some((score, positions))
+}
+
+/// Match the `query` in the candidates, return the matches sorted by the score.
+///
+/// All candidates are returned in order if the query is empty.
+pub fn match_strings<S: AsRef<str>>(candidates: &[S], query: &str) -> Vec<StringMatch> {
+    let mut matches = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(candidate_id, candidate)| {
+            let (score, positions) = fuzzy_match(candidate.as_ref(), query)?;
+            Some(StringMatch {
+                candidate_id,
+                score,
+                positions,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    matches.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then(a.candidate_id.cmp(&b.candidate_id))
+    });
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuzzy_match, match_strings};
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert_eq!(fuzzy_match("hello", ""), Some((0, vec![])));
+        assert_eq!(fuzzy_match("hello", "hx"), None);
+        assert_eq!(fuzzy_match("hello", "hello!"), None);
+
+        let (_, positions) = fuzzy_match("hello world", "hw").unwrap();
+        assert_eq!(positions, vec![0, 6]);
+        let (_, positions) = fuzzy_match("Hello World", "wor").unwrap();
+        assert_eq!(positions, vec![6, 7, 8]);
+        // Prefer the word boundary and the consecutive matches.
+        let (_, positions) = fuzzy_match("abc_bcd", "bcd").unwrap();
+        assert_eq!(positions, vec![4, 5, 6]);
+        let (_, positions) = fuzzy_match("ListItem", "li").unwrap();
+        assert_eq!(positions, vec![0, 1]);
+        let (_, positions) = fuzzy_match("MyListItem", "LI").unwrap();
+        assert_eq!(positions, vec![2, 6]);
+        // Case-sensitive with the uppercase query.
+        assert_eq!(fuzzy_match("hello", "H"), None);
+        // The positions are byte offsets.
+        let (_, positions) = fuzzy_match("你好 world", "w").unwrap();
+        assert_eq!(positions, vec![7]);
+    }
+
+    #[test]
+    fn test_match_strings() {
+        let candidates = ["src/list.rs", "src/list/list_item.rs", "README.md", "lisp"];
+
+        let ids = |query| {
+            match_strings(&candidates, query)
+                .iter()
+                .map(|m| m.candidate_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(""), vec![0, 1, 2, 3]);
+        assert_eq!(ids("lsi"), vec![1]);
+        assert_eq!(ids("list"), vec![0, 1]);
+        assert_eq!(ids("lis")[2], 3);
+        assert_eq!(ids("md"), vec![2]);
+    }
+}
@@ -0,0 +1,18 @@
+use std::ops::Range;
+
+use gpui::{HighlightStyle, WindowContext};
+
+/// The pluggable syntax highlighter for the code blocks.
+///
+/// There is no built-in highlighter, implement it with the library you like, e.g.: `tree-sitter`, `syntect`.
+pub trait Highlighter {
+    /// Return the styles of the ranges in the code, the ranges must be sorted and not overlapped.
+    ///
+    /// The `language` is the name of the code language, e.g.: `rust`, it's empty if unknown.
+    fn highlight(
+        &self,
+        language: &str,
+        code: &str,
+        cx: &WindowContext,
+    ) -> Vec<(Range<usize>, HighlightStyle)>;
+}
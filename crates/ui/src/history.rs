@@ -17,6 +17,8 @@ pub struct History<I: HistoryItem> {
     pub(crate) ignore: bool,
     max_undo: usize,
     group_interval: Option<Duration>,
+    /// Force the next change to start a new group.
+    new_group: bool,
 }
 
 impl<I> History<I>
@@ -32,6 +34,7 @@ where
             version: 0,
             max_undo: 1000,
             group_interval: None,
+            new_group: false,
         }
     }
 
@@ -50,14 +53,33 @@ where
     /// Increment the version number if the last change was made more than `GROUP_INTERVAL` milliseconds ago.
     fn inc_version(&mut self) -> usize {
         let t = Instant::now();
-        if Some(self.last_changed_at.elapsed()) > self.group_interval {
+        if self.new_group || Some(self.last_changed_at.elapsed()) > self.group_interval {
             self.version += 1;
         }
+        self.new_group = false;
 
         self.last_changed_at = t;
         self.version
     }
 
+    /// Start a new group, the next change will not be grouped with the previous changes.
+    pub fn start_group(&mut self) {
+        self.new_group = true;
+    }
+
+    /// Clear the redo steps, e.g.: when a new change is made after undo.
+    pub fn clear_redos(&mut self) {
+        self.redos.clear();
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undos.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redos.is_empty()
+    }
+
     /// Get the current version number.
     pub fn version(&self) -> usize {
         self.version
@@ -189,4 +211,27 @@ mod tests {
 
         assert_eq!(history.undo().is_none(), true);
     }
+
+    #[test]
+    fn test_history_group() {
+        let mut history: History<TabIndex> = History::new().group_interval(Duration::from_secs(60));
+        history.push(0.into());
+        history.push(1.into());
+        history.start_group();
+        history.push(2.into());
+        history.push(3.into());
+
+        let changes = history.undo().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].tab_index, 3);
+        assert_eq!(changes[1].tab_index, 2);
+        assert!(history.can_redo());
+
+        let changes = history.undo().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert!(!history.can_undo());
+
+        history.clear_redos();
+        assert!(!history.can_redo());
+    }
 }
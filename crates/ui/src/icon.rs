@@ -21,6 +21,7 @@ pub enum IconName {
     ChevronsUpDown,
     CircleCheck,
     CircleX,
+    Clock,
     Close,
     Copy,
     Dash,
@@ -29,6 +30,9 @@ pub enum IconName {
     EllipsisVertical,
     Eye,
     EyeOff,
+    File,
+    Folder,
+    FolderOpen,
     GitHub,
     Globe,
     Heart,
@@ -49,7 +53,11 @@ pub enum IconName {
     PanelLeftOpen,
     PanelRight,
     PanelRightOpen,
+    Pause,
+    Play,
     Plus,
+    Repeat,
+    RotateCw,
     Search,
     SortAscending,
     SortDescending,
@@ -59,6 +67,9 @@ pub enum IconName {
     ThumbsDown,
     ThumbsUp,
     TriangleAlert,
+    Upload,
+    ZoomIn,
+    ZoomOut,
 }
 
 impl IconName {
@@ -79,6 +90,7 @@ impl IconName {
             IconName::ChevronsUpDown => "icons/chevrons-up-down.svg",
             IconName::CircleCheck => "icons/circle-check.svg",
             IconName::CircleX => "icons/circle-x.svg",
+            IconName::Clock => "icons/clock.svg",
             IconName::Close => "icons/close.svg",
             IconName::Copy => "icons/copy.svg",
             IconName::Dash => "icons/dash.svg",
@@ -87,6 +99,9 @@ impl IconName {
             IconName::EllipsisVertical => "icons/ellipsis-vertical.svg",
             IconName::Eye => "icons/eye.svg",
             IconName::EyeOff => "icons/eye-off.svg",
+            IconName::File => "icons/file.svg",
+            IconName::Folder => "icons/folder.svg",
+            IconName::FolderOpen => "icons/folder-open.svg",
             IconName::GitHub => "icons/github.svg",
             IconName::Globe => "icons/globe.svg",
             IconName::Heart => "icons/heart.svg",
@@ -107,7 +122,11 @@ impl IconName {
             IconName::PanelLeftOpen => "icons/panel-left-open.svg",
             IconName::PanelRight => "icons/panel-right.svg",
             IconName::PanelRightOpen => "icons/panel-right-open.svg",
+            IconName::Pause => "icons/pause.svg",
+            IconName::Play => "icons/play.svg",
             IconName::Plus => "icons/plus.svg",
+            IconName::Repeat => "icons/repeat.svg",
+            IconName::RotateCw => "icons/rotate-cw.svg",
             IconName::Search => "icons/search.svg",
             IconName::SortAscending => "icons/sort-ascending.svg",
             IconName::SortDescending => "icons/sort-descending.svg",
@@ -117,6 +136,9 @@ impl IconName {
             IconName::ThumbsDown => "icons/thumbs-down.svg",
             IconName::ThumbsUp => "icons/thumbs-up.svg",
             IconName::TriangleAlert => "icons/triangle-alert.svg",
+            IconName::Upload => "icons/upload.svg",
+            IconName::ZoomIn => "icons/zoom-in.svg",
+            IconName::ZoomOut => "icons/zoom-out.svg",
         }
         .into()
     }
@@ -0,0 +1,668 @@
+use std::{path::PathBuf, sync::Arc, time::Instant};
+
+use anyhow::Result;
+use gpui::{
+    canvas, div, point, prelude::FluentBuilder as _, px, size, AppContext, Bounds, ContentMask,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, MouseButton, MouseDownEvent,
+    MouseMoveEvent, ParentElement as _, Pixels, Point, Render, RenderImage, ScrollWheelEvent,
+    SharedString, Size, Styled as _, Task, ViewContext,
+};
+use image::{imageops, Frame, RgbaImage};
+use rust_i18n::t;
+use smallvec::SmallVec;
+use smol::io::AsyncReadExt as _;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    indicator::Indicator,
+    progress::Progress,
+    svg_img::{decode_frames, FramePlayback},
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Selectable as _, Sizable as _,
+};
+
+const MIN_ZOOM: f32 = 0.05;
+const MAX_ZOOM: f32 = 20.;
+/// The zoom factor of the zoom in and zoom out buttons.
+const ZOOM_STEP: f32 = 1.25;
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// How the image is sized in the [`ImageViewer`] before it is zoomed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImageFit {
+    /// Scale to show the whole image.
+    #[default]
+    Fit,
+    /// Scale to cover the whole viewer, the image may be clipped.
+    Fill,
+    /// Show the image in its actual size.
+    ActualSize,
+}
+
+impl ImageFit {
+    /// The scale to show the `image` size in the `viewer` size.
+    fn scale(&self, image: Size<Pixels>, viewer: Size<Pixels>) -> f32 {
+        if image.width <= px(0.) || image.height <= px(0.) {
+            return 1.;
+        }
+
+        let x = viewer.width / image.width;
+        let y = viewer.height / image.height;
+        match self {
+            Self::Fit => x.min(y),
+            Self::Fill => x.max(y),
+            Self::ActualSize => 1.,
+        }
+    }
+}
+
+/// Returns the pan offset to keep the point under the `cursor` unchanged when zooming from `scale` to `new_scale`.
+///
+/// The `cursor` and the `offset` are relative to the center of the viewer.
+fn zoom_offset(
+    cursor: Point<Pixels>,
+    offset: Point<Pixels>,
+    scale: f32,
+    new_scale: f32,
+) -> Point<Pixels> {
+    let ratio = new_scale / scale;
+    point(
+        cursor.x - (cursor.x - offset.x) * ratio,
+        cursor.y - (cursor.y - offset.y) * ratio,
+    )
+}
+
+/// Decode the image bytes, e.g.: PNG, JPEG, WebP, animated GIF, to paint.
+fn decode_image(bytes: &[u8]) -> Result<Arc<RenderImage>> {
+    Ok(Arc::new(RenderImage::new(decode_frames(bytes)?)))
+}
+
+/// Rotate all the frames of the image clockwise by the quarter turns.
+fn rotate_image(image: &Arc<RenderImage>, rotation: u8) -> Option<Arc<RenderImage>> {
+    if rotation % 4 == 0 {
+        return Some(image.clone());
+    }
+
+    let mut frames = SmallVec::new();
+    for ix in 0..image.frame_count() {
+        let size = image.size(ix);
+        let buffer = RgbaImage::from_raw(
+            size.width.0 as u32,
+            size.height.0 as u32,
+            image.as_bytes(ix)?.to_vec(),
+        )?;
+        let buffer = match rotation % 4 {
+            1 => imageops::rotate90(&buffer),
+            2 => imageops::rotate180(&buffer),
+            _ => imageops::rotate270(&buffer),
+        };
+        frames.push(Frame::from_parts(buffer, 0, 0, image.delay(ix)));
+    }
+
+    Some(Arc::new(RenderImage::new(frames)))
+}
+
+/// A viewer to inspect an image, with zooming, panning and rotation.
+///
+/// - Scroll to zoom around the cursor, drag to pan, and double-click to reset.
+/// - The image is decoded in the background, a progress is shown while reading the file.
+/// - The animated GIF, APNG and WebP are played, and can be paused in the toolbar.
+///
+/// ```ignore
+/// let viewer = cx.new_view(|cx| {
+///     let mut viewer = ImageViewer::new(cx);
+///     viewer.load_path("photo.jpg", cx);
+///     viewer
+/// });
+/// ```
+pub struct ImageViewer {
+    focus_handle: FocusHandle,
+    /// The decoded image without rotation.
+    original: Option<Arc<RenderImage>>,
+    /// The rotated image to paint.
+    image: Option<Arc<RenderImage>>,
+    fit: ImageFit,
+    /// The scale set by zooming, `None` to follow the `fit`.
+    zoom: Option<f32>,
+    /// The offset of the image center to the viewer center.
+    offset: Point<Pixels>,
+    /// The clockwise quarter turns.
+    rotation: u8,
+    bounds: Bounds<Pixels>,
+    /// The mouse position and the offset when the panning is started.
+    drag_start: Option<(Point<Pixels>, Point<Pixels>)>,
+    /// The loading progress in 0..1, `None` if not loading.
+    progress: Option<f32>,
+    error: Option<SharedString>,
+    playing: bool,
+    looping: bool,
+    playback: FramePlayback,
+    show_toolbar: bool,
+    _task: Task<()>,
+}
+
+impl ImageViewer {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            original: None,
+            image: None,
+            fit: ImageFit::default(),
+            zoom: None,
+            offset: Point::default(),
+            rotation: 0,
+            bounds: Bounds::default(),
+            drag_start: None,
+            progress: None,
+            error: None,
+            playing: true,
+            looping: true,
+            playback: FramePlayback::default(),
+            show_toolbar: true,
+            _task: Task::ready(()),
+        }
+    }
+
+    /// Set the initial [`ImageFit`], default is [`ImageFit::Fit`].
+    pub fn fit(mut self, fit: ImageFit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    /// Show the toolbar to zoom, fit and rotate at the bottom, default is true.
+    pub fn show_toolbar(mut self, show: bool) -> Self {
+        self.show_toolbar = show;
+        self
+    }
+
+    /// Set to play the animated image repeatedly, default is true.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Read the image file in the background, the progress is shown until it is decoded.
+    pub fn load_path(&mut self, path: impl Into<PathBuf>, cx: &mut ViewContext<Self>) {
+        let path = path.into();
+        self.progress = Some(0.);
+        self.error = None;
+        cx.notify();
+
+        self._task = cx.spawn(|this, mut cx| async move {
+            let result: Result<Arc<RenderImage>> = async {
+                let mut file = smol::fs::File::open(&path).await?;
+                let total = file.metadata().await?.len().max(1);
+                let mut bytes = Vec::with_capacity(total as usize);
+                let mut chunk = vec![0; READ_CHUNK_SIZE];
+                loop {
+                    let n = file.read(&mut chunk).await?;
+                    if n == 0 {
+                        break;
+                    }
+                    bytes.extend_from_slice(&chunk[..n]);
+
+                    let progress = bytes.len() as f32 / total as f32;
+                    this.update(&mut cx, |this, cx| {
+                        this.progress = Some(progress);
+                        cx.notify();
+                    })?;
+                }
+
+                cx.background_executor()
+                    .spawn(async move { decode_image(&bytes) })
+                    .await
+            }
+            .await;
+
+            _ = this.update(&mut cx, |this, cx| this.set_loaded(result, cx));
+        });
+    }
+
+    /// Decode the image bytes in the background, e.g.: the image downloaded by the app.
+    pub fn load_bytes(&mut self, bytes: impl Into<Arc<[u8]>>, cx: &mut ViewContext<Self>) {
+        let bytes = bytes.into();
+        self.progress = Some(1.);
+        self.error = None;
+        cx.notify();
+
+        self._task = cx.spawn(|this, mut cx| async move {
+            let result = cx
+                .background_executor()
+                .spawn(async move { decode_image(&bytes) })
+                .await;
+
+            _ = this.update(&mut cx, |this, cx| this.set_loaded(result, cx));
+        });
+    }
+
+    fn set_loaded(&mut self, result: Result<Arc<RenderImage>>, cx: &mut ViewContext<Self>) {
+        self.progress = None;
+        match result {
+            Ok(image) => {
+                self.original = Some(image.clone());
+                self.image = Some(image);
+                self.rotation = 0;
+                self.playback = FramePlayback::default();
+                self.reset_view();
+            }
+            Err(err) => {
+                self.error = Some(err.to_string().into());
+            }
+        }
+        cx.notify();
+    }
+
+    /// Drop the image that can't be painted and show the error, so it is not painted again in every frame.
+    fn set_paint_failed(&mut self, err: anyhow::Error, cx: &mut ViewContext<Self>) {
+        self.original = None;
+        self.image = None;
+        self.error = Some(err.to_string().into());
+        cx.notify();
+    }
+
+    /// The size of the rotated image.
+    fn image_size(&self) -> Size<Pixels> {
+        match self.image.as_ref() {
+            Some(image) => {
+                let size = image.size(0);
+                gpui::size(px(size.width.0 as f32), px(size.height.0 as f32))
+            }
+            None => Size::default(),
+        }
+    }
+
+    /// The current scale of the image.
+    pub fn scale(&self) -> f32 {
+        self.zoom
+            .unwrap_or_else(|| self.fit.scale(self.image_size(), self.bounds.size))
+    }
+
+    fn reset_view(&mut self) {
+        self.zoom = None;
+        self.offset = Point::default();
+    }
+
+    /// Set the [`ImageFit`] and reset the zoom and panning.
+    pub fn set_fit(&mut self, fit: ImageFit, cx: &mut ViewContext<Self>) {
+        self.fit = fit;
+        self.reset_view();
+        cx.notify();
+    }
+
+    /// Set the scale of the image, 1.0 is the actual size.
+    pub fn set_zoom(&mut self, scale: f32, cx: &mut ViewContext<Self>) {
+        self.zoom_at(Point::default(), scale, cx);
+    }
+
+    pub fn zoom_in(&mut self, cx: &mut ViewContext<Self>) {
+        self.zoom_at(Point::default(), self.scale() * ZOOM_STEP, cx);
+    }
+
+    pub fn zoom_out(&mut self, cx: &mut ViewContext<Self>) {
+        self.zoom_at(Point::default(), self.scale() / ZOOM_STEP, cx);
+    }
+
+    /// Zoom around the `cursor` position relative to the viewer center.
+    fn zoom_at(&mut self, cursor: Point<Pixels>, scale: f32, cx: &mut ViewContext<Self>) {
+        let new_scale = scale.clamp(MIN_ZOOM, MAX_ZOOM);
+        self.offset = zoom_offset(cursor, self.offset, self.scale(), new_scale);
+        self.zoom = Some(new_scale);
+        cx.notify();
+    }
+
+    /// Returns true if the image has multiple frames, e.g.: the animated GIF.
+    pub fn is_animated(&self) -> bool {
+        self.image
+            .as_ref()
+            .map_or(false, |image| image.frame_count() > 1)
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Play or pause the animated image, the playback is restarted if it has finished.
+    pub fn set_playing(&mut self, playing: bool, cx: &mut ViewContext<Self>) {
+        self.playing = playing;
+        if let Some(image) = self.image.as_ref() {
+            if playing && self.playback.is_finished(image.frame_count(), self.looping) {
+                self.playback = FramePlayback::default();
+            }
+        }
+        cx.notify();
+    }
+
+    pub fn toggle_playing(&mut self, cx: &mut ViewContext<Self>) {
+        self.set_playing(!self.playing, cx);
+    }
+
+    /// Set to play the animated image repeatedly, otherwise it stops at the last frame.
+    pub fn set_looping(&mut self, looping: bool, cx: &mut ViewContext<Self>) {
+        self.looping = looping;
+        cx.notify();
+    }
+
+    /// Advance the frames of the animated image, returns the frame index to paint.
+    fn advance_frame(&mut self, cx: &mut ViewContext<Self>) -> usize {
+        let Some(image) = self.image.as_ref() else {
+            return 0;
+        };
+
+        if !self.playing {
+            self.playback.pause();
+        } else if self.playback.advance(
+            image.frame_count(),
+            |ix| image.delay(ix).into(),
+            self.looping,
+            Instant::now(),
+        ) {
+            cx.request_animation_frame();
+        }
+
+        self.playback.frame_ix
+    }
+
+    /// Rotate the image 90 degrees clockwise.
+    pub fn rotate(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(original) = self.original.clone() else {
+            return;
+        };
+
+        self.rotation = (self.rotation + 1) % 4;
+        self.reset_view();
+        let rotation = self.rotation;
+        self._task = cx.spawn(|this, mut cx| async move {
+            let image = cx
+                .background_executor()
+                .spawn(async move { rotate_image(&original, rotation) })
+                .await;
+
+            _ = this.update(&mut cx, |this, cx| {
+                if image.is_some() {
+                    this.image = image;
+                }
+                cx.notify();
+            });
+        });
+        cx.notify();
+    }
+
+    fn on_scroll_wheel(&mut self, event: &ScrollWheelEvent, cx: &mut ViewContext<Self>) {
+        if self.image.is_none() {
+            return;
+        }
+
+        let delta = event.delta.pixel_delta(cx.line_height()).y;
+        let scale = self.scale() * (delta.0 * 0.005).exp();
+        self.zoom_at(event.position - self.bounds.center(), scale, cx);
+    }
+
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut ViewContext<Self>) {
+        cx.focus(&self.focus_handle);
+        if event.click_count == 2 {
+            self.reset_view();
+            cx.notify();
+            return;
+        }
+        self.drag_start = Some((event.position, self.offset));
+    }
+
+    fn on_mouse_move(&mut self, event: &MouseMoveEvent, cx: &mut ViewContext<Self>) {
+        let Some((position, offset)) = self.drag_start else {
+            return;
+        };
+
+        if event.dragging() {
+            self.offset = offset + (event.position - position);
+        } else {
+            self.drag_start = None;
+        }
+        cx.notify();
+    }
+
+    fn render_toolbar(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let zoomed = self.zoom.is_some();
+
+        h_flex()
+            .absolute()
+            .bottom_3()
+            .left_0()
+            .right_0()
+            .justify_center()
+            .child(
+                h_flex()
+                    .gap_1()
+                    .p_1()
+                    .rounded(px(cx.theme().radius))
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .bg(cx.theme().background.opacity(0.9))
+                    .shadow_md()
+                    // Do not start panning by clicking the buttons.
+                    .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                    .child(
+                        Button::new("zoom-out")
+                            .icon(IconName::ZoomOut)
+                            .ghost()
+                            .xsmall()
+                            .tooltip(t!("ImageViewer.zoom_out"))
+                            .on_click(cx.listener(|this, _, cx| this.zoom_out(cx))),
+                    )
+                    .child(
+                        div()
+                            .w(px(48.))
+                            .text_xs()
+                            .text_center()
+                            .child(format!("{:.0}%", self.scale() * 100.)),
+                    )
+                    .child(
+                        Button::new("zoom-in")
+                            .icon(IconName::ZoomIn)
+                            .ghost()
+                            .xsmall()
+                            .tooltip(t!("ImageViewer.zoom_in"))
+                            .on_click(cx.listener(|this, _, cx| this.zoom_in(cx))),
+                    )
+                    .children(
+                        [
+                            ("fit", ImageFit::Fit, t!("ImageViewer.fit")),
+                            ("fill", ImageFit::Fill, t!("ImageViewer.fill")),
+                            (
+                                "actual-size",
+                                ImageFit::ActualSize,
+                                t!("ImageViewer.actual_size"),
+                            ),
+                        ]
+                        .into_iter()
+                        .map(|(id, fit, label)| {
+                            Button::new(id)
+                                .label(label)
+                                .ghost()
+                                .xsmall()
+                                .selected(!zoomed && self.fit == fit)
+                                .on_click(cx.listener(move |this, _, cx| this.set_fit(fit, cx)))
+                        }),
+                    )
+                    .child(
+                        Button::new("rotate")
+                            .icon(IconName::RotateCw)
+                            .ghost()
+                            .xsmall()
+                            .tooltip(t!("ImageViewer.rotate"))
+                            .on_click(cx.listener(|this, _, cx| this.rotate(cx))),
+                    )
+                    .when(self.is_animated(), |this| {
+                        this.child(
+                            Button::new("play")
+                                .map(|this| {
+                                    if self.playing {
+                                        this.icon(IconName::Pause).tooltip(t!("ImageViewer.pause"))
+                                    } else {
+                                        this.icon(IconName::Play).tooltip(t!("ImageViewer.play"))
+                                    }
+                                })
+                                .ghost()
+                                .xsmall()
+                                .on_click(cx.listener(|this, _, cx| this.toggle_playing(cx))),
+                        )
+                        .child(
+                            Button::new("loop")
+                                .icon(IconName::Repeat)
+                                .ghost()
+                                .xsmall()
+                                .selected(self.looping)
+                                .tooltip(t!("ImageViewer.loop"))
+                                .on_click(
+                                    cx.listener(|this, _, cx| this.set_looping(!this.looping, cx)),
+                                ),
+                        )
+                    }),
+            )
+    }
+}
+
+impl FocusableView for ImageViewer {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ImageViewer {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let paint_view = view.clone();
+        let image = self.image.clone();
+        let image_size = self.image_size();
+        let fit = self.fit;
+        let zoom = self.zoom;
+        let offset = self.offset;
+
+        div()
+            .id("image-viewer")
+            .track_focus(&self.focus_handle)
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .bg(cx.theme().muted)
+            .map(|this| {
+                if self.drag_start.is_some() {
+                    this.cursor_grabbing()
+                } else {
+                    this.cursor_grab()
+                }
+            })
+            .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .on_mouse_move(cx.listener(Self::on_mouse_move))
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(|this, _, _| this.drag_start = None),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                cx.listener(|this, _, _| this.drag_start = None),
+            )
+            .child(
+                canvas(
+                    move |bounds, cx| {
+                        view.update(cx, |this, cx| {
+                            this.bounds = bounds;
+                            this.advance_frame(cx)
+                        })
+                    },
+                    move |bounds, frame_ix, cx| {
+                        let Some(image) = image else {
+                            return;
+                        };
+
+                        let scale = zoom.unwrap_or_else(|| fit.scale(image_size, bounds.size));
+                        let size = size(image_size.width * scale, image_size.height * scale);
+                        let origin =
+                            bounds.center() + offset - point(size.width / 2., size.height / 2.);
+
+                        cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+                            if let Err(err) = cx.paint_image(
+                                Bounds { origin, size },
+                                px(0.).into(),
+                                image,
+                                frame_ix,
+                                false,
+                            ) {
+                                cx.defer(move |cx| {
+                                    paint_view.update(cx, |this, cx| this.set_paint_failed(err, cx))
+                                });
+                            }
+                        });
+                    },
+                )
+                .absolute()
+                .size_full(),
+            )
+            .when_some(self.progress, |this, progress| {
+                this.child(
+                    v_flex()
+                        .absolute()
+                        .size_full()
+                        .items_center()
+                        .justify_center()
+                        .gap_3()
+                        .child(Indicator::new())
+                        .child(
+                            div()
+                                .w(px(160.))
+                                .child(Progress::new().value(progress * 100.)),
+                        ),
+                )
+            })
+            .when_some(self.error.clone(), |this, error| {
+                this.child(
+                    v_flex()
+                        .absolute()
+                        .size_full()
+                        .items_center()
+                        .justify_center()
+                        .gap_2()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(Icon::new(IconName::TriangleAlert).size(px(32.)))
+                        .child(t!("ImageViewer.load_failed").to_string())
+                        .child(error),
+                )
+            })
+            .when(self.show_toolbar && self.image.is_some(), |this| {
+                this.child(self.render_toolbar(cx))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size};
+
+    use super::{zoom_offset, ImageFit};
+
+    #[test]
+    fn test_fit_scale() {
+        let image = size(px(400.), px(200.));
+        let viewer = size(px(200.), px(200.));
+        assert_eq!(ImageFit::Fit.scale(image, viewer), 0.5);
+        assert_eq!(ImageFit::Fill.scale(image, viewer), 1.);
+        assert_eq!(ImageFit::ActualSize.scale(image, viewer), 1.);
+        assert_eq!(ImageFit::Fit.scale(size(px(0.), px(0.)), viewer), 1.);
+    }
+
+    #[test]
+    fn test_zoom_offset() {
+        // Zoom around the center, the offset is scaled.
+        let offset = zoom_offset(point(px(0.), px(0.)), point(px(10.), px(-20.)), 1., 2.);
+        assert_eq!(offset, point(px(20.), px(-40.)));
+
+        // The point under the cursor is kept.
+        let cursor = point(px(100.), px(50.));
+        let offset = zoom_offset(cursor, point(px(0.), px(0.)), 1., 2.);
+        assert_eq!(offset, point(px(-100.), px(-50.)));
+        // The image point at the cursor is (100, 50) at 1x, and (200, 100) at 2x.
+        assert_eq!(cursor - offset, point(px(200.), px(100.)));
+    }
+}
@@ -0,0 +1,118 @@
+use std::ops::Range;
+
+/// The brackets and quotes to be closed automatically.
+const PAIRS: &[(char, char)] = &[
+    ('(', ')'),
+    ('[', ']'),
+    ('{', '}'),
+    ('"', '"'),
+    ('\'', '\''),
+    ('`', '`'),
+];
+
+/// Return the range to replace, the new text and the selected range after the replacement for typing the `input`,
+/// or `None` to insert the input as it is.
+///
+/// - Typing an opening bracket or quote inserts the pair, or wraps the selected text.
+/// - Typing a closing bracket or quote before the same one moves over it.
+pub(super) fn auto_pair_insert(
+    text: &str,
+    selected_range: &Range<usize>,
+    input: &str,
+) -> Option<(Range<usize>, String, Range<usize>)> {
+    let mut chars = input.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+
+    let prev_char = text[..selected_range.start].chars().next_back();
+    let next_char = text[selected_range.end..].chars().next();
+
+    if selected_range.is_empty()
+        && next_char == Some(c)
+        && PAIRS.iter().any(|(_, close)| *close == c)
+    {
+        let cursor = selected_range.end + c.len_utf8();
+        return Some((selected_range.clone(), String::new(), cursor..cursor));
+    }
+
+    let (open, close) = PAIRS.iter().find(|(open, _)| *open == c)?;
+    if !selected_range.is_empty() {
+        let new_text = format!("{}{}{}", open, &text[selected_range.clone()], close);
+        let start = selected_range.start + open.len_utf8();
+        return Some((
+            selected_range.clone(),
+            new_text,
+            start..start + selected_range.len(),
+        ));
+    }
+
+    // Don't pair the quote after a word, e.g.: `don't`.
+    if open == close && prev_char.is_some_and(char::is_alphanumeric) {
+        return None;
+    }
+    // Only pair before the whitespace or the closing brackets.
+    if next_char.is_some_and(|c| !c.is_whitespace() && !matches!(c, ')' | ']' | '}' | ',' | ';')) {
+        return None;
+    }
+
+    let cursor = selected_range.start + open.len_utf8();
+    Some((
+        selected_range.clone(),
+        format!("{}{}", open, close),
+        cursor..cursor,
+    ))
+}
+
+/// Return the range to delete by backspace if the cursor is in an empty pair, e.g.: `(|)`.
+pub(super) fn auto_pair_delete(text: &str, cursor: usize) -> Option<Range<usize>> {
+    let prev_char = text[..cursor].chars().next_back()?;
+    let next_char = text[cursor..].chars().next()?;
+
+    PAIRS
+        .iter()
+        .any(|pair| *pair == (prev_char, next_char))
+        .then(|| cursor - prev_char.len_utf8()..cursor + next_char.len_utf8())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{auto_pair_delete, auto_pair_insert};
+
+    #[test]
+    fn test_auto_pair_insert() {
+        assert_eq!(auto_pair_insert("", &(0..0), "a"), None);
+        assert_eq!(auto_pair_insert("", &(0..0), "()"), None);
+        assert_eq!(
+            auto_pair_insert("foo", &(3..3), "("),
+            Some((3..3, "()".to_string(), 4..4))
+        );
+        assert_eq!(
+            auto_pair_insert("foo", &(0..3), "["),
+            Some((0..3, "[foo]".to_string(), 1..4))
+        );
+        assert_eq!(
+            auto_pair_insert("()", &(1..1), ")"),
+            Some((1..1, "".to_string(), 2..2))
+        );
+        assert_eq!(
+            auto_pair_insert("\"\"", &(1..1), "\""),
+            Some((1..1, "".to_string(), 2..2))
+        );
+        assert_eq!(auto_pair_insert("don", &(3..3), "'"), None);
+        assert_eq!(auto_pair_insert("foo", &(0..0), "("), None);
+        assert_eq!(
+            auto_pair_insert("[]", &(1..1), "{"),
+            Some((1..1, "{}".to_string(), 2..2))
+        );
+    }
+
+    #[test]
+    fn test_auto_pair_delete() {
+        assert_eq!(auto_pair_delete("()", 1), Some(0..2));
+        assert_eq!(auto_pair_delete("a\"\"", 2), Some(1..3));
+        assert_eq!(auto_pair_delete("(a)", 1), None);
+        assert_eq!(auto_pair_delete("(", 1), None);
+        assert_eq!(auto_pair_delete("", 0), None);
+    }
+}
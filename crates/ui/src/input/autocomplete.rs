@@ -0,0 +1,410 @@
+use std::{ops::Range, rc::Rc, time::Duration};
+
+use gpui::{
+    actions, anchored, canvas, deferred, div, prelude::FluentBuilder as _, px, AppContext, Bounds,
+    EventEmitter, FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding,
+    ParentElement as _, Pixels, Render, SharedString, Styled as _, Task, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+
+use super::{InputEvent, TextInput};
+use crate::{h_flex, list::ListItem, theme::ActiveTheme as _, v_flex, StyledExt as _};
+
+actions!(
+    autocomplete,
+    [
+        SelectPrevSuggestion,
+        SelectNextSuggestion,
+        AcceptSuggestion,
+        DismissSuggestions
+    ]
+);
+
+const CONTEXT: &str = "Autocomplete";
+
+pub(crate) fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrevSuggestion, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNextSuggestion, Some(CONTEXT)),
+        KeyBinding::new("tab", AcceptSuggestion, Some(CONTEXT)),
+        KeyBinding::new("escape", DismissSuggestions, Some(CONTEXT)),
+    ]);
+}
+
+/// A suggestion of the [`Autocomplete`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub text: SharedString,
+    pub description: Option<SharedString>,
+}
+
+impl Suggestion {
+    pub fn new(text: impl Into<SharedString>) -> Self {
+        Self {
+            text: text.into(),
+            description: None,
+        }
+    }
+
+    /// Set the description to display on the right side of the suggestion.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+pub enum AutocompleteEvent {
+    /// Emitted when a suggestion is accepted, the input text has been replaced by the suggestion.
+    Accept(Suggestion),
+}
+
+type Provider = Rc<dyn Fn(&str, &mut WindowContext) -> Task<Vec<Suggestion>> + 'static>;
+
+/// Returns the byte range of the first case-insensitive match of the query in the text.
+fn match_range(text: &str, query: &str) -> Option<Range<usize>> {
+    let query = query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .collect::<Vec<_>>();
+    if query.is_empty() {
+        return None;
+    }
+
+    text.char_indices().find_map(|(start, _)| {
+        let mut lowered = Vec::with_capacity(query.len());
+        let mut end = start;
+        for c in text[start..].chars() {
+            if lowered.len() >= query.len() {
+                break;
+            }
+            lowered.extend(c.to_lowercase());
+            end += c.len_utf8();
+        }
+
+        (lowered == query).then_some(start..end)
+    })
+}
+
+/// A [`TextInput`] with the suggestions provided by an async provider.
+///
+/// - The provider is called after the typing is paused for the `debounce` duration.
+/// - Press `Up`/`Down` to select a suggestion, `Tab`/`Enter` to accept, `Escape` to dismiss.
+///
+/// ```ignore
+/// Autocomplete::new(cx).provider(|query, cx| {
+///     let query = query.to_string();
+///     cx.background_executor().spawn(async move { search(&query) })
+/// })
+/// ```
+pub struct Autocomplete {
+    input: View<TextInput>,
+    provider: Option<Provider>,
+    debounce: Duration,
+    placeholder: Option<SharedString>,
+    suggestions: Vec<Suggestion>,
+    selected_index: Option<usize>,
+    query: SharedString,
+    open: bool,
+    /// The text of the accepted suggestion, the change event of it should not trigger a query.
+    accepted_text: Option<SharedString>,
+    bounds: Bounds<Pixels>,
+    _query_task: Task<()>,
+}
+
+impl EventEmitter<AutocompleteEvent> for Autocomplete {}
+
+impl Autocomplete {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(TextInput::new);
+        cx.subscribe(&input, Self::on_input_event).detach();
+
+        Self {
+            input,
+            provider: None,
+            debounce: Duration::from_millis(200),
+            placeholder: None,
+            suggestions: vec![],
+            selected_index: None,
+            query: SharedString::default(),
+            open: false,
+            accepted_text: None,
+            bounds: Bounds::default(),
+            _query_task: Task::ready(()),
+        }
+    }
+
+    /// Set the suggestion provider, it is called with the input text and returns a task of the suggestions.
+    pub fn provider(
+        mut self,
+        provider: impl Fn(&str, &mut WindowContext) -> Task<Vec<Suggestion>> + 'static,
+    ) -> Self {
+        self.provider = Some(Rc::new(provider));
+        self
+    }
+
+    /// Set the duration to wait after the typing is paused before querying, default: 200ms
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Set the placeholder of the input.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Returns the inner [`TextInput`].
+    pub fn input(&self) -> &View<TextInput> {
+        &self.input
+    }
+
+    /// Return the text of the input.
+    pub fn text(&self, cx: &AppContext) -> SharedString {
+        self.input.read(cx).text()
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::Change(text) => {
+                if self.accepted_text.take().as_ref() == Some(text) {
+                    return;
+                }
+                self.query(text.clone(), cx);
+            }
+            InputEvent::PressEnter => self.accept(&AcceptSuggestion, cx),
+            InputEvent::Blur | InputEvent::Cleared => self.dismiss(&DismissSuggestions, cx),
+            InputEvent::Focus | InputEvent::Input(_) => {}
+        }
+    }
+
+    /// Query the suggestions after the debounce duration, the previous query is canceled.
+    fn query(&mut self, text: SharedString, cx: &mut ViewContext<Self>) {
+        let Some(provider) = self.provider.clone() else {
+            return;
+        };
+
+        if text.trim().is_empty() {
+            self._query_task = Task::ready(());
+            self.close(cx);
+            return;
+        }
+
+        let debounce = self.debounce;
+        self._query_task = cx.spawn(|this, mut cx| async move {
+            cx.background_executor().timer(debounce).await;
+
+            let Ok(task) = this.update(&mut cx, |this, cx| {
+                this.input
+                    .update(cx, |input, cx| input.set_loading(true, cx));
+                provider(&text, cx)
+            }) else {
+                return;
+            };
+            let suggestions = task.await;
+
+            _ = this.update(&mut cx, |this, cx| {
+                this.input
+                    .update(cx, |input, cx| input.set_loading(false, cx));
+                this.query = text;
+                this.open = !suggestions.is_empty();
+                this.selected_index = this.open.then_some(0);
+                this.suggestions = suggestions;
+                cx.notify();
+            });
+        });
+    }
+
+    fn close(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = false;
+        self.selected_index = None;
+        cx.notify();
+    }
+
+    fn select_prev(&mut self, _: &SelectPrevSuggestion, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+
+        let len = self.suggestions.len();
+        self.selected_index = Some(match self.selected_index {
+            Some(ix) if ix > 0 => ix - 1,
+            _ => len - 1,
+        });
+        cx.notify();
+    }
+
+    fn select_next(&mut self, _: &SelectNextSuggestion, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+
+        let len = self.suggestions.len();
+        self.selected_index = Some(match self.selected_index {
+            Some(ix) if ix + 1 < len => ix + 1,
+            _ => 0,
+        });
+        cx.notify();
+    }
+
+    fn accept(&mut self, _: &AcceptSuggestion, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+
+        if let Some(ix) = self.selected_index {
+            self.accept_at(ix, cx);
+        }
+    }
+
+    fn accept_at(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(suggestion) = self.suggestions.get(ix).cloned() else {
+            return;
+        };
+
+        let text = suggestion.text.clone();
+        self.accepted_text = Some(text.clone());
+        self.input.update(cx, |input, cx| {
+            input.set_text(text, cx);
+            input.focus(cx);
+        });
+        self._query_task = Task::ready(());
+        self.close(cx);
+        cx.emit(AutocompleteEvent::Accept(suggestion));
+    }
+
+    fn dismiss(&mut self, _: &DismissSuggestions, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+
+        self.close(cx);
+    }
+
+    fn render_suggestion(
+        &self,
+        ix: usize,
+        suggestion: &Suggestion,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let text = suggestion.text.clone();
+        let label = match match_range(&text, &self.query) {
+            Some(range) => h_flex()
+                .child(text[..range.start].to_string())
+                .child(
+                    div()
+                        .font_semibold()
+                        .text_color(cx.theme().primary)
+                        .child(text[range.clone()].to_string()),
+                )
+                .child(text[range.end..].to_string()),
+            None => h_flex().child(text),
+        };
+
+        ListItem::new(("suggestion", ix))
+            .selected(self.selected_index == Some(ix))
+            .child(
+                h_flex()
+                    .justify_between()
+                    .gap_2()
+                    .child(label.whitespace_nowrap())
+                    .when_some(suggestion.description.clone(), |this, description| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(description),
+                        )
+                    }),
+            )
+            .on_click(cx.listener(move |this, _, cx| this.accept_at(ix, cx)))
+    }
+}
+
+impl FocusableView for Autocomplete {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for Autocomplete {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if let Some(placeholder) = self.placeholder.take() {
+            self.input
+                .update(cx, |input, _| input.set_placeholder(placeholder));
+        }
+
+        let view = cx.view().clone();
+        let bounds = self.bounds;
+
+        div()
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::select_next))
+            .on_action(cx.listener(Self::accept))
+            .on_action(cx.listener(Self::dismiss))
+            .relative()
+            .w_full()
+            .child(self.input.clone())
+            .child(
+                canvas(
+                    move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .when(self.open, |this| {
+                this.child(
+                    deferred(
+                        anchored().snap_to_window_with_margin(px(8.)).child(
+                            v_flex()
+                                .occlude()
+                                .w(bounds.size.width)
+                                .mt_1p5()
+                                .py_1()
+                                .bg(cx.theme().popover)
+                                .border_1()
+                                .border_color(cx.theme().border)
+                                .rounded(px(cx.theme().radius))
+                                .shadow_md()
+                                .children(
+                                    self.suggestions
+                                        .iter()
+                                        .enumerate()
+                                        .map(|(ix, suggestion)| {
+                                            self.render_suggestion(ix, suggestion, cx)
+                                        })
+                                        .collect::<Vec<_>>(),
+                                )
+                                .on_mouse_down_out(cx.listener(|this, _, cx| this.close(cx))),
+                        ),
+                    )
+                    .with_priority(1),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::match_range;
+
+    #[test]
+    fn test_match_range() {
+        assert_eq!(match_range("Hello World", "wor"), Some(6..9));
+        assert_eq!(match_range("Hello World", "HELLO"), Some(0..5));
+        assert_eq!(match_range("Hello World", "xyz"), None);
+        assert_eq!(match_range("Hello World", ""), None);
+        assert_eq!(match_range("你好世界", "世界"), Some(6..12));
+    }
+}
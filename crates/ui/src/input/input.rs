@@ -4,25 +4,33 @@
 //! https://github.com/zed-industries/zed/blob/main/crates/gpui/examples/input.rs
 
 use std::ops::Range;
+use std::rc::Rc;
+use std::time::Duration;
 
+use super::auto_pair::{auto_pair_delete, auto_pair_insert};
 use super::blink_cursor::BlinkCursor;
 use super::change::Change;
+use super::mask::MaskPattern;
+use super::password::StrengthMeter;
+use super::text_area::{content_height, offset_for_position, position_for_offset, TextAreaElement};
 use super::ClearButton;
+use crate::button::{Button, ButtonStyled as _};
+use crate::highlighter::Highlighter;
 use crate::history::History;
 use crate::indicator::Indicator;
 use crate::theme::ActiveTheme;
 use crate::StyledExt as _;
 use crate::{event::InteractiveElementExt as _, Size};
-use crate::{Sizable, StyleSized};
+use crate::{v_flex, IconName, Sizable, StyleSized};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
     actions, div, fill, point, px, relative, rems, size, AnyElement, AppContext, Bounds,
     ClickEvent, ClipboardItem, Context as _, Element, ElementId, ElementInputHandler, EventEmitter,
-    FocusHandle, FocusableView, GlobalElementId, InteractiveElement as _, IntoElement, KeyBinding,
-    KeyDownEvent, LayoutId, Model, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
-    PaintQuad, ParentElement as _, Pixels, Point, Render, ShapedLine, SharedString, Style,
-    Styled as _, TextRun, UTF16Selection, UnderlineStyle, View, ViewContext, ViewInputHandler,
-    WindowContext,
+    FocusHandle, FocusableView, GlobalElementId, HighlightStyle, InteractiveElement as _,
+    IntoElement, KeyBinding, KeyDownEvent, LayoutId, Model, MouseButton, MouseDownEvent,
+    MouseMoveEvent, MouseUpEvent, PaintQuad, ParentElement as _, Pixels, Point, Render, ShapedLine,
+    SharedString, Style, Styled as _, Task, TextRun, UTF16Selection, UnderlineStyle, View,
+    ViewContext, ViewInputHandler, WindowContext, WrappedLine,
 };
 use unicode_segmentation::*;
 
@@ -49,19 +57,35 @@ actions!(
         Redo,
         MoveToStartOfLine,
         MoveToEndOfLine,
+        MoveToPreviousWord,
+        MoveToNextWord,
+        SelectToPreviousWord,
+        SelectToNextWord,
         TextChanged,
+        Up,
+        Down,
+        SelectUp,
+        SelectDown,
+        Submit,
     ]
 );
 
 #[derive(Clone)]
 pub enum InputEvent {
+    /// The text has been changed, it is emitted on every keystroke.
     Change(SharedString),
+    /// The text has been changed and the typing has paused for the [`TextInput::debounce`] duration,
+    /// it is emitted right after [`InputEvent::Change`] if there is no debounce.
+    Input(SharedString),
     PressEnter,
     Focus,
     Blur,
+    /// The text has been cleared by the clear button.
+    Cleared,
 }
 
 const CONTEXT: &str = "Input";
+const TEXT_AREA_CONTEXT: &str = "TextArea";
 
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([
@@ -77,6 +101,22 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("shift-home", SelectToHome, Some(CONTEXT)),
         KeyBinding::new("shift-end", SelectToEnd, Some(CONTEXT)),
         #[cfg(target_os = "macos")]
+        KeyBinding::new("alt-left", MoveToPreviousWord, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("alt-right", MoveToNextWord, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("alt-shift-left", SelectToPreviousWord, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("alt-shift-right", SelectToNextWord, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-left", MoveToPreviousWord, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-right", MoveToNextWord, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-left", SelectToPreviousWord, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-right", SelectToNextWord, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
         KeyBinding::new("shift-cmd-left", SelectToHome, Some(CONTEXT)),
         #[cfg(target_os = "macos")]
         KeyBinding::new("shift-cmd-right", SelectToEnd, Some(CONTEXT)),
@@ -114,32 +154,88 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("ctrl-z", Undo, Some(CONTEXT)),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-y", Redo, Some(CONTEXT)),
+        KeyBinding::new("up", Up, Some(TEXT_AREA_CONTEXT)),
+        KeyBinding::new("down", Down, Some(TEXT_AREA_CONTEXT)),
+        KeyBinding::new("shift-up", SelectUp, Some(TEXT_AREA_CONTEXT)),
+        KeyBinding::new("shift-down", SelectDown, Some(TEXT_AREA_CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-enter", Submit, Some(TEXT_AREA_CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-enter", Submit, Some(TEXT_AREA_CONTEXT)),
     ]);
 }
 
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Return the start offset of the word before the offset, the non-word characters are skipped.
+fn previous_word_boundary(text: &str, offset: usize) -> usize {
+    let mut chars = text[..offset].char_indices().rev().peekable();
+    while chars.next_if(|(_, c)| !is_word_char(*c)).is_some() {}
+    while chars.next_if(|(_, c)| is_word_char(*c)).is_some() {}
+    chars.peek().map_or(0, |(ix, c)| ix + c.len_utf8())
+}
+
+/// Return the end offset of the word after the offset, the non-word characters are skipped.
+fn next_word_boundary(text: &str, offset: usize) -> usize {
+    let mut chars = text[offset..].char_indices().peekable();
+    while chars.next_if(|(_, c)| !is_word_char(*c)).is_some() {}
+    while chars.next_if(|(_, c)| is_word_char(*c)).is_some() {}
+    chars.peek().map_or(text.len(), |(ix, _)| offset + ix)
+}
+
 pub struct TextInput {
-    focus_handle: FocusHandle,
-    text: SharedString,
+    pub(super) focus_handle: FocusHandle,
+    pub(super) text: SharedString,
     history: History<Change>,
+    /// The cursor offset after the last edit, to group the consecutive edits in the history.
+    last_edit_offset: Option<usize>,
     blink_cursor: Model<BlinkCursor>,
     prefix: Option<Box<dyn Fn(&mut ViewContext<Self>) -> AnyElement + 'static>>,
     suffix: Option<Box<dyn Fn(&mut ViewContext<Self>) -> AnyElement + 'static>>,
     loading: bool,
-    placeholder: SharedString,
-    selected_range: Range<usize>,
+    pub(super) placeholder: SharedString,
+    pub(super) selected_range: Range<usize>,
     selection_reversed: bool,
     marked_range: Option<Range<usize>>,
     last_layout: Option<ShapedLine>,
-    last_bounds: Option<Bounds<Pixels>>,
-    scroll_offset: Point<Pixels>,
+    pub(super) last_bounds: Option<Bounds<Pixels>>,
+    pub(super) scroll_offset: Point<Pixels>,
+    /// The wrapped lines of the last frame in multi-line mode.
+    pub(super) last_lines: Vec<WrappedLine>,
+    pub(super) last_line_height: Pixels,
+    /// The cursor offset of the last frame, to scroll to the cursor when it is moved.
+    pub(super) last_cursor: Option<usize>,
+    multi_line: bool,
+    pub(crate) rows: usize,
+    pub(crate) max_rows: usize,
+    /// Wrap the long lines in multi-line mode, otherwise scroll them horizontally.
+    pub(crate) soft_wrap: bool,
+    pub(crate) line_number: bool,
+    /// The language and the highlighter of the text.
+    highlighter: Option<(SharedString, Rc<dyn Highlighter>)>,
+    /// The highlights of the last highlighted text.
+    last_highlights: Option<(SharedString, Vec<(Range<usize>, HighlightStyle)>)>,
+    monospace: bool,
+    auto_pair: bool,
     is_selecting: bool,
     disabled: bool,
     masked: bool,
-    appearance: bool,
+    /// Show the eye button to toggle the masked state.
+    password: bool,
+    strength_meter: Option<Box<dyn Fn(&str) -> u8 + 'static>>,
+    pub(super) appearance: bool,
     cleanable: bool,
-    size: Size,
+    pub(crate) size: Size,
     pattern: Option<regex::Regex>,
+    mask: Option<MaskPattern>,
+    filter: Option<Box<dyn Fn(char) -> bool + 'static>>,
+    max_length: Option<usize>,
+    show_counter: bool,
     validate: Option<Box<dyn Fn(&str) -> bool + 'static>>,
+    debounce: Option<Duration>,
+    _debounce_task: Option<Task<()>>,
 }
 
 impl EventEmitter<InputEvent> for TextInput {}
@@ -154,6 +250,7 @@ impl TextInput {
             text: "".into(),
             blink_cursor,
             history,
+            last_edit_offset: None,
             placeholder: "".into(),
             selected_range: 0..0,
             selection_reversed: false,
@@ -161,9 +258,23 @@ impl TextInput {
             last_layout: None,
             last_bounds: None,
             scroll_offset: point(px(0.), px(0.)),
+            last_lines: vec![],
+            last_line_height: px(0.),
+            last_cursor: None,
+            multi_line: false,
+            rows: 2,
+            max_rows: 8,
+            soft_wrap: true,
+            line_number: false,
+            highlighter: None,
+            last_highlights: None,
+            monospace: false,
+            auto_pair: false,
             is_selecting: false,
             disabled: false,
             masked: false,
+            password: false,
+            strength_meter: None,
             appearance: true,
             cleanable: false,
             loading: false,
@@ -171,7 +282,13 @@ impl TextInput {
             suffix: None,
             size: Size::Medium,
             pattern: None,
+            mask: None,
+            filter: None,
+            max_length: None,
+            show_counter: false,
             validate: None,
+            debounce: None,
+            _debounce_task: None,
         };
 
         // Observe the blink cursor to repaint the view when it changes.
@@ -243,6 +360,107 @@ impl TextInput {
         cx.notify();
     }
 
+    /// Set the input field to multi-line mode, see [`TextArea`](super::TextArea).
+    pub fn multi_line(mut self) -> Self {
+        self.multi_line = true;
+        self
+    }
+
+    /// Set the min visible rows in multi-line mode, default: 2
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Set the max visible rows in multi-line mode, it scrolls beyond the max rows, default: 8
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Set false to scroll the long lines horizontally instead of wrapping them in multi-line mode, default: true
+    pub fn soft_wrap(mut self, soft_wrap: bool) -> Self {
+        self.soft_wrap = soft_wrap;
+        self
+    }
+
+    /// Set true to show the line numbers at the left in multi-line mode, default: false
+    pub fn line_number(mut self, line_number: bool) -> Self {
+        self.line_number = line_number;
+        self
+    }
+
+    /// Set the highlighter to highlight the text as the code of the language in multi-line mode.
+    pub fn highlighter(
+        mut self,
+        language: impl Into<SharedString>,
+        highlighter: impl Highlighter + 'static,
+    ) -> Self {
+        self.highlighter = Some((language.into(), Rc::new(highlighter)));
+        self
+    }
+
+    /// Replace the highlighter, the text is highlighted again on the next render.
+    pub(crate) fn set_highlighter(
+        &mut self,
+        language: SharedString,
+        highlighter: Rc<dyn Highlighter>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.highlighter = Some((language, highlighter));
+        self.last_highlights = None;
+        cx.notify();
+    }
+
+    /// Set true to use the monospace font of the theme, default: false
+    pub fn monospace(mut self, monospace: bool) -> Self {
+        self.monospace = monospace;
+        self
+    }
+
+    /// Set true to close the brackets and quotes automatically, default: false
+    pub fn auto_pair(mut self, auto_pair: bool) -> Self {
+        self.auto_pair = auto_pair;
+        self
+    }
+
+    /// Return the highlights of the text by the highlighter, they are cached until the text is changed.
+    pub(super) fn highlights(&mut self, cx: &WindowContext) -> Vec<(Range<usize>, HighlightStyle)> {
+        let Some((language, highlighter)) = self.highlighter.as_ref() else {
+            return vec![];
+        };
+
+        match &self.last_highlights {
+            Some((text, highlights)) if *text == self.text => highlights.clone(),
+            _ => {
+                let highlights = highlighter.highlight(language, &self.text, cx);
+                self.last_highlights = Some((self.text.clone(), highlights.clone()));
+                highlights
+            }
+        }
+    }
+
+    /// Set the input mask pattern, e.g.: `(999) 999-9999`, `9999-99-99`.
+    ///
+    /// - `9` - A digit.
+    /// - `a` - A letter.
+    /// - `*` - A digit or a letter.
+    /// - Other characters are literals, they are inserted automatically when typing or pasting.
+    ///
+    /// The [`InputEvent::Change`] contains the formatted text, use [`TextInput::raw_text`] to get the text without literals.
+    pub fn mask(mut self, pattern: impl Into<SharedString>) -> Self {
+        self.mask = Some(MaskPattern::new(pattern));
+        self
+    }
+
+    /// Return the text without the literals of the mask, same as [`TextInput::text`] if the mask is not set.
+    pub fn raw_text(&self) -> SharedString {
+        match &self.mask {
+            Some(mask) => mask.unmask(&self.text).into(),
+            None => self.text.clone(),
+        }
+    }
+
     /// Set the Input size
     pub fn set_size(&mut self, size: Size, cx: &mut ViewContext<Self>) {
         self.size = size;
@@ -286,6 +504,23 @@ impl TextInput {
         self.placeholder = placeholder.into();
     }
 
+    /// Set the input field as a password input, the text is masked and an eye button is displayed to toggle it.
+    ///
+    /// The masked text can't be copied or cut to the clipboard.
+    pub fn password(mut self) -> Self {
+        self.masked = true;
+        self.password = true;
+        self
+    }
+
+    /// Show a strength meter under the input field, the scorer returns a score in `0..=4`.
+    ///
+    /// See [`password_strength`](super::password_strength) for a default scorer.
+    pub fn strength_meter(mut self, scorer: impl Fn(&str) -> u8 + 'static) -> Self {
+        self.strength_meter = Some(Box::new(scorer));
+        self
+    }
+
     /// Set true to show the clear button when the input field is not empty.
     pub fn cleanable(mut self) -> Self {
         self.cleanable = true;
@@ -298,13 +533,49 @@ impl TextInput {
         self
     }
 
+    /// Set the filter of the characters, the rejected characters are dropped when typing or pasting.
+    ///
+    /// ```ignore
+    /// TextInput::new(cx).filter(|c| c.is_ascii_alphanumeric() || c == '_')
+    /// ```
+    pub fn filter(mut self, f: impl Fn(char) -> bool + 'static) -> Self {
+        self.filter = Some(Box::new(f));
+        self
+    }
+
+    /// Set the max length of the characters, the pasted text is truncated to fit it.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set true to show the count of the characters at the input right, e.g.: `12/20` with the max length.
+    pub fn show_counter(mut self, show_counter: bool) -> Self {
+        self.show_counter = show_counter;
+        self
+    }
+
     /// Set the validation function of the input field.
     pub fn validate(mut self, f: impl Fn(&str) -> bool + 'static) -> Self {
         self.validate = Some(Box::new(f));
         self
     }
 
-    /// Set true to show indicator at the input right.
+    /// Set the duration to wait after the typing pauses to emit [`InputEvent::Input`], default: None
+    ///
+    /// Use it to avoid the expensive work (e.g. search) on every keystroke.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = Some(debounce);
+        self
+    }
+
+    /// Set true to show indicator at the input right instead of the suffix.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set true to show indicator at the input right instead of the suffix.
     pub fn set_loading(&mut self, loading: bool, cx: &mut ViewContext<Self>) {
         self.loading = loading;
         cx.notify();
@@ -350,30 +621,72 @@ impl TextInput {
         self.select_to(self.next_boundary(self.cursor_offset()), cx);
     }
 
-    fn select_all(&mut self, _: &SelectAll, cx: &mut ViewContext<Self>) {
+    pub(crate) fn select_all(&mut self, _: &SelectAll, cx: &mut ViewContext<Self>) {
         self.move_to(0, cx);
         self.select_to(self.text.len(), cx)
     }
 
+    /// Return the start offset of the line at the offset, it is 0 in single-line mode.
+    fn line_start(&self, offset: usize) -> usize {
+        self.text[..offset].rfind('\n').map_or(0, |ix| ix + 1)
+    }
+
+    /// Return the end offset of the line at the offset, it is the text length in single-line mode.
+    fn line_end(&self, offset: usize) -> usize {
+        self.text[offset..]
+            .find('\n')
+            .map_or(self.text.len(), |ix| offset + ix)
+    }
+
     fn home(&mut self, _: &Home, cx: &mut ViewContext<Self>) {
         self.pause_blink_cursor(cx);
-        self.move_to(0, cx);
+        self.move_to(self.line_start(self.cursor_offset()), cx);
     }
 
     fn end(&mut self, _: &End, cx: &mut ViewContext<Self>) {
         self.pause_blink_cursor(cx);
-        self.move_to(self.text.len(), cx);
+        self.move_to(self.line_end(self.cursor_offset()), cx);
     }
 
     fn select_to_home(&mut self, _: &SelectToHome, cx: &mut ViewContext<Self>) {
-        self.select_to(0, cx);
+        self.select_to(self.line_start(self.cursor_offset()), cx);
     }
 
     fn select_to_end(&mut self, _: &SelectToEnd, cx: &mut ViewContext<Self>) {
-        self.select_to(self.text.len(), cx);
+        self.select_to(self.line_end(self.cursor_offset()), cx);
+    }
+
+    fn move_to_previous_word(&mut self, _: &MoveToPreviousWord, cx: &mut ViewContext<Self>) {
+        let offset = previous_word_boundary(&self.text, self.cursor_offset());
+        self.move_to(offset, cx);
+    }
+
+    fn move_to_next_word(&mut self, _: &MoveToNextWord, cx: &mut ViewContext<Self>) {
+        let offset = next_word_boundary(&self.text, self.cursor_offset());
+        self.move_to(offset, cx);
+    }
+
+    fn select_to_previous_word(&mut self, _: &SelectToPreviousWord, cx: &mut ViewContext<Self>) {
+        self.select_to(previous_word_boundary(&self.text, self.cursor_offset()), cx);
+    }
+
+    fn select_to_next_word(&mut self, _: &SelectToNextWord, cx: &mut ViewContext<Self>) {
+        self.select_to(next_word_boundary(&self.text, self.cursor_offset()), cx);
+    }
+
+    /// Select the line at the offset, it selects all in single-line mode.
+    fn select_line(&mut self, offset: usize, cx: &mut ViewContext<Self>) {
+        self.selection_reversed = false;
+        self.selected_range = self.line_start(offset)..self.line_end(offset);
+        cx.notify()
     }
 
     fn backspace(&mut self, _: &Backspace, cx: &mut ViewContext<Self>) {
+        if self.auto_pair && self.selected_range.is_empty() {
+            if let Some(range) = auto_pair_delete(&self.text, self.cursor_offset()) {
+                self.selected_range = range;
+            }
+        }
         if self.selected_range.is_empty() {
             self.select_to(self.previous_boundary(self.cursor_offset()), cx)
         }
@@ -390,22 +703,85 @@ impl TextInput {
     }
 
     fn enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
+        if self.multi_line {
+            self.replace_text_in_range(None, "\n", cx);
+            self.pause_blink_cursor(cx);
+            return;
+        }
+
+        cx.emit(InputEvent::PressEnter);
+    }
+
+    fn submit(&mut self, _: &Submit, cx: &mut ViewContext<Self>) {
         cx.emit(InputEvent::PressEnter);
     }
 
+    /// Return the offset of the previous or next visual row at the same x of the cursor.
+    fn vertical_offset(&self, offset: usize, down: bool) -> usize {
+        let line_height = self.last_line_height;
+        let position = position_for_offset(&self.last_lines, offset, line_height);
+        let y = if down {
+            position.y + line_height
+        } else {
+            position.y - line_height
+        };
+
+        if y < px(0.) {
+            return 0;
+        }
+        if y >= content_height(&self.last_lines, line_height) {
+            return self.text.len();
+        }
+        offset_for_position(&self.last_lines, point(position.x, y), line_height)
+    }
+
+    fn up(&mut self, _: &Up, cx: &mut ViewContext<Self>) {
+        self.pause_blink_cursor(cx);
+        let offset = if self.selected_range.is_empty() {
+            self.vertical_offset(self.cursor_offset(), false)
+        } else {
+            self.selected_range.start
+        };
+        self.move_to(offset, cx);
+    }
+
+    fn down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        self.pause_blink_cursor(cx);
+        let offset = if self.selected_range.is_empty() {
+            self.vertical_offset(self.cursor_offset(), true)
+        } else {
+            self.selected_range.end
+        };
+        self.move_to(offset, cx);
+    }
+
+    fn select_up(&mut self, _: &SelectUp, cx: &mut ViewContext<Self>) {
+        self.select_to(self.vertical_offset(self.cursor_offset(), false), cx);
+    }
+
+    fn select_down(&mut self, _: &SelectDown, cx: &mut ViewContext<Self>) {
+        self.select_to(self.vertical_offset(self.cursor_offset(), true), cx);
+    }
+
     fn clean(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
         self.replace_text("", cx);
+        self.focus(cx);
+        cx.emit(InputEvent::Cleared);
     }
 
     fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut ViewContext<Self>) {
         self.is_selecting = true;
         let offset = self.index_for_mouse_position(event.position);
 
-        // Double click to select word
+        // Double click to select word, triple click to select line
         if event.button == MouseButton::Left && event.click_count == 2 {
             self.select_word(offset, cx);
             return;
         }
+        if event.button == MouseButton::Left && event.click_count >= 3 {
+            self.select_line(offset, cx);
+            return;
+        }
 
         if event.modifiers.shift {
             self.select_to(offset, cx);
@@ -418,12 +794,17 @@ impl TextInput {
         self.is_selecting = false;
     }
 
+    fn toggle_masked(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.masked = !self.masked;
+        cx.notify();
+    }
+
     fn show_character_palette(&mut self, _: &ShowCharacterPalette, cx: &mut ViewContext<Self>) {
         cx.show_character_palette();
     }
 
     fn copy(&mut self, _: &Copy, cx: &mut ViewContext<Self>) {
-        if self.selected_range.is_empty() {
+        if self.selected_range.is_empty() || self.masked {
             return;
         }
 
@@ -432,7 +813,7 @@ impl TextInput {
     }
 
     fn cut(&mut self, _: &Cut, cx: &mut ViewContext<Self>) {
-        if self.selected_range.is_empty() {
+        if self.selected_range.is_empty() || self.masked {
             return;
         }
 
@@ -443,11 +824,35 @@ impl TextInput {
 
     fn paste(&mut self, _: &Paste, cx: &mut ViewContext<Self>) {
         if let Some(clipboard) = cx.read_from_clipboard() {
-            let new_text = clipboard.text().unwrap_or_default().replace('\n', "");
+            let mut new_text = clipboard.text().unwrap_or_default();
+            if !self.multi_line {
+                new_text = new_text.replace('\n', "");
+            }
+            // The paste is a single step in the history.
+            self.last_edit_offset = None;
             self.replace_text_in_range(None, &new_text, cx);
+            self.last_edit_offset = None;
         }
     }
 
+    /// Emit [`InputEvent::Change`] now, and [`InputEvent::Input`] after the debounce duration.
+    fn emit_change(&mut self, cx: &mut ViewContext<Self>) {
+        cx.emit(InputEvent::Change(self.text.clone()));
+
+        let Some(debounce) = self.debounce else {
+            cx.emit(InputEvent::Input(self.text.clone()));
+            return;
+        };
+
+        // Replace the pending task to restart the timer.
+        self._debounce_task = Some(cx.spawn(|this, mut cx| async move {
+            cx.background_executor().timer(debounce).await;
+            _ = this.update(&mut cx, |this, cx| {
+                cx.emit(InputEvent::Input(this.text.clone()));
+            });
+        }));
+    }
+
     fn push_history(&mut self, range: &Range<usize>, new_text: &str, cx: &mut ViewContext<Self>) {
         if self.history.ignore {
             return;
@@ -459,6 +864,16 @@ impl TextInput {
 
         let new_range = range.start..range.start + new_text.len();
 
+        // Only group the consecutive typing or deleting at the same position.
+        let contiguous = self
+            .last_edit_offset
+            .map_or(false, |offset| range.start == offset || range.end == offset);
+        if !contiguous {
+            self.history.start_group();
+        }
+        self.last_edit_offset = Some(new_range.end);
+        self.history.clear_redos();
+
         self.history.push(Change::new(
             range.clone(),
             &old_text,
@@ -467,7 +882,9 @@ impl TextInput {
         ));
     }
 
-    fn undo(&mut self, _: &Undo, cx: &mut ViewContext<Self>) {
+    /// Undo the last group of changes.
+    pub fn undo(&mut self, cx: &mut ViewContext<Self>) {
+        self.last_edit_offset = None;
         self.history.ignore = true;
         if let Some(changes) = self.history.undo() {
             for change in changes {
@@ -478,7 +895,9 @@ impl TextInput {
         self.history.ignore = false;
     }
 
-    fn redo(&mut self, _: &Redo, cx: &mut ViewContext<Self>) {
+    /// Redo the last undone group of changes.
+    pub fn redo(&mut self, cx: &mut ViewContext<Self>) {
+        self.last_edit_offset = None;
         self.history.ignore = true;
         if let Some(changes) = self.history.redo() {
             for change in changes {
@@ -489,13 +908,29 @@ impl TextInput {
         self.history.ignore = false;
     }
 
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    fn on_action_undo(&mut self, _: &Undo, cx: &mut ViewContext<Self>) {
+        self.undo(cx);
+    }
+
+    fn on_action_redo(&mut self, _: &Redo, cx: &mut ViewContext<Self>) {
+        self.redo(cx);
+    }
+
     fn move_to(&mut self, offset: usize, cx: &mut ViewContext<Self>) {
         self.selected_range = offset..offset;
         self.pause_blink_cursor(cx);
         cx.notify()
     }
 
-    fn cursor_offset(&self) -> usize {
+    pub(super) fn cursor_offset(&self) -> usize {
         if self.selection_reversed {
             self.selected_range.start
         } else {
@@ -509,6 +944,14 @@ impl TextInput {
             return 0;
         }
 
+        if self.multi_line {
+            let origin = self
+                .last_bounds
+                .map(|bounds| bounds.origin)
+                .unwrap_or_default();
+            return offset_for_position(&self.last_lines, position - origin, self.last_line_height);
+        }
+
         let (Some(bounds), Some(line)) = (self.last_bounds.as_ref(), self.last_layout.as_ref())
         else {
             return 0;
@@ -659,12 +1102,12 @@ impl TextInput {
         self.pause_blink_cursor(cx)
     }
 
-    fn on_drag_move(&mut self, event: &MouseMoveEvent, cx: &mut ViewContext<Self>) {
+    pub(super) fn on_drag_move(&mut self, event: &MouseMoveEvent, cx: &mut ViewContext<Self>) {
         if self.text.is_empty() {
             return;
         }
 
-        if self.last_layout.is_none() {
+        if self.last_layout.is_none() && self.last_lines.is_empty() {
             return;
         }
 
@@ -683,6 +1126,10 @@ impl TextInput {
     fn offset_of_position(&self, position: Point<Pixels>) -> usize {
         let bounds = self.last_bounds.unwrap_or_default();
         let position = position - bounds.origin;
+        if self.multi_line {
+            return offset_for_position(&self.last_lines, position, self.last_line_height);
+        }
+
         self.last_layout
             .as_ref()
             .map(|line| match line.index_for_x(position.x) {
@@ -701,6 +1148,31 @@ impl TextInput {
             .unwrap_or(0)
     }
 
+    /// Remove the characters rejected by the filter, and truncate the text to fit the max length.
+    ///
+    /// Return None if all the characters are rejected, to keep the selected text.
+    fn filter_new_text(&self, new_text: &str, range: &Range<usize>) -> Option<String> {
+        let mut chars = new_text
+            .chars()
+            .filter(|c| self.filter.as_ref().map_or(true, |filter| filter(*c)))
+            .collect::<String>();
+
+        if let Some(max_length) = self.max_length {
+            let remaining_len =
+                self.text.chars().count() - self.text[range.clone()].chars().count();
+            chars = chars
+                .chars()
+                .take(max_length.saturating_sub(remaining_len))
+                .collect();
+        }
+
+        if chars.is_empty() && !new_text.is_empty() {
+            return None;
+        }
+
+        Some(chars)
+    }
+
     fn is_valid_input(&self, new_text: &str) -> bool {
         if new_text.is_empty() {
             return true;
@@ -767,23 +1239,55 @@ impl ViewInputHandler for TextInput {
             return;
         }
 
+        // Only for the typing, the range is given when replacing the text by code.
+        if self.auto_pair && range_utf16.is_none() && self.marked_range.is_none() {
+            if let Some((range, new_text, selected_range)) =
+                auto_pair_insert(&self.text, &self.selected_range, new_text)
+            {
+                if !new_text.is_empty() {
+                    self.replace_text_in_range(Some(self.range_to_utf16(&range)), &new_text, cx);
+                }
+                self.selection_reversed = false;
+                self.selected_range = selected_range;
+                cx.notify();
+                return;
+            }
+        }
+
         let range = range_utf16
             .as_ref()
             .map(|range_utf16| self.range_from_utf16(range_utf16))
             .or(self.marked_range.clone())
             .unwrap_or(self.selected_range.clone());
 
-        let pending_text: SharedString =
-            (self.text[0..range.start].to_owned() + new_text + &self.text[range.end..]).into();
+        let Some(mut new_text) = self.filter_new_text(new_text, &range) else {
+            return;
+        };
+        let mut pending_text: SharedString =
+            (self.text[0..range.start].to_owned() + &new_text + &self.text[range.end..]).into();
+        let mut cursor = range.start + new_text.len();
+        let mut range = range;
+        if let Some(mask) = &self.mask {
+            let formatted = mask.format(&pending_text);
+            cursor = if cursor == pending_text.len() {
+                formatted.len()
+            } else {
+                mask.offset_for_raw_count(&formatted, mask.raw_count(&pending_text[..cursor]))
+            };
+            // The literals may be changed, so replace the whole text.
+            range = 0..self.text.len();
+            new_text = formatted.clone();
+            pending_text = formatted.into();
+        }
         if !self.is_valid_input(&pending_text) {
             return;
         }
 
-        self.push_history(&range, new_text, cx);
+        self.push_history(&range, &new_text, cx);
         self.text = pending_text;
-        self.selected_range = range.start + new_text.len()..range.start + new_text.len();
+        self.selected_range = cursor..cursor;
         self.marked_range.take();
-        cx.emit(InputEvent::Change(self.text.clone()));
+        self.emit_change(cx);
         cx.notify();
     }
 
@@ -817,7 +1321,7 @@ impl ViewInputHandler for TextInput {
             .map(|range_utf16| self.range_from_utf16(range_utf16))
             .map(|new_range| new_range.start + range.start..new_range.end + range.end)
             .unwrap_or_else(|| range.start + new_text.len()..range.start + new_text.len());
-        cx.emit(InputEvent::Change(self.text.clone()));
+        self.emit_change(cx);
         cx.notify();
     }
 
@@ -827,8 +1331,22 @@ impl ViewInputHandler for TextInput {
         bounds: Bounds<Pixels>,
         _cx: &mut ViewContext<Self>,
     ) -> Option<Bounds<Pixels>> {
-        let last_layout = self.last_layout.as_ref()?;
         let range = self.range_from_utf16(&range_utf16);
+        if self.multi_line {
+            let line_height = self.last_line_height;
+            let start = position_for_offset(&self.last_lines, range.start, line_height);
+            let end = position_for_offset(&self.last_lines, range.end, line_height);
+            let origin = self
+                .last_bounds
+                .map(|bounds| bounds.origin)
+                .unwrap_or(bounds.origin);
+            return Some(Bounds::from_corners(
+                origin + start,
+                origin + point(end.x, end.y + line_height),
+            ));
+        }
+
+        let last_layout = self.last_layout.as_ref()?;
         Some(Bounds::from_corners(
             point(
                 bounds.left() + last_layout.x_for_index(range.start),
@@ -1085,16 +1603,31 @@ impl Render for TextInput {
 
         let prefix = self.prefix.as_ref().map(|build| build(cx));
         let suffix = self.suffix.as_ref().map(|build| build(cx));
+        let strength = self
+            .strength_meter
+            .as_ref()
+            .map(|scorer| scorer(&self.text));
 
-        div()
+        let input = div()
             .flex()
-            .key_context(CONTEXT)
+            .key_context(if self.multi_line {
+                "Input TextArea"
+            } else {
+                CONTEXT
+            })
             .track_focus(&self.focus_handle)
             .when(!self.disabled, |this| {
                 this.on_action(cx.listener(Self::backspace))
                     .on_action(cx.listener(Self::delete))
                     .on_action(cx.listener(Self::enter))
             })
+            .when(self.multi_line, |this| {
+                this.on_action(cx.listener(Self::up))
+                    .on_action(cx.listener(Self::down))
+                    .on_action(cx.listener(Self::select_up))
+                    .on_action(cx.listener(Self::select_down))
+                    .on_action(cx.listener(Self::submit))
+            })
             .on_action(cx.listener(Self::left))
             .on_action(cx.listener(Self::right))
             .on_action(cx.listener(Self::select_left))
@@ -1104,13 +1637,16 @@ impl Render for TextInput {
             .on_action(cx.listener(Self::select_to_end))
             .on_action(cx.listener(Self::home))
             .on_action(cx.listener(Self::end))
+            .on_action(cx.listener(Self::move_to_previous_word))
+            .on_action(cx.listener(Self::move_to_next_word))
+            .on_action(cx.listener(Self::select_to_previous_word))
+            .on_action(cx.listener(Self::select_to_next_word))
             .on_action(cx.listener(Self::show_character_palette))
             .on_action(cx.listener(Self::copy))
             .on_action(cx.listener(Self::paste))
             .on_action(cx.listener(Self::cut))
-            .on_action(cx.listener(Self::undo))
-            .on_action(cx.listener(Self::redo))
-            .on_action(cx.listener(Self::redo))
+            .on_action(cx.listener(Self::on_action_undo))
+            .on_action(cx.listener(Self::on_action_redo))
             // Double click to select all
             .on_double_click(cx.listener(|view, _, cx| {
                 view.select_all(&SelectAll, cx);
@@ -1123,8 +1659,11 @@ impl Render for TextInput {
             .size_full()
             .line_height(rems(1.25))
             .text_size(rems(0.875))
+            .when(self.monospace, |this| {
+                this.font_family(cx.theme().mono_font_family.clone())
+            })
             .input_py(self.size)
-            .input_h(self.size)
+            .when(!self.multi_line, |this| this.input_h(self.size))
             .when(self.appearance, |this| {
                 this.bg(if self.disabled {
                     cx.theme().muted
@@ -1137,26 +1676,94 @@ impl Render for TextInput {
                 .when(cx.theme().shadow, |this| this.shadow_sm())
                 .when(focused, |this| this.outline(cx))
                 .when(prefix.is_none(), |this| this.input_pl(self.size))
-                .when(suffix.is_none(), |this| this.input_pr(self.size))
+                .when(suffix.is_none() || self.loading, |this| {
+                    this.input_pr(self.size)
+                })
             })
             .children(prefix)
             .gap_1()
-            .items_center()
+            .map(|this| {
+                if self.multi_line {
+                    this.items_start()
+                } else {
+                    this.items_center()
+                }
+            })
             .child(
                 div()
                     .id("TextElement")
                     .flex_grow()
                     .overflow_x_hidden()
                     .cursor_text()
-                    .child(TextElement {
-                        input: cx.view().clone(),
+                    .map(|this| {
+                        let input = cx.view().clone();
+                        if self.multi_line {
+                            this.child(TextAreaElement { input })
+                        } else {
+                            this.child(TextElement { input })
+                        }
                     }),
             )
+            .when(self.show_counter, |this| {
+                let count = self.text.chars().count();
+                this.child(
+                    div()
+                        .flex_none()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(match self.max_length {
+                            Some(max_length) => format!("{}/{}", count, max_length),
+                            None => count.to_string(),
+                        }),
+                )
+            })
             .when(self.loading, |this| this.child(Indicator::new()))
             .when(
                 self.cleanable && !self.loading && !self.text.is_empty(),
                 |this| this.child(ClearButton::new(cx).on_click(cx.listener(Self::clean))),
             )
-            .children(suffix)
+            .when(self.password, |this| {
+                this.child(
+                    Button::new("toggle-masked")
+                        .icon(if self.masked {
+                            IconName::Eye
+                        } else {
+                            IconName::EyeOff
+                        })
+                        .ghost()
+                        .xsmall()
+                        .on_click(cx.listener(Self::toggle_masked)),
+                )
+            })
+            .when(!self.loading, |this| this.children(suffix));
+
+        match strength {
+            Some(score) => v_flex()
+                .w_full()
+                .gap_1p5()
+                .child(input)
+                .child(StrengthMeter::new(score))
+                .into_any_element(),
+            None => input.into_any_element(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{next_word_boundary, previous_word_boundary};
+
+    #[test]
+    fn test_word_boundary() {
+        let text = "Hello, 世界 foo_bar";
+        assert_eq!(previous_word_boundary(text, text.len()), 14);
+        assert_eq!(previous_word_boundary(text, 14), 7);
+        assert_eq!(previous_word_boundary(text, 7), 0);
+        assert_eq!(previous_word_boundary(text, 0), 0);
+
+        assert_eq!(next_word_boundary(text, 0), 5);
+        assert_eq!(next_word_boundary(text, 5), 13);
+        assert_eq!(next_word_boundary(text, 13), text.len());
+        assert_eq!(next_word_boundary(text, text.len()), text.len());
     }
 }
@@ -0,0 +1,209 @@
+use gpui::SharedString;
+
+/// The token of the [`MaskPattern`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MaskToken {
+    /// `9` - A digit.
+    Digit,
+    /// `a` - A letter.
+    Letter,
+    /// `*` - A digit or a letter.
+    Any,
+    /// Other characters, they are inserted automatically.
+    Literal(char),
+}
+
+impl MaskToken {
+    fn parse(c: char) -> Self {
+        match c {
+            '9' => Self::Digit,
+            'a' => Self::Letter,
+            '*' => Self::Any,
+            c => Self::Literal(c),
+        }
+    }
+
+    fn is_placeholder(&self) -> bool {
+        !matches!(self, Self::Literal(_))
+    }
+
+    /// Return true if the character can be filled into the placeholder.
+    fn accepts(&self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Any => c.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+}
+
+/// The input mask pattern, e.g.: `(999) 999-9999`, `9999-99-99`.
+///
+/// - `9` - A digit.
+/// - `a` - A letter.
+/// - `*` - A digit or a letter.
+/// - Other characters are literals, they are inserted automatically.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MaskPattern {
+    tokens: Vec<MaskToken>,
+}
+
+impl MaskPattern {
+    pub(crate) fn new(pattern: impl Into<SharedString>) -> Self {
+        Self {
+            tokens: pattern.into().chars().map(MaskToken::parse).collect(),
+        }
+    }
+
+    /// Format the text by the mask, the characters that don't match the placeholders are dropped.
+    ///
+    /// The literals are only inserted before a filled placeholder, so the text never ends with literals.
+    pub(crate) fn format(&self, text: &str) -> String {
+        let tokens = &self.tokens;
+        let mut chars = text.chars().peekable();
+        let mut out = String::new();
+        let mut ix = 0;
+
+        while ix < tokens.len() {
+            if tokens[ix].is_placeholder() {
+                loop {
+                    let Some(c) = chars.next() else {
+                        return out;
+                    };
+                    if tokens[ix].accepts(c) {
+                        out.push(c);
+                        break;
+                    }
+                }
+                ix += 1;
+                continue;
+            }
+
+            let end = tokens[ix..]
+                .iter()
+                .position(MaskToken::is_placeholder)
+                .map_or(tokens.len(), |len| ix + len);
+            let literals = tokens[ix..end]
+                .iter()
+                .filter_map(|token| match token {
+                    MaskToken::Literal(c) => Some(*c),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            // Skip the literals if they have been typed or pasted, e.g.: `+1 `.
+            // The literal digits (the `1` in `+1 999`) are only skipped with the whole literals,
+            // otherwise they are the typed digits to fill the placeholders.
+            let rest = chars.clone().take(literals.len()).collect::<Vec<_>>();
+            if literals.starts_with(&rest) {
+                for _ in 0..rest.len() {
+                    chars.next();
+                }
+            }
+            if chars.peek().is_none() {
+                return out;
+            }
+
+            out.extend(literals);
+            ix = end;
+        }
+
+        out
+    }
+
+    /// Return the text without the literals of the mask.
+    pub(crate) fn unmask(&self, formatted: &str) -> String {
+        formatted
+            .chars()
+            .zip(self.tokens.iter())
+            .filter(|(_, token)| token.is_placeholder())
+            .map(|(c, _)| c)
+            .collect()
+    }
+
+    /// Return the byte offset in the formatted text after the `count` of the raw characters.
+    pub(crate) fn offset_for_raw_count(&self, formatted: &str, count: usize) -> usize {
+        if count == 0 {
+            return 0;
+        }
+
+        formatted
+            .char_indices()
+            .zip(self.tokens.iter())
+            .filter(|(_, token)| token.is_placeholder())
+            .nth(count - 1)
+            .map(|((ix, c), _)| ix + c.len_utf8())
+            .unwrap_or(formatted.len())
+    }
+
+    /// Return the count of the raw characters that the text fills into the placeholders,
+    /// the literals typed or pasted in the text are not counted.
+    pub(crate) fn raw_count(&self, text: &str) -> usize {
+        self.unmask(&self.format(text)).chars().count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MaskPattern;
+
+    #[test]
+    fn test_format() {
+        let mask = MaskPattern::new("(999) 999-9999");
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.format("5"), "(5");
+        assert_eq!(mask.format("555"), "(555");
+        assert_eq!(mask.format("5551"), "(555) 1");
+        assert_eq!(mask.format("555-123-45678"), "(555) 123-4567");
+        assert_eq!(mask.format("(555) 12x3"), "(555) 123");
+        assert_eq!(mask.format("(555) "), "(555");
+
+        let mask = MaskPattern::new("+1 999");
+        assert_eq!(mask.format("+1 23"), "+1 23");
+        assert_eq!(mask.format("23"), "+1 23");
+        assert_eq!(mask.format("1"), "+1 1");
+        assert_eq!(mask.format("123"), "+1 123");
+        assert_eq!(mask.format("+1 1"), "+1 1");
+        assert_eq!(mask.format("+1 "), "");
+
+        let mask = MaskPattern::new("aa-99");
+        assert_eq!(mask.format("ab12"), "ab-12");
+        assert_eq!(mask.format("1ab"), "ab");
+    }
+
+    #[test]
+    fn test_unmask() {
+        let mask = MaskPattern::new("(999) 999-9999");
+        assert_eq!(mask.unmask("(555) 123-4567"), "5551234567");
+        assert_eq!(mask.unmask("(555) 1"), "5551");
+        assert_eq!(mask.unmask(""), "");
+
+        let mask = MaskPattern::new("+1 (999)");
+        assert_eq!(mask.unmask("+1 (555)"), "555");
+    }
+
+    #[test]
+    fn test_offset_for_raw_count() {
+        let mask = MaskPattern::new("(999) 999-9999");
+        assert_eq!(mask.offset_for_raw_count("(555) 123", 0), 0);
+        assert_eq!(mask.offset_for_raw_count("(555) 123", 3), 4);
+        assert_eq!(mask.offset_for_raw_count("(555) 123", 4), 7);
+        assert_eq!(mask.offset_for_raw_count("(555) 123", 10), 9);
+    }
+
+    #[test]
+    fn test_literal_digits() {
+        let mask = MaskPattern::new("+1 (999) 999");
+        assert_eq!(mask.format("5551"), "+1 (555) 1");
+        assert_eq!(mask.format("+1 (555) 1"), "+1 (555) 1");
+
+        // The `1` of the literals is not a raw character.
+        assert_eq!(mask.raw_count("+1 (55"), 2);
+        assert_eq!(mask.raw_count("+1 ("), 0);
+        assert_eq!(mask.raw_count("15"), 2);
+        assert_eq!(mask.offset_for_raw_count("+1 (555) 1", 0), 0);
+        assert_eq!(mask.offset_for_raw_count("+1 (555) 1", 2), 6);
+        assert_eq!(mask.offset_for_raw_count("+1 (555) 1", 4), 10);
+    }
+}
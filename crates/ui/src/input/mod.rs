@@ -1,9 +1,23 @@
+mod auto_pair;
+pub(crate) mod autocomplete;
 mod blink_cursor;
 mod change;
 mod clear_button;
 mod input;
-mod otp_input;
+mod mask;
+pub(crate) mod number_input;
+pub(crate) mod otp_input;
+mod password;
+mod tag_input;
+mod text_area;
 
+pub use autocomplete::*;
 pub(crate) use clear_button::*;
 pub use input::*;
+// The components init their own key bindings, `init` is the one of the `TextInput`.
+pub use input::init;
+pub use number_input::*;
 pub use otp_input::*;
+pub use password::{password_strength, MAX_PASSWORD_SCORE};
+pub use tag_input::*;
+pub use text_area::TextArea;
@@ -0,0 +1,332 @@
+use gpui::{
+    actions, AppContext, EventEmitter, FocusHandle, FocusableView, InteractiveElement as _,
+    IntoElement, KeyBinding, ParentElement as _, Render, SharedString, Styled as _, View,
+    ViewContext, VisualContext as _,
+};
+use rust_i18n::t;
+
+use super::{InputEvent, TextInput};
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex, IconName, Sizable, Size,
+};
+
+actions!(number_input, [Increment, Decrement]);
+
+const CONTEXT: &str = "NumberInput";
+
+pub(crate) fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", Increment, Some(CONTEXT)),
+        KeyBinding::new("down", Decrement, Some(CONTEXT)),
+    ]);
+}
+
+pub enum NumberInputEvent {
+    /// The value has been changed by pressing Enter, blur or the steppers, `None` if the input is empty.
+    Change(Option<f64>),
+}
+
+/// Format the number with the precision, the thousands separator and the decimal separator.
+fn format_number(
+    value: f64,
+    precision: Option<usize>,
+    thousands: Option<char>,
+    decimal: char,
+) -> String {
+    let text = match precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => value.to_string(),
+    };
+    let (sign, text) = match text.strip_prefix('-') {
+        Some(text) => ("-", text),
+        None => ("", text.as_str()),
+    };
+    let (int, frac) = match text.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (text, None),
+    };
+
+    let mut out = String::from(sign);
+    for (ix, c) in int.chars().enumerate() {
+        if ix > 0 && (int.len() - ix) % 3 == 0 {
+            out.extend(thousands);
+        }
+        out.push(c);
+    }
+    if let Some(frac) = frac {
+        out.push(decimal);
+        out.push_str(frac);
+    }
+    out
+}
+
+/// Parse the number formatted by [`format_number`].
+fn parse_number(text: &str, thousands: Option<char>, decimal: char) -> Option<f64> {
+    text.trim()
+        .chars()
+        .filter(|c| Some(*c) != thousands)
+        .map(|c| if c == decimal { '.' } else { c })
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+/// An input that only accepts numbers.
+///
+/// - Press `Up`/`Down` or click the steppers to increment/decrement by `step`.
+/// - The value is clamped to `min`/`max` and rounded to `precision` when committed by `Enter` or blur.
+/// - The thousands separator is displayed when not focused, the separators follow the current locale.
+pub struct NumberInput {
+    input: View<TextInput>,
+    value: Option<f64>,
+    min: f64,
+    max: f64,
+    step: f64,
+    precision: Option<usize>,
+    thousands_separator: bool,
+    placeholder: Option<SharedString>,
+    size: Size,
+}
+
+impl EventEmitter<NumberInputEvent> for NumberInput {}
+
+impl NumberInput {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let (thousands, decimal) = Self::separators();
+        let view = cx.view().downgrade();
+        let input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .validate(move |text| {
+                    text.chars()
+                        .all(|c| c.is_ascii_digit() || c == '-' || c == decimal || c == thousands)
+                })
+                .prefix({
+                    let view = view.clone();
+                    move |_| {
+                        let view = view.clone();
+                        Button::new("decrement")
+                            .icon(IconName::Minus)
+                            .ghost()
+                            .xsmall()
+                            .ml_1()
+                            .on_click(move |_, cx| {
+                                _ = view.update(cx, |this, cx| this.decrement(&Decrement, cx));
+                            })
+                    }
+                })
+                .suffix(move |_| {
+                    let view = view.clone();
+                    Button::new("increment")
+                        .icon(IconName::Plus)
+                        .ghost()
+                        .xsmall()
+                        .mr_1()
+                        .on_click(move |_, cx| {
+                            _ = view.update(cx, |this, cx| this.increment(&Increment, cx));
+                        })
+                })
+        });
+        cx.subscribe(&input, Self::on_input_event).detach();
+
+        Self {
+            input,
+            value: None,
+            min: f64::MIN,
+            max: f64::MAX,
+            step: 1.,
+            precision: None,
+            thousands_separator: true,
+            placeholder: None,
+            size: Size::default(),
+        }
+    }
+
+    /// Return the thousands and decimal separators of the current locale.
+    fn separators() -> (char, char) {
+        let thousands = t!("NumberInput.thousands_separator").chars().next();
+        let decimal = t!("NumberInput.decimal_separator").chars().next();
+        (thousands.unwrap_or(','), decimal.unwrap_or('.'))
+    }
+
+    /// Set the minimum value, default: f64::MIN
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum value, default: f64::MAX
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the step to increment/decrement, default: 1
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the number of the decimal places, default is not limited.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Set true to display the thousands separator when not focused, default: true
+    pub fn thousands_separator(mut self, thousands_separator: bool) -> Self {
+        self.thousands_separator = thousands_separator;
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn value(&self) -> Option<f64> {
+        self.value
+    }
+
+    /// Set the value, it will be clamped and rounded.
+    pub fn set_value(&mut self, value: Option<f64>, cx: &mut ViewContext<Self>) {
+        let value = value.map(|value| self.normalize(value));
+        let text = value
+            .map(|value| self.format(value, cx))
+            .unwrap_or_default();
+        self.input.update(cx, |input, cx| input.set_text(text, cx));
+
+        if self.value != value {
+            self.value = value;
+            cx.emit(NumberInputEvent::Change(value));
+            cx.notify();
+        }
+    }
+
+    pub fn focus(&self, cx: &mut ViewContext<Self>) {
+        self.input.focus_handle(cx).focus(cx);
+    }
+
+    fn normalize(&self, value: f64) -> f64 {
+        let value = value.clamp(self.min, self.max);
+        match self.precision {
+            Some(precision) => {
+                let scale = 10f64.powi(precision as i32);
+                (value * scale).round() / scale
+            }
+            None => value,
+        }
+    }
+
+    fn format(&self, value: f64, cx: &ViewContext<Self>) -> String {
+        let (thousands, decimal) = Self::separators();
+        let focused = self.input.focus_handle(cx).is_focused(cx);
+        let thousands = (self.thousands_separator && !focused).then_some(thousands);
+        format_number(value, self.precision, thousands, decimal)
+    }
+
+    fn parse(&self, cx: &AppContext) -> Option<f64> {
+        let (thousands, decimal) = Self::separators();
+        parse_number(&self.input.read(cx).text(), Some(thousands), decimal)
+    }
+
+    /// Commit the input text as the value, restore to the last value if the text is invalid.
+    fn commit(&mut self, cx: &mut ViewContext<Self>) {
+        let value = if self.input.read(cx).text().trim().is_empty() {
+            None
+        } else {
+            self.parse(cx).or(self.value)
+        };
+        self.set_value(value, cx);
+    }
+
+    fn increment(&mut self, _: &Increment, cx: &mut ViewContext<Self>) {
+        let value = self.parse(cx).or(self.value).unwrap_or(0.);
+        self.set_value(Some(value + self.step), cx);
+    }
+
+    fn decrement(&mut self, _: &Decrement, cx: &mut ViewContext<Self>) {
+        let value = self.parse(cx).or(self.value).unwrap_or(0.);
+        self.set_value(Some(value - self.step), cx);
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::PressEnter | InputEvent::Blur => self.commit(cx),
+            // Remove the thousands separator for editing.
+            InputEvent::Focus => {
+                if let Some(value) = self.value {
+                    let text = self.format(value, cx);
+                    self.input.update(cx, |input, cx| input.set_text(text, cx));
+                }
+            }
+            InputEvent::Change(_) | InputEvent::Input(_) | InputEvent::Cleared => {}
+        }
+    }
+}
+
+impl Sizable for NumberInput {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl FocusableView for NumberInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for NumberInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let size = self.size;
+        let placeholder = self.placeholder.take();
+        self.input.update(cx, |input, cx| {
+            if let Some(placeholder) = placeholder {
+                input.set_placeholder(placeholder);
+            }
+            if input.size != size {
+                input.set_size(size, cx);
+            }
+        });
+
+        h_flex()
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::increment))
+            .on_action(cx.listener(Self::decrement))
+            .w_full()
+            .child(self.input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_number, parse_number};
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(0., None, Some(','), '.'), "0");
+        assert_eq!(
+            format_number(1234567.5, None, Some(','), '.'),
+            "1,234,567.5"
+        );
+        assert_eq!(format_number(-1234.5, Some(2), Some(','), '.'), "-1,234.50");
+        assert_eq!(format_number(123.456, Some(1), None, '.'), "123.5");
+        assert_eq!(format_number(1234.5, None, Some('.'), ','), "1.234,5");
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("1,234,567.5", Some(','), '.'), Some(1234567.5));
+        assert_eq!(parse_number(" -12 ", Some(','), '.'), Some(-12.));
+        assert_eq!(parse_number("1.234,5", Some('.'), ','), Some(1234.5));
+        assert_eq!(parse_number("-", Some(','), '.'), None);
+        assert_eq!(parse_number("", Some(','), '.'), None);
+    }
+}
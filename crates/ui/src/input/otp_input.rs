@@ -1,12 +1,26 @@
+use std::rc::Rc;
+
 use gpui::{
-    div, prelude::FluentBuilder, px, AnyElement, Context, EventEmitter, FocusHandle, FocusableView,
-    InteractiveElement, IntoElement, KeyDownEvent, Model, MouseButton, MouseDownEvent,
-    ParentElement as _, Render, SharedString, Styled as _, ViewContext,
+    div, prelude::FluentBuilder, px, AnyElement, AppContext, Context, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement, IntoElement, KeyBinding, KeyDownEvent, Model, MouseButton,
+    MouseDownEvent, ParentElement as _, Render, SharedString, Styled as _, ViewContext,
+    WindowContext,
 };
 
 use crate::{h_flex, theme::ActiveTheme, v_flex, Icon, IconName, Sizable, Size};
 
-use super::{blink_cursor::BlinkCursor, InputEvent};
+use super::{blink_cursor::BlinkCursor, InputEvent, Paste};
+
+const CONTEXT: &str = "OtpInput";
+
+pub(crate) fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-v", Paste, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-v", Paste, Some(CONTEXT)),
+    ]);
+}
 
 pub enum InputOptEvent {
     /// When all OTP input have filled, this event will be triggered.
@@ -21,6 +35,7 @@ pub struct OtpInput {
     value: SharedString,
     blink_cursor: Model<BlinkCursor>,
     size: Size,
+    on_complete: Option<Rc<dyn Fn(&SharedString, &mut WindowContext)>>,
 }
 
 impl OtpInput {
@@ -35,6 +50,7 @@ impl OtpInput {
             masked: false,
             blink_cursor: blink_cursor.clone(),
             size: Size::Medium,
+            on_complete: None,
         };
 
         // Observe the blink cursor to repaint the view when it changes.
@@ -97,6 +113,43 @@ impl OtpInput {
         self.focus_handle.focus(cx);
     }
 
+    /// Set the handler to be called with the code when all the digits have been filled.
+    pub fn on_complete(
+        mut self,
+        handler: impl Fn(&SharedString, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_complete = Some(Rc::new(handler));
+        self
+    }
+
+    fn change(&mut self, value: SharedString, cx: &mut ViewContext<Self>) {
+        self.pause_blink_cursor(cx);
+        self.value = value;
+
+        if self.value.chars().count() == self.length {
+            cx.emit(InputEvent::Change(self.value.clone()));
+            if let Some(on_complete) = self.on_complete.clone() {
+                on_complete(&self.value, cx);
+            }
+        }
+        cx.notify()
+    }
+
+    /// Paste the digits of the clipboard text, e.g.: `123-456` to fill a full code.
+    fn paste(&mut self, _: &Paste, cx: &mut ViewContext<Self>) {
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+
+        let value = self
+            .value
+            .chars()
+            .chain(text.chars().filter(|c| c.is_ascii_digit()))
+            .take(self.length)
+            .collect::<String>();
+        self.change(value.into(), cx);
+    }
+
     fn on_input_mouse_down(&mut self, _: &MouseDownEvent, cx: &mut ViewContext<Self>) {
         cx.focus(&self.focus_handle);
     }
@@ -118,6 +171,11 @@ impl OtpInput {
                 cx.stop_propagation();
             }
             _ => {
+                let modifiers = event.keystroke.modifiers;
+                if modifiers.control || modifiers.platform || modifiers.alt {
+                    return;
+                }
+
                 let c = key.chars().next().unwrap();
                 if !matches!(c, '0'..='9') {
                     return;
@@ -133,13 +191,7 @@ impl OtpInput {
             }
         }
 
-        self.pause_blink_cursor(cx);
-        self.value = SharedString::from(chars.iter().collect::<String>());
-
-        if self.value.chars().count() == self.length {
-            cx.emit(InputEvent::Change(self.value.clone()));
-        }
-        cx.notify()
+        self.change(chars.iter().collect::<String>().into(), cx);
     }
 
     fn on_focus(&mut self, cx: &mut ViewContext<Self>) {
@@ -171,7 +223,7 @@ impl Sizable for OtpInput {
 }
 
 impl FocusableView for OtpInput {
-    fn focus_handle(&self, _: &gpui::AppContext) -> FocusHandle {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
         self.focus_handle.clone()
     }
 }
@@ -252,7 +304,9 @@ impl Render for OtpInput {
         }
 
         v_flex()
+            .key_context(CONTEXT)
             .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::paste))
             .on_key_down(cx.listener(Self::on_key_down))
             .items_center()
             .child(
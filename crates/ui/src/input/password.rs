@@ -0,0 +1,104 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, IntoElement, ParentElement as _, RenderOnce, Styled as _,
+    WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{h_flex, theme::ActiveTheme as _};
+
+/// The max score of the password strength.
+pub const MAX_PASSWORD_SCORE: u8 = 4;
+
+/// A simple password strength scorer, returns a score in `0..=4`.
+///
+/// The score is based on the length and the kinds of characters,
+/// use it as the default of [`TextInput::strength_meter`](super::TextInput::strength_meter).
+pub fn password_strength(password: &str) -> u8 {
+    let len = password.chars().count();
+    if len < 6 {
+        return if len == 0 { 0 } else { 1 };
+    }
+
+    let has_lower = password.chars().any(|c| c.is_lowercase());
+    let has_upper = password.chars().any(|c| c.is_uppercase());
+    let has_digit = password.chars().any(|c| c.is_ascii_digit());
+    let has_symbol = password.chars().any(|c| !c.is_alphanumeric());
+
+    let mut score = 0;
+    if len >= 8 {
+        score += 1;
+    }
+    if len >= 12 {
+        score += 1;
+    }
+    if has_lower && has_upper {
+        score += 1;
+    }
+    if has_digit {
+        score += 1;
+    }
+    if has_symbol {
+        score += 1;
+    }
+
+    score.clamp(1, MAX_PASSWORD_SCORE)
+}
+
+/// The bars to display the password strength under the input.
+#[derive(IntoElement)]
+pub(crate) struct StrengthMeter {
+    score: u8,
+}
+
+impl StrengthMeter {
+    pub(crate) fn new(score: u8) -> Self {
+        Self {
+            score: score.min(MAX_PASSWORD_SCORE),
+        }
+    }
+}
+
+impl RenderOnce for StrengthMeter {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (color, label) = match self.score {
+            0 => (cx.theme().muted_foreground, None),
+            1 => (crate::red_500(), Some(t!("Password.weak"))),
+            2 => (crate::orange_500(), Some(t!("Password.fair"))),
+            3 => (crate::yellow_500(), Some(t!("Password.good"))),
+            _ => (crate::green_500(), Some(t!("Password.strong"))),
+        };
+
+        h_flex()
+            .gap_2()
+            .child(
+                h_flex()
+                    .flex_1()
+                    .gap_1()
+                    .children((0..MAX_PASSWORD_SCORE).map(|ix| {
+                        div()
+                            .flex_1()
+                            .h(px(3.))
+                            .rounded(px(1.5))
+                            .bg(cx.theme().muted)
+                            .when(ix < self.score, |this| this.bg(color))
+                    })),
+            )
+            .when_some(label, |this, label| {
+                this.child(div().text_xs().text_color(color).child(label.to_string()))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::password_strength;
+
+    #[test]
+    fn test_password_strength() {
+        assert_eq!(password_strength(""), 0);
+        assert_eq!(password_strength("abc"), 1);
+        assert_eq!(password_strength("abcdefgh"), 1);
+        assert_eq!(password_strength("Abcdefgh1"), 3);
+        assert_eq!(password_strength("Abcdefgh1234!"), 4);
+    }
+}
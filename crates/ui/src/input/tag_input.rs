@@ -0,0 +1,253 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AppContext, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement as _, IntoElement, MouseButton, ParentElement as _, Render, SharedString,
+    Styled as _, View, ViewContext, VisualContext as _,
+};
+
+use super::{Backspace, InputEvent, TextInput};
+use crate::{
+    h_flex, tag::Tag, theme::ActiveTheme as _, Sizable, Size, StyleSized as _, StyledExt as _,
+};
+
+pub enum TagInputEvent {
+    /// The tags have been changed by adding or removing.
+    Change(Vec<SharedString>),
+}
+
+/// Split the text by comma, return the completed tags and the remaining text after the last comma.
+fn split_tags(text: &str) -> (Vec<&str>, &str) {
+    match text.rfind(',') {
+        Some(ix) => (
+            text[..ix]
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .collect(),
+            &text[ix + 1..],
+        ),
+        None => (vec![], text),
+    }
+}
+
+/// An input to enter multiple tags.
+///
+/// - Press `Enter` or type `,` to create a tag from the text.
+/// - Press `Backspace` on the empty input to remove the last tag.
+/// - Paste `a, b, c` to create multiple tags at once.
+pub struct TagInput {
+    input: View<TextInput>,
+    tags: Vec<SharedString>,
+    /// Return false to reject the new tag, the arguments are the new tag and the current tags.
+    validator: Rc<dyn Fn(&str, &[SharedString]) -> bool>,
+    placeholder: Option<SharedString>,
+    size: Size,
+    disabled: bool,
+}
+
+impl EventEmitter<TagInputEvent> for TagInput {}
+
+impl TagInput {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| TextInput::new(cx).appearance(false));
+        cx.subscribe(&input, Self::on_input_event).detach();
+
+        Self {
+            input,
+            tags: vec![],
+            validator: Rc::new(|tag, tags| !tags.iter().any(|t| t == tag)),
+            placeholder: None,
+            size: Size::default(),
+            disabled: false,
+        }
+    }
+
+    /// Set the validator of the new tag, default rejects the duplicate tags.
+    pub fn validate(mut self, f: impl Fn(&str, &[SharedString]) -> bool + 'static) -> Self {
+        self.validator = Rc::new(f);
+        self
+    }
+
+    /// Set the placeholder, it only displays when there is no tag.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn tags(&self) -> &[SharedString] {
+        &self.tags
+    }
+
+    /// Set the tags, the validator is not applied.
+    pub fn set_tags(&mut self, tags: Vec<SharedString>, cx: &mut ViewContext<Self>) {
+        self.tags = tags;
+        cx.notify();
+    }
+
+    pub fn focus(&self, cx: &mut ViewContext<Self>) {
+        self.input.focus_handle(cx).focus(cx);
+    }
+
+    /// Add a tag, return false if the tag is rejected by the validator.
+    pub fn add_tag(&mut self, tag: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> bool {
+        if !self.insert(tag.into()) {
+            return false;
+        }
+
+        cx.emit(TagInputEvent::Change(self.tags.clone()));
+        cx.notify();
+        true
+    }
+
+    pub fn remove_tag(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.tags.len() {
+            return;
+        }
+
+        self.tags.remove(ix);
+        cx.emit(TagInputEvent::Change(self.tags.clone()));
+        cx.notify();
+    }
+
+    fn insert(&mut self, tag: SharedString) -> bool {
+        if tag.trim().is_empty() || !(self.validator)(&tag, &self.tags) {
+            return false;
+        }
+
+        self.tags.push(tag);
+        true
+    }
+
+    fn on_input_event(
+        &mut self,
+        input: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::Change(text) => {
+                let (tags, rest) = split_tags(text);
+                if tags.is_empty() && rest.len() == text.len() {
+                    return;
+                }
+
+                let mut changed = false;
+                for tag in tags {
+                    changed |= self.insert(tag.to_string().into());
+                }
+                let rest = rest.trim_start().to_string();
+                input.update(cx, |input, cx| input.set_text(rest, cx));
+
+                if changed {
+                    cx.emit(TagInputEvent::Change(self.tags.clone()));
+                    cx.notify();
+                }
+            }
+            InputEvent::PressEnter => {
+                let text = input.read(cx).text();
+                let tag = text.trim();
+                if tag.is_empty() {
+                    return;
+                }
+
+                // Keep the text to edit if the tag is rejected.
+                if self.add_tag(tag.to_string(), cx) {
+                    input.update(cx, |input, cx| input.set_text("", cx));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn backspace(&mut self, _: &Backspace, cx: &mut ViewContext<Self>) {
+        if !self.input.read(cx).text().is_empty() || self.tags.is_empty() {
+            return;
+        }
+
+        cx.stop_propagation();
+        self.remove_tag(self.tags.len() - 1, cx);
+    }
+}
+
+impl Sizable for TagInput {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl FocusableView for TagInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for TagInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.input.focus_handle(cx).is_focused(cx);
+        let placeholder = if self.tags.is_empty() {
+            self.placeholder.clone().unwrap_or_default()
+        } else {
+            SharedString::default()
+        };
+        let (size, disabled) = (self.size, self.disabled);
+        self.input.update(cx, |input, cx| {
+            input.set_placeholder(placeholder);
+            if input.size != size {
+                input.set_size(size, cx);
+            }
+            if input.disabled() != disabled {
+                input.set_disabled(disabled, cx);
+            }
+        });
+
+        h_flex()
+            .id("tag-input")
+            .flex_wrap()
+            .gap_1()
+            .w_full()
+            .input_pl(self.size)
+            .input_pr(self.size)
+            .py_1()
+            .bg(if self.disabled {
+                cx.theme().muted
+            } else {
+                cx.theme().background
+            })
+            .border_1()
+            .border_color(cx.theme().input)
+            .rounded(px(cx.theme().radius))
+            .when(cx.theme().shadow, |this| this.shadow_sm())
+            .when(focused, |this| this.outline(cx))
+            .capture_action(cx.listener(Self::backspace))
+            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, cx| this.focus(cx)))
+            .children(self.tags.iter().enumerate().map(|(ix, tag)| {
+                Tag::new(("tag", ix))
+                    .small()
+                    .child(tag.clone())
+                    .when(!disabled, |this| {
+                        this.on_remove(cx.listener(move |this, _, cx| this.remove_tag(ix, cx)))
+                    })
+            }))
+            .child(div().flex_1().min_w(px(80.)).child(self.input.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_tags;
+
+    #[test]
+    fn test_split_tags() {
+        assert_eq!(split_tags("foo"), (vec![], "foo"));
+        assert_eq!(split_tags("foo,"), (vec!["foo"], ""));
+        assert_eq!(split_tags("foo, bar ,, baz"), (vec!["foo", "bar"], " baz"));
+        assert_eq!(split_tags(","), (vec![], ""));
+    }
+}
@@ -0,0 +1,641 @@
+use std::ops::Range;
+
+use gpui::{
+    fill, point, px, relative, size, AppContext, Bounds, ContentMask, Element, ElementId,
+    ElementInputHandler, EventEmitter, FocusHandle, FocusableView, GlobalElementId, HighlightStyle,
+    IntoElement, LayoutId, MouseButton, MouseMoveEvent, PaintQuad, Pixels, Point, Render,
+    ScrollWheelEvent, SharedString, Style, Subscription, TextRun, View, ViewContext,
+    VisualContext as _, WindowContext, WrappedLine,
+};
+use smallvec::SmallVec;
+
+use super::{InputEvent, TextInput};
+use crate::{theme::ActiveTheme as _, Sizable, Size};
+
+/// A multi-line text input with soft wrapping.
+///
+/// - Press `Enter` to insert a new line, `Cmd/Ctrl+Enter` to submit ([`InputEvent::PressEnter`]).
+/// - The height grows with the content from the min to the max rows, and scrolls beyond the max rows.
+/// - The [`InputEvent`]s of the inner [`TextInput`] are emitted by the TextArea.
+///
+/// ```ignore
+/// cx.new_view(|cx| TextArea::new(cx).rows(3).max_rows(10).placeholder("Enter text here..."))
+/// ```
+pub struct TextArea {
+    input: View<TextInput>,
+    rows: usize,
+    max_rows: usize,
+    soft_wrap: bool,
+    appearance: bool,
+    placeholder: SharedString,
+    size: Size,
+    _subscription: Subscription,
+}
+
+impl TextArea {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| TextInput::new(cx).multi_line());
+        let _subscription = cx.subscribe(&input, |_, _, event: &InputEvent, cx| {
+            cx.emit(event.clone())
+        });
+
+        Self {
+            input,
+            rows: 2,
+            max_rows: 8,
+            soft_wrap: true,
+            appearance: true,
+            placeholder: SharedString::default(),
+            size: Size::Medium,
+            _subscription,
+        }
+    }
+
+    /// Set the min visible rows, default: 2
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Set the max visible rows, it scrolls beyond the max rows, default: 8
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Set false to scroll the long lines horizontally instead of wrapping them, default: true
+    pub fn soft_wrap(mut self, soft_wrap: bool) -> Self {
+        self.soft_wrap = soft_wrap;
+        self
+    }
+
+    /// Set false to render without the border and the background, default: true
+    pub fn appearance(mut self, appearance: bool) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    /// Set the placeholder text of the text area.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Return the text of the text area.
+    pub fn text(&self, cx: &AppContext) -> SharedString {
+        self.input.read(cx).text()
+    }
+
+    /// Set the text of the text area.
+    pub fn set_text(&mut self, text: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        self.input.update(cx, |input, cx| input.set_text(text, cx));
+    }
+
+    pub fn focus(&self, cx: &mut ViewContext<Self>) {
+        self.input.focus_handle(cx).focus(cx);
+    }
+}
+
+impl EventEmitter<InputEvent> for TextArea {}
+
+impl Sizable for TextArea {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl FocusableView for TextArea {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for TextArea {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        self.input.update(cx, |input, _| {
+            input.rows = self.rows;
+            input.max_rows = self.max_rows;
+            input.soft_wrap = self.soft_wrap;
+            input.appearance = self.appearance;
+            input.placeholder = self.placeholder.clone();
+            input.size = self.size;
+        });
+
+        self.input.clone()
+    }
+}
+
+/// Return the position of the offset, relative to the origin of the lines.
+pub(super) fn position_for_offset(
+    lines: &[WrappedLine],
+    offset: usize,
+    line_height: Pixels,
+) -> Point<Pixels> {
+    let mut line_start = 0;
+    let mut y = px(0.);
+    for line in lines {
+        let line_end = line_start + line.len();
+        if offset <= line_end {
+            let position = line
+                .position_for_index(offset - line_start, line_height)
+                .unwrap_or_default();
+            return point(position.x, y + position.y);
+        }
+
+        // Skip the `\n`
+        line_start = line_end + 1;
+        y += line.size(line_height).height;
+    }
+
+    point(px(0.), y)
+}
+
+/// Return the offset closest to the position, the position is relative to the origin of the lines.
+pub(super) fn offset_for_position(
+    lines: &[WrappedLine],
+    position: Point<Pixels>,
+    line_height: Pixels,
+) -> usize {
+    let mut line_start = 0;
+    let mut y = px(0.);
+    for (ix, line) in lines.iter().enumerate() {
+        let height = line.size(line_height).height;
+        if position.y < y + height || ix + 1 == lines.len() {
+            // The visual row of the position in the wrapped line.
+            let row = ((position.y - y).max(px(0.)).min(height - px(1.)) / line_height).floor();
+            let row_y = line_height * row;
+
+            // Find the closest char boundary in the row.
+            let mut closest = (line.len(), px(f32::MAX));
+            for (index, _) in line.text.char_indices().chain([(line.len(), ' ')]) {
+                let Some(pos) = line.position_for_index(index, line_height) else {
+                    continue;
+                };
+                if pos.y != row_y {
+                    continue;
+                }
+                let distance = (pos.x - position.x).abs();
+                if distance < closest.1 {
+                    closest = (index, distance);
+                }
+            }
+
+            return line_start + closest.0;
+        }
+
+        line_start += line.len() + 1;
+        y += height;
+    }
+
+    0
+}
+
+pub(super) fn content_height(lines: &[WrappedLine], line_height: Pixels) -> Pixels {
+    lines.iter().fold(px(0.), |height, line| {
+        height + line.size(line_height).height
+    })
+}
+
+/// Split the text into the runs by the highlights, the ranges of the highlights must be sorted.
+fn highlight_runs(
+    len: usize,
+    base: &TextRun,
+    highlights: &[(Range<usize>, HighlightStyle)],
+) -> Vec<TextRun> {
+    let mut runs = vec![];
+    let mut ix = 0;
+    for (range, style) in highlights {
+        let range = range.start.max(ix)..range.end.min(len);
+        if range.is_empty() {
+            continue;
+        }
+        if range.start > ix {
+            runs.push(TextRun {
+                len: range.start - ix,
+                ..base.clone()
+            });
+        }
+
+        let mut font = base.font.clone();
+        if let Some(weight) = style.font_weight {
+            font.weight = weight;
+        }
+        if let Some(style) = style.font_style {
+            font.style = style;
+        }
+        runs.push(TextRun {
+            len: range.len(),
+            font,
+            color: style.color.unwrap_or(base.color),
+            background_color: style.background_color.or(base.background_color),
+            underline: style.underline.or(base.underline),
+            strikethrough: style.strikethrough.or(base.strikethrough),
+        });
+        ix = range.end;
+    }
+    if ix < len {
+        runs.push(TextRun {
+            len: len - ix,
+            ..base.clone()
+        });
+    }
+
+    runs
+}
+
+const GUTTER_PADDING: Pixels = px(8.);
+const CURSOR_WIDTH: Pixels = px(2.);
+
+pub(super) struct TextAreaElement {
+    pub(super) input: View<TextInput>,
+}
+
+impl TextAreaElement {
+    fn shape_lines(
+        &self,
+        wrap_width: Option<Pixels>,
+        cx: &mut WindowContext,
+    ) -> (SmallVec<[WrappedLine; 1]>, bool) {
+        let highlights = self.input.update(cx, |input, cx| input.highlights(cx));
+        let input = self.input.read(cx);
+        let wrap_width = wrap_width.filter(|_| input.soft_wrap);
+        let style = cx.text_style();
+        let is_placeholder = input.text.is_empty();
+        let (text, color): (SharedString, _) = if is_placeholder {
+            (input.placeholder.clone(), cx.theme().muted_foreground)
+        } else {
+            (input.text.clone(), cx.theme().foreground)
+        };
+
+        let run = TextRun {
+            len: text.len(),
+            font: style.font(),
+            color,
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+        let runs = if is_placeholder || highlights.is_empty() {
+            vec![run]
+        } else {
+            highlight_runs(text.len(), &run, &highlights)
+        };
+        let font_size = style.font_size.to_pixels(cx.rem_size());
+        let lines = cx
+            .text_system()
+            .shape_text(text, font_size, &runs, wrap_width)
+            .unwrap_or_default();
+
+        (lines, is_placeholder)
+    }
+
+    /// Return the width of the gutter to display the line numbers, it's zero if the line number is disabled.
+    fn gutter_width(&self, cx: &mut WindowContext) -> Pixels {
+        let input = self.input.read(cx);
+        if !input.line_number {
+            return px(0.);
+        }
+
+        let digits = input.text.split('\n').count().to_string().len().max(2);
+        let style = cx.text_style();
+        let run = TextRun {
+            len: digits,
+            font: style.font(),
+            color: cx.theme().muted_foreground,
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+        let font_size = style.font_size.to_pixels(cx.rem_size());
+        let width = cx
+            .text_system()
+            .shape_line("0".repeat(digits).into(), font_size, &[run])
+            .map(|line| line.width)
+            .unwrap_or_default();
+
+        width + GUTTER_PADDING * 2.
+    }
+
+    /// Paint the line numbers of the visible lines, the current line is highlighted.
+    fn paint_line_numbers(
+        &self,
+        bounds: Bounds<Pixels>,
+        gutter_width: Pixels,
+        origin_y: Pixels,
+        lines: &[WrappedLine],
+        cx: &mut WindowContext,
+    ) {
+        let line_height = cx.line_height();
+        let style = cx.text_style();
+        let font_size = style.font_size.to_pixels(cx.rem_size());
+        let input = self.input.read(cx);
+        let cursor = input.cursor_offset();
+        let current_line = input.text[..cursor.min(input.text.len())]
+            .matches('\n')
+            .count();
+
+        let mut y = origin_y;
+        for (ix, line) in lines.iter().enumerate() {
+            let height = line.size(line_height).height;
+            if y + height >= bounds.top() && y <= bounds.bottom() {
+                let number: SharedString = (ix + 1).to_string().into();
+                let run = TextRun {
+                    len: number.len(),
+                    font: style.font(),
+                    color: if ix == current_line {
+                        cx.theme().foreground
+                    } else {
+                        cx.theme().muted_foreground
+                    },
+                    background_color: None,
+                    underline: None,
+                    strikethrough: None,
+                };
+                if let Ok(shaped) = cx.text_system().shape_line(number, font_size, &[run]) {
+                    let x = bounds.left() + gutter_width - GUTTER_PADDING - shaped.width;
+                    _ = shaped.paint(point(x, y), line_height, cx);
+                }
+            }
+            y += height;
+        }
+    }
+
+    /// Listen the mouse drag to select text, and the mouse wheel to scroll if the content is overflow.
+    fn paint_mouse_listeners(
+        &mut self,
+        bounds: Bounds<Pixels>,
+        overflow: bool,
+        cx: &mut WindowContext,
+    ) {
+        cx.on_mouse_event({
+            let input = self.input.clone();
+
+            move |event: &MouseMoveEvent, _, cx| {
+                if event.pressed_button == Some(MouseButton::Left) {
+                    input.update(cx, |input, cx| {
+                        input.on_drag_move(event, cx);
+                    });
+                }
+            }
+        });
+
+        if !overflow {
+            return;
+        }
+
+        cx.on_mouse_event({
+            let input = self.input.clone();
+            let line_height = cx.line_height();
+
+            move |event: &ScrollWheelEvent, phase, cx| {
+                if phase.bubble() && bounds.contains(&event.position) {
+                    let delta = event.delta.pixel_delta(line_height);
+                    input.update(cx, |input, cx| {
+                        input.scroll_offset.y += delta.y;
+                        if !input.soft_wrap {
+                            input.scroll_offset.x += delta.x;
+                        }
+                        cx.notify();
+                    });
+                    cx.stop_propagation();
+                }
+            }
+        });
+    }
+}
+
+pub(super) struct TextAreaPrepaintState {
+    lines: SmallVec<[WrappedLine; 1]>,
+    is_placeholder: bool,
+    /// The bounds of the text, excluding the gutter.
+    text_bounds: Bounds<Pixels>,
+    gutter_width: Pixels,
+    scroll_offset: Point<Pixels>,
+    overflow: bool,
+    cursor: Option<PaintQuad>,
+    selections: Vec<PaintQuad>,
+}
+
+impl IntoElement for TextAreaElement {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for TextAreaElement {
+    type RequestLayoutState = ();
+    type PrepaintState = TextAreaPrepaintState;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        // Use the width of the last frame to measure the wrapped lines.
+        let input = self.input.read(cx);
+        let wrap_width = input.last_bounds.map(|bounds| bounds.size.width);
+        let (rows, max_rows) = (input.rows.max(1), input.max_rows.max(input.rows));
+
+        let line_height = cx.line_height();
+        let (lines, _) = self.shape_lines(wrap_width, cx);
+        let visible_rows = (content_height(&lines, line_height) / line_height)
+            .round()
+            .clamp(rows as f32, max_rows as f32);
+
+        let mut style = Style::default();
+        style.size.width = relative(1.).into();
+        style.size.height = (line_height * visible_rows).into();
+        (cx.request_layout(style, []), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        let line_height = cx.line_height();
+        let gutter_width = self.gutter_width(cx);
+        let text_bounds = Bounds::new(
+            bounds.origin + point(gutter_width, px(0.)),
+            size(bounds.size.width - gutter_width, bounds.size.height),
+        );
+        let (lines, is_placeholder) = self.shape_lines(Some(text_bounds.size.width), cx);
+
+        let input = self.input.read(cx);
+        let selected_range = input.selected_range.clone();
+        let cursor = input.cursor_offset();
+        let text_lines: &[WrappedLine] = if is_placeholder { &[] } else { &lines };
+        let cursor_pos = position_for_offset(text_lines, cursor, line_height);
+
+        // Scroll to keep the cursor in view when it is moved, otherwise keep the scroll position of the mouse wheel.
+        let content_height = content_height(&lines, line_height);
+        let content_width = if input.soft_wrap {
+            text_bounds.size.width
+        } else {
+            lines.iter().fold(px(0.), |width, line| {
+                width.max(line.size(line_height).width)
+            }) + CURSOR_WIDTH
+        };
+        let mut scroll_offset = input.scroll_offset;
+        if input.last_cursor != Some(cursor) {
+            if cursor_pos.y + scroll_offset.y < px(0.) {
+                scroll_offset.y = -cursor_pos.y;
+            } else if cursor_pos.y + line_height + scroll_offset.y > bounds.size.height {
+                scroll_offset.y = bounds.size.height - cursor_pos.y - line_height;
+            }
+
+            if cursor_pos.x + scroll_offset.x < px(0.) {
+                scroll_offset.x = -cursor_pos.x;
+            } else if cursor_pos.x + CURSOR_WIDTH + scroll_offset.x > text_bounds.size.width {
+                scroll_offset.x = text_bounds.size.width - cursor_pos.x - CURSOR_WIDTH;
+            }
+        }
+        scroll_offset.y = scroll_offset
+            .y
+            .min(px(0.))
+            .max((bounds.size.height - content_height).min(px(0.)));
+        scroll_offset.x = scroll_offset
+            .x
+            .min(px(0.))
+            .max((text_bounds.size.width - content_width).min(px(0.)));
+
+        let origin = text_bounds.origin + scroll_offset;
+        let mut selections = vec![];
+        let mut cursor = None;
+        if selected_range.is_empty() {
+            if input.show_cursor(cx) {
+                cursor = Some(fill(
+                    Bounds::new(origin + cursor_pos, size(CURSOR_WIDTH, line_height)),
+                    crate::blue_500(),
+                ));
+            }
+        } else {
+            let start = position_for_offset(text_lines, selected_range.start, line_height);
+            let end = position_for_offset(text_lines, selected_range.end, line_height);
+            let width = content_width.max(text_bounds.size.width - scroll_offset.x);
+            let color = cx.theme().selection;
+
+            if start.y == end.y {
+                selections.push(fill(
+                    Bounds::from_corners(origin + start, origin + end + point(px(0.), line_height)),
+                    color,
+                ));
+            } else {
+                selections.push(fill(
+                    Bounds::from_corners(
+                        origin + start,
+                        origin + point(width, start.y + line_height),
+                    ),
+                    color,
+                ));
+                if end.y > start.y + line_height {
+                    selections.push(fill(
+                        Bounds::from_corners(
+                            origin + point(px(0.), start.y + line_height),
+                            origin + point(width, end.y),
+                        ),
+                        color,
+                    ));
+                }
+                selections.push(fill(
+                    Bounds::from_corners(
+                        origin + point(px(0.), end.y),
+                        origin + end + point(px(0.), line_height),
+                    ),
+                    color,
+                ));
+            }
+        }
+
+        TextAreaPrepaintState {
+            lines,
+            is_placeholder,
+            text_bounds,
+            gutter_width,
+            scroll_offset,
+            overflow: content_height > bounds.size.height || content_width > text_bounds.size.width,
+            cursor,
+            selections,
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        prepaint: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let focus_handle = self.input.read(cx).focus_handle.clone();
+        let focused = focus_handle.is_focused(cx);
+        let line_height = cx.line_height();
+        let text_bounds = prepaint.text_bounds;
+        let origin = text_bounds.origin + prepaint.scroll_offset;
+
+        cx.handle_input(
+            &focus_handle,
+            ElementInputHandler::new(bounds, self.input.clone()),
+        );
+
+        if prepaint.gutter_width > px(0.) {
+            cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+                self.paint_line_numbers(
+                    bounds,
+                    prepaint.gutter_width,
+                    origin.y,
+                    &prepaint.lines,
+                    cx,
+                );
+            });
+        }
+
+        cx.with_content_mask(
+            Some(ContentMask {
+                bounds: text_bounds,
+            }),
+            |cx| {
+                for selection in prepaint.selections.drain(..) {
+                    cx.paint_quad(selection);
+                }
+
+                let mut line_origin = origin;
+                for line in prepaint.lines.iter() {
+                    _ = line.paint(line_origin, line_height, cx);
+                    line_origin.y += line.size(line_height).height;
+                }
+
+                if focused {
+                    if let Some(cursor) = prepaint.cursor.take() {
+                        cx.paint_quad(cursor);
+                    }
+                }
+            },
+        );
+
+        let lines = if prepaint.is_placeholder {
+            vec![]
+        } else {
+            prepaint.lines.to_vec()
+        };
+        let scroll_offset = prepaint.scroll_offset;
+        self.input.update(cx, |input, _| {
+            input.scroll_offset = scroll_offset;
+            input.last_cursor = Some(input.cursor_offset());
+            input.last_lines = lines;
+            input.last_line_height = line_height;
+            input.last_bounds = Some(Bounds::new(origin, text_bounds.size));
+        });
+
+        self.paint_mouse_listeners(bounds, prepaint.overflow, cx);
+    }
+}
@@ -0,0 +1,153 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, Action, IntoElement, Keystroke,
+    ParentElement as _, RenderOnce, Styled as _, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, Sizable, Size};
+
+/// A Kbd to display the keyboard shortcut in keycaps, e.g.: `Ctrl` `+` `S`.
+///
+/// ```ignore
+/// Kbd::new(Keystroke::parse("cmd-s").unwrap())
+/// Kbd::binding_for_action(&Save, cx)
+/// ```
+#[derive(IntoElement, Clone)]
+pub struct Kbd {
+    keystrokes: Vec<Keystroke>,
+    appearance: bool,
+    size: Size,
+}
+
+impl Kbd {
+    pub fn new(keystroke: Keystroke) -> Self {
+        Self::from_keystrokes([keystroke])
+    }
+
+    /// Create a Kbd with a sequence of keystrokes, e.g.: `cmd-k cmd-s`.
+    pub fn from_keystrokes(keystrokes: impl IntoIterator<Item = Keystroke>) -> Self {
+        Self {
+            keystrokes: keystrokes.into_iter().collect(),
+            appearance: true,
+            size: Size::default(),
+        }
+    }
+
+    /// Return the Kbd of the first key binding of the action, None if the action has no binding.
+    pub fn binding_for_action(action: &dyn Action, cx: &WindowContext) -> Option<Self> {
+        cx.bindings_for_action(action)
+            .first()
+            .map(|binding| Self::from_keystrokes(binding.keystrokes().iter().cloned()))
+    }
+
+    /// Set false to render as plain text without the keycap border and background, default: true
+    pub fn appearance(mut self, appearance: bool) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    pub fn keystrokes(&self) -> &[Keystroke] {
+        &self.keystrokes
+    }
+}
+
+impl Sizable for Kbd {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl RenderOnce for Kbd {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme();
+
+        h_flex()
+            .flex_none()
+            .gap_1()
+            .text_color(theme.muted_foreground)
+            .line_height(relative(1.))
+            .map(|this| match self.size {
+                Size::Large => this.text_sm(),
+                _ => this.text_xs(),
+            })
+            .children(self.keystrokes.into_iter().map(|key| {
+                div()
+                    .flex_none()
+                    .when(self.appearance, |this| {
+                        this.px_1()
+                            .py_0p5()
+                            .min_w(px(18.))
+                            .flex()
+                            .justify_center()
+                            .rounded(px(theme.radius.min(4.)))
+                            .border_1()
+                            .border_b_2()
+                            .border_color(theme.border)
+                            .bg(theme.secondary)
+                            .text_color(theme.secondary_foreground)
+                    })
+                    .child(key_shortcut(key))
+            }))
+    }
+}
+
+/// Return the Platform specific keybinding string by KeyStroke
+pub fn key_shortcut(key: Keystroke) -> String {
+    if cfg!(target_os = "macos") {
+        return format!("{}", key);
+    }
+
+    let mut parts = vec![];
+    if key.modifiers.control {
+        parts.push("Ctrl");
+    }
+    if key.modifiers.alt {
+        parts.push("Alt");
+    }
+    if key.modifiers.platform {
+        parts.push("Win");
+    }
+    if key.modifiers.shift {
+        parts.push("Shift");
+    }
+
+    // Capitalize the first letter
+    let key = if let Some(first_c) = key.key.chars().next() {
+        format!("{}{}", first_c.to_uppercase(), &key.key[1..])
+    } else {
+        key.key.to_string()
+    };
+
+    parts.push(&key);
+    parts.join("+")
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_key_shortcut() {
+        use super::key_shortcut;
+        use gpui::Keystroke;
+
+        if cfg!(target_os = "windows") {
+            assert_eq!(key_shortcut(Keystroke::parse("a").unwrap()), "A");
+            assert_eq!(key_shortcut(Keystroke::parse("ctrl-a").unwrap()), "Ctrl+A");
+            assert_eq!(
+                key_shortcut(Keystroke::parse("ctrl-alt-a").unwrap()),
+                "Ctrl+Alt+A"
+            );
+            assert_eq!(
+                key_shortcut(Keystroke::parse("ctrl-alt-shift-a").unwrap()),
+                "Ctrl+Alt+Shift+A"
+            );
+            assert_eq!(
+                key_shortcut(Keystroke::parse("ctrl-alt-shift-win-a").unwrap()),
+                "Ctrl+Alt+Win+Shift+A"
+            );
+            assert_eq!(
+                key_shortcut(Keystroke::parse("ctrl-shift-backspace").unwrap()),
+                "Ctrl+Shift+Backspace"
+            );
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use gpui::{
-    div, prelude::FluentBuilder, rems, Div, IntoElement, ParentElement, RenderOnce, SharedString,
-    Styled, WindowContext,
+    div, prelude::FluentBuilder, rems, AnyElement, Div, FontWeight, HighlightStyle, IntoElement,
+    ParentElement, RenderOnce, SharedString, Styled, StyledText, WindowContext,
 };
 
 use crate::{h_flex, theme::ActiveTheme};
@@ -19,6 +19,7 @@ pub struct Label {
     label: SharedString,
     align: TextAlign,
     marked: bool,
+    highlight_positions: Vec<usize>,
 }
 
 impl Label {
@@ -28,6 +29,7 @@ impl Label {
             label: label.into(),
             align: TextAlign::default(),
             marked: false,
+            highlight_positions: vec![],
         }
     }
 
@@ -55,6 +57,12 @@ impl Label {
         self.marked = masked;
         self
     }
+
+    /// Set the byte offsets of the characters to be bold, e.g.: the positions of [`crate::fuzzy::StringMatch`].
+    pub fn highlight_positions(mut self, positions: impl Into<Vec<usize>>) -> Self {
+        self.highlight_positions = positions.into();
+        self
+    }
 }
 
 impl Styled for Label {
@@ -69,10 +77,28 @@ impl RenderOnce for Label {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let text = self.label;
 
-        let text_display = if self.marked {
-            MASKED.repeat(text.chars().count())
+        let text_display: AnyElement = if self.marked {
+            MASKED.repeat(text.chars().count()).into_any_element()
+        } else if !self.highlight_positions.is_empty() {
+            let highlights = self
+                .highlight_positions
+                .iter()
+                .filter_map(|&pos| {
+                    let c = text.get(pos..)?.chars().next()?;
+                    Some((
+                        pos..pos + c.len_utf8(),
+                        HighlightStyle {
+                            font_weight: Some(FontWeight::BOLD),
+                            ..Default::default()
+                        },
+                    ))
+                })
+                .collect::<Vec<_>>();
+            StyledText::new(text)
+                .with_highlights(&cx.text_style(), highlights)
+                .into_any_element()
         } else {
-            text.to_string()
+            text.into_any_element()
         };
 
         div().text_color(cx.theme().foreground).child(
@@ -8,38 +8,62 @@ mod svg_img;
 mod time;
 
 pub mod animation;
+pub mod avatar;
+pub mod badge;
 pub mod button;
 pub mod button_group;
+pub mod card;
+pub mod carousel;
+pub mod chart;
 pub mod checkbox;
 pub mod clipboard;
 pub mod color_picker;
 pub mod context_menu;
 pub mod divider;
+pub mod dnd;
 pub mod dock;
 pub mod drawer;
 pub mod dropdown;
+pub mod editable_label;
+pub mod editor;
+pub mod file;
+pub mod form;
+pub mod fuzzy;
+pub mod highlighter;
 pub mod history;
+pub mod image_viewer;
 pub mod indicator;
 pub mod input;
+pub mod kbd;
 pub mod label;
 pub mod link;
 pub mod list;
+pub mod markdown;
 pub mod modal;
 pub mod notification;
+pub mod pagination;
 pub mod popover;
 pub mod popup_menu;
 pub mod prelude;
 pub mod progress;
 pub mod radio;
+pub mod rating;
 pub mod resizable;
 pub mod scroll;
+pub mod select;
+pub mod settings;
 pub mod skeleton;
 pub mod slider;
+pub mod status_bar;
 pub mod switch;
 pub mod tab;
 pub mod table;
+pub mod tag;
+pub mod text;
 pub mod theme;
+pub mod title_bar;
 pub mod tooltip;
+pub mod tree;
 pub mod webview;
 
 // re-export
@@ -59,16 +83,28 @@ pub use svg_img::*;
 /// Initialize the UI module.
 pub fn init(cx: &mut gpui::AppContext) {
     theme::init(cx);
+    calendar::init(cx);
+    carousel::init(cx);
+    checkbox::init(cx);
     context_menu::init(cx);
     date_picker::init(cx);
+    date_time_picker::init(cx);
     dock::init(cx);
     dropdown::init(cx);
+    editable_label::init(cx);
     input::init(cx);
+    input::autocomplete::init(cx);
+    input::number_input::init(cx);
+    input::otp_input::init(cx);
     list::init(cx);
     modal::init(cx);
     popover::init(cx);
     popup_menu::init(cx);
+    rating::init(cx);
+    slider::init(cx);
     table::init(cx);
+    time_picker::init(cx);
+    tree::init(cx);
     webview::init(cx);
 }
 
@@ -1,32 +1,105 @@
-use std::time::Duration;
+use std::ops::Range;
+use std::time::{Duration, Instant};
 use std::{cell::Cell, rc::Rc};
 
+use super::sections::{ListRow, Sections};
 use crate::{
-    input::{InputEvent, TextInput},
+    button::{Button, ButtonStyled as _},
+    checkbox::Checkbox,
+    context_menu::ContextMenuExt,
+    dnd::{self, AutoScroll, DragItem, DraggableExt as _, DroppableExt as _},
+    fuzzy::{match_strings, StringMatch},
+    h_flex,
+    indicator::Indicator,
+    input::{Backspace, InputEvent, TextInput},
+    popup_menu::PopupMenu,
     scroll::{Scrollbar, ScrollbarState},
     theme::ActiveTheme,
-    v_flex, IconName, Size,
+    v_flex, Icon, IconName, Sizable as _, Size,
 };
 use gpui::{
-    actions, div, prelude::FluentBuilder, uniform_list, AnyElement, AppContext, Entity,
-    FocusHandle, FocusableView, InteractiveElement, IntoElement, KeyBinding, Length,
-    ListSizingBehavior, MouseButton, ParentElement, Render, SharedString, Styled, Task,
-    UniformListScrollHandle, View, ViewContext, VisualContext, WindowContext,
+    actions, div, list, prelude::FluentBuilder, px, uniform_list, AnyElement, AppContext, Axis,
+    ClickEvent, DragMoveEvent, Entity, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement, IntoElement, KeyBinding, KeyDownEvent, Length, ListAlignment, ListOffset,
+    ListSizingBehavior, ListState, Modifiers, MouseButton, MouseDownEvent, ParentElement, Pixels,
+    Render, SharedString, StatefulInteractiveElement, Styled, Task, UniformListScrollHandle, View,
+    ViewContext, VisualContext, WindowContext,
 };
+use rust_i18n::t;
 use smol::Timer;
 
-actions!(list, [Cancel, Confirm, SelectPrev, SelectNext]);
+actions!(
+    list,
+    [
+        Cancel,
+        Confirm,
+        SecondaryConfirm,
+        SelectPrev,
+        SelectNext,
+        ExtendSelectionPrev,
+        ExtendSelectionNext,
+        SelectAll
+    ]
+);
 
 pub fn init(cx: &mut AppContext) {
     let context: Option<&str> = Some("List");
     cx.bind_keys([
         KeyBinding::new("escape", Cancel, context),
         KeyBinding::new("enter", Confirm, context),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-enter", SecondaryConfirm, context),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-enter", SecondaryConfirm, context),
         KeyBinding::new("up", SelectPrev, context),
         KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("shift-up", ExtendSelectionPrev, context),
+        KeyBinding::new("shift-down", ExtendSelectionNext, context),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-a", SelectAll, context),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-a", SelectAll, context),
     ]);
 }
 
+pub enum ListEvent {
+    /// Emitted when the selected indices are changed in the multi-select mode.
+    SelectionChanged(Vec<usize>),
+    /// Emitted when an item is moved by dragging in the reorderable list.
+    ItemMoved { from: usize, to: usize },
+}
+
+/// The position to scroll an item to, see [`List::scroll_to`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollStrategy {
+    /// Scroll the item to the top of the list.
+    Top,
+    /// Scroll the item to the center of the list.
+    Center,
+    /// Scroll the least distance to make the item visible, do nothing if it is already visible.
+    #[default]
+    Visible,
+}
+
+/// The number of rows to the end of the list to start loading more items.
+const LOAD_MORE_THRESHOLD: usize = 5;
+
+/// The timeout to reset the typed prefix of the type-to-select.
+const TYPE_TO_SELECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Return the new index of the item at `ix` after moving the item from `from` to `to`.
+fn moved_index(ix: usize, from: usize, to: usize) -> usize {
+    if ix == from {
+        to
+    } else if from < ix && ix <= to {
+        ix - 1
+    } else if to <= ix && ix < from {
+        ix + 1
+    } else {
+        ix
+    }
+}
+
 /// A delegate for the List.
 #[allow(unused)]
 pub trait ListDelegate: Sized + 'static {
@@ -34,10 +107,44 @@ pub trait ListDelegate: Sized + 'static {
 
     /// When Query Input change, this method will be called.
     /// You can perform search here.
+    ///
+    /// The search can be asynchronous, e.g.: query a database, the list shows the loading indicator until the task is done.
+    /// The task will be dropped to cancel the search when a new query arrives,
+    /// so apply the results in the task instead of detaching it.
+    ///
+    /// Default to fuzzy match the query in the [`ListDelegate::match_candidates`] in the background,
+    /// and then call [`ListDelegate::set_matches`] with the matches sorted by the score.
     fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
-        Task::Ready(Some(()))
+        let candidates = self.match_candidates();
+        if candidates.is_empty() {
+            return Task::Ready(Some(()));
+        }
+
+        let query = query.to_string();
+        let matches = cx
+            .background_executor()
+            .spawn(async move { match_strings(&candidates, &query) });
+        cx.spawn(|this, mut cx| async move {
+            let matches = matches.await;
+            _ = this.update(&mut cx, |list, cx| {
+                list.delegate_mut().set_matches(matches, cx);
+                cx.notify();
+            });
+        })
+    }
+
+    /// Return the texts of all items to match by the default [`ListDelegate::perform_search`],
+    /// the index of the text is the `candidate_id` of the [`StringMatch`].
+    fn match_candidates(&self) -> Vec<SharedString> {
+        vec![]
     }
 
+    /// Set the matched candidates of the default [`ListDelegate::perform_search`], sorted by the score.
+    ///
+    /// The delegate should show the matched items in order,
+    /// the `positions` can be used to highlight the matched characters, see [`crate::label::Label::highlight_positions`].
+    fn set_matches(&mut self, matches: Vec<StringMatch>, cx: &mut ViewContext<List<Self>>) {}
+
     /// Return the number of items in the list.
     fn items_count(&self) -> usize;
 
@@ -46,9 +153,133 @@ pub trait ListDelegate: Sized + 'static {
     /// Return None will skip the item.
     fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item>;
 
+    /// Return false if the item at the given index can't be selected, e.g.: a group header or a disabled item.
+    ///
+    /// The keyboard navigation will skip the item, and clicking it does nothing.
+    fn can_select(&self, ix: usize) -> bool {
+        true
+    }
+
+    /// Return the number of sections to group the items, default is 0 that means the list is not grouped.
+    ///
+    /// The items are grouped in order, e.g.: the items of the first section are `0..section_items_count(0)`.
+    fn sections_count(&self) -> usize {
+        0
+    }
+
+    /// Return the number of items in the section.
+    fn section_items_count(&self, section: usize) -> usize {
+        0
+    }
+
+    /// Render the header of the section, the header sticks to the top of the list while its items are scrolling.
+    ///
+    /// The header should have a background to cover the items under it.
+    /// With the uniform list (the default), the header should have the same height as the items.
+    fn render_section_header(
+        &self,
+        section: usize,
+        cx: &mut ViewContext<List<Self>>,
+    ) -> Option<AnyElement> {
+        None
+    }
+
+    /// Return true if there are more items to load, see [`ListDelegate::load_more`].
+    fn has_more(&self) -> bool {
+        false
+    }
+
+    /// Load the next page of the items when the list is scrolled near the end and [`ListDelegate::has_more`] is true.
+    ///
+    /// The loaded items should be appended to the end, a loading row is shown until the task is done.
+    fn load_more(&mut self, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+        Task::Ready(Some(()))
+    }
+
+    /// Return the text of the item, used to select the item by typing the prefix when the list is focused.
+    fn item_text(&self, ix: usize) -> Option<SharedString> {
+        None
+    }
+
+    /// Return true if the item has a context menu, see [`ListDelegate::context_menu`].
+    fn has_context_menu(&self, ix: usize) -> bool {
+        false
+    }
+
+    /// Build the context menu of the item, it is opened at the cursor by right clicking the item,
+    /// and the item will be selected.
+    fn context_menu(
+        &self,
+        ix: usize,
+        menu: PopupMenu,
+        cx: &mut ViewContext<List<Self>>,
+    ) -> PopupMenu {
+        menu
+    }
+
+    /// Return the error message if the last search or loading is failed, the error state will be shown if the list is empty.
+    fn error(&self) -> Option<SharedString> {
+        None
+    }
+
     /// Return a Element to show when list is empty.
     fn render_empty(&self, cx: &mut ViewContext<List<Self>>) -> impl IntoElement {
-        div()
+        v_flex()
+            .items_center()
+            .justify_center()
+            .gap_2()
+            .py_6()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child(
+                Icon::new(IconName::Inbox)
+                    .size(px(28.))
+                    .text_color(cx.theme().muted_foreground.opacity(0.6)),
+            )
+            .child(t!("List.empty").to_string())
+    }
+
+    /// Return a Element to show when the list is loading and empty.
+    fn render_loading(&self, cx: &mut ViewContext<List<Self>>) -> impl IntoElement {
+        h_flex()
+            .justify_center()
+            .gap_2()
+            .py_6()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child(Indicator::new().small())
+            .child(t!("List.loading").to_string())
+    }
+
+    /// Return a Element to show the [`ListDelegate::error`] when the list is empty.
+    ///
+    /// Default shows the error message with a retry button to perform the search again.
+    fn render_error(
+        &self,
+        error: SharedString,
+        cx: &mut ViewContext<List<Self>>,
+    ) -> impl IntoElement {
+        v_flex()
+            .items_center()
+            .justify_center()
+            .gap_2()
+            .py_6()
+            .px_4()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child(
+                Icon::new(IconName::TriangleAlert)
+                    .size(px(28.))
+                    .text_color(cx.theme().destructive),
+            )
+            .child(div().text_center().child(error))
+            .child(
+                Button::new("retry")
+                    .label(t!("List.retry"))
+                    .small()
+                    .outline()
+                    .on_click(cx.listener(|list, _, cx| list.retry(cx))),
+            )
     }
 
     /// Returns Some(AnyElement) to render the initial state of the list.
@@ -70,11 +301,53 @@ pub trait ListDelegate: Sized + 'static {
     /// Set the selected index, just store the ix, don't confirm.
     fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>);
 
+    /// Return true to enable the multi-select mode.
+    ///
+    /// - Shift+Click or Shift+Up/Down to select a range.
+    /// - Cmd+Click (Ctrl+Click on Windows, Linux) to toggle an item.
+    /// - Cmd+A (Ctrl+A on Windows, Linux) to select all items.
+    fn multi_select(&self) -> bool {
+        false
+    }
+
+    /// Set the selected indices in the multi-select mode, the indices are sorted.
+    fn set_selected_indices(&mut self, indices: &[usize], cx: &mut ViewContext<List<Self>>) {}
+
+    /// Move the item from the `from` index to the `to` index in the reorderable list,
+    /// the `to` is the index after the item is moved.
+    fn move_item(&mut self, from: usize, to: usize, cx: &mut ViewContext<List<Self>>) {}
+
     /// Set the confirm and give the selected index, this is means user have clicked the item or pressed Enter.
     fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {}
 
+    /// Set the confirm with the secondary action, e.g.: Pressed Cmd+Enter (Ctrl+Enter on Windows, Linux)
+    /// to open the selected item in a split.
+    fn secondary_confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {}
+
     /// Cancel the selection, e.g.: Pressed ESC.
     fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {}
+
+    /// Returns Some(AnyElement) to render a footer at the bottom of the list.
+    ///
+    /// This can be used to show the action hints or buttons,
+    /// e.g.: "Enter to select · Cmd+Enter to open in split".
+    fn render_footer(&self, cx: &mut ViewContext<List<Self>>) -> Option<AnyElement> {
+        None
+    }
+
+    /// Return the prior choices of a multi-step list, they will be displayed as a breadcrumb before the query input.
+    ///
+    /// For example, pick a repo, and then pick a branch: `["gpui-component"]`.
+    fn breadcrumbs(&self) -> Vec<SharedString> {
+        vec![]
+    }
+
+    /// Go back to the previous step of a multi-step list, this is called when pressed Backspace with an empty query.
+    ///
+    /// Return true if went back, then the query will be reset.
+    fn back(&mut self, cx: &mut ViewContext<List<Self>>) -> bool {
+        false
+    }
 }
 
 pub struct List<D: ListDelegate> {
@@ -83,14 +356,38 @@ pub struct List<D: ListDelegate> {
     max_height: Option<Length>,
     query_input: Option<View<TextInput>>,
     last_query: Option<String>,
+    /// The query of the in-flight search.
+    searching_query: Option<String>,
     loading: bool,
 
     enable_scrollbar: bool,
     vertical_scroll_handle: UniformListScrollHandle,
     scrollbar_state: Rc<Cell<ScrollbarState>>,
+    /// The state of the variable height list, `None` to use the uniform list.
+    list_state: Option<ListState>,
+    sections: Sections,
 
     pub(crate) size: Size,
     selected_index: Option<usize>,
+    /// The selected indices in the multi-select mode, sorted.
+    selected_indices: Vec<usize>,
+    /// The start of the range selection.
+    selection_anchor: Option<usize>,
+    checkbox_selection: bool,
+    /// The items count of the last sync, the selectable items are counted again when it changes.
+    items_count: usize,
+    /// The count of the selectable items, for the state of the "Select all" checkbox.
+    selectable_count: usize,
+
+    reorderable: bool,
+    /// The index to insert the dragging item, from 0 to items count.
+    drop_target: Option<usize>,
+    auto_scroll: AutoScroll,
+    loading_more: bool,
+    _load_more_task: Task<()>,
+    /// The typed prefix and the time of the last typing for the type-to-select.
+    typed_prefix: String,
+    last_typed_at: Option<Instant>,
     _search_task: Task<()>,
 }
 
@@ -115,11 +412,26 @@ where
             delegate,
             query_input: Some(query_input),
             last_query: None,
+            searching_query: None,
             selected_index: None,
+            selected_indices: vec![],
+            selection_anchor: None,
+            checkbox_selection: false,
+            items_count: 0,
+            selectable_count: 0,
+            reorderable: false,
+            drop_target: None,
+            auto_scroll: AutoScroll::new(),
+            loading_more: false,
+            _load_more_task: Task::Ready(None),
+            typed_prefix: String::new(),
+            last_typed_at: None,
             vertical_scroll_handle: UniformListScrollHandle::new(),
             scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
             max_height: None,
             enable_scrollbar: true,
+            list_state: None,
+            sections: Sections::default(),
             loading: false,
             size: Size::default(),
             _search_task: Task::Ready(None),
@@ -141,6 +453,44 @@ where
         self
     }
 
+    /// Measure the height of each item to support the items with different heights,
+    /// default all items have the same height as the first item.
+    ///
+    /// The `overscan` is the extra height to render the items outside the viewport,
+    /// to avoid the blank area when scrolling fast.
+    ///
+    /// The scrollbar is not supported for the variable height list.
+    ///
+    /// The appended items are measured when rendering, call [`List::splice_items`] after changing the items in place.
+    pub fn variable_height(mut self, overscan: Pixels, cx: &mut ViewContext<Self>) -> Self {
+        let view = cx.view().downgrade();
+        self.list_state = Some(ListState::new(
+            self.rows_count(),
+            ListAlignment::Top,
+            overscan,
+            move |ix, cx| {
+                view.upgrade()
+                    .map(|view| view.update(cx, |list, cx| list.render_row(ix, cx)))
+                    .unwrap_or_else(|| div().into_any_element())
+            },
+        ));
+        self
+    }
+
+    /// Allow to reorder the items by dragging, the [`ListDelegate::move_item`] will be called on drop.
+    pub fn reorderable(mut self) -> Self {
+        self.reorderable = true;
+        self
+    }
+
+    /// Render a checkbox before each item to select multiple items, and a "Select all" checkbox above the items.
+    ///
+    /// The checked items are the [`List::selected_indices`], it works without [`ListDelegate::multi_select`].
+    pub fn selectable_with_checkbox(mut self, selectable: bool) -> Self {
+        self.checkbox_selection = selectable;
+        self
+    }
+
     pub fn no_scrollbar(mut self) -> Self {
         self.enable_scrollbar = false;
         self
@@ -169,15 +519,185 @@ where
         self.focus_handle(cx).focus(cx);
     }
 
+    /// Set the selected index and scroll it into view.
     pub fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<Self>) {
         self.selected_index = ix;
         self.delegate.set_selected_index(ix, cx);
+        self.scroll_to_selected_item(cx);
     }
 
     pub fn selected_index(&self) -> Option<usize> {
         self.selected_index
     }
 
+    /// Return the selected indices in the multi-select mode.
+    pub fn selected_indices(&self) -> &[usize] {
+        &self.selected_indices
+    }
+
+    /// Set the selected indices in the multi-select mode, the unselectable items are ignored.
+    pub fn set_selected_indices(&mut self, indices: Vec<usize>, cx: &mut ViewContext<Self>) {
+        let items_count = self.delegate.items_count();
+        let mut indices = indices
+            .into_iter()
+            .filter(|ix| *ix < items_count && self.delegate.can_select(*ix))
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        indices.dedup();
+
+        if indices == self.selected_indices {
+            return;
+        }
+
+        self.selected_indices = indices;
+        self.delegate
+            .set_selected_indices(&self.selected_indices, cx);
+        cx.emit(ListEvent::SelectionChanged(self.selected_indices.clone()));
+        cx.notify();
+    }
+
+    /// Select the selectable items between the anchor and the `ix` (inclusive).
+    fn select_range_to(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let anchor = *self.selection_anchor.get_or_insert(ix);
+        let range = anchor.min(ix)..=anchor.max(ix);
+        self.set_selected_indices(range.collect(), cx);
+    }
+
+    /// Set the cursor to the `ix` and select only it, it also becomes the anchor of the range selection.
+    ///
+    /// In the checkbox selection mode, only the cursor is moved, the checked items are kept.
+    fn select_only(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.selected_index = Some(ix);
+        if self.delegate.multi_select() && !self.checkbox_selection {
+            self.selection_anchor = Some(ix);
+            self.set_selected_indices(vec![ix], cx);
+        }
+    }
+
+    fn is_multi_select(&self) -> bool {
+        self.checkbox_selection || self.delegate.multi_select()
+    }
+
+    /// Toggle the item in the selected indices, and move the cursor to it.
+    fn toggle_selected(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.selected_index = Some(ix);
+        self.selection_anchor = Some(ix);
+        let mut indices = self.selected_indices.clone();
+        match indices.binary_search(&ix) {
+            Ok(pos) => {
+                indices.remove(pos);
+            }
+            Err(pos) => indices.insert(pos, ix),
+        }
+        self.set_selected_indices(indices, cx);
+    }
+
+    fn on_item_click(&mut self, ix: usize, modifiers: &Modifiers, cx: &mut ViewContext<Self>) {
+        cx.stop_propagation();
+        if !self.delegate.can_select(ix) {
+            return;
+        }
+
+        if self.is_multi_select() {
+            if modifiers.shift {
+                self.selected_index = Some(ix);
+                self.select_range_to(ix, cx);
+                return;
+            }
+            if is_toggle_modifier(modifiers) {
+                self.toggle_selected(ix, cx);
+                return;
+            }
+        }
+
+        self.select_only(ix, cx);
+        self.on_action_confirm(&Confirm, cx);
+    }
+
+    /// Update the drop target by the position of the dragging item over the item at `ix`.
+    fn on_item_drag_move(
+        &mut self,
+        ix: usize,
+        event: &DragMoveEvent<DragItem<usize>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if !event.drag(cx).is_from(cx.view().entity_id())
+            || !event.bounds.contains(&event.event.position)
+        {
+            return;
+        }
+
+        let drop_target = if event.event.position.y < event.bounds.center().y {
+            ix
+        } else {
+            ix + 1
+        };
+        if self.drop_target != Some(drop_target) {
+            self.drop_target = Some(drop_target);
+            cx.notify();
+        }
+    }
+
+    /// Scroll the list while dragging near the top or bottom edges.
+    fn on_drag_move_list(
+        &mut self,
+        event: &DragMoveEvent<DragItem<usize>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if !event.drag(cx).is_from(cx.view().entity_id()) {
+            return;
+        }
+
+        self.auto_scroll.update(
+            Axis::Vertical,
+            event.event.position,
+            event.bounds,
+            cx,
+            |this, distance, _| this.scroll_by(distance),
+        );
+    }
+
+    /// Scroll the list by the distance, positive to scroll down.
+    fn scroll_by(&self, distance: Pixels) {
+        match &self.list_state {
+            Some(list_state) => list_state.scroll_by(distance),
+            None => {
+                let state = self.vertical_scroll_handle.0.borrow();
+                dnd::scroll_by(&state.base_handle, Axis::Vertical, distance);
+            }
+        }
+    }
+
+    fn on_drop_item(&mut self, drag: &DragItem<usize>, cx: &mut ViewContext<Self>) {
+        self.auto_scroll.stop();
+        let Some(drop_target) = self.drop_target.take() else {
+            return;
+        };
+
+        let from = drag.value;
+        let to = if drop_target > from {
+            drop_target - 1
+        } else {
+            drop_target
+        };
+        if from == to || to >= self.delegate.items_count() {
+            cx.notify();
+            return;
+        }
+
+        self.delegate.move_item(from, to, cx);
+        self.selected_index = self.selected_index.map(|ix| moved_index(ix, from, to));
+        self.selection_anchor = self.selection_anchor.map(|ix| moved_index(ix, from, to));
+        let selected_indices = self
+            .selected_indices
+            .iter()
+            .map(|ix| moved_index(*ix, from, to))
+            .collect();
+        self.set_selected_indices(selected_indices, cx);
+        cx.emit(ListEvent::ItemMoved { from, to });
+        cx.notify();
+    }
+
     /// Set the query_input text
     pub fn set_query(&mut self, query: &str, cx: &mut ViewContext<Self>) {
         if let Some(query_input) = &self.query_input {
@@ -186,13 +706,64 @@ where
         }
     }
 
+    /// Clear the query and perform the search again.
+    ///
+    /// This is useful for multi-step list to reset the list when the step changed,
+    /// e.g. call it by `cx.defer(|list, cx| list.reset_query(cx))` in [`ListDelegate::confirm`].
+    pub fn reset_query(&mut self, cx: &mut ViewContext<Self>) {
+        self.last_query = None;
+        self.searching_query = None;
+        self.selected_index = None;
+        if let Some(query_input) = &self.query_input {
+            query_input.update(cx, |input, cx| input.set_text("", cx))
+        }
+        self.perform_search("".into(), cx);
+    }
+
+    /// Perform the search again with the current query, e.g.: after the search is failed.
+    pub fn retry(&mut self, cx: &mut ViewContext<Self>) {
+        let query = self
+            .query_input
+            .as_ref()
+            .map(|input| input.read(cx).text().trim().to_string())
+            .unwrap_or_default();
+        self.perform_search(query, cx);
+    }
+
+    /// Notify the list that the items in the `old_range` are replaced by `count` new items,
+    /// the variable height list remeasures the changed items only.
+    ///
+    /// The list is totally remeasured if there are multiple sections, because the rows of the headers may be moved.
+    pub fn splice_items(
+        &mut self,
+        old_range: Range<usize>,
+        count: usize,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match self.list_state.clone() {
+            Some(list_state) if self.delegate.sections_count() <= 1 => {
+                // The rows of the old items, before the sections are updated.
+                let start = self.sections.item_row(old_range.start);
+                list_state.splice(start..start + old_range.len(), count);
+                self.sync_items_count();
+            }
+            Some(list_state) => {
+                self.sync_items_count();
+                reset_list_state(&list_state, self.rows_count());
+            }
+            None => self.sync_items_count(),
+        }
+        self.count_selectable_items();
+        cx.notify();
+    }
+
     /// Get the query_input text
     pub fn query(&self, cx: &mut ViewContext<Self>) -> Option<SharedString> {
         self.query_input.as_ref().map(|input| input.read(cx).text())
     }
 
     fn render_scrollbar(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
-        if !self.enable_scrollbar {
+        if !self.enable_scrollbar || self.list_state.is_some() {
             return None;
         }
 
@@ -203,12 +774,103 @@ where
         ))
     }
 
-    fn scroll_to_selected_item(&mut self, _cx: &mut ViewContext<Self>) {
+    /// Scroll to the item at the `ix` by the strategy.
+    ///
+    /// The [`ScrollStrategy::Center`] is same as [`ScrollStrategy::Visible`] for the variable height list,
+    /// because the heights of the items above are unknown before they are rendered.
+    pub fn scroll_to(&mut self, ix: usize, strategy: ScrollStrategy, cx: &mut ViewContext<Self>) {
+        let row = self.sections.item_row(ix);
+        match &self.list_state {
+            Some(list_state) => match strategy {
+                ScrollStrategy::Top => list_state.scroll_to(ListOffset {
+                    item_ix: row,
+                    offset_in_item: px(0.),
+                }),
+                ScrollStrategy::Center | ScrollStrategy::Visible => {
+                    list_state.scroll_to_reveal_item(row)
+                }
+            },
+            None => {
+                let state = self.vertical_scroll_handle.0.borrow();
+                let item_size = match (strategy, state.last_item_size) {
+                    (ScrollStrategy::Top | ScrollStrategy::Center, Some(item_size)) => item_size,
+                    _ => {
+                        drop(state);
+                        self.vertical_scroll_handle.scroll_to_item(row);
+                        cx.notify();
+                        return;
+                    }
+                };
+
+                let viewport_height = state.base_handle.bounds().size.height;
+                let row_height = item_size.item.height;
+                let top = match strategy {
+                    ScrollStrategy::Center => {
+                        row_height * row as f32 - (viewport_height - row_height) / 2.
+                    }
+                    _ => row_height * row as f32,
+                };
+                let max_top = (item_size.contents.height - viewport_height).max(px(0.));
+
+                let mut offset = state.base_handle.offset();
+                offset.y = -top.min(max_top).max(px(0.));
+                state.base_handle.set_offset(offset);
+            }
+        }
+        cx.notify();
+    }
+
+    fn scroll_to_selected_item(&mut self, cx: &mut ViewContext<Self>) {
         if let Some(ix) = self.selected_index {
-            self.vertical_scroll_handle.scroll_to_item(ix);
+            self.scroll_to(ix, ScrollStrategy::Visible, cx);
         }
     }
 
+    /// Sync the item count of the variable height list.
+    ///
+    /// The new items are assumed to be appended at the end. When the items are removed,
+    /// the list is remeasured and the scroll position is kept at the same top row,
+    /// use [`List::splice_items`] to remeasure the changed items only.
+    fn sync_items_count(&mut self) {
+        let items_count = self.delegate.items_count();
+        if self.selected_index.is_some_and(|ix| ix >= items_count) {
+            self.selected_index = None;
+        }
+        self.selected_indices.retain(|ix| *ix < items_count);
+        if items_count != self.items_count {
+            self.items_count = items_count;
+            self.count_selectable_items();
+        }
+        self.sections = Sections::new(
+            (0..self.delegate.sections_count())
+                .map(|section| self.delegate.section_items_count(section)),
+        );
+
+        let Some(list_state) = &self.list_state else {
+            return;
+        };
+        let rows_count = self.rows_count();
+        let old_count = list_state.item_count();
+        if rows_count > old_count {
+            // The more items are loaded at the end.
+            list_state.splice(old_count..old_count, rows_count - old_count);
+        } else if rows_count < old_count {
+            // The items may be removed from anywhere, remeasure all of them.
+            reset_list_state(list_state, rows_count);
+        }
+    }
+
+    /// Count the selectable items, call it when the items are changed.
+    fn count_selectable_items(&mut self) {
+        if !self.checkbox_selection {
+            return;
+        }
+
+        self.selectable_count = (0..self.delegate.items_count())
+            .filter(|ix| self.delegate.can_select(*ix))
+            .count();
+    }
+
     fn on_query_input_event(
         &mut self,
         _: View<TextInput>,
@@ -218,33 +880,53 @@ where
         match event {
             InputEvent::Change(text) => {
                 let text = text.trim().to_string();
-                if Some(&text) == self.last_query.as_ref() {
+                // Search again for the last query if there is another search in-flight, to cancel it.
+                if Some(&text) == self.searching_query.as_ref().or(self.last_query.as_ref()) {
                     return;
                 }
 
-                self.set_loading(true, cx);
-                let search = self.delegate.perform_search(&text, cx);
-
-                self._search_task = cx.spawn(|this, mut cx| async move {
-                    search.await;
-
-                    let _ = this.update(&mut cx, |this, _| {
-                        this.vertical_scroll_handle.scroll_to_item(0);
-                        this.last_query = Some(text);
-                    });
-
-                    // Always wait 100ms to avoid flicker
-                    Timer::after(Duration::from_millis(100)).await;
-                    let _ = this.update(&mut cx, |this, cx| {
-                        this.set_loading(false, cx);
-                    });
-                });
+                self.perform_search(text, cx);
             }
             InputEvent::PressEnter => self.on_action_confirm(&Confirm, cx),
             _ => {}
         }
     }
 
+    /// Perform the search, the previous in-flight search will be canceled.
+    fn perform_search(&mut self, text: String, cx: &mut ViewContext<Self>) {
+        self.set_loading(true, cx);
+        self.searching_query = Some(text.clone());
+        // Drop the previous search task to cancel it before starting the new one.
+        self._search_task = Task::Ready(None);
+        let search = self.delegate.perform_search(&text, cx);
+
+        self._search_task = cx.spawn(|this, mut cx| async move {
+            search.await;
+
+            let _ = this.update(&mut cx, |this, cx| {
+                this.searching_query = None;
+                this.selection_anchor = None;
+                this.set_selected_indices(vec![], cx);
+                this.count_selectable_items();
+                // The loading page is outdated for the new query.
+                this.loading_more = false;
+                this._load_more_task = Task::Ready(None);
+                match &this.list_state {
+                    // The items are totally changed, remeasure all of them.
+                    Some(list_state) => list_state.reset(this.rows_count()),
+                    None => this.vertical_scroll_handle.scroll_to_item(0),
+                }
+                this.last_query = Some(text);
+            });
+
+            // Always wait 100ms to avoid flicker
+            Timer::after(Duration::from_millis(100)).await;
+            let _ = this.update(&mut cx, |this, cx| {
+                this.set_loading(false, cx);
+            });
+        });
+    }
+
     fn set_loading(&mut self, loading: bool, cx: &mut ViewContext<Self>) {
         self.loading = loading;
         if let Some(input) = &self.query_input {
@@ -263,47 +945,510 @@ where
         if self.delegate.items_count() == 0 {
             return;
         }
+        // The disabled item can not be confirmed by the keyboard.
+        if let Some(ix) = self.selected_index {
+            if !self.delegate.can_select(ix) {
+                return;
+            }
+        }
 
         self.delegate.confirm(self.selected_index, cx);
         cx.notify();
     }
 
+    fn on_action_secondary_confirm(&mut self, _: &SecondaryConfirm, cx: &mut ViewContext<Self>) {
+        if self.delegate.items_count() == 0 {
+            return;
+        }
+        if let Some(ix) = self.selected_index {
+            if !self.delegate.can_select(ix) {
+                return;
+            }
+        }
+
+        self.delegate.secondary_confirm(self.selected_index, cx);
+        cx.notify();
+    }
+
+    fn on_action_backspace(&mut self, _: &Backspace, cx: &mut ViewContext<Self>) {
+        let Some(query_input) = &self.query_input else {
+            return;
+        };
+
+        // Only go back when the query is empty, otherwise let the input to handle it.
+        if !query_input.read(cx).text().is_empty() {
+            return;
+        }
+
+        if self.delegate.back(cx) {
+            cx.stop_propagation();
+            self.reset_query(cx);
+        }
+    }
+
+    fn render_breadcrumbs(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let breadcrumbs = self.delegate.breadcrumbs();
+        if breadcrumbs.is_empty() {
+            return None;
+        }
+
+        Some(
+            h_flex()
+                .flex_shrink_0()
+                .gap_1()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .children(breadcrumbs.into_iter().map(|label| {
+                    h_flex()
+                        .gap_1()
+                        .child(label)
+                        .child(Icon::new(IconName::ChevronRight).xsmall())
+                })),
+        )
+    }
+
+    /// Find the next selectable index from the `ix` (exclusive) in the direction, wrapped around.
+    fn next_selectable_index(&self, ix: Option<usize>, forward: bool) -> Option<usize> {
+        let count = self.delegate.items_count();
+        if count == 0 {
+            return None;
+        }
+
+        let mut next = ix;
+        for _ in 0..count {
+            let ix = match (next, forward) {
+                (None, true) => 0,
+                (None, false) => count - 1,
+                (Some(ix), true) => (ix + 1) % count,
+                (Some(ix), false) => (ix + count - 1) % count,
+            };
+            if self.delegate.can_select(ix) {
+                return Some(ix);
+            }
+            next = Some(ix);
+        }
+
+        None
+    }
+
     fn on_action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
         if self.delegate.items_count() == 0 {
             return;
         }
 
-        let selected_index = self.selected_index.unwrap_or(0);
-        if selected_index > 0 {
-            self.selected_index = Some(selected_index - 1);
-        } else {
-            self.selected_index = Some(self.delegate.items_count() - 1);
+        let selected_index = self.selected_index.or(Some(0));
+        if let Some(ix) = self.next_selectable_index(selected_index, false) {
+            self.select_only(ix, cx);
         }
 
         self.scroll_to_selected_item(cx);
         cx.notify();
     }
 
+    fn render_list_item(&mut self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let checkbox = (self.checkbox_selection && self.delegate.can_select(ix)).then(|| {
+            let view = cx.view().downgrade();
+            div()
+                .flex_shrink_0()
+                .pl_2()
+                // Avoid to confirm the item by clicking the checkbox.
+                .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                .child(
+                    Checkbox::new(("list-item-checkbox", ix))
+                        .checked(self.selected_indices.binary_search(&ix).is_ok())
+                        .on_change(move |_, cx| {
+                            _ = view.update(cx, |list, cx| list.toggle_selected(ix, cx));
+                        }),
+                )
+        });
+
+        h_flex()
+            .id("list-item")
+            .w_full()
+            .when(self.checkbox_selection, |this| {
+                // Keep the items aligned with the checkbox placeholder.
+                this.child(
+                    div()
+                        .flex_shrink_0()
+                        .children(checkbox)
+                        .when(!self.delegate.can_select(ix), |this| {
+                            this.pl_2().w(px(16.) + px(8.))
+                        }),
+                )
+            })
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .children(self.delegate.render_item(ix, cx)),
+            )
+            .when(self.delegate.has_context_menu(ix), |this| {
+                let view = cx.view().downgrade();
+                this.relative().context_menu(move |menu, cx| {
+                    let Some(view) = view.upgrade() else {
+                        return menu;
+                    };
+                    view.update(cx, |list, cx| {
+                        if list.delegate.can_select(ix) {
+                            list.select_only(ix, cx);
+                            cx.notify();
+                        }
+                        list.delegate.context_menu(ix, menu, cx)
+                    })
+                })
+            })
+            .when(
+                self.selected_index == Some(ix) || self.selected_indices.binary_search(&ix).is_ok(),
+                |this| this.bg(cx.theme().list_active),
+            )
+            .map(|this| {
+                if !self.reorderable {
+                    return this.on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |this, event: &MouseDownEvent, cx| {
+                            this.on_item_click(ix, &event.modifiers, cx)
+                        }),
+                    );
+                }
+
+                // Confirm on click, to allow dragging without confirming.
+                let view = cx.view().downgrade();
+                let is_last = ix + 1 == self.delegate.items_count();
+                this.on_click(cx.listener(move |this, event: &ClickEvent, cx| {
+                    this.on_item_click(ix, &event.down.modifiers, cx)
+                }))
+                .on_drag_item(DragItem::new(cx.view().entity_id(), ix), move |ix, cx| {
+                    let item = view.upgrade().and_then(|view| {
+                        view.update(cx, |list, cx| list.delegate.render_item(*ix, cx))
+                    });
+                    div().w_64().children(item).into_any_element()
+                })
+                .on_drag_move(cx.listener(
+                    move |this, event: &DragMoveEvent<DragItem<usize>>, cx| {
+                        this.on_item_drag_move(ix, event, cx)
+                    },
+                ))
+                .when(self.drop_target == Some(ix), |this| {
+                    this.border_t_2().border_color(cx.theme().drag_border)
+                })
+                .when(is_last && self.drop_target == Some(ix + 1), |this| {
+                    this.border_b_2().border_color(cx.theme().drag_border)
+                })
+            })
+    }
+
+    /// Return the number of rows, including the section headers and the loading row.
+    fn rows_count(&self) -> usize {
+        self.sections.rows_count(self.delegate.items_count()) + self.loading_more as usize
+    }
+
+    fn should_load_more(&self, row: usize) -> bool {
+        !self.loading_more
+            && row + LOAD_MORE_THRESHOLD >= self.rows_count()
+            && self.delegate.has_more()
+    }
+
+    fn load_more(&mut self, cx: &mut ViewContext<Self>) {
+        if self.loading_more || !self.delegate.has_more() {
+            return;
+        }
+
+        self.loading_more = true;
+        let load = self.delegate.load_more(cx);
+        self._load_more_task = cx.spawn(|this, mut cx| async move {
+            load.await;
+            _ = this.update(&mut cx, |this, cx| {
+                this.loading_more = false;
+                cx.notify();
+            });
+        });
+        cx.notify();
+    }
+
+    /// Render the "Select all" checkbox of the checkbox selection mode.
+    fn render_select_all(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        if !self.checkbox_selection || self.items_count == 0 {
+            return None;
+        }
+
+        let selected_count = self.selected_indices.len();
+        let all_selected = selected_count > 0 && selected_count == self.selectable_count;
+        let view = cx.view().downgrade();
+
+        Some(
+            h_flex()
+                .flex_shrink_0()
+                .justify_between()
+                .gap_2()
+                .px_2()
+                .py_1()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .text_sm()
+                .child(
+                    Checkbox::new("list-select-all")
+                        .label(t!("List.select_all"))
+                        .checked(all_selected)
+                        .indeterminate(selected_count > 0 && !all_selected)
+                        .on_change(move |_, cx| {
+                            _ = view.update(cx, |list, cx| {
+                                let indices = if all_selected {
+                                    vec![]
+                                } else {
+                                    (0..list.delegate.items_count()).collect()
+                                };
+                                list.set_selected_indices(indices, cx);
+                            });
+                        }),
+                )
+                .when(selected_count > 0, |this| {
+                    this.child(
+                        div()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(t!("List.selected_count", count = selected_count).to_string()),
+                    )
+                }),
+        )
+    }
+
+    /// Render the error, loading or empty state for the empty list.
+    fn render_empty_state(&self, cx: &mut ViewContext<Self>) -> AnyElement {
+        if let Some(error) = self.delegate.error() {
+            self.delegate.render_error(error, cx).into_any_element()
+        } else if self.loading || self.loading_more {
+            self.delegate.render_loading(cx).into_any_element()
+        } else {
+            self.delegate.render_empty(cx).into_any_element()
+        }
+    }
+
+    fn render_loading_row(&self, cx: &mut ViewContext<Self>) -> AnyElement {
+        h_flex()
+            .id("list-loading-more")
+            .w_full()
+            .justify_center()
+            .gap_2()
+            .py_2()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child(Indicator::new().small())
+            .child(t!("List.loading").to_string())
+            .into_any_element()
+    }
+
+    fn render_row(&mut self, row: usize, cx: &mut ViewContext<Self>) -> AnyElement {
+        if self.should_load_more(row) {
+            cx.defer(|this, cx| this.load_more(cx));
+        }
+        if row >= self.sections.rows_count(self.delegate.items_count()) {
+            return self.render_loading_row(cx);
+        }
+
+        match self.sections.row(row) {
+            ListRow::SectionHeader(section) => div()
+                .id(("list-section-header", section))
+                .w_full()
+                .children(self.delegate.render_section_header(section, cx))
+                .into_any_element(),
+            ListRow::Item(ix) => self.render_list_item(ix, cx).into_any_element(),
+        }
+    }
+
+    /// Render the header of the section at the top of the list, it is pushed up by the next header.
+    fn render_sticky_section_header(&mut self, cx: &mut ViewContext<Self>) -> Option<AnyElement> {
+        if self.sections.is_empty() {
+            return None;
+        }
+
+        let (top_row, top) = match &self.list_state {
+            Some(list_state) => (list_state.logical_scroll_top().item_ix, px(0.)),
+            None => {
+                let state = self.vertical_scroll_handle.0.borrow();
+                let row_height = state.last_item_size?.item.height;
+                if row_height <= px(0.) {
+                    return None;
+                }
+                let scroll_top = -state.base_handle.offset().y;
+                let top_row = (scroll_top / row_height).floor() as usize;
+                let top = self
+                    .sections
+                    .section_of_row(top_row)
+                    .and_then(|section| self.sections.header_row(section + 1))
+                    .map(|next_header_row| {
+                        (row_height * next_header_row as f32 - scroll_top - row_height).min(px(0.))
+                    })
+                    .unwrap_or_default();
+                (top_row, top)
+            }
+        };
+
+        let section = self.sections.section_of_row(top_row)?;
+        // The header is in place, no need to stick.
+        if self.sections.header_row(section) == Some(top_row) && top == px(0.) {
+            return None;
+        }
+
+        let header = self.delegate.render_section_header(section, cx)?;
+        Some(
+            div()
+                .id("list-sticky-section-header")
+                .absolute()
+                .top(top)
+                .left_0()
+                .right_0()
+                .child(header)
+                .into_any_element(),
+        )
+    }
+
+    /// Select the first item starts with the typed prefix, only when the list itself is focused (no query input).
+    fn on_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        if !self.focus_handle.is_focused(cx) {
+            return;
+        }
+
+        let keystroke = &event.keystroke;
+        if keystroke.modifiers.control || keystroke.modifiers.platform || keystroke.modifiers.alt {
+            return;
+        }
+        let c = match keystroke.key.as_str() {
+            "space" => ' ',
+            key => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => c,
+                    _ => return,
+                }
+            }
+        };
+
+        if self
+            .last_typed_at
+            .map_or(true, |at| at.elapsed() > TYPE_TO_SELECT_TIMEOUT)
+        {
+            self.typed_prefix.clear();
+        }
+        // The space is only used in the prefix, not to start typing.
+        if c == ' ' && self.typed_prefix.is_empty() {
+            return;
+        }
+        self.typed_prefix.extend(c.to_lowercase());
+        self.last_typed_at = Some(Instant::now());
+
+        // Repeat the same character to cycle the items starting with it.
+        let mut prefix_chars = self.typed_prefix.chars();
+        let first = prefix_chars.next();
+        let is_repeated = prefix_chars.all(|c| Some(c) == first);
+        let prefix = if is_repeated {
+            first.map(String::from).unwrap_or_default()
+        } else {
+            self.typed_prefix.clone()
+        };
+
+        let count = self.delegate.items_count();
+        let start = match self.selected_index {
+            // Find from the next item for the new typing or cycling.
+            Some(ix) if is_repeated => ix + 1,
+            Some(ix) => ix,
+            None => 0,
+        };
+        let found = (0..count).map(|i| (start + i) % count).find(|&ix| {
+            self.delegate.can_select(ix)
+                && self
+                    .delegate
+                    .item_text(ix)
+                    .is_some_and(|text| text.to_lowercase().starts_with(&prefix))
+        });
+
+        cx.stop_propagation();
+        if let Some(ix) = found {
+            self.select_only(ix, cx);
+            self.scroll_to_selected_item(cx);
+            cx.notify();
+        }
+    }
+
     fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
         if self.delegate.items_count() == 0 {
             return;
         }
 
+        if let Some(ix) = self.next_selectable_index(self.selected_index, true) {
+            self.select_only(ix, cx);
+        }
+
+        self.scroll_to_selected_item(cx);
+        cx.notify();
+    }
+
+    /// Move the cursor and select the range from the anchor, without wrapping around.
+    fn extend_selection(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        if !self.is_multi_select() {
+            return;
+        }
+
+        let Some(ix) = self.next_selectable_index(self.selected_index, forward) else {
+            return;
+        };
         if let Some(selected_index) = self.selected_index {
-            if selected_index < self.delegate.items_count() - 1 {
-                self.selected_index = Some(selected_index + 1);
-            } else {
-                self.selected_index = Some(0);
+            if (ix > selected_index) != forward {
+                return;
             }
-        } else {
-            self.selected_index = Some(0);
+            self.selection_anchor.get_or_insert(selected_index);
         }
 
+        self.selected_index = Some(ix);
+        self.select_range_to(ix, cx);
         self.scroll_to_selected_item(cx);
         cx.notify();
     }
+
+    fn on_action_extend_selection_prev(
+        &mut self,
+        _: &ExtendSelectionPrev,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.extend_selection(false, cx);
+    }
+
+    fn on_action_extend_selection_next(
+        &mut self,
+        _: &ExtendSelectionNext,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.extend_selection(true, cx);
+    }
+
+    fn on_action_select_all(&mut self, _: &SelectAll, cx: &mut ViewContext<Self>) {
+        if !self.is_multi_select() {
+            return;
+        }
+
+        self.set_selected_indices((0..self.delegate.items_count()).collect(), cx);
+    }
+}
+
+/// Cmd on macOS, Ctrl on Windows and Linux.
+/// Remeasure all the rows of the list state, and keep the scroll position at the same top row.
+fn reset_list_state(list_state: &ListState, rows_count: usize) {
+    let scroll_top = list_state.logical_scroll_top();
+    list_state.reset(rows_count);
+    list_state.scroll_to(ListOffset {
+        item_ix: scroll_top.item_ix.min(rows_count.saturating_sub(1)),
+        offset_in_item: scroll_top.offset_in_item,
+    });
+}
+
+pub(crate) fn is_toggle_modifier(modifiers: &Modifiers) -> bool {
+    if cfg!(target_os = "macos") {
+        modifiers.platform
+    } else {
+        modifiers.control
+    }
 }
 
+impl<D> EventEmitter<ListEvent> for List<D> where D: ListDelegate {}
+
 impl<D> FocusableView for List<D>
 where
     D: ListDelegate,
@@ -322,17 +1467,21 @@ where
     D: ListDelegate,
 {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        self.sync_items_count();
+        if !cx.has_active_drag() {
+            self.drop_target = None;
+        }
+
         let view = cx.view().clone();
         let vertical_scroll_handle = self.vertical_scroll_handle.clone();
         let items_count = self.delegate.items_count();
+        let rows_count = self.rows_count();
         let sizing_behavior = if self.max_height.is_some() {
             ListSizingBehavior::Infer
         } else {
             ListSizingBehavior::Auto
         };
 
-        let selected_bg = cx.theme().list_active;
-
         let inital_view = if let Some(input) = &self.query_input {
             if input.read(cx).text().is_empty() {
                 self.delegate().render_initial(cx)
@@ -352,20 +1501,28 @@ where
             .overflow_hidden()
             .on_action(cx.listener(Self::on_action_cancel))
             .on_action(cx.listener(Self::on_action_confirm))
+            .on_action(cx.listener(Self::on_action_secondary_confirm))
+            .capture_action(cx.listener(Self::on_action_backspace))
             .on_action(cx.listener(Self::on_action_select_next))
             .on_action(cx.listener(Self::on_action_select_prev))
+            .on_action(cx.listener(Self::on_action_extend_selection_prev))
+            .on_action(cx.listener(Self::on_action_extend_selection_next))
+            .on_action(cx.listener(Self::on_action_select_all))
+            .on_key_down(cx.listener(Self::on_key_down))
             .when_some(self.query_input.clone(), |this, input| {
                 this.child(
-                    div()
+                    h_flex()
                         .map(|this| match self.size {
                             Size::Small => this.py_0().px_1p5(),
                             _ => this.py_1().px_2(),
                         })
                         .border_b_1()
                         .border_color(cx.theme().border)
-                        .child(input),
+                        .children(self.render_breadcrumbs(cx))
+                        .child(div().flex_1().child(input)),
                 )
             })
+            .children(self.render_select_all(cx))
             .map(|this| {
                 if let Some(view) = inital_view {
                     this.child(view)
@@ -376,39 +1533,27 @@ where
                             .relative()
                             .when_some(self.max_height, |this, h| this.max_h(h))
                             .overflow_hidden()
+                            .when(self.reorderable, |this| {
+                                let list_id = cx.view().entity_id();
+                                this.on_drag_move(cx.listener(Self::on_drag_move_list))
+                                    .on_drop_item(
+                                        move |drag: &DragItem<usize>, _| drag.is_from(list_id),
+                                        cx.listener(Self::on_drop_item),
+                                    )
+                            })
                             .when(items_count == 0, |this| {
-                                this.child(self.delegate().render_empty(cx))
+                                this.child(self.render_empty_state(cx))
                             })
                             .when(items_count > 0, |this| {
-                                this.child(
-                                    uniform_list(view, "uniform-list", items_count, {
+                                this.child(match self.list_state.clone() {
+                                    Some(list_state) => list(list_state)
+                                        .flex_grow()
+                                        .with_sizing_behavior(sizing_behavior)
+                                        .into_any_element(),
+                                    None => uniform_list(view, "uniform-list", rows_count, {
                                         move |list, visible_range, cx| {
                                             visible_range
-                                                .map(|ix| {
-                                                    div()
-                                                        .id("list-item")
-                                                        .w_full()
-                                                        .children(list.delegate.render_item(ix, cx))
-                                                        .when_some(
-                                                            list.selected_index,
-                                                            |this, selected_index| {
-                                                                this.when(
-                                                                    ix == selected_index,
-                                                                    |this| this.bg(selected_bg),
-                                                                )
-                                                            },
-                                                        )
-                                                        .on_mouse_down(
-                                                            MouseButton::Left,
-                                                            cx.listener(move |this, _, cx| {
-                                                                cx.stop_propagation();
-                                                                this.selected_index = Some(ix);
-                                                                this.on_action_confirm(
-                                                                    &Confirm, cx,
-                                                                );
-                                                            }),
-                                                        )
-                                                })
+                                                .map(|row| list.render_row(row, cx))
                                                 .collect::<Vec<_>>()
                                         }
                                     })
@@ -416,11 +1561,26 @@ where
                                     .with_sizing_behavior(sizing_behavior)
                                     .track_scroll(vertical_scroll_handle)
                                     .into_any_element(),
-                                )
+                                })
                             })
+                            .children(self.render_sticky_section_header(cx))
                             .children(self.render_scrollbar(cx)),
                     )
                 }
             })
+            .when_some(self.delegate.render_footer(cx), |this, footer| {
+                this.child(
+                    h_flex()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_1()
+                        .gap_2()
+                        .border_t_1()
+                        .border_color(cx.theme().border)
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(footer),
+                )
+            })
     }
 }
@@ -5,7 +5,10 @@ use gpui::{
 };
 use smallvec::SmallVec;
 
-use crate::{h_flex, theme::ActiveTheme, Disableable, Icon, IconName, Selectable, Sizable as _};
+use crate::{
+    h_flex, theme::ActiveTheme, tooltip::Tooltip, v_flex, Disableable, Icon, IconName, Selectable,
+    Sizable as _,
+};
 
 #[derive(IntoElement)]
 pub struct ListItem {
@@ -19,6 +22,11 @@ pub struct ListItem {
     on_click: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
     on_mouse_enter: Option<Box<dyn Fn(&MouseMoveEvent, &mut WindowContext) + 'static>>,
     suffix: Option<Box<dyn Fn(&mut WindowContext) -> AnyElement + 'static>>,
+    description: Option<SharedString>,
+    leading: Option<AnyElement>,
+    trailing: Option<AnyElement>,
+    tooltip: Option<SharedString>,
+    actions: SmallVec<[AnyElement; 2]>,
     children: SmallVec<[AnyElement; 2]>,
 }
 
@@ -36,6 +44,11 @@ impl ListItem {
             check_icon: None,
             suffix: None,
             group_id: None,
+            description: None,
+            leading: None,
+            trailing: None,
+            tooltip: None,
+            actions: SmallVec::new(),
             children: SmallVec::new(),
         }
     }
@@ -64,11 +77,18 @@ impl ListItem {
         self
     }
 
+    /// Set ListItem as disabled, it will be rendered with the muted color and not respond to the mouse.
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
 
+    /// Set the tooltip to show on hover, e.g.: to explain why the item is disabled.
+    pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     /// Set the suffix element of the input field, for example a clear button.
     pub fn suffix<F, E>(mut self, builder: F) -> Self
     where
@@ -79,6 +99,34 @@ impl ListItem {
         self
     }
 
+    /// Set the secondary text below the children, it is truncated to one line.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the element before the content, e.g.: an avatar or an icon.
+    pub fn leading(mut self, leading: impl IntoElement) -> Self {
+        self.leading = Some(leading.into_any_element());
+        self
+    }
+
+    /// Set the element after the content, e.g.: a timestamp, a badge or a chevron.
+    pub fn trailing(mut self, trailing: impl IntoElement) -> Self {
+        self.trailing = Some(trailing.into_any_element());
+        self
+    }
+
+    /// Set the action buttons at the end of the item, e.g.: delete or archive buttons.
+    ///
+    /// The actions are revealed on hover, and always visible when the item is selected.
+    /// Clicking in the actions area will not trigger the `on_click` of the item.
+    pub fn actions(mut self, actions: impl IntoIterator<Item = impl IntoElement>) -> Self {
+        self.actions
+            .extend(actions.into_iter().map(|action| action.into_any_element()));
+        self
+    }
+
     pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
         self.on_click = Some(Box::new(handler));
         self
@@ -126,10 +174,20 @@ impl ParentElement for ListItem {
 impl RenderOnce for ListItem {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let is_active = self.selected || self.confirmed;
+        let group_id = self
+            .group_id
+            .unwrap_or_else(|| SharedString::from("list-item"));
 
         self.base
-            .when_some(self.group_id, |this, group_id| this.group(group_id))
-            .text_color(cx.theme().foreground)
+            .group(group_id.clone())
+            .map(|this| {
+                if self.disabled {
+                    this.text_color(cx.theme().muted_foreground)
+                        .cursor_default()
+                } else {
+                    this.text_color(cx.theme().foreground)
+                }
+            })
             .relative()
             .items_center()
             .justify_between()
@@ -163,7 +221,38 @@ impl RenderOnce for ListItem {
                     .items_center()
                     .justify_between()
                     .gap_x_1()
-                    .child(div().w_full().children(self.children))
+                    .when_some(self.leading, |this, leading| {
+                        this.child(div().flex_shrink_0().mr_1().child(leading))
+                    })
+                    .child(
+                        v_flex()
+                            .flex_1()
+                            .min_w_0()
+                            .overflow_hidden()
+                            .child(div().w_full().children(self.children))
+                            .when_some(self.description, |this, description| {
+                                this.child(
+                                    div()
+                                        .w_full()
+                                        .text_sm()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .whitespace_nowrap()
+                                        .overflow_hidden()
+                                        .text_ellipsis()
+                                        .child(description),
+                                )
+                            }),
+                    )
+                    .when_some(self.trailing, |this, trailing| {
+                        this.child(
+                            h_flex()
+                                .flex_shrink_0()
+                                .gap_1()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(trailing),
+                        )
+                    })
                     .when_some(self.check_icon, |this, icon| {
                         this.child(
                             div().w_5().items_center().justify_center().when(
@@ -175,6 +264,25 @@ impl RenderOnce for ListItem {
                         )
                     }),
             )
+            .when(!self.actions.is_empty() && !self.disabled, |this| {
+                this.child(
+                    h_flex()
+                        .id("actions")
+                        .flex_shrink_0()
+                        .gap_1()
+                        .when(!is_active, |this| {
+                            this.invisible()
+                                .group_hover(group_id, |this| this.visible())
+                        })
+                        // Don't let the click on the actions to trigger the item.
+                        .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                        .on_click(|_, cx| cx.stop_propagation())
+                        .children(self.actions),
+                )
+            })
             .when_some(self.suffix, |this, suffix| this.child(suffix(cx)))
+            .when_some(self.tooltip, |this, tooltip| {
+                this.tooltip(move |cx| Tooltip::new(tooltip.clone(), cx))
+            })
     }
 }
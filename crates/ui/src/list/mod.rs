@@ -1,5 +1,6 @@
 mod list;
 mod list_item;
+mod sections;
 
 pub use list::*;
 pub use list_item::*;
@@ -0,0 +1,119 @@
+/// A row of the grouped list, the section headers are inserted before the items of each section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ListRow {
+    SectionHeader(usize),
+    Item(usize),
+}
+
+/// The layout of the section headers in the rows of a list.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct Sections {
+    /// The row index of each section header.
+    header_rows: Vec<usize>,
+}
+
+impl Sections {
+    /// Build from the number of items in each section.
+    pub(crate) fn new(items_counts: impl IntoIterator<Item = usize>) -> Self {
+        let mut row = 0;
+        let header_rows = items_counts
+            .into_iter()
+            .map(|count| {
+                let header_row = row;
+                row += count + 1;
+                header_row
+            })
+            .collect();
+
+        Self { header_rows }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.header_rows.is_empty()
+    }
+
+    /// Return the number of rows for the items count, including the section headers.
+    pub(crate) fn rows_count(&self, items_count: usize) -> usize {
+        items_count + self.header_rows.len()
+    }
+
+    /// Return the row at the row index.
+    pub(crate) fn row(&self, row: usize) -> ListRow {
+        let section_ix = self
+            .header_rows
+            .partition_point(|header_row| *header_row <= row);
+        match section_ix.checked_sub(1) {
+            Some(section) if self.header_rows[section] == row => ListRow::SectionHeader(section),
+            _ => ListRow::Item(row - section_ix),
+        }
+    }
+
+    /// Return the row index of the item.
+    pub(crate) fn item_row(&self, ix: usize) -> usize {
+        // The number of the items before the header is `header_row - section`.
+        let headers_before = self
+            .header_rows
+            .iter()
+            .enumerate()
+            .take_while(|(section, header_row)| *header_row - section <= ix)
+            .count();
+        ix + headers_before
+    }
+
+    /// Return the section that the row belongs to.
+    pub(crate) fn section_of_row(&self, row: usize) -> Option<usize> {
+        self.header_rows
+            .partition_point(|header_row| *header_row <= row)
+            .checked_sub(1)
+    }
+
+    /// Return the row index of the section header.
+    pub(crate) fn header_row(&self, section: usize) -> Option<usize> {
+        self.header_rows.get(section).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ListRow, Sections};
+
+    #[test]
+    fn test_sections() {
+        // Header 0, items 0..2, Header 1 (empty), Header 2, items 2..5
+        let sections = Sections::new([2, 0, 3]);
+        assert_eq!(sections.rows_count(5), 8);
+
+        let rows = (0..8).map(|row| sections.row(row)).collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                ListRow::SectionHeader(0),
+                ListRow::Item(0),
+                ListRow::Item(1),
+                ListRow::SectionHeader(1),
+                ListRow::SectionHeader(2),
+                ListRow::Item(2),
+                ListRow::Item(3),
+                ListRow::Item(4),
+            ]
+        );
+        for (row, list_row) in rows.iter().enumerate() {
+            if let ListRow::Item(ix) = list_row {
+                assert_eq!(sections.item_row(*ix), row);
+            }
+        }
+
+        assert_eq!(sections.section_of_row(0), Some(0));
+        assert_eq!(sections.section_of_row(2), Some(0));
+        assert_eq!(sections.section_of_row(3), Some(1));
+        assert_eq!(sections.section_of_row(7), Some(2));
+        assert_eq!(sections.header_row(2), Some(4));
+        assert_eq!(sections.header_row(3), None);
+
+        let sections = Sections::default();
+        assert!(sections.is_empty());
+        assert_eq!(sections.row(3), ListRow::Item(3));
+        assert_eq!(sections.item_row(3), 3);
+        assert_eq!(sections.section_of_row(3), None);
+    }
+}
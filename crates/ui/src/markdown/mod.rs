@@ -0,0 +1,4 @@
+mod parser;
+mod view;
+
+pub use view::*;
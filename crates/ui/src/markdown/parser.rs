@@ -0,0 +1,485 @@
+use std::ops::Range;
+
+use gpui::SharedString;
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+
+use crate::text::TextSpan;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Image {
+    pub(crate) url: SharedString,
+    pub(crate) alt: SharedString,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ListItem {
+    /// The checked state of the task list item, `None` if it is not a task.
+    pub(crate) checked: Option<bool>,
+    pub(crate) blocks: Vec<Block>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Block {
+    Heading {
+        level: u8,
+        spans: Vec<TextSpan>,
+    },
+    /// The images of the paragraph are displayed after the text.
+    Paragraph {
+        spans: Vec<TextSpan>,
+        images: Vec<Image>,
+    },
+    List {
+        /// The number of the first item if the list is ordered.
+        start: Option<u64>,
+        items: Vec<ListItem>,
+    },
+    BlockQuote(Vec<Block>),
+    CodeBlock {
+        language: SharedString,
+        code: SharedString,
+    },
+    Table {
+        aligns: Vec<Alignment>,
+        head: Vec<Vec<TextSpan>>,
+        rows: Vec<Vec<Vec<TextSpan>>>,
+    },
+    Rule,
+}
+
+/// A top-level block with the byte range in the source.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ParsedBlock {
+    pub(crate) range: Range<usize>,
+    pub(crate) block: Block,
+}
+
+pub(crate) fn parse_markdown(source: &str) -> Vec<ParsedBlock> {
+    parse_from(source, 0)
+}
+
+/// Parse the source again and reuse the blocks before the first changed byte.
+///
+/// The blocks that end before the last blank line ahead of the change are not affected by the
+/// changes after them, so only the rest of the source is parsed.
+pub(crate) fn reparse_markdown(
+    old_source: &str,
+    old_blocks: &[ParsedBlock],
+    source: &str,
+) -> Vec<ParsedBlock> {
+    let changed_ix = old_source
+        .char_indices()
+        .zip(source.chars())
+        .find(|((_, old), new)| old != new)
+        .map(|((ix, _), _)| ix)
+        .unwrap_or(old_source.len().min(source.len()));
+    if changed_ix == old_source.len() && changed_ix == source.len() {
+        return old_blocks.to_vec();
+    }
+
+    let boundary = source[..changed_ix]
+        .rfind("\n\n")
+        .map(|ix| ix + 1)
+        .unwrap_or(0);
+    let reused = old_blocks
+        .iter()
+        .take_while(|block| block.range.end <= boundary)
+        .count();
+    let start = old_blocks
+        .get(reused)
+        .map(|block| block.range.start.min(boundary))
+        .unwrap_or(boundary);
+    // Parse from the line start, the range of the block may not include the indentation.
+    let start = source[..start].rfind('\n').map(|ix| ix + 1).unwrap_or(0);
+
+    let mut blocks = old_blocks[..reused].to_vec();
+    blocks.extend(parse_from(source, start));
+    blocks
+}
+
+fn parse_from(source: &str, offset: usize) -> Vec<ParsedBlock> {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let events = Parser::new_ext(&source[offset..], options)
+        .into_offset_iter()
+        .collect();
+    let mut parser = BlockParser { events, ix: 0 };
+
+    let mut blocks = vec![];
+    while let Some((_, range)) = parser.events.get(parser.ix) {
+        let range = range.start + offset..range.end + offset;
+        if let Some(block) = parser.next_block() {
+            blocks.push(ParsedBlock { range, block });
+        }
+    }
+    blocks
+}
+
+fn is_inline(event: &Event) -> bool {
+    match event {
+        Event::Start(tag) => matches!(
+            tag,
+            Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Link { .. } | Tag::Image { .. }
+        ),
+        Event::End(tag) => matches!(
+            tag,
+            TagEnd::Emphasis
+                | TagEnd::Strong
+                | TagEnd::Strikethrough
+                | TagEnd::Link
+                | TagEnd::Image
+        ),
+        Event::Text(_)
+        | Event::Code(_)
+        | Event::InlineHtml(_)
+        | Event::InlineMath(_)
+        | Event::FootnoteReference(_)
+        | Event::SoftBreak
+        | Event::HardBreak => true,
+        _ => false,
+    }
+}
+
+/// Push the text to the last span if they have the same style.
+fn push_span(spans: &mut Vec<TextSpan>, span: TextSpan) {
+    if let Some(last) = spans.last_mut() {
+        if last.bold == span.bold
+            && last.italic == span.italic
+            && last.code == span.code
+            && last.link == span.link
+        {
+            last.text = format!("{}{}", last.text, span.text).into();
+            return;
+        }
+    }
+
+    spans.push(span);
+}
+
+struct BlockParser<'a> {
+    events: Vec<(Event<'a>, Range<usize>)>,
+    ix: usize,
+}
+
+impl<'a> BlockParser<'a> {
+    fn peek(&self) -> Option<&Event<'a>> {
+        self.events.get(self.ix).map(|(event, _)| event)
+    }
+
+    fn next(&mut self) -> Option<Event<'a>> {
+        let event = self.events.get(self.ix).map(|(event, _)| event.clone());
+        self.ix += 1;
+        event
+    }
+
+    /// Skip the events until the end of the tag.
+    fn skip(&mut self, end: TagEnd) {
+        let mut depth = 0;
+        while let Some(event) = self.next() {
+            match event {
+                Event::Start(tag) if tag.to_end() == end => depth += 1,
+                Event::End(tag) if tag == end => {
+                    if depth == 0 {
+                        break;
+                    }
+                    depth -= 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_blocks(&mut self, end: TagEnd) -> Vec<Block> {
+        let mut blocks = vec![];
+        while let Some(event) = self.peek() {
+            if *event == Event::End(end) {
+                self.ix += 1;
+                break;
+            }
+            if let Some(block) = self.next_block() {
+                blocks.push(block);
+            }
+        }
+        blocks
+    }
+
+    /// Parse the next block, return `None` if the block is not supported.
+    fn next_block(&mut self) -> Option<Block> {
+        // The items of a tight list contain the inlines without the paragraph.
+        if self.peek().is_some_and(is_inline) {
+            let (spans, images) = self.parse_inlines(None);
+            return Some(Block::Paragraph { spans, images });
+        }
+
+        match self.next()? {
+            Event::Start(Tag::Paragraph) => {
+                let (spans, images) = self.parse_inlines(Some(TagEnd::Paragraph));
+                Some(Block::Paragraph { spans, images })
+            }
+            Event::Start(Tag::Heading { level, .. }) => {
+                let (spans, _) = self.parse_inlines(Some(TagEnd::Heading(level)));
+                Some(Block::Heading {
+                    level: level as u8,
+                    spans,
+                })
+            }
+            Event::Start(Tag::BlockQuote(kind)) => Some(Block::BlockQuote(
+                self.parse_blocks(TagEnd::BlockQuote(kind)),
+            )),
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                let mut code = String::new();
+                while let Some(event) = self.next() {
+                    match event {
+                        Event::Text(text) => code.push_str(&text),
+                        Event::End(TagEnd::CodeBlock) => break,
+                        _ => {}
+                    }
+                }
+                if code.ends_with('\n') {
+                    code.pop();
+                }
+
+                Some(Block::CodeBlock {
+                    language: language.into(),
+                    code: code.into(),
+                })
+            }
+            Event::Start(Tag::List(start)) => {
+                let mut items = vec![];
+                while let Some(event) = self.next() {
+                    match event {
+                        Event::Start(Tag::Item) => {
+                            let checked = match self.peek() {
+                                Some(Event::TaskListMarker(checked)) => {
+                                    let checked = *checked;
+                                    self.ix += 1;
+                                    Some(checked)
+                                }
+                                _ => None,
+                            };
+                            let blocks = self.parse_blocks(TagEnd::Item);
+                            items.push(ListItem { checked, blocks });
+                        }
+                        Event::End(TagEnd::List(_)) => break,
+                        _ => {}
+                    }
+                }
+
+                Some(Block::List { start, items })
+            }
+            Event::Start(Tag::Table(aligns)) => {
+                let (mut head, mut rows) = (vec![], vec![]);
+                while let Some(event) = self.next() {
+                    match event {
+                        Event::Start(Tag::TableHead) => head = self.parse_row(TagEnd::TableHead),
+                        Event::Start(Tag::TableRow) => rows.push(self.parse_row(TagEnd::TableRow)),
+                        Event::End(TagEnd::Table) => break,
+                        _ => {}
+                    }
+                }
+
+                Some(Block::Table { aligns, head, rows })
+            }
+            Event::Rule => Some(Block::Rule),
+            // The HTML, footnote definitions and the metadata are not supported.
+            Event::Start(tag) => {
+                self.skip(tag.to_end());
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_row(&mut self, end: TagEnd) -> Vec<Vec<TextSpan>> {
+        let mut cells = vec![];
+        while let Some(event) = self.next() {
+            match event {
+                Event::Start(Tag::TableCell) => {
+                    cells.push(self.parse_inlines(Some(TagEnd::TableCell)).0);
+                }
+                Event::End(tag) if tag == end => break,
+                _ => {}
+            }
+        }
+        cells
+    }
+
+    /// Parse the inlines until the end tag, or until a block event if the end is `None`.
+    fn parse_inlines(&mut self, end: Option<TagEnd>) -> (Vec<TextSpan>, Vec<Image>) {
+        let mut spans = vec![];
+        let mut images = vec![];
+        let (mut bold, mut italic) = (0, 0);
+        let mut link: Option<SharedString> = None;
+        let mut image: Option<(SharedString, String)> = None;
+
+        while let Some(event) = self.peek() {
+            match end {
+                Some(end) if *event == Event::End(end) => {
+                    self.ix += 1;
+                    break;
+                }
+                None if !is_inline(event) => break,
+                _ => {}
+            }
+
+            let (text, code) = match self.next().unwrap() {
+                Event::Text(text) | Event::InlineHtml(text) | Event::InlineMath(text) => {
+                    (text.to_string(), false)
+                }
+                Event::Code(text) => (text.to_string(), true),
+                Event::FootnoteReference(label) => (format!("[{}]", label), false),
+                Event::SoftBreak => (" ".to_string(), false),
+                Event::HardBreak => ("\n".to_string(), false),
+                Event::Start(Tag::Strong) => {
+                    bold += 1;
+                    continue;
+                }
+                Event::End(TagEnd::Strong) => {
+                    bold -= 1;
+                    continue;
+                }
+                Event::Start(Tag::Emphasis) => {
+                    italic += 1;
+                    continue;
+                }
+                Event::End(TagEnd::Emphasis) => {
+                    italic -= 1;
+                    continue;
+                }
+                Event::Start(Tag::Link { dest_url, .. }) => {
+                    link = Some(dest_url.to_string().into());
+                    continue;
+                }
+                Event::End(TagEnd::Link) => {
+                    link = None;
+                    continue;
+                }
+                Event::Start(Tag::Image { dest_url, .. }) => {
+                    image = Some((dest_url.to_string().into(), String::new()));
+                    continue;
+                }
+                Event::End(TagEnd::Image) => {
+                    if let Some((url, alt)) = image.take() {
+                        images.push(Image {
+                            url,
+                            alt: alt.into(),
+                        });
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            if let Some((_, alt)) = image.as_mut() {
+                alt.push_str(&text);
+                continue;
+            }
+
+            push_span(
+                &mut spans,
+                TextSpan {
+                    text: text.into(),
+                    bold: bold > 0,
+                    italic: italic > 0,
+                    code,
+                    link: link.clone(),
+                },
+            );
+        }
+
+        (spans, images)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_markdown, reparse_markdown, Block, ListItem};
+    use crate::text::TextSpan;
+
+    fn paragraph(spans: Vec<TextSpan>) -> Block {
+        Block::Paragraph {
+            spans,
+            images: vec![],
+        }
+    }
+
+    #[test]
+    fn test_parse_markdown() {
+        let source = "# Title\n\nHello **world** [link](https://example.com)\n\n- [x] Done\n- Todo\n\n```rust\nfn main() {}\n```\n\n| A | B |\n|---|---|\n| 1 | `2` |\n";
+        let blocks = parse_markdown(source)
+            .into_iter()
+            .map(|block| block.block)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Heading {
+                    level: 1,
+                    spans: vec![TextSpan::new("Title")]
+                },
+                paragraph(vec![
+                    TextSpan::new("Hello "),
+                    TextSpan::new("world").bold(),
+                    TextSpan::new(" "),
+                    TextSpan::new("link").link("https://example.com"),
+                ]),
+                Block::List {
+                    start: None,
+                    items: vec![
+                        ListItem {
+                            checked: Some(true),
+                            blocks: vec![paragraph(vec![TextSpan::new("Done")])],
+                        },
+                        ListItem {
+                            checked: None,
+                            blocks: vec![paragraph(vec![TextSpan::new("Todo")])],
+                        },
+                    ]
+                },
+                Block::CodeBlock {
+                    language: "rust".into(),
+                    code: "fn main() {}".into(),
+                },
+                Block::Table {
+                    aligns: vec![pulldown_cmark::Alignment::None; 2],
+                    head: vec![vec![TextSpan::new("A")], vec![TextSpan::new("B")]],
+                    rows: vec![vec![
+                        vec![TextSpan::new("1")],
+                        vec![TextSpan::new("2").code()]
+                    ]],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reparse_markdown() {
+        let old_source = "# Title\n\nFirst paragraph.\n\n- Item 1\n\n- Item 2\n\nLast paragraph.\n";
+        let old_blocks = parse_markdown(old_source);
+
+        for source in [
+            old_source,
+            "# Title\n\nFirst paragraph.\n\n- Item 1\n\n- Item 2 changed\n\nLast paragraph.\n",
+            "# Title\n\nFirst paragraph.\n\n- Item 1\n\n- Item 2\n\nLast paragraph.\n\n```\ncode",
+            "# Title\n\nFirst paragraph.\n---\n- Item 1\n",
+            "# Title changed\n\nFirst paragraph.\n",
+            "",
+        ] {
+            assert_eq!(
+                reparse_markdown(old_source, &old_blocks, source),
+                parse_markdown(source),
+                "source: {:?}",
+                source
+            );
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, img, prelude::FluentBuilder as _, px, rems, AnyElement, ElementId, FontWeight,
+    InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
+    StatefulInteractiveElement as _, Styled as _, StyledText, Task, ViewContext, WindowContext,
+};
+use pulldown_cmark::Alignment;
+
+use super::parser::{parse_markdown, reparse_markdown, Block, ParsedBlock};
+use crate::{
+    checkbox::Checkbox,
+    divider::Divider,
+    h_flex,
+    highlighter::Highlighter,
+    text::{RichText, TextSpan},
+    theme::ActiveTheme as _,
+    v_flex,
+};
+
+/// A view to render the markdown document.
+///
+/// Supports the headings, paragraphs, lists, task lists, block quotes, tables, code blocks, images and links.
+/// The source is parsed in the background when changed, the blocks before the change are reused.
+///
+/// The view is not scrollable, put it into a scrollable container if the document is long.
+///
+/// ```ignore
+/// cx.new_view(|cx| MarkdownView::new("# Hello\n\nThis is **markdown**.", cx))
+/// ```
+pub struct MarkdownView {
+    source: SharedString,
+    /// The source of the blocks, it is behind the `source` while parsing.
+    parsed_source: SharedString,
+    blocks: Vec<ParsedBlock>,
+    highlighter: Option<Rc<dyn Highlighter>>,
+    on_click_link: Option<Rc<dyn Fn(&SharedString, &mut WindowContext)>>,
+    _parse_task: Task<()>,
+}
+
+impl MarkdownView {
+    pub fn new(source: impl Into<SharedString>, _: &mut ViewContext<Self>) -> Self {
+        let source = source.into();
+
+        Self {
+            blocks: parse_markdown(&source),
+            parsed_source: source.clone(),
+            source,
+            highlighter: None,
+            on_click_link: None,
+            _parse_task: Task::ready(()),
+        }
+    }
+
+    /// Set the highlighter of the code blocks, the code is not highlighted by default.
+    pub fn highlighter(mut self, highlighter: impl Highlighter + 'static) -> Self {
+        self.highlighter = Some(Rc::new(highlighter));
+        self
+    }
+
+    /// Set the handler of clicking the links with the url, default opens the url in the browser.
+    pub fn on_click_link(
+        mut self,
+        handler: impl Fn(&SharedString, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_click_link = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn source(&self) -> &SharedString {
+        &self.source
+    }
+
+    /// Set the markdown source, it will be parsed in the background.
+    pub fn set_source(&mut self, source: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        let source = source.into();
+        if source == self.source {
+            return;
+        }
+        self.source = source.clone();
+
+        let old_source = self.parsed_source.clone();
+        let old_blocks = self.blocks.clone();
+        let parse = cx.background_executor().spawn(async move {
+            let blocks = reparse_markdown(&old_source, &old_blocks, &source);
+            (source, blocks)
+        });
+
+        // Replace the task to drop the outdated parsing.
+        self._parse_task = cx.spawn(|this, mut cx| async move {
+            let (source, blocks) = parse.await;
+            _ = this.update(&mut cx, |this, cx| {
+                this.parsed_source = source;
+                this.blocks = blocks;
+                cx.notify();
+            });
+        });
+    }
+
+    fn render_spans(&self, id: impl Into<ElementId>, spans: &[TextSpan]) -> RichText {
+        let text = spans
+            .iter()
+            .cloned()
+            .fold(RichText::new(id), |text, span| text.span(span));
+
+        match self.on_click_link.clone() {
+            Some(on_click) => text.on_click(move |url, cx| on_click(url, cx)),
+            None => text,
+        }
+    }
+
+    fn render_blocks(&self, id: &str, blocks: &[Block], cx: &WindowContext) -> Vec<AnyElement> {
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(ix, block)| self.render_block(format!("{}-{}", id, ix).into(), block, cx))
+            .collect()
+    }
+
+    fn render_block(&self, id: SharedString, block: &Block, cx: &WindowContext) -> AnyElement {
+        match block {
+            Block::Heading { level, spans } => {
+                let size = match level {
+                    1 => rems(1.75),
+                    2 => rems(1.5),
+                    3 => rems(1.25),
+                    4 => rems(1.125),
+                    5 => rems(1.),
+                    _ => rems(0.875),
+                };
+
+                div()
+                    .text_size(size)
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .when(*level <= 2, |this| {
+                        this.pb_1().border_b_1().border_color(cx.theme().border)
+                    })
+                    .child(self.render_spans(id, spans))
+                    .into_any_element()
+            }
+            Block::Paragraph { spans, images } => v_flex()
+                .gap_2()
+                .when(
+                    spans.iter().any(|span| !span.text.trim().is_empty()),
+                    |this| this.child(self.render_spans(id, spans)),
+                )
+                .children(
+                    images
+                        .iter()
+                        .map(|image| img(image.url.clone()).max_w_full()),
+                )
+                .into_any_element(),
+            Block::List { start, items } => v_flex()
+                .gap_1()
+                .children(items.iter().enumerate().map(|(ix, item)| {
+                    let id = format!("{}-{}", id, ix);
+                    let marker = match (item.checked, start) {
+                        (Some(checked), _) => {
+                            Checkbox::new(SharedString::from(format!("{}-checkbox", id)))
+                                .checked(checked)
+                                .into_any_element()
+                        }
+                        (None, Some(start)) => div()
+                            .child(format!("{}.", start + ix as u64))
+                            .into_any_element(),
+                        (None, None) => div().child("•").into_any_element(),
+                    };
+
+                    h_flex()
+                        .items_start()
+                        .gap_2()
+                        .child(div().flex_shrink_0().min_w(rems(1.)).child(marker))
+                        .child(v_flex().flex_1().gap_1().children(self.render_blocks(
+                            &id,
+                            &item.blocks,
+                            cx,
+                        )))
+                }))
+                .into_any_element(),
+            Block::BlockQuote(blocks) => v_flex()
+                .gap_2()
+                .pl_3()
+                .border_l_4()
+                .border_color(cx.theme().border)
+                .text_color(cx.theme().muted_foreground)
+                .children(self.render_blocks(&id, blocks, cx))
+                .into_any_element(),
+            Block::CodeBlock { language, code } => {
+                let highlights = self
+                    .highlighter
+                    .as_ref()
+                    .map(|highlighter| highlighter.highlight(language, code, cx))
+                    .unwrap_or_default();
+                let mut text_style = cx.text_style();
+                text_style.font_family = cx.theme().mono_font_family.clone();
+
+                div()
+                    .id(id)
+                    .w_full()
+                    .p_3()
+                    .rounded(px(cx.theme().radius))
+                    .bg(cx.theme().muted)
+                    .font_family(cx.theme().mono_font_family.clone())
+                    .text_sm()
+                    .overflow_x_scroll()
+                    .child(StyledText::new(code.clone()).with_highlights(&text_style, highlights))
+                    .into_any_element()
+            }
+            Block::Table { aligns, head, rows } => {
+                let render_row = |row_id: String, cells: &Vec<Vec<TextSpan>>| {
+                    h_flex().children(cells.iter().enumerate().map(|(ix, spans)| {
+                        h_flex()
+                            .flex_1()
+                            .px_2()
+                            .py_1()
+                            .map(|this| match aligns.get(ix) {
+                                Some(Alignment::Center) => this.justify_center(),
+                                Some(Alignment::Right) => this.justify_end(),
+                                _ => this,
+                            })
+                            .child(self.render_spans(
+                                SharedString::from(format!("{}-{}", row_id, ix)),
+                                spans,
+                            ))
+                    }))
+                };
+
+                v_flex()
+                    .w_full()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded(px(cx.theme().radius))
+                    .overflow_hidden()
+                    .child(
+                        render_row(format!("{}-head", id), head)
+                            .bg(cx.theme().table_head)
+                            .text_color(cx.theme().table_head_foreground)
+                            .font_weight(FontWeight::SEMIBOLD),
+                    )
+                    .children(rows.iter().enumerate().map(|(ix, row)| {
+                        render_row(format!("{}-{}", id, ix), row)
+                            .border_t_1()
+                            .border_color(cx.theme().table_row_border)
+                            .when(ix % 2 == 1, |this| this.bg(cx.theme().table_even))
+                    }))
+                    .into_any_element()
+            }
+            Block::Rule => Divider::horizontal().into_any_element(),
+        }
+    }
+}
+
+impl Render for MarkdownView {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .w_full()
+            .gap_3()
+            .children(self.blocks.iter().enumerate().map(|(ix, block)| {
+                self.render_block(format!("markdown-{}", ix).into(), &block.block, cx)
+            }))
+    }
+}
@@ -1,14 +1,14 @@
-use std::{rc::Rc, time::Duration};
+use std::rc::Rc;
 
 use gpui::{
-    actions, anchored, div, hsla, prelude::FluentBuilder, px, relative, Animation,
-    AnimationExt as _, AnyElement, AppContext, Bounds, ClickEvent, Div, FocusHandle, Hsla,
-    InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point,
-    RenderOnce, SharedString, Styled, WindowContext,
+    actions, anchored, div, hsla, prelude::FluentBuilder, px, relative, AnimationExt as _,
+    AnyElement, AppContext, Bounds, ClickEvent, Div, FocusHandle, Hsla, InteractiveElement,
+    IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point, RenderOnce, SharedString,
+    Styled, WindowContext,
 };
 
 use crate::{
-    animation::cubic_bezier,
+    animation::{ease_emphasized, motion, DURATION_NORMAL},
     button::{Button, ButtonStyled as _},
     theme::ActiveTheme as _,
     v_flex, ContextModal, IconName, Sizable as _,
@@ -39,6 +39,8 @@ pub struct Modal {
     pub(crate) focus_handle: FocusHandle,
     pub(crate) layer_ix: usize,
     pub(crate) overlay_visible: bool,
+    /// The id of the modeless dialog, `None` for a normal modal.
+    pub(crate) modeless_id: Option<SharedString>,
 }
 
 pub(crate) fn overlay_color(overlay: bool, cx: &WindowContext) -> Hsla {
@@ -77,6 +79,7 @@ impl Modal {
             overlay: true,
             layer_ix: 0,
             overlay_visible: true,
+            modeless_id: None,
             on_close: Rc::new(|_, _| {}),
             show_close: true,
         }
@@ -134,7 +137,7 @@ impl Modal {
     }
 
     pub(crate) fn has_overlay(&self) -> bool {
-        self.overlay
+        self.overlay && self.modeless_id.is_none()
     }
 }
 
@@ -153,7 +156,22 @@ impl Styled for Modal {
 impl RenderOnce for Modal {
     fn render(self, cx: &mut WindowContext) -> impl gpui::IntoElement {
         let layer_ix = self.layer_ix;
-        let on_close = self.on_close.clone();
+        let modeless = self.modeless_id.is_some();
+        let close: Rc<dyn Fn(&mut WindowContext)> = {
+            let on_close = self.on_close.clone();
+            let modeless_id = self.modeless_id.clone();
+            Rc::new(move |cx| {
+                on_close(&ClickEvent::default(), cx);
+                match modeless_id.clone() {
+                    Some(id) => cx.close_modeless(id),
+                    None => cx.close_modal(),
+                }
+            })
+        };
+        let id_prefix = match &self.modeless_id {
+            Some(id) => format!("modeless-{id}"),
+            None => format!("modal-{layer_ix}"),
+        };
         let view_size = cx.viewport_size();
         let bounds = Bounds {
             origin: Point::default(),
@@ -165,35 +183,35 @@ impl RenderOnce for Modal {
 
         anchored().snap_to_window().child(
             div()
-                .occlude()
-                .w(view_size.width)
-                .h(view_size.height)
-                .when(self.overlay_visible, |this| {
-                    this.bg(overlay_color(self.overlay, cx))
-                })
-                .when(self.overlay, |this| {
-                    this.on_mouse_down(MouseButton::Left, {
-                        let on_close = self.on_close.clone();
-                        move |_, cx| {
-                            on_close(&ClickEvent::default(), cx);
-                            cx.close_modal();
-                        }
-                    })
+                // The modeless dialog must not block the content under it,
+                // so only the dialog itself is occluded.
+                .when(!modeless, |this| {
+                    this.occlude()
+                        .w(view_size.width)
+                        .h(view_size.height)
+                        .when(self.overlay_visible, |this| {
+                            this.bg(overlay_color(self.overlay, cx))
+                        })
+                        .when(self.overlay, |this| {
+                            this.on_mouse_down(MouseButton::Left, {
+                                let close = close.clone();
+                                move |_, cx| close(cx)
+                            })
+                        })
                 })
                 .child(
                     self.base
-                        .id(SharedString::from(format!("modal-{layer_ix}")))
+                        .id(SharedString::from(id_prefix.clone()))
                         .key_context(CONTEXT)
                         .track_focus(&self.focus_handle)
                         .on_action({
-                            let on_close = self.on_close.clone();
+                            let close = close.clone();
                             move |_: &Escape, cx| {
                                 // FIXME:
                                 //
                                 // Here some Modal have no focus_handle, so it will not work will Escape key.
                                 // But by now, we `cx.close_modal()` going to close the last active model, so the Escape is unexpected to work.
-                                on_close(&ClickEvent::default(), cx);
-                                cx.close_modal();
+                                close(cx)
                             }
                         })
                         .absolute()
@@ -208,25 +226,21 @@ impl RenderOnce for Modal {
                         })
                         .when(self.show_close, |this| {
                             this.child(
-                                Button::new(SharedString::from(format!("modal-close-{layer_ix}")))
+                                Button::new(SharedString::from(format!("{id_prefix}-close")))
                                     .absolute()
                                     .top_2()
                                     .right_2()
                                     .small()
                                     .ghost()
                                     .icon(IconName::Close)
-                                    .on_click(move |_, cx| {
-                                        on_close(&ClickEvent::default(), cx);
-                                        cx.close_modal();
-                                    }),
+                                    .on_click(move |_, cx| close(cx)),
                             )
                         })
                         .child(self.content)
                         .children(self.footer)
                         .with_animation(
                             "slide-down",
-                            Animation::new(Duration::from_secs_f64(0.25))
-                                .with_easing(cubic_bezier(0.32, 0.72, 0., 1.)),
+                            motion(DURATION_NORMAL, ease_emphasized(), cx),
                             move |this, delta| {
                                 let y_offset = px(0.) + delta * px(30.);
                                 this.top(y + y_offset).opacity(delta)
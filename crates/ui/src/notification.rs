@@ -1,20 +1,22 @@
 use std::{any::TypeId, collections::VecDeque, sync::Arc, time::Duration};
 
 use gpui::{
-    div, prelude::FluentBuilder, px, Animation, AnimationExt, ClickEvent, DismissEvent, ElementId,
-    EventEmitter, InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
-    StatefulInteractiveElement, Styled, View, ViewContext, VisualContext, WindowContext,
+    div, prelude::FluentBuilder, px, ClickEvent, DismissEvent, ElementId, EventEmitter,
+    InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
+    StatefulInteractiveElement, Styled, View, ViewContext, VisualContext, WeakView, WindowContext,
 };
 use smol::Timer;
 
 use crate::{
-    animation::cubic_bezier,
+    animation::{overlay_enter, overlay_exit, reduce_motion, TransitionExt as _, DURATION_FAST},
     button::{Button, ButtonStyled as _},
     h_flex,
+    status_bar::StatusBar,
     theme::ActiveTheme as _,
     v_flex, Icon, IconName, Sizable as _, StyledExt,
 };
 
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum NotificationType {
     Info,
     Success,
@@ -22,6 +24,17 @@ pub enum NotificationType {
     Error,
 }
 
+impl NotificationType {
+    pub(crate) fn icon(&self) -> Icon {
+        match self {
+            Self::Info => Icon::new(IconName::Info).text_color(crate::blue_500()),
+            Self::Success => Icon::new(IconName::CircleCheck).text_color(crate::green_500()),
+            Self::Warning => Icon::new(IconName::TriangleAlert).text_color(crate::yellow_500()),
+            Self::Error => Icon::new(IconName::CircleX).text_color(crate::red_500()),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub(crate) enum NotificationId {
     Id(TypeId),
@@ -116,6 +129,17 @@ impl Notification {
         Self::new(message).with_type(NotificationType::Error)
     }
 
+    /// Return true if the notification is a short message can be shown in the status bar.
+    fn is_transient(&self) -> bool {
+        self.autohide
+            && self.title.is_none()
+            && self.on_click.is_none()
+            && matches!(
+                self.type_,
+                NotificationType::Info | NotificationType::Success
+            )
+    }
+
     /// Set the type for unique identification of the notification.
     ///
     /// ```rs
@@ -174,9 +198,14 @@ impl Notification {
         self.closing = true;
         cx.notify();
 
-        // Dismiss the notification after 0.15s to show the animation.
+        // Dismiss the notification after the exit transition.
+        let delay = if reduce_motion(cx) {
+            Duration::ZERO
+        } else {
+            DURATION_FAST
+        };
         cx.spawn(|view, mut cx| async move {
-            Timer::after(Duration::from_secs_f32(0.15)).await;
+            Timer::after(delay).await;
             cx.update(|cx| {
                 if let Some(view) = view.upgrade() {
                     view.update(cx, |view, cx| {
@@ -196,18 +225,7 @@ impl Render for Notification {
         let closing = self.closing;
         let icon = match self.icon.clone() {
             Some(icon) => icon,
-            None => match self.type_ {
-                NotificationType::Info => Icon::new(IconName::Info).text_color(crate::blue_500()),
-                NotificationType::Success => {
-                    Icon::new(IconName::CircleCheck).text_color(crate::green_500())
-                }
-                NotificationType::Warning => {
-                    Icon::new(IconName::TriangleAlert).text_color(crate::yellow_500())
-                }
-                NotificationType::Error => {
-                    Icon::new(IconName::CircleX).text_color(crate::red_500())
-                }
-            },
+            None => self.type_.icon(),
         };
 
         div()
@@ -259,19 +277,14 @@ impl Render for Notification {
                         ),
                 )
             })
-            .with_animation(
+            .with_transition(
                 ElementId::NamedInteger("slide-down".into(), closing as usize),
-                Animation::new(Duration::from_secs_f64(0.15))
-                    .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
-                move |this, delta| {
-                    if closing {
-                        let x_offset = px(0.) + delta * px(45.);
-                        this.left(px(0.) + x_offset).opacity(1. - delta)
-                    } else {
-                        let y_offset = px(-45.) + delta * px(45.);
-                        this.top(px(0.) + y_offset).opacity(delta)
-                    }
+                if closing {
+                    overlay_exit(px(45.), DURATION_FAST)
+                } else {
+                    overlay_enter(px(45.), DURATION_FAST)
                 },
+                cx,
             )
     }
 }
@@ -281,6 +294,8 @@ pub struct NotificationList {
     /// Notifications that will be auto hidden.
     pub(crate) notifications: VecDeque<View<Notification>>,
     expanded: bool,
+    /// The status bar to show the transient notifications, see [`StatusBar::route_notifications`].
+    pub(crate) status_bar: Option<WeakView<StatusBar>>,
 }
 
 impl NotificationList {
@@ -288,11 +303,21 @@ impl NotificationList {
         Self {
             notifications: VecDeque::new(),
             expanded: false,
+            status_bar: None,
         }
     }
 
     pub fn push(&mut self, notification: impl Into<Notification>, cx: &mut ViewContext<Self>) {
         let notification = notification.into();
+        if let Some(status_bar) = self.status_bar.as_ref().and_then(|v| v.upgrade()) {
+            if notification.is_transient() {
+                status_bar.update(cx, |status_bar, cx| {
+                    status_bar.show_message(notification.message, notification.type_, cx)
+                });
+                return;
+            }
+        }
+
         let id = notification.id.clone();
         let autohide = notification.autohide;
 
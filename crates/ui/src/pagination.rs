@@ -0,0 +1,320 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, impl_actions, prelude::FluentBuilder as _, ElementId, InteractiveElement as _,
+    IntoElement, ParentElement as _, RenderOnce, SharedString, Styled as _, WindowContext,
+};
+use rust_i18n::t;
+use serde::Deserialize;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    popup_menu::PopupMenuExt as _,
+    theme::ActiveTheme as _,
+    Disableable as _, IconName, Selectable as _, Sizable, Size,
+};
+
+/// Action dispatched by the page size menu.
+#[derive(Clone, PartialEq, Deserialize)]
+struct SelectPageSize(usize);
+
+impl_actions!(pagination, [SelectPageSize]);
+
+/// An item of the page buttons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PageItem {
+    Page(usize),
+    Ellipsis,
+}
+
+/// Returns the page items to display, the first and last page are always visible,
+/// the pages out of the `siblings` window around the current page are collapsed into an ellipsis.
+///
+/// The page is 1-based, e.g.: `1 … 4 5 6 … 10`.
+fn page_items(current: usize, total: usize, siblings: usize) -> Vec<PageItem> {
+    if total == 0 {
+        return vec![];
+    }
+
+    let current = current.clamp(1, total);
+    let start = current.saturating_sub(siblings).max(1);
+    let end = (current + siblings).min(total);
+
+    let mut items = vec![];
+    if start > 1 {
+        items.push(PageItem::Page(1));
+        // Show the page instead of the ellipsis if only one page is hidden.
+        match start {
+            2 => {}
+            3 => items.push(PageItem::Page(2)),
+            _ => items.push(PageItem::Ellipsis),
+        }
+    }
+    items.extend((start..=end).map(PageItem::Page));
+    if end < total {
+        match total - end {
+            1 => {}
+            2 => items.push(PageItem::Page(total - 1)),
+            _ => items.push(PageItem::Ellipsis),
+        }
+        items.push(PageItem::Page(total));
+    }
+
+    items
+}
+
+type OnChange = Rc<dyn Fn(&usize, &mut WindowContext) + 'static>;
+
+/// A Pagination to navigate the pages of the Table or List.
+///
+/// The page is 1-based.
+///
+/// ```ignore
+/// Pagination::new("pagination")
+///     .page(self.page)
+///     .total_pages(20)
+///     .on_page_change(cx.listener(|this, page, cx| {
+///         this.page = *page;
+///         cx.notify();
+///     }))
+/// ```
+#[derive(IntoElement)]
+pub struct Pagination {
+    id: ElementId,
+    page: usize,
+    total_pages: usize,
+    siblings: usize,
+    page_size: Option<usize>,
+    page_sizes: Vec<usize>,
+    disabled: bool,
+    size: Size,
+    on_page_change: Option<OnChange>,
+    on_page_size_change: Option<OnChange>,
+}
+
+impl Pagination {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            page: 1,
+            total_pages: 1,
+            siblings: 1,
+            page_size: None,
+            page_sizes: vec![10, 20, 50, 100],
+            disabled: false,
+            size: Size::default(),
+            on_page_change: None,
+            on_page_size_change: None,
+        }
+    }
+
+    /// Set the current page, 1-based.
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Set the total number of pages.
+    pub fn total_pages(mut self, total_pages: usize) -> Self {
+        self.total_pages = total_pages;
+        self
+    }
+
+    /// Set the total number of pages by the total number of items and the page size.
+    pub fn total_items(mut self, total_items: usize, page_size: usize) -> Self {
+        self.total_pages = total_items.div_ceil(page_size.max(1));
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set the number of pages to display on each side of the current page, default: 1
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    /// Set the current page size, the page size selector is displayed if this is set.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set the options of the page size selector, default: `[10, 20, 50, 100]`
+    pub fn page_sizes(mut self, page_sizes: impl Into<Vec<usize>>) -> Self {
+        self.page_sizes = page_sizes.into();
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the handler to be called when the page is changed, the argument is the new page.
+    pub fn on_page_change(
+        mut self,
+        handler: impl Fn(&usize, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_page_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler to be called when the page size is changed by the page size selector.
+    pub fn on_page_size_change(
+        mut self,
+        handler: impl Fn(&usize, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_page_size_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn page_button(&self, id: impl Into<ElementId>, page: usize) -> Button {
+        let on_page_change = self.on_page_change.clone();
+        Button::new(id)
+            .ghost()
+            .compact()
+            .with_size(self.size)
+            .disabled(self.disabled)
+            .when_some(on_page_change, |this, on_page_change| {
+                this.on_click(move |_, cx| on_page_change(&page, cx))
+            })
+    }
+}
+
+impl Sizable for Pagination {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl RenderOnce for Pagination {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let total = self.total_pages.max(1);
+        let current = self.page.clamp(1, total);
+
+        h_flex()
+            .id(self.id.clone())
+            .gap_1()
+            .child(
+                self.page_button("prev", current.saturating_sub(1))
+                    .icon(IconName::ChevronLeft)
+                    .disabled(self.disabled || current <= 1),
+            )
+            .children(
+                page_items(current, total, self.siblings)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, item)| match item {
+                        PageItem::Page(page) => self
+                            .page_button(("page", page), page)
+                            .label(page.to_string())
+                            .min_w_8()
+                            .selected(page == current)
+                            .into_any_element(),
+                        PageItem::Ellipsis => h_flex()
+                            .id(("ellipsis", ix))
+                            .min_w_8()
+                            .justify_center()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(IconName::Ellipsis)
+                            .into_any_element(),
+                    }),
+            )
+            .child(
+                self.page_button("next", current + 1)
+                    .icon(IconName::ChevronRight)
+                    .disabled(self.disabled || current >= total),
+            )
+            .when_some(self.page_size, |this, page_size| {
+                let page_sizes = self.page_sizes.clone();
+                let on_page_size_change = self.on_page_size_change.clone();
+
+                this.child(
+                    div()
+                        .ml_2()
+                        .when_some(on_page_size_change, |this, on_page_size_change| {
+                            this.on_action(move |action: &SelectPageSize, cx| {
+                                on_page_size_change(&action.0, cx)
+                            })
+                        })
+                        .child(
+                            Button::new("page-size")
+                                .outline()
+                                .with_size(self.size)
+                                .disabled(self.disabled)
+                                .label(SharedString::from(t!(
+                                    "Pagination.page_size",
+                                    size = page_size
+                                )))
+                                .popup_menu(move |menu, _| {
+                                    page_sizes.iter().fold(menu, |menu, size| {
+                                        menu.menu_with_check(
+                                            SharedString::from(t!(
+                                                "Pagination.page_size",
+                                                size = size
+                                            )),
+                                            *size == page_size,
+                                            Box::new(SelectPageSize(*size)),
+                                        )
+                                    })
+                                }),
+                        ),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{page_items, PageItem::*};
+
+    #[test]
+    fn test_page_items() {
+        assert_eq!(page_items(1, 0, 1), vec![]);
+        assert_eq!(page_items(1, 1, 1), vec![Page(1)]);
+        assert_eq!(
+            page_items(1, 5, 1),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5)]
+        );
+        assert_eq!(
+            page_items(1, 10, 1),
+            vec![Page(1), Page(2), Ellipsis, Page(10)]
+        );
+        assert_eq!(
+            page_items(5, 10, 1),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(4),
+                Page(5),
+                Page(6),
+                Ellipsis,
+                Page(10)
+            ]
+        );
+        assert_eq!(
+            page_items(10, 10, 1),
+            vec![Page(1), Ellipsis, Page(9), Page(10)]
+        );
+        // Only one page is hidden, display it instead of the ellipsis.
+        assert_eq!(
+            page_items(4, 10, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Ellipsis,
+                Page(10)
+            ]
+        );
+        // Out of range.
+        assert_eq!(
+            page_items(20, 10, 2),
+            vec![Page(1), Ellipsis, Page(8), Page(9), Page(10)]
+        );
+    }
+}
@@ -8,10 +8,12 @@ use gpui::{
     SharedString, Styled as _, View, ViewContext, VisualContext as _, WindowContext,
 };
 use gpui::{
-    anchored, canvas, rems, AnchorCorner, AnyElement, Bounds, Edges, FocusableView, Keystroke,
-    ScrollHandle, StatefulInteractiveElement, WeakView,
+    anchored, canvas, rems, AnchorCorner, AnyElement, Bounds, Edges, FocusableView, ScrollHandle,
+    StatefulInteractiveElement, WeakView,
 };
 
+pub use crate::kbd::key_shortcut;
+use crate::kbd::Kbd;
 use crate::scroll::{Scrollbar, ScrollbarState};
 use crate::StyledExt;
 use crate::{
@@ -51,6 +53,8 @@ enum PopupMenuItem {
         icon: Option<Icon>,
         label: SharedString,
         action: Option<Box<dyn Action>>,
+        disabled: bool,
+        tooltip: Option<SharedString>,
         handler: Rc<dyn Fn(&mut WindowContext)>,
     },
     ElementItem {
@@ -66,7 +70,10 @@ enum PopupMenuItem {
 
 impl PopupMenuItem {
     fn is_clickable(&self) -> bool {
-        !matches!(self, PopupMenuItem::Separator)
+        !matches!(
+            self,
+            PopupMenuItem::Separator | PopupMenuItem::Item { disabled: true, .. }
+        )
     }
 
     fn is_separator(&self) -> bool {
@@ -169,6 +176,8 @@ impl PopupMenu {
             icon: None,
             label: label.into(),
             action: None,
+            disabled: false,
+            tooltip: None,
             handler: Rc::new(move |cx| cx.open_url(&href)),
         });
         self
@@ -186,6 +195,8 @@ impl PopupMenu {
             icon: Some(icon.into()),
             label: label.into(),
             action: None,
+            disabled: false,
+            tooltip: None,
             handler: Rc::new(move |cx| cx.open_url(&href)),
         });
         self
@@ -218,6 +229,28 @@ impl PopupMenu {
         self
     }
 
+    /// Add Menu Item with disabled state, the disabled item can't be clicked or confirmed by the keyboard.
+    pub fn menu_with_disabled(
+        mut self,
+        label: impl Into<SharedString>,
+        disabled: bool,
+        action: Box<dyn Action>,
+    ) -> Self {
+        self.add_menu_item(label, None, action);
+        if let Some(PopupMenuItem::Item { disabled: d, .. }) = self.menu_items.last_mut() {
+            *d = disabled;
+        }
+        self
+    }
+
+    /// Set the tooltip of the last Menu Item, e.g.: to explain why the item is disabled.
+    pub fn tooltip(mut self, text: impl Into<SharedString>) -> Self {
+        if let Some(PopupMenuItem::Item { tooltip, .. }) = self.menu_items.last_mut() {
+            *tooltip = Some(text.into());
+        }
+        self
+    }
+
     /// Add Menu Item with custom element render.
     pub fn menu_with_element<F, E>(mut self, builder: F, action: Box<dyn Action>) -> Self
     where
@@ -271,6 +304,8 @@ impl PopupMenu {
             icon,
             label: label.into(),
             action: Some(action.boxed_clone()),
+            disabled: false,
+            tooltip: None,
             handler: self.wrap_handler(action),
         });
         self
@@ -353,7 +388,11 @@ impl PopupMenu {
             Some(index) => {
                 let item = self.menu_items.get(index);
                 match item {
-                    Some(PopupMenuItem::Item { handler, .. }) => {
+                    Some(PopupMenuItem::Item {
+                        disabled: false,
+                        handler,
+                        ..
+                    }) => {
                         handler(cx);
                         self.dismiss(&Dismiss, cx)
                     }
@@ -368,29 +407,39 @@ impl PopupMenu {
         }
     }
 
+    /// Select the next clickable item, the separators and the disabled items are skipped.
     fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
-        let count = self.clickable_menu_items().count();
-        if count > 0 {
-            let ix = self
-                .selected_index
-                .map(|index| if index == count - 1 { 0 } else { index + 1 })
-                .unwrap_or(0);
-
-            self.selected_index = Some(ix);
-            cx.notify();
-        }
+        let ixs = self
+            .clickable_menu_items()
+            .map(|(ix, _)| ix)
+            .collect::<Vec<_>>();
+        let Some(first_ix) = ixs.first().copied() else {
+            return;
+        };
+
+        let ix = self
+            .selected_index
+            .and_then(|index| ixs.iter().copied().find(|ix| *ix > index))
+            .unwrap_or(first_ix);
+        self.selected_index = Some(ix);
+        cx.notify();
     }
 
     fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
-        let count = self.clickable_menu_items().count();
-        if count > 0 {
-            let ix = self
-                .selected_index
-                .map(|index| if index == count - 1 { 0 } else { index - 1 })
-                .unwrap_or(count - 1);
-            self.selected_index = Some(ix);
-            cx.notify();
-        }
+        let ixs = self
+            .clickable_menu_items()
+            .map(|(ix, _)| ix)
+            .collect::<Vec<_>>();
+        let Some(last_ix) = ixs.last().copied() else {
+            return;
+        };
+
+        let ix = self
+            .selected_index
+            .and_then(|index| ixs.iter().copied().rev().find(|ix| *ix < index))
+            .unwrap_or(last_ix);
+        self.selected_index = Some(ix);
+        cx.notify();
     }
 
     fn dismiss(&mut self, _: &Dismiss, cx: &mut ViewContext<Self>) {
@@ -412,20 +461,9 @@ impl PopupMenu {
         action: Option<Box<dyn Action>>,
         cx: &ViewContext<Self>,
     ) -> Option<impl IntoElement> {
-        if let Some(action) = action {
-            if let Some(keybinding) = cx.bindings_for_action(action.deref()).first() {
-                let el = div().text_color(cx.theme().muted_foreground).children(
-                    keybinding
-                        .keystrokes()
-                        .into_iter()
-                        .map(|key| key_shortcut(key.clone())),
-                );
-
-                return Some(el);
-            }
-        }
-
-        return None;
+        action.and_then(|action| {
+            Kbd::binding_for_action(action.deref(), cx).map(|kbd| kbd.appearance(false))
+        })
     }
 
     fn render_icon(
@@ -566,6 +604,8 @@ impl Render for PopupMenu {
                                                 icon,
                                                 label,
                                                 action,
+                                                disabled,
+                                                tooltip,
                                                 ..
                                             } => {
                                                 let action = action
@@ -573,29 +613,33 @@ impl Render for PopupMenu {
                                                     .map(|action| action.boxed_clone());
                                                 let key = Self::render_keybinding(action, cx);
 
-                                                this.on_click(cx.listener(move |this, _, cx| {
-                                                    this.on_click(ix, cx)
-                                                }))
-                                                .child(
-                                                    h_flex()
-                                                        .h(ITEM_HEIGHT)
-                                                        .items_center()
-                                                        .gap_x_1p5()
-                                                        .children(Self::render_icon(
-                                                            has_icon,
-                                                            icon.clone(),
-                                                            cx,
-                                                        ))
-                                                        .child(
-                                                            h_flex()
-                                                                .flex_1()
-                                                                .gap_2()
-                                                                .items_center()
-                                                                .justify_between()
-                                                                .child(label.clone())
-                                                                .children(key),
-                                                        ),
-                                                )
+                                                this.disabled(*disabled)
+                                                    .when_some(tooltip.clone(), |this, tooltip| {
+                                                        this.tooltip(tooltip)
+                                                    })
+                                                    .on_click(cx.listener(move |this, _, cx| {
+                                                        this.on_click(ix, cx)
+                                                    }))
+                                                    .child(
+                                                        h_flex()
+                                                            .h(ITEM_HEIGHT)
+                                                            .items_center()
+                                                            .gap_x_1p5()
+                                                            .children(Self::render_icon(
+                                                                has_icon,
+                                                                icon.clone(),
+                                                                cx,
+                                                            ))
+                                                            .child(
+                                                                h_flex()
+                                                                    .flex_1()
+                                                                    .gap_2()
+                                                                    .items_center()
+                                                                    .justify_between()
+                                                                    .child(label.clone())
+                                                                    .children(key),
+                                                            ),
+                                                    )
                                             }
                                             PopupMenuItem::Submenu { icon, label, menu } => this
                                                 .when(self.hovered_menu_ix == Some(ix), |this| {
@@ -700,64 +744,3 @@ impl Render for PopupMenu {
             })
     }
 }
-
-/// Return the Platform specific keybinding string by KeyStroke
-pub fn key_shortcut(key: Keystroke) -> String {
-    if cfg!(target_os = "macos") {
-        return format!("{}", key);
-    }
-
-    let mut parts = vec![];
-    if key.modifiers.control {
-        parts.push("Ctrl");
-    }
-    if key.modifiers.alt {
-        parts.push("Alt");
-    }
-    if key.modifiers.platform {
-        parts.push("Win");
-    }
-    if key.modifiers.shift {
-        parts.push("Shift");
-    }
-
-    // Capitalize the first letter
-    let key = if let Some(first_c) = key.key.chars().next() {
-        format!("{}{}", first_c.to_uppercase(), &key.key[1..])
-    } else {
-        key.key.to_string()
-    };
-
-    parts.push(&key);
-    parts.join("+")
-}
-
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_key_shortcut() {
-        use super::key_shortcut;
-        use gpui::Keystroke;
-
-        if cfg!(target_os = "windows") {
-            assert_eq!(key_shortcut(Keystroke::parse("a").unwrap()), "A");
-            assert_eq!(key_shortcut(Keystroke::parse("ctrl-a").unwrap()), "Ctrl+A");
-            assert_eq!(
-                key_shortcut(Keystroke::parse("ctrl-alt-a").unwrap()),
-                "Ctrl+Alt+A"
-            );
-            assert_eq!(
-                key_shortcut(Keystroke::parse("ctrl-alt-shift-a").unwrap()),
-                "Ctrl+Alt+Shift+A"
-            );
-            assert_eq!(
-                key_shortcut(Keystroke::parse("ctrl-alt-shift-win-a").unwrap()),
-                "Ctrl+Alt+Win+Shift+A"
-            );
-            assert_eq!(
-                key_shortcut(Keystroke::parse("ctrl-shift-backspace").unwrap()),
-                "Ctrl+Shift+Backspace"
-            );
-        }
-    }
-}
@@ -0,0 +1,297 @@
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, relative, AppContext, EventEmitter, FocusHandle,
+    Hsla, InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _, Pixels, Render,
+    StatefulInteractiveElement as _, Styled as _, ViewContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, Icon, IconName, Sizable, Size};
+
+actions!(rating, [Increment, Decrement, Clear, Fill]);
+
+const CONTEXT: &str = "Rating";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", Decrement, Some(CONTEXT)),
+        KeyBinding::new("down", Decrement, Some(CONTEXT)),
+        KeyBinding::new("right", Increment, Some(CONTEXT)),
+        KeyBinding::new("up", Increment, Some(CONTEXT)),
+        KeyBinding::new("home", Clear, Some(CONTEXT)),
+        KeyBinding::new("backspace", Clear, Some(CONTEXT)),
+        KeyBinding::new("end", Fill, Some(CONTEXT)),
+    ]);
+}
+
+pub enum RatingEvent {
+    Change(f32),
+}
+
+/// The filled ratio of the star at the `ix`, range of 0.0..1.0
+fn star_fill(value: f32, ix: usize) -> f32 {
+    (value - ix as f32).clamp(0., 1.)
+}
+
+/// Snap the value to the step, whole or half star, and clamp it into 0..max.
+fn snap(value: f32, half: bool, max: usize) -> f32 {
+    let value = if half {
+        (value * 2.).round() / 2.
+    } else {
+        value.round()
+    };
+
+    value.clamp(0., max as f32)
+}
+
+/// A rating input with stars, supports the half star, hover preview and keyboard adjustment.
+///
+/// ```ignore
+/// let rating = cx.new_view(|_| Rating::new().max(5).half(true).default_value(3.5));
+/// ```
+pub struct Rating {
+    value: f32,
+    max: usize,
+    half: bool,
+    readonly: bool,
+    icon: Icon,
+    color: Option<Hsla>,
+    size: Size,
+    /// The value under the mouse to preview.
+    hovered_value: Option<f32>,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl Rating {
+    pub fn new() -> Self {
+        Self {
+            value: 0.,
+            max: 5,
+            half: false,
+            readonly: false,
+            icon: IconName::Star.into(),
+            color: None,
+            size: Size::Medium,
+            hovered_value: None,
+            focus_handle: None,
+        }
+    }
+
+    /// Set the number of the stars, default: 5
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max.max(1);
+        self
+    }
+
+    /// Set true to allow the half star, default: false
+    pub fn half(mut self, half: bool) -> Self {
+        self.half = half;
+        self
+    }
+
+    /// Set true to only display the value, the value can't be changed by the user, default: false
+    ///
+    /// The readonly rating can show any fraction value, e.g.: 4.3 for the average rating.
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    /// Set the icon of the stars, default: [`IconName::Star`]
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// Set the color of the filled stars, default: yellow
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the default value of the rating, default: 0.0
+    pub fn default_value(mut self, value: f32) -> Self {
+        self.value = value.clamp(0., self.max as f32);
+        self
+    }
+
+    /// Set the value of the rating.
+    pub fn set_value(&mut self, value: f32, cx: &mut ViewContext<Self>) {
+        self.value = value.clamp(0., self.max as f32);
+        cx.notify();
+    }
+
+    /// Return the value of the rating.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    fn step(&self) -> f32 {
+        if self.half {
+            0.5
+        } else {
+            1.
+        }
+    }
+
+    /// Set the value by the user, and emit the change event.
+    fn update_value(&mut self, value: f32, cx: &mut ViewContext<Self>) {
+        if self.readonly {
+            return;
+        }
+
+        let value = snap(value, self.half, self.max);
+        if value != self.value {
+            self.value = value;
+            cx.emit(RatingEvent::Change(value));
+        }
+        cx.notify();
+    }
+
+    fn on_action_increment(&mut self, _: &Increment, cx: &mut ViewContext<Self>) {
+        self.update_value(self.value + self.step(), cx);
+    }
+
+    fn on_action_decrement(&mut self, _: &Decrement, cx: &mut ViewContext<Self>) {
+        self.update_value(self.value - self.step(), cx);
+    }
+
+    fn on_action_clear(&mut self, _: &Clear, cx: &mut ViewContext<Self>) {
+        self.update_value(0., cx);
+    }
+
+    fn on_action_fill(&mut self, _: &Fill, cx: &mut ViewContext<Self>) {
+        self.update_value(self.max as f32, cx);
+    }
+
+    fn icon_size(&self) -> Pixels {
+        match self.size {
+            Size::Size(size) => size,
+            Size::XSmall => px(12.),
+            Size::Small => px(16.),
+            Size::Medium => px(20.),
+            Size::Large => px(24.),
+        }
+    }
+
+    /// The hit areas of the star to hover and click, `(left, width, value)` in relative.
+    fn star_targets(&self, ix: usize) -> Vec<(f32, f32, f32)> {
+        let ix = ix as f32;
+        if self.half {
+            vec![(0., 0.5, ix + 0.5), (0.5, 0.5, ix + 1.)]
+        } else {
+            vec![(0., 1., ix + 1.)]
+        }
+    }
+
+    fn render_star(&self, ix: usize, value: f32, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let size = self.icon_size();
+        let fill = star_fill(value, ix);
+        let color = self.color.unwrap_or_else(crate::yellow_500);
+        let empty_color = cx.theme().muted_foreground.opacity(0.3);
+
+        div()
+            .relative()
+            .flex_shrink_0()
+            .size(size)
+            .child(self.icon.clone().size(size).text_color(empty_color))
+            .when(fill > 0., |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .h_full()
+                        .w(relative(fill))
+                        .overflow_hidden()
+                        .child(self.icon.clone().size(size).text_color(color)),
+                )
+            })
+            .when(!self.readonly, |this| {
+                this.cursor_pointer()
+                    .children(self.star_targets(ix).into_iter().enumerate().map(
+                        |(part, (left, width, value))| {
+                            div()
+                                .id(("rating-star", ix * 2 + part))
+                                .absolute()
+                                .top_0()
+                                .h_full()
+                                .left(relative(left))
+                                .w(relative(width))
+                                .on_hover(cx.listener(move |this, hovered: &bool, cx| {
+                                    if *hovered {
+                                        this.hovered_value = Some(value);
+                                    } else if this.hovered_value == Some(value) {
+                                        this.hovered_value = None;
+                                    }
+                                    cx.notify();
+                                }))
+                                .on_click(cx.listener(move |this, _, cx| {
+                                    this.update_value(value, cx);
+                                }))
+                        },
+                    ))
+            })
+    }
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sizable for Rating {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl EventEmitter<RatingEvent> for Rating {}
+
+impl Render for Rating {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let value = self
+            .hovered_value
+            .filter(|_| !self.readonly)
+            .unwrap_or(self.value);
+
+        h_flex()
+            .id("rating")
+            .gap_1()
+            .when(!self.readonly, |this| {
+                this.key_context(CONTEXT)
+                    .track_focus(&focus_handle)
+                    .on_action(cx.listener(Self::on_action_increment))
+                    .on_action(cx.listener(Self::on_action_decrement))
+                    .on_action(cx.listener(Self::on_action_clear))
+                    .on_action(cx.listener(Self::on_action_fill))
+            })
+            .children((0..self.max).map(|ix| self.render_star(ix, value, cx)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{snap, star_fill};
+
+    #[test]
+    fn test_star_fill() {
+        assert_eq!(star_fill(3.5, 2), 1.);
+        assert_eq!(star_fill(3.5, 3), 0.5);
+        assert_eq!(star_fill(3.5, 4), 0.);
+        assert_eq!(star_fill(0., 0), 0.);
+    }
+
+    #[test]
+    fn test_snap() {
+        assert_eq!(snap(3.3, false, 5), 3.);
+        assert_eq!(snap(3.3, true, 5), 3.5);
+        assert_eq!(snap(3.2, true, 5), 3.);
+        assert_eq!(snap(-1., true, 5), 0.);
+        assert_eq!(snap(6.5, false, 5), 5.);
+    }
+}
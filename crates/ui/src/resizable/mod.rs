@@ -3,7 +3,8 @@ use gpui::{Axis, ViewContext};
 mod panel;
 mod resize_handle;
 pub use panel::*;
-pub(crate) use resize_handle::*;
+pub use resize_handle::{resize_handle, ResizableHandle};
+pub(crate) use resize_handle::{HANDLE_PADDING, HANDLE_SIZE};
 
 pub fn h_resizable(cx: &mut ViewContext<ResizablePanelGroup>) -> ResizablePanelGroup {
     ResizablePanelGroup::new(cx).axis(Axis::Horizontal)
@@ -1,13 +1,13 @@
 use std::rc::Rc;
 
 use gpui::{
-    canvas, div, prelude::FluentBuilder, px, relative, Along, AnyElement, AnyView, Axis, Bounds,
-    Element, Entity, EntityId, EventEmitter, IntoElement, MouseMoveEvent, MouseUpEvent,
-    ParentElement, Pixels, Render, StatefulInteractiveElement as _, Style, Styled, View,
-    ViewContext, VisualContext as _, WindowContext,
+    canvas, div, prelude::FluentBuilder, px, relative, Along, AnyElement, AnyView, AppContext,
+    Axis, Bounds, ClickEvent, Element, Entity, EntityId, EventEmitter, IntoElement, MouseMoveEvent,
+    MouseUpEvent, ParentElement, Pixels, Render, StatefulInteractiveElement as _, Style, Styled,
+    View, ViewContext, VisualContext as _, WindowContext,
 };
 
-use crate::{h_flex, v_flex, AxisExt};
+use crate::{button::Button, h_flex, v_flex, AxisExt, IconName, Sizable as _};
 
 use super::resize_handle;
 
@@ -28,6 +28,7 @@ pub struct ResizablePanelGroup {
     size: Option<Pixels>,
     bounds: Bounds<Pixels>,
     resizing_panel_ix: Option<usize>,
+    equalize_on_double_click: bool,
 }
 
 impl ResizablePanelGroup {
@@ -39,6 +40,7 @@ impl ResizablePanelGroup {
             size: None,
             bounds: Bounds::default(),
             resizing_panel_ix: None,
+            equalize_on_double_click: false,
         }
     }
 
@@ -53,6 +55,14 @@ impl ResizablePanelGroup {
         self
     }
 
+    /// Double-click the resize handle to give all the panels equal sizes, instead of collapsing, default is false.
+    ///
+    /// The collapsed panel next to the handle is still restored first.
+    pub fn equalize_on_double_click(mut self, equalize: bool) -> Self {
+        self.equalize_on_double_click = equalize;
+        self
+    }
+
     pub(crate) fn set_axis(&mut self, axis: Axis, cx: &mut ViewContext<Self>) {
         self.axis = axis;
         cx.notify();
@@ -88,6 +98,160 @@ impl ResizablePanelGroup {
         self.sizes.clone()
     }
 
+    /// Returns true if the panel at the `ix` keeps its pixel size when the group is resized.
+    pub fn is_fixed(&self, ix: usize, cx: &AppContext) -> bool {
+        self.panels
+            .get(ix)
+            .map_or(false, |panel| panel.read(cx).fixed)
+    }
+
+    /// Set the panel at the `ix` to keep its pixel size (true) or resize with the group (false).
+    pub fn set_fixed(&mut self, ix: usize, fixed: bool, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.panels.get(ix) else {
+            return;
+        };
+
+        panel.update(cx, |panel, cx| {
+            panel.fixed = fixed;
+            cx.notify();
+        });
+        cx.emit(ResizablePanelEvent::Resized);
+        cx.notify();
+    }
+
+    /// Returns the size ratios of the panels, the collapsed panel is `0.`.
+    ///
+    /// Persist it to restore the layout by [`Self::set_ratios`] next time.
+    pub fn ratios(&self, cx: &AppContext) -> Vec<f32> {
+        let total_size = self.total_size();
+        let count = self.panels.len() as f32;
+
+        self.panels
+            .iter()
+            .zip(self.sizes.iter())
+            .map(|(panel, size)| {
+                let panel = panel.read(cx);
+                if panel.collapsed {
+                    0.
+                } else if total_size > px(0.) {
+                    *size / total_size
+                } else {
+                    // Not laid out yet.
+                    panel.size_ratio.unwrap_or(1. / count)
+                }
+            })
+            .collect()
+    }
+
+    /// Restore the size ratios of the panels that returned by [`Self::ratios`], `0.` to collapse the panel.
+    pub fn set_ratios(&mut self, ratios: &[f32], cx: &mut ViewContext<Self>) {
+        for (panel, ratio) in self.panels.iter().zip(ratios.iter().copied()) {
+            panel.update(cx, |panel, cx| {
+                if ratio > 0. {
+                    panel.collapsed = false;
+                    panel.size_ratio = Some(ratio);
+                } else {
+                    panel.collapsed = true;
+                }
+                cx.notify();
+            });
+        }
+        cx.notify();
+    }
+
+    /// Returns true if the panel at `ix` is collapsed.
+    pub fn is_collapsed(&self, ix: usize, cx: &AppContext) -> bool {
+        self.panels
+            .get(ix)
+            .map_or(false, |panel| panel.read(cx).collapsed)
+    }
+
+    /// Collapse the panel at `ix` to zero size, or restore it if it is collapsed.
+    pub fn toggle_collapsed(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.panels.get(ix) else {
+            return;
+        };
+
+        panel.update(cx, |panel, cx| {
+            panel.collapsed = !panel.collapsed;
+            cx.notify();
+        });
+        cx.emit(ResizablePanelEvent::Resized);
+        cx.notify();
+    }
+
+    /// Give all the expanded panels equal sizes.
+    pub fn equalize(&mut self, cx: &mut ViewContext<Self>) {
+        let count = self
+            .panels
+            .iter()
+            .filter(|panel| !panel.read(cx).collapsed)
+            .count();
+        if count == 0 {
+            return;
+        }
+
+        for panel in self.panels.iter() {
+            panel.update(cx, |panel, cx| {
+                if !panel.collapsed {
+                    panel.size = None;
+                    panel.size_ratio = Some(1. / count as f32);
+                    cx.notify();
+                }
+            });
+        }
+        cx.emit(ResizablePanelEvent::Resized);
+        cx.notify();
+    }
+
+    /// Returns the index of the collapsed panel next to the handle `ix`.
+    fn collapsed_by_handle(&self, ix: usize, cx: &AppContext) -> Option<usize> {
+        [ix, ix + 1]
+            .into_iter()
+            .find(|&ix| self.is_collapsed(ix, cx))
+    }
+
+    /// Double-click the handle `ix` to restore the collapsed panel next to it,
+    /// then equalize the panels if `equalize_on_double_click`,
+    /// or collapse the collapsible one, the panel before the handle first.
+    fn toggle_collapsed_by_handle(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if self.collapsed_by_handle(ix, cx).is_none() && self.equalize_on_double_click {
+            self.equalize(cx);
+            return;
+        }
+
+        let candidates = [ix, ix + 1];
+        let target = self.collapsed_by_handle(ix, cx).or_else(|| {
+            candidates.into_iter().find(|&ix| {
+                self.panels
+                    .get(ix)
+                    .map_or(false, |panel| panel.read(cx).collapsible)
+            })
+        });
+
+        if let Some(ix) = target {
+            self.toggle_collapsed(ix, cx);
+        }
+    }
+
+    /// Grow the panel at `ix` by the `delta` size, negative to shrink, e.g.: resize by the keyboard.
+    ///
+    /// The last panel is resized by moving the handle before it.
+    pub fn resize_panel_by(&mut self, ix: usize, delta: Pixels, cx: &mut ViewContext<Self>) {
+        if self.panels.len() < 2 || ix >= self.panels.len() {
+            return;
+        }
+
+        self.sync_real_panel_sizes(cx);
+        if ix == self.panels.len() - 1 {
+            self.resize_panels(ix - 1, self.sizes[ix - 1] - delta, cx);
+        } else {
+            self.resize_panels(ix, self.sizes[ix] + delta, cx);
+        }
+        self.done_resizing(cx);
+        cx.notify();
+    }
+
     /// Calculates the sum of all panel sizes within the group.
     pub fn total_size(&self) -> Pixels {
         self.sizes.iter().fold(px(0.0), |acc, &size| acc + size)
@@ -124,9 +288,11 @@ impl ResizablePanelGroup {
         let old_panel = self.panels[ix].clone();
         let old_panel_initial_size = old_panel.read(cx).initial_size;
         let old_panel_size_ratio = old_panel.read(cx).size_ratio;
+        let old_panel_fixed = old_panel.read(cx).fixed;
 
         panel.initial_size = old_panel_initial_size;
         panel.size_ratio = old_panel_size_ratio;
+        panel.fixed = old_panel_fixed;
         panel.axis = self.axis;
         panel.group = Some(cx.view().clone());
         self.sizes[ix] = panel.initial_size.unwrap_or_default();
@@ -148,17 +314,54 @@ impl ResizablePanelGroup {
 
     fn render_resize_handle(&self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let view = cx.view().clone();
-        resize_handle(("resizable-handle", ix), self.axis).on_drag(
-            DragPanel((cx.entity_id(), ix, self.axis)),
-            move |drag_panel, cx| {
-                cx.stop_propagation();
-                // Set current resizing panel ix
-                view.update(cx, |view, _| {
-                    view.resizing_panel_ix = Some(ix);
-                });
-                cx.new_view(|_| drag_panel.clone())
-            },
-        )
+        let collapsed_ix = self.collapsed_by_handle(ix, cx);
+
+        resize_handle(("resizable-handle", ix), self.axis)
+            // The button to restore the collapsed panel.
+            .when_some(collapsed_ix, |this, collapsed_ix| {
+                let icon = match (self.axis, collapsed_ix == ix) {
+                    (Axis::Horizontal, true) => IconName::ChevronRight,
+                    (Axis::Horizontal, false) => IconName::ChevronLeft,
+                    (Axis::Vertical, true) => IconName::ChevronDown,
+                    (Axis::Vertical, false) => IconName::ChevronUp,
+                };
+
+                this.child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .size_full()
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .child(
+                            Button::new(("restore-panel", ix))
+                                .icon(icon)
+                                .xsmall()
+                                .on_click(cx.listener(move |view, _, cx| {
+                                    cx.stop_propagation();
+                                    view.toggle_collapsed(collapsed_ix, cx);
+                                })),
+                        ),
+                )
+            })
+            .on_drag(
+                DragPanel((cx.entity_id(), ix, self.axis)),
+                move |drag_panel, cx| {
+                    cx.stop_propagation();
+                    // Set current resizing panel ix
+                    view.update(cx, |view, _| {
+                        view.resizing_panel_ix = Some(ix);
+                    });
+                    cx.new_view(|_| drag_panel.clone())
+                },
+            )
+            .on_click(cx.listener(move |view, e: &ClickEvent, cx| {
+                if e.up.click_count == 2 {
+                    view.toggle_collapsed_by_handle(ix, cx);
+                }
+            }))
     }
 
     fn done_resizing(&mut self, cx: &mut ViewContext<Self>) {
@@ -180,11 +383,38 @@ impl ResizablePanelGroup {
         if ix >= self.panels.len() - 1 {
             return;
         }
-        let size = size.floor();
         let container_size = self.bounds.size.along(self.axis);
+        let min_sizes: Vec<Pixels> = self
+            .panels
+            .iter()
+            .map(|panel| panel.read(cx).current_min_size())
+            .collect();
+        let max_size = self.panels[ix].read(cx).max_size;
+        let size = size
+            .floor()
+            .min(max_size.unwrap_or(container_size))
+            .max(px(0.));
+
+        // Drag the collapsed panel to restore it.
+        if self.panels[ix].read(cx).collapsed {
+            if size < self.panels[ix].read(cx).min_size {
+                return;
+            }
+            self.panels[ix].update(cx, |panel, _| panel.collapsed = false);
+        }
 
         self.sync_real_panel_sizes(cx);
 
+        // Drag the collapsible panel far below its min size to collapse it.
+        let next_size = self.sizes[ix] + self.sizes[ix + 1] - size;
+        for (target, target_size) in [(ix, size), (ix + 1, next_size)] {
+            let panel = self.panels[target].read(cx);
+            if panel.collapsible && !panel.collapsed && target_size < panel.min_size * 0.5 {
+                self.toggle_collapsed(target, cx);
+                return;
+            }
+        }
+
         let mut changed = size - self.sizes[ix];
         let is_expand = changed > px(0.);
 
@@ -197,20 +427,20 @@ impl ResizablePanelGroup {
             // Now to expand logic is correct.
             while changed > px(0.) && ix < self.panels.len() - 1 {
                 ix += 1;
-                let available_size = (new_sizes[ix] - PANEL_MIN_SIZE).max(px(0.));
+                let available_size = (new_sizes[ix] - min_sizes[ix]).max(px(0.));
                 let to_reduce = changed.min(available_size);
                 new_sizes[ix] -= to_reduce;
                 changed -= to_reduce;
             }
         } else {
-            let new_size = size.max(PANEL_MIN_SIZE);
+            let new_size = size.max(min_sizes[ix]);
             new_sizes[ix] = new_size;
-            changed = size - PANEL_MIN_SIZE;
+            changed = size - min_sizes[ix];
             new_sizes[ix + 1] += self.sizes[ix] - new_size;
 
             while changed < px(0.) && ix > 0 {
                 ix -= 1;
-                let available_size = self.sizes[ix] - PANEL_MIN_SIZE;
+                let available_size = self.sizes[ix] - min_sizes[ix];
                 let to_increase = (changed).min(available_size);
                 new_sizes[ix] += to_increase;
                 changed += to_increase;
@@ -221,7 +451,7 @@ impl ResizablePanelGroup {
         let total_size: Pixels = new_sizes.iter().map(|s| s.0).sum::<f32>().into();
         if total_size > container_size {
             let overflow = total_size - container_size;
-            new_sizes[main_ix] = (new_sizes[main_ix] - overflow).max(PANEL_MIN_SIZE);
+            new_sizes[main_ix] = (new_sizes[main_ix] - overflow).max(min_sizes[main_ix]);
         }
 
         let total_size = new_sizes.iter().fold(px(0.0), |acc, &size| acc + size);
@@ -288,6 +518,12 @@ pub struct ResizablePanel {
     /// The bounds of the resizable panel, when render the bounds will be updated.
     bounds: Bounds<Pixels>,
     resize_handle: Option<AnyElement>,
+    min_size: Pixels,
+    max_size: Option<Pixels>,
+    collapsible: bool,
+    collapsed: bool,
+    /// Keep the pixel size when the group is resized, instead of the size ratio.
+    fixed: bool,
 }
 
 impl ResizablePanel {
@@ -302,6 +538,11 @@ impl ResizablePanel {
             content_view: None,
             bounds: Bounds::default(),
             resize_handle: None,
+            min_size: PANEL_MIN_SIZE,
+            max_size: None,
+            collapsible: false,
+            collapsed: false,
+            fixed: false,
         }
     }
 
@@ -324,6 +565,47 @@ impl ResizablePanel {
         self
     }
 
+    /// Set the minimum size of the panel, default is 100px.
+    pub fn min_size(mut self, size: Pixels) -> Self {
+        self.min_size = size;
+        self
+    }
+
+    /// Set the maximum size of the panel.
+    pub fn max_size(mut self, size: Pixels) -> Self {
+        self.max_size = Some(size);
+        self
+    }
+
+    /// Allow to collapse the panel to zero size by double-click the resize handle next to it, default is false.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Set the panel to be collapsed initially.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Keep the pixel size when the group is resized, e.g.: a sidebar, default is false.
+    ///
+    /// The panels are proportional by default, they keep the size ratio when the window grows.
+    /// The fixed panel can still be resized by dragging the resize handle.
+    pub fn fixed(mut self, fixed: bool) -> Self {
+        self.fixed = fixed;
+        self
+    }
+
+    fn current_min_size(&self) -> Pixels {
+        if self.collapsed {
+            px(0.)
+        } else {
+            self.min_size
+        }
+    }
+
     /// Save the real panel size, and update group sizes
     fn update_size(&mut self, bounds: Bounds<Pixels>, cx: &mut ViewContext<Self>) {
         let new_size = bounds.size.along(self.axis);
@@ -358,8 +640,15 @@ impl Render for ResizablePanel {
             .size_full()
             .relative()
             .when(self.initial_size.is_none(), |this| this.flex_shrink())
-            .when(self.axis.is_vertical(), |this| this.min_h(PANEL_MIN_SIZE))
-            .when(self.axis.is_horizontal(), |this| this.min_w(PANEL_MIN_SIZE))
+            .when(self.axis.is_vertical(), |this| this.min_h(self.min_size))
+            .when(self.axis.is_horizontal(), |this| this.min_w(self.min_size))
+            .when_some(self.max_size, |this, size| {
+                if self.axis.is_vertical() {
+                    this.max_h(size)
+                } else {
+                    this.max_w(size)
+                }
+            })
             .when_some(self.initial_size, |this, size| {
                 // The `self.size` is None, that mean the initial size for the panel, so we need set flex_shrink_0
                 // To let it keep the initial size.
@@ -369,6 +658,17 @@ impl Render for ResizablePanel {
             .when_some(self.size_ratio, |this, size_ratio| {
                 this.flex_basis(relative(size_ratio))
             })
+            .when(self.fixed, |this| {
+                this.when_some(self.size.or(self.initial_size), |this, size| {
+                    this.flex_none().flex_basis(size)
+                })
+            })
+            .when(self.collapsed, |this| {
+                this.flex_none()
+                    .flex_basis(px(0.))
+                    .min_w(px(0.))
+                    .min_h(px(0.))
+            })
             .child({
                 canvas(
                     move |bounds, cx| view.update(cx, |r, cx| r.update_size(bounds, cx)),
@@ -377,8 +677,10 @@ impl Render for ResizablePanel {
                 .absolute()
                 .size_full()
             })
-            .when_some(self.content_builder.clone(), |this, c| this.child(c(cx)))
-            .when_some(self.content_view.clone(), |this, c| this.child(c))
+            .when(!self.collapsed, |this| {
+                this.when_some(self.content_builder.clone(), |this, c| this.child(c(cx)))
+                    .when_some(self.content_view.clone(), |this, c| this.child(c))
+            })
             .when_some(self.resize_handle.take(), |this, c| this.child(c))
     }
 }
@@ -1,42 +1,54 @@
 use gpui::{
-    div, prelude::FluentBuilder as _, px, Axis, Div, ElementId, InteractiveElement, IntoElement,
-    ParentElement as _, Pixels, RenderOnce, Stateful, StatefulInteractiveElement, Styled as _,
-    WindowContext,
+    div, prelude::FluentBuilder as _, px, AnyElement, Axis, Div, ElementId, InteractiveElement,
+    IntoElement, ParentElement, Pixels, RenderOnce, Stateful, StatefulInteractiveElement,
+    Styled as _, WindowContext,
 };
 
+use smallvec::SmallVec;
+
 use crate::{theme::ActiveTheme as _, AxisExt as _};
 
 pub(crate) const HANDLE_PADDING: Pixels = px(4.);
 pub(crate) const HANDLE_SIZE: Pixels = px(1.);
 
+/// A drag handle between two resizable panes, it is placed at the start edge of the parent
+/// (left for horizontal, top for vertical) and bound the drag or double-click by the caller.
 #[derive(IntoElement)]
-pub(crate) struct ResizeHandle {
+pub struct ResizableHandle {
     base: Stateful<Div>,
     axis: Axis,
+    children: SmallVec<[AnyElement; 1]>,
 }
 
-impl ResizeHandle {
+impl ResizableHandle {
     fn new(id: impl Into<ElementId>, axis: Axis) -> Self {
         Self {
             base: div().id(id.into()),
             axis,
+            children: SmallVec::new(),
         }
     }
 }
 
 /// Create a resize handle for a resizable panel.
-pub(crate) fn resize_handle(id: impl Into<ElementId>, axis: Axis) -> ResizeHandle {
-    ResizeHandle::new(id, axis)
+pub fn resize_handle(id: impl Into<ElementId>, axis: Axis) -> ResizableHandle {
+    ResizableHandle::new(id, axis)
 }
 
-impl InteractiveElement for ResizeHandle {
+impl InteractiveElement for ResizableHandle {
     fn interactivity(&mut self) -> &mut gpui::Interactivity {
         self.base.interactivity()
     }
 }
-impl StatefulInteractiveElement for ResizeHandle {}
+impl StatefulInteractiveElement for ResizableHandle {}
+
+impl ParentElement for ResizableHandle {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
 
-impl RenderOnce for ResizeHandle {
+impl RenderOnce for ResizableHandle {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let neg_offset = -HANDLE_PADDING;
 
@@ -68,5 +80,6 @@ impl RenderOnce for ResizeHandle {
                     })
                     .when(self.axis.is_vertical(), |this| this.w_full().h(HANDLE_SIZE)),
             )
+            .children(self.children)
     }
 }
@@ -1,6 +1,7 @@
 use gpui::{
-    div, AnyView, FocusHandle, InteractiveElement, IntoElement, ParentElement as _, Render, Styled,
-    View, ViewContext, VisualContext as _, WindowContext,
+    div, prelude::FluentBuilder as _, AnyElement, AnyView, Bounds, FocusHandle, InteractiveElement,
+    IntoElement, ParentElement as _, Pixels, Point, Render, SharedString, Styled, View,
+    ViewContext, VisualContext as _, WindowContext,
 };
 use std::{
     ops::{Deref, DerefMut},
@@ -8,6 +9,7 @@ use std::{
 };
 
 use crate::{
+    dnd::{DropAnimation, DROP_ANIMATION_DURATION},
     drawer::Drawer,
     modal::Modal,
     notification::{Notification, NotificationList},
@@ -41,6 +43,30 @@ pub trait ContextModal: Sized {
     /// Closes all active Modals.
     fn close_all_modals(&mut self);
 
+    /// Opens a modeless dialog with the given `id`.
+    ///
+    /// The modeless dialog floats above the content without overlay and does not trap the focus,
+    /// so it can be opened together with other modeless dialogs and normal modals.
+    ///
+    /// If a modeless dialog with the same `id` is already opened, it will be replaced.
+    fn open_modeless<F>(&mut self, id: impl Into<SharedString>, build: F)
+    where
+        F: Fn(Modal, &mut WindowContext) -> Modal + 'static;
+
+    /// Return true, if the modeless dialog with the given `id` is opened.
+    fn has_modeless(&self, id: impl Into<SharedString>) -> bool;
+
+    /// Closes the modeless dialog with the given `id`.
+    fn close_modeless(&mut self, id: impl Into<SharedString>);
+
+    /// Animates a preview of the dropped element flying from the `from` position to the `to` bounds.
+    ///
+    /// Call this in `on_drop` with the bounds of the final position,
+    /// or with the bounds of the drag source to snap back after the drag is canceled.
+    fn animate_drop<F>(&mut self, from: Point<Pixels>, to: Bounds<Pixels>, build: F)
+    where
+        F: Fn(&mut WindowContext) -> AnyElement + 'static;
+
     /// Pushes a notification to the notification list.
     fn push_notification(&mut self, note: impl Into<Notification>);
     fn clear_notifications(&mut self);
@@ -123,6 +149,64 @@ impl<'a> ContextModal for WindowContext<'a> {
         })
     }
 
+    fn open_modeless<F>(&mut self, id: impl Into<SharedString>, build: F)
+    where
+        F: Fn(Modal, &mut WindowContext) -> Modal + 'static,
+    {
+        let id = id.into();
+        Root::update(self, move |root, cx| {
+            let modeless = ActiveModeless {
+                id: id.clone(),
+                focus_handle: cx.focus_handle(),
+                builder: Rc::new(build),
+            };
+
+            if let Some(ix) = root.active_modeless.iter().position(|m| m.id == id) {
+                root.active_modeless[ix] = modeless;
+            } else {
+                root.active_modeless.push(modeless);
+            }
+            cx.notify();
+        })
+    }
+
+    fn has_modeless(&self, id: impl Into<SharedString>) -> bool {
+        let id = id.into();
+        Root::read(&self).active_modeless.iter().any(|m| m.id == id)
+    }
+
+    fn close_modeless(&mut self, id: impl Into<SharedString>) {
+        let id = id.into();
+        Root::update(self, move |root, cx| {
+            root.active_modeless.retain(|m| m.id != id);
+            cx.notify();
+        })
+    }
+
+    fn animate_drop<F>(&mut self, from: Point<Pixels>, to: Bounds<Pixels>, build: F)
+    where
+        F: Fn(&mut WindowContext) -> AnyElement + 'static,
+    {
+        Root::update(self, move |root, cx| {
+            let id = root.next_drop_animation_id;
+            root.next_drop_animation_id += 1;
+            root.drop_animations
+                .push(DropAnimation::new(id, from, to, build));
+            cx.notify();
+
+            cx.spawn(|view, mut cx| async move {
+                cx.background_executor()
+                    .timer(DROP_ANIMATION_DURATION)
+                    .await;
+                _ = view.update(&mut cx, |root, cx| {
+                    root.drop_animations.retain(|a| a.id != id);
+                    cx.notify();
+                });
+            })
+            .detach();
+        })
+    }
+
     fn push_notification(&mut self, note: impl Into<Notification>) {
         let note = note.into();
         Root::update(self, move |root, cx| {
@@ -179,6 +263,28 @@ impl<'a, V> ContextModal for ViewContext<'a, V> {
         self.deref_mut().close_all_modals()
     }
 
+    fn open_modeless<F>(&mut self, id: impl Into<SharedString>, build: F)
+    where
+        F: Fn(Modal, &mut WindowContext) -> Modal + 'static,
+    {
+        self.deref_mut().open_modeless(id, build)
+    }
+
+    fn has_modeless(&self, id: impl Into<SharedString>) -> bool {
+        self.deref().has_modeless(id)
+    }
+
+    fn close_modeless(&mut self, id: impl Into<SharedString>) {
+        self.deref_mut().close_modeless(id)
+    }
+
+    fn animate_drop<F>(&mut self, from: Point<Pixels>, to: Bounds<Pixels>, build: F)
+    where
+        F: Fn(&mut WindowContext) -> AnyElement + 'static,
+    {
+        self.deref_mut().animate_drop(from, to, build)
+    }
+
     fn push_notification(&mut self, note: impl Into<Notification>) {
         self.deref_mut().push_notification(note)
     }
@@ -201,6 +307,9 @@ pub struct Root {
     previous_focus_handle: Option<FocusHandle>,
     active_drawer: Option<Rc<dyn Fn(Drawer, &mut WindowContext) -> Drawer + 'static>>,
     active_modals: Vec<ActiveModal>,
+    active_modeless: Vec<ActiveModeless>,
+    drop_animations: Vec<DropAnimation>,
+    next_drop_animation_id: usize,
     pub notification: View<NotificationList>,
     child: AnyView,
 }
@@ -211,12 +320,22 @@ struct ActiveModal {
     builder: Rc<dyn Fn(Modal, &mut WindowContext) -> Modal + 'static>,
 }
 
+#[derive(Clone)]
+struct ActiveModeless {
+    id: SharedString,
+    focus_handle: FocusHandle,
+    builder: Rc<dyn Fn(Modal, &mut WindowContext) -> Modal + 'static>,
+}
+
 impl Root {
     pub fn new(child: AnyView, cx: &mut ViewContext<Self>) -> Self {
         Self {
             previous_focus_handle: None,
             active_drawer: None,
             active_modals: Vec::new(),
+            active_modeless: Vec::new(),
+            drop_animations: Vec::new(),
+            next_drop_animation_id: 0,
             notification: cx.new_view(NotificationList::new),
             child,
         }
@@ -287,34 +406,47 @@ impl Root {
             .expect("The window root view should be of type `ui::Root`.");
 
         let active_modals = root.read(cx).active_modals.clone();
+        let active_modeless = root.read(cx).active_modeless.clone();
         let mut has_overlay = false;
 
-        if active_modals.is_empty() {
+        if active_modals.is_empty() && active_modeless.is_empty() {
             return None;
         }
 
         Some(
-            div().children(active_modals.iter().enumerate().map(|(i, active_modal)| {
-                let mut modal = Modal::new(cx);
-
-                modal = (active_modal.builder)(modal, cx);
-                modal.layer_ix = i;
-                // Give the modal the focus handle, because `modal` is a temporary value, is not possible to
-                // keep the focus handle in the modal.
-                //
-                // So we keep the focus handle in the `active_modal`, this is owned by the `Root`.
-                modal.focus_handle = active_modal.focus_handle.clone();
-
-                // Keep only have one overlay, we only render the first modal with overlay.
-                if has_overlay {
+            div()
+                // The modeless dialogs are always rendered below the modals.
+                .children(active_modeless.iter().enumerate().map(|(i, active)| {
+                    let mut modal = Modal::new(cx);
+
+                    modal = (active.builder)(modal, cx);
+                    modal.layer_ix = i;
+                    modal.focus_handle = active.focus_handle.clone();
+                    modal.modeless_id = Some(active.id.clone());
                     modal.overlay_visible = false;
-                }
-                if modal.has_overlay() {
-                    has_overlay = true;
-                }
-
-                modal
-            })),
+                    modal
+                }))
+                .children(active_modals.iter().enumerate().map(|(i, active_modal)| {
+                    let mut modal = Modal::new(cx);
+
+                    modal = (active_modal.builder)(modal, cx);
+                    modal.layer_ix = i;
+                    // Give the modal the focus handle, because `modal` is a temporary value, is not possible to
+                    // keep the focus handle in the modal.
+                    //
+                    // So we keep the focus handle in the `active_modal`, this is owned by the `Root`.
+                    modal.focus_handle = active_modal.focus_handle.clone();
+
+                    // Keep only have one overlay, we only render the first modal with overlay.
+                    if has_overlay {
+                        modal.overlay_visible = false;
+                    }
+                    if modal.has_overlay() {
+                        has_overlay = true;
+                    }
+
+                    modal
+                })),
         )
     }
 }
@@ -326,5 +458,16 @@ impl Render for Root {
             .size_full()
             .text_color(cx.theme().foreground)
             .child(self.child.clone())
+            .when(!self.drop_animations.is_empty(), |this| {
+                let drop_animations = self.drop_animations.clone();
+                this.child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .size_full()
+                        .children(drop_animations.iter().map(|a| a.render(cx))),
+                )
+            })
     }
 }
@@ -1,7 +1,9 @@
 mod scrollable;
 mod scrollable_mask;
 mod scrollbar;
+mod sticky_header;
 
 pub use scrollable::*;
 pub use scrollable_mask::*;
 pub use scrollbar::*;
+pub use sticky_header::*;
@@ -2,9 +2,9 @@ use std::{cell::Cell, rc::Rc};
 
 use crate::theme::ActiveTheme;
 use gpui::{
-    fill, point, px, relative, Bounds, ContentMask, Edges, Element, EntityId, Hitbox, IntoElement,
-    MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad, Pixels, Point, Position, ScrollHandle,
-    Style, UniformListScrollHandle,
+    fill, point, px, relative, size, Bounds, ContentMask, Edges, Element, EntityId, Hitbox,
+    IntoElement, ListOffset, ListState, MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad,
+    Pixels, Point, Position, ScrollHandle, Style, UniformListScrollHandle,
 };
 
 const MIN_THUMB_SIZE: f32 = 80.;
@@ -43,6 +43,51 @@ impl ScrollHandleOffsetable for UniformListScrollHandle {
     }
 }
 
+/// The scroll handle of a variable height [`ListState`].
+///
+/// The heights of the items out of the view are unknown before they are rendered,
+/// so the offset is estimated by the `item_height`.
+#[derive(Clone)]
+pub struct ListScrollHandle {
+    state: ListState,
+    item_height: Pixels,
+}
+
+impl ListScrollHandle {
+    pub fn new(state: ListState, item_height: Pixels) -> Self {
+        Self { state, item_height }
+    }
+
+    /// The estimated size of the items.
+    fn scroll_size(&self) -> gpui::Size<Pixels> {
+        size(px(0.), self.item_height * self.state.item_count() as f32)
+    }
+}
+
+impl ScrollHandleOffsetable for ListScrollHandle {
+    fn offset(&self) -> Point<Pixels> {
+        let top = self.state.logical_scroll_top();
+        point(
+            px(0.),
+            -(self.item_height * top.item_ix as f32 + top.offset_in_item),
+        )
+    }
+
+    fn set_offset(&self, offset: Point<Pixels>) {
+        if self.item_height <= px(0.) {
+            return;
+        }
+
+        let y = (-offset.y).max(px(0.));
+        let item_ix = ((y / self.item_height).floor() as usize)
+            .min(self.state.item_count().saturating_sub(1));
+        self.state.scroll_to(ListOffset {
+            item_ix,
+            offset_in_item: (y - self.item_height * item_ix as f32).min(self.item_height),
+        });
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct ScrollbarState {
     hovered_axis: Option<ScrollbarAxis>,
@@ -237,6 +282,23 @@ impl Scrollbar {
         )
     }
 
+    /// Create vertical scrollbar for the variable height list.
+    pub fn list(
+        view_id: EntityId,
+        state: Rc<Cell<ScrollbarState>>,
+        scroll_handle: ListScrollHandle,
+    ) -> Self {
+        let scroll_size = scroll_handle.scroll_size();
+
+        Self::new(
+            view_id,
+            state,
+            ScrollbarAxis::Vertical,
+            scroll_handle,
+            scroll_size,
+        )
+    }
+
     /// Set scrollbar axis.
     pub fn axis(mut self, axis: ScrollbarAxis) -> Self {
         self.axis = axis;
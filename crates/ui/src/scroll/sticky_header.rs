@@ -0,0 +1,204 @@
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnimationExt as _, AnyElement, Div, ElementId,
+    InteractiveElement, IntoElement, ParentElement, Pixels, RenderOnce, ScrollHandle, Styled,
+    WindowContext,
+};
+use smallvec::SmallVec;
+
+use crate::{
+    animation::{ease_emphasized, motion},
+    theme::ActiveTheme,
+};
+
+/// The state of the [`StickyHeader`], keep it in the view to remember the last scroll offset.
+#[derive(Debug, Clone, Copy)]
+pub struct StickyHeaderState {
+    /// The last scrolled distance (positive value) that changed the visibility.
+    last_offset: Pixels,
+    visible: bool,
+    /// Whether the visibility has changed at least once, used to skip the initial animation.
+    changed: bool,
+}
+
+impl Default for StickyHeaderState {
+    fn default() -> Self {
+        Self {
+            last_offset: px(0.),
+            visible: true,
+            changed: false,
+        }
+    }
+}
+
+impl StickyHeaderState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the header is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Update the visibility by the scroll offset (the `y` of [`ScrollHandle::offset`], negative when scrolled down).
+    ///
+    /// - Always visible when near the top (the scrolled distance is less than the `height`).
+    /// - Hidden when scrolled down more than the `threshold`.
+    /// - Visible when scrolled up more than the `threshold`.
+    fn update(&mut self, offset_y: Pixels, height: Pixels, threshold: Pixels) {
+        let offset = -offset_y;
+        let visible = if offset <= height {
+            true
+        } else {
+            let delta = offset - self.last_offset;
+            if delta > threshold {
+                false
+            } else if delta < -threshold {
+                true
+            } else {
+                // Keep the last offset to accumulate the small deltas.
+                return;
+            }
+        };
+
+        self.last_offset = offset;
+        if self.visible != visible {
+            self.visible = visible;
+            self.changed = true;
+        }
+    }
+}
+
+/// A header (or toolbar) that linked to a scroll view,
+/// it hides when scrolling down and reappears when scrolling up or near the top.
+///
+/// The header is absolute positioned at the top of the parent, so the parent must be `relative`,
+/// and the scroll content should leave the space of the header on the top.
+#[derive(IntoElement)]
+pub struct StickyHeader {
+    id: ElementId,
+    base: Div,
+    scroll_handle: ScrollHandle,
+    state: Rc<Cell<StickyHeaderState>>,
+    height: Pixels,
+    threshold: Pixels,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl StickyHeader {
+    pub fn new(
+        id: impl Into<ElementId>,
+        scroll_handle: &ScrollHandle,
+        state: Rc<Cell<StickyHeaderState>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            scroll_handle: scroll_handle.clone(),
+            state,
+            height: px(40.),
+            threshold: px(8.),
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Set the height of the header, default: 40px
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Set the minimum scroll distance to toggle the header, default: 8px
+    pub fn threshold(mut self, threshold: impl Into<Pixels>) -> Self {
+        self.threshold = threshold.into();
+        self
+    }
+}
+
+impl Styled for StickyHeader {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for StickyHeader {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl RenderOnce for StickyHeader {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let mut state = self.state.get();
+        state.update(self.scroll_handle.offset().y, self.height, self.threshold);
+        self.state.set(state);
+
+        let visible = state.visible;
+        let height = self.height;
+
+        self.base
+            .id(self.id)
+            .absolute()
+            .top_0()
+            .left_0()
+            .right_0()
+            .h(height)
+            .occlude()
+            .bg(cx.theme().background)
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .children(self.children)
+            .map(|this| {
+                if state.changed {
+                    this.with_animation(
+                        ElementId::NamedInteger("sticky-header".into(), visible as usize),
+                        motion(Duration::from_millis(200), ease_emphasized(), cx),
+                        move |this, delta| {
+                            let progress = if visible { delta } else { 1. - delta };
+                            this.top(-height * (1. - progress)).opacity(progress)
+                        },
+                    )
+                    .into_any_element()
+                } else {
+                    this.into_any_element()
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::StickyHeaderState;
+
+    #[test]
+    fn test_update_visible() {
+        let mut state = StickyHeaderState::new();
+        let (height, threshold) = (px(40.), px(8.));
+
+        // Near the top, always visible
+        state.update(px(-30.), height, threshold);
+        assert!(state.is_visible());
+
+        // Scroll down
+        state.update(px(-100.), height, threshold);
+        assert!(!state.is_visible());
+
+        // Scroll up with a small delta, keep hidden
+        state.update(px(-95.), height, threshold);
+        assert!(!state.is_visible());
+
+        // Accumulated delta is greater than the threshold
+        state.update(px(-90.), height, threshold);
+        assert!(state.is_visible());
+
+        // Back to the top
+        state.update(px(-200.), height, threshold);
+        assert!(!state.is_visible());
+        state.update(px(0.), height, threshold);
+        assert!(state.is_visible());
+    }
+}
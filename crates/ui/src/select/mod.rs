@@ -0,0 +1,6 @@
+mod multi_select;
+pub(crate) mod popup;
+mod select;
+
+pub use multi_select::*;
+pub use select::*;
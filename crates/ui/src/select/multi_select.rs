@@ -0,0 +1,265 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder, rems, AppContext, ElementId, EventEmitter, FocusHandle,
+    FocusableView, IntoElement, Length, ParentElement, Render, SharedString, Styled, ViewContext,
+    VisualContext, WindowContext,
+};
+use rust_i18n::t;
+
+use super::{
+    popup::{render_popup, SelectPopup, SelectPopupView},
+    SelectGroup, SelectListDelegate, SelectOption, SelectOwner,
+};
+use crate::{h_flex, list::List, tag::Tag, theme::ActiveTheme, Sizable, Size};
+
+pub enum MultiSelectEvent<T> {
+    /// Emitted when the selected values are changed by the user.
+    Change(Vec<T>),
+}
+
+type OnChange<T> = Rc<dyn Fn(&[T], &mut WindowContext) + 'static>;
+
+/// A Select to pick multiple typed values, the selected values are displayed as removable tags.
+///
+/// ```ignore
+/// MultiSelect::new("tags", options, cx)
+///     .select_all(true)
+///     .max_selected(3)
+/// ```
+pub struct MultiSelect<T: Clone + PartialEq + 'static> {
+    popup: SelectPopup<SelectListDelegate<T, Self>>,
+    show_select_all: bool,
+    max_selected: Option<usize>,
+    on_change: Option<OnChange<T>>,
+}
+
+impl<T: Clone + PartialEq + 'static> MultiSelect<T> {
+    pub fn new(
+        id: impl Into<ElementId>,
+        options: impl IntoIterator<Item = SelectOption<T>>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        Self::grouped(id, vec![SelectGroup::ungrouped(options)], cx)
+    }
+
+    /// Create a MultiSelect with the option groups.
+    pub fn grouped(
+        id: impl Into<ElementId>,
+        groups: impl IntoIterator<Item = SelectGroup<T>>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let delegate = SelectListDelegate::new(cx.view().downgrade(), groups, true);
+        let list = cx.new_view(|cx| List::new(delegate, cx).max_h(rems(20.)));
+
+        let mut popup = SelectPopup::new(id, list, cx);
+        popup.multiple = true;
+
+        Self {
+            popup,
+            show_select_all: false,
+            max_selected: None,
+            on_change: None,
+        }
+    }
+
+    /// Set true to show the `Select all` option on the top of the menu.
+    pub fn select_all(mut self, show: bool) -> Self {
+        self.show_select_all = show;
+        self
+    }
+
+    /// Set the max number of the selected values, the other options are disabled when reached.
+    pub fn max_selected(mut self, max: usize) -> Self {
+        self.max_selected = Some(max);
+        self
+    }
+
+    /// Set the width of the select input, default: Length::Auto
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.popup.width = width.into();
+        self
+    }
+
+    /// Set the width of the select menu, default: Length::Auto
+    pub fn menu_width(mut self, width: impl Into<Length>) -> Self {
+        self.popup.menu_width = width.into();
+        self
+    }
+
+    /// Set the placeholder for display when no value is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.popup.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set true to show the clear button when the values are not empty.
+    pub fn cleanable(mut self) -> Self {
+        self.popup.cleanable = true;
+        self
+    }
+
+    /// Set the disable state for the select.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.popup.disabled = disabled;
+        self
+    }
+
+    /// Set the handler to be called when the values are changed by the user.
+    pub fn on_change(mut self, handler: impl Fn(&[T], &mut WindowContext) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Returns the selected values.
+    pub fn values<'a>(&self, cx: &'a AppContext) -> &'a [T] {
+        &self.popup.list.read(cx).delegate().values
+    }
+
+    /// Set the selected values, this will not emit the change event.
+    pub fn set_values(&mut self, values: Vec<T>, cx: &mut ViewContext<Self>) {
+        self.popup.list.update(cx, |list, cx| {
+            list.delegate_mut().values = values;
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// Replace the option groups, the selected values are kept.
+    pub fn set_groups(
+        &mut self,
+        groups: impl IntoIterator<Item = SelectGroup<T>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let groups = groups.into_iter().collect::<Vec<_>>();
+        self.popup.list.update(cx, |list, cx| {
+            list.delegate_mut().groups = groups;
+            list.delegate_mut().update_rows("");
+            list.set_query("", cx);
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    pub fn focus(&self, cx: &mut WindowContext) {
+        self.popup.focus(cx);
+    }
+
+    fn remove(&mut self, value: &T, cx: &mut ViewContext<Self>) {
+        let mut values = self.values(cx).to_vec();
+        values.retain(|v| v != value);
+        self.change(values, cx);
+    }
+
+    fn render_tags(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let delegate = self.popup.list.read(cx).delegate();
+        let selected = delegate
+            .values
+            .iter()
+            .filter_map(|value| {
+                delegate
+                    .find(value)
+                    .map(|option| (value.clone(), option.label()))
+            })
+            .collect::<Vec<_>>();
+
+        if selected.is_empty() {
+            return div()
+                .text_color(cx.theme().accent_foreground)
+                .when(self.popup.disabled, |this| {
+                    this.text_color(cx.theme().muted_foreground)
+                })
+                .child(
+                    self.popup
+                        .placeholder
+                        .clone()
+                        .unwrap_or_else(|| t!("Select.placeholder").into()),
+                );
+        }
+
+        let disabled = self.popup.disabled;
+        h_flex()
+            .flex_wrap()
+            .gap_1()
+            .children(
+                selected
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, (value, label))| {
+                        Tag::new(("tag", ix))
+                            .small()
+                            .child(label)
+                            .when(!disabled, |this| {
+                                this.on_remove(
+                                    cx.listener(move |this, _, cx| this.remove(&value, cx)),
+                                )
+                            })
+                    }),
+            )
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> SelectPopupView for MultiSelect<T> {
+    type Delegate = SelectListDelegate<T, Self>;
+
+    fn popup(&self) -> &SelectPopup<Self::Delegate> {
+        &self.popup
+    }
+
+    fn popup_mut(&mut self) -> &mut SelectPopup<Self::Delegate> {
+        &mut self.popup
+    }
+
+    fn on_open(&mut self, cx: &mut ViewContext<Self>) {
+        let (show_select_all, max_selected) = (self.show_select_all, self.max_selected);
+        self.popup.list.update(cx, |list, cx| {
+            let delegate = list.delegate_mut();
+            delegate.show_select_all = show_select_all;
+            delegate.max_selected = max_selected;
+            delegate.update_rows("");
+            list.set_query("", cx);
+        });
+    }
+
+    fn on_clean(&mut self, cx: &mut ViewContext<Self>) {
+        self.change(vec![], cx);
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> SelectOwner<T> for MultiSelect<T> {
+    fn change(&mut self, values: Vec<T>, cx: &mut ViewContext<Self>) {
+        self.popup.list.update(cx, |list, cx| {
+            list.delegate_mut().values = values.clone();
+            cx.notify();
+        });
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(&values, cx);
+        }
+        cx.emit(MultiSelectEvent::Change(values));
+        cx.notify();
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Sizable for MultiSelect<T> {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.popup.size = size.into();
+        self
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> EventEmitter<MultiSelectEvent<T>> for MultiSelect<T> {}
+impl<T: Clone + PartialEq + 'static> FocusableView for MultiSelect<T> {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.popup.focus_handle(cx)
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Render for MultiSelect<T> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let show_clean =
+            self.popup.cleanable && !self.values(cx).is_empty() && !self.popup.disabled;
+        let title = self.render_tags(cx);
+
+        render_popup(self, title, show_clean, cx)
+    }
+}
@@ -0,0 +1,315 @@
+use gpui::{
+    anchored, canvas, deferred, div, prelude::FluentBuilder, px, AppContext, Bounds, ClickEvent,
+    ElementId, FocusHandle, FocusableView, InteractiveElement, IntoElement, Length, ParentElement,
+    Pixels, SharedString, StatefulInteractiveElement, Styled, View, ViewContext, WindowContext,
+};
+
+use crate::{
+    dropdown::{Down, Enter, Escape, Up, CONTEXT},
+    h_flex,
+    input::ClearButton,
+    list::{self, List, ListDelegate},
+    theme::ActiveTheme,
+    v_flex, Disableable, Icon, IconName, Size, StyleSized,
+};
+
+/// The trigger input and the popup menu with a [`List`], shared by the [`crate::dropdown::Dropdown`],
+/// [`super::Select`] and [`super::MultiSelect`].
+pub(crate) struct SelectPopup<D: ListDelegate> {
+    pub(crate) id: ElementId,
+    pub(crate) focus_handle: FocusHandle,
+    pub(crate) list: View<List<D>>,
+    pub(crate) open: bool,
+    pub(crate) size: Size,
+    /// The right icon of the input, instead of the default arrow icon.
+    pub(crate) icon: Option<IconName>,
+    pub(crate) placeholder: Option<SharedString>,
+    pub(crate) cleanable: bool,
+    pub(crate) disabled: bool,
+    /// Set true to let the input grow with the wrapped content, e.g.: the tags.
+    pub(crate) multiple: bool,
+    pub(crate) width: Length,
+    pub(crate) menu_width: Length,
+    /// Store the bounds of the input
+    bounds: Bounds<Pixels>,
+}
+
+impl<D: ListDelegate> SelectPopup<D> {
+    /// Create the popup, the menu is closed when both the input and the list are blurred.
+    pub(crate) fn new<V>(
+        id: impl Into<ElementId>,
+        list: View<List<D>>,
+        cx: &mut ViewContext<V>,
+    ) -> Self
+    where
+        V: SelectPopupView<Delegate = D>,
+    {
+        let focus_handle = cx.focus_handle();
+        cx.on_blur(&list.focus_handle(cx), V::on_blur).detach();
+        cx.on_blur(&focus_handle, V::on_blur).detach();
+
+        Self {
+            id: id.into(),
+            focus_handle,
+            list,
+            open: false,
+            size: Size::Medium,
+            icon: None,
+            placeholder: None,
+            cleanable: false,
+            disabled: false,
+            multiple: false,
+            width: Length::Auto,
+            menu_width: Length::Auto,
+            bounds: Bounds::default(),
+        }
+    }
+
+    /// The list is focused when the menu is open.
+    pub(crate) fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        if self.open {
+            self.list.focus_handle(cx)
+        } else {
+            self.focus_handle.clone()
+        }
+    }
+
+    pub(crate) fn focus(&self, cx: &mut WindowContext) {
+        self.focus_handle.focus(cx);
+    }
+
+    /// Close the menu and focus back to the input, called by the list delegate.
+    pub(crate) fn close(&mut self, cx: &mut WindowContext) {
+        self.open = false;
+        self.focus(cx);
+    }
+}
+
+/// The view with a [`SelectPopup`], to share the keyboard handling and the rendering.
+pub(crate) trait SelectPopupView: Sized + 'static {
+    type Delegate: ListDelegate;
+
+    fn popup(&self) -> &SelectPopup<Self::Delegate>;
+
+    fn popup_mut(&mut self) -> &mut SelectPopup<Self::Delegate>;
+
+    /// Called before the menu is opened, e.g.: to reset the query.
+    fn on_open(&mut self, _: &mut ViewContext<Self>) {}
+
+    /// Called when the clear button is clicked.
+    fn on_clean(&mut self, cx: &mut ViewContext<Self>);
+
+    fn open_menu(&mut self, cx: &mut ViewContext<Self>) {
+        if self.popup().disabled {
+            return;
+        }
+
+        self.on_open(cx);
+        let popup = self.popup_mut();
+        popup.open = true;
+        popup.list.focus_handle(cx).focus(cx);
+        cx.notify();
+    }
+
+    fn on_blur(&mut self, cx: &mut ViewContext<Self>) {
+        // When the input and the menu are both not focused, close the menu.
+        let popup = self.popup_mut();
+        if popup.list.focus_handle(cx).is_focused(cx) || popup.focus_handle.is_focused(cx) {
+            return;
+        }
+
+        popup.open = false;
+        cx.notify();
+    }
+
+    fn up(&mut self, _: &Up, cx: &mut ViewContext<Self>) {
+        if !self.popup().open {
+            return;
+        }
+        self.popup().list.focus_handle(cx).focus(cx);
+        cx.dispatch_action(Box::new(list::SelectPrev));
+    }
+
+    fn down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        if !self.popup().open {
+            self.open_menu(cx);
+        }
+
+        self.popup().list.focus_handle(cx).focus(cx);
+        cx.dispatch_action(Box::new(list::SelectNext));
+    }
+
+    fn enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
+        // Propagate the event to the parent view, for example to the Modal to support ENTER to confirm.
+        cx.propagate();
+
+        if !self.popup().open {
+            self.open_menu(cx);
+        } else {
+            self.popup().list.focus_handle(cx).focus(cx);
+            cx.dispatch_action(Box::new(list::Confirm));
+        }
+    }
+
+    fn escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
+        // Propagate the event to the parent view, for example to the Modal to support ESC to close.
+        cx.propagate();
+
+        self.popup_mut().open = false;
+        cx.notify();
+    }
+
+    fn toggle_menu(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        cx.stop_propagation();
+        self.open_menu(cx);
+    }
+
+    fn clean(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        cx.stop_propagation();
+        self.on_clean(cx);
+    }
+}
+
+/// Render the input with the `title`, and the menu if it's open.
+pub(crate) fn render_popup<V: SelectPopupView>(
+    this: &V,
+    title: impl IntoElement,
+    show_clean: bool,
+    cx: &mut ViewContext<V>,
+) -> impl IntoElement {
+    let view = cx.view().clone();
+    let popup = this.popup();
+    let is_focused = popup.focus_handle.is_focused(cx);
+    let (open, disabled, size) = (popup.open, popup.disabled, popup.size);
+    let allow_open = !(open || disabled);
+    let outline_visible = open || is_focused && !disabled;
+    let bounds = popup.bounds;
+
+    // If the size has change, set size to the list, to change the QueryInput size.
+    if popup.list.read(cx).size != size {
+        popup.list.update(cx, |list, cx| list.set_size(size, cx))
+    }
+
+    div()
+        .id(popup.id.clone())
+        .key_context(CONTEXT)
+        .track_focus(&popup.focus_handle)
+        .on_action(cx.listener(V::up))
+        .on_action(cx.listener(V::down))
+        .on_action(cx.listener(V::enter))
+        .on_action(cx.listener(V::escape))
+        .size_full()
+        .relative()
+        .input_text_size(size)
+        .child(
+            div()
+                .id("dropdown-input")
+                .relative()
+                .flex()
+                .items_center()
+                .justify_between()
+                .bg(cx.theme().background)
+                .border_1()
+                .border_color(cx.theme().input)
+                .rounded(px(cx.theme().radius))
+                .when(cx.theme().shadow, |this| this.shadow_sm())
+                .map(|this| {
+                    if disabled {
+                        this.cursor_not_allowed()
+                    } else {
+                        this.cursor_pointer()
+                    }
+                })
+                .overflow_hidden()
+                .input_text_size(size)
+                .map(|this| match popup.width {
+                    Length::Definite(l) => this.flex_none().w(l),
+                    Length::Auto => this.w_full(),
+                })
+                .when(outline_visible, |this| this.outline(cx))
+                .map(|this| {
+                    if popup.multiple {
+                        this.input_px(size).py_1().min_h_8()
+                    } else {
+                        this.input_size(size)
+                    }
+                })
+                .when(allow_open, |this| {
+                    this.on_click(cx.listener(V::toggle_menu))
+                })
+                .child(
+                    h_flex()
+                        .w_full()
+                        .items_center()
+                        .justify_between()
+                        .gap_1()
+                        .child(div().flex_1().overflow_hidden().child(title).when(
+                            disabled,
+                            |this| {
+                                this.cursor_not_allowed()
+                                    .text_color(cx.theme().muted_foreground)
+                            },
+                        ))
+                        .map(|this| {
+                            if show_clean {
+                                this.child(ClearButton::new(cx).map(|this| {
+                                    if disabled {
+                                        this.disabled(true)
+                                    } else {
+                                        this.on_click(cx.listener(V::clean))
+                                    }
+                                }))
+                            } else {
+                                let icon = match popup.icon.clone() {
+                                    Some(icon) => icon,
+                                    None if open => IconName::ChevronUp,
+                                    None => IconName::ChevronDown,
+                                };
+
+                                this.child(
+                                    Icon::new(icon)
+                                        .text_color(cx.theme().muted_foreground)
+                                        .when(disabled, |this| this.cursor_not_allowed()),
+                                )
+                            }
+                        }),
+                )
+                .child(
+                    canvas(
+                        move |bounds, cx| view.update(cx, |r, _| r.popup_mut().bounds = bounds),
+                        |_, _, _| {},
+                    )
+                    .absolute()
+                    .size_full(),
+                ),
+        )
+        .when(open, |this| {
+            this.child(
+                deferred(
+                    anchored().snap_to_window_with_margin(px(8.)).child(
+                        div()
+                            .occlude()
+                            .map(|this| match popup.menu_width {
+                                Length::Auto => this.w(bounds.size.width),
+                                Length::Definite(w) => this.w(w),
+                            })
+                            .child(
+                                v_flex()
+                                    .occlude()
+                                    .mt_1p5()
+                                    .bg(cx.theme().background)
+                                    .border_1()
+                                    .border_color(cx.theme().border)
+                                    .rounded(px(cx.theme().radius))
+                                    .shadow_md()
+                                    .child(popup.list.clone()),
+                            )
+                            .on_mouse_down_out(cx.listener(|this: &mut V, _, cx| {
+                                this.escape(&Escape, cx);
+                            })),
+                    ),
+                )
+                .with_priority(1),
+            )
+        })
+}
@@ -0,0 +1,679 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder, px, rems, AppContext, ElementId, EventEmitter, FocusHandle,
+    FocusableView, IntoElement, Length, ParentElement, Render, SharedString, Styled, Task,
+    ViewContext, VisualContext, WeakView, WindowContext,
+};
+use rust_i18n::t;
+
+use super::popup::{render_popup, SelectPopup, SelectPopupView};
+use crate::{
+    checkbox::Checkbox,
+    h_flex,
+    list::{List, ListDelegate, ListItem},
+    theme::ActiveTheme,
+    Disableable as _, Icon, IconName, Sizable, Size, StyleSized, StyledExt,
+};
+
+/// An option of the [`Select`], with a typed value and a label to display.
+#[derive(Clone)]
+pub struct SelectOption<T> {
+    value: T,
+    label: SharedString,
+    disabled: bool,
+}
+
+impl<T> SelectOption<T> {
+    pub fn new(value: T, label: impl Into<SharedString>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    /// Set true to make the option unselectable.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn label(&self) -> SharedString {
+        self.label.clone()
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+/// A group of the [`SelectOption`]s, displayed with a group header in the menu.
+#[derive(Clone)]
+pub struct SelectGroup<T> {
+    pub(super) label: Option<SharedString>,
+    pub(super) options: Vec<SelectOption<T>>,
+}
+
+impl<T> SelectGroup<T> {
+    /// The options without the group header.
+    pub(super) fn ungrouped(options: impl IntoIterator<Item = SelectOption<T>>) -> Self {
+        Self {
+            label: None,
+            options: options.into_iter().collect(),
+        }
+    }
+
+    pub fn new(
+        label: impl Into<SharedString>,
+        options: impl IntoIterator<Item = SelectOption<T>>,
+    ) -> Self {
+        Self {
+            label: Some(label.into()),
+            options: options.into_iter().collect(),
+        }
+    }
+}
+
+/// A row of the menu, the group header or the option with the (group_ix, option_ix).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SelectRow {
+    /// The `Select all` row on the top, only in the multiple mode.
+    SelectAll,
+    Group(usize),
+    Item(usize, usize),
+}
+
+/// Flatten the groups into the rows, only keep the options matched the query.
+pub(super) fn build_rows<T>(groups: &[SelectGroup<T>], query: &str) -> Vec<SelectRow> {
+    let query = query.to_lowercase();
+    let mut rows = vec![];
+    for (group_ix, group) in groups.iter().enumerate() {
+        let matched = group
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| query.is_empty() || option.label.to_lowercase().contains(&query))
+            .map(|(option_ix, _)| SelectRow::Item(group_ix, option_ix))
+            .collect::<Vec<_>>();
+
+        if matched.is_empty() {
+            continue;
+        }
+        if group.label.is_some() {
+            rows.push(SelectRow::Group(group_ix));
+        }
+        rows.extend(matched);
+    }
+    rows
+}
+
+/// Toggle the value in the values, the value can't be added if the `max` is reached.
+///
+/// Returns true if the values changed.
+pub(super) fn toggle_value<T: PartialEq>(
+    values: &mut Vec<T>,
+    value: T,
+    max: Option<usize>,
+) -> bool {
+    if let Some(ix) = values.iter().position(|v| v == &value) {
+        values.remove(ix);
+        return true;
+    }
+
+    if max.map_or(false, |max| values.len() >= max) {
+        return false;
+    }
+
+    values.push(value);
+    true
+}
+
+/// The [`Select`] or [`super::MultiSelect`] that owns the [`SelectListDelegate`].
+pub(crate) trait SelectOwner<T>: SelectPopupView {
+    /// Called when the values are changed by the user in the menu.
+    fn change(&mut self, values: Vec<T>, cx: &mut ViewContext<Self>);
+}
+
+/// The options menu of the [`Select`], and of the [`super::MultiSelect`] in the multiple mode.
+pub(crate) struct SelectListDelegate<T: 'static, V: 'static> {
+    owner: WeakView<V>,
+    pub(super) groups: Vec<SelectGroup<T>>,
+    rows: Vec<SelectRow>,
+    /// The selected values, at most one value if not in the multiple mode.
+    pub(super) values: Vec<T>,
+    /// Toggle the options with the checkboxes, and keep the menu open on confirm.
+    multiple: bool,
+    pub(super) show_select_all: bool,
+    pub(super) max_selected: Option<usize>,
+    selected_index: Option<usize>,
+}
+
+impl<T: Clone + PartialEq + 'static, V: SelectOwner<T>> SelectListDelegate<T, V> {
+    pub(super) fn new(
+        owner: WeakView<V>,
+        groups: impl IntoIterator<Item = SelectGroup<T>>,
+        multiple: bool,
+    ) -> Self {
+        let mut this = Self {
+            owner,
+            groups: groups.into_iter().collect(),
+            rows: vec![],
+            values: vec![],
+            multiple,
+            show_select_all: false,
+            max_selected: None,
+            selected_index: None,
+        };
+        this.update_rows("");
+        this
+    }
+
+    /// Rebuild the rows by the query.
+    pub(super) fn update_rows(&mut self, query: &str) {
+        self.rows = build_rows(&self.groups, query);
+        if self.multiple && self.show_select_all && !self.rows.is_empty() {
+            self.rows.insert(0, SelectRow::SelectAll);
+        }
+    }
+
+    fn option(&self, ix: usize) -> Option<&SelectOption<T>> {
+        match self.rows.get(ix) {
+            Some(SelectRow::Item(group_ix, option_ix)) => {
+                self.groups.get(*group_ix)?.options.get(*option_ix)
+            }
+            _ => None,
+        }
+    }
+
+    pub(super) fn find(&self, value: &T) -> Option<&SelectOption<T>> {
+        self.groups
+            .iter()
+            .flat_map(|group| group.options.iter())
+            .find(|option| &option.value == value)
+    }
+
+    fn position(&self, value: &T) -> Option<usize> {
+        (0..self.rows.len()).find(|ix| self.option(*ix).map_or(false, |o| &o.value == value))
+    }
+
+    fn enabled_values(&self) -> impl Iterator<Item = &T> {
+        self.groups
+            .iter()
+            .flat_map(|group| group.options.iter())
+            .filter(|option| !option.disabled)
+            .map(|option| &option.value)
+    }
+
+    fn is_checked(&self, value: &T) -> bool {
+        self.values.contains(value)
+    }
+
+    fn is_all_checked(&self) -> bool {
+        self.enabled_values().all(|value| self.is_checked(value))
+    }
+
+    fn is_full(&self) -> bool {
+        self.max_selected
+            .map_or(false, |max| self.values.len() >= max)
+    }
+
+    /// Select all the enabled options (until the max), or unselect all if all of them are selected.
+    fn toggle_all(&mut self) {
+        if self.is_all_checked() || self.is_full() {
+            self.values.clear();
+            return;
+        }
+
+        let values = self.enabled_values().cloned().collect::<Vec<_>>();
+        for value in values {
+            if !self.is_checked(&value) {
+                toggle_value(&mut self.values, value, self.max_selected);
+            }
+        }
+    }
+
+    fn size(&self, cx: &AppContext) -> Size {
+        self.owner
+            .upgrade()
+            .map_or(Size::Medium, |owner| owner.read(cx).popup().size)
+    }
+}
+
+impl<T: Clone + PartialEq + 'static, V: SelectOwner<T>> ListDelegate for SelectListDelegate<T, V> {
+    type Item = ListItem;
+
+    fn items_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn confirmed_index(&self) -> Option<usize> {
+        if self.multiple {
+            return None;
+        }
+
+        self.values.first().and_then(|value| self.position(value))
+    }
+
+    fn can_select(&self, ix: usize) -> bool {
+        match self.rows.get(ix) {
+            Some(SelectRow::SelectAll) => true,
+            Some(SelectRow::Item(..)) => self.option(ix).map_or(false, |option| {
+                !option.disabled
+                    && !(self.multiple && self.is_full() && !self.is_checked(&option.value))
+            }),
+            _ => false,
+        }
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let size = self.size(cx);
+        let item = ListItem::new(("option", ix))
+            .input_text_size(size)
+            .list_size(size)
+            .selected(self.selected_index == Some(ix));
+
+        match *self.rows.get(ix)? {
+            SelectRow::SelectAll => {
+                let checked = !self.values.is_empty();
+                Some(
+                    item.cursor_pointer().child(
+                        Checkbox::new(("select-all", ix))
+                            .label(t!("MultiSelect.select_all"))
+                            .checked(checked)
+                            .indeterminate(checked && !self.is_all_checked()),
+                    ),
+                )
+            }
+            SelectRow::Group(group_ix) => {
+                let label = self.groups.get(group_ix)?.label.clone()?;
+                Some(
+                    item.disabled(true).child(
+                        div()
+                            .text_xs()
+                            .font_semibold()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(label),
+                    ),
+                )
+            }
+            SelectRow::Item(..) => {
+                let option = self.option(ix)?;
+                let disabled = !self.can_select(ix);
+                let item = item
+                    .disabled(disabled)
+                    .when(!disabled, |this| this.cursor_pointer());
+
+                if self.multiple {
+                    Some(
+                        item.child(
+                            Checkbox::new(("checkbox", ix))
+                                .label(option.label.clone())
+                                .checked(self.is_checked(&option.value))
+                                .disabled(disabled),
+                        ),
+                    )
+                } else {
+                    Some(
+                        item.check_icon(IconName::Check)
+                            .confirmed(self.is_checked(&option.value))
+                            .child(
+                                div()
+                                    .whitespace_nowrap()
+                                    .when(disabled, |this| {
+                                        this.text_color(cx.theme().muted_foreground)
+                                    })
+                                    .child(option.label.clone()),
+                            ),
+                    )
+                }
+            }
+        }
+    }
+
+    fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {
+        let owner = self.owner.clone();
+        cx.defer(move |_, cx| {
+            _ = owner.update(cx, |this, cx| this.popup_mut().close(cx));
+        });
+    }
+
+    /// Select the option and close the menu, or toggle the option and keep the menu open in the multiple mode.
+    fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        let Some(ix) = ix.filter(|ix| self.can_select(*ix)) else {
+            return;
+        };
+
+        let changed = match self.rows.get(ix) {
+            Some(SelectRow::SelectAll) => {
+                self.toggle_all();
+                true
+            }
+            Some(SelectRow::Item(..)) => {
+                let value = self.option(ix).map(|option| option.value.clone());
+                match value {
+                    Some(value) if self.multiple => {
+                        toggle_value(&mut self.values, value, self.max_selected)
+                    }
+                    Some(value) => {
+                        self.values = vec![value];
+                        true
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        };
+
+        let close = !self.multiple;
+        if !changed && !close {
+            return;
+        }
+
+        let values = self.values.clone();
+        let owner = self.owner.clone();
+        cx.defer(move |_, cx| {
+            _ = owner.update(cx, |this, cx| {
+                if close {
+                    this.popup_mut().close(cx);
+                }
+                this.change(values, cx);
+            });
+        });
+    }
+
+    fn perform_search(&mut self, query: &str, _: &mut ViewContext<List<Self>>) -> Task<()> {
+        self.update_rows(query);
+        Task::Ready(Some(()))
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix;
+    }
+
+    fn render_empty(&self, cx: &mut ViewContext<List<Self>>) -> impl IntoElement {
+        h_flex()
+            .justify_center()
+            .py_6()
+            .text_color(cx.theme().muted_foreground.opacity(0.6))
+            .child(Icon::new(IconName::Inbox).size(px(28.)))
+    }
+}
+
+pub enum SelectEvent<T> {
+    /// Emitted when the value is changed by the user, `None` if cleared.
+    Change(Option<T>),
+}
+
+type OnChange<T> = Rc<dyn Fn(&Option<T>, &mut WindowContext) + 'static>;
+
+/// A Select to pick a typed value from the options, the options can be grouped and searched.
+///
+/// ```ignore
+/// Select::new(
+///     "fruit",
+///     vec![
+///         SelectOption::new(Fruit::Apple, "Apple"),
+///         SelectOption::new(Fruit::Banana, "Banana").disabled(true),
+///     ],
+///     cx,
+/// )
+/// .placeholder("Select a fruit")
+/// .cleanable()
+/// ```
+pub struct Select<T: Clone + PartialEq + 'static> {
+    popup: SelectPopup<SelectListDelegate<T, Self>>,
+    value: Option<T>,
+    on_change: Option<OnChange<T>>,
+}
+
+impl<T: Clone + PartialEq + 'static> Select<T> {
+    pub fn new(
+        id: impl Into<ElementId>,
+        options: impl IntoIterator<Item = SelectOption<T>>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        Self::grouped(id, vec![SelectGroup::ungrouped(options)], cx)
+    }
+
+    /// Create a Select with the option groups.
+    pub fn grouped(
+        id: impl Into<ElementId>,
+        groups: impl IntoIterator<Item = SelectGroup<T>>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let delegate = SelectListDelegate::new(cx.view().downgrade(), groups, false);
+        let list = cx.new_view(|cx| List::new(delegate, cx).max_h(rems(20.)));
+
+        Self {
+            popup: SelectPopup::new(id, list, cx),
+            value: None,
+            on_change: None,
+        }
+    }
+
+    /// Set the width of the select input, default: Length::Auto
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.popup.width = width.into();
+        self
+    }
+
+    /// Set the width of the select menu, default: Length::Auto
+    pub fn menu_width(mut self, width: impl Into<Length>) -> Self {
+        self.popup.menu_width = width.into();
+        self
+    }
+
+    /// Set the placeholder for display when the value is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.popup.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set true to show the clear button when the value is not empty.
+    pub fn cleanable(mut self) -> Self {
+        self.popup.cleanable = true;
+        self
+    }
+
+    /// Set the disable state for the select.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.popup.disabled = disabled;
+        self
+    }
+
+    /// Set the handler to be called when the value is changed by the user.
+    pub fn on_change(mut self, handler: impl Fn(&Option<T>, &mut WindowContext) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Returns the selected value.
+    pub fn value(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Set the selected value, this will not emit the change event.
+    pub fn set_value(&mut self, value: Option<T>, cx: &mut ViewContext<Self>) {
+        self.value = value;
+        self.sync_values(cx);
+        cx.notify();
+    }
+
+    /// Replace the options.
+    pub fn set_options(
+        &mut self,
+        options: impl IntoIterator<Item = SelectOption<T>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.set_groups(vec![SelectGroup::ungrouped(options)], cx);
+    }
+
+    /// Replace the option groups.
+    pub fn set_groups(
+        &mut self,
+        groups: impl IntoIterator<Item = SelectGroup<T>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let groups = groups.into_iter().collect::<Vec<_>>();
+        self.popup.list.update(cx, |list, cx| {
+            list.delegate_mut().groups = groups;
+            list.delegate_mut().update_rows("");
+            list.set_query("", cx);
+            cx.notify();
+        });
+        self.sync_values(cx);
+        cx.notify();
+    }
+
+    pub fn focus(&self, cx: &mut WindowContext) {
+        self.popup.focus(cx);
+    }
+
+    /// Update the values of the menu and the selected index of the list by the value.
+    fn sync_values(&mut self, cx: &mut ViewContext<Self>) {
+        let values = self.value.clone().into_iter().collect();
+        self.popup.list.update(cx, |list, cx| {
+            list.delegate_mut().values = values;
+            let ix = list.delegate().confirmed_index();
+            list.set_selected_index(ix, cx);
+        });
+    }
+
+    fn display_title(&self, cx: &WindowContext) -> impl IntoElement {
+        let label = self
+            .value
+            .as_ref()
+            .and_then(|value| self.popup.list.read(cx).delegate().find(value))
+            .map(|option| option.label.clone());
+
+        match label {
+            Some(label) => div().child(label),
+            None => div().text_color(cx.theme().accent_foreground).child(
+                self.popup
+                    .placeholder
+                    .clone()
+                    .unwrap_or_else(|| t!("Select.placeholder").into()),
+            ),
+        }
+        .whitespace_nowrap()
+        .overflow_hidden()
+        .text_ellipsis()
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> SelectPopupView for Select<T> {
+    type Delegate = SelectListDelegate<T, Self>;
+
+    fn popup(&self) -> &SelectPopup<Self::Delegate> {
+        &self.popup
+    }
+
+    fn popup_mut(&mut self) -> &mut SelectPopup<Self::Delegate> {
+        &mut self.popup
+    }
+
+    fn on_open(&mut self, cx: &mut ViewContext<Self>) {
+        self.popup.list.update(cx, |list, cx| {
+            list.delegate_mut().update_rows("");
+            list.set_query("", cx);
+        });
+        self.sync_values(cx);
+    }
+
+    fn on_clean(&mut self, cx: &mut ViewContext<Self>) {
+        self.change(vec![], cx);
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> SelectOwner<T> for Select<T> {
+    fn change(&mut self, values: Vec<T>, cx: &mut ViewContext<Self>) {
+        let value = values.into_iter().next();
+        if self.value == value {
+            return;
+        }
+
+        self.value = value.clone();
+        self.sync_values(cx);
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(&value, cx);
+        }
+        cx.emit(SelectEvent::Change(value));
+        cx.notify();
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Sizable for Select<T> {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.popup.size = size.into();
+        self
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> EventEmitter<SelectEvent<T>> for Select<T> {}
+impl<T: Clone + PartialEq + 'static> FocusableView for Select<T> {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.popup.focus_handle(cx)
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Render for Select<T> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let show_clean = self.popup.cleanable && self.value.is_some();
+        let title = self.display_title(cx);
+
+        render_popup(self, title, show_clean, cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_rows, toggle_value, SelectGroup, SelectOption, SelectRow::*};
+
+    #[test]
+    fn test_build_rows() {
+        let groups = vec![
+            SelectGroup::new(
+                "Fruits",
+                [
+                    SelectOption::new(1, "Apple"),
+                    SelectOption::new(2, "Banana").disabled(true),
+                ],
+            ),
+            SelectGroup::new("Vegetables", [SelectOption::new(3, "Carrot")]),
+        ];
+
+        assert_eq!(
+            build_rows(&groups, ""),
+            vec![Group(0), Item(0, 0), Item(0, 1), Group(1), Item(1, 0)]
+        );
+        assert_eq!(build_rows(&groups, "an"), vec![Group(0), Item(0, 1)]);
+        assert_eq!(build_rows(&groups, "CAR"), vec![Group(1), Item(1, 0)]);
+        assert_eq!(build_rows(&groups, "xyz"), vec![]);
+
+        let groups = vec![SelectGroup {
+            label: None,
+            options: vec![SelectOption::new(1, "Apple")],
+        }];
+        assert_eq!(build_rows(&groups, ""), vec![Item(0, 0)]);
+    }
+
+    #[test]
+    fn test_toggle_value() {
+        let mut values = vec![1, 2];
+        assert!(toggle_value(&mut values, 3, None));
+        assert_eq!(values, vec![1, 2, 3]);
+        assert!(toggle_value(&mut values, 2, None));
+        assert_eq!(values, vec![1, 3]);
+
+        // The max is reached, can't add but can remove.
+        assert!(!toggle_value(&mut values, 4, Some(2)));
+        assert_eq!(values, vec![1, 3]);
+        assert!(toggle_value(&mut values, 1, Some(2)));
+        assert_eq!(values, vec![3]);
+    }
+}
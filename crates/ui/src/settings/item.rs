@@ -0,0 +1,176 @@
+use gpui::SharedString;
+use serde_json::Value;
+
+use crate::IconName;
+
+/// The kind of the control to edit a setting.
+#[derive(Clone)]
+pub enum SettingKind {
+    /// A switch, the value is a bool.
+    Toggle,
+    /// A select with the `(value, label)` options, the value is the string of the selected option.
+    Select(Vec<(SharedString, SharedString)>),
+    /// A slider, the value is a number.
+    Slider { min: f32, max: f32, step: f32 },
+    /// A key binding recorder, the value is the keystroke string, e.g.: `cmd-shift-p`.
+    Keybinding,
+}
+
+/// A setting described declaratively, the value is stored by the `key` in the [`super::SettingsView`].
+///
+/// ```ignore
+/// SettingItem::toggle("editor.word_wrap", "Word Wrap")
+///     .description("Wrap the lines that exceed the viewport width.")
+///     .default_value(true)
+/// ```
+#[derive(Clone)]
+pub struct SettingItem {
+    pub(super) key: SharedString,
+    pub(super) title: SharedString,
+    pub(super) description: Option<SharedString>,
+    pub(super) kind: SettingKind,
+    pub(super) default_value: Value,
+}
+
+impl SettingItem {
+    fn new(
+        key: impl Into<SharedString>,
+        title: impl Into<SharedString>,
+        kind: SettingKind,
+    ) -> Self {
+        let default_value = match &kind {
+            SettingKind::Toggle => Value::Bool(false),
+            SettingKind::Slider { min, .. } => Value::from(*min),
+            SettingKind::Select(_) | SettingKind::Keybinding => Value::Null,
+        };
+
+        Self {
+            key: key.into(),
+            title: title.into(),
+            description: None,
+            kind,
+            default_value,
+        }
+    }
+
+    /// A setting to toggle by a switch, default: `false`.
+    pub fn toggle(key: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self::new(key, title, SettingKind::Toggle)
+    }
+
+    /// A setting to select one of the `(value, label)` options, default: `null`.
+    pub fn select<V, L>(
+        key: impl Into<SharedString>,
+        title: impl Into<SharedString>,
+        options: impl IntoIterator<Item = (V, L)>,
+    ) -> Self
+    where
+        V: Into<SharedString>,
+        L: Into<SharedString>,
+    {
+        let options = options
+            .into_iter()
+            .map(|(value, label)| (value.into(), label.into()))
+            .collect();
+        Self::new(key, title, SettingKind::Select(options))
+    }
+
+    /// A setting to pick a number in the range by a slider, default: `min`.
+    pub fn slider(
+        key: impl Into<SharedString>,
+        title: impl Into<SharedString>,
+        min: f32,
+        max: f32,
+        step: f32,
+    ) -> Self {
+        Self::new(key, title, SettingKind::Slider { min, max, step })
+    }
+
+    /// A setting to record a key binding, default: `null`.
+    pub fn keybinding(key: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self::new(key, title, SettingKind::Keybinding)
+    }
+
+    /// Set the description under the title.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the default value, it is used if the value is not loaded from the storage.
+    pub fn default_value(mut self, value: impl Into<Value>) -> Self {
+        self.default_value = value.into();
+        self
+    }
+
+    pub fn key(&self) -> &SharedString {
+        &self.key
+    }
+
+    /// Return true if the title, description or key contains the lowercase `query`.
+    pub(super) fn matches(&self, query: &str) -> bool {
+        [
+            Some(&self.title),
+            self.description.as_ref(),
+            Some(&self.key),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|text| text.to_lowercase().contains(query))
+    }
+}
+
+/// A section of the settings, listed in the sidebar of the [`super::SettingsView`].
+pub struct SettingSection {
+    pub(super) title: SharedString,
+    pub(super) icon: Option<IconName>,
+    pub(super) items: Vec<SettingItem>,
+}
+
+impl SettingSection {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            icon: None,
+            items: vec![],
+        }
+    }
+
+    /// Set the icon before the title in the sidebar.
+    pub fn icon(mut self, icon: impl Into<IconName>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Add a setting to the section.
+    pub fn item(mut self, item: SettingItem) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::SettingItem;
+
+    #[test]
+    fn test_setting_item() {
+        let item = SettingItem::toggle("editor.word_wrap", "Word Wrap")
+            .description("Wrap the long lines.");
+        assert_eq!(item.default_value, json!(false));
+        assert!(item.matches("wrap"));
+        assert!(item.matches("long"));
+        assert!(item.matches("editor."));
+        assert!(!item.matches("font"));
+
+        let item = SettingItem::slider("font_size", "Font Size", 10., 24., 1.);
+        assert_eq!(item.default_value, json!(10.));
+        let item = item.default_value(14);
+        assert_eq!(item.default_value, json!(14));
+
+        let item = SettingItem::select("theme", "Theme", [("light", "Light"), ("dark", "Dark")]);
+        assert_eq!(item.default_value, json!(null));
+    }
+}
@@ -0,0 +1,5 @@
+mod item;
+mod settings_view;
+
+pub use item::*;
+pub use settings_view::*;
@@ -0,0 +1,592 @@
+use std::{collections::HashMap, rc::Rc};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, AppContext, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement as _, IntoElement, KeyDownEvent, Keystroke,
+    ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled as _,
+    Subscription, View, ViewContext, VisualContext as _, WindowContext,
+};
+use rust_i18n::t;
+use serde_json::{Map, Value};
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    input::{InputEvent, TextInput},
+    kbd::Kbd,
+    scroll::ScrollbarAxis,
+    select::{Select, SelectEvent, SelectOption},
+    slider::{Slider, SliderEvent},
+    switch::Switch,
+    theme::ActiveTheme as _,
+    v_flex, Disableable as _, Icon, Sizable as _, StyledExt as _,
+};
+
+use super::{SettingItem, SettingKind, SettingSection};
+
+type OnSave = Rc<dyn Fn(&Value, &mut WindowContext) -> anyhow::Result<()>>;
+
+pub enum SettingsEvent {
+    /// The value of the setting is changed by the user, `(key, value)`.
+    Change(SharedString, Value),
+    /// The values are saved to the storage.
+    Save(Value),
+}
+
+/// The stateful control of the setting, the toggle and keybinding are rendered from the value.
+enum SettingControl {
+    None,
+    Select(View<Select<SharedString>>),
+    Slider(View<Slider>),
+}
+
+/// Return the keystroke string can be parsed by [`Keystroke::parse`], e.g.: `ctrl-shift-p`.
+fn keystroke_to_string(keystroke: &Keystroke) -> String {
+    let modifiers = &keystroke.modifiers;
+    let mut parts = vec![];
+    if modifiers.control {
+        parts.push("ctrl");
+    }
+    if modifiers.alt {
+        parts.push("alt");
+    }
+    if modifiers.shift {
+        parts.push("shift");
+    }
+    if modifiers.function {
+        parts.push("fn");
+    }
+    if modifiers.platform {
+        parts.push("cmd");
+    }
+    parts.push(&keystroke.key);
+    parts.join("-")
+}
+
+/// A settings view with a sidebar of the sections, and the searchable settings of the section.
+///
+/// The changed values are tracked as dirty until saved by the `on_save` callback.
+///
+/// ```ignore
+/// let settings = cx.new_view(|cx| {
+///     SettingsView::new(cx)
+///         .section(
+///             SettingSection::new("Editor")
+///                 .item(SettingItem::toggle("editor.word_wrap", "Word Wrap"))
+///                 .item(SettingItem::slider("editor.font_size", "Font Size", 10., 24., 1.)),
+///             cx,
+///         )
+///         .on_save(|values, _| Ok(std::fs::write("settings.json", values.to_string())?))
+/// });
+/// settings.update(cx, |view, cx| view.set_values(&stored_values, cx));
+/// ```
+pub struct SettingsView {
+    focus_handle: FocusHandle,
+    sections: Vec<SettingSection>,
+    controls: HashMap<SharedString, SettingControl>,
+    values: Map<String, Value>,
+    /// The values in the storage, to compare with the values to check dirty.
+    saved: Map<String, Value>,
+    active_section: usize,
+    search_input: View<TextInput>,
+    query: SharedString,
+    /// The key of the keybinding setting is recording.
+    recording: Option<SharedString>,
+    recorder_focus_handle: FocusHandle,
+    on_save: Option<OnSave>,
+    save_error: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl SettingsView {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let search_input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .placeholder(t!("Settings.search"))
+                .cleanable()
+                .small()
+        });
+        let recorder_focus_handle = cx.focus_handle();
+
+        let _subscriptions = vec![
+            cx.subscribe(&search_input, |this, input, event: &InputEvent, cx| {
+                if let InputEvent::Change(_) = event {
+                    this.query = input.read(cx).text().trim().to_lowercase().into();
+                    cx.notify();
+                }
+            }),
+            cx.on_blur(&recorder_focus_handle, |this, cx| {
+                this.recording = None;
+                cx.notify();
+            }),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            sections: vec![],
+            controls: HashMap::new(),
+            values: Map::new(),
+            saved: Map::new(),
+            active_section: 0,
+            search_input,
+            query: SharedString::default(),
+            recording: None,
+            recorder_focus_handle,
+            on_save: None,
+            save_error: None,
+            _subscriptions,
+        }
+    }
+
+    /// Add a section of the settings.
+    pub fn section(mut self, section: SettingSection, cx: &mut ViewContext<Self>) -> Self {
+        self.add_section(section, cx);
+        self
+    }
+
+    /// Set the callback to persist the values, the values are a JSON object by the keys.
+    ///
+    /// The values are kept dirty if it returns an error.
+    pub fn on_save(
+        mut self,
+        on_save: impl Fn(&Value, &mut WindowContext) -> anyhow::Result<()> + 'static,
+    ) -> Self {
+        self.on_save = Some(Rc::new(on_save));
+        self
+    }
+
+    /// Add a section of the settings, the default values are used for the new keys.
+    pub fn add_section(&mut self, section: SettingSection, cx: &mut ViewContext<Self>) {
+        for item in &section.items {
+            let value = self
+                .values
+                .entry(item.key.to_string())
+                .or_insert_with(|| item.default_value.clone())
+                .clone();
+            self.saved
+                .entry(item.key.to_string())
+                .or_insert_with(|| value.clone());
+
+            let control = self.build_control(item, &value, cx);
+            self.controls.insert(item.key.clone(), control);
+        }
+
+        self.sections.push(section);
+        cx.notify();
+    }
+
+    fn build_control(
+        &mut self,
+        item: &SettingItem,
+        value: &Value,
+        cx: &mut ViewContext<Self>,
+    ) -> SettingControl {
+        let key = item.key.clone();
+        match &item.kind {
+            SettingKind::Select(options) => {
+                let options = options
+                    .iter()
+                    .map(|(value, label)| SelectOption::new(value.clone(), label.clone()))
+                    .collect::<Vec<_>>();
+                let select = cx.new_view(|cx| {
+                    let mut select = Select::new(key.clone(), options, cx)
+                        .width(px(200.))
+                        .small();
+                    select.set_value(value.as_str().map(|v| v.to_string().into()), cx);
+                    select
+                });
+                self._subscriptions.push(cx.subscribe(
+                    &select,
+                    move |this, _, event: &SelectEvent<SharedString>, cx| match event {
+                        SelectEvent::Change(value) => {
+                            let value = value
+                                .as_ref()
+                                .map_or(Value::Null, |v| Value::String(v.to_string()));
+                            this.set_value(key.clone(), value, cx);
+                        }
+                    },
+                ));
+                SettingControl::Select(select)
+            }
+            SettingKind::Slider { min, max, step } => {
+                let value = value.as_f64().map_or(*min, |v| v as f32);
+                let slider = cx.new_view(|_| {
+                    Slider::horizontal()
+                        .min(*min)
+                        .max(*max)
+                        .step(*step)
+                        .default_value(value)
+                });
+                self._subscriptions.push(cx.subscribe(
+                    &slider,
+                    move |this, _, event: &SliderEvent, cx| {
+                        if let SliderEvent::Change(value) = event {
+                            this.set_value(key.clone(), Value::from(*value), cx);
+                        }
+                    },
+                ));
+                SettingControl::Slider(slider)
+            }
+            SettingKind::Toggle | SettingKind::Keybinding => SettingControl::None,
+        }
+    }
+
+    /// Get the value of the setting.
+    pub fn value(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+
+    /// Get all the values as a JSON object by the keys.
+    pub fn values(&self) -> Value {
+        Value::Object(self.values.clone())
+    }
+
+    /// Set the values loaded from the storage, the values are not dirty after this.
+    pub fn set_values(&mut self, values: &Value, cx: &mut ViewContext<Self>) {
+        if let Value::Object(values) = values {
+            for (key, value) in values {
+                self.values.insert(key.clone(), value.clone());
+                self.saved.insert(key.clone(), value.clone());
+            }
+        }
+        self.save_error = None;
+        self.sync_controls(cx);
+    }
+
+    /// Set the value of the setting and emit [`SettingsEvent::Change`].
+    pub fn set_value(
+        &mut self,
+        key: impl Into<SharedString>,
+        value: Value,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let key = key.into();
+        if self.values.get(key.as_ref()) == Some(&value) {
+            return;
+        }
+
+        self.values.insert(key.to_string(), value.clone());
+        cx.emit(SettingsEvent::Change(key, value));
+        cx.notify();
+    }
+
+    /// Return true if the values are changed after loaded or saved.
+    pub fn is_dirty(&self) -> bool {
+        self.values != self.saved
+    }
+
+    /// Save the values by the `on_save` callback.
+    pub fn save(&mut self, cx: &mut ViewContext<Self>) {
+        let values = self.values();
+        if let Some(on_save) = self.on_save.clone() {
+            if let Err(err) = on_save(&values, cx) {
+                self.save_error = Some(err.to_string().into());
+                cx.notify();
+                return;
+            }
+        }
+
+        self.saved = self.values.clone();
+        self.save_error = None;
+        cx.emit(SettingsEvent::Save(values));
+        cx.notify();
+    }
+
+    /// Discard the unsaved changes.
+    pub fn reset(&mut self, cx: &mut ViewContext<Self>) {
+        self.values = self.saved.clone();
+        self.save_error = None;
+        self.sync_controls(cx);
+    }
+
+    fn sync_controls(&mut self, cx: &mut ViewContext<Self>) {
+        for (key, control) in &self.controls {
+            let value = self.values.get(key.as_ref()).unwrap_or(&Value::Null);
+            match control {
+                SettingControl::Select(select) => {
+                    let value = value.as_str().map(|v| SharedString::from(v.to_string()));
+                    select.update(cx, |select, cx| select.set_value(value, cx));
+                }
+                SettingControl::Slider(slider) => {
+                    if let Some(value) = value.as_f64() {
+                        slider.update(cx, |slider, cx| slider.set_value(value as f32, cx));
+                    }
+                }
+                SettingControl::None => {}
+            }
+        }
+        cx.notify();
+    }
+
+    fn start_recording(&mut self, key: SharedString, cx: &mut ViewContext<Self>) {
+        self.recording = Some(key);
+        self.recorder_focus_handle.focus(cx);
+        cx.notify();
+    }
+
+    fn on_record_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        let Some(key) = self.recording.take() else {
+            return;
+        };
+        cx.stop_propagation();
+
+        let keystroke = &event.keystroke;
+        let no_modifiers = !keystroke.modifiers.modified();
+        match keystroke.key.as_str() {
+            "escape" if no_modifiers => {}
+            "backspace" if no_modifiers => self.set_value(key, Value::Null, cx),
+            _ => self.set_value(key, Value::String(keystroke_to_string(keystroke)), cx),
+        }
+        self.focus_handle.focus(cx);
+        cx.notify();
+    }
+
+    fn render_sidebar(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let searching = !self.query.is_empty();
+
+        v_flex()
+            .w(px(200.))
+            .h_full()
+            .flex_none()
+            .gap_0p5()
+            .p_2()
+            .border_r_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().tab_bar)
+            .children(self.sections.iter().enumerate().map(|(ix, section)| {
+                let active = !searching && ix == self.active_section;
+
+                h_flex()
+                    .id(ix)
+                    .gap_2()
+                    .px_2()
+                    .py_1p5()
+                    .rounded(px(cx.theme().radius))
+                    .text_sm()
+                    .cursor_pointer()
+                    .when(active, |this| {
+                        this.bg(cx.theme().accent)
+                            .text_color(cx.theme().accent_foreground)
+                            .font_medium()
+                    })
+                    .when(!active, |this| {
+                        this.hover(|this| this.bg(cx.theme().accent.opacity(0.5)))
+                    })
+                    .when_some(section.icon.clone(), |this, icon| {
+                        this.child(Icon::new(icon).small())
+                    })
+                    .child(section.title.clone())
+                    .on_click(cx.listener(move |this, _, cx| {
+                        this.active_section = ix;
+                        this.search_input
+                            .update(cx, |input, cx| input.set_text("", cx));
+                        cx.notify();
+                    }))
+            }))
+    }
+
+    fn render_item(&self, item: &SettingItem, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let value = self.values.get(item.key.as_ref()).unwrap_or(&Value::Null);
+
+        h_flex()
+            .justify_between()
+            .gap_4()
+            .py_3()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                v_flex()
+                    .gap_1()
+                    .child(div().text_sm().font_medium().child(item.title.clone()))
+                    .when_some(item.description.clone(), |this, description| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(description),
+                        )
+                    }),
+            )
+            .child(self.render_control(item, value, cx))
+    }
+
+    fn render_control(
+        &self,
+        item: &SettingItem,
+        value: &Value,
+        cx: &mut ViewContext<Self>,
+    ) -> AnyElement {
+        let key = item.key.clone();
+        match (&item.kind, self.controls.get(&item.key)) {
+            (_, Some(SettingControl::Select(select))) => select.clone().into_any_element(),
+            (_, Some(SettingControl::Slider(slider))) => {
+                div().w(px(200.)).child(slider.clone()).into_any_element()
+            }
+            (SettingKind::Keybinding, _) => {
+                let recording = self.recording.as_ref() == Some(&item.key);
+                let keystroke = value.as_str().and_then(|v| Keystroke::parse(v).ok());
+
+                div()
+                    .when(recording, |this| {
+                        this.track_focus(&self.recorder_focus_handle)
+                            .on_key_down(cx.listener(Self::on_record_key_down))
+                    })
+                    .child(
+                        Button::new(key.clone())
+                            .small()
+                            .outline()
+                            .map(|this| match (recording, keystroke) {
+                                (true, _) => this.label(t!("Settings.press_keys")),
+                                (false, Some(keystroke)) => this.child(Kbd::new(keystroke)),
+                                (false, None) => this.label(t!("Settings.none")),
+                            })
+                            .on_click(cx.listener(move |this, _, cx| {
+                                this.start_recording(key.clone(), cx);
+                            })),
+                    )
+                    .into_any_element()
+            }
+            _ => Switch::new(key.clone())
+                .checked(value.as_bool().unwrap_or_default())
+                .on_click(cx.listener(move |this, checked: &bool, cx| {
+                    this.set_value(key.clone(), Value::Bool(*checked), cx);
+                }))
+                .into_any_element(),
+        }
+    }
+
+    fn render_body(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let sections: Vec<(&SettingSection, Vec<&SettingItem>)> = if self.query.is_empty() {
+            self.sections
+                .get(self.active_section)
+                .map(|section| (section, section.items.iter().collect()))
+                .into_iter()
+                .collect()
+        } else {
+            self.sections
+                .iter()
+                .map(|section| {
+                    let items = section
+                        .items
+                        .iter()
+                        .filter(|item| item.matches(&self.query))
+                        .collect::<Vec<_>>();
+                    (section, items)
+                })
+                .filter(|(_, items)| !items.is_empty())
+                .collect()
+        };
+
+        v_flex()
+            .px_6()
+            .py_4()
+            .gap_6()
+            .when(sections.is_empty(), |this| {
+                this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t!("Settings.no_results")),
+                )
+            })
+            .children(sections.into_iter().map(|(section, items)| {
+                v_flex()
+                    .child(
+                        div()
+                            .pb_2()
+                            .text_lg()
+                            .font_semibold()
+                            .child(section.title.clone()),
+                    )
+                    .children(items.into_iter().map(|item| self.render_item(item, cx)))
+            }))
+    }
+}
+
+impl EventEmitter<SettingsEvent> for SettingsView {}
+
+impl FocusableView for SettingsView {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for SettingsView {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let is_dirty = self.is_dirty();
+
+        h_flex()
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .items_start()
+            .child(self.render_sidebar(cx))
+            .child(
+                v_flex()
+                    .flex_1()
+                    .h_full()
+                    .overflow_hidden()
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .px_6()
+                            .py_3()
+                            .border_b_1()
+                            .border_color(cx.theme().border)
+                            .child(div().flex_1().child(self.search_input.clone()))
+                            .when_some(self.save_error.clone(), |this, error| {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().destructive)
+                                        .child(error),
+                                )
+                            })
+                            .when(is_dirty && self.save_error.is_none(), |this| {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(t!("Settings.unsaved")),
+                                )
+                            })
+                            .child(
+                                Button::new("reset")
+                                    .small()
+                                    .ghost()
+                                    .label(t!("Settings.reset"))
+                                    .disabled(!is_dirty)
+                                    .on_click(cx.listener(|this, _, cx| this.reset(cx))),
+                            )
+                            .child(
+                                Button::new("save")
+                                    .small()
+                                    .primary()
+                                    .label(t!("Settings.save"))
+                                    .disabled(!is_dirty)
+                                    .on_click(cx.listener(|this, _, cx| this.save(cx))),
+                            ),
+                    )
+                    .child(
+                        div().flex_1().overflow_hidden().child(
+                            self.render_body(cx)
+                                .scrollable(cx.view().entity_id(), ScrollbarAxis::Vertical),
+                        ),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::Keystroke;
+
+    use super::keystroke_to_string;
+
+    #[test]
+    fn test_keystroke_to_string() {
+        for text in ["a", "ctrl-shift-p", "alt-fn-f1", "ctrl-alt-shift-cmd-k"] {
+            let keystroke = Keystroke::parse(text).unwrap();
+            assert_eq!(keystroke_to_string(&keystroke), text);
+        }
+    }
+}
@@ -1,16 +1,55 @@
-use crate::{theme::ActiveTheme, tooltip::Tooltip};
+use crate::{h_flex, theme::ActiveTheme, tooltip::Tooltip};
 use gpui::{
-    canvas, div, prelude::FluentBuilder as _, px, relative, Axis, Bounds, DragMoveEvent, EntityId,
-    EventEmitter, InteractiveElement, IntoElement, MouseButton, MouseDownEvent, ParentElement as _,
-    Pixels, Point, Render, StatefulInteractiveElement as _, Styled, ViewContext,
-    VisualContext as _,
+    actions, canvas, div, prelude::FluentBuilder as _, px, relative, AppContext, Axis, Bounds,
+    DragMoveEvent, EntityId, EventEmitter, FocusHandle, InteractiveElement, IntoElement,
+    KeyBinding, MouseButton, MouseDownEvent, ParentElement as _, Pixels, Point, Render,
+    SharedString, StatefulInteractiveElement as _, Styled, ViewContext, VisualContext as _,
 };
 
+actions!(
+    slider,
+    [
+        Increment,
+        Decrement,
+        PageIncrement,
+        PageDecrement,
+        Home,
+        End
+    ]
+);
+
+const CONTEXT: &str = "Slider";
+
+/// The number of steps to move by PageUp/PageDown.
+const PAGE_STEPS: f32 = 10.;
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", Decrement, Some(CONTEXT)),
+        KeyBinding::new("down", Decrement, Some(CONTEXT)),
+        KeyBinding::new("right", Increment, Some(CONTEXT)),
+        KeyBinding::new("up", Increment, Some(CONTEXT)),
+        KeyBinding::new("pagedown", PageDecrement, Some(CONTEXT)),
+        KeyBinding::new("pageup", PageIncrement, Some(CONTEXT)),
+        KeyBinding::new("home", Home, Some(CONTEXT)),
+        KeyBinding::new("end", End, Some(CONTEXT)),
+    ]);
+}
+
+/// The thumb of the slider, the `Start` thumb is only available in range mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Thumb {
+    Start,
+    End,
+}
+
 #[derive(Clone, Render)]
-pub struct DragThumb(EntityId);
+pub struct DragThumb(EntityId, Thumb);
 
 pub enum SliderEvent {
     Change(f32),
+    /// Emitted when the range of a range slider changed, `(start, end)`.
+    ChangeRange(f32, f32),
 }
 
 /// A slider component.
@@ -19,7 +58,17 @@ pub struct Slider {
     min: f32,
     max: f32,
     step: f32,
+    /// The value of the slider, or the end value in range mode.
     value: f32,
+    /// The start value in range mode.
+    start: Option<f32>,
+    marks: Vec<(f32, SharedString)>,
+    ticks: bool,
+    show_tooltip: bool,
+    /// The thumb that being dragged, or last dragged (for keyboard interaction).
+    active_thumb: Thumb,
+    dragging: bool,
+    focus_handle: Option<FocusHandle>,
     bounds: Bounds<Pixels>,
 }
 
@@ -31,6 +80,13 @@ impl Slider {
             max: 100.0,
             step: 1.0,
             value: 0.0,
+            start: None,
+            marks: Vec::new(),
+            ticks: false,
+            show_tooltip: true,
+            active_thumb: Thumb::End,
+            dragging: false,
+            focus_handle: None,
             bounds: Bounds::default(),
         }
     }
@@ -63,39 +119,104 @@ impl Slider {
         self
     }
 
+    /// Set the slider to range mode with dual thumbs, with the default range.
+    pub fn range(mut self, start: f32, end: f32) -> Self {
+        self.start = Some(start.min(end));
+        self.value = end.max(start);
+        self
+    }
+
+    /// Add a mark with the label at the given value.
+    pub fn mark(mut self, value: f32, label: impl Into<SharedString>) -> Self {
+        self.marks.push((value, label.into()));
+        self
+    }
+
+    /// Set true to show a tick at each step, default: false
+    pub fn ticks(mut self, ticks: bool) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Set false to hide the value tooltip while dragging, default: true
+    pub fn tooltip(mut self, show_tooltip: bool) -> Self {
+        self.show_tooltip = show_tooltip;
+        self
+    }
+
     /// Set the value of the slider.
     pub fn set_value(&mut self, value: f32, cx: &mut gpui::ViewContext<Self>) {
         self.value = value;
         cx.notify();
     }
 
-    /// Return percentage value of the slider, range of 0.0..1.0
-    fn relative_value(&self) -> f32 {
-        let step = self.step;
-        let value = self.value;
-        let min = self.min;
-        let max = self.max;
+    /// Set the range of the range slider.
+    pub fn set_range(&mut self, start: f32, end: f32, cx: &mut gpui::ViewContext<Self>) {
+        self.start = Some(start.min(end));
+        self.value = end.max(start);
+        cx.notify();
+    }
 
-        let relative_value = (value - min) / (max - min);
-        let relative_step = step / (max - min);
+    /// Return the value of the slider, or the end value in range mode.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
 
-        let relative_value = (relative_value / relative_step).round() * relative_step;
-        relative_value.clamp(0.0, 1.0)
+    /// Return the `(start, end)` of the range slider, None if not in range mode.
+    pub fn range_value(&self) -> Option<(f32, f32)> {
+        self.start.map(|start| (start, self.value))
     }
 
-    /// Update value by mouse position
-    fn update_value_by_position(
-        &mut self,
-        position: Point<Pixels>,
-        cx: &mut gpui::ViewContext<Self>,
-    ) {
+    fn thumb_value(&self, thumb: Thumb) -> f32 {
+        match thumb {
+            Thumb::Start => self.start.unwrap_or(self.min),
+            Thumb::End => self.value,
+        }
+    }
+
+    /// Return percentage of the value, range of 0.0..1.0
+    fn relative_value(&self, value: f32) -> f32 {
+        if self.max <= self.min {
+            return 0.;
+        }
+
+        ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    /// Snap the value to the step and clamp it into min..max.
+    fn snap(&self, value: f32) -> f32 {
+        let value = if self.step > 0. {
+            self.min + ((value - self.min) / self.step).round() * self.step
+        } else {
+            value
+        };
+
+        value.clamp(self.min, self.max)
+    }
+
+    /// Set the value of the thumb, keep the start not greater than the end, and emit the change event.
+    fn update_thumb_value(&mut self, thumb: Thumb, value: f32, cx: &mut ViewContext<Self>) {
+        let value = self.snap(value);
+
+        match (thumb, self.start) {
+            (Thumb::Start, Some(_)) => self.start = Some(value.min(self.value)),
+            (Thumb::End, Some(start)) => self.value = value.max(start),
+            _ => self.value = value,
+        }
+
+        match self.start {
+            Some(start) => cx.emit(SliderEvent::ChangeRange(start, self.value)),
+            None => cx.emit(SliderEvent::Change(self.value)),
+        }
+        cx.notify();
+    }
+
+    /// Return the value of the mouse position.
+    fn value_by_position(&self, position: Point<Pixels>) -> f32 {
         let bounds = self.bounds;
-        let axis = self.axis;
-        let min = self.min;
-        let max = self.max;
-        let step = self.step;
+        let (min, max) = (self.min, self.max);
 
-        let value = match axis {
+        match self.axis {
             Axis::Horizontal => {
                 let relative = (position.x - bounds.left()) / bounds.size.width;
                 min + (max - min) * relative
@@ -104,40 +225,81 @@ impl Slider {
                 let relative = (position.y - bounds.top()) / bounds.size.height;
                 max - (max - min) * relative
             }
-        };
+        }
+    }
 
-        let value = (value / step).round() * step;
+    /// Update value by mouse position
+    fn update_value_by_position(
+        &mut self,
+        thumb: Thumb,
+        position: Point<Pixels>,
+        cx: &mut gpui::ViewContext<Self>,
+    ) {
+        let value = self.value_by_position(position);
+        self.update_thumb_value(thumb, value, cx);
+    }
 
-        self.value = value.clamp(self.min, self.max);
-        cx.emit(SliderEvent::Change(self.value));
-        cx.notify();
+    fn step_by(&mut self, steps: f32, cx: &mut ViewContext<Self>) {
+        let thumb = self.active_thumb;
+        let value = self.thumb_value(thumb) + self.step * steps;
+        self.update_thumb_value(thumb, value, cx);
+    }
+
+    fn on_action_increment(&mut self, _: &Increment, cx: &mut ViewContext<Self>) {
+        self.step_by(1., cx);
+    }
+
+    fn on_action_decrement(&mut self, _: &Decrement, cx: &mut ViewContext<Self>) {
+        self.step_by(-1., cx);
     }
 
-    fn render_thumb(&self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
-        let value = self.value;
+    fn on_action_page_increment(&mut self, _: &PageIncrement, cx: &mut ViewContext<Self>) {
+        self.step_by(PAGE_STEPS, cx);
+    }
+
+    fn on_action_page_decrement(&mut self, _: &PageDecrement, cx: &mut ViewContext<Self>) {
+        self.step_by(-PAGE_STEPS, cx);
+    }
+
+    fn on_action_home(&mut self, _: &Home, cx: &mut ViewContext<Self>) {
+        self.update_thumb_value(self.active_thumb, self.min, cx);
+    }
+
+    fn on_action_end(&mut self, _: &End, cx: &mut ViewContext<Self>) {
+        self.update_thumb_value(self.active_thumb, self.max, cx);
+    }
+
+    fn render_thumb(&self, thumb: Thumb, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
+        let value = self.thumb_value(thumb);
         let entity_id = cx.entity_id();
+        let show_value = self.show_tooltip && self.dragging && self.active_thumb == thumb;
 
         div()
-            .id("slider-thumb")
-            .on_drag(DragThumb(entity_id), |drag, cx| {
+            .id(match thumb {
+                Thumb::Start => "slider-thumb-start",
+                Thumb::End => "slider-thumb",
+            })
+            .on_drag(DragThumb(entity_id, thumb), |drag, cx| {
                 cx.stop_propagation();
                 cx.new_view(|_| drag.clone())
             })
             .on_drag_move(cx.listener(
                 move |view, e: &DragMoveEvent<DragThumb>, cx| match e.drag(cx) {
-                    DragThumb(id) => {
+                    DragThumb(id, thumb) => {
                         if *id != entity_id {
                             return;
                         }
 
+                        view.active_thumb = *thumb;
+                        view.dragging = true;
                         // set value by mouse position
-                        view.update_value_by_position(e.event.position, cx)
+                        view.update_value_by_position(*thumb, e.event.position, cx)
                     }
                 },
             ))
             .absolute()
             .top(px(-5.))
-            .left(relative(self.relative_value()))
+            .left(relative(self.relative_value(value)))
             .ml(-px(8.))
             .size_4()
             .rounded_full()
@@ -145,11 +307,104 @@ impl Slider {
             .border_color(cx.theme().slider_bar.opacity(0.9))
             .when(cx.theme().shadow, |this| this.shadow_md())
             .bg(cx.theme().slider_thumb)
-            .tooltip(move |cx| Tooltip::new(format!("{}", value), cx))
+            .when(!self.dragging, |this| {
+                this.tooltip(move |cx| Tooltip::new(format!("{}", value), cx))
+            })
+            .when(show_value, |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .bottom(px(20.))
+                        .left(px(-12.))
+                        .min_w(px(40.))
+                        .flex()
+                        .justify_center()
+                        .child(
+                            div()
+                                .px_1p5()
+                                .py_0p5()
+                                .rounded(px(4.))
+                                .text_xs()
+                                .bg(cx.theme().popover)
+                                .text_color(cx.theme().popover_foreground)
+                                .border_1()
+                                .border_color(cx.theme().border)
+                                .shadow_md()
+                                .child(format!("{}", value)),
+                        ),
+                )
+            })
+    }
+
+    fn render_ticks(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        const MAX_TICKS: usize = 100;
+
+        if !self.ticks || self.step <= 0. || self.max <= self.min {
+            return None;
+        }
+
+        let count = ((self.max - self.min) / self.step).floor() as usize;
+        if count > MAX_TICKS {
+            return None;
+        }
+
+        Some(div().absolute().size_full().children((0..=count).map(|ix| {
+            let value = self.min + ix as f32 * self.step;
+            div()
+                .absolute()
+                .top_0()
+                .left(relative(self.relative_value(value)))
+                .ml(-px(1.))
+                .w(px(2.))
+                .h_full()
+                .bg(cx.theme().slider_thumb.opacity(0.6))
+        })))
+    }
+
+    fn render_marks(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        if self.marks.is_empty() {
+            return None;
+        }
+
+        Some(
+            div()
+                .relative()
+                .w_full()
+                .h_5()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .children(self.marks.iter().map(|(value, label)| {
+                    h_flex()
+                        .absolute()
+                        .top_0()
+                        .left(relative(self.relative_value(*value)))
+                        .w(px(40.))
+                        .ml(-px(20.))
+                        .justify_center()
+                        .child(label.clone())
+                })),
+        )
     }
 
     fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut gpui::ViewContext<Self>) {
-        self.update_value_by_position(event.position, cx);
+        if let Some(focus_handle) = &self.focus_handle {
+            focus_handle.focus(cx);
+        }
+
+        // Move the nearest thumb to the mouse position in range mode.
+        let value = self.value_by_position(event.position);
+        self.active_thumb = match self.start {
+            Some(start) if (value - start).abs() < (value - self.value).abs() => Thumb::Start,
+            _ => Thumb::End,
+        };
+        self.update_thumb_value(self.active_thumb, value, cx);
+    }
+
+    fn on_mouse_up(&mut self, _: &gpui::MouseUpEvent, cx: &mut gpui::ViewContext<Self>) {
+        if self.dragging {
+            self.dragging = false;
+            cx.notify();
+        }
     }
 }
 
@@ -157,40 +412,73 @@ impl EventEmitter<SliderEvent> for Slider {}
 
 impl Render for Slider {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let is_focused = focus_handle.is_focused(cx);
+
+        let (start, end) = match self.start {
+            Some(start) => (self.relative_value(start), self.relative_value(self.value)),
+            None => (0., self.relative_value(self.value)),
+        };
+
         div()
             .id("slider")
+            .key_context(CONTEXT)
+            .track_focus(&focus_handle)
+            .on_action(cx.listener(Self::on_action_increment))
+            .on_action(cx.listener(Self::on_action_decrement))
+            .on_action(cx.listener(Self::on_action_page_increment))
+            .on_action(cx.listener(Self::on_action_page_decrement))
+            .on_action(cx.listener(Self::on_action_home))
+            .on_action(cx.listener(Self::on_action_end))
             .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
-            .h_5()
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
             .child(
-                div()
-                    .id("slider-bar")
-                    .relative()
-                    .w_full()
-                    .my_1p5()
-                    .h_1p5()
-                    .bg(cx.theme().slider_bar.opacity(0.2))
-                    .active(|this| this.bg(cx.theme().slider_bar.opacity(0.4)))
-                    .rounded(px(3.))
-                    .child(
-                        div()
-                            .absolute()
-                            .top_0()
-                            .left_0()
-                            .h_full()
-                            .w(relative(self.relative_value()))
-                            .bg(cx.theme().slider_bar)
-                            .rounded_l(px(3.)),
-                    )
-                    .child(self.render_thumb(cx))
-                    .child({
-                        let view = cx.view().clone();
-                        canvas(
-                            move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
-                            |_, _, _| {},
+                div().h_5().child(
+                    div()
+                        .id("slider-bar")
+                        .relative()
+                        .w_full()
+                        .my_1p5()
+                        .h_1p5()
+                        .bg(cx.theme().slider_bar.opacity(0.2))
+                        .active(|this| this.bg(cx.theme().slider_bar.opacity(0.4)))
+                        .when(is_focused, |this| {
+                            this.bg(cx.theme().slider_bar.opacity(0.3))
+                        })
+                        .rounded(px(3.))
+                        .child(
+                            div()
+                                .absolute()
+                                .top_0()
+                                .left(relative(start))
+                                .h_full()
+                                .w(relative(end - start))
+                                .bg(cx.theme().slider_bar)
+                                .map(|this| match self.start {
+                                    Some(_) => this,
+                                    None => this.rounded_l(px(3.)),
+                                }),
                         )
-                        .absolute()
-                        .size_full()
-                    }),
+                        .children(self.render_ticks(cx))
+                        .when(self.start.is_some(), |this| {
+                            this.child(self.render_thumb(Thumb::Start, cx))
+                        })
+                        .child(self.render_thumb(Thumb::End, cx))
+                        .child({
+                            let view = cx.view().clone();
+                            canvas(
+                                move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                                |_, _, _| {},
+                            )
+                            .absolute()
+                            .size_full()
+                        }),
+                ),
             )
+            .children(self.render_marks(cx))
     }
 }
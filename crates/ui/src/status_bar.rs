@@ -0,0 +1,311 @@
+use std::{rc::Rc, time::Duration};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnchorCorner, ClickEvent, ElementId, IntoElement,
+    ParentElement as _, Render, RenderOnce, SharedString, Styled as _, Task, View, ViewContext,
+    WindowContext,
+};
+use smol::Timer;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    notification::NotificationType,
+    popup_menu::{PopupMenu, PopupMenuExt as _},
+    progress::Progress,
+    theme::ActiveTheme as _,
+    Icon, Root, Sizable as _,
+};
+
+/// The duration to show the transient message in the status bar.
+const MESSAGE_DURATION: Duration = Duration::from_secs(5);
+
+type PopupBuilder = Rc<dyn Fn(PopupMenu, &mut ViewContext<PopupMenu>) -> PopupMenu + 'static>;
+
+/// A compact item of the [`StatusBar`], with an icon and label, click to run a handler or open a popup menu.
+///
+/// ```ignore
+/// StatusItem::new("language")
+///     .label("Rust")
+///     .popup_menu(|menu, _| {
+///         menu.menu_with_check("Rust", true, Box::new(SelectRust))
+///             .menu_with_check("TOML", false, Box::new(SelectToml))
+///     })
+/// ```
+#[derive(IntoElement, Clone)]
+pub struct StatusItem {
+    id: ElementId,
+    icon: Option<Icon>,
+    label: Option<SharedString>,
+    tooltip: Option<SharedString>,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+    popup_menu: Option<PopupBuilder>,
+    anchor: AnchorCorner,
+}
+
+impl StatusItem {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            icon: None,
+            label: None,
+            tooltip: None,
+            on_click: None,
+            popup_menu: None,
+            anchor: AnchorCorner::BottomLeft,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Open a popup menu above the item when clicked, e.g.: a language selector.
+    pub fn popup_menu(
+        mut self,
+        f: impl Fn(PopupMenu, &mut ViewContext<PopupMenu>) -> PopupMenu + 'static,
+    ) -> Self {
+        self.popup_menu = Some(Rc::new(f));
+        self
+    }
+
+    fn anchor(mut self, anchor: AnchorCorner) -> Self {
+        self.anchor = anchor;
+        self
+    }
+}
+
+impl RenderOnce for StatusItem {
+    fn render(self, _: &mut WindowContext) -> impl IntoElement {
+        let button = Button::new(self.id)
+            .ghost()
+            .xsmall()
+            .compact()
+            .when_some(self.icon, |this, icon| this.icon(icon))
+            .when_some(self.label, |this, label| this.label(label))
+            .when_some(self.tooltip, |this, tooltip| this.tooltip(tooltip))
+            .when_some(self.on_click, |this, on_click| {
+                this.on_click(move |event, cx| on_click(event, cx))
+            });
+
+        match self.popup_menu {
+            Some(popup_menu) => button
+                .popup_menu(move |menu, cx| popup_menu(menu, cx))
+                .anchor(self.anchor)
+                .into_any_element(),
+            None => button.into_any_element(),
+        }
+    }
+}
+
+/// The slot of the items in the [`StatusBar`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum StatusSlot {
+    Left,
+    Center,
+    Right,
+}
+
+struct StatusMessage {
+    text: SharedString,
+    type_: Option<NotificationType>,
+}
+
+/// A status bar at the bottom of the app window.
+///
+/// It has the left, center and right slots of the [`StatusItem`]s, a message area after the left items,
+/// and a progress area before the right items.
+///
+/// ```ignore
+/// let status_bar = cx.new_view(|cx| {
+///     StatusBar::new(cx)
+///         .left(StatusItem::new("branch").icon(IconName::GitHub).label("main"))
+///         .right(StatusItem::new("position").label("Ln 1, Col 1"))
+/// });
+/// // Show the transient notifications in the status bar instead of the popups.
+/// StatusBar::route_notifications(&status_bar, cx);
+/// ```
+pub struct StatusBar {
+    left: Vec<StatusItem>,
+    center: Vec<StatusItem>,
+    right: Vec<StatusItem>,
+    message: Option<StatusMessage>,
+    progress: Option<(SharedString, f32)>,
+    _message_task: Option<Task<()>>,
+}
+
+impl StatusBar {
+    pub fn new(_: &mut ViewContext<Self>) -> Self {
+        Self {
+            left: vec![],
+            center: vec![],
+            right: vec![],
+            message: None,
+            progress: None,
+            _message_task: None,
+        }
+    }
+
+    /// Add an item to the left slot.
+    pub fn left(mut self, item: StatusItem) -> Self {
+        self.left.push(item);
+        self
+    }
+
+    /// Add an item to the center slot.
+    pub fn center(mut self, item: StatusItem) -> Self {
+        self.center.push(item);
+        self
+    }
+
+    /// Add an item to the right slot.
+    pub fn right(mut self, item: StatusItem) -> Self {
+        self.right.push(item);
+        self
+    }
+
+    /// Replace the items of the slot.
+    pub fn set_items(
+        &mut self,
+        slot: StatusSlot,
+        items: impl IntoIterator<Item = StatusItem>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let items = items.into_iter().collect();
+        match slot {
+            StatusSlot::Left => self.left = items,
+            StatusSlot::Center => self.center = items,
+            StatusSlot::Right => self.right = items,
+        }
+        cx.notify();
+    }
+
+    /// Set the message until it is changed, None to clear.
+    pub fn set_message(&mut self, message: Option<SharedString>, cx: &mut ViewContext<Self>) {
+        self._message_task = None;
+        self.message = message.map(|text| StatusMessage { text, type_: None });
+        cx.notify();
+    }
+
+    /// Show a transient message with the type icon, it is hidden after 5 seconds.
+    pub fn show_message(
+        &mut self,
+        message: impl Into<SharedString>,
+        type_: NotificationType,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.message = Some(StatusMessage {
+            text: message.into(),
+            type_: Some(type_),
+        });
+        self._message_task = Some(cx.spawn(|this, mut cx| async move {
+            Timer::after(MESSAGE_DURATION).await;
+            _ = this.update(&mut cx, |this, cx| {
+                this.message = None;
+                cx.notify();
+            });
+        }));
+        cx.notify();
+    }
+
+    /// Set the progress with the label, the value is in 0..100.
+    pub fn set_progress(
+        &mut self,
+        label: impl Into<SharedString>,
+        value: f32,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.progress = Some((label.into(), value));
+        cx.notify();
+    }
+
+    /// Hide the progress.
+    pub fn clear_progress(&mut self, cx: &mut ViewContext<Self>) {
+        self.progress = None;
+        cx.notify();
+    }
+
+    /// Route the transient notifications (autohide, without title, info or success)
+    /// pushed by [`crate::ContextModal::push_notification`] to this status bar.
+    pub fn route_notifications(view: &View<Self>, cx: &mut WindowContext) {
+        let status_bar = view.downgrade();
+        Root::update(cx, move |root, cx| {
+            root.notification.update(cx, |list, _| {
+                list.status_bar = Some(status_bar);
+            });
+        });
+    }
+
+    fn render_items(items: &[StatusItem], anchor: AnchorCorner) -> impl IntoElement {
+        h_flex()
+            .gap_1()
+            .children(items.iter().map(|item| item.clone().anchor(anchor)))
+    }
+}
+
+impl Render for StatusBar {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .h(px(26.))
+            .w_full()
+            .flex_none()
+            .px_1()
+            .gap_2()
+            .justify_between()
+            .text_xs()
+            .text_color(cx.theme().muted_foreground)
+            .bg(cx.theme().title_bar_background)
+            .border_t_1()
+            .border_color(cx.theme().border)
+            .child(
+                h_flex()
+                    .flex_1()
+                    .gap_2()
+                    .overflow_hidden()
+                    .child(Self::render_items(&self.left, AnchorCorner::BottomLeft))
+                    .when_some(self.message.as_ref(), |this, message| {
+                        this.child(
+                            h_flex()
+                                .gap_1()
+                                .overflow_hidden()
+                                .when_some(message.type_.as_ref(), |this, type_| {
+                                    this.child(type_.icon().xsmall())
+                                })
+                                .child(div().truncate().child(message.text.clone())),
+                        )
+                    }),
+            )
+            .child(Self::render_items(&self.center, AnchorCorner::BottomLeft))
+            .child(
+                h_flex()
+                    .flex_1()
+                    .gap_2()
+                    .justify_end()
+                    .when_some(self.progress.clone(), |this, (label, value)| {
+                        this.child(
+                            h_flex()
+                                .gap_1p5()
+                                .child(label)
+                                .child(div().w(px(80.)).child(Progress::new().value(value))),
+                        )
+                    })
+                    .child(Self::render_items(&self.right, AnchorCorner::BottomRight)),
+            )
+    }
+}
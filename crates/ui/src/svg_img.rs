@@ -1,15 +1,136 @@
-use std::{hash::Hash, ops::Deref, sync::Arc};
+use std::{
+    hash::Hash,
+    io::Cursor,
+    ops::Deref,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use gpui::{
     px, size, AppContext, Asset, Bounds, Element, Hitbox, ImageCacheError, InteractiveElement,
     Interactivity, IntoElement, IsZero, Pixels, RenderImage, SharedString, Size, StyleRefinement,
     Styled, WindowContext,
 };
-use image::Frame;
+use image::{
+    codecs::{gif::GifDecoder, png::PngDecoder, webp::WebPDecoder},
+    AnimationDecoder as _, Frame, ImageError, ImageFormat,
+};
 use smallvec::SmallVec;
 
 use image::ImageBuffer;
 
+/// The frame delay shorter than this is treated as [`DEFAULT_FRAME_DELAY`], same as the browsers.
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(20);
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+/// Decode the raster image bytes into BGRA frames.
+///
+/// All the frames of the animated GIF, APNG and WebP are decoded, other images have a single frame.
+pub(crate) fn decode_frames(bytes: &[u8]) -> Result<SmallVec<[Frame; 1]>, ImageError> {
+    let cursor = Cursor::new(bytes);
+    let animation = match image::guess_format(bytes)? {
+        ImageFormat::Gif => Some(GifDecoder::new(cursor)?.into_frames()),
+        ImageFormat::Png => {
+            let decoder = PngDecoder::new(cursor)?;
+            if decoder.is_apng()? {
+                Some(decoder.apng()?.into_frames())
+            } else {
+                None
+            }
+        }
+        ImageFormat::WebP => {
+            let decoder = WebPDecoder::new(cursor)?;
+            if decoder.has_animation() {
+                Some(decoder.into_frames())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+
+    let mut frames = match animation {
+        Some(animation) => SmallVec::from_vec(animation.collect_frames()?),
+        None => SmallVec::from_elem(Frame::new(image::load_from_memory(bytes)?.into_rgba8()), 1),
+    };
+
+    // Convert from RGBA to BGRA.
+    for frame in frames.iter_mut() {
+        for pixel in frame.buffer_mut().chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+    }
+
+    Ok(frames)
+}
+
+/// The playback state of an animated image.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct FramePlayback {
+    /// The index of the frame to paint.
+    pub(crate) frame_ix: usize,
+    /// When the current frame was shown, `None` if not playing.
+    shown_at: Option<Instant>,
+}
+
+impl FramePlayback {
+    /// Advance the frames to the `now`, returns true if there are more frames to play.
+    ///
+    /// Without `looping`, the playback stops at the last frame.
+    pub(crate) fn advance(
+        &mut self,
+        frame_count: usize,
+        delay: impl Fn(usize) -> Duration,
+        looping: bool,
+        now: Instant,
+    ) -> bool {
+        if frame_count <= 1 {
+            self.frame_ix = 0;
+            return false;
+        }
+
+        let last_ix = frame_count - 1;
+        self.frame_ix = self.frame_ix.min(last_ix);
+        if self.is_finished(frame_count, looping) {
+            self.shown_at = None;
+            return false;
+        }
+
+        let shown_at = *self.shown_at.get_or_insert(now);
+        let mut elapsed = now.saturating_duration_since(shown_at);
+        loop {
+            let delay = match delay(self.frame_ix) {
+                delay if delay < MIN_FRAME_DELAY => DEFAULT_FRAME_DELAY,
+                delay => delay,
+            };
+            if elapsed < delay {
+                self.shown_at = Some(now - elapsed);
+                return true;
+            }
+
+            elapsed -= delay;
+            if self.frame_ix < last_ix {
+                self.frame_ix += 1;
+            } else if looping {
+                self.frame_ix = 0;
+            } else {
+                self.shown_at = None;
+                return false;
+            }
+        }
+    }
+
+    /// Returns true if the playback without looping has reached the last frame.
+    pub(crate) fn is_finished(&self, frame_count: usize, looping: bool) -> bool {
+        !looping && self.frame_ix + 1 >= frame_count
+    }
+
+    /// Pause at the current frame, the frame is shown for its full delay after resuming.
+    pub(crate) fn pause(&mut self) {
+        self.shown_at = None;
+    }
+}
+
 #[derive(Debug, Clone, Hash)]
 pub enum SvgSource {
     /// A svg bytes
@@ -48,6 +169,8 @@ impl Clone for SvgImg {
             interactivity: Interactivity::default(),
             source: self.source.clone(),
             size: self.size,
+            playing: self.playing,
+            looping: self.looping,
         }
     }
 }
@@ -103,6 +226,11 @@ impl Asset for Image {
                 }
             };
 
+            // The raster images, e.g.: PNG, JPEG and the animated GIF.
+            if image::guess_format(&bytes).is_ok() {
+                return Ok(Arc::new(RenderImage::new(decode_frames(&bytes)?)));
+            }
+
             let options = usvg::Options {
                 ..Default::default()
             };
@@ -134,7 +262,9 @@ impl Asset for Image {
     }
 }
 
-/// An SVG image element.
+/// An SVG image element, the raster images (PNG, JPEG, GIF, WebP) are also supported.
+///
+/// The animated GIF, APNG and WebP are played when the element has an `id` to keep the playback state.
 pub fn svg_img() -> SvgImg {
     SvgImg::new()
 }
@@ -143,6 +273,8 @@ pub struct SvgImg {
     interactivity: Interactivity,
     source: Option<SvgSource>,
     size: Size<Pixels>,
+    playing: bool,
+    looping: bool,
 }
 
 impl SvgImg {
@@ -154,9 +286,29 @@ impl SvgImg {
             interactivity: Interactivity::default(),
             source: None,
             size: Size::default(),
+            playing: true,
+            looping: true,
         }
     }
 
+    /// Set to play the frames of the animated image, default is true.
+    ///
+    /// When paused, the current frame is kept.
+    #[must_use]
+    pub fn playing(mut self, playing: bool) -> Self {
+        self.playing = playing;
+        self
+    }
+
+    /// Set to play the animated image repeatedly, default is true.
+    ///
+    /// Otherwise, it stops at the last frame.
+    #[must_use]
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
     /// Set the path of the svg image from the asset.
     ///
     /// The `size` argument is the size of the original svg image.
@@ -221,6 +373,8 @@ impl Element for SvgImg {
         cx: &mut WindowContext,
     ) {
         let source = self.source.clone();
+        let playing = self.playing;
+        let looping = self.looping;
 
         self.interactivity
             .paint(global_id, bounds, hitbox.as_ref(), cx, |_style, cx| {
@@ -236,6 +390,27 @@ impl Element for SvgImg {
                 };
 
                 if let Some(data) = data {
+                    let frame_ix = match global_id {
+                        Some(global_id) if data.frame_count() > 1 => {
+                            cx.with_element_state(global_id, |state, cx| {
+                                let mut playback: FramePlayback = state.unwrap_or_default();
+                                if !playing {
+                                    playback.pause();
+                                } else if playback.advance(
+                                    data.frame_count(),
+                                    |ix| data.delay(ix).into(),
+                                    looping,
+                                    Instant::now(),
+                                ) {
+                                    cx.request_animation_frame();
+                                }
+
+                                (playback.frame_ix, playback)
+                            })
+                        }
+                        _ => 0,
+                    };
+
                     // To calculate the ratio of the original image size to the container bounds size.
                     // Scale by shortest side (width or height) to get a fit image.
                     // And center the image in the container bounds.
@@ -262,7 +437,7 @@ impl Element for SvgImg {
                         size: new_size.map(|size| size.ceil()),
                     };
 
-                    match cx.paint_image(img_bounds, px(0.).into(), data, 0, false) {
+                    match cx.paint_image(img_bounds, px(0.).into(), data, frame_ix, false) {
                         Ok(_) => {}
                         Err(err) => eprintln!("failed to paint svg image: {:?}", err),
                     }
@@ -282,3 +457,47 @@ impl InteractiveElement for SvgImg {
         &mut self.interactivity
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::FramePlayback;
+
+    #[test]
+    fn test_frame_playback() {
+        let delay = |ix: usize| Duration::from_millis([50, 100, 0][ix]);
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+
+        let mut playback = FramePlayback::default();
+        assert!(playback.advance(3, delay, true, at(0)));
+        assert_eq!(playback.frame_ix, 0);
+        assert!(playback.advance(3, delay, true, at(60)));
+        assert_eq!(playback.frame_ix, 1);
+        // The zero delay of the last frame is treated as 100ms.
+        assert!(playback.advance(3, delay, true, at(200)));
+        assert_eq!(playback.frame_ix, 2);
+        assert!(playback.advance(3, delay, true, at(260)));
+        assert_eq!(playback.frame_ix, 0);
+
+        // Stop at the last frame without looping.
+        let mut playback = FramePlayback::default();
+        assert!(playback.advance(3, delay, false, at(0)));
+        assert!(!playback.advance(3, delay, false, at(1000)));
+        assert_eq!(playback.frame_ix, 2);
+        assert!(playback.is_finished(3, false));
+
+        // Resume from the paused frame.
+        let mut playback = FramePlayback::default();
+        assert!(playback.advance(3, delay, true, at(0)));
+        assert!(playback.advance(3, delay, true, at(60)));
+        assert_eq!(playback.frame_ix, 1);
+        playback.pause();
+        assert!(playback.advance(3, delay, true, at(1000)));
+        assert_eq!(playback.frame_ix, 1);
+
+        // A single frame is not played.
+        assert!(!FramePlayback::default().advance(1, delay, true, at(0)));
+    }
+}
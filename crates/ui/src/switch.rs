@@ -1,10 +1,15 @@
-use std::{cell::RefCell, rc::Rc, time::Duration};
+use std::{cell::RefCell, rc::Rc};
 
-use crate::{h_flex, theme::ActiveTheme, Disableable, Sizable, Size};
+use crate::{
+    animation::{ease_standard, motion, DURATION_FAST},
+    h_flex,
+    theme::ActiveTheme,
+    Disableable, Sizable, Size,
+};
 use gpui::{
-    div, prelude::FluentBuilder as _, px, Animation, AnimationExt as _, AnyElement, Element,
-    ElementId, GlobalElementId, InteractiveElement, IntoElement, LayoutId, ParentElement as _,
-    SharedString, Styled as _, WindowContext,
+    div, prelude::FluentBuilder as _, px, AnimationExt as _, AnyElement, Element, ElementId,
+    GlobalElementId, InteractiveElement, IntoElement, LayoutId, ParentElement as _, SharedString,
+    Styled as _, WindowContext,
 };
 
 type OnClick = Rc<dyn Fn(&bool, &mut WindowContext)>;
@@ -167,7 +172,7 @@ impl Element for Switch {
                                             .borrow()
                                             .map_or(false, |prev| prev != checked)
                                     {
-                                        let dur = Duration::from_secs_f64(0.15);
+                                        let dur = DURATION_FAST;
                                         cx.spawn(|cx| async move {
                                             cx.background_executor().timer(dur).await;
 
@@ -179,7 +184,7 @@ impl Element for Switch {
                                                 "move".into(),
                                                 checked as usize,
                                             ),
-                                            Animation::new(dur),
+                                            motion(dur, ease_standard(), cx),
                                             move |this, delta| {
                                                 let max_x = bg_width - bar_width - inset * 2;
                                                 let x = if checked {
@@ -2,7 +2,7 @@ use crate::theme::ActiveTheme;
 use crate::Selectable;
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
-    div, px, AnyElement, Div, ElementId, InteractiveElement, IntoElement, ParentElement as _,
+    div, px, AnyElement, Div, ElementId, InteractiveElement, IntoElement, ParentElement,
     RenderOnce, Stateful, StatefulInteractiveElement, Styled, WindowContext,
 };
 
@@ -63,6 +63,12 @@ impl InteractiveElement for Tab {
 
 impl StatefulInteractiveElement for Tab {}
 
+impl ParentElement for Tab {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.base.extend(elements)
+    }
+}
+
 impl Styled for Tab {
     fn style(&mut self) -> &mut gpui::StyleRefinement {
         self.base.style()
@@ -2,7 +2,7 @@ use crate::h_flex;
 use crate::theme::ActiveTheme;
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
-    div, AnyElement, Div, ElementId, IntoElement, ParentElement, RenderOnce, ScrollHandle,
+    div, AnyElement, Div, ElementId, Hsla, IntoElement, ParentElement, RenderOnce, ScrollHandle,
     StatefulInteractiveElement as _, Styled, WindowContext,
 };
 use gpui::{px, InteractiveElement};
@@ -49,12 +49,55 @@ impl TabBar {
     }
 }
 
+/// Return whether the tabs can be scrolled to the (left, right), `count` is the number of the children.
+pub(crate) fn scrollable_edges(scroll_handle: &ScrollHandle, count: usize) -> (bool, bool) {
+    let Some(last_bounds) = count
+        .checked_sub(1)
+        .and_then(|ix| scroll_handle.bounds_for_item(ix))
+    else {
+        return (false, false);
+    };
+
+    let offset_x = scroll_handle.offset().x;
+    let bounds = scroll_handle.bounds();
+    // The children bounds are not scrolled, so add the offset to get the visible position.
+    let can_scroll_left = offset_x < px(0.);
+    let can_scroll_right = last_bounds.right() + offset_x > bounds.right() + px(1.);
+
+    (can_scroll_left, can_scroll_right)
+}
+
+/// A fade at the edge of the tabs to hint there are more tabs to scroll.
+fn edge_fade(color: Hsla, left: bool) -> impl IntoElement {
+    const STEPS: [f32; 4] = [0.9, 0.6, 0.35, 0.15];
+
+    h_flex()
+        .absolute()
+        .top_0()
+        .bottom(px(1.))
+        .map(|this| if left { this.left_0() } else { this.right_0() })
+        .children(STEPS.iter().enumerate().map(move |(ix, opacity)| {
+            let opacity = if left {
+                *opacity
+            } else {
+                STEPS[STEPS.len() - 1 - ix]
+            };
+            div().h_full().w(px(4.)).bg(color.opacity(opacity))
+        }))
+}
+
 impl ParentElement for TabBar {
     fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
         self.children.extend(elements)
     }
 }
 
+impl InteractiveElement for TabBar {
+    fn interactivity(&mut self) -> &mut gpui::Interactivity {
+        self.base.interactivity()
+    }
+}
+
 impl Styled for TabBar {
     fn style(&mut self) -> &mut gpui::StyleRefinement {
         self.base.style()
@@ -63,6 +106,10 @@ impl Styled for TabBar {
 
 impl RenderOnce for TabBar {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (can_scroll_left, can_scroll_right) =
+            scrollable_edges(&self.scroll_handle, self.children.len());
+        let bg = cx.theme().tab_bar;
+
         self.base
             .id(self.id)
             .group("tab-bar")
@@ -83,12 +130,20 @@ impl RenderOnce for TabBar {
             )
             .when_some(self.prefix, |this, prefix| this.child(prefix))
             .child(
-                h_flex()
-                    .id("tabs")
+                div()
+                    .relative()
                     .flex_grow()
-                    .overflow_x_scroll()
-                    .track_scroll(&self.scroll_handle)
-                    .children(self.children),
+                    .overflow_hidden()
+                    .child(
+                        h_flex()
+                            .id("tabs")
+                            .w_full()
+                            .overflow_x_scroll()
+                            .track_scroll(&self.scroll_handle)
+                            .children(self.children),
+                    )
+                    .when(can_scroll_left, |this| this.child(edge_fade(bg, true)))
+                    .when(can_scroll_right, |this| this.child(edge_fade(bg, false))),
             )
             .when_some(self.suffix, |this, suffix| this.child(suffix))
     }
@@ -1,18 +1,39 @@
-use std::{cell::Cell, ops::Range, rc::Rc};
+mod cell;
+mod column;
+mod editing;
+mod export;
+mod grouping;
+mod selection;
+mod state;
+
+use std::{cell::Cell, collections::HashSet, ops::Range, rc::Rc};
 
 use crate::{
+    animation::{transition, TransitionExt as _, TransitionProperty, DURATION_FAST},
+    context_menu::ContextMenuExt as _,
     h_flex,
-    scroll::{ScrollableAxis, ScrollableMask, Scrollbar, ScrollbarState},
+    scroll::{ListScrollHandle, ScrollableAxis, ScrollableMask, Scrollbar, ScrollbarState},
     theme::ActiveTheme,
-    v_flex, Icon, IconName, Sizable, Size, StyleSized as _,
+    v_flex, ContextModal as _, Icon, IconName, Sizable, Size, StyleSized as _,
 };
 use gpui::{
-    actions, canvas, div, prelude::FluentBuilder, px, uniform_list, AppContext, Bounds, Div,
-    DragMoveEvent, Edges, Entity, EntityId, EventEmitter, FocusHandle, FocusableView,
-    InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point, Render,
+    actions, canvas, div, impl_actions, list, point, prelude::FluentBuilder, px, uniform_list,
+    AnyElement, AppContext, Bounds, ClickEvent, Div, DragMoveEvent, Edges, Entity, EntityId,
+    EventEmitter, FocusHandle, FocusableView, InteractiveElement, IntoElement, KeyBinding,
+    ListAlignment, ListState, MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render,
     ScrollHandle, SharedString, StatefulInteractiveElement as _, Styled, UniformListScrollHandle,
     ViewContext, VisualContext as _, WindowContext,
 };
+use rust_i18n::t;
+use serde::Deserialize;
+
+use grouping::{Groups, TableRow};
+use selection::Movement;
+
+pub use cell::*;
+pub use column::*;
+pub use editing::ColEditor;
+pub use state::*;
 
 actions!(
     table,
@@ -21,10 +42,36 @@ actions!(
         SelectPrev,
         SelectNext,
         SelectPrevColumn,
-        SelectNextColumn
+        SelectNextColumn,
+        SelectFirst,
+        SelectLast,
+        SelectTop,
+        SelectBottom,
+        SelectPageUp,
+        SelectPageDown,
+        SelectToPrev,
+        SelectToNext,
+        SelectToPrevColumn,
+        SelectToNextColumn,
+        SelectToFirst,
+        SelectToLast,
+        SelectToTop,
+        SelectToBottom,
+        SelectToPageUp,
+        SelectToPageDown,
+        Copy,
+        CopyAsCsv,
+        CopyAsMarkdown,
+        EditCell
     ]
 );
 
+/// Action dispatched by the header context menu to show or hide the column.
+#[derive(Clone, PartialEq, Deserialize)]
+struct ToggleColumnVisible(usize);
+
+impl_actions!(table, [ToggleColumnVisible]);
+
 pub fn init(cx: &mut AppContext) {
     let context = Some("Table");
     cx.bind_keys([
@@ -33,21 +80,60 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("down", SelectNext, context),
         KeyBinding::new("left", SelectPrevColumn, context),
         KeyBinding::new("right", SelectNextColumn, context),
+        KeyBinding::new("home", SelectFirst, context),
+        KeyBinding::new("end", SelectLast, context),
+        KeyBinding::new("pageup", SelectPageUp, context),
+        KeyBinding::new("pagedown", SelectPageDown, context),
+        KeyBinding::new("shift-up", SelectToPrev, context),
+        KeyBinding::new("shift-down", SelectToNext, context),
+        KeyBinding::new("shift-left", SelectToPrevColumn, context),
+        KeyBinding::new("shift-right", SelectToNextColumn, context),
+        KeyBinding::new("shift-home", SelectToFirst, context),
+        KeyBinding::new("shift-end", SelectToLast, context),
+        KeyBinding::new("shift-pageup", SelectToPageUp, context),
+        KeyBinding::new("shift-pagedown", SelectToPageDown, context),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-up", SelectTop, context),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-down", SelectBottom, context),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("shift-cmd-up", SelectToTop, context),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("shift-cmd-down", SelectToBottom, context),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-c", Copy, context),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-home", SelectTop, context),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-end", SelectBottom, context),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-home", SelectToTop, context),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-end", SelectToBottom, context),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-c", Copy, context),
+        KeyBinding::new("enter", EditCell, context),
+        KeyBinding::new("f2", EditCell, context),
     ]);
 }
 
+/// The width of the leading column to toggle the row detail.
+const EXPAND_COL_WIDTH: Pixels = px(28.);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ColFixed {
     Left,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone)]
 struct ColGroup {
+    column: Column,
     width: Option<Pixels>,
     bounds: Bounds<Pixels>,
     sort: Option<ColSort>,
-    fixed: Option<ColFixed>,
-    padding: Option<Edges<Pixels>>,
+    /// The priority of the sort key, the smaller is the higher.
+    sort_order: usize,
+    visible: bool,
 }
 
 #[derive(Clone)]
@@ -68,6 +154,21 @@ pub enum ColSort {
     Descending,
 }
 
+impl ColSort {
+    /// Return the next sort state when clicking the column.
+    fn toggle(self) -> Self {
+        match self {
+            ColSort::Ascending => ColSort::Descending,
+            ColSort::Descending => ColSort::Ascending,
+            ColSort::Default => ColSort::Descending,
+        }
+    }
+
+    fn is_sorted(&self) -> bool {
+        !matches!(self, ColSort::Default)
+    }
+}
+
 impl Render for DragCol {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         div()
@@ -91,13 +192,18 @@ pub struct ResizeCol(pub (EntityId, usize));
 enum SelectionState {
     Column,
     Row,
+    Cell,
 }
 
 #[derive(Clone)]
 pub enum TableEvent {
     SelectRow(usize),
     SelectCol(usize),
+    /// The active cell `(row_ix, col_ix)` is selected, when the table is [`Table::cell_selectable`].
+    SelectCell(usize, usize),
     ColWidthsChanged(Vec<Option<Pixels>>),
+    /// The column widths, order or visibility changed, the state can be persisted to restore the layout.
+    LayoutChanged(TableState),
 }
 
 pub struct Table<D: TableDelegate> {
@@ -120,6 +226,9 @@ pub struct Table<D: TableDelegate> {
     selection_state: SelectionState,
     selected_row: Option<usize>,
     selected_col: Option<usize>,
+    /// The `(row_ix, col_ix)` where the range selection starts, the range ends at the selected cell.
+    selection_anchor: Option<(usize, usize)>,
+    cell_selectable: bool,
 
     /// The column index that is being resized.
     resizing_col: Option<usize>,
@@ -130,6 +239,14 @@ pub struct Table<D: TableDelegate> {
     border: bool,
     /// The cell size of the table.
     size: Size,
+    /// The number of the leading columns to pin, overrides the delegate's `col_fixed`.
+    fixed_cols: Option<usize>,
+    editing: Option<editing::EditingCell>,
+    /// The list state to render the rows in variable height, when the rows are expandable.
+    list_state: Option<ListState>,
+    expanded_rows: HashSet<usize>,
+    groups: Groups,
+    collapsed_groups: HashSet<usize>,
 }
 
 #[allow(unused)]
@@ -139,8 +256,27 @@ pub trait TableDelegate: Sized + 'static {
     /// Return the number of rows in the table.
     fn rows_count(&self) -> usize;
 
+    /// Return the definition of the column at the given index.
+    ///
+    /// Default is built from the `col_*` methods, override this to define the column in one place.
+    ///
+    /// This is only called when the table initializes or refreshes.
+    fn column(&self, col_ix: usize) -> Column {
+        let mut column = Column::new(self.col_name(col_ix)).resizable(self.can_resize_col(col_ix));
+        column.width = self.col_width(col_ix);
+        column.sort = self.col_sort(col_ix);
+        column.fixed = self.col_fixed(col_ix);
+        column.padding = self.col_padding(col_ix);
+        if let Some(format) = self.col_format(col_ix) {
+            column = column.format(format);
+        }
+        column
+    }
+
     /// Returns the name of the column at the given index.
-    fn col_name(&self, col_ix: usize) -> SharedString;
+    fn col_name(&self, col_ix: usize) -> SharedString {
+        SharedString::default()
+    }
 
     /// Returns whether the column at the given index can be resized. Default: true
     fn can_resize_col(&self, col_ix: usize) -> bool {
@@ -156,7 +292,9 @@ pub trait TableDelegate: Sized + 'static {
     /// Return None, use auto width.
     ///
     /// This is only called when the table initializes.
-    fn col_width(&self, col_ix: usize) -> Option<Pixels>;
+    fn col_width(&self, col_ix: usize) -> Option<Pixels> {
+        None
+    }
 
     /// Return the sort state of the column at the given index.
     ///
@@ -180,9 +318,21 @@ pub trait TableDelegate: Sized + 'static {
     /// Perform sort on the column at the given index.
     fn perform_sort(&mut self, col_ix: usize, sort: ColSort, cx: &mut ViewContext<Table<Self>>) {}
 
+    /// Called when the sort keys of the table changed, the `sorts` is the `(col_ix, sort)` in priority order.
+    ///
+    /// Clicking the header sorts by the column only, Shift+click adds the column as the secondary sort key.
+    ///
+    /// Default calls [`TableDelegate::perform_sort`] with the primary sort key,
+    /// override this to sort by multiple columns, or to refetch the data.
+    fn sort_changed(&mut self, sorts: Vec<(usize, ColSort)>, cx: &mut ViewContext<Table<Self>>) {
+        if let Some((col_ix, sort)) = sorts.first() {
+            self.perform_sort(*col_ix, *sort, cx);
+        }
+    }
+
     /// Render the header cell at the given column index, default to the column name.
     fn render_th(&self, col_ix: usize, cx: &mut ViewContext<Table<Self>>) -> impl IntoElement {
-        div().size_full().child(self.col_name(col_ix))
+        div().child(self.column(col_ix).name)
     }
 
     /// Render the row at the given row and column.
@@ -191,12 +341,80 @@ pub trait TableDelegate: Sized + 'static {
     }
 
     /// Render cell at the given row and column.
+    ///
+    /// This is not called if the column has a `format` or `render` in the [`Column`] definition.
     fn render_td(
         &self,
         row_ix: usize,
         col_ix: usize,
         cx: &mut ViewContext<Table<Self>>,
-    ) -> impl IntoElement;
+    ) -> impl IntoElement {
+        div()
+    }
+
+    /// Return the built-in cell format of the column at the given index.
+    ///
+    /// If return Some, the cell will be rendered by the format with the value of [`TableDelegate::cell_value`],
+    /// instead of [`TableDelegate::render_td`].
+    ///
+    /// This is only called when the table initializes.
+    fn col_format(&self, col_ix: usize) -> Option<CellFormat> {
+        None
+    }
+
+    /// Called when the cell is edited by the `editor` of the [`Column`], the `value` is the new value of the cell.
+    ///
+    /// The editing starts by double-clicking or pressing Enter on the cell,
+    /// commits on Enter or blur, and cancels on Escape.
+    fn cell_edited(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        value: CellValue,
+        cx: &mut ViewContext<Table<Self>>,
+    ) {
+    }
+
+    /// Return the value of the cell at the given row and column,
+    /// this is used by the built-in cell formats and the `render` of the [`Column`].
+    fn cell_value(&self, row_ix: usize, col_ix: usize) -> CellValue {
+        CellValue::Empty
+    }
+
+    /// Return true if the row can be expanded to show the detail, see [`Table::expandable`].
+    fn can_expand_row(&self, row_ix: usize) -> bool {
+        false
+    }
+
+    /// Render the detail of the expanded row, it is full-width below the row.
+    fn render_row_detail(&self, row_ix: usize, cx: &mut ViewContext<Table<Self>>) -> AnyElement {
+        div().into_any_element()
+    }
+
+    /// Return the number of groups to group the rows, default is 0 that means the table is not grouped.
+    ///
+    /// The rows are grouped in order, e.g.: grouped by the value of a column,
+    /// the rows of the first group are `0..group_rows_count(0)`.
+    /// Keep the rows sorted within each group in [`TableDelegate::sort_changed`] to preserve the groups.
+    fn groups_count(&self) -> usize {
+        0
+    }
+
+    /// Return the number of rows in the group.
+    fn group_rows_count(&self, group_ix: usize) -> usize {
+        0
+    }
+
+    /// Return the title of the group header.
+    fn group_title(&self, group_ix: usize) -> SharedString {
+        SharedString::default()
+    }
+
+    /// Return the aggregate value (e.g.: sum, avg or count) of the column in the group,
+    /// it is rendered in the group header by the format of the column.
+    fn group_aggregate(&self, group_ix: usize, col_ix: usize) -> Option<CellValue> {
+        None
+    }
 
     /// Return true to enable loop selection on the table.
     ///
@@ -266,6 +484,8 @@ where
             selection_state: SelectionState::Row,
             selected_row: None,
             selected_col: None,
+            selection_anchor: None,
+            cell_selectable: false,
             resizing_col: None,
             bounds: Bounds::default(),
             fixed_head_cols_bounds: Bounds::default(),
@@ -273,6 +493,12 @@ where
             stripe: false,
             border: true,
             size: Size::default(),
+            fixed_cols: None,
+            editing: None,
+            list_state: None,
+            expanded_rows: HashSet::new(),
+            groups: Groups::default(),
+            collapsed_groups: HashSet::new(),
         };
 
         this.prepare_col_groups(cx);
@@ -304,6 +530,122 @@ where
         self
     }
 
+    /// Allow to expand the rows to show the detail by [`TableDelegate::render_row_detail`],
+    /// a chevron column is added before the columns to toggle the rows.
+    ///
+    /// The rows are rendered in variable height, the vertical scrollbar is estimated by the row height in this mode.
+    pub fn expandable(mut self, cx: &mut ViewContext<Self>) -> Self {
+        let view = cx.view().downgrade();
+        self.list_state = Some(ListState::new(
+            self.delegate.rows_count(),
+            ListAlignment::Top,
+            px(100.),
+            move |row_ix, cx| {
+                view.upgrade()
+                    .map(|view| {
+                        view.update(cx, |table, cx| table.render_expandable_row(row_ix, cx))
+                    })
+                    .unwrap_or_else(|| div().into_any_element())
+            },
+        ));
+        self
+    }
+
+    /// Return true if the row is expanded.
+    pub fn is_row_expanded(&self, row_ix: usize) -> bool {
+        self.expanded_rows.contains(&row_ix)
+    }
+
+    /// Expand or collapse the row.
+    ///
+    /// The expanded rows are collapsed when the rows are removed or by [`Table::refresh`],
+    /// because the row indices may point to the other rows.
+    pub fn set_row_expanded(&mut self, row_ix: usize, expanded: bool, cx: &mut ViewContext<Self>) {
+        let changed = if expanded {
+            self.expanded_rows.insert(row_ix)
+        } else {
+            self.expanded_rows.remove(&row_ix)
+        };
+        if !changed {
+            return;
+        }
+
+        // Remeasure the row for the new height.
+        if let (Some(list_state), Some(ix)) = (&self.list_state, self.groups.display_row(row_ix)) {
+            if ix < list_state.item_count() {
+                list_state.splice(ix..ix + 1, 1);
+            }
+        }
+        cx.notify();
+    }
+
+    /// Return true if the group is collapsed.
+    pub fn is_group_collapsed(&self, group_ix: usize) -> bool {
+        self.collapsed_groups.contains(&group_ix)
+    }
+
+    /// Collapse or expand the group, the rows of the collapsed group are hidden.
+    pub fn set_group_collapsed(
+        &mut self,
+        group_ix: usize,
+        collapsed: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let changed = if collapsed {
+            self.collapsed_groups.insert(group_ix)
+        } else {
+            self.collapsed_groups.remove(&group_ix)
+        };
+        if !changed {
+            return;
+        }
+
+        self.prepare_groups();
+        if let Some(list_state) = &self.list_state {
+            list_state.reset(self.display_rows_count());
+        }
+        cx.notify();
+    }
+
+    fn prepare_groups(&mut self) {
+        self.groups = Groups::new((0..self.delegate.groups_count()).map(|group_ix| {
+            (
+                self.delegate.group_rows_count(group_ix),
+                self.collapsed_groups.contains(&group_ix),
+            )
+        }));
+    }
+
+    /// Return the number of the display rows, including the group headers.
+    fn display_rows_count(&self) -> usize {
+        self.groups.display_rows_count(self.delegate.rows_count())
+    }
+
+    /// Pin the first `count` columns on the left side, they stay visible when scrolling horizontally.
+    ///
+    /// This overrides the [`TableDelegate::col_fixed`].
+    pub fn fixed_cols(mut self, count: usize) -> Self {
+        self.fixed_cols = Some(count);
+        self.apply_fixed_cols();
+        self
+    }
+
+    pub fn set_fixed_cols(&mut self, count: usize, cx: &mut ViewContext<Self>) {
+        self.fixed_cols = Some(count);
+        self.apply_fixed_cols();
+        cx.notify();
+    }
+
+    fn apply_fixed_cols(&mut self) {
+        let Some(count) = self.fixed_cols else {
+            return;
+        };
+
+        for (ix, group) in self.col_groups.iter_mut().enumerate() {
+            group.column.fixed = (ix < count).then_some(ColFixed::Left);
+        }
+    }
+
     /// Set the size to the table.
     pub fn set_size(&mut self, size: Size, cx: &mut ViewContext<Self>) {
         self.size = size;
@@ -312,32 +654,69 @@ where
 
     /// When we update columns or rows, we need to refresh the table.
     pub fn refresh(&mut self, cx: &mut ViewContext<Self>) {
+        self.expanded_rows.clear();
+        self.prepare_groups();
+        if let Some(list_state) = &self.list_state {
+            list_state.reset(self.display_rows_count());
+        }
         self.prepare_col_groups(cx);
     }
 
     fn prepare_col_groups(&mut self, cx: &mut ViewContext<Self>) {
         self.col_groups = (0..self.delegate.cols_count())
-            .map(|col_ix| ColGroup {
-                width: self.delegate.col_width(col_ix),
-                padding: self.delegate.col_padding(col_ix),
-                bounds: Bounds::default(),
-                sort: self.delegate.col_sort(col_ix),
-                fixed: self.delegate.col_fixed(col_ix),
+            .map(|col_ix| {
+                let column = self.delegate.column(col_ix);
+                ColGroup {
+                    width: column.width.map(|width| column.clamp_width(width)),
+                    bounds: Bounds::default(),
+                    sort: column.sort,
+                    sort_order: 0,
+                    visible: column.visible,
+                    column,
+                }
             })
             .collect();
+        self.apply_fixed_cols();
         cx.notify();
     }
 
     fn scroll_to_row(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
-        self.vertical_scroll_handle.scroll_to_item(row_ix);
+        if let Some(ix) = self.groups.display_row(row_ix) {
+            self.scroll_to_display_row(ix, cx);
+        }
+    }
+
+    fn scroll_to_display_row(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        match &self.list_state {
+            Some(list_state) => list_state.scroll_to_reveal_item(ix),
+            None => self.vertical_scroll_handle.scroll_to_item(ix),
+        }
         cx.notify();
     }
 
+    /// Keep the rows count of the list state same as the delegate.
+    ///
+    /// The new rows are appended at the end (e.g.: by `load_more`), otherwise the rows are replaced,
+    /// so the list state is reset and the expanded rows are collapsed.
+    fn sync_rows_count(&mut self) {
+        let Some(list_state) = &self.list_state else {
+            return;
+        };
+        let rows_count = self.display_rows_count();
+        let old_count = list_state.item_count();
+        if rows_count > old_count {
+            list_state.splice(old_count..old_count, rows_count - old_count);
+        } else if rows_count < old_count {
+            list_state.reset(rows_count);
+            self.expanded_rows.clear();
+        }
+    }
+
     fn set_selected_row(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
         self.selection_state = SelectionState::Row;
         self.selected_row = Some(row_ix);
         if let Some(row_ix) = self.selected_row {
-            self.vertical_scroll_handle.scroll_to_item(row_ix);
+            self.scroll_to_row(row_ix, cx);
         }
         cx.emit(TableEvent::SelectRow(row_ix));
         cx.notify();
@@ -356,10 +735,114 @@ where
         cx.notify();
     }
 
-    fn on_row_click(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
+    fn on_row_click(&mut self, row_ix: usize, event: &MouseDownEvent, cx: &mut ViewContext<Self>) {
+        let extend = event.modifiers.shift;
+        if self.cell_selectable {
+            if let Some(col_ix) = self.col_ix_at(event.position.x) {
+                self.select_cell(row_ix, col_ix, extend, cx);
+            }
+            return;
+        }
+
+        self.update_anchor(extend);
         self.set_selected_row(row_ix, cx)
     }
 
+    /// Return the visible column at the x position, the fixed columns are on top of the others.
+    fn col_ix_at(&self, x: Pixels) -> Option<usize> {
+        self.col_groups
+            .iter()
+            .position(|group| group.visible && group.bounds.left() <= x && x < group.bounds.right())
+    }
+
+    /// Return the column layout of the table, used to persist the layout.
+    pub fn dump(&self) -> TableState {
+        TableState {
+            columns: self
+                .col_groups
+                .iter()
+                .map(|group| ColumnState {
+                    key: group.column.key.clone(),
+                    width: group.width,
+                    visible: group.visible,
+                })
+                .collect(),
+        }
+    }
+
+    /// Restore the column layout of the table from the state, the unknown columns in the state are ignored.
+    pub fn load(&mut self, state: TableState, cx: &mut ViewContext<Self>) {
+        let mut to_ix = 0;
+        for col_state in state.columns {
+            let Some(col_ix) = self
+                .col_groups
+                .iter()
+                .position(|group| group.column.key == col_state.key)
+            else {
+                continue;
+            };
+
+            if col_ix != to_ix {
+                self.delegate.move_col(col_ix, to_ix);
+                let col_group = self.col_groups.remove(col_ix);
+                self.col_groups.insert(to_ix, col_group);
+            }
+            let group = &mut self.col_groups[to_ix];
+            group.width = col_state.width.map(|width| group.column.clamp_width(width));
+            group.visible = col_state.visible;
+            to_ix += 1;
+        }
+        self.apply_fixed_cols();
+
+        cx.emit(TableEvent::LayoutChanged(self.dump()));
+        cx.notify();
+    }
+
+    /// Show or hide the column at the given index, the last visible column can't be hidden.
+    pub fn set_col_visible(&mut self, col_ix: usize, visible: bool, cx: &mut ViewContext<Self>) {
+        if !visible && self.col_groups.iter().filter(|group| group.visible).count() <= 1 {
+            return;
+        }
+        let Some(group) = self.col_groups.get_mut(col_ix) else {
+            return;
+        };
+        if group.visible == visible {
+            return;
+        }
+
+        group.visible = visible;
+        cx.emit(TableEvent::LayoutChanged(self.dump()));
+        cx.notify();
+    }
+
+    fn on_action_toggle_column_visible(
+        &mut self,
+        action: &ToggleColumnVisible,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let col_ix = action.0;
+        if let Some(group) = self.col_groups.get(col_ix) {
+            self.set_col_visible(col_ix, !group.visible, cx);
+        }
+    }
+
+    /// Return the sort keys of the table in priority order.
+    pub fn sorts(&self) -> Vec<(usize, ColSort)> {
+        let mut sorts = self
+            .col_groups
+            .iter()
+            .enumerate()
+            .filter_map(|(ix, group)| {
+                group
+                    .sort
+                    .filter(ColSort::is_sorted)
+                    .map(|sort| (group.sort_order, ix, sort))
+            })
+            .collect::<Vec<_>>();
+        sorts.sort_by_key(|(order, ix, _)| (*order, *ix));
+        sorts.into_iter().map(|(_, ix, sort)| (ix, sort)).collect()
+    }
+
     fn on_col_head_click(&mut self, col_ix: usize, cx: &mut ViewContext<Self>) {
         if !self.delegate.can_select_col(col_ix) {
             return;
@@ -369,13 +852,41 @@ where
     }
 
     fn action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        if self.cancel_editing(cx) {
+            return;
+        }
+
         self.selection_state = SelectionState::Row;
         self.selected_row = None;
         self.selected_col = None;
+        self.selection_anchor = None;
         cx.notify();
     }
 
+    /// Edit the selected cell, or the first editable cell of the selected row.
+    fn action_edit_cell(&mut self, _: &EditCell, cx: &mut ViewContext<Self>) {
+        let Some(row_ix) = self.selected_row else {
+            return;
+        };
+
+        let col_ix = match (self.selection_state, self.selected_col) {
+            (SelectionState::Column | SelectionState::Cell, Some(col_ix)) => Some(col_ix),
+            _ => self
+                .col_groups
+                .iter()
+                .position(|group| group.visible && group.column.editor.is_some()),
+        };
+        if let Some(col_ix) = col_ix {
+            self.start_editing(row_ix, col_ix, cx);
+        }
+    }
+
     fn action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        if self.cell_selectable {
+            return self.move_selection(Movement::Up, false, cx);
+        }
+
+        self.selection_anchor = None;
         let mut selected_row = self.selected_row.unwrap_or(0);
         let rows_count = self.delegate.rows_count();
         if selected_row > 0 {
@@ -390,6 +901,11 @@ where
     }
 
     fn action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        if self.cell_selectable {
+            return self.move_selection(Movement::Down, false, cx);
+        }
+
+        self.selection_anchor = None;
         let mut selected_row = self.selected_row.unwrap_or(0);
         if selected_row < self.delegate.rows_count() - 1 {
             selected_row += 1;
@@ -403,6 +919,10 @@ where
     }
 
     fn action_select_prev_col(&mut self, _: &SelectPrevColumn, cx: &mut ViewContext<Self>) {
+        if self.cell_selectable {
+            return self.move_selection(Movement::Left, false, cx);
+        }
+
         let mut selected_col = self.selected_col.unwrap_or(0);
         let cols_count = self.delegate.cols_count();
         if selected_col > 0 {
@@ -416,6 +936,10 @@ where
     }
 
     fn action_select_next_col(&mut self, _: &SelectNextColumn, cx: &mut ViewContext<Self>) {
+        if self.cell_selectable {
+            return self.move_selection(Movement::Right, false, cx);
+        }
+
         let mut selected_col = self.selected_col.unwrap_or(0);
         if selected_col < self.delegate.cols_count() - 1 {
             selected_col += 1;
@@ -428,6 +952,86 @@ where
         self.set_selected_col(selected_col, cx);
     }
 
+    fn action_select_first(&mut self, _: &SelectFirst, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::Home, false, cx);
+    }
+
+    fn action_select_last(&mut self, _: &SelectLast, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::End, false, cx);
+    }
+
+    fn action_select_top(&mut self, _: &SelectTop, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::Top, false, cx);
+    }
+
+    fn action_select_bottom(&mut self, _: &SelectBottom, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::Bottom, false, cx);
+    }
+
+    fn action_select_page_up(&mut self, _: &SelectPageUp, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::PageUp(self.page_rows()), false, cx);
+    }
+
+    fn action_select_page_down(&mut self, _: &SelectPageDown, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::PageDown(self.page_rows()), false, cx);
+    }
+
+    fn action_select_to_prev(&mut self, _: &SelectToPrev, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::Up, true, cx);
+    }
+
+    fn action_select_to_next(&mut self, _: &SelectToNext, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::Down, true, cx);
+    }
+
+    fn action_select_to_prev_col(&mut self, _: &SelectToPrevColumn, cx: &mut ViewContext<Self>) {
+        if self.cell_selectable {
+            self.move_selection(Movement::Left, true, cx);
+        }
+    }
+
+    fn action_select_to_next_col(&mut self, _: &SelectToNextColumn, cx: &mut ViewContext<Self>) {
+        if self.cell_selectable {
+            self.move_selection(Movement::Right, true, cx);
+        }
+    }
+
+    fn action_select_to_first(&mut self, _: &SelectToFirst, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::Home, true, cx);
+    }
+
+    fn action_select_to_last(&mut self, _: &SelectToLast, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::End, true, cx);
+    }
+
+    fn action_select_to_top(&mut self, _: &SelectToTop, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::Top, true, cx);
+    }
+
+    fn action_select_to_bottom(&mut self, _: &SelectToBottom, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::Bottom, true, cx);
+    }
+
+    fn action_select_to_page_up(&mut self, _: &SelectToPageUp, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::PageUp(self.page_rows()), true, cx);
+    }
+
+    fn action_select_to_page_down(&mut self, _: &SelectToPageDown, cx: &mut ViewContext<Self>) {
+        self.move_selection(Movement::PageDown(self.page_rows()), true, cx);
+    }
+
+    fn action_copy(&mut self, _: &Copy, cx: &mut ViewContext<Self>) {
+        self.copy_selection(cx);
+    }
+
+    fn action_copy_as_csv(&mut self, _: &CopyAsCsv, cx: &mut ViewContext<Self>) {
+        self.copy_as_csv(cx);
+    }
+
+    fn action_copy_as_markdown(&mut self, _: &CopyAsMarkdown, cx: &mut ViewContext<Self>) {
+        self.copy_as_markdown(cx);
+    }
+
     /// Scroll table when mouse position is near the edge of the table bounds.
     fn scroll_table_by_col_resizing(
         &mut self,
@@ -451,25 +1055,20 @@ where
     /// The `ix`` is the index of the col to resize,
     /// and the `size` is the new size for the col.
     fn resize_cols(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) {
-        const MIN_WIDTH: Pixels = px(10.0);
-        const MAX_WIDTH: Pixels = px(1200.0);
-
-        if !self.delegate.can_resize_col(ix) {
+        let column = &self.col_groups[ix].column;
+        if !column.resizable {
             return;
         }
         let size = size.floor();
 
         let old_width = self.col_groups[ix].width.unwrap_or_default();
-        let new_width = size;
-        if new_width < MIN_WIDTH {
-            return;
-        }
+        let new_width = column.clamp_width(size);
         let changed_width = new_width - old_width;
         // If change size is less than 1px, do nothing.
         if changed_width > px(-1.0) && changed_width < px(1.0) {
             return;
         }
-        self.col_groups[ix].width = Some(new_width.min(MAX_WIDTH));
+        self.col_groups[ix].width = Some(new_width);
 
         // Resize next col, table not need to resize the right cols.
         // let next_width = self.col_groups[ix + 1].width.unwrap_or_default();
@@ -479,28 +1078,36 @@ where
         cx.notify();
     }
 
-    fn perform_sort(&mut self, col_ix: usize, cx: &mut ViewContext<Self>) {
-        let sort = self.col_groups.get(col_ix).and_then(|g| g.sort);
-        if sort.is_none() {
+    /// Toggle the sort of the column, if `additive` is true, keep the other sort keys,
+    /// and add the column as the last sort key.
+    fn perform_sort(&mut self, col_ix: usize, additive: bool, cx: &mut ViewContext<Self>) {
+        let Some(old_sort) = self.col_groups.get(col_ix).and_then(|g| g.sort) else {
             return;
-        }
-
-        let sort = sort.unwrap();
-        let sort = match sort {
-            ColSort::Ascending => ColSort::Descending,
-            ColSort::Descending => ColSort::Ascending,
-            ColSort::Default => ColSort::Descending,
         };
 
-        for (ix, col_group) in self.col_groups.iter_mut().enumerate() {
-            if ix == col_ix {
-                col_group.sort = Some(sort);
-            } else {
-                col_group.sort = Some(ColSort::Default);
+        let sort_order = if !additive {
+            for col_group in self.col_groups.iter_mut() {
+                if col_group.sort.is_some() {
+                    col_group.sort = Some(ColSort::Default);
+                }
             }
-        }
+            0
+        } else if old_sort.is_sorted() {
+            self.col_groups[col_ix].sort_order
+        } else {
+            self.sorts()
+                .iter()
+                .map(|(ix, _)| self.col_groups[*ix].sort_order + 1)
+                .max()
+                .unwrap_or(0)
+        };
+
+        let col_group = &mut self.col_groups[col_ix];
+        col_group.sort = Some(old_sort.toggle());
+        col_group.sort_order = sort_order;
 
-        self.delegate_mut().perform_sort(col_ix, sort, cx);
+        let sorts = self.sorts();
+        self.delegate_mut().sort_changed(sorts, cx);
 
         cx.notify();
     }
@@ -513,7 +1120,10 @@ where
         self.delegate.move_col(col_ix, to_ix);
         let col_group = self.col_groups.remove(col_ix);
         self.col_groups.insert(to_ix, col_group);
+        // Keep the first N columns pinned after reordering.
+        self.apply_fixed_cols();
 
+        cx.emit(TableEvent::LayoutChanged(self.dump()));
         cx.notify();
     }
 
@@ -559,10 +1169,13 @@ where
 
     fn render_cell(&self, col_ix: usize, _cx: &mut ViewContext<Self>) -> Div {
         let col_width = self.col_groups[col_ix].width;
-        let col_padding = self.col_groups[col_ix].padding;
+        let column = &self.col_groups[col_ix].column;
+        let col_padding = column.padding;
 
         div()
             .when_some(col_width, |this, width| this.w(width))
+            .min_w(column.min_width)
+            .max_w(column.max_width)
             .h_full()
             .flex_shrink_0()
             .overflow_hidden()
@@ -578,6 +1191,73 @@ where
             })
     }
 
+    /// Render the cell content by the column render or format, or fallback to the delegate's `render_td`.
+    fn render_td(&self, row_ix: usize, col_ix: usize, cx: &mut ViewContext<Self>) -> AnyElement {
+        if let Some(editor) = self.render_cell_editor(row_ix, col_ix) {
+            return editor;
+        }
+
+        let column = &self.col_groups[col_ix].column;
+        let content = if column.render.is_some() || column.format.is_some() {
+            self.render_value(col_ix, self.delegate.cell_value(row_ix, col_ix), cx)
+        } else {
+            self.delegate
+                .render_td(row_ix, col_ix, cx)
+                .into_any_element()
+        };
+        let content = Self::align_cell(column.align, content);
+
+        if column.editor.is_none() {
+            return content;
+        }
+
+        // Double-click to edit the cell.
+        div()
+            .size_full()
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, e: &MouseDownEvent, cx| {
+                    if e.click_count == 2 {
+                        this.start_editing(row_ix, col_ix, cx);
+                    }
+                }),
+            )
+            .child(content)
+            .into_any_element()
+    }
+
+    /// Render the value by the render or format of the column, default is the text format.
+    fn render_value(
+        &self,
+        col_ix: usize,
+        value: CellValue,
+        cx: &mut ViewContext<Self>,
+    ) -> AnyElement {
+        let column = &self.col_groups[col_ix].column;
+        match (&column.render, &column.format) {
+            (Some(render), _) => render(value, cx),
+            (None, Some(format)) => format.render(value, cx),
+            (None, None) => CellFormat::Text.render(value, cx),
+        }
+    }
+
+    fn align_cell(align: ColAlign, content: AnyElement) -> AnyElement {
+        match align {
+            ColAlign::Left => content,
+            align => Self::align_content(align, h_flex().size_full())
+                .child(content)
+                .into_any_element(),
+        }
+    }
+
+    fn align_content(align: ColAlign, el: Div) -> Div {
+        match align {
+            ColAlign::Left => el,
+            ColAlign::Center => el.justify_center(),
+            ColAlign::Right => el.justify_end(),
+        }
+    }
+
     /// Show Column selection style, when the column is selected and the selection state is Column.
     fn render_col_wrap(&self, col_ix: usize, cx: &mut ViewContext<Self>) -> Div {
         let el = h_flex().h_full();
@@ -594,6 +1274,18 @@ where
 
     fn render_scrollbar(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
         let state = self.scrollbar_state.clone();
+        let scrollbar = match &self.list_state {
+            Some(list_state) => Scrollbar::list(
+                cx.view().entity_id(),
+                state,
+                ListScrollHandle::new(list_state.clone(), self.size.table_row_height()),
+            ),
+            None => Scrollbar::uniform_scroll(
+                cx.view().entity_id(),
+                state,
+                self.vertical_scroll_handle.clone(),
+            ),
+        };
 
         Some(
             div()
@@ -602,11 +1294,7 @@ where
                 .left_0()
                 .right_0()
                 .bottom_0()
-                .child(Scrollbar::uniform_scroll(
-                    cx.view().entity_id(),
-                    state,
-                    self.vertical_scroll_handle.clone(),
-                )),
+                .child(scrollbar),
         )
     }
 
@@ -631,7 +1319,7 @@ where
     fn render_resize_handle(&self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
         const HANDLE_SIZE: Pixels = px(2.);
 
-        if !self.delegate.can_resize_col(ix) {
+        if !self.col_groups[ix].column.resizable {
             return div().into_any_element();
         }
 
@@ -670,7 +1358,11 @@ where
                         let ix = *ix;
                         view.resizing_col = Some(ix);
 
-                        let col_group = *view.col_groups.get(ix).expect("BUG: invalid col index");
+                        let col_group = view
+                            .col_groups
+                            .get(ix)
+                            .expect("BUG: invalid col index")
+                            .clone();
 
                         view.resize_cols(
                             ix,
@@ -698,6 +1390,7 @@ where
 
                     let new_widths = view.col_groups.iter().map(|g| g.width).collect();
                     cx.emit(TableEvent::ColWidthsChanged(new_widths));
+                    cx.emit(TableEvent::LayoutChanged(view.dump()));
                     cx.notify();
                 }),
             )
@@ -715,6 +1408,12 @@ where
         }
 
         let sort = sort.unwrap();
+        // Show the priority of the sort key, when sorting by multiple columns.
+        let sorts = self.sorts();
+        let priority = sorts
+            .iter()
+            .position(|(ix, _)| *ix == col_ix)
+            .filter(|_| sorts.len() > 1);
 
         let (icon, is_on) = match sort {
             ColSort::Ascending => (IconName::SortAscending, true),
@@ -736,12 +1435,25 @@ where
                 .hover(|this| this.bg(cx.theme().secondary).opacity(7.))
                 .active(|this| this.bg(cx.theme().secondary_active).opacity(1.))
                 .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
-                .on_click(cx.listener(move |table, _, cx| table.perform_sort(col_ix, cx)))
+                .on_click(cx.listener(move |table, e: &ClickEvent, cx| {
+                    cx.stop_propagation();
+                    table.perform_sort(col_ix, e.down.modifiers.shift, cx)
+                }))
+                .flex()
+                .items_center()
                 .child(
                     Icon::new(icon)
                         .size_3()
                         .text_color(cx.theme().secondary_foreground),
-                ),
+                )
+                .when_some(priority, |this, priority| {
+                    this.child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!("{}", priority + 1)),
+                    )
+                }),
         )
     }
 
@@ -753,7 +1465,7 @@ where
         let entity_id = cx.entity_id();
         let col_group = self.col_groups.get(col_ix).expect("BUG: invalid col index");
 
-        let name = self.delegate.col_name(col_ix);
+        let name = col_group.column.name.clone();
         h_flex()
             .child(
                 self.render_cell(col_ix, cx)
@@ -765,13 +1477,26 @@ where
                             this.on_col_head_click(col_ix, cx);
                         }),
                     )
+                    .when(
+                        col_group.sort.is_some() && !self.delegate.can_select_col(col_ix),
+                        |this| {
+                            this.cursor_pointer().on_click(cx.listener(
+                                move |this, e: &ClickEvent, cx| {
+                                    this.perform_sort(col_ix, e.down.modifiers.shift, cx)
+                                },
+                            ))
+                        },
+                    )
                     .child(
                         h_flex()
                             .size_full()
                             .justify_between()
                             .items_center()
-                            .child(self.delegate.render_th(col_ix, cx))
-                            .when_some(self.delegate().col_padding(col_ix), |this, padding| {
+                            .child(
+                                Self::align_content(col_group.column.align, h_flex().flex_1())
+                                    .child(self.delegate.render_th(col_ix, cx)),
+                            )
+                            .when_some(col_group.column.padding, |this, padding| {
                                 // Leave right space for the sort icon, if this column have custom padding
                                 let offset_pr =
                                     self.size.table_cell_padding().right - padding.right;
@@ -806,7 +1531,27 @@ where
                                     return;
                                 }
 
+                                // The final bounds of the dragged col, the bounds of the cols are
+                                // not updated until the next layout, so use the bounds of the slot before moving.
+                                let slot = table.col_groups[col_ix].bounds;
+                                let width = table.col_groups[drag.col_ix].bounds.size.width;
+                                let left = if drag.col_ix > col_ix {
+                                    slot.left()
+                                } else {
+                                    slot.right() - width
+                                };
+                                let to = Bounds::new(
+                                    point(left, slot.top()),
+                                    gpui::size(width, slot.size.height),
+                                );
+
                                 table.move_col(drag.col_ix, col_ix, cx);
+
+                                // Fly the preview of the dragged col to the new position.
+                                let preview = cx.new_view(|_| drag.clone());
+                                cx.animate_drop(cx.mouse_position(), to, move |_| {
+                                    preview.clone().into_any_element()
+                                });
                             },
                         ))
                     }),
@@ -835,16 +1580,33 @@ where
         let fixed_cols_count = self
             .col_groups
             .iter()
-            .filter(|col| col.fixed.is_some())
+            .filter(|col| col.column.fixed.is_some())
             .count();
+        let focus_handle = self.focus_handle.clone();
+        let columns = self
+            .col_groups
+            .iter()
+            .map(|group| (group.column.name.clone(), group.visible))
+            .collect::<Vec<_>>();
 
         h_flex()
+            .id("table-head-row")
+            .relative()
             .w_full()
             .h(self.size.table_row_height())
             .flex_shrink_0()
             .border_b_1()
             .border_color(cx.theme().border)
             .text_color(cx.theme().table_head_foreground)
+            .when(self.list_state.is_some(), |this| {
+                this.child(
+                    div()
+                        .w(EXPAND_COL_WIDTH)
+                        .h_full()
+                        .flex_shrink_0()
+                        .bg(cx.theme().table_head),
+                )
+            })
             .when(fixed_cols_count > 0, |this| {
                 let view = view.clone();
                 // Render left fixed columns
@@ -858,8 +1620,9 @@ where
                         .children(
                             self.col_groups
                                 .iter()
-                                .filter(|col| col.fixed == Some(ColFixed::Left))
+                                .filter(|col| col.column.fixed == Some(ColFixed::Left))
                                 .enumerate()
+                                .filter(|(_, col)| col.visible)
                                 .map(|(col_ix, _)| self.render_th(col_ix, cx)),
                         )
                         .child(
@@ -898,8 +1661,9 @@ where
                                         table
                                             .col_groups
                                             .iter()
-                                            .filter(|col| col.fixed == None)
+                                            .filter(|col| col.column.fixed == None)
                                             .enumerate()
+                                            .filter(|(_, col)| col.visible)
                                             .map(|(col_ix, _)| {
                                                 table.render_th(left_cols_count + col_ix, cx)
                                             }),
@@ -925,6 +1689,25 @@ where
                 .h_full()
                 .flex_1(),
             )
+            // The context menu to show or hide the columns, and copy the table.
+            .context_menu(move |menu, _| {
+                columns
+                    .iter()
+                    .enumerate()
+                    .fold(
+                        menu.track_focus(&focus_handle),
+                        |menu, (col_ix, (name, visible))| {
+                            menu.menu_with_check(
+                                name.clone(),
+                                *visible,
+                                Box::new(ToggleColumnVisible(col_ix)),
+                            )
+                        },
+                    )
+                    .separator()
+                    .menu(t!("Table.copy_as_csv"), Box::new(CopyAsCsv))
+                    .menu(t!("Table.copy_as_markdown"), Box::new(CopyAsMarkdown))
+            })
     }
 
     fn render_table_row(
@@ -937,7 +1720,8 @@ where
     ) -> impl IntoElement {
         let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
         let is_stripe_row = self.stripe && row_ix % 2 != 0;
-        let is_selected = self.selected_row == Some(row_ix);
+        let is_selected = self.is_row_selected(row_ix);
+        let active_bg = cx.theme().table_active;
 
         if row_ix < rows_count {
             self.delegate
@@ -956,6 +1740,9 @@ where
                         this.bg(cx.theme().table_hover)
                     }
                 })
+                .when(self.list_state.is_some(), |this| {
+                    this.child(self.render_expand_toggle(row_ix, cx))
+                })
                 .children(if left_cols_count > 0 {
                     // Left fixed columns
                     Some(
@@ -963,12 +1750,20 @@ where
                             .h_full()
                             .border_r_1()
                             .border_color(cx.theme().table_row_border)
-                            .children((0..left_cols_count).map(|col_ix| {
-                                self.render_col_wrap(col_ix, cx).child(
-                                    self.render_cell(col_ix, cx)
-                                        .child(self.delegate.render_td(row_ix, col_ix, cx)),
-                                )
-                            })),
+                            .children(
+                                (0..left_cols_count)
+                                    .filter(|col_ix| self.col_groups[*col_ix].visible)
+                                    .map(|col_ix| {
+                                        self.render_col_wrap(col_ix, cx).child(
+                                            self.render_cell(col_ix, cx)
+                                                .when(
+                                                    self.is_cell_selected(row_ix, col_ix),
+                                                    |this| this.bg(active_bg),
+                                                )
+                                                .child(self.render_td(row_ix, col_ix, cx)),
+                                        )
+                                    }),
+                            ),
                     )
                 } else {
                     None
@@ -978,17 +1773,25 @@ where
                         .flex_1()
                         .h_full()
                         .overflow_hidden()
-                        .children((left_cols_count..cols_count).map(|col_ix| {
-                            self
-                                // Make the row scroll sync with the
-                                // horizontal_scroll_handle to support horizontal scrolling.
-                                .render_col_wrap(col_ix, cx)
-                                .left(horizontal_scroll_handle.offset().x)
-                                .child(
-                                    self.render_cell(col_ix, cx)
-                                        .child(self.delegate.render_td(row_ix, col_ix, cx)),
-                                )
-                        }))
+                        .children(
+                            (left_cols_count..cols_count)
+                                .filter(|col_ix| self.col_groups[*col_ix].visible)
+                                .map(|col_ix| {
+                                    self
+                                        // Make the row scroll sync with the
+                                        // horizontal_scroll_handle to support horizontal scrolling.
+                                        .render_col_wrap(col_ix, cx)
+                                        .left(horizontal_scroll_handle.offset().x)
+                                        .child(
+                                            self.render_cell(col_ix, cx)
+                                                .when(
+                                                    self.is_cell_selected(row_ix, col_ix),
+                                                    |this| this.bg(active_bg),
+                                                )
+                                                .child(self.render_td(row_ix, col_ix, cx)),
+                                        )
+                                }),
+                        )
                         .child(Self::render_last_empty_col(cx)),
                 )
                 // Row selected style
@@ -1000,8 +1803,8 @@ where
                 })
                 .on_mouse_down(
                     MouseButton::Left,
-                    cx.listener(move |this, _, cx| {
-                        this.on_row_click(row_ix, cx);
+                    cx.listener(move |this, event, cx| {
+                        this.on_row_click(row_ix, event, cx);
                     }),
                 )
         } else {
@@ -1014,15 +1817,219 @@ where
                 .border_t_1()
                 .border_color(cx.theme().table_row_border)
                 .when(is_stripe_row, |this| this.bg(cx.theme().table_even))
-                .children((0..cols_count).map(|col_ix| {
-                    h_flex()
-                        .left(horizontal_scroll_handle.offset().x)
-                        .child(self.render_cell(col_ix, cx))
-                }))
+                .children(
+                    (0..cols_count)
+                        .filter(|col_ix| self.col_groups[*col_ix].visible)
+                        .map(|col_ix| {
+                            h_flex()
+                                .left(horizontal_scroll_handle.offset().x)
+                                .child(self.render_cell(col_ix, cx))
+                        }),
+                )
                 .child(Self::render_last_empty_col(cx))
         }
     }
 
+    fn render_expand_toggle(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let expanded = self.is_row_expanded(row_ix);
+
+        h_flex()
+            .id(("expand-toggle", row_ix))
+            .w(EXPAND_COL_WIDTH)
+            .h_full()
+            .flex_shrink_0()
+            .justify_center()
+            .when(self.delegate.can_expand_row(row_ix), |this| {
+                this.cursor_pointer()
+                    .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                    .on_click(cx.listener(move |table, _, cx| {
+                        table.set_row_expanded(row_ix, !expanded, cx)
+                    }))
+                    .child(
+                        Icon::new(if expanded {
+                            IconName::ChevronDown
+                        } else {
+                            IconName::ChevronRight
+                        })
+                        .size_4()
+                        .text_color(cx.theme().muted_foreground),
+                    )
+            })
+    }
+
+    /// Render the group header with the title, rows count and the aggregate values, click to collapse the group.
+    fn render_group_row(
+        &self,
+        group_ix: usize,
+        left_cols_count: usize,
+        cols_count: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> AnyElement {
+        let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
+        let collapsed = self.is_group_collapsed(group_ix);
+        // The title is rendered in the first visible column.
+        let title_col_ix = (0..cols_count).find(|col_ix| self.col_groups[*col_ix].visible);
+
+        h_flex()
+            .id(("table-group", group_ix))
+            .w_full()
+            .h(self.size.table_row_height())
+            .border_t_1()
+            .border_color(cx.theme().table_row_border)
+            .bg(cx.theme().table_head)
+            .font_semibold()
+            .cursor_pointer()
+            .on_click(
+                cx.listener(move |table, _, cx| {
+                    table.set_group_collapsed(group_ix, !collapsed, cx)
+                }),
+            )
+            .when(self.list_state.is_some(), |this| {
+                this.child(div().w(EXPAND_COL_WIDTH).h_full().flex_shrink_0())
+            })
+            .children(if left_cols_count > 0 {
+                // Left fixed columns
+                Some(
+                    h_flex()
+                        .h_full()
+                        .border_r_1()
+                        .border_color(cx.theme().table_row_border)
+                        .children(
+                            (0..left_cols_count)
+                                .filter(|col_ix| self.col_groups[*col_ix].visible)
+                                .map(|col_ix| {
+                                    self.render_group_cell(group_ix, col_ix, title_col_ix, cx)
+                                }),
+                        ),
+                )
+            } else {
+                None
+            })
+            .child(
+                h_flex()
+                    .flex_1()
+                    .h_full()
+                    .overflow_hidden()
+                    .children(
+                        (left_cols_count..cols_count)
+                            .filter(|col_ix| self.col_groups[*col_ix].visible)
+                            .map(|col_ix| {
+                                self.render_group_cell(group_ix, col_ix, title_col_ix, cx)
+                                    .left(horizontal_scroll_handle.offset().x)
+                            }),
+                    )
+                    .child(Self::render_last_empty_col(cx)),
+            )
+            .into_any_element()
+    }
+
+    fn render_group_cell(
+        &self,
+        group_ix: usize,
+        col_ix: usize,
+        title_col_ix: Option<usize>,
+        cx: &mut ViewContext<Self>,
+    ) -> Div {
+        let content = if title_col_ix == Some(col_ix) {
+            let icon = if self.is_group_collapsed(group_ix) {
+                IconName::ChevronRight
+            } else {
+                IconName::ChevronDown
+            };
+
+            h_flex()
+                .gap_1()
+                .child(Icon::new(icon).size_4())
+                .child(self.delegate.group_title(group_ix))
+                .child(
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(format!("({})", self.groups.rows_count(group_ix))),
+                )
+                .into_any_element()
+        } else {
+            match self.delegate.group_aggregate(group_ix, col_ix) {
+                Some(value) => Self::align_cell(
+                    self.col_groups[col_ix].column.align,
+                    self.render_value(col_ix, value, cx),
+                ),
+                None => div().into_any_element(),
+            }
+        };
+
+        self.render_col_wrap(col_ix, cx)
+            .child(self.render_cell(col_ix, cx).child(content))
+    }
+
+    /// Render the display row at the given index, the group header or the data row.
+    fn render_display_row(
+        &mut self,
+        ix: usize,
+        left_cols_count: usize,
+        cols_count: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> AnyElement {
+        let rows_count = self.delegate.rows_count();
+        let display_rows_count = self.display_rows_count();
+
+        // The fake rows are after the display rows.
+        if ix >= display_rows_count {
+            let row_ix = rows_count + ix - display_rows_count;
+            return self
+                .render_table_row(row_ix, rows_count, left_cols_count, cols_count, cx)
+                .into_any_element();
+        }
+
+        match self.groups.row(ix) {
+            TableRow::GroupHeader(group_ix) => {
+                self.render_group_row(group_ix, left_cols_count, cols_count, cx)
+            }
+            TableRow::Row(row_ix) => self
+                .render_table_row(row_ix, rows_count, left_cols_count, cols_count, cx)
+                .into_any_element(),
+        }
+    }
+
+    /// Render the row with the detail if it is expanded, used by the list state.
+    fn render_expandable_row(&mut self, ix: usize, cx: &mut ViewContext<Self>) -> AnyElement {
+        let rows_count = self.delegate.rows_count();
+        let cols_count = self.delegate.cols_count();
+        let left_cols_count = self
+            .col_groups
+            .iter()
+            .filter(|col| col.column.fixed == Some(ColFixed::Left))
+            .count();
+        self.load_more(ix..ix + 1, cx);
+
+        let row_ix = match self.groups.row(ix) {
+            TableRow::Row(row_ix) => row_ix,
+            TableRow::GroupHeader(_) => {
+                return self.render_display_row(ix, left_cols_count, cols_count, cx)
+            }
+        };
+
+        let expanded = row_ix < rows_count && self.is_row_expanded(row_ix);
+        v_flex()
+            .w_full()
+            .child(self.render_table_row(row_ix, rows_count, left_cols_count, cols_count, cx))
+            .when(expanded, |this| {
+                this.child(
+                    div()
+                        .w_full()
+                        .border_t_1()
+                        .border_color(cx.theme().table_row_border)
+                        .bg(cx.theme().table_even)
+                        .child(self.delegate.render_row_detail(row_ix, cx))
+                        .with_transition(
+                            ("row-detail", row_ix),
+                            transition(TransitionProperty::Opacity, DURATION_FAST),
+                            cx,
+                        ),
+                )
+            })
+            .into_any_element()
+    }
+
     fn render_last_empty_col(_: &mut WindowContext) -> Div {
         h_flex().w(px(100.)).h_full().flex_shrink_0()
     }
@@ -1053,11 +2060,14 @@ where
     D: TableDelegate,
 {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        self.prepare_groups();
+        self.sync_rows_count();
         let view = cx.view().clone();
         let vertical_scroll_handle = self.vertical_scroll_handle.clone();
         let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
         let cols_count: usize = self.delegate.cols_count();
         let rows_count = self.delegate.rows_count();
+        let display_rows_count = self.display_rows_count();
 
         let row_height = self
             .vertical_scroll_handle
@@ -1078,7 +2088,7 @@ where
         let mut extra_rows_needed = 0;
         if let Some(row_height) = row_height {
             if row_height > px(0.) {
-                let actual_height = row_height * rows_count as f32;
+                let actual_height = row_height * display_rows_count as f32;
                 let remaining_height = total_height - actual_height;
                 if remaining_height > px(0.) {
                     extra_rows_needed = (remaining_height / row_height).ceil() as usize;
@@ -1089,7 +2099,7 @@ where
         let left_cols_count = self
             .col_groups
             .iter()
-            .filter(|col| col.fixed == Some(ColFixed::Left))
+            .filter(|col| col.column.fixed == Some(ColFixed::Left))
             .count();
 
         let inner_table = v_flex()
@@ -1101,37 +2111,67 @@ where
             .on_action(cx.listener(Self::action_select_prev))
             .on_action(cx.listener(Self::action_select_next_col))
             .on_action(cx.listener(Self::action_select_prev_col))
+            .on_action(cx.listener(Self::on_action_toggle_column_visible))
+            .on_action(cx.listener(Self::action_select_first))
+            .on_action(cx.listener(Self::action_select_last))
+            .on_action(cx.listener(Self::action_select_top))
+            .on_action(cx.listener(Self::action_select_bottom))
+            .on_action(cx.listener(Self::action_select_page_up))
+            .on_action(cx.listener(Self::action_select_page_down))
+            .on_action(cx.listener(Self::action_select_to_prev))
+            .on_action(cx.listener(Self::action_select_to_next))
+            .on_action(cx.listener(Self::action_select_to_prev_col))
+            .on_action(cx.listener(Self::action_select_to_next_col))
+            .on_action(cx.listener(Self::action_select_to_first))
+            .on_action(cx.listener(Self::action_select_to_last))
+            .on_action(cx.listener(Self::action_select_to_top))
+            .on_action(cx.listener(Self::action_select_to_bottom))
+            .on_action(cx.listener(Self::action_select_to_page_up))
+            .on_action(cx.listener(Self::action_select_to_page_down))
+            .on_action(cx.listener(Self::action_copy))
+            .on_action(cx.listener(Self::action_copy_as_csv))
+            .on_action(cx.listener(Self::action_copy_as_markdown))
+            .on_action(cx.listener(Self::action_edit_cell))
             .size_full()
             .overflow_hidden()
             .child(self.render_table_head(left_cols_count, cx))
             .map(|this| {
                 if rows_count == 0 {
                     this.child(div().size_full().child(self.delegate.render_empty(cx)))
+                } else if let Some(list_state) = self.list_state.clone() {
+                    this.child(
+                        h_flex()
+                            .id("table-body")
+                            .flex_grow()
+                            .size_full()
+                            .child(list(list_state).flex_grow().size_full()),
+                    )
                 } else {
                     this.child(
                         h_flex().id("table-body").flex_grow().size_full().child(
                             uniform_list(
                                 view,
                                 "table-uniform-list",
-                                rows_count + extra_rows_needed,
+                                display_rows_count + extra_rows_needed,
                                 {
                                     move |table, visible_range, cx| {
                                         table.load_more(visible_range.clone(), cx);
 
-                                        if visible_range.end > rows_count {
-                                            table.scroll_to_row(
-                                                std::cmp::min(visible_range.start, rows_count - 1),
+                                        if visible_range.end > display_rows_count {
+                                            table.scroll_to_display_row(
+                                                std::cmp::min(
+                                                    visible_range.start,
+                                                    display_rows_count - 1,
+                                                ),
                                                 cx,
                                             );
                                         }
 
                                         // Render fake rows to fill the table
                                         visible_range
-                                            .map(|row_ix| {
-                                                // Render real rows for available data
-                                                table.render_table_row(
-                                                    row_ix,
-                                                    rows_count,
+                                            .map(|ix| {
+                                                table.render_display_row(
+                                                    ix,
                                                     left_cols_count,
                                                     cols_count,
                                                     cx,
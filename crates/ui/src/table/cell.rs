@@ -0,0 +1,326 @@
+use std::fmt::Write as _;
+
+use chrono::NaiveDateTime;
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, IntoElement, ParentElement as _,
+    SharedString, Styled as _, WindowContext,
+};
+
+use crate::{h_flex, link::Link, progress::Progress, theme::ActiveTheme as _, Icon, IconName};
+
+/// The value of a table cell, used by the built-in [`CellFormat`] to render the cell.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellValue {
+    Empty,
+    Text(SharedString),
+    Number(f64),
+    Bool(bool),
+    DateTime(NaiveDateTime),
+    Tags(Vec<SharedString>),
+    Link {
+        label: SharedString,
+        href: SharedString,
+    },
+}
+
+impl From<f64> for CellValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<NaiveDateTime> for CellValue {
+    fn from(value: NaiveDateTime) -> Self {
+        Self::DateTime(value)
+    }
+}
+
+impl From<SharedString> for CellValue {
+    fn from(value: SharedString) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&'static str> for CellValue {
+    fn from(value: &'static str) -> Self {
+        Self::Text(value.into())
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(value: String) -> Self {
+        Self::Text(value.into())
+    }
+}
+
+impl From<Vec<SharedString>> for CellValue {
+    fn from(value: Vec<SharedString>) -> Self {
+        Self::Tags(value)
+    }
+}
+
+impl std::fmt::Display for CellValue {
+    /// Format the value as plain text, used to copy or export the cells.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => Ok(()),
+            Self::Text(text) => write!(f, "{}", text),
+            Self::Number(number) => write!(f, "{}", number),
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::DateTime(value) => write!(f, "{}", value),
+            Self::Tags(tags) => write!(f, "{}", tags.join(", ")),
+            Self::Link { label, .. } => write!(f, "{}", label),
+        }
+    }
+}
+
+/// The built-in cell formats of the Table, selected per column by [`crate::table::TableDelegate::col_format`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellFormat {
+    /// Render the value as plain text.
+    Text,
+    /// Number with locale thousands separator, right aligned.
+    Number { decimals: usize },
+    /// Currency with a symbol prefix, right aligned.
+    Currency {
+        symbol: SharedString,
+        decimals: usize,
+    },
+    /// Percent value (0.1 is 10%), right aligned.
+    ///
+    /// If `colored` is true, the positive value will be green, and the negative value will be red.
+    Percent { decimals: usize, colored: bool },
+    /// Date time with the [`chrono::format::strftime`] format, e.g.: `%Y-%m-%d %H:%M`.
+    DateTime(SharedString),
+    /// Render a check mark for `true`.
+    Bool,
+    /// Progress bar, the value is in range of 0.0..100.0.
+    Progress,
+    /// A list of tags.
+    Tags,
+    /// A link to open the href.
+    Link,
+}
+
+impl CellFormat {
+    /// Create a number format with the given decimals.
+    pub fn number(decimals: usize) -> Self {
+        Self::Number { decimals }
+    }
+
+    /// Create a currency format with the given symbol, and 2 decimals.
+    pub fn currency(symbol: impl Into<SharedString>) -> Self {
+        Self::Currency {
+            symbol: symbol.into(),
+            decimals: 2,
+        }
+    }
+
+    /// Create a colored percent format with the given decimals.
+    pub fn percent(decimals: usize) -> Self {
+        Self::Percent {
+            decimals,
+            colored: true,
+        }
+    }
+
+    /// Create a date time format, e.g.: `%Y-%m-%d`.
+    pub fn date_time(format: impl Into<SharedString>) -> Self {
+        Self::DateTime(format.into())
+    }
+
+    /// Returns true if the cell content should be aligned to the right.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            Self::Number { .. } | Self::Currency { .. } | Self::Percent { .. }
+        )
+    }
+
+    /// Format the value to a text, returns None if the format is not a text format.
+    pub fn format_text(&self, value: &CellValue) -> Option<String> {
+        let locale = crate::locale();
+
+        let text = match (self, value) {
+            (_, CellValue::Empty) => String::new(),
+            (Self::Number { decimals }, CellValue::Number(v)) => {
+                format_number(*v, *decimals, &locale)
+            }
+            (Self::Currency { symbol, decimals }, CellValue::Number(v)) => {
+                let sign = if *v < 0. { "-" } else { "" };
+                format!(
+                    "{}{}{}",
+                    sign,
+                    symbol,
+                    format_number(v.abs(), *decimals, &locale)
+                )
+            }
+            (Self::Percent { decimals, .. }, CellValue::Number(v)) => {
+                let sign = if *v > 0. { "+" } else { "" };
+                format!("{}{}%", sign, format_number(v * 100., *decimals, &locale))
+            }
+            (Self::DateTime(format), CellValue::DateTime(v)) => {
+                // The invalid format string returns an error instead of panic by `write!`.
+                let mut text = String::new();
+                match write!(text, "{}", v.format(format.as_ref())) {
+                    Ok(_) => text,
+                    Err(_) => v.to_string(),
+                }
+            }
+            (_, CellValue::Text(v)) => v.to_string(),
+            (_, CellValue::Number(v)) => v.to_string(),
+            (_, CellValue::DateTime(v)) => v.to_string(),
+            _ => return None,
+        };
+
+        Some(text)
+    }
+
+    /// Render the cell with the value.
+    pub fn render(&self, value: CellValue, cx: &mut WindowContext) -> AnyElement {
+        match (self, value) {
+            (Self::Bool, CellValue::Bool(checked)) => h_flex()
+                .justify_center()
+                .when(checked, |this| {
+                    this.child(Icon::new(IconName::Check).text_color(cx.theme().foreground))
+                })
+                .into_any_element(),
+            (Self::Progress, CellValue::Number(v)) => div()
+                .w_full()
+                .py(px(6.))
+                .child(Progress::new().value(v as f32))
+                .into_any_element(),
+            (Self::Tags, CellValue::Tags(tags)) => h_flex()
+                .gap_1()
+                .overflow_hidden()
+                .children(tags.into_iter().map(|tag| {
+                    div()
+                        .px_1p5()
+                        .rounded(px(cx.theme().radius))
+                        .text_xs()
+                        .bg(cx.theme().secondary)
+                        .text_color(cx.theme().secondary_foreground)
+                        .child(tag)
+                }))
+                .into_any_element(),
+            (Self::Link, CellValue::Link { label, href }) => {
+                Link::new(SharedString::from(format!("cell-link-{}", href)))
+                    .href(href)
+                    .child(label)
+                    .into_any_element()
+            }
+            (format, value) => {
+                let text = format.format_text(&value).unwrap_or_default();
+                let color = match (format, &value) {
+                    (Self::Percent { colored: true, .. }, CellValue::Number(v)) if *v > 0. => {
+                        Some(crate::green_500())
+                    }
+                    (Self::Percent { colored: true, .. }, CellValue::Number(v)) if *v < 0. => {
+                        Some(crate::red_500())
+                    }
+                    _ => None,
+                };
+
+                h_flex()
+                    .w_full()
+                    .when(format.is_numeric(), |this| this.justify_end())
+                    .when_some(color, |this, color| this.text_color(color))
+                    .child(text)
+                    .into_any_element()
+            }
+        }
+    }
+}
+
+/// Format the number with thousands separator by the locale.
+///
+/// e.g.: `1234567.891` with 2 decimals is `1,234,567.89` in `en`, `1.234.567,89` in `de`.
+pub(crate) fn format_number(value: f64, decimals: usize, locale: &str) -> String {
+    let (group_sep, decimal_sep) = separators(locale);
+
+    let text = format!("{:.*}", decimals, value.abs());
+    let (int_part, frac_part) = match text.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (text.as_str(), None),
+    };
+
+    let mut out = String::new();
+    if value < 0. && text.chars().any(|c| c != '0' && c != '.') {
+        out.push('-');
+    }
+
+    let len = int_part.len();
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (len - i) % 3 == 0 {
+            out.push_str(group_sep);
+        }
+        out.push(c);
+    }
+
+    if let Some(frac_part) = frac_part {
+        out.push_str(decimal_sep);
+        out.push_str(frac_part);
+    }
+
+    out
+}
+
+/// Returns the (thousands, decimal) separators of the locale.
+fn separators(locale: &str) -> (&'static str, &'static str) {
+    let lang = locale
+        .split(|c| c == '-' || c == '_')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    match lang.as_str() {
+        "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" => (".", ","),
+        "fr" | "ru" | "pl" | "cs" | "sv" | "fi" | "nb" | "uk" => ("\u{a0}", ","),
+        _ => (",", "."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{format_number, CellFormat, CellValue};
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(0., 0, "en"), "0");
+        assert_eq!(format_number(999., 0, "en"), "999");
+        assert_eq!(format_number(1000., 0, "en"), "1,000");
+        assert_eq!(format_number(1234567.891, 2, "en"), "1,234,567.89");
+        assert_eq!(format_number(-1234567.891, 1, "zh-CN"), "-1,234,567.9");
+        assert_eq!(format_number(1234567.891, 2, "de"), "1.234.567,89");
+        assert_eq!(format_number(1234.5, 2, "fr_FR"), "1\u{a0}234,50");
+        assert_eq!(format_number(-0.001, 2, "en"), "0.00");
+    }
+
+    #[test]
+    fn test_format_date_time() {
+        let value = CellValue::DateTime(
+            NaiveDate::from_ymd_opt(2024, 8, 1)
+                .unwrap()
+                .and_hms_opt(9, 30, 0)
+                .unwrap(),
+        );
+
+        assert_eq!(
+            CellFormat::DateTime("%Y-%m-%d".into()).format_text(&value),
+            Some("2024-08-01".to_string())
+        );
+        // Fallback to the default display for the invalid format.
+        assert_eq!(
+            CellFormat::DateTime("%Q".into()).format_text(&value),
+            Some("2024-08-01 09:30:00".to_string())
+        );
+    }
+}
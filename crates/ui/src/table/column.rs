@@ -0,0 +1,174 @@
+use std::rc::Rc;
+
+use gpui::{px, AnyElement, Edges, IntoElement, Pixels, SharedString, WindowContext};
+
+use super::{CellFormat, CellValue, ColEditor, ColFixed, ColSort};
+
+/// The horizontal alignment of the cells in a column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// The definition of a table column, returned by [`crate::table::TableDelegate::column`].
+///
+/// ```ignore
+/// Column::new("Price")
+///     .width(px(120.))
+///     .min_width(px(80.))
+///     .align(ColAlign::Right)
+///     .format(CellFormat::currency("$"))
+///     .sortable()
+/// ```
+#[derive(Clone)]
+pub struct Column {
+    /// The unique key of the column to persist the layout, default is the name.
+    pub key: SharedString,
+    pub name: SharedString,
+    /// The initial width of the column, None to use auto width.
+    pub width: Option<Pixels>,
+    /// The min width of the column when resizing, default is 10px.
+    pub min_width: Pixels,
+    /// The max width of the column when resizing, default is 1200px.
+    pub max_width: Pixels,
+    pub align: ColAlign,
+    /// The initial sort state, None means the column is not sortable.
+    pub sort: Option<ColSort>,
+    pub fixed: Option<ColFixed>,
+    pub resizable: bool,
+    /// The initial visibility, the hidden columns can be shown by the header context menu.
+    pub visible: bool,
+    /// The padding to override the default cell padding.
+    pub padding: Option<Edges<Pixels>>,
+    /// The built-in format to render the [`CellValue`] of the cells.
+    pub format: Option<CellFormat>,
+    /// The custom renderer of the [`CellValue`] of the cells, it takes precedence over the `format`.
+    pub render: Option<Rc<dyn Fn(CellValue, &mut WindowContext) -> AnyElement>>,
+    /// The editor to edit the cells, None means the cells are read-only.
+    pub editor: Option<ColEditor>,
+}
+
+impl Column {
+    pub fn new(name: impl Into<SharedString>) -> Self {
+        let name = name.into();
+        Self {
+            key: name.clone(),
+            name,
+            width: None,
+            min_width: px(10.),
+            max_width: px(1200.),
+            align: ColAlign::default(),
+            sort: None,
+            fixed: None,
+            resizable: true,
+            visible: true,
+            padding: None,
+            format: None,
+            render: None,
+            editor: None,
+        }
+    }
+
+    /// Set the unique key of the column.
+    pub fn key(mut self, key: impl Into<SharedString>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    /// Set the initial width of the column.
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Set the min width of the column.
+    pub fn min_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.min_width = width.into();
+        self
+    }
+
+    /// Set the max width of the column.
+    pub fn max_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.max_width = width.into();
+        self
+    }
+
+    /// Set the alignment of the header and the cells, default is left.
+    pub fn align(mut self, align: ColAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the column to be sortable, with the [`ColSort::Default`] state.
+    pub fn sortable(mut self) -> Self {
+        self.sort = Some(ColSort::Default);
+        self
+    }
+
+    /// Set the initial sort state of the column.
+    pub fn sort(mut self, sort: ColSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Fix the column on the left side when scrolling horizontally.
+    pub fn fixed_left(mut self) -> Self {
+        self.fixed = Some(ColFixed::Left);
+        self
+    }
+
+    /// Set whether the column can be resized, default is true.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Set the initial visibility of the column, default is true.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the padding of the cells to override the default padding.
+    pub fn padding(mut self, padding: Edges<Pixels>) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Set the built-in format to render the cells.
+    pub fn format(mut self, format: CellFormat) -> Self {
+        if format.is_numeric() {
+            self.align = ColAlign::Right;
+        }
+        self.format = Some(format);
+        self
+    }
+
+    /// Set a custom renderer to render the cells by the [`CellValue`].
+    pub fn render<E>(
+        mut self,
+        render: impl Fn(CellValue, &mut WindowContext) -> E + 'static,
+    ) -> Self
+    where
+        E: IntoElement,
+    {
+        self.render = Some(Rc::new(move |value, cx| {
+            render(value, cx).into_any_element()
+        }));
+        self
+    }
+
+    /// Set the editor to edit the cells, the edited value is sent to the delegate's `cell_edited`.
+    pub fn editor(mut self, editor: ColEditor) -> Self {
+        self.editor = Some(editor);
+        self
+    }
+
+    /// Clamp the width into the min and max width.
+    pub(super) fn clamp_width(&self, width: Pixels) -> Pixels {
+        width.max(self.min_width).min(self.max_width)
+    }
+}
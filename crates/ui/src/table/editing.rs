@@ -0,0 +1,205 @@
+use gpui::{
+    AnyElement, EntityId, IntoElement as _, SharedString, Subscription, View, ViewContext,
+    VisualContext as _,
+};
+
+use super::{CellValue, Table, TableDelegate};
+use crate::{
+    dropdown::{Dropdown, DropdownEvent},
+    input::{InputEvent, TextInput},
+    Sizable as _,
+};
+
+/// The editor to edit the cells of a column, see [`crate::table::Column::editor`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColEditor {
+    /// Edit the text or number in a text input, the number is parsed when commit.
+    Input,
+    /// Select a text value from the options.
+    Select(Vec<SharedString>),
+    /// Toggle the bool value, it commits immediately without the editor.
+    Checkbox,
+}
+
+enum CellEditorView {
+    Input(View<TextInput>),
+    Select(View<Dropdown<Vec<SharedString>>>),
+}
+
+impl CellEditorView {
+    fn entity_id(&self) -> EntityId {
+        match self {
+            Self::Input(view) => view.entity_id(),
+            Self::Select(view) => view.entity_id(),
+        }
+    }
+}
+
+/// The cell in editing.
+pub(super) struct EditingCell {
+    row_ix: usize,
+    col_ix: usize,
+    /// The value before editing, the edited text is converted to the same type.
+    value: CellValue,
+    editor: CellEditorView,
+    _subscription: Subscription,
+}
+
+impl<D> Table<D>
+where
+    D: TableDelegate,
+{
+    /// Start editing the cell by the editor of the column, returns false if the column is not editable.
+    pub fn start_editing(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> bool {
+        let Some(editor) = self
+            .col_groups
+            .get(col_ix)
+            .and_then(|group| group.column.editor.clone())
+        else {
+            return false;
+        };
+        if row_ix >= self.delegate.rows_count() {
+            return false;
+        }
+
+        self.commit_editing(cx);
+        let value = self.delegate.cell_value(row_ix, col_ix);
+
+        let (editor, subscription) = match editor {
+            ColEditor::Checkbox => {
+                let checked = matches!(value, CellValue::Bool(true));
+                self.delegate
+                    .cell_edited(row_ix, col_ix, CellValue::Bool(!checked), cx);
+                cx.notify();
+                return true;
+            }
+            ColEditor::Input => {
+                let text = match &value {
+                    CellValue::Text(text) => text.clone(),
+                    CellValue::Number(number) => number.to_string().into(),
+                    _ => SharedString::default(),
+                };
+
+                let input = cx.new_view(|cx| {
+                    let mut input = TextInput::new(cx).small();
+                    input.set_text(text, cx);
+                    input
+                });
+                input.update(cx, |input, cx| input.focus(cx));
+
+                let subscription = cx.subscribe(&input, |this, input, event: &InputEvent, cx| {
+                    if !this.is_editing_by(input.entity_id()) {
+                        return;
+                    }
+
+                    match event {
+                        InputEvent::PressEnter | InputEvent::Blur => this.commit_editing(cx),
+                        _ => {}
+                    }
+                });
+                (CellEditorView::Input(input), subscription)
+            }
+            ColEditor::Select(options) => {
+                let selected_index = match &value {
+                    CellValue::Text(text) => options.iter().position(|option| option == text),
+                    _ => None,
+                };
+
+                let dropdown = cx.new_view(|cx| {
+                    Dropdown::new("table-cell-editor", options, selected_index, cx).small()
+                });
+                dropdown.update(cx, |dropdown, cx| dropdown.focus(cx));
+
+                let subscription = cx.subscribe(
+                    &dropdown,
+                    |this, dropdown, event: &DropdownEvent<Vec<SharedString>>, cx| {
+                        if !this.is_editing_by(dropdown.entity_id()) {
+                            return;
+                        }
+
+                        match event {
+                            DropdownEvent::Confirm(_) => this.commit_editing(cx),
+                        }
+                    },
+                );
+                (CellEditorView::Select(dropdown), subscription)
+            }
+        };
+
+        self.editing = Some(EditingCell {
+            row_ix,
+            col_ix,
+            value,
+            editor,
+            _subscription: subscription,
+        });
+        cx.notify();
+        true
+    }
+
+    fn is_editing_by(&self, entity_id: EntityId) -> bool {
+        self.editing
+            .as_ref()
+            .map_or(false, |editing| editing.editor.entity_id() == entity_id)
+    }
+
+    /// Commit the editing cell to the delegate's `cell_edited`.
+    ///
+    /// If the text can't be parsed to a number for the number cell, the editing is canceled.
+    pub fn commit_editing(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(editing) = self.editing.take() else {
+            return;
+        };
+
+        let new_value = match &editing.editor {
+            CellEditorView::Input(input) => {
+                let text = input.read(cx).text();
+                match editing.value {
+                    CellValue::Number(_) => text.trim().parse::<f64>().ok().map(CellValue::Number),
+                    _ => Some(CellValue::Text(text)),
+                }
+            }
+            CellEditorView::Select(dropdown) => dropdown
+                .read(cx)
+                .selected_value()
+                .cloned()
+                .map(CellValue::Text),
+        };
+
+        if let Some(new_value) = new_value.filter(|value| *value != editing.value) {
+            self.delegate
+                .cell_edited(editing.row_ix, editing.col_ix, new_value, cx);
+        }
+        self.focus_handle.focus(cx);
+        cx.notify();
+    }
+
+    /// Cancel the editing cell without commit, returns false if there is no editing cell.
+    pub fn cancel_editing(&mut self, cx: &mut ViewContext<Self>) -> bool {
+        if self.editing.take().is_none() {
+            return false;
+        }
+
+        self.focus_handle.focus(cx);
+        cx.notify();
+        true
+    }
+
+    /// Return the editor element if the cell is in editing.
+    pub(super) fn render_cell_editor(&self, row_ix: usize, col_ix: usize) -> Option<AnyElement> {
+        let editing = self.editing.as_ref()?;
+        if editing.row_ix != row_ix || editing.col_ix != col_ix {
+            return None;
+        }
+
+        Some(match &editing.editor {
+            CellEditorView::Input(input) => input.clone().into_any_element(),
+            CellEditorView::Select(dropdown) => dropdown.clone().into_any_element(),
+        })
+    }
+}
@@ -0,0 +1,139 @@
+use std::{borrow::Cow, io};
+
+use gpui::{ClipboardItem, ViewContext};
+
+use super::{selection::to_tsv, Table, TableDelegate};
+
+/// Quote the CSV field if it contains the comma, quote or line break.
+fn csv_field(text: &str) -> Cow<'_, str> {
+    if text.contains([',', '"', '\r', '\n']) {
+        Cow::Owned(format!("\"{}\"", text.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Write the rows as CSV (RFC 4180) to the writer, the lines end with CRLF.
+pub(crate) fn write_csv(
+    mut writer: impl io::Write,
+    rows: impl IntoIterator<Item = Vec<String>>,
+) -> io::Result<()> {
+    for cells in rows {
+        let line = cells
+            .iter()
+            .map(|cell| csv_field(cell))
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(writer, "{}\r\n", line)?;
+    }
+
+    writer.flush()
+}
+
+/// Join the rows to a Markdown table, the first row is the header.
+pub(crate) fn to_markdown(rows: impl IntoIterator<Item = Vec<String>>) -> String {
+    let line = |cells: &[String]| {
+        let cells = cells
+            .iter()
+            .map(|cell| cell.replace('|', "\\|").replace(['\r', '\n'], " "))
+            .collect::<Vec<_>>();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let mut rows = rows.into_iter();
+    let Some(header) = rows.next() else {
+        return String::new();
+    };
+
+    let mut lines = vec![line(&header), line(&vec!["---".to_string(); header.len()])];
+    lines.extend(rows.map(|cells| line(&cells)));
+    lines.join("\n")
+}
+
+impl<D> Table<D>
+where
+    D: TableDelegate,
+{
+    /// Return the rows of the current view to export, the first row is the header.
+    ///
+    /// The rows are in the order of the delegate (after sorting or filtering),
+    /// and only the visible columns are included in the display order.
+    /// The text of the cells is from the delegate's `cell_value`.
+    fn export_rows(&self) -> impl Iterator<Item = Vec<String>> + '_ {
+        let cols = self.visible_cols();
+        let header = cols
+            .iter()
+            .map(|col_ix| self.col_groups[*col_ix].column.name.to_string())
+            .collect::<Vec<_>>();
+
+        let rows = (0..self.delegate.rows_count()).map(move |row_ix| {
+            cols.iter()
+                .map(|col_ix| self.delegate.cell_value(row_ix, *col_ix).to_string())
+                .collect()
+        });
+        std::iter::once(header).chain(rows)
+    }
+
+    /// Export the current view of the table with the header as CSV to the writer.
+    ///
+    /// ```ignore
+    /// let file = std::fs::File::create("stocks.csv")?;
+    /// table.read(cx).export_csv(file)?;
+    /// ```
+    pub fn export_csv(&self, writer: impl io::Write) -> io::Result<()> {
+        write_csv(writer, self.export_rows())
+    }
+
+    /// Export the current view of the table with the header as TSV to the writer.
+    pub fn export_tsv(&self, mut writer: impl io::Write) -> io::Result<()> {
+        writer.write_all(to_tsv(self.export_rows()).as_bytes())?;
+        writer.flush()
+    }
+
+    /// Copy the current view of the table as CSV to the clipboard.
+    pub fn copy_as_csv(&self, cx: &mut ViewContext<Self>) {
+        let mut buf = Vec::new();
+        if self.export_csv(&mut buf).is_ok() {
+            let text = String::from_utf8_lossy(&buf).to_string();
+            cx.write_to_clipboard(ClipboardItem::new_string(text));
+        }
+    }
+
+    /// Copy the current view of the table as a Markdown table to the clipboard.
+    pub fn copy_as_markdown(&self, cx: &mut ViewContext<Self>) {
+        let text = to_markdown(self.export_rows());
+        cx.write_to_clipboard(ClipboardItem::new_string(text));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_markdown, write_csv};
+
+    fn rows() -> Vec<Vec<String>> {
+        vec![
+            vec!["Name".to_string(), "Price".to_string()],
+            vec!["Apple, Inc.".to_string(), "1.5".to_string()],
+            vec!["12\" Pizza | Large".to_string(), "".to_string()],
+        ]
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut buf = Vec::new();
+        write_csv(&mut buf, rows()).unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "Name,Price\r\n\"Apple, Inc.\",1.5\r\n\"12\"\" Pizza | Large\",\r\n"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(rows()),
+            "| Name | Price |\n| --- | --- |\n| Apple, Inc. | 1.5 |\n| 12\" Pizza \\| Large |  |"
+        );
+        assert_eq!(to_markdown(Vec::<Vec<String>>::new()), "");
+    }
+}
@@ -0,0 +1,139 @@
+use std::ops::Range;
+
+/// A display row of the grouped table, the group headers are inserted before the rows of each group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TableRow {
+    GroupHeader(usize),
+    Row(usize),
+}
+
+/// The layout of the group headers in the display rows of a table, the rows of the collapsed groups are hidden.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct Groups {
+    /// The data rows of each group.
+    ranges: Vec<Range<usize>>,
+    /// The display row index of each group header.
+    header_rows: Vec<usize>,
+    collapsed: Vec<bool>,
+}
+
+impl Groups {
+    /// Build from the number of rows in each group, and whether the group is collapsed.
+    pub(crate) fn new(groups: impl IntoIterator<Item = (usize, bool)>) -> Self {
+        let mut this = Self::default();
+        let (mut start, mut display_row) = (0, 0);
+        for (count, collapsed) in groups {
+            this.ranges.push(start..start + count);
+            this.header_rows.push(display_row);
+            this.collapsed.push(collapsed);
+            start += count;
+            display_row += 1 + if collapsed { 0 } else { count };
+        }
+        this
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Return the number of display rows for the rows count, including the group headers.
+    pub(crate) fn display_rows_count(&self, rows_count: usize) -> usize {
+        if self.is_empty() {
+            return rows_count;
+        }
+
+        let last = self.ranges.len() - 1;
+        self.header_rows[last] + 1 + self.visible_count(last)
+    }
+
+    fn visible_count(&self, group: usize) -> usize {
+        if self.collapsed[group] {
+            0
+        } else {
+            self.ranges[group].len()
+        }
+    }
+
+    /// Return the row at the display row index.
+    pub(crate) fn row(&self, display_row: usize) -> TableRow {
+        let Some(group) = self
+            .header_rows
+            .partition_point(|header_row| *header_row <= display_row)
+            .checked_sub(1)
+        else {
+            return TableRow::Row(display_row);
+        };
+
+        match display_row - self.header_rows[group] {
+            0 => TableRow::GroupHeader(group),
+            offset => TableRow::Row(self.ranges[group].start + offset - 1),
+        }
+    }
+
+    /// Return the display row index of the data row, None if the row is in a collapsed group.
+    pub(crate) fn display_row(&self, row_ix: usize) -> Option<usize> {
+        if self.is_empty() {
+            return Some(row_ix);
+        }
+
+        let group = self
+            .ranges
+            .partition_point(|range| range.end <= row_ix)
+            .min(self.ranges.len() - 1);
+        let range = &self.ranges[group];
+        if self.collapsed[group] || !range.contains(&row_ix) {
+            return None;
+        }
+
+        Some(self.header_rows[group] + 1 + row_ix - range.start)
+    }
+
+    /// Return the number of the data rows in the group.
+    pub(crate) fn rows_count(&self, group: usize) -> usize {
+        self.ranges.get(group).map_or(0, |range| range.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Groups, TableRow};
+
+    #[test]
+    fn test_groups() {
+        // Header 0, rows 0..2, Header 1 (collapsed, rows 2..4), Header 2, rows 4..5
+        let groups = Groups::new([(2, false), (2, true), (1, false)]);
+        assert_eq!(groups.display_rows_count(5), 6);
+
+        let rows = (0..6).map(|row| groups.row(row)).collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                TableRow::GroupHeader(0),
+                TableRow::Row(0),
+                TableRow::Row(1),
+                TableRow::GroupHeader(1),
+                TableRow::GroupHeader(2),
+                TableRow::Row(4),
+            ]
+        );
+        assert_eq!(groups.display_row(0), Some(1));
+        assert_eq!(groups.display_row(1), Some(2));
+        assert_eq!(groups.display_row(2), None);
+        assert_eq!(groups.display_row(3), None);
+        assert_eq!(groups.display_row(4), Some(5));
+        assert_eq!(groups.display_row(5), None);
+        assert_eq!(groups.rows_count(1), 2);
+
+        let groups = Groups::new([(0, false), (2, false)]);
+        assert_eq!(groups.display_rows_count(2), 4);
+        assert_eq!(groups.row(1), TableRow::GroupHeader(1));
+        assert_eq!(groups.row(3), TableRow::Row(1));
+        assert_eq!(groups.display_row(0), Some(2));
+
+        let groups = Groups::default();
+        assert!(groups.is_empty());
+        assert_eq!(groups.display_rows_count(3), 3);
+        assert_eq!(groups.row(2), TableRow::Row(2));
+        assert_eq!(groups.display_row(2), Some(2));
+    }
+}
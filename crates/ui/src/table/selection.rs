@@ -0,0 +1,269 @@
+use std::ops::RangeInclusive;
+
+use gpui::{ClipboardItem, ViewContext};
+
+use super::{SelectionState, Table, TableDelegate, TableEvent};
+
+/// The keyboard movement of the selection, the `PageUp` and `PageDown` move by the number of rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Movement {
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp(usize),
+    PageDown(usize),
+    /// The first column of the row.
+    Home,
+    /// The last column of the row.
+    End,
+    /// The first row of the column.
+    Top,
+    /// The last row of the column.
+    Bottom,
+}
+
+impl Movement {
+    /// Move the `(row, col)` position in a grid of `rows_count` x `cols_count`, it stops at the edges.
+    pub(crate) fn apply(
+        self,
+        (row, col): (usize, usize),
+        rows_count: usize,
+        cols_count: usize,
+    ) -> (usize, usize) {
+        let last_row = rows_count.saturating_sub(1);
+        let last_col = cols_count.saturating_sub(1);
+
+        let (row, col) = match self {
+            Self::Up => (row.saturating_sub(1), col),
+            Self::Down => (row + 1, col),
+            Self::Left => (row, col.saturating_sub(1)),
+            Self::Right => (row, col + 1),
+            Self::PageUp(page) => (row.saturating_sub(page.max(1)), col),
+            Self::PageDown(page) => (row + page.max(1), col),
+            Self::Home => (row, 0),
+            Self::End => (row, last_col),
+            Self::Top => (0, col),
+            Self::Bottom => (last_row, col),
+        };
+
+        (row.min(last_row), col.min(last_col))
+    }
+}
+
+/// Join the cells to a TSV text, the tabs and line breaks in the cells are replaced by spaces.
+pub(crate) fn to_tsv(rows: impl IntoIterator<Item = Vec<String>>) -> String {
+    rows.into_iter()
+        .map(|cells| {
+            cells
+                .iter()
+                .map(|cell| cell.replace(['\t', '\r', '\n'], " "))
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn range_between(a: usize, b: usize) -> RangeInclusive<usize> {
+    a.min(b)..=a.max(b)
+}
+
+impl<D> Table<D>
+where
+    D: TableDelegate,
+{
+    /// Set to select the cells instead of the rows by click and the arrow keys, default is false.
+    ///
+    /// Hold `Shift` to select a range of the cells, like a spreadsheet.
+    pub fn cell_selectable(mut self, cell_selectable: bool) -> Self {
+        self.cell_selectable = cell_selectable;
+        self
+    }
+
+    pub fn set_cell_selectable(&mut self, cell_selectable: bool, cx: &mut ViewContext<Self>) {
+        self.cell_selectable = cell_selectable;
+        self.selection_state = SelectionState::Row;
+        self.selection_anchor = None;
+        cx.notify();
+    }
+
+    /// Return the indices of the visible columns in display order.
+    pub(super) fn visible_cols(&self) -> Vec<usize> {
+        self.col_groups
+            .iter()
+            .enumerate()
+            .filter(|(_, group)| group.visible)
+            .map(|(ix, _)| ix)
+            .collect()
+    }
+
+    /// Return the selected rows and columns, the selection is from the anchor to the active cell.
+    ///
+    /// All the columns are included when selecting rows.
+    pub fn selected_range(&self) -> Option<(RangeInclusive<usize>, RangeInclusive<usize>)> {
+        let row_ix = self.selected_row?;
+        let col_ix = self.selected_col.unwrap_or(0);
+        let (anchor_row, anchor_col) = self.selection_anchor.unwrap_or((row_ix, col_ix));
+
+        match self.selection_state {
+            SelectionState::Row => Some((
+                range_between(anchor_row, row_ix),
+                0..=self.col_groups.len().saturating_sub(1),
+            )),
+            SelectionState::Cell => Some((
+                range_between(anchor_row, row_ix),
+                range_between(anchor_col, col_ix),
+            )),
+            SelectionState::Column => None,
+        }
+    }
+
+    pub(super) fn is_row_selected(&self, row_ix: usize) -> bool {
+        self.selection_state == SelectionState::Row
+            && self
+                .selected_range()
+                .map_or(false, |(rows, _)| rows.contains(&row_ix))
+    }
+
+    pub(super) fn is_cell_selected(&self, row_ix: usize, col_ix: usize) -> bool {
+        self.selection_state == SelectionState::Cell
+            && self.selected_range().map_or(false, |(rows, cols)| {
+                rows.contains(&row_ix) && cols.contains(&col_ix)
+            })
+    }
+
+    /// Select the cell, if `extend` is true, select the range from the anchor to the cell.
+    pub fn select_cell(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        extend: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if row_ix >= self.delegate.rows_count() || col_ix >= self.col_groups.len() {
+            return;
+        }
+
+        self.update_anchor(extend);
+        self.selection_state = SelectionState::Cell;
+        self.selected_row = Some(row_ix);
+        self.selected_col = Some(col_ix);
+        self.scroll_to_row(row_ix, cx);
+        cx.emit(TableEvent::SelectCell(row_ix, col_ix));
+        cx.notify();
+    }
+
+    /// Keep the anchor when extending the selection, otherwise start a new selection.
+    pub(super) fn update_anchor(&mut self, extend: bool) {
+        if !extend {
+            self.selection_anchor = None;
+        } else if self.selection_anchor.is_none() {
+            if let Some(row_ix) = self.selected_row {
+                self.selection_anchor = Some((row_ix, self.selected_col.unwrap_or(0)));
+            }
+        }
+    }
+
+    /// Move the selected cell or row by the keyboard.
+    pub(super) fn move_selection(
+        &mut self,
+        movement: Movement,
+        extend: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let rows_count = self.delegate.rows_count();
+        if rows_count == 0 {
+            return;
+        }
+
+        if !self.cell_selectable {
+            // Move to the first or last row to select rows.
+            let movement = match movement {
+                Movement::Home => Movement::Top,
+                Movement::End => Movement::Bottom,
+                movement => movement,
+            };
+            let (row_ix, _) = movement.apply((self.selected_row.unwrap_or(0), 0), rows_count, 1);
+            self.update_anchor(extend);
+            self.set_selected_row(row_ix, cx);
+            return;
+        }
+
+        // Move in the visible columns, the hidden columns are skipped.
+        let visible_cols = self.visible_cols();
+        let col_pos = self
+            .selected_col
+            .and_then(|col_ix| visible_cols.iter().position(|ix| *ix == col_ix))
+            .unwrap_or(0);
+        let pos = match (self.selection_state, self.selected_row) {
+            (SelectionState::Cell, Some(row_ix)) => {
+                movement.apply((row_ix, col_pos), rows_count, visible_cols.len())
+            }
+            // Start from the first cell.
+            _ => (self.selected_row.unwrap_or(0), col_pos),
+        };
+
+        if let Some(col_ix) = visible_cols.get(pos.1) {
+            self.select_cell(pos.0, *col_ix, extend, cx);
+        }
+    }
+
+    /// Return the number of rows in a page of the table, used to move the selection by `PageUp` and `PageDown`.
+    pub(super) fn page_rows(&self) -> usize {
+        (self.bounds.size.height / self.size.table_row_height()).floor() as usize
+    }
+
+    /// Copy the selected cells or rows to the clipboard as TSV text.
+    ///
+    /// The text of the cells is from the delegate's `cell_value`.
+    pub fn copy_selection(&self, cx: &mut ViewContext<Self>) {
+        let Some((rows, cols)) = self.selected_range() else {
+            return;
+        };
+
+        let cols = cols
+            .filter(|col_ix| self.col_groups[*col_ix].visible)
+            .collect::<Vec<_>>();
+        let text = to_tsv(rows.map(|row_ix| {
+            cols.iter()
+                .map(|col_ix| self.delegate.cell_value(row_ix, *col_ix).to_string())
+                .collect()
+        }));
+
+        cx.write_to_clipboard(ClipboardItem::new_string(text));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_tsv, Movement};
+
+    #[test]
+    fn test_movement() {
+        assert_eq!(Movement::Up.apply((0, 1), 10, 3), (0, 1));
+        assert_eq!(Movement::Down.apply((0, 1), 10, 3), (1, 1));
+        assert_eq!(Movement::Down.apply((9, 1), 10, 3), (9, 1));
+        assert_eq!(Movement::Left.apply((2, 0), 10, 3), (2, 0));
+        assert_eq!(Movement::Right.apply((2, 1), 10, 3), (2, 2));
+        assert_eq!(Movement::Right.apply((2, 2), 10, 3), (2, 2));
+        assert_eq!(Movement::PageUp(4).apply((2, 1), 10, 3), (0, 1));
+        assert_eq!(Movement::PageDown(4).apply((2, 1), 10, 3), (6, 1));
+        assert_eq!(Movement::PageDown(4).apply((8, 1), 10, 3), (9, 1));
+        assert_eq!(Movement::PageDown(0).apply((2, 1), 10, 3), (3, 1));
+        assert_eq!(Movement::Home.apply((2, 1), 10, 3), (2, 0));
+        assert_eq!(Movement::End.apply((2, 1), 10, 3), (2, 2));
+        assert_eq!(Movement::Top.apply((2, 1), 10, 3), (0, 1));
+        assert_eq!(Movement::Bottom.apply((2, 1), 10, 3), (9, 1));
+    }
+
+    #[test]
+    fn test_to_tsv() {
+        let rows = vec![
+            vec!["Name".to_string(), "Price".to_string()],
+            vec!["Apple\tInc.".to_string(), "1.5".to_string()],
+            vec!["Line\nbreak".to_string(), "".to_string()],
+        ];
+        assert_eq!(to_tsv(rows), "Name\tPrice\nApple Inc.\t1.5\nLine break\t");
+    }
+}
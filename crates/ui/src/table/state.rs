@@ -0,0 +1,25 @@
+use gpui::{Pixels, SharedString};
+use serde::{Deserialize, Serialize};
+
+/// Used to serialize and deserialize the column layout of the Table.
+///
+/// Use [`crate::table::Table::dump`] to save the layout, and [`crate::table::Table::load`] to restore it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TableState {
+    /// The columns in the display order.
+    pub columns: Vec<ColumnState>,
+}
+
+/// Used to serialize and deserialize a column of the Table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ColumnState {
+    /// The key of the column, see [`crate::table::Column::key`].
+    pub key: SharedString,
+    pub width: Option<Pixels>,
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+}
+
+fn default_visible() -> bool {
+    true
+}
@@ -0,0 +1,235 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, AnyElement, ClickEvent, Div, ElementId, Hsla,
+    InteractiveElement as _, IntoElement, ParentElement, RenderOnce,
+    StatefulInteractiveElement as _, Styled, WindowContext,
+};
+use smallvec::SmallVec;
+
+use crate::{h_flex, theme::ActiveTheme as _, Icon, IconName, Sizable, Size};
+
+/// The color variant of the [`Tag`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TagVariant {
+    #[default]
+    Secondary,
+    Primary,
+    Success,
+    Warning,
+    Danger,
+    Outline,
+    Custom(Hsla),
+}
+
+impl TagVariant {
+    /// Returns the (background, foreground, border) colors.
+    fn colors(&self, cx: &WindowContext) -> (Hsla, Hsla, Hsla) {
+        let theme = cx.theme();
+        let tinted = |color: Hsla| (color.opacity(0.15), color, color.opacity(0.3));
+
+        match self {
+            Self::Secondary => (theme.secondary, theme.secondary_foreground, theme.border),
+            Self::Primary => (theme.primary, theme.primary_foreground, theme.primary),
+            Self::Success => tinted(crate::green_600()),
+            Self::Warning => tinted(crate::amber_600()),
+            Self::Danger => tinted(theme.destructive),
+            Self::Outline => (theme.transparent, theme.foreground, theme.border),
+            Self::Custom(color) => tinted(*color),
+        }
+    }
+}
+
+type OnRemove = Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>;
+
+/// A Tag (or Chip) to display a short label, with an optional leading icon and a close button.
+#[derive(IntoElement)]
+pub struct Tag {
+    id: ElementId,
+    base: Div,
+    variant: TagVariant,
+    icon: Option<Icon>,
+    size: Size,
+    on_remove: Option<OnRemove>,
+    children: SmallVec<[AnyElement; 1]>,
+}
+
+impl Tag {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            variant: TagVariant::default(),
+            icon: None,
+            size: Size::Medium,
+            on_remove: None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Set the color variant of the Tag, default: [`TagVariant::Secondary`]
+    pub fn variant(mut self, variant: TagVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set the color variant to [`TagVariant::Primary`]
+    pub fn primary(self) -> Self {
+        self.variant(TagVariant::Primary)
+    }
+
+    /// Set the color variant to [`TagVariant::Success`]
+    pub fn success(self) -> Self {
+        self.variant(TagVariant::Success)
+    }
+
+    /// Set the color variant to [`TagVariant::Warning`]
+    pub fn warning(self) -> Self {
+        self.variant(TagVariant::Warning)
+    }
+
+    /// Set the color variant to [`TagVariant::Danger`]
+    pub fn danger(self) -> Self {
+        self.variant(TagVariant::Danger)
+    }
+
+    /// Set the color variant to [`TagVariant::Outline`]
+    pub fn outline(self) -> Self {
+        self.variant(TagVariant::Outline)
+    }
+
+    /// Set a custom color of the Tag.
+    pub fn color(self, color: impl Into<Hsla>) -> Self {
+        self.variant(TagVariant::Custom(color.into()))
+    }
+
+    /// Set the leading icon.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the handler to show a close button, the handler is called when the close button is clicked.
+    pub fn on_remove(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_remove = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Sizable for Tag {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for Tag {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for Tag {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for Tag {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (bg, fg, border) = self.variant.colors(cx);
+        let (height, icon_size) = match self.size {
+            Size::XSmall | Size::Small => (px(20.), px(12.)),
+            Size::Large => (px(28.), px(16.)),
+            _ => (px(24.), px(14.)),
+        };
+
+        self.base
+            .flex()
+            .flex_none()
+            .items_center()
+            .gap_1()
+            .h(height)
+            .px_2()
+            .map(|this| match self.size {
+                Size::XSmall | Size::Small => this.text_xs(),
+                _ => this.text_sm(),
+            })
+            .line_height(relative(1.))
+            .whitespace_nowrap()
+            .rounded(px(cx.theme().radius))
+            .border_1()
+            .border_color(border)
+            .bg(bg)
+            .text_color(fg)
+            .when_some(self.icon, |this, icon| {
+                this.child(icon.size(icon_size).text_color(fg))
+            })
+            .children(self.children)
+            .when_some(self.on_remove, |this, on_remove| {
+                this.child(
+                    h_flex()
+                        .id(self.id)
+                        .flex_none()
+                        .justify_center()
+                        .rounded_sm()
+                        .cursor_pointer()
+                        .opacity(0.6)
+                        .hover(|this| this.opacity(1.))
+                        .child(Icon::new(IconName::Close).size(icon_size).text_color(fg))
+                        .on_click(move |event, cx| {
+                            cx.stop_propagation();
+                            on_remove(event, cx)
+                        }),
+                )
+            })
+    }
+}
+
+/// A wrapping layout of [`Tag`]s.
+#[derive(IntoElement)]
+pub struct TagList {
+    base: Div,
+    children: SmallVec<[AnyElement; 4]>,
+}
+
+impl TagList {
+    pub fn new() -> Self {
+        Self {
+            base: div(),
+            children: SmallVec::new(),
+        }
+    }
+}
+
+impl Default for TagList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Styled for TagList {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for TagList {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for TagList {
+    fn render(self, _: &mut WindowContext) -> impl IntoElement {
+        self.base
+            .flex()
+            .flex_wrap()
+            .items_center()
+            .gap_1()
+            .children(self.children)
+    }
+}
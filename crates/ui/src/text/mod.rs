@@ -0,0 +1,3 @@
+mod rich_text;
+
+pub use rich_text::*;
@@ -0,0 +1,319 @@
+use std::rc::Rc;
+
+use gpui::{
+    px, ElementId, FontStyle, FontWeight, HighlightStyle, InteractiveText, IntoElement, RenderOnce,
+    SharedString, StyledText, UnderlineStyle, WindowContext,
+};
+
+use crate::theme::ActiveTheme as _;
+
+/// A span of the [`RichText`] with the inline styles.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextSpan {
+    pub text: SharedString,
+    pub bold: bool,
+    pub italic: bool,
+    pub code: bool,
+    pub link: Option<SharedString>,
+}
+
+impl TextSpan {
+    pub fn new(text: impl Into<SharedString>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub fn code(mut self) -> Self {
+        self.code = true;
+        self
+    }
+
+    pub fn link(mut self, url: impl Into<SharedString>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
+}
+
+impl From<&'static str> for TextSpan {
+    fn from(text: &'static str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<SharedString> for TextSpan {
+    fn from(text: SharedString) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for TextSpan {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+/// Parse the inline markdown into spans.
+///
+/// Supports `**bold**`, `*italic*`, `` `code` ``, `[link](url)` and `\` escape,
+/// the unmatched markers are kept as the plain text.
+pub(crate) fn parse_inline_markdown(text: &str) -> Vec<TextSpan> {
+    let mut spans = vec![];
+    let mut buf = String::new();
+    let (mut bold, mut italic) = (false, false);
+
+    let flush = |buf: &mut String, spans: &mut Vec<TextSpan>, bold: bool, italic: bool| {
+        if !buf.is_empty() {
+            spans.push(TextSpan {
+                text: std::mem::take(buf).into(),
+                bold,
+                italic,
+                ..Default::default()
+            });
+        }
+    };
+
+    let mut ix = 0;
+    while ix < text.len() {
+        let rest = &text[ix..];
+        let c = rest.chars().next().unwrap();
+
+        match c {
+            '\\' => {
+                if let Some(next) = rest[1..]
+                    .chars()
+                    .next()
+                    .filter(|c| c.is_ascii_punctuation())
+                {
+                    buf.push(next);
+                    ix += 1 + next.len_utf8();
+                    continue;
+                }
+            }
+            '`' => {
+                if let Some(end) = rest[1..].find('`') {
+                    flush(&mut buf, &mut spans, bold, italic);
+                    spans.push(TextSpan {
+                        text: rest[1..1 + end].to_string().into(),
+                        bold,
+                        italic,
+                        code: true,
+                        link: None,
+                    });
+                    ix += end + 2;
+                    continue;
+                }
+            }
+            '*' | '_' => {
+                let marker = if rest[1..].starts_with(c) {
+                    &rest[..2]
+                } else {
+                    &rest[..1]
+                };
+                let after = &rest[marker.len()..];
+                let is_open = if marker.len() == 2 { bold } else { italic };
+                // The `_` inside a word is not a marker, e.g.: `snake_case`.
+                let in_word = c == '_'
+                    && text[..ix].chars().last().is_some_and(char::is_alphanumeric)
+                    && after.chars().next().is_some_and(char::is_alphanumeric);
+                let can_open = after.chars().next().is_some_and(|c| !c.is_whitespace())
+                    && after.contains(marker);
+
+                if !in_word && (is_open || can_open) {
+                    flush(&mut buf, &mut spans, bold, italic);
+                    if marker.len() == 2 {
+                        bold = !bold;
+                    } else {
+                        italic = !italic;
+                    }
+                    ix += marker.len();
+                    continue;
+                }
+            }
+            '[' => {
+                if let Some((label, url, len)) = parse_link(rest) {
+                    flush(&mut buf, &mut spans, bold, italic);
+                    spans.push(TextSpan {
+                        text: label.to_string().into(),
+                        bold,
+                        italic,
+                        code: false,
+                        link: Some(url.to_string().into()),
+                    });
+                    ix += len;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+
+        buf.push(c);
+        ix += c.len_utf8();
+    }
+    flush(&mut buf, &mut spans, bold, italic);
+
+    spans
+}
+
+/// Parse the `[label](url)` at the start of the text, return the label, url and the length.
+fn parse_link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = text.find("](")?;
+    let url_end = label_end + 2 + text[label_end + 2..].find(')')?;
+    let (label, url) = (&text[1..label_end], &text[label_end + 2..url_end]);
+    if label.is_empty() || label.contains(['[', '\n']) || url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((label, url, url_end + 1))
+}
+
+/// A text with the limited inline styles: bold, italic, code span and links.
+///
+/// The text is wrapped and inherits the text style of the parent.
+///
+/// ```ignore
+/// RichText::new("message").markdown("Hello **world**, see [docs](https://example.com).")
+///
+/// RichText::new("message")
+///     .span("Hello ")
+///     .span(TextSpan::new("world").bold())
+/// ```
+#[derive(IntoElement)]
+pub struct RichText {
+    id: ElementId,
+    spans: Vec<TextSpan>,
+    on_click: Option<Rc<dyn Fn(&SharedString, &mut WindowContext)>>,
+}
+
+impl RichText {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            spans: vec![],
+            on_click: None,
+        }
+    }
+
+    /// Append the spans parsed from the inline markdown.
+    pub fn markdown(mut self, text: &str) -> Self {
+        self.spans.extend(parse_inline_markdown(text));
+        self
+    }
+
+    /// Append a span.
+    pub fn span(mut self, span: impl Into<TextSpan>) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Set the handler of clicking the links with the url, default opens the url in the browser.
+    pub fn on_click(
+        mut self,
+        handler: impl Fn(&SharedString, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for RichText {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let mut text = String::new();
+        let mut highlights = vec![];
+        let mut link_ranges = vec![];
+        let mut urls = vec![];
+
+        for span in self.spans {
+            let start = text.len();
+            text.push_str(&span.text);
+            let range = start..text.len();
+            if range.is_empty() {
+                continue;
+            }
+
+            let mut style = HighlightStyle::default();
+            if span.bold {
+                style.font_weight = Some(FontWeight::BOLD);
+            }
+            if span.italic {
+                style.font_style = Some(FontStyle::Italic);
+            }
+            if span.code {
+                style.background_color = Some(cx.theme().muted);
+            }
+            if let Some(url) = span.link {
+                style.color = Some(cx.theme().link);
+                style.underline = Some(UnderlineStyle {
+                    thickness: px(1.),
+                    color: Some(cx.theme().link),
+                    wavy: false,
+                });
+                link_ranges.push(range.clone());
+                urls.push(url);
+            }
+
+            if style != HighlightStyle::default() {
+                highlights.push((range, style));
+            }
+        }
+
+        let text = StyledText::new(text).with_highlights(&cx.text_style(), highlights);
+        let on_click = self.on_click;
+
+        InteractiveText::new(self.id, text).on_click(link_ranges, move |ix, cx| {
+            let url = &urls[ix];
+            match &on_click {
+                Some(on_click) => on_click(url, cx),
+                None => cx.open_url(url),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_inline_markdown, TextSpan};
+
+    #[test]
+    fn test_parse_inline_markdown() {
+        assert_eq!(parse_inline_markdown("Hello"), vec![TextSpan::new("Hello")]);
+        assert_eq!(
+            parse_inline_markdown("Hello **world** and *you*"),
+            vec![
+                TextSpan::new("Hello "),
+                TextSpan::new("world").bold(),
+                TextSpan::new(" and "),
+                TextSpan::new("you").italic(),
+            ]
+        );
+        assert_eq!(
+            parse_inline_markdown("Run `cargo run`, see [docs](https://example.com)."),
+            vec![
+                TextSpan::new("Run "),
+                TextSpan::new("cargo run").code(),
+                TextSpan::new(", see "),
+                TextSpan::new("docs").link("https://example.com"),
+                TextSpan::new("."),
+            ]
+        );
+        assert_eq!(
+            parse_inline_markdown("snake_case, 2 * 3, \\*not italic\\*, [no link]"),
+            vec![TextSpan::new("snake_case, 2 * 3, *not italic*, [no link]")]
+        );
+        assert_eq!(
+            parse_inline_markdown("***both***"),
+            vec![TextSpan::new("both").bold().italic()]
+        );
+    }
+}
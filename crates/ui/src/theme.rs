@@ -5,6 +5,9 @@ use gpui::{
     ViewContext, WindowAppearance, WindowContext,
 };
 
+mod extension;
+pub use extension::*;
+
 pub fn init(cx: &mut AppContext) {
     Theme::sync_system_appearance(cx)
 }
@@ -169,6 +172,11 @@ struct Colors {
     pub list_head: Hsla,
     pub link: Hsla,
     pub drop_target: Hsla,
+    pub chart_1: Hsla,
+    pub chart_2: Hsla,
+    pub chart_3: Hsla,
+    pub chart_4: Hsla,
+    pub chart_5: Hsla,
 }
 
 impl Colors {
@@ -211,6 +219,11 @@ impl Colors {
             list_head: hsl(0.0, 0.0, 100.),
             link: hsl(221.0, 83.0, 53.0),
             drop_target: hsl(235.0, 30., 44.0).opacity(0.25),
+            chart_1: hsl(12.0, 76.0, 61.0),
+            chart_2: hsl(173.0, 58.0, 39.0),
+            chart_3: hsl(197.0, 37.0, 24.0),
+            chart_4: hsl(43.0, 74.0, 66.0),
+            chart_5: hsl(27.0, 87.0, 67.0),
         }
     }
 
@@ -253,6 +266,11 @@ impl Colors {
             list_head: hsl(0.0, 0.0, 6.0),
             link: hsl(221.0, 83.0, 53.0),
             drop_target: hsl(235.0, 30., 44.0).opacity(0.1),
+            chart_1: hsl(220.0, 70.0, 50.0),
+            chart_2: hsl(160.0, 60.0, 45.0),
+            chart_3: hsl(30.0, 80.0, 55.0),
+            chart_4: hsl(280.0, 65.0, 60.0),
+            chart_5: hsl(340.0, 75.0, 55.0),
         }
     }
 }
@@ -265,6 +283,8 @@ pub struct Theme {
     /// Basic font size
     pub font_size: f32,
     pub font_family: SharedString,
+    /// The monospace font for the code.
+    pub mono_font_family: SharedString,
     pub background: Hsla,
     pub foreground: Hsla,
     pub card: Hsla,
@@ -323,6 +343,14 @@ pub struct Theme {
     pub link_hover: Hsla,
     pub link_active: Hsla,
     pub skeleton: Hsla,
+    /// The palette of the chart series, see [`crate::chart`].
+    pub chart_1: Hsla,
+    pub chart_2: Hsla,
+    pub chart_3: Hsla,
+    pub chart_4: Hsla,
+    pub chart_5: Hsla,
+    /// The tokens of the third-party components, see [`ComponentStyle`].
+    pub extensions: ThemeExtensions,
 }
 
 impl Global for Theme {}
@@ -331,6 +359,48 @@ impl Theme {
     pub fn get_global(cx: &AppContext) -> &Self {
         cx.global::<Self>()
     }
+
+    /// Register the [`ComponentStyle`] tokens into the theme,
+    /// this should be called in the `init` of the component crate after [`crate::init`].
+    pub fn register_ext<T: ComponentStyle>(cx: &mut AppContext) {
+        let theme = cx.global_mut::<Self>();
+        let mode = theme.mode;
+        theme.extensions.register::<T>(mode);
+    }
+
+    /// Get the [`ComponentStyle`] tokens.
+    ///
+    /// Panics if the tokens are not registered by [`Theme::register_ext`].
+    pub fn ext<T: ComponentStyle>(&self) -> &T {
+        self.try_ext::<T>().unwrap_or_else(|| {
+            panic!(
+                "theme tokens `{}` are not registered, call `Theme::register_ext` first",
+                T::NAMESPACE
+            )
+        })
+    }
+
+    /// Get the [`ComponentStyle`] tokens, returns None if not registered.
+    pub fn try_ext<T: ComponentStyle>(&self) -> Option<&T> {
+        self.extensions.get::<T>()
+    }
+
+    /// Load the [`ComponentStyle`] tokens from the theme JSON, keyed by the namespace.
+    ///
+    /// The loaded tokens are merged into the defaults, and keep after the theme mode changed.
+    /// Returns error if any tokens are invalid, and then none of the tokens are loaded.
+    pub fn load_ext_json(json: &serde_json::Value, cx: &mut AppContext) -> anyhow::Result<()> {
+        let theme = cx.global_mut::<Self>();
+        let mode = theme.mode;
+        theme.extensions.load_json(json, mode)?;
+        cx.refresh();
+        Ok(())
+    }
+
+    /// Serialize the [`ComponentStyle`] tokens into the theme JSON, keyed by the namespace.
+    pub fn ext_json(&self) -> serde_json::Value {
+        self.extensions.to_json()
+    }
 }
 
 impl From<Colors> for Theme {
@@ -346,6 +416,13 @@ impl From<Colors> for Theme {
             } else {
                 "FreeMono".into()
             },
+            mono_font_family: if cfg!(target_os = "macos") {
+                "Menlo".into()
+            } else if cfg!(target_os = "windows") {
+                "Consolas".into()
+            } else {
+                "DejaVu Sans Mono".into()
+            },
             radius: 4.0,
             shadow: true,
             title_bar_background: colors.title_bar_background,
@@ -404,6 +481,12 @@ impl From<Colors> for Theme {
             link_hover: colors.link.lighten(0.2),
             link_active: colors.link.darken(0.2),
             skeleton: hsla(colors.primary.h, colors.primary.s, colors.primary.l, 0.1),
+            chart_1: colors.chart_1,
+            chart_2: colors.chart_2,
+            chart_3: colors.chart_3,
+            chart_4: colors.chart_4,
+            chart_5: colors.chart_5,
+            extensions: ThemeExtensions::default(),
         }
     }
 }
@@ -442,6 +525,10 @@ impl Theme {
 
         let mut theme = Theme::from(colors);
         theme.mode = mode;
+        if let Some(old_theme) = cx.try_global::<Theme>() {
+            theme.extensions = old_theme.extensions.clone();
+            theme.extensions.resolve(mode);
+        }
 
         cx.set_global(theme);
         cx.refresh();
@@ -0,0 +1,249 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    rc::Rc,
+};
+
+use anyhow::{Context as _, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use super::ThemeMode;
+
+/// The theme tokens of a third-party component.
+///
+/// Implement this trait for the tokens struct, and register it by [`crate::theme::Theme::register_ext`],
+/// then read them with `cx.theme().ext::<MyTokens>()`.
+///
+/// ```ignore
+/// #[derive(Clone, Serialize, Deserialize)]
+/// struct ChartTokens {
+///     grid: Hsla,
+///     line: Hsla,
+/// }
+///
+/// impl ComponentStyle for ChartTokens {
+///     const NAMESPACE: &'static str = "my_crate.chart";
+///
+///     fn default_for(mode: ThemeMode) -> Self {
+///         ...
+///     }
+/// }
+/// ```
+pub trait ComponentStyle: Clone + Serialize + DeserializeOwned + 'static {
+    /// The namespace of the tokens in the theme JSON, e.g.: `my_crate.chart`.
+    const NAMESPACE: &'static str;
+
+    /// Return the default tokens of the theme mode.
+    fn default_for(mode: ThemeMode) -> Self;
+}
+
+type DefaultTokens = fn(ThemeMode) -> Rc<dyn Any>;
+type Resolve = fn(ThemeMode, &Value) -> Result<Rc<dyn Any>>;
+type ToJson = fn(&dyn Any) -> Value;
+
+#[derive(Clone)]
+struct Entry {
+    namespace: &'static str,
+    value: Rc<dyn Any>,
+    /// The tokens loaded from the theme JSON, will be merged into the default tokens.
+    overrides: Option<Value>,
+    default: DefaultTokens,
+    resolve: Resolve,
+    to_json: ToJson,
+}
+
+/// The registered [`ComponentStyle`] tokens of the theme.
+#[derive(Clone, Default)]
+pub struct ThemeExtensions {
+    entries: HashMap<TypeId, Entry>,
+}
+
+impl fmt::Debug for ThemeExtensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.entries.values().map(|entry| entry.namespace))
+            .finish()
+    }
+}
+
+impl ThemeExtensions {
+    pub(crate) fn register<T: ComponentStyle>(&mut self, mode: ThemeMode) {
+        let default: DefaultTokens = |mode| Rc::new(T::default_for(mode));
+        let resolve: Resolve = |mode, overrides| Ok(Rc::new(resolve_tokens::<T>(mode, overrides)?));
+        let to_json: ToJson = |value| {
+            value
+                .downcast_ref::<T>()
+                .and_then(|value| serde_json::to_value(value).ok())
+                .unwrap_or_default()
+        };
+
+        self.entries.insert(
+            TypeId::of::<T>(),
+            Entry {
+                namespace: T::NAMESPACE,
+                value: default(mode),
+                overrides: None,
+                default,
+                resolve,
+                to_json,
+            },
+        );
+    }
+
+    pub(crate) fn get<T: ComponentStyle>(&self) -> Option<&T> {
+        self.entries
+            .get(&TypeId::of::<T>())
+            .and_then(|entry| entry.value.downcast_ref::<T>())
+    }
+
+    /// Resolve all the tokens for the theme mode.
+    ///
+    /// The overrides have been checked by [`Self::load_json`], if they still can't be merged
+    /// into the defaults of the mode, the default tokens of the mode are used.
+    pub(crate) fn resolve(&mut self, mode: ThemeMode) {
+        for entry in self.entries.values_mut() {
+            entry.value = entry
+                .overrides
+                .as_ref()
+                .and_then(|overrides| (entry.resolve)(mode, overrides).ok())
+                .unwrap_or_else(|| (entry.default)(mode));
+        }
+    }
+
+    /// Load the tokens from the JSON object that keyed by the namespace, the unknown namespaces are ignored.
+    ///
+    /// Returns error if any tokens are invalid, and then nothing is changed.
+    pub(crate) fn load_json(&mut self, json: &Value, mode: ThemeMode) -> Result<()> {
+        let mut loaded = vec![];
+        for (type_id, entry) in self.entries.iter() {
+            if let Some(overrides) = json.get(entry.namespace) {
+                let value = (entry.resolve)(mode, overrides)?;
+                loaded.push((*type_id, overrides.clone(), value));
+            }
+        }
+
+        for (type_id, overrides, value) in loaded {
+            if let Some(entry) = self.entries.get_mut(&type_id) {
+                entry.overrides = Some(overrides);
+                entry.value = value;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Serialize the current tokens into a JSON object that keyed by the namespace.
+    pub(crate) fn to_json(&self) -> Value {
+        let map = self
+            .entries
+            .values()
+            .map(|entry| {
+                (
+                    entry.namespace.to_string(),
+                    (entry.to_json)(entry.value.as_ref()),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        Value::Object(map)
+    }
+}
+
+/// Merge the overrides into the default tokens of the mode.
+fn resolve_tokens<T: ComponentStyle>(mode: ThemeMode, overrides: &Value) -> Result<T> {
+    let mut value = serde_json::to_value(T::default_for(mode))?;
+    merge(&mut value, overrides);
+
+    serde_json::from_value(value)
+        .with_context(|| format!("invalid theme tokens of `{}`", T::NAMESPACE))
+}
+
+fn merge(target: &mut Value, source: &Value) {
+    match (target, source) {
+        (Value::Object(target), Value::Object(source)) => {
+            for (key, value) in source {
+                match target.get_mut(key) {
+                    Some(target) => merge(target, value),
+                    None => {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (target, source) => *target = source.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::{ComponentStyle, ThemeExtensions};
+    use crate::theme::ThemeMode;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TestTokens {
+        line: String,
+        width: f32,
+    }
+
+    impl ComponentStyle for TestTokens {
+        const NAMESPACE: &'static str = "test.chart";
+
+        fn default_for(mode: ThemeMode) -> Self {
+            Self {
+                line: if mode.is_dark() { "white" } else { "black" }.into(),
+                width: 1.,
+            }
+        }
+    }
+
+    #[test]
+    fn test_extensions() {
+        let mut extensions = ThemeExtensions::default();
+        extensions.register::<TestTokens>(ThemeMode::Light);
+        assert_eq!(extensions.get::<TestTokens>().unwrap().line, "black");
+
+        extensions.resolve(ThemeMode::Dark);
+        assert_eq!(extensions.get::<TestTokens>().unwrap().line, "white");
+
+        extensions
+            .load_json(
+                &json!({ "test.chart": { "width": 2.0 }, "unknown": { "foo": 1 } }),
+                ThemeMode::Dark,
+            )
+            .unwrap();
+        assert_eq!(
+            extensions.get::<TestTokens>(),
+            Some(&TestTokens {
+                line: "white".into(),
+                width: 2.,
+            })
+        );
+        assert_eq!(
+            extensions.to_json(),
+            json!({ "test.chart": { "line": "white", "width": 2.0 } })
+        );
+
+        // Invalid overrides are returned as error, and the loaded tokens are kept.
+        assert!(extensions
+            .load_json(
+                &json!({ "test.chart": { "width": "wide" } }),
+                ThemeMode::Dark
+            )
+            .is_err());
+        assert_eq!(extensions.get::<TestTokens>().unwrap().width, 2.);
+
+        extensions.resolve(ThemeMode::Light);
+        assert_eq!(
+            extensions.get::<TestTokens>(),
+            Some(&TestTokens {
+                line: "black".into(),
+                width: 2.,
+            })
+        );
+    }
+}
@@ -1,10 +1,10 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, rc::Rc};
 
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Duration, Local, Months, NaiveDate, Weekday};
 use gpui::{
-    prelude::FluentBuilder as _, relative, ClickEvent, ElementId, EventEmitter, FocusHandle,
-    InteractiveElement, IntoElement, ParentElement, Render, SharedString,
-    StatefulInteractiveElement, Styled, ViewContext,
+    actions, prelude::FluentBuilder as _, relative, AppContext, ClickEvent, ElementId,
+    EventEmitter, FocusHandle, FocusableView, InteractiveElement, IntoElement, KeyBinding,
+    ParentElement, Render, SharedString, StatefulInteractiveElement, Styled, ViewContext,
 };
 use rust_i18n::t;
 
@@ -15,7 +15,74 @@ use crate::{
     v_flex, Disableable as _, IconName, Selectable,
 };
 
-use super::utils::days_in_month;
+use super::utils::{days_from_week_start, days_in_month, locale_week_start};
+
+actions!(
+    calendar,
+    [
+        SelectPrevDay,
+        SelectNextDay,
+        SelectPrevWeek,
+        SelectNextWeek,
+        SelectPrevMonth,
+        SelectNextMonth,
+        SelectPrevYear,
+        SelectNextYear,
+        SelectWeekStart,
+        SelectWeekEnd,
+        Confirm
+    ]
+);
+
+pub fn init(cx: &mut AppContext) {
+    let context = Some("Calendar");
+    cx.bind_keys([
+        KeyBinding::new("left", SelectPrevDay, context),
+        KeyBinding::new("right", SelectNextDay, context),
+        KeyBinding::new("up", SelectPrevWeek, context),
+        KeyBinding::new("down", SelectNextWeek, context),
+        KeyBinding::new("pageup", SelectPrevMonth, context),
+        KeyBinding::new("pagedown", SelectNextMonth, context),
+        KeyBinding::new("shift-pageup", SelectPrevYear, context),
+        KeyBinding::new("shift-pagedown", SelectNextYear, context),
+        KeyBinding::new("home", SelectWeekStart, context),
+        KeyBinding::new("end", SelectWeekEnd, context),
+        KeyBinding::new("enter", Confirm, context),
+        KeyBinding::new("space", Confirm, context),
+    ]);
+}
+
+/// The keyboard movement of the active date in the calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Movement {
+    Days(i64),
+    Months(i32),
+    WeekStart,
+    WeekEnd,
+}
+
+impl Movement {
+    /// Move the date, the day is clamped to the last day of the month when moving by months.
+    fn apply(self, date: NaiveDate, week_start: Weekday) -> NaiveDate {
+        let moved = match self {
+            Self::Days(days) => date.checked_add_signed(Duration::days(days)),
+            Self::Months(months) if months < 0 => {
+                date.checked_sub_months(Months::new(months.unsigned_abs()))
+            }
+            Self::Months(months) => date.checked_add_months(Months::new(months as u32)),
+            Self::WeekStart => {
+                let days = days_from_week_start(date.weekday(), week_start);
+                date.checked_sub_signed(Duration::days(days as i64))
+            }
+            Self::WeekEnd => {
+                let days = 6 - days_from_week_start(date.weekday(), week_start);
+                date.checked_add_signed(Duration::days(days as i64))
+            }
+        };
+
+        moved.unwrap_or(date)
+    }
+}
 
 pub enum CalendarEvent {
     /// The user selected a date.
@@ -85,6 +152,17 @@ impl Date {
         }
     }
 
+    /// Check if the date is between the selected start and the hovered date,
+    /// to preview the range before selecting the end.
+    fn is_in_preview(&self, v: &NaiveDate, hovered: Option<NaiveDate>) -> bool {
+        match (self, hovered) {
+            (Self::Range(Some(start), None), Some(hovered)) => {
+                hovered >= *start && v >= start && *v <= hovered
+            }
+            _ => false,
+        }
+    }
+
     pub fn is_some(&self) -> bool {
         match self {
             Self::Single(Some(_)) | Self::Range(Some(_), _) => true,
@@ -152,6 +230,15 @@ impl ViewMode {
 pub struct Calendar {
     focus_handle: FocusHandle,
     date: Date,
+    /// The date focused by the keyboard, press `Enter` to select it.
+    active_date: Option<NaiveDate>,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
+    disabled_matcher: Option<Rc<dyn Fn(&NaiveDate) -> bool>>,
+    /// The first day of the week, default is by the locale.
+    week_start: Weekday,
+    /// The hovered date to preview the range in range mode.
+    hovered_date: Option<NaiveDate>,
     view_mode: ViewMode,
     current_year: i32,
     current_month: u8,
@@ -168,6 +255,12 @@ impl Calendar {
             focus_handle: cx.focus_handle(),
             view_mode: ViewMode::Day,
             date: Date::Single(None),
+            active_date: None,
+            min_date: None,
+            max_date: None,
+            disabled_matcher: None,
+            week_start: locale_week_start(),
+            hovered_date: None,
             current_month: today.month() as u8,
             current_year: today.year(),
             years: vec![],
@@ -214,6 +307,68 @@ impl Calendar {
         cx.notify();
     }
 
+    /// Set the earliest date can be selected.
+    pub fn min_date(mut self, date: NaiveDate) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    /// Set the latest date can be selected.
+    pub fn max_date(mut self, date: NaiveDate) -> Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Set a matcher to disable the dates, e.g.: the weekends.
+    ///
+    /// ```ignore
+    /// Calendar::new(cx).disabled_dates(|date| date.weekday().num_days_from_monday() >= 5)
+    /// ```
+    pub fn disabled_dates(mut self, matcher: impl Fn(&NaiveDate) -> bool + 'static) -> Self {
+        self.disabled_matcher = Some(Rc::new(matcher));
+        self
+    }
+
+    /// Set the first day of the week, default is by the locale, e.g.: Sunday in `en`, Monday in `zh-CN`.
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    pub fn set_min_date(&mut self, date: Option<NaiveDate>, cx: &mut ViewContext<Self>) {
+        self.min_date = date;
+        cx.notify();
+    }
+
+    pub fn set_max_date(&mut self, date: Option<NaiveDate>, cx: &mut ViewContext<Self>) {
+        self.max_date = date;
+        cx.notify();
+    }
+
+    pub fn set_disabled_dates(
+        &mut self,
+        matcher: Option<Rc<dyn Fn(&NaiveDate) -> bool>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.disabled_matcher = matcher;
+        cx.notify();
+    }
+
+    pub fn set_week_start(&mut self, week_start: Weekday, cx: &mut ViewContext<Self>) {
+        self.week_start = week_start;
+        cx.notify();
+    }
+
+    /// Return true if the date is out of the min and max dates, or disabled by the matcher.
+    pub fn is_disabled(&self, date: &NaiveDate) -> bool {
+        self.min_date.map_or(false, |min| *date < min)
+            || self.max_date.map_or(false, |max| *date > max)
+            || self
+                .disabled_matcher
+                .as_ref()
+                .map_or(false, |matcher| matcher(date))
+    }
+
     /// Set the year range of the calendar, default is 50 years before and after the current year.
     ///
     /// Each year page contains 20 years, so the range will be divided into chunks of 20 years is better.
@@ -247,15 +402,135 @@ impl Calendar {
         (year, month as u32)
     }
 
-    /// Returns the days of the month in a 2D vector to render on calendar.
-    fn days(&self) -> Vec<Vec<NaiveDate>> {
+    /// Returns the weeks of each month to render on calendar.
+    fn days(&self) -> Vec<Vec<Vec<NaiveDate>>> {
         (0..self.number_of_months)
-            .flat_map(|offset| {
-                days_in_month(self.current_year, self.current_month as u32 + offset as u32)
+            .map(|offset| {
+                let (year, month) = self.offset_year_month(offset);
+                days_in_month(year, month, self.week_start)
             })
             .collect()
     }
 
+    /// Return true if the date is in the months shown in the calendar.
+    fn is_shown(&self, date: &NaiveDate) -> bool {
+        let (year, month) = self.offset_year_month(self.number_of_months.saturating_sub(1));
+        let first = (self.current_year, self.current_month as u32);
+        (first..=(year, month)).contains(&(date.year(), date.month()))
+    }
+
+    /// Move the active date by the keyboard, and show the month of it.
+    fn move_active_date(&mut self, movement: Movement, cx: &mut ViewContext<Self>) {
+        let today = Local::now().naive_local().date();
+        let date = self.active_date.or(self.date.start()).unwrap_or(today);
+        // Start from the current date at the first time.
+        let mut date = if self.active_date.is_none() {
+            date
+        } else {
+            movement.apply(date, self.week_start)
+        };
+        if let Some(min) = self.min_date {
+            date = date.max(min);
+        }
+        if let Some(max) = self.max_date {
+            date = date.min(max);
+        }
+
+        if !self.is_shown(&date) {
+            // Show the date in the last month when moving forward, otherwise in the first month.
+            let first = match movement {
+                Movement::Days(1..) | Movement::Months(1..) | Movement::WeekEnd => date
+                    .checked_sub_months(Months::new(self.number_of_months.saturating_sub(1) as u32))
+                    .unwrap_or(date),
+                _ => date,
+            };
+            self.current_year = first.year();
+            self.current_month = first.month() as u8;
+        }
+
+        self.view_mode = ViewMode::Day;
+        self.active_date = Some(date);
+        cx.notify();
+    }
+
+    fn on_action_prev_day(&mut self, _: &SelectPrevDay, cx: &mut ViewContext<Self>) {
+        self.move_active_date(Movement::Days(-1), cx);
+    }
+
+    fn on_action_next_day(&mut self, _: &SelectNextDay, cx: &mut ViewContext<Self>) {
+        self.move_active_date(Movement::Days(1), cx);
+    }
+
+    fn on_action_prev_week(&mut self, _: &SelectPrevWeek, cx: &mut ViewContext<Self>) {
+        self.move_active_date(Movement::Days(-7), cx);
+    }
+
+    fn on_action_next_week(&mut self, _: &SelectNextWeek, cx: &mut ViewContext<Self>) {
+        self.move_active_date(Movement::Days(7), cx);
+    }
+
+    fn on_action_prev_month(&mut self, _: &SelectPrevMonth, cx: &mut ViewContext<Self>) {
+        self.move_active_date(Movement::Months(-1), cx);
+    }
+
+    fn on_action_next_month(&mut self, _: &SelectNextMonth, cx: &mut ViewContext<Self>) {
+        self.move_active_date(Movement::Months(1), cx);
+    }
+
+    fn on_action_prev_year(&mut self, _: &SelectPrevYear, cx: &mut ViewContext<Self>) {
+        self.move_active_date(Movement::Months(-12), cx);
+    }
+
+    fn on_action_next_year(&mut self, _: &SelectNextYear, cx: &mut ViewContext<Self>) {
+        self.move_active_date(Movement::Months(12), cx);
+    }
+
+    fn on_action_week_start(&mut self, _: &SelectWeekStart, cx: &mut ViewContext<Self>) {
+        self.move_active_date(Movement::WeekStart, cx);
+    }
+
+    fn on_action_week_end(&mut self, _: &SelectWeekEnd, cx: &mut ViewContext<Self>) {
+        self.move_active_date(Movement::WeekEnd, cx);
+    }
+
+    fn on_action_confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        if let Some(date) = self.active_date {
+            self.select_day(date, cx);
+        }
+    }
+
+    /// Select the day, in the range mode, the first click selects the start and the second click selects the end.
+    fn select_day(&mut self, date: NaiveDate, cx: &mut ViewContext<Self>) {
+        if self.is_disabled(&date) {
+            return;
+        }
+
+        self.active_date = Some(date);
+        if self.date.is_single() {
+            self.set_date(date, cx);
+            cx.emit(CalendarEvent::Selected(self.date()));
+        } else {
+            let start = self.date.start();
+            let end = self.date.end();
+
+            if start.is_none() && end.is_none() {
+                self.set_date(Date::Range(Some(date), None), cx);
+            } else if start.is_some() && end.is_none() {
+                if date < start.unwrap() {
+                    self.set_date(Date::Range(Some(date), None), cx);
+                } else {
+                    self.set_date(Date::Range(Some(start.unwrap()), Some(date)), cx);
+                }
+            } else {
+                self.set_date(Date::Range(Some(date), None), cx);
+            }
+
+            if self.date.is_complete() {
+                cx.emit(CalendarEvent::Selected(self.date()));
+            }
+        }
+    }
+
     fn has_prev_year_page(&self) -> bool {
         self.year_page > 0
     }
@@ -345,6 +620,7 @@ impl Calendar {
             .child(week.into())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn item_button(
         &self,
         id: impl Into<ElementId>,
@@ -352,6 +628,7 @@ impl Calendar {
         active: bool,
         secondary_active: bool,
         muted: bool,
+        disabled: bool,
         cx: &mut ViewContext<Self>,
     ) -> impl IntoElement + Styled + StatefulInteractiveElement {
         h_flex()
@@ -360,10 +637,13 @@ impl Calendar {
             .h_9()
             .rounded_lg()
             .justify_center()
-            .cursor_pointer()
+            .when(!disabled, |this| this.cursor_pointer())
             .when(muted, |this| {
                 this.text_color(cx.theme().muted_foreground.opacity(0.3))
             })
+            .when(disabled, |this| {
+                this.text_color(cx.theme().muted_foreground.opacity(0.5))
+            })
             .when(secondary_active, |this| {
                 this.bg(if muted {
                     cx.theme().accent.opacity(0.5)
@@ -372,7 +652,7 @@ impl Calendar {
                 })
                 .text_color(cx.theme().accent_foreground)
             })
-            .when(!active, |this| {
+            .when(!active && !disabled, |this| {
                 this.hover(|this| {
                     this.bg(cx.theme().accent)
                         .text_color(cx.theme().accent_foreground)
@@ -396,7 +676,10 @@ impl Calendar {
         let day = d.day();
         let is_current_month = d.month() == month;
         let is_active = self.date.is_active(d) && is_current_month;
-        let is_in_range = self.date.is_in_range(d);
+        let is_in_range = self.date.is_in_range(d) || self.date.is_in_preview(d, self.hovered_date);
+        let is_disabled = self.is_disabled(d);
+        let is_keyboard_active =
+            is_current_month && self.active_date == Some(*d) && self.focus_handle.is_focused(cx);
 
         let date = *d;
 
@@ -406,33 +689,33 @@ impl Calendar {
             is_active,
             is_in_range,
             !is_current_month,
+            is_disabled,
             cx,
         )
-        .on_click(cx.listener(move |view, _: &ClickEvent, cx| {
-            if view.date.is_single() {
-                view.set_date(date, cx);
-                cx.emit(CalendarEvent::Selected(view.date()));
-            } else {
-                let start = view.date.start();
-                let end = view.date.end();
-
-                if start.is_none() && end.is_none() {
-                    view.set_date(Date::Range(Some(date), None), cx);
-                } else if start.is_some() && end.is_none() {
-                    if date < start.unwrap() {
-                        view.set_date(Date::Range(Some(date), None), cx);
-                    } else {
-                        view.set_date(Date::Range(Some(start.unwrap()), Some(date)), cx);
-                    }
+        .when(is_keyboard_active, |this| {
+            this.border_1().border_color(cx.theme().ring)
+        })
+        .when(!is_disabled, |this| {
+            this.on_click(cx.listener(move |view, _: &ClickEvent, cx| {
+                view.select_day(date, cx);
+            }))
+        })
+        .when(!self.date.is_single() && !is_disabled, |this| {
+            this.on_hover(cx.listener(move |view, hovered: &bool, cx| {
+                let hovered_date = if *hovered {
+                    Some(date)
+                } else if view.hovered_date == Some(date) {
+                    None
                 } else {
-                    view.set_date(Date::Range(Some(date), None), cx);
-                }
+                    return;
+                };
 
-                if view.date.is_complete() {
-                    cx.emit(CalendarEvent::Selected(view.date()));
+                if view.hovered_date != hovered_date {
+                    view.hovered_date = hovered_date;
+                    cx.notify();
                 }
-            }
-        }))
+            }))
+        })
     }
 
     fn set_view_mode(&mut self, mode: ViewMode, cx: &mut ViewContext<Self>) {
@@ -546,7 +829,7 @@ impl Calendar {
     }
 
     fn render_days(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        let weeks = [
+        let week_names = [
             t!("Calendar.week.0"),
             t!("Calendar.week.1"),
             t!("Calendar.week.2"),
@@ -555,10 +838,15 @@ impl Calendar {
             t!("Calendar.week.5"),
             t!("Calendar.week.6"),
         ];
+        // The week names from the first day of the week.
+        let week_start = self.week_start.num_days_from_sunday() as usize;
+        let weeks = (0..7)
+            .map(|ix| week_names[(week_start + ix) % 7].clone())
+            .collect::<Vec<_>>();
 
         h_flex().gap_4().justify_between().text_sm().children(
             self.days()
-                .chunks(5)
+                .into_iter()
                 .enumerate()
                 .map(|(offset_month, days)| {
                     v_flex()
@@ -595,7 +883,7 @@ impl Calendar {
                     .map(|(ix, month)| {
                         let active = (ix + 1) as u8 == self.current_month;
 
-                        self.item_button(ix, month.to_string(), active, false, false, cx)
+                        self.item_button(ix, month.to_string(), active, false, false, false, cx)
                             .w(relative(0.3))
                             .text_sm()
                             .on_click(cx.listener(move |view, _, cx| {
@@ -626,7 +914,7 @@ impl Calendar {
                         let year = *year;
                         let active = year == self.current_year;
 
-                        self.item_button(ix, year.to_string(), active, false, false, cx)
+                        self.item_button(ix, year.to_string(), active, false, false, false, cx)
                             .w(relative(0.2))
                             .on_click(cx.listener(move |view, _, cx| {
                                 view.current_year = year;
@@ -641,10 +929,28 @@ impl Calendar {
 
 impl EventEmitter<CalendarEvent> for Calendar {}
 
+impl FocusableView for Calendar {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
 impl Render for Calendar {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
         v_flex()
+            .key_context("Calendar")
             .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_action_prev_day))
+            .on_action(cx.listener(Self::on_action_next_day))
+            .on_action(cx.listener(Self::on_action_prev_week))
+            .on_action(cx.listener(Self::on_action_next_week))
+            .on_action(cx.listener(Self::on_action_prev_month))
+            .on_action(cx.listener(Self::on_action_next_month))
+            .on_action(cx.listener(Self::on_action_prev_year))
+            .on_action(cx.listener(Self::on_action_next_year))
+            .on_action(cx.listener(Self::on_action_week_start))
+            .on_action(cx.listener(Self::on_action_week_end))
+            .on_action(cx.listener(Self::on_action_confirm))
             .gap_0p5()
             .child(self.render_header(cx))
             .child(
@@ -664,9 +970,9 @@ impl Render for Calendar {
 
 #[cfg(test)]
 mod tests {
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, Weekday};
 
-    use super::Date;
+    use super::{Date, Movement};
 
     #[test]
     fn test_date_to_string() {
@@ -691,4 +997,43 @@ mod tests {
         let date = Date::Range(None, None);
         assert_eq!(date.to_string(), "nil");
     }
+
+    #[test]
+    fn test_movement() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+
+        assert_eq!(
+            Movement::Days(-1).apply(date(8, 1), Weekday::Sun),
+            date(7, 31)
+        );
+        assert_eq!(
+            Movement::Days(7).apply(date(8, 28), Weekday::Sun),
+            date(9, 4)
+        );
+        assert_eq!(
+            Movement::Months(1).apply(date(1, 31), Weekday::Sun),
+            date(2, 29)
+        );
+        assert_eq!(
+            Movement::Months(-12).apply(date(8, 1), Weekday::Sun),
+            NaiveDate::from_ymd_opt(2023, 8, 1).unwrap()
+        );
+        // 2024-08-07 is Wednesday.
+        assert_eq!(
+            Movement::WeekStart.apply(date(8, 7), Weekday::Sun),
+            date(8, 4)
+        );
+        assert_eq!(
+            Movement::WeekStart.apply(date(8, 7), Weekday::Mon),
+            date(8, 5)
+        );
+        assert_eq!(
+            Movement::WeekEnd.apply(date(8, 7), Weekday::Sun),
+            date(8, 10)
+        );
+        assert_eq!(
+            Movement::WeekEnd.apply(date(8, 7), Weekday::Mon),
+            date(8, 11)
+        );
+    }
 }
@@ -1,3 +1,6 @@
+use std::rc::Rc;
+
+use chrono::{Datelike as _, Days, Local, Months, NaiveDate, Weekday};
 use gpui::{
     anchored, deferred, div, prelude::FluentBuilder as _, px, AppContext, ElementId, EventEmitter,
     FocusHandle, FocusableView, InteractiveElement as _, KeyBinding, Length, MouseButton,
@@ -7,15 +10,109 @@ use gpui::{
 use rust_i18n::t;
 
 use crate::{
-    dropdown::Escape, h_flex, input::ClearButton, theme::ActiveTheme as _, Icon, IconName, Sizable,
-    Size, StyleSized as _, StyledExt as _,
+    button::{Button, ButtonStyled as _},
+    dropdown::{Down, Enter, Escape},
+    h_flex,
+    input::ClearButton,
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Selectable as _, Sizable, Size, StyleSized as _, StyledExt as _,
 };
 
-use super::calendar::{Calendar, CalendarEvent, Date};
+pub use super::calendar::{Calendar, CalendarEvent, Date};
 
 pub fn init(cx: &mut AppContext) {
     let context = Some("DatePicker");
-    cx.bind_keys([KeyBinding::new("escape", Escape, context)])
+    cx.bind_keys([
+        KeyBinding::new("escape", Escape, context),
+        KeyBinding::new("enter", Enter, context),
+        KeyBinding::new("down", Down, context),
+    ])
+}
+
+/// Return true if the range is valid, the end must not be before the start.
+fn is_valid_range(start: NaiveDate, end: NaiveDate) -> bool {
+    end >= start
+}
+
+/// Return the range of the last `days` days, including today.
+fn last_days_range(today: NaiveDate, days: u64) -> (NaiveDate, NaiveDate) {
+    let start = today
+        .checked_sub_days(Days::new(days.saturating_sub(1)))
+        .unwrap_or(today);
+    (start, today)
+}
+
+/// Return the range from the first day to the last day of the month of the date.
+fn month_range(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let start = date.with_day(1).unwrap_or(date);
+    let end = start
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(date);
+    (start, end)
+}
+
+/// A preset range of the range mode of the [`DatePicker`], the range is computed from today when it is used.
+#[derive(Clone)]
+pub struct DateRangePreset {
+    label: SharedString,
+    range: Rc<dyn Fn(NaiveDate) -> (NaiveDate, NaiveDate)>,
+}
+
+impl DateRangePreset {
+    /// Create a preset with a function to compute the range from today.
+    ///
+    /// ```ignore
+    /// DateRangePreset::new("Next 7 days", |today| (today, today + Days::new(6)))
+    /// ```
+    pub fn new(
+        label: impl Into<SharedString>,
+        range: impl Fn(NaiveDate) -> (NaiveDate, NaiveDate) + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            range: Rc::new(range),
+        }
+    }
+
+    /// The last `days` days, including today.
+    pub fn last_days(label: impl Into<SharedString>, days: u64) -> Self {
+        Self::new(label, move |today| last_days_range(today, days))
+    }
+
+    /// The current month, from the first day to the last day.
+    pub fn this_month(label: impl Into<SharedString>) -> Self {
+        Self::new(label, month_range)
+    }
+
+    /// The previous month, from the first day to the last day.
+    pub fn last_month(label: impl Into<SharedString>) -> Self {
+        Self::new(label, |today| {
+            let last_month = today.with_day(1).and_then(|date| date.pred_opt());
+            month_range(last_month.unwrap_or(today))
+        })
+    }
+
+    /// The default presets: Today, Last 7 days, Last 30 days, This month and Last month.
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::last_days(t!("DatePicker.today"), 1),
+            Self::last_days(t!("DatePicker.last_7_days"), 7),
+            Self::last_days(t!("DatePicker.last_30_days"), 30),
+            Self::this_month(t!("DatePicker.this_month")),
+            Self::last_month(t!("DatePicker.last_month")),
+        ]
+    }
+
+    /// Get the label of the preset.
+    pub fn label(&self) -> SharedString {
+        self.label.clone()
+    }
+
+    /// Compute the range of the preset from the date.
+    pub fn range(&self, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        (self.range)(today)
+    }
 }
 
 #[derive(Clone)]
@@ -35,6 +132,11 @@ pub struct DatePicker {
     date_format: SharedString,
     calendar: View<Calendar>,
     number_of_months: usize,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
+    disabled_matcher: Option<Rc<dyn Fn(&NaiveDate) -> bool>>,
+    week_start: Option<Weekday>,
+    presets: Vec<DateRangePreset>,
 }
 
 impl DatePicker {
@@ -81,7 +183,12 @@ impl DatePicker {
             date_format: "%Y/%m/%d".into(),
             cleanable: false,
             number_of_months: 1,
+            min_date: None,
+            max_date: None,
+            disabled_matcher: None,
+            week_start: None,
             placeholder: None,
+            presets: vec![],
         }
     }
 
@@ -115,17 +222,57 @@ impl DatePicker {
         self
     }
 
+    /// Set the earliest date can be selected.
+    pub fn min_date(mut self, date: NaiveDate) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    /// Set the latest date can be selected.
+    pub fn max_date(mut self, date: NaiveDate) -> Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Set a matcher to disable the dates in the calendar.
+    pub fn disabled_dates(mut self, matcher: impl Fn(&NaiveDate) -> bool + 'static) -> Self {
+        self.disabled_matcher = Some(Rc::new(matcher));
+        self
+    }
+
+    /// Set the first day of the week in the calendar, default is by the locale.
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = Some(week_start);
+        self
+    }
+
+    /// Set the presets to show on the left of the calendar in range mode, default is none.
+    ///
+    /// ```ignore
+    /// DatePicker::range_picker("range", cx).presets(DateRangePreset::defaults())
+    /// ```
+    pub fn presets(mut self, presets: impl IntoIterator<Item = DateRangePreset>) -> Self {
+        self.presets = presets.into_iter().collect();
+        self
+    }
+
     /// Get the date of the date picker.
     pub fn date(&self) -> Date {
         self.date
     }
 
-    /// Set the date of the date picker.
+    /// Set the date of the date picker, the range with the end before the start is ignored.
     pub fn set_date(&mut self, date: impl Into<Date>, cx: &mut ViewContext<Self>) {
         self.update_date(date.into(), false, cx);
     }
 
     fn update_date(&mut self, date: Date, emit: bool, cx: &mut ViewContext<Self>) {
+        if let Date::Range(Some(start), Some(end)) = date {
+            if !is_valid_range(start, end) {
+                return;
+            }
+        }
+
         self.date = date;
         self.calendar.update(cx, |view, cx| {
             view.set_date(date, cx);
@@ -138,6 +285,9 @@ impl DatePicker {
     }
 
     fn escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
+        // Reset the calendar to discard the incomplete range selection.
+        let date = self.date;
+        self.calendar.update(cx, |view, cx| view.set_date(date, cx));
         self.open = false;
         self.focus_handle.focus(cx);
         cx.notify();
@@ -158,6 +308,56 @@ impl DatePicker {
         self.open = !self.open;
         cx.notify();
     }
+
+    /// Open the calendar by the keyboard, and focus it to navigate the days.
+    fn open_calendar(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = true;
+        self.calendar.focus_handle(cx).focus(cx);
+        cx.notify();
+    }
+
+    fn on_action_enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
+        self.open_calendar(cx);
+    }
+
+    fn on_action_down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        self.open_calendar(cx);
+    }
+
+    fn select_preset(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(preset) = self.presets.get(ix) else {
+            return;
+        };
+
+        let (start, end) = preset.range(Local::now().naive_local().date());
+        self.update_date(Date::Range(Some(start), Some(end)), true, cx);
+        self.focus_handle.focus(cx);
+    }
+
+    fn render_presets(&self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
+        let today = Local::now().naive_local().date();
+
+        v_flex()
+            .flex_none()
+            .w(px(140.))
+            .gap_0p5()
+            .pr_3()
+            .border_r_1()
+            .border_color(cx.theme().border)
+            .children(self.presets.iter().enumerate().map(|(ix, preset)| {
+                let (start, end) = preset.range(today);
+                let selected = self.date == Date::Range(Some(start), Some(end));
+
+                Button::new(("preset", ix))
+                    .ghost()
+                    .small()
+                    .w_full()
+                    .justify_start()
+                    .label(preset.label())
+                    .selected(selected)
+                    .on_click(cx.listener(move |view, _, cx| view.select_preset(ix, cx)))
+            }))
+    }
 }
 
 impl EventEmitter<DatePickerEvent> for DatePicker {}
@@ -188,16 +388,28 @@ impl Render for DatePicker {
 
         self.calendar.update(cx, |view, cx| {
             view.set_number_of_months(self.number_of_months, cx);
+            view.set_min_date(self.min_date, cx);
+            view.set_max_date(self.max_date, cx);
+            view.set_disabled_dates(self.disabled_matcher.clone(), cx);
+            if let Some(week_start) = self.week_start {
+                view.set_week_start(week_start, cx);
+            }
         });
 
-        let popover_width =
-            285.0 * self.number_of_months as f32 + (self.number_of_months - 1) as f32 * 16.0;
+        let show_presets = matches!(self.date, Date::Range(..)) && !self.presets.is_empty();
+        let popover_width = 285.0 * self.number_of_months as f32
+            + (self.number_of_months - 1) as f32 * 16.0
+            + if show_presets { 152. } else { 0. };
 
         div()
             .id(self.id.clone())
             .key_context("DatePicker")
             .track_focus(&self.focus_handle)
             .when(self.open, |this| this.on_action(cx.listener(Self::escape)))
+            .when(!self.open, |this| {
+                this.on_action(cx.listener(Self::on_action_enter))
+                    .on_action(cx.listener(Self::on_action_down))
+            })
             .w_full()
             .relative()
             .map(|this| match self.width {
@@ -247,11 +459,13 @@ impl Render for DatePicker {
                 this.child(
                     deferred(
                         anchored().snap_to_window_with_margin(px(8.)).child(
-                            div()
+                            h_flex()
                                 .track_focus(&self.focus_handle)
                                 .occlude()
                                 .absolute()
                                 .mt_1p5()
+                                .items_start()
+                                .gap_3()
                                 .overflow_hidden()
                                 .rounded_lg()
                                 .p_3()
@@ -265,7 +479,8 @@ impl Render for DatePicker {
                                     MouseButton::Left,
                                     cx.listener(|view, _, cx| view.escape(&Escape, cx)),
                                 )
-                                .child(self.calendar.clone()),
+                                .when(show_presets, |this| this.child(self.render_presets(cx)))
+                                .child(div().flex_1().child(self.calendar.clone())),
                         ),
                     )
                     .with_priority(2),
@@ -273,3 +488,45 @@ impl Render for DatePicker {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{is_valid_range, last_days_range, month_range, DateRangePreset};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_is_valid_range() {
+        assert!(is_valid_range(date(2024, 8, 1), date(2024, 8, 1)));
+        assert!(is_valid_range(date(2024, 8, 1), date(2024, 8, 2)));
+        assert!(!is_valid_range(date(2024, 8, 2), date(2024, 8, 1)));
+    }
+
+    #[test]
+    fn test_preset_ranges() {
+        assert_eq!(
+            last_days_range(date(2024, 3, 3), 7),
+            (date(2024, 2, 26), date(2024, 3, 3))
+        );
+        assert_eq!(
+            last_days_range(date(2024, 3, 3), 1),
+            (date(2024, 3, 3), date(2024, 3, 3))
+        );
+        assert_eq!(
+            month_range(date(2024, 2, 15)),
+            (date(2024, 2, 1), date(2024, 2, 29))
+        );
+        assert_eq!(
+            month_range(date(2024, 12, 31)),
+            (date(2024, 12, 1), date(2024, 12, 31))
+        );
+        assert_eq!(
+            DateRangePreset::last_month("").range(date(2024, 1, 10)),
+            (date(2023, 12, 1), date(2023, 12, 31))
+        );
+    }
+}
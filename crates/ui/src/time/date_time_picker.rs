@@ -0,0 +1,373 @@
+use chrono::{DateTime, FixedOffset, NaiveDateTime, NaiveTime};
+use gpui::{
+    anchored, deferred, div, prelude::FluentBuilder as _, px, AppContext, ElementId, EventEmitter,
+    FocusHandle, FocusableView, InteractiveElement as _, KeyBinding, Length, MouseButton,
+    ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled as _, View,
+    ViewContext, VisualContext as _,
+};
+use rust_i18n::t;
+
+use crate::{
+    dropdown::{Down, Enter, Escape},
+    h_flex,
+    input::ClearButton,
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Sizable, Size, StyleSized as _, StyledExt as _,
+};
+
+use super::{
+    calendar::{Calendar, CalendarEvent, Date},
+    time_picker::{TimePicker, TimePickerEvent},
+    time_zone::{zoned_date_time, TimeZonePicker, TimeZonePickerEvent, Tz},
+};
+
+pub fn init(cx: &mut AppContext) {
+    let context = Some("DateTimePicker");
+    cx.bind_keys([
+        KeyBinding::new("escape", Escape, context),
+        KeyBinding::new("enter", Enter, context),
+        KeyBinding::new("down", Down, context),
+    ])
+}
+
+#[derive(Clone)]
+pub enum DateTimePickerEvent {
+    Change(Option<NaiveDateTime>),
+    /// Emitted after `Change` if the time zone is set, with the zone-aware date time.
+    ZonedChange(Option<DateTime<FixedOffset>>),
+}
+
+/// A picker to select the date in a calendar and the time in a [`TimePicker`].
+pub struct DateTimePicker {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    date_time: Option<NaiveDateTime>,
+    cleanable: bool,
+    placeholder: Option<SharedString>,
+    open: bool,
+    size: Size,
+    width: Length,
+    date_format: SharedString,
+    calendar: View<Calendar>,
+    time_picker: View<TimePicker>,
+    use_12_hour: bool,
+    minute_step: u32,
+    time_zone: Option<Tz>,
+    time_zone_picker: Option<View<TimeZonePicker>>,
+}
+
+impl DateTimePicker {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        let calendar = cx.new_view(Calendar::new);
+        let time_picker = cx.new_view(|cx| TimePicker::new("time", cx));
+
+        cx.subscribe(&calendar, |this, _, ev: &CalendarEvent, cx| {
+            if let CalendarEvent::Selected(Date::Single(Some(date))) = ev {
+                let time = this.time_picker.read(cx).time().unwrap_or(NaiveTime::MIN);
+                this.update_date_time(Some(date.and_time(time)), true, cx);
+            }
+        })
+        .detach();
+        cx.subscribe(&time_picker, |this, _, ev: &TimePickerEvent, cx| match ev {
+            TimePickerEvent::Change(time) => {
+                if let Some(date_time) = this.date_time {
+                    this.update_date_time(Some(date_time.date().and_time(*time)), true, cx);
+                }
+            }
+        })
+        .detach();
+
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            date_time: None,
+            cleanable: false,
+            placeholder: None,
+            open: false,
+            size: Size::default(),
+            width: Length::Auto,
+            date_format: "%Y/%m/%d %H:%M".into(),
+            calendar,
+            time_picker,
+            use_12_hour: false,
+            minute_step: 1,
+            time_zone: None,
+            time_zone_picker: None,
+        }
+    }
+
+    /// Set the date format of the date time picker to display in Input, default: "%Y/%m/%d %H:%M".
+    pub fn date_format(mut self, format: impl Into<SharedString>) -> Self {
+        self.date_format = format.into();
+        self
+    }
+
+    /// Set the placeholder of the date time picker, default: "Select date and time".
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set true to show the clear button when the input field is not empty.
+    pub fn cleanable(mut self) -> Self {
+        self.cleanable = true;
+        self
+    }
+
+    /// Set width of the date time picker input field, default is `Length::Auto`.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set true to use the 12-hour format in the time picker, default is false.
+    ///
+    /// The default date format is changed to "%Y/%m/%d %I:%M %p".
+    pub fn use_12_hour(mut self, use_12_hour: bool) -> Self {
+        self.use_12_hour = use_12_hour;
+        if use_12_hour {
+            self.date_format = "%Y/%m/%d %I:%M %p".into();
+        }
+        self
+    }
+
+    /// Set the minutes to spin the minute segment of the time picker, default is 1.
+    pub fn minute_step(mut self, minute_step: u32) -> Self {
+        self.minute_step = minute_step;
+        self
+    }
+
+    /// Set the time zone to emit the zone-aware date time by [`DateTimePickerEvent::ZonedChange`].
+    pub fn time_zone(mut self, tz: Tz) -> Self {
+        self.time_zone = Some(tz);
+        self
+    }
+
+    /// Show a time zone selector beside the input to change the time zone.
+    pub fn time_zone_selector(mut self, cx: &mut ViewContext<Self>) -> Self {
+        let time_zone = self.time_zone;
+        let picker = cx.new_view(|cx| {
+            let mut picker = TimeZonePicker::new("time-zone", cx);
+            picker.set_time_zone(time_zone, cx);
+            picker
+        });
+        cx.subscribe(&picker, |this, _, ev: &TimeZonePickerEvent, cx| match ev {
+            TimeZonePickerEvent::Change(tz) => this.set_time_zone(*tz, cx),
+        })
+        .detach();
+
+        self.time_zone_picker = Some(picker);
+        self
+    }
+
+    /// Set the time zone of the date time picker, None to disable the zone-aware date time.
+    pub fn set_time_zone(&mut self, tz: Option<Tz>, cx: &mut ViewContext<Self>) {
+        self.time_zone = tz;
+        if self.time_zone.is_some() {
+            cx.emit(DateTimePickerEvent::ZonedChange(self.zoned_date_time()));
+        }
+        cx.notify();
+    }
+
+    /// Get the date time of the date time picker.
+    pub fn date_time(&self) -> Option<NaiveDateTime> {
+        self.date_time
+    }
+
+    /// Get the zone-aware date time, None if the time zone is not set or the date time is empty.
+    pub fn zoned_date_time(&self) -> Option<DateTime<FixedOffset>> {
+        let tz = self.time_zone?;
+        let date_time = self.date_time?;
+        Some(zoned_date_time(date_time, &tz).fixed_offset())
+    }
+
+    /// Set the date time of the date time picker.
+    pub fn set_date_time(&mut self, date_time: Option<NaiveDateTime>, cx: &mut ViewContext<Self>) {
+        self.update_date_time(date_time, false, cx);
+    }
+
+    fn update_date_time(
+        &mut self,
+        date_time: Option<NaiveDateTime>,
+        emit: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.date_time = date_time;
+        self.calendar.update(cx, |view, cx| {
+            view.set_date(Date::Single(date_time.map(|dt| dt.date())), cx);
+        });
+        self.time_picker.update(cx, |view, cx| {
+            view.set_time(date_time.map(|dt| dt.time()), cx);
+        });
+        if emit {
+            cx.emit(DateTimePickerEvent::Change(date_time));
+            if self.time_zone.is_some() {
+                cx.emit(DateTimePickerEvent::ZonedChange(self.zoned_date_time()));
+            }
+        }
+        cx.notify();
+    }
+
+    fn escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
+        self.open = false;
+        self.focus_handle.focus(cx);
+        cx.notify();
+    }
+
+    fn clean(&mut self, _: &gpui::ClickEvent, cx: &mut ViewContext<Self>) {
+        self.update_date_time(None, true, cx);
+    }
+
+    fn toggle_calendar(&mut self, _: &gpui::ClickEvent, cx: &mut ViewContext<Self>) {
+        self.open = !self.open;
+        cx.notify();
+    }
+
+    /// Open the calendar by the keyboard, and focus it to navigate the days.
+    fn open_calendar(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = true;
+        self.calendar.focus_handle(cx).focus(cx);
+        cx.notify();
+    }
+
+    fn on_action_enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
+        self.open_calendar(cx);
+    }
+
+    fn on_action_down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        self.open_calendar(cx);
+    }
+}
+
+impl EventEmitter<DateTimePickerEvent> for DateTimePicker {}
+impl Sizable for DateTimePicker {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+impl FocusableView for DateTimePicker {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for DateTimePicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
+        let is_focused = self.focus_handle.is_focused(cx);
+        let show_clean = self.cleanable && self.date_time.is_some();
+        let placeholder = self
+            .placeholder
+            .clone()
+            .unwrap_or_else(|| t!("DateTimePicker.placeholder").into());
+        let display_title = self
+            .date_time
+            .map(|dt| SharedString::from(dt.format(&self.date_format).to_string()))
+            .unwrap_or(placeholder.clone());
+
+        self.time_picker.update(cx, |view, cx| {
+            view.set_use_12_hour(self.use_12_hour, cx);
+            view.set_minute_step(self.minute_step, cx);
+        });
+
+        h_flex()
+            .gap_2()
+            .map(|this| match self.width {
+                Length::Definite(l) => this.flex_none().w(l),
+                Length::Auto => this.w_full(),
+            })
+            .child(
+                div()
+                    .id(self.id.clone())
+                    .key_context("DateTimePicker")
+                    .track_focus(&self.focus_handle)
+                    .when(self.open, |this| this.on_action(cx.listener(Self::escape)))
+                    .when(!self.open, |this| {
+                        this.on_action(cx.listener(Self::on_action_enter))
+                            .on_action(cx.listener(Self::on_action_down))
+                    })
+                    .flex_1()
+                    .relative()
+                    .input_text_size(self.size)
+                    .child(
+                        div()
+                            .id("date-time-picker-input")
+                            .relative()
+                            .flex()
+                            .items_center()
+                            .justify_between()
+                            .bg(cx.theme().background)
+                            .border_1()
+                            .border_color(cx.theme().input)
+                            .rounded(px(cx.theme().radius))
+                            .when(cx.theme().shadow, |this| this.shadow_sm())
+                            .cursor_pointer()
+                            .overflow_hidden()
+                            .input_text_size(self.size)
+                            .when(is_focused, |this| this.outline(cx))
+                            .input_size(self.size)
+                            .when(!self.open, |this| {
+                                this.on_click(cx.listener(Self::toggle_calendar))
+                            })
+                            .child(
+                                h_flex()
+                                    .w_full()
+                                    .items_center()
+                                    .justify_between()
+                                    .gap_1()
+                                    .child(div().w_full().overflow_hidden().child(display_title))
+                                    .when(show_clean, |this| {
+                                        this.child(
+                                            ClearButton::new(cx).on_click(cx.listener(Self::clean)),
+                                        )
+                                    })
+                                    .when(!show_clean, |this| {
+                                        this.child(
+                                            Icon::new(IconName::Calendar)
+                                                .text_color(cx.theme().muted_foreground),
+                                        )
+                                    }),
+                            ),
+                    )
+                    .when(self.open, |this| {
+                        this.child(
+                            deferred(
+                                anchored().snap_to_window_with_margin(px(8.)).child(
+                                    v_flex()
+                                        .track_focus(&self.focus_handle)
+                                        .occlude()
+                                        .absolute()
+                                        .mt_1p5()
+                                        .gap_3()
+                                        .overflow_hidden()
+                                        .p_3()
+                                        .w(px(285.))
+                                        .border_1()
+                                        .border_color(cx.theme().border)
+                                        .shadow_lg()
+                                        .rounded_lg()
+                                        .bg(cx.theme().background)
+                                        .on_mouse_up_out(
+                                            MouseButton::Left,
+                                            cx.listener(|view, _, cx| view.escape(&Escape, cx)),
+                                        )
+                                        .child(self.calendar.clone())
+                                        .child(
+                                            h_flex()
+                                                .gap_3()
+                                                .justify_between()
+                                                .text_sm()
+                                                .child(t!("DateTimePicker.time").to_string())
+                                                .child(self.time_picker.clone()),
+                                        ),
+                                ),
+                            )
+                            .with_priority(2),
+                        )
+                    }),
+            )
+            .when_some(self.time_zone_picker.clone(), |this, picker| {
+                this.child(div().w(px(240.)).flex_none().child(picker))
+            })
+    }
+}
@@ -1,3 +1,6 @@
 pub mod calendar;
 pub mod date_picker;
+pub mod date_time_picker;
+pub mod time_picker;
+pub mod time_zone;
 mod utils;
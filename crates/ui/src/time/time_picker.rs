@@ -0,0 +1,292 @@
+use chrono::{NaiveTime, Timelike as _};
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, AppContext, ElementId, EventEmitter,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding, MouseButton,
+    ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled as _,
+    ViewContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    h_flex, theme::ActiveTheme as _, Icon, IconName, Sizable, Size, StyleSized as _, StyledExt as _,
+};
+
+actions!(time_picker, [SpinUp, SpinDown, PrevSegment, NextSegment]);
+
+pub fn init(cx: &mut AppContext) {
+    let context = Some("TimePicker");
+    cx.bind_keys([
+        KeyBinding::new("up", SpinUp, context),
+        KeyBinding::new("down", SpinDown, context),
+        KeyBinding::new("left", PrevSegment, context),
+        KeyBinding::new("right", NextSegment, context),
+    ])
+}
+
+/// The editable segment of the time picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment {
+    Hour,
+    Minute,
+    /// The AM/PM in 12-hour format.
+    Period,
+}
+
+impl Segment {
+    fn all(use_12_hour: bool) -> &'static [Segment] {
+        if use_12_hour {
+            &[Segment::Hour, Segment::Minute, Segment::Period]
+        } else {
+            &[Segment::Hour, Segment::Minute]
+        }
+    }
+
+    /// Spin the segment of the time by `delta` steps, the value wraps around in the segment.
+    ///
+    /// The minute is snapped to the `minute_step`, e.g.: spin up `07` by 15 minutes is `15`.
+    fn spin(self, time: NaiveTime, delta: i32, minute_step: u32) -> NaiveTime {
+        let hour = time.hour() as i32;
+        let minute = time.minute() as i32;
+
+        let (hour, minute) = match self {
+            Self::Hour => ((hour + delta).rem_euclid(24), minute),
+            Self::Minute => {
+                let step = minute_step.clamp(1, 60) as i32;
+                let count = 59 / step + 1;
+                let ix = if delta > 0 {
+                    minute / step + delta
+                } else {
+                    (minute + step - 1) / step + delta
+                };
+                (hour, ix.rem_euclid(count) * step)
+            }
+            Self::Period if delta % 2 != 0 => ((hour + 12) % 24, minute),
+            Self::Period => (hour, minute),
+        };
+
+        NaiveTime::from_hms_opt(hour as u32, minute as u32, 0).unwrap_or(time)
+    }
+
+    /// Format the segment of the time, e.g.: `09`, `30`, `PM`.
+    fn format(self, time: NaiveTime, use_12_hour: bool) -> SharedString {
+        let (is_pm, hour_12) = time.hour12();
+        match self {
+            Self::Hour if use_12_hour => format!("{:02}", hour_12).into(),
+            Self::Hour => format!("{:02}", time.hour()).into(),
+            Self::Minute => format!("{:02}", time.minute()).into(),
+            Self::Period if is_pm => t!("TimePicker.pm").into(),
+            Self::Period => t!("TimePicker.am").into(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum TimePickerEvent {
+    Change(NaiveTime),
+}
+
+/// A time input with the hour and minute segments, use `Up` and `Down` keys to spin the active segment.
+pub struct TimePicker {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    time: Option<NaiveTime>,
+    use_12_hour: bool,
+    minute_step: u32,
+    active_segment: usize,
+    size: Size,
+}
+
+impl TimePicker {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            time: None,
+            use_12_hour: false,
+            minute_step: 1,
+            active_segment: 0,
+            size: Size::default(),
+        }
+    }
+
+    /// Set true to use the 12-hour format with AM/PM, default is false.
+    pub fn use_12_hour(mut self, use_12_hour: bool) -> Self {
+        self.use_12_hour = use_12_hour;
+        self
+    }
+
+    /// Set the minutes to spin the minute segment, default is 1.
+    pub fn minute_step(mut self, minute_step: u32) -> Self {
+        self.minute_step = minute_step.clamp(1, 60);
+        self
+    }
+
+    pub fn set_use_12_hour(&mut self, use_12_hour: bool, cx: &mut ViewContext<Self>) {
+        self.use_12_hour = use_12_hour;
+        self.active_segment = self.active_segment.min(Segment::all(use_12_hour).len() - 1);
+        cx.notify();
+    }
+
+    pub fn set_minute_step(&mut self, minute_step: u32, cx: &mut ViewContext<Self>) {
+        self.minute_step = minute_step.clamp(1, 60);
+        cx.notify();
+    }
+
+    /// Get the time of the time picker.
+    pub fn time(&self) -> Option<NaiveTime> {
+        self.time
+    }
+
+    /// Set the time of the time picker.
+    pub fn set_time(&mut self, time: Option<NaiveTime>, cx: &mut ViewContext<Self>) {
+        self.time = time;
+        cx.notify();
+    }
+
+    fn spin(&mut self, delta: i32, cx: &mut ViewContext<Self>) {
+        let segment = Segment::all(self.use_12_hour)[self.active_segment];
+        // Start from the midnight at the first time.
+        let time = match self.time {
+            Some(time) => segment.spin(time, delta, self.minute_step),
+            None => NaiveTime::MIN,
+        };
+
+        self.time = Some(time);
+        cx.emit(TimePickerEvent::Change(time));
+        cx.notify();
+    }
+
+    fn on_action_spin_up(&mut self, _: &SpinUp, cx: &mut ViewContext<Self>) {
+        self.spin(1, cx);
+    }
+
+    fn on_action_spin_down(&mut self, _: &SpinDown, cx: &mut ViewContext<Self>) {
+        self.spin(-1, cx);
+    }
+
+    fn on_action_prev_segment(&mut self, _: &PrevSegment, cx: &mut ViewContext<Self>) {
+        self.active_segment = self.active_segment.saturating_sub(1);
+        cx.notify();
+    }
+
+    fn on_action_next_segment(&mut self, _: &NextSegment, cx: &mut ViewContext<Self>) {
+        let len = Segment::all(self.use_12_hour).len();
+        self.active_segment = (self.active_segment + 1).min(len - 1);
+        cx.notify();
+    }
+
+    fn render_segment(
+        &self,
+        ix: usize,
+        segment: Segment,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let is_active = self.active_segment == ix && self.focus_handle.is_focused(cx);
+        let label = match self.time {
+            Some(time) => segment.format(time, self.use_12_hour),
+            None => "--".into(),
+        };
+
+        div()
+            .id(ix)
+            .px_0p5()
+            .rounded(px(2.))
+            .when(self.time.is_none(), |this| {
+                this.text_color(cx.theme().muted_foreground)
+            })
+            .when(is_active, |this| {
+                this.bg(cx.theme().accent)
+                    .text_color(cx.theme().accent_foreground)
+            })
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |view, _, cx| {
+                    view.active_segment = ix;
+                    view.focus_handle.focus(cx);
+                    cx.notify();
+                }),
+            )
+            .child(label)
+    }
+}
+
+impl EventEmitter<TimePickerEvent> for TimePicker {}
+impl Sizable for TimePicker {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+impl FocusableView for TimePicker {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TimePicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let is_focused = self.focus_handle.is_focused(cx);
+        let segments = Segment::all(self.use_12_hour);
+
+        h_flex()
+            .id(self.id.clone())
+            .key_context("TimePicker")
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_action_spin_up))
+            .on_action(cx.listener(Self::on_action_spin_down))
+            .on_action(cx.listener(Self::on_action_prev_segment))
+            .on_action(cx.listener(Self::on_action_next_segment))
+            .gap_1()
+            .bg(cx.theme().background)
+            .border_1()
+            .border_color(cx.theme().input)
+            .rounded(px(cx.theme().radius))
+            .when(cx.theme().shadow, |this| this.shadow_sm())
+            .when(is_focused, |this| this.outline(cx))
+            .input_size(self.size)
+            .input_text_size(self.size)
+            .child(Icon::new(IconName::Clock).text_color(cx.theme().muted_foreground))
+            .child(
+                h_flex().children(segments.iter().enumerate().map(|(ix, segment)| {
+                    h_flex()
+                        .when(*segment == Segment::Minute, |this| this.child(":"))
+                        .when(*segment == Segment::Period, |this| this.ml_1())
+                        .child(self.render_segment(ix, *segment, cx))
+                })),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+
+    use super::Segment;
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_spin() {
+        assert_eq!(Segment::Hour.spin(time(23, 10), 1, 1), time(0, 10));
+        assert_eq!(Segment::Hour.spin(time(0, 10), -1, 1), time(23, 10));
+        assert_eq!(Segment::Minute.spin(time(9, 59), 1, 1), time(9, 0));
+        assert_eq!(Segment::Minute.spin(time(9, 0), -1, 1), time(9, 59));
+        assert_eq!(Segment::Minute.spin(time(9, 7), 1, 15), time(9, 15));
+        assert_eq!(Segment::Minute.spin(time(9, 7), -1, 15), time(9, 0));
+        assert_eq!(Segment::Minute.spin(time(9, 45), 1, 15), time(9, 0));
+        assert_eq!(Segment::Minute.spin(time(9, 0), -1, 15), time(9, 45));
+        assert_eq!(Segment::Minute.spin(time(9, 56), 1, 7), time(9, 0));
+        assert_eq!(Segment::Period.spin(time(9, 30), 1, 1), time(21, 30));
+        assert_eq!(Segment::Period.spin(time(21, 30), -1, 1), time(9, 30));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Segment::Hour.format(time(0, 5), false), "00");
+        assert_eq!(Segment::Hour.format(time(0, 5), true), "12");
+        assert_eq!(Segment::Hour.format(time(13, 5), true), "01");
+        assert_eq!(Segment::Minute.format(time(13, 5), true), "05");
+    }
+}
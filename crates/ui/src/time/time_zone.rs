@@ -0,0 +1,182 @@
+use chrono::{DateTime, Duration, LocalResult, NaiveDateTime, Offset as _, TimeZone, Utc};
+use gpui::{
+    AppContext, ElementId, EventEmitter, FocusHandle, FocusableView, IntoElement, Render,
+    SharedString, View, ViewContext, VisualContext as _,
+};
+
+use rust_i18n::t;
+
+pub use chrono_tz::Tz;
+
+use crate::{
+    dropdown::{Dropdown, DropdownEvent, DropdownItem, SearchableVec},
+    IconName,
+};
+
+/// A IANA time zone item with the current UTC offset, e.g.: `(UTC+08:00) Asia/Shanghai`.
+#[derive(Clone)]
+pub struct TimeZoneItem {
+    tz: Tz,
+    title: SharedString,
+    offset_seconds: i32,
+}
+
+impl TimeZoneItem {
+    pub fn new(tz: Tz) -> Self {
+        let offset_seconds = utc_offset_seconds(&tz, Utc::now().naive_utc());
+        let title = format!("({}) {}", format_utc_offset(offset_seconds), tz.name()).into();
+
+        Self {
+            tz,
+            title,
+            offset_seconds,
+        }
+    }
+
+    pub fn time_zone(&self) -> Tz {
+        self.tz
+    }
+}
+
+impl DropdownItem for TimeZoneItem {
+    type Value = Tz;
+
+    fn title(&self) -> SharedString {
+        self.title.clone()
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.tz
+    }
+}
+
+/// Returns all the IANA time zones, sorted by the current UTC offset and name.
+pub fn time_zone_items() -> Vec<TimeZoneItem> {
+    let mut items = chrono_tz::TZ_VARIANTS
+        .iter()
+        .map(|tz| TimeZoneItem::new(*tz))
+        .collect::<Vec<_>>();
+    items.sort_by(|a, b| {
+        a.offset_seconds
+            .cmp(&b.offset_seconds)
+            .then_with(|| a.tz.name().cmp(b.tz.name()))
+    });
+    items
+}
+
+/// Convert the local date time in the time zone to a zone-aware timestamp.
+///
+/// - If the local time is ambiguous (DST ends, the clock is turned back), use the earliest one.
+/// - If the local time is in a DST gap (DST starts, the clock is turned forward),
+///   shift it forward by the length of the gap, e.g.: `02:30` in `America/New_York` on the DST start day is `03:30`.
+pub fn zoned_date_time<T: TimeZone>(local: NaiveDateTime, tz: &T) -> DateTime<T> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => {
+            // Use the offset before the gap, the transitions are at least one day apart.
+            let offset = utc_offset_seconds(tz, local - Duration::days(1));
+            tz.from_utc_datetime(&(local - Duration::seconds(offset as i64)))
+        }
+    }
+}
+
+/// Returns the UTC offset in seconds of the time zone at the UTC time.
+fn utc_offset_seconds<T: TimeZone>(tz: &T, utc: NaiveDateTime) -> i32 {
+    tz.offset_from_utc_datetime(&utc).fix().local_minus_utc()
+}
+
+/// Format the UTC offset in seconds, e.g.: `UTC+08:00`, `UTC-03:30`.
+fn format_utc_offset(offset_seconds: i32) -> String {
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let minutes = offset_seconds.abs() / 60;
+    format!("UTC{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+}
+
+pub enum TimeZonePickerEvent {
+    Change(Option<Tz>),
+}
+
+/// A searchable dropdown to pick the IANA time zone.
+pub struct TimeZonePicker {
+    dropdown: View<Dropdown<SearchableVec<TimeZoneItem>>>,
+}
+
+impl TimeZonePicker {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        let id = id.into();
+        let dropdown = cx.new_view(|cx| {
+            Dropdown::new(id, SearchableVec::new(time_zone_items()), None, cx)
+                .icon(IconName::Globe)
+                .placeholder(t!("TimeZonePicker.placeholder"))
+        });
+        cx.subscribe(&dropdown, |_, _, event, cx| match event {
+            DropdownEvent::Confirm(tz) => cx.emit(TimeZonePickerEvent::Change(*tz)),
+        })
+        .detach();
+
+        Self { dropdown }
+    }
+
+    /// Returns the selected time zone.
+    pub fn time_zone(&self, cx: &AppContext) -> Option<Tz> {
+        self.dropdown.read(cx).selected_value().copied()
+    }
+
+    /// Set the selected time zone.
+    pub fn set_time_zone(&mut self, tz: Option<Tz>, cx: &mut ViewContext<Self>) {
+        self.dropdown.update(cx, |dropdown, cx| match tz {
+            Some(tz) => dropdown.set_selected_value(&tz, cx),
+            None => dropdown.set_selected_index(None, cx),
+        });
+        cx.notify();
+    }
+}
+
+impl EventEmitter<TimeZonePickerEvent> for TimeZonePicker {}
+
+impl FocusableView for TimeZonePicker {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.dropdown.focus_handle(cx)
+    }
+}
+
+impl Render for TimeZonePicker {
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        self.dropdown.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Offset as _};
+    use chrono_tz::America::New_York;
+
+    use super::{format_utc_offset, zoned_date_time};
+
+    #[test]
+    fn test_zoned_date_time() {
+        let date = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let dt = zoned_date_time(date.and_hms_opt(9, 0, 0).unwrap(), &New_York);
+        assert_eq!(dt.to_rfc3339(), "2024-07-01T09:00:00-04:00");
+
+        // DST gap, 02:00 ~ 03:00 is not exist.
+        let date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let dt = zoned_date_time(date.and_hms_opt(2, 30, 0).unwrap(), &New_York);
+        assert_eq!(dt.to_rfc3339(), "2024-03-10T03:30:00-04:00");
+
+        // DST end, 01:00 ~ 02:00 is ambiguous.
+        let date = NaiveDate::from_ymd_opt(2024, 11, 3).unwrap();
+        let dt = zoned_date_time(date.and_hms_opt(1, 30, 0).unwrap(), &New_York);
+        assert_eq!(dt.to_rfc3339(), "2024-11-03T01:30:00-04:00");
+        assert_eq!(dt.offset().fix().local_minus_utc(), -4 * 3600);
+    }
+
+    #[test]
+    fn test_format_utc_offset() {
+        assert_eq!(format_utc_offset(0), "UTC+00:00");
+        assert_eq!(format_utc_offset(8 * 3600), "UTC+08:00");
+        assert_eq!(format_utc_offset(-(3 * 3600 + 1800)), "UTC-03:30");
+        assert_eq!(format_utc_offset(5 * 3600 + 45 * 60), "UTC+05:45");
+    }
+}
@@ -1,4 +1,5 @@
-use chrono::{Datelike, Duration, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use rust_i18n::t;
 
 trait NaiveDateExt {
     fn days_in_month(&self) -> i32;
@@ -28,7 +29,24 @@ impl NaiveDateExt for chrono::NaiveDate {
     }
 }
 
-pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
+/// Return the number of days from the `week_start` to the `weekday`, e.g.: Wednesday is 2 days from Monday.
+pub(crate) fn days_from_week_start(weekday: Weekday, week_start: Weekday) -> u32 {
+    (weekday.num_days_from_sunday() + 7 - week_start.num_days_from_sunday()) % 7
+}
+
+/// Return the first day of the week by the current locale, e.g.: Monday in China.
+pub(crate) fn locale_week_start() -> Weekday {
+    match t!("Calendar.week_start").as_ref() {
+        "monday" => Weekday::Mon,
+        "saturday" => Weekday::Sat,
+        _ => Weekday::Sun,
+    }
+}
+
+/// Returns the weeks of the month to render on calendar, each week starts from the `week_start`.
+///
+/// The days of the previous and next months are included to fill the first and last weeks.
+pub(crate) fn days_in_month(year: i32, month: u32, week_start: Weekday) -> Vec<Vec<NaiveDate>> {
     let mut year = year;
     let mut month = month;
     if month > 12 {
@@ -41,55 +59,31 @@ pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
     }
 
     let date = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
-    let num_days = date.days_in_month();
-    let start_weekday = date.weekday().num_days_from_sunday();
+    let num_days = date.days_in_month() as u32;
+    let offset = days_from_week_start(date.weekday(), week_start);
+    let start = date - Duration::days(offset as i64);
 
-    // Get the days in the month, 2023-02 will returns
+    // Get the days in the month, 2023-02 starts from Sunday will returns
     // "29|30|31| 1| 2| 3| 4",
     // " 5| 6| 7| 8| 9|10|11",
     // "12|13|14|15|16|17|18",
     // "19|20|21|22|23|24|25",
     // "26|27|28| 1| 2| 3| 4",
-    let mut days = vec![];
-    for n in 0..5 {
-        let mut week_days = vec![];
-        for weekday in 0..7 {
-            let (mut y, mut m) = (year, month);
-
-            // If the day is less than the start weekday, we need to go back to the previous month.
-            if n == 0 && weekday < start_weekday {
-                m = if m == 1 { 12 } else { m - 1 };
-                y = if m == 1 { year - 1 } else { y };
-            }
-
-            // If start_weekday is 3, and n is 0 and weekday is 3, then day is 1.
-            // If start_weekday is 3, and n is 1 and weekday is 4, then day is 9.
-            let day = n * 7 + weekday as i32 - start_weekday as i32;
-
-            // If the day is greater than the number of days in the month, we need to go to the next month.
-            if day > num_days {
-                m = if m == 12 { 1 } else { m + 1 };
-                y = if m == 1 { year + 1 } else { y };
-            }
-
-            #[allow(clippy::expect_fun_call)]
-            let date = date
-                .checked_add_signed(Duration::days(day as i64))
-                .expect(&format!("invalid date {}-{} days {}", y, m, day));
-            week_days.push(date);
-        }
-
-        days.push(week_days);
-    }
-
-    days
+    let weeks = (offset + num_days).div_ceil(7);
+    (0..weeks)
+        .map(|week| {
+            (0..7)
+                .map(|day| start + Duration::days((week * 7 + day) as i64))
+                .collect()
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::{Datelike, NaiveDate};
+    use chrono::{Datelike, NaiveDate, Weekday};
 
-    use super::{days_in_month, NaiveDateExt};
+    use super::{days_from_week_start, days_in_month, NaiveDateExt};
 
     #[test]
     fn test_days_in_month() {
@@ -115,7 +109,12 @@ mod tests {
     fn test_days() {
         #[track_caller]
         fn assert_case(date: NaiveDate, expected: Vec<&str>) {
-            let out = days_in_month(date.year(), date.month())
+            assert_case_with_week_start(date, Weekday::Sun, expected)
+        }
+
+        #[track_caller]
+        fn assert_case_with_week_start(date: NaiveDate, week_start: Weekday, expected: Vec<&str>) {
+            let out = days_in_month(date.year(), date.month(), week_start)
                 .iter()
                 .map(|week| {
                     week.iter()
@@ -177,5 +176,37 @@ mod tests {
                 "26|27|28|3-1|3-2|3-3|3-4",
             ],
         );
+        // The month needs 6 weeks.
+        assert_case(
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            vec![
+                "2-25|2-26|2-27|2-28|2-29| 1| 2",
+                " 3| 4| 5| 6| 7| 8| 9",
+                "10|11|12|13|14|15|16",
+                "17|18|19|20|21|22|23",
+                "24|25|26|27|28|29|30",
+                "31|4-1|4-2|4-3|4-4|4-5|4-6",
+            ],
+        );
+        // The week starts from Monday.
+        assert_case_with_week_start(
+            NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+            Weekday::Mon,
+            vec![
+                "7-29|7-30|7-31| 1| 2| 3| 4",
+                " 5| 6| 7| 8| 9|10|11",
+                "12|13|14|15|16|17|18",
+                "19|20|21|22|23|24|25",
+                "26|27|28|29|30|31|9-1",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_days_from_week_start() {
+        assert_eq!(days_from_week_start(Weekday::Sun, Weekday::Sun), 0);
+        assert_eq!(days_from_week_start(Weekday::Wed, Weekday::Mon), 2);
+        assert_eq!(days_from_week_start(Weekday::Sun, Weekday::Mon), 6);
+        assert_eq!(days_from_week_start(Weekday::Fri, Weekday::Sat), 6);
     }
 }
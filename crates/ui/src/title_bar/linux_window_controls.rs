@@ -1,5 +1,5 @@
+use crate::{h_flex, prelude::*, Icon, IconName};
 use gpui::{prelude::*, Action, Rgba, WindowAppearance};
-use ui::{h_flex, prelude::*, Icon, IconName};
 
 #[derive(IntoElement)]
 pub struct LinuxWindowControls {
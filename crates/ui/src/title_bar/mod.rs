@@ -3,4 +3,5 @@ mod platform;
 mod title_bar;
 mod windows_window_controls;
 
+pub use platform::PlatformStyle;
 pub use title_bar::*;
@@ -1,18 +1,37 @@
 use gpui::{Action, AnyElement, Interactivity, Stateful};
 use smallvec::SmallVec;
 
-use ui::{h_flex, prelude::*, theme::ActiveTheme};
+use crate::{h_flex, prelude::*, theme::ActiveTheme};
 
 use super::{
     linux_window_controls::LinuxWindowControls, platform::PlatformStyle,
     windows_window_controls::WindowsWindowControls,
 };
 
+/// A custom title bar of the window, works with the transparent titlebar of [`gpui::TitlebarOptions`].
+///
+/// It is draggable to move the window, double-click to maximize (zoom) the window,
+/// and renders the window controls by the platform:
+///
+/// - macOS: leaves the space for the inset traffic lights on the left.
+/// - Windows and Linux: minimize, maximize/restore and close buttons on the right.
+///
+/// The children are laid out in a row with space between, e.g.: the menus on the left
+/// and the actions on the right, and the `center` slot is kept at the center of the window,
+/// e.g.: the title or tabs.
+///
+/// ```ignore
+/// TitleBar::new("title-bar", Box::new(CloseWindow))
+///     .child(menu_bar)
+///     .center("My App")
+///     .child(h_flex().gap_2().child(theme_toggle))
+/// ```
 #[derive(IntoElement)]
 pub struct TitleBar {
     platform_style: PlatformStyle,
     content: Stateful<Div>,
     children: SmallVec<[AnyElement; 2]>,
+    center: Option<AnyElement>,
     close_window_action: Box<dyn Action>,
 }
 
@@ -50,11 +69,13 @@ impl TitleBar {
         }
     }
 
+    /// Create a title bar, the `close_window_action` is dispatched by the close button on Linux.
     pub fn new(id: impl Into<ElementId>, close_window_action: Box<dyn Action>) -> Self {
         Self {
             platform_style: PlatformStyle::platform(),
             content: div().id(id.into()),
             children: SmallVec::new(),
+            center: None,
             close_window_action,
         }
     }
@@ -64,6 +85,12 @@ impl TitleBar {
         self.platform_style = style;
         self
     }
+
+    /// Set the element at the center of the title bar, e.g.: the title or tabs.
+    pub fn center(mut self, element: impl IntoElement) -> Self {
+        self.center = Some(element.into_any_element());
+        self
+    }
 }
 
 impl InteractiveElement for TitleBar {
@@ -87,6 +114,7 @@ impl RenderOnce for TitleBar {
 
         h_flex()
             .id("titlebar")
+            .relative()
             .w_full()
             .pt(Self::top_padding(cx))
             .h(height + Self::top_padding(cx))
@@ -109,6 +137,14 @@ impl RenderOnce for TitleBar {
             .border_color(theme.border)
             .bg(theme.title_bar_background)
             .content_stretch()
+            // The double-click is handled by the system caption on Windows.
+            .when(self.platform_style != PlatformStyle::Windows, |this| {
+                this.on_click(|event, cx| {
+                    if event.up.click_count == 2 {
+                        cx.zoom_window();
+                    }
+                })
+            })
             .child(
                 self.content
                     .id("titlebar-content")
@@ -118,6 +154,17 @@ impl RenderOnce for TitleBar {
                     .w_full()
                     .children(self.children),
             )
+            .when_some(self.center, |this, center| {
+                this.child(
+                    h_flex()
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .size_full()
+                        .justify_center()
+                        .child(center),
+                )
+            })
             .when(
                 self.platform_style == PlatformStyle::Windows && !cx.is_fullscreen(),
                 |title_bar| title_bar.child(WindowsWindowControls::new(height)),
@@ -1,6 +1,6 @@
 use gpui::{hsla, prelude::*, Hsla};
 
-use ui::{h_flex, prelude::*, theme::ActiveTheme};
+use crate::{h_flex, prelude::*, theme::ActiveTheme};
 
 #[derive(IntoElement)]
 pub struct WindowsWindowControls {
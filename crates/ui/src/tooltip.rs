@@ -1,17 +1,36 @@
 use gpui::{
-    div, px, AnyView, IntoElement, ParentElement, Render, SharedString, Styled, ViewContext,
-    VisualContext, WindowContext,
+    div, prelude::FluentBuilder as _, px, Action, AnyView, IntoElement, ParentElement, Render,
+    SharedString, Styled, ViewContext, VisualContext, WindowContext,
 };
 
-use crate::theme::ActiveTheme;
+use crate::{h_flex, kbd::Kbd, theme::ActiveTheme};
 
 pub struct Tooltip {
     text: SharedString,
+    key_binding: Option<Kbd>,
 }
 
 impl Tooltip {
     pub fn new(text: impl Into<SharedString>, cx: &mut WindowContext) -> AnyView {
-        cx.new_view(|_| Self { text: text.into() }).into()
+        cx.new_view(|_| Self {
+            text: text.into(),
+            key_binding: None,
+        })
+        .into()
+    }
+
+    /// Create a Tooltip with the key binding of the action displayed after the text.
+    pub fn for_action(
+        text: impl Into<SharedString>,
+        action: &dyn Action,
+        cx: &mut WindowContext,
+    ) -> AnyView {
+        let key_binding = Kbd::binding_for_action(action, cx);
+        cx.new_view(|_| Self {
+            text: text.into(),
+            key_binding,
+        })
+        .into()
     }
 }
 
@@ -32,7 +51,12 @@ impl Render for Tooltip {
                 .py_0p5()
                 .px_2()
                 .text_sm()
-                .child(self.text.clone()),
+                .child(
+                    h_flex()
+                        .gap_3()
+                        .child(self.text.clone())
+                        .when_some(self.key_binding.clone(), |this, kbd| this.child(kbd)),
+                ),
         )
     }
 }
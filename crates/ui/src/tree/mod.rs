@@ -0,0 +1,5 @@
+mod node;
+mod tree;
+
+pub use node::*;
+pub use tree::*;
@@ -0,0 +1,558 @@
+use std::collections::{HashMap, HashSet};
+
+use gpui::SharedString;
+
+use crate::{fuzzy::fuzzy_match, IconName};
+
+/// A node of the [`crate::tree::TreeView`].
+///
+/// ```ignore
+/// TreeNode::new("src", "src")
+///     .icon(IconName::Folder)
+///     .expanded_icon(IconName::FolderOpen)
+///     .children([
+///         TreeNode::new("src/lib.rs", "lib.rs").icon(IconName::File),
+///     ])
+/// ```
+#[derive(Clone)]
+pub struct TreeNode {
+    /// The unique id of the node in the tree, e.g.: the path of the file.
+    pub id: SharedString,
+    pub label: SharedString,
+    pub icon: Option<IconName>,
+    /// The icon to use when the node is expanded, default is the `icon`.
+    pub expanded_icon: Option<IconName>,
+    pub children: Vec<TreeNode>,
+    /// The children are not loaded yet, they are loaded by [`crate::tree::TreeDelegate::load_children`]
+    /// when the node is expanded.
+    pub lazy: bool,
+}
+
+impl TreeNode {
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            expanded_icon: None,
+            children: Vec::new(),
+            lazy: false,
+        }
+    }
+
+    /// Set the icon of the node.
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Set the icon of the node when it is expanded.
+    pub fn expanded_icon(mut self, icon: IconName) -> Self {
+        self.expanded_icon = Some(icon);
+        self
+    }
+
+    /// Set the child nodes.
+    pub fn children(mut self, children: impl IntoIterator<Item = TreeNode>) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Set the children to be loaded lazily when the node is expanded, e.g.: a folder in a remote file system.
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    /// Return true if the node can be expanded.
+    pub fn has_children(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+}
+
+/// The position to drop the dragging node relative to the target node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropPosition {
+    /// Insert before the target node as a sibling.
+    Before,
+    /// Insert after the target node as a sibling.
+    After,
+    /// Append to the children of the target node.
+    Inside,
+}
+
+/// A visible node in the flattened tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TreeEntry {
+    pub(crate) id: SharedString,
+    /// The child indices from the root nodes to the node.
+    pub(crate) path: Vec<usize>,
+    /// The loading or error row in place of the children of the expanded lazy node,
+    /// the `id` is the id of the lazy node.
+    pub(crate) placeholder: bool,
+}
+
+impl TreeEntry {
+    pub(crate) fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    pub(crate) fn parent_path(&self) -> &[usize] {
+        &self.path[..self.path.len() - 1]
+    }
+}
+
+/// Push the entries of the visible nodes and the descendants of the expanded nodes,
+/// the `path` is the path of the parent of the nodes.
+fn walk(
+    nodes: &[TreeNode],
+    expanded: &HashSet<SharedString>,
+    visible: Option<&HashSet<SharedString>>,
+    path: &mut Vec<usize>,
+    entries: &mut Vec<TreeEntry>,
+) {
+    for (ix, node) in nodes.iter().enumerate() {
+        if visible.map_or(false, |visible| !visible.contains(&node.id)) {
+            continue;
+        }
+
+        path.push(ix);
+        entries.push(TreeEntry {
+            id: node.id.clone(),
+            path: path.clone(),
+            placeholder: false,
+        });
+        if expanded.contains(&node.id) {
+            walk_children(node, expanded, visible, path, entries);
+        }
+        path.pop();
+    }
+}
+
+/// Push the entries of the children of the expanded node at the `path`,
+/// or the placeholder if the children of the lazy node are not loaded.
+fn walk_children(
+    node: &TreeNode,
+    expanded: &HashSet<SharedString>,
+    visible: Option<&HashSet<SharedString>>,
+    path: &mut Vec<usize>,
+    entries: &mut Vec<TreeEntry>,
+) {
+    if node.lazy {
+        path.push(0);
+        entries.push(TreeEntry {
+            id: node.id.clone(),
+            path: path.clone(),
+            placeholder: true,
+        });
+        path.pop();
+    } else {
+        walk(&node.children, expanded, visible, path, entries);
+    }
+}
+
+/// Flatten the visible nodes in order, the children of the collapsed nodes are hidden.
+///
+/// If `visible` is given, the nodes not in it are hidden, see [`filter_nodes`].
+pub(crate) fn flatten(
+    nodes: &[TreeNode],
+    expanded: &HashSet<SharedString>,
+    visible: Option<&HashSet<SharedString>>,
+) -> Vec<TreeEntry> {
+    let mut entries = Vec::new();
+    walk(nodes, expanded, visible, &mut Vec::new(), &mut entries);
+    entries
+}
+
+/// Insert the entries of the descendants after the entry at `ix` when it is expanded,
+/// instead of flattening the whole tree again.
+///
+/// The entry must have no descendant entries, e.g.: it was collapsed.
+pub(crate) fn expand_entry(
+    entries: &mut Vec<TreeEntry>,
+    ix: usize,
+    nodes: &[TreeNode],
+    expanded: &HashSet<SharedString>,
+    visible: Option<&HashSet<SharedString>>,
+) {
+    let Some(entry) = entries.get(ix) else {
+        return;
+    };
+    let Some(node) = node_at(nodes, &entry.path) else {
+        return;
+    };
+
+    let mut path = entry.path.clone();
+    let mut children = Vec::new();
+    walk_children(node, expanded, visible, &mut path, &mut children);
+    entries.splice(ix + 1..ix + 1, children);
+}
+
+/// Remove the entries of the descendants after the entry at `ix` when it is collapsed.
+pub(crate) fn collapse_entry(entries: &mut Vec<TreeEntry>, ix: usize) {
+    let Some(depth) = entries.get(ix).map(|entry| entry.depth()) else {
+        return;
+    };
+
+    let end = entries[ix + 1..]
+        .iter()
+        .position(|entry| entry.depth() <= depth)
+        .map_or(entries.len(), |len| ix + 1 + len);
+    entries.drain(ix + 1..end);
+}
+
+/// The nodes matched the filter query.
+#[derive(Debug, Default)]
+pub(crate) struct FilterResult {
+    /// The byte offsets of the matched characters in the labels of the matched nodes.
+    pub(crate) matches: HashMap<SharedString, Vec<usize>>,
+    /// The ancestors of the matched nodes, they are expanded to reveal the matches.
+    pub(crate) ancestors: HashSet<SharedString>,
+    /// The matched nodes with their ancestors and descendants.
+    pub(crate) visible: HashSet<SharedString>,
+}
+
+/// Fuzzy match the labels of all nodes by the query.
+pub(crate) fn filter_nodes(nodes: &[TreeNode], query: &str) -> FilterResult {
+    /// Return true if any node in the nodes or their descendants is matched.
+    fn walk(nodes: &[TreeNode], query: &str, in_match: bool, result: &mut FilterResult) -> bool {
+        let mut any_matched = false;
+        for node in nodes {
+            let positions = fuzzy_match(&node.label, query).map(|(_, positions)| positions);
+            let matched = positions.is_some();
+            if let Some(positions) = positions {
+                result.matches.insert(node.id.clone(), positions);
+            }
+
+            let child_matched = walk(&node.children, query, in_match || matched, result);
+            if child_matched {
+                result.ancestors.insert(node.id.clone());
+            }
+            if in_match || matched || child_matched {
+                result.visible.insert(node.id.clone());
+            }
+            any_matched |= matched || child_matched;
+        }
+        any_matched
+    }
+
+    let mut result = FilterResult::default();
+    walk(nodes, query, false, &mut result);
+    result
+}
+
+/// Return the node at the path of child indices.
+pub(crate) fn node_at<'a>(nodes: &'a [TreeNode], path: &[usize]) -> Option<&'a TreeNode> {
+    let (first, rest) = path.split_first()?;
+    rest.iter()
+        .try_fold(nodes.get(*first)?, |node, ix| node.children.get(*ix))
+}
+
+/// Find the node by the id in the whole tree, including the hidden nodes.
+pub(crate) fn find_node<'a>(nodes: &'a [TreeNode], id: &SharedString) -> Option<&'a TreeNode> {
+    nodes.iter().find_map(|node| {
+        if &node.id == id {
+            Some(node)
+        } else {
+            find_node(&node.children, id)
+        }
+    })
+}
+
+/// Find the node by the id in the whole tree, including the hidden nodes.
+pub(crate) fn find_node_mut<'a>(
+    nodes: &'a mut [TreeNode],
+    id: &SharedString,
+) -> Option<&'a mut TreeNode> {
+    for node in nodes.iter_mut() {
+        if &node.id == id {
+            return Some(node);
+        }
+        if let Some(node) = find_node_mut(&mut node.children, id) {
+            return Some(node);
+        }
+    }
+    None
+}
+
+/// Return true if the node is the ancestor of the `id` node, or the node itself.
+pub(crate) fn contains_node(node: &TreeNode, id: &SharedString) -> bool {
+    &node.id == id || find_node(&node.children, id).is_some()
+}
+
+fn remove_node(nodes: &mut Vec<TreeNode>, id: &SharedString) -> Option<TreeNode> {
+    if let Some(ix) = nodes.iter().position(|node| &node.id == id) {
+        return Some(nodes.remove(ix));
+    }
+    nodes
+        .iter_mut()
+        .find_map(|node| remove_node(&mut node.children, id))
+}
+
+fn insert_node(
+    nodes: &mut Vec<TreeNode>,
+    target: &SharedString,
+    position: DropPosition,
+    node: TreeNode,
+) -> Result<(), TreeNode> {
+    if let Some(ix) = nodes.iter().position(|node| &node.id == target) {
+        match position {
+            DropPosition::Before => nodes.insert(ix, node),
+            DropPosition::After => nodes.insert(ix + 1, node),
+            DropPosition::Inside => nodes[ix].children.push(node),
+        }
+        return Ok(());
+    }
+
+    let mut node = node;
+    for parent in nodes.iter_mut() {
+        match insert_node(&mut parent.children, target, position, node) {
+            Ok(()) => return Ok(()),
+            Err(n) => node = n,
+        }
+    }
+    Err(node)
+}
+
+/// Move the node with the `id` to the position of the `target` node.
+///
+/// Return false if the nodes are not found, or the target is in the moving node.
+pub(crate) fn move_node(
+    nodes: &mut Vec<TreeNode>,
+    id: &SharedString,
+    target: &SharedString,
+    position: DropPosition,
+) -> bool {
+    let movable = find_node(nodes, target).is_some()
+        && find_node(nodes, id).map_or(false, |node| !contains_node(node, target));
+    if !movable {
+        return false;
+    }
+
+    let Some(node) = remove_node(nodes, id) else {
+        return false;
+    };
+    insert_node(nodes, target, position, node).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use gpui::SharedString;
+
+    use super::{
+        collapse_entry, expand_entry, filter_nodes, find_node_mut, flatten, move_node, node_at,
+        DropPosition, TreeNode,
+    };
+
+    fn nodes() -> Vec<TreeNode> {
+        vec![
+            TreeNode::new("src", "src").children([
+                TreeNode::new("src/ui", "ui")
+                    .children([TreeNode::new("src/ui/tree.rs", "tree.rs")]),
+                TreeNode::new("src/lib.rs", "lib.rs"),
+            ]),
+            TreeNode::new("target", "target").lazy(),
+            TreeNode::new("Cargo.toml", "Cargo.toml"),
+        ]
+    }
+
+    #[test]
+    fn test_flatten() {
+        let nodes = nodes();
+        let ids = |expanded: &[&'static str]| {
+            let expanded = expanded
+                .iter()
+                .map(|id| SharedString::from(*id))
+                .collect::<HashSet<_>>();
+            flatten(&nodes, &expanded, None)
+                .into_iter()
+                .map(|entry| (entry.id.to_string(), entry.depth()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(&[]),
+            vec![
+                ("src".into(), 0),
+                ("target".into(), 0),
+                ("Cargo.toml".into(), 0)
+            ]
+        );
+        // The expanded node in a collapsed node is hidden.
+        assert_eq!(
+            ids(&["src/ui"]),
+            vec![
+                ("src".into(), 0),
+                ("target".into(), 0),
+                ("Cargo.toml".into(), 0)
+            ]
+        );
+        assert_eq!(
+            ids(&["src", "src/ui"]),
+            vec![
+                ("src".into(), 0),
+                ("src/ui".into(), 1),
+                ("src/ui/tree.rs".into(), 2),
+                ("src/lib.rs".into(), 1),
+                ("target".into(), 0),
+                ("Cargo.toml".into(), 0),
+            ]
+        );
+
+        // The placeholder of the expanded lazy node.
+        let expanded = HashSet::from([SharedString::from("target")]);
+        let entries = flatten(&nodes, &expanded, None);
+        assert_eq!(entries[2].id, "target");
+        assert!(entries[2].placeholder);
+        assert_eq!(entries[2].depth(), 1);
+        assert_eq!(entries[2].parent_path(), entries[1].path);
+    }
+
+    #[test]
+    fn test_expand_collapse_entry() {
+        let nodes = nodes();
+        let mut expanded = HashSet::new();
+        let mut entries = flatten(&nodes, &expanded, None);
+
+        // Expand and collapse in any order should be the same as flattening the whole tree.
+        for (id, expand) in [
+            ("src", true),
+            ("src/ui", true),
+            ("target", true),
+            ("src", false),
+            ("src", true),
+            ("src/ui", false),
+            ("target", false),
+        ] {
+            let id = SharedString::from(id);
+            let ix = entries.iter().position(|entry| entry.id == id).unwrap();
+            if expand {
+                expanded.insert(id);
+                expand_entry(&mut entries, ix, &nodes, &expanded, None);
+            } else {
+                expanded.remove(&id);
+                collapse_entry(&mut entries, ix);
+            }
+            assert_eq!(entries, flatten(&nodes, &expanded, None));
+        }
+    }
+
+    #[test]
+    fn test_filter_nodes() {
+        let nodes = nodes();
+        let result = filter_nodes(&nodes, "tree");
+        assert_eq!(result.matches.len(), 1);
+        assert_eq!(
+            result.matches[&SharedString::from("src/ui/tree.rs")],
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            result.ancestors,
+            HashSet::from(["src".into(), "src/ui".into()])
+        );
+
+        let expanded = HashSet::from(["src".into(), "src/ui".into()]);
+        let ids = flatten(&nodes, &expanded, Some(&result.visible))
+            .into_iter()
+            .map(|entry| entry.id.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["src", "src/ui", "src/ui/tree.rs"]);
+
+        // The descendants of the matched node are visible.
+        let result = filter_nodes(&nodes, "ui");
+        assert!(result
+            .visible
+            .contains(&SharedString::from("src/ui/tree.rs")));
+        assert!(!result.visible.contains(&SharedString::from("src/lib.rs")));
+
+        let result = filter_nodes(&nodes, "xyz");
+        assert!(result.matches.is_empty());
+        assert!(result.visible.is_empty());
+    }
+
+    #[test]
+    fn test_node_at() {
+        let nodes = nodes();
+        assert_eq!(node_at(&nodes, &[0, 0, 0]).unwrap().label, "tree.rs");
+        assert_eq!(node_at(&nodes, &[2]).unwrap().label, "Cargo.toml");
+        assert!(node_at(&nodes, &[2, 0]).is_none());
+        assert!(node_at(&nodes, &[]).is_none());
+    }
+
+    #[test]
+    fn test_find_node_mut() {
+        let mut nodes = nodes();
+        let node = find_node_mut(&mut nodes, &"src/ui/tree.rs".into()).unwrap();
+        assert_eq!(node.label, "tree.rs");
+        assert!(find_node_mut(&mut nodes, &"unknown".into()).is_none());
+    }
+
+    #[test]
+    fn test_move_node() {
+        let ids = |nodes: &[TreeNode]| {
+            let expanded = ["src", "src/ui", "target"]
+                .into_iter()
+                .map(SharedString::from)
+                .collect::<HashSet<_>>();
+            flatten(nodes, &expanded, None)
+                .into_iter()
+                .filter(|entry| !entry.placeholder)
+                .map(|entry| (entry.id.to_string(), entry.depth()))
+                .collect::<Vec<_>>()
+        };
+
+        let mut nodes = nodes();
+        assert!(move_node(
+            &mut nodes,
+            &"Cargo.toml".into(),
+            &"src/lib.rs".into(),
+            DropPosition::Before
+        ));
+        assert!(move_node(
+            &mut nodes,
+            &"src/ui/tree.rs".into(),
+            &"src".into(),
+            DropPosition::After
+        ));
+        assert!(move_node(
+            &mut nodes,
+            &"src/lib.rs".into(),
+            &"src/ui".into(),
+            DropPosition::Inside
+        ));
+        assert_eq!(
+            ids(&nodes),
+            vec![
+                ("src".into(), 0),
+                ("src/ui".into(), 1),
+                ("src/lib.rs".into(), 2),
+                ("Cargo.toml".into(), 1),
+                ("src/ui/tree.rs".into(), 0),
+                ("target".into(), 0),
+            ]
+        );
+
+        // Can't move a node into itself or its descendants.
+        assert!(!move_node(
+            &mut nodes,
+            &"src".into(),
+            &"src".into(),
+            DropPosition::Inside
+        ));
+        assert!(!move_node(
+            &mut nodes,
+            &"src".into(),
+            &"src/lib.rs".into(),
+            DropPosition::After
+        ));
+        assert!(!move_node(
+            &mut nodes,
+            &"src".into(),
+            &"unknown".into(),
+            DropPosition::Before
+        ));
+        assert_eq!(ids(&nodes)[0], ("src".into(), 0));
+    }
+}
@@ -0,0 +1,1158 @@
+use std::{
+    cell::Cell,
+    collections::{HashMap, HashSet},
+    f32::consts::FRAC_PI_2,
+    rc::Rc,
+    time::Duration,
+};
+
+use anyhow::Result;
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, radians, uniform_list, AnimationExt as _,
+    AnyElement, AppContext, Axis, ClickEvent, Div, DragMoveEvent, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement as _, IntoElement, KeyBinding, MouseButton,
+    ParentElement as _, Pixels, Render, SharedString, StatefulInteractiveElement as _, Styled as _,
+    Task, Transformation, UniformListScrollHandle, ViewContext,
+};
+use rust_i18n::t;
+use smol::Timer;
+
+use super::node::{
+    collapse_entry, contains_node, expand_entry, filter_nodes, find_node, find_node_mut, flatten,
+    move_node, node_at, DropPosition, TreeEntry, TreeNode,
+};
+use crate::{
+    animation::{ease_standard, motion, transition, TransitionExt as _, TransitionProperty},
+    button::{Button, ButtonStyled as _},
+    dnd::{self, AutoScroll, DragItem, DraggableExt as _, DroppableExt as _},
+    h_flex,
+    indicator::Indicator,
+    label::Label,
+    list::is_toggle_modifier,
+    scroll::{Scrollbar, ScrollbarState},
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Sizable as _,
+};
+
+actions!(
+    tree,
+    [
+        SelectPrev,
+        SelectNext,
+        SelectFirst,
+        SelectLast,
+        ExtendSelectionPrev,
+        ExtendSelectionNext,
+        SelectAll,
+        Collapse,
+        Expand,
+        Confirm
+    ]
+);
+
+pub fn init(cx: &mut AppContext) {
+    let context = Some("Tree");
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, context),
+        KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("home", SelectFirst, context),
+        KeyBinding::new("end", SelectLast, context),
+        KeyBinding::new("shift-up", ExtendSelectionPrev, context),
+        KeyBinding::new("shift-down", ExtendSelectionNext, context),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-a", SelectAll, context),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-a", SelectAll, context),
+        KeyBinding::new("left", Collapse, context),
+        KeyBinding::new("right", Expand, context),
+        KeyBinding::new("enter", Confirm, context),
+    ]);
+}
+
+/// The width of each level of the indentation.
+const INDENT_WIDTH: Pixels = Pixels(16.);
+/// The duration of the expand and collapse animation.
+const TOGGLE_DURATION: Duration = Duration::from_millis(150);
+/// The delay to expand the collapsed node while dragging over it.
+const AUTO_EXPAND_DELAY: Duration = Duration::from_millis(600);
+
+pub enum TreeEvent {
+    /// The node is selected by the mouse or the keyboard.
+    Select(SharedString),
+    /// The node is confirmed by `Enter` or double click.
+    Confirm(SharedString),
+    Expand(SharedString),
+    Collapse(SharedString),
+    /// Emitted when the selected nodes are changed in the multi-select mode.
+    SelectionChanged(Vec<SharedString>),
+    /// Emitted when the node is moved by dragging in the draggable tree.
+    Moved {
+        id: SharedString,
+        target: SharedString,
+        position: DropPosition,
+    },
+}
+
+/// A delegate for the [`TreeView`].
+#[allow(unused)]
+pub trait TreeDelegate: Sized + 'static {
+    /// Return the root nodes of the tree.
+    ///
+    /// This is called when the tree initializes or [`TreeView::refresh`].
+    fn root_nodes(&self) -> Vec<TreeNode>;
+
+    /// Return true to enable the multi-select mode.
+    ///
+    /// - Shift+Click or Shift+Up/Down to select a range.
+    /// - Cmd+Click (Ctrl+Click on Windows, Linux) to toggle a node.
+    /// - Cmd+A (Ctrl+A on Windows, Linux) to select all visible nodes.
+    fn multi_select(&self) -> bool {
+        false
+    }
+
+    /// Return true if the `src` node can be dropped to the position of the `dst` node in the draggable tree.
+    ///
+    /// The node can't be dropped into itself or its descendants, this is checked before.
+    /// Default is to drop into the nodes with children only.
+    fn can_drop(&self, src: &TreeNode, dst: &TreeNode, position: DropPosition) -> bool {
+        position != DropPosition::Inside || dst.has_children()
+    }
+
+    /// Move the node with the `id` to the position of the `target` node, called on drop.
+    ///
+    /// The nodes of the tree are moved after this, update the data of the delegate here to keep them in sync.
+    fn move_node(
+        &mut self,
+        id: &SharedString,
+        target: &SharedString,
+        position: DropPosition,
+        cx: &mut ViewContext<TreeView<Self>>,
+    ) {
+    }
+
+    /// Load the children of the [`TreeNode::lazy`] node when it is expanded the first time.
+    ///
+    /// A loading row is shown until the task is done, if the task fails,
+    /// an error row is shown with a retry button to load again.
+    fn load_children(
+        &mut self,
+        node: &TreeNode,
+        cx: &mut ViewContext<TreeView<Self>>,
+    ) -> Task<Result<Vec<TreeNode>>> {
+        Task::ready(Ok(vec![]))
+    }
+
+    /// Render the content of the node, the indentation and the chevron are rendered by the tree.
+    ///
+    /// The `highlight_positions` are the matched characters of the label by the [`TreeView::set_filter`],
+    /// see [`crate::label::Label::highlight_positions`].
+    ///
+    /// Default is the icon and the label of the node.
+    fn render_node(
+        &self,
+        node: &TreeNode,
+        expanded: bool,
+        highlight_positions: &[usize],
+        cx: &mut ViewContext<TreeView<Self>>,
+    ) -> AnyElement {
+        let icon = if expanded {
+            node.expanded_icon.clone().or(node.icon.clone())
+        } else {
+            node.icon.clone()
+        };
+
+        h_flex()
+            .gap_1p5()
+            .overflow_hidden()
+            .when_some(icon, |this, icon| {
+                this.child(
+                    Icon::new(icon)
+                        .small()
+                        .text_color(cx.theme().muted_foreground),
+                )
+            })
+            .child(
+                div()
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .child(
+                        Label::new(node.label.clone())
+                            .highlight_positions(highlight_positions.to_vec()),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+/// A tree view to show the hierarchical nodes, e.g.: a file explorer or an outline panel.
+///
+/// The nodes are provided by the [`TreeDelegate::root_nodes`], and identified by the [`TreeNode::id`].
+pub struct TreeView<D: TreeDelegate> {
+    focus_handle: FocusHandle,
+    delegate: D,
+    nodes: Vec<TreeNode>,
+    /// The ids of the expanded nodes.
+    expanded: HashSet<SharedString>,
+    /// The visible nodes in order, it is updated incrementally when a node is expanded or collapsed,
+    /// only the visible range is rendered, so it can show a huge tree.
+    entries: Vec<TreeEntry>,
+    selected: Option<SharedString>,
+    /// The selected nodes in the multi-select mode, in the order of the tree.
+    selected_ids: Vec<SharedString>,
+    /// The start node of the range selection.
+    selection_anchor: Option<SharedString>,
+    filter: SharedString,
+    /// The matched characters of the labels by the filter.
+    matches: HashMap<SharedString, Vec<usize>>,
+    /// The nodes to show by the filter, `None` to show all nodes.
+    visible: Option<HashSet<SharedString>>,
+    /// The node that is toggled last, to animate the chevron and the children.
+    last_toggled: Option<SharedString>,
+    /// The loading tasks of the lazy nodes.
+    loading: HashMap<SharedString, Task<()>>,
+    /// The error messages of the lazy nodes failed to load.
+    errors: HashMap<SharedString, SharedString>,
+    draggable: bool,
+    /// The node and the position to drop the dragging node.
+    drop_target: Option<(SharedString, DropPosition)>,
+    _auto_expand_task: Task<()>,
+    auto_scroll: AutoScroll,
+    vertical_scroll_handle: UniformListScrollHandle,
+    scrollbar_state: Rc<Cell<ScrollbarState>>,
+}
+
+impl<D> TreeView<D>
+where
+    D: TreeDelegate,
+{
+    pub fn new(delegate: D, cx: &mut ViewContext<Self>) -> Self {
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            delegate,
+            nodes: Vec::new(),
+            expanded: HashSet::new(),
+            entries: Vec::new(),
+            selected: None,
+            selected_ids: Vec::new(),
+            selection_anchor: None,
+            filter: SharedString::default(),
+            matches: HashMap::new(),
+            visible: None,
+            last_toggled: None,
+            loading: HashMap::new(),
+            errors: HashMap::new(),
+            draggable: false,
+            drop_target: None,
+            _auto_expand_task: Task::ready(()),
+            auto_scroll: AutoScroll::new(),
+            vertical_scroll_handle: UniformListScrollHandle::new(),
+            scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
+        };
+        this.refresh(cx);
+        this
+    }
+
+    /// Allow to move the nodes by dragging, the [`TreeDelegate::move_node`] will be called on drop.
+    ///
+    /// Drop on the top or bottom edge of a node to insert before or after it, or on the middle to move into it.
+    pub fn draggable(mut self) -> Self {
+        self.draggable = true;
+        self
+    }
+
+    pub fn delegate(&self) -> &D {
+        &self.delegate
+    }
+
+    pub fn delegate_mut(&mut self) -> &mut D {
+        &mut self.delegate
+    }
+
+    /// Reload the nodes from the delegate, the expanded and selected nodes are kept by the ids.
+    pub fn refresh(&mut self, cx: &mut ViewContext<Self>) {
+        self.nodes = self.delegate.root_nodes();
+        self.loading.clear();
+        self.errors.clear();
+        self.update_entries();
+        // Load the children of the lazy nodes those are still expanded.
+        let lazy_ids = self
+            .entries
+            .iter()
+            .filter(|entry| entry.placeholder)
+            .map(|entry| entry.id.clone())
+            .collect::<Vec<_>>();
+        for id in lazy_ids {
+            self.load_children(id, cx);
+        }
+        if !self
+            .selected
+            .as_ref()
+            .map_or(false, |id| self.entry_ix(id).is_some())
+        {
+            self.selected = None;
+        }
+        cx.notify();
+    }
+
+    /// Flatten the whole tree again, e.g.: the nodes or the filter are changed.
+    fn update_entries(&mut self) {
+        if self.filter.is_empty() {
+            self.matches.clear();
+            self.visible = None;
+        } else {
+            let result = filter_nodes(&self.nodes, &self.filter);
+            self.matches = result.matches;
+            self.visible = Some(result.visible);
+        }
+        self.entries = flatten(&self.nodes, &self.expanded, self.visible.as_ref());
+    }
+
+    /// Update the entries of the descendants of the node after its children are changed.
+    fn update_children_entries(&mut self, id: &SharedString) {
+        // The new children need to be matched by the filter.
+        if !self.filter.is_empty() {
+            self.update_entries();
+            return;
+        }
+
+        if let Some(ix) = self.entry_ix(id).filter(|_| self.is_expanded(id)) {
+            collapse_entry(&mut self.entries, ix);
+            expand_entry(&mut self.entries, ix, &self.nodes, &self.expanded, None);
+        }
+    }
+
+    /// Return the filter query.
+    pub fn filter(&self) -> &SharedString {
+        &self.filter
+    }
+
+    /// Filter the nodes by fuzzy matching the labels, an empty query to show all nodes.
+    ///
+    /// The matched nodes are shown with their ancestors and descendants, and the matched characters are highlighted.
+    /// The ancestors are expanded to reveal the matches.
+    pub fn set_filter(&mut self, query: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        let query = query.into();
+        if query == self.filter {
+            return;
+        }
+
+        self.filter = query;
+        if !self.filter.is_empty() {
+            let result = filter_nodes(&self.nodes, &self.filter);
+            self.expanded.extend(result.ancestors);
+        }
+        self.last_toggled = None;
+        self.update_entries();
+
+        // Keep the selected node in view, or scroll to the first match.
+        let ix = self.selected_ix().or_else(|| {
+            self.entries
+                .iter()
+                .position(|entry| !entry.placeholder && self.matches.contains_key(&entry.id))
+        });
+        if let Some(ix) = ix {
+            self.vertical_scroll_handle.scroll_to_item(ix);
+        }
+        cx.notify();
+    }
+
+    fn entry_ix(&self, id: &SharedString) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| !entry.placeholder && &entry.id == id)
+    }
+
+    fn entry_node(&self, ix: usize) -> Option<&TreeNode> {
+        node_at(&self.nodes, &self.entries.get(ix)?.path)
+    }
+
+    /// Return the id of the selected node.
+    pub fn selected(&self) -> Option<&SharedString> {
+        self.selected.as_ref()
+    }
+
+    /// Return the selected node.
+    pub fn selected_node(&self) -> Option<&TreeNode> {
+        self.entry_node(self.entry_ix(self.selected.as_ref()?)?)
+    }
+
+    pub fn is_expanded(&self, id: &SharedString) -> bool {
+        self.expanded.contains(id)
+    }
+
+    /// Select the node by the id, the node must be visible.
+    ///
+    /// In the multi-select mode, only this node is selected.
+    pub fn select(&mut self, id: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        let id = id.into();
+        if !self.move_cursor(id.clone(), cx) {
+            return;
+        }
+
+        if self.is_multi_select() {
+            self.selection_anchor = Some(id.clone());
+            self.set_selected_ids([id], cx);
+        }
+    }
+
+    /// Move the cursor to the node and scroll to it, return false if the node is not visible.
+    fn move_cursor(&mut self, id: SharedString, cx: &mut ViewContext<Self>) -> bool {
+        let Some(ix) = self.entry_ix(&id) else {
+            return false;
+        };
+
+        self.vertical_scroll_handle.scroll_to_item(ix);
+        self.selected = Some(id.clone());
+        cx.emit(TreeEvent::Select(id));
+        cx.notify();
+        true
+    }
+
+    fn is_multi_select(&self) -> bool {
+        self.delegate.multi_select()
+    }
+
+    /// Return the ids of the selected nodes in the multi-select mode.
+    pub fn selected_ids(&self) -> &[SharedString] {
+        &self.selected_ids
+    }
+
+    /// Set the selected nodes in the multi-select mode.
+    pub fn set_selected_ids(
+        &mut self,
+        ids: impl IntoIterator<Item = SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let mut seen = HashSet::new();
+        let mut ids = ids
+            .into_iter()
+            .filter(|id| seen.insert(id.clone()))
+            .collect::<Vec<_>>();
+        // The hidden nodes are kept after the visible nodes.
+        ids.sort_by_key(|id| self.entry_ix(id).unwrap_or(usize::MAX));
+
+        if ids == self.selected_ids {
+            return;
+        }
+
+        self.selected_ids = ids;
+        cx.emit(TreeEvent::SelectionChanged(self.selected_ids.clone()));
+        cx.notify();
+    }
+
+    /// Select the visible nodes between the anchor and the entry at `ix` (inclusive).
+    fn select_range_to(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let anchor_ix = self
+            .selection_anchor
+            .as_ref()
+            .and_then(|id| self.entry_ix(id))
+            .unwrap_or(ix);
+        if self.selection_anchor.is_none() {
+            self.selection_anchor = self.entries.get(ix).map(|entry| entry.id.clone());
+        }
+
+        let ids = self.entries[anchor_ix.min(ix)..=anchor_ix.max(ix)]
+            .iter()
+            .filter(|entry| !entry.placeholder)
+            .map(|entry| entry.id.clone())
+            .collect::<Vec<_>>();
+        self.set_selected_ids(ids, cx);
+    }
+
+    /// Toggle the node in the selected nodes, and move the cursor to it.
+    fn toggle_selected(&mut self, id: SharedString, cx: &mut ViewContext<Self>) {
+        if !self.move_cursor(id.clone(), cx) {
+            return;
+        }
+
+        self.selection_anchor = Some(id.clone());
+        let mut ids = self.selected_ids.clone();
+        match ids.iter().position(|selected| selected == &id) {
+            Some(pos) => {
+                ids.remove(pos);
+            }
+            None => ids.push(id),
+        }
+        self.set_selected_ids(ids, cx);
+    }
+
+    /// Expand or collapse the node by the id, the node without children can't be expanded.
+    pub fn set_expanded(
+        &mut self,
+        id: impl Into<SharedString>,
+        expanded: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let id = id.into();
+        if expanded == self.is_expanded(&id) {
+            return;
+        }
+        let ix = self.entry_ix(&id);
+        if expanded {
+            let Some(node) = ix.and_then(|ix| self.entry_node(ix)) else {
+                return;
+            };
+            if !node.has_children() {
+                return;
+            }
+            let lazy = node.lazy;
+
+            self.expanded.insert(id.clone());
+            if lazy && !self.loading.contains_key(&id) && !self.errors.contains_key(&id) {
+                self.load_children(id.clone(), cx);
+            }
+            cx.emit(TreeEvent::Expand(id.clone()));
+        } else {
+            self.expanded.remove(&id);
+            cx.emit(TreeEvent::Collapse(id.clone()));
+        }
+
+        self.last_toggled = Some(id);
+        if let Some(ix) = ix {
+            if expanded {
+                let visible = self.visible.as_ref();
+                expand_entry(&mut self.entries, ix, &self.nodes, &self.expanded, visible);
+            } else {
+                collapse_entry(&mut self.entries, ix);
+            }
+        }
+        // Select the collapsed node if the selected node is hidden.
+        if let Some(selected) = &self.selected {
+            if self.entry_ix(selected).is_none() {
+                self.selected = self.last_toggled.clone();
+            }
+        }
+        cx.notify();
+    }
+
+    /// Load the children of the lazy node by the delegate, the loading row is shown until it is done.
+    fn load_children(&mut self, id: SharedString, cx: &mut ViewContext<Self>) {
+        let Some(node) = find_node_mut(&mut self.nodes, &id).map(|node| node.clone()) else {
+            return;
+        };
+
+        self.errors.remove(&id);
+        let task = self.delegate.load_children(&node, cx);
+        let node_id = id.clone();
+        let load_task = cx.spawn(|this, mut cx| async move {
+            let result = task.await;
+            _ = this.update(&mut cx, |this, cx| {
+                this.loading.remove(&node_id);
+                match result {
+                    Ok(children) => {
+                        if let Some(node) = find_node_mut(&mut this.nodes, &node_id) {
+                            node.children = children;
+                            node.lazy = false;
+                        }
+                        this.update_children_entries(&node_id);
+                    }
+                    Err(err) => {
+                        this.errors.insert(node_id, err.to_string().into());
+                    }
+                }
+                cx.notify();
+            });
+        });
+        self.loading.insert(id, load_task);
+        cx.notify();
+    }
+
+    /// Load the children of the lazy node again after it failed to load.
+    pub fn retry(&mut self, id: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        let id = id.into();
+        if self.errors.contains_key(&id) {
+            self.load_children(id, cx);
+        }
+    }
+
+    /// Toggle the node to expand or collapse.
+    pub fn toggle(&mut self, id: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        let id = id.into();
+        let expanded = self.is_expanded(&id);
+        self.set_expanded(id, !expanded, cx);
+    }
+
+    fn selected_ix(&self) -> Option<usize> {
+        self.entry_ix(self.selected.as_ref()?)
+    }
+
+    /// Select the node at the entry index, the placeholder rows can't be selected.
+    fn select_ix(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if let Some(entry) = self.entries.get(ix).filter(|entry| !entry.placeholder) {
+            self.select(entry.id.clone(), cx);
+        }
+    }
+
+    fn on_action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        let ix = match self.selected_ix() {
+            Some(ix) => (0..ix).rev().find(|ix| !self.entries[*ix].placeholder),
+            None => Some(0),
+        };
+        if let Some(ix) = ix {
+            self.select_ix(ix, cx);
+        }
+    }
+
+    fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        let ix = match self.selected_ix() {
+            Some(ix) => (ix + 1..self.entries.len()).find(|ix| !self.entries[*ix].placeholder),
+            None => Some(0),
+        };
+        if let Some(ix) = ix {
+            self.select_ix(ix, cx);
+        }
+    }
+
+    /// Move the cursor and select the range from the anchor.
+    fn extend_selection(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        if !self.is_multi_select() {
+            return;
+        }
+
+        let ix = match self.selected_ix() {
+            Some(ix) if forward => {
+                (ix + 1..self.entries.len()).find(|ix| !self.entries[*ix].placeholder)
+            }
+            Some(ix) => (0..ix).rev().find(|ix| !self.entries[*ix].placeholder),
+            None => Some(0),
+        };
+        let Some(ix) = ix.filter(|ix| *ix < self.entries.len()) else {
+            return;
+        };
+
+        if self.selection_anchor.is_none() {
+            self.selection_anchor = self.selected.clone();
+        }
+        let id = self.entries[ix].id.clone();
+        self.move_cursor(id, cx);
+        self.select_range_to(ix, cx);
+    }
+
+    fn on_action_extend_selection_prev(
+        &mut self,
+        _: &ExtendSelectionPrev,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.extend_selection(false, cx);
+    }
+
+    fn on_action_extend_selection_next(
+        &mut self,
+        _: &ExtendSelectionNext,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.extend_selection(true, cx);
+    }
+
+    fn on_action_select_all(&mut self, _: &SelectAll, cx: &mut ViewContext<Self>) {
+        if !self.is_multi_select() {
+            return;
+        }
+
+        let ids = self
+            .entries
+            .iter()
+            .filter(|entry| !entry.placeholder)
+            .map(|entry| entry.id.clone())
+            .collect::<Vec<_>>();
+        self.set_selected_ids(ids, cx);
+    }
+
+    fn on_action_select_first(&mut self, _: &SelectFirst, cx: &mut ViewContext<Self>) {
+        self.select_ix(0, cx);
+    }
+
+    fn on_action_select_last(&mut self, _: &SelectLast, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.entries.iter().rposition(|entry| !entry.placeholder) {
+            self.select_ix(ix, cx);
+        }
+    }
+
+    /// Collapse the selected node, or select the parent if it is collapsed.
+    fn on_action_collapse(&mut self, _: &Collapse, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.selected_ix() else {
+            return;
+        };
+        let entry = &self.entries[ix];
+
+        if self.is_expanded(&entry.id) {
+            self.set_expanded(entry.id.clone(), false, cx);
+        } else if let Some(parent_ix) = self.entries[..ix]
+            .iter()
+            .rposition(|parent| parent.depth() < entry.depth())
+        {
+            self.select_ix(parent_ix, cx);
+        }
+    }
+
+    /// Expand the selected node, or select the first child if it is expanded.
+    fn on_action_expand(&mut self, _: &Expand, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.selected_ix() else {
+            return;
+        };
+        let id = self.entries[ix].id.clone();
+
+        if self.is_expanded(&id) {
+            self.select_ix(ix + 1, cx);
+        } else {
+            self.set_expanded(id, true, cx);
+        }
+    }
+
+    fn on_action_confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        if let Some(id) = self.selected.clone() {
+            self.confirm(id, cx);
+        }
+    }
+
+    /// Toggle the node with children, and emit the confirm event.
+    fn confirm(&mut self, id: SharedString, cx: &mut ViewContext<Self>) {
+        self.toggle(id.clone(), cx);
+        cx.emit(TreeEvent::Confirm(id));
+    }
+
+    fn on_entry_click(&mut self, ix: usize, event: &ClickEvent, cx: &mut ViewContext<Self>) {
+        let Some(entry) = self.entries.get(ix) else {
+            return;
+        };
+        let id = entry.id.clone();
+
+        self.focus_handle.focus(cx);
+        if self.is_multi_select() {
+            let modifiers = &event.down.modifiers;
+            if modifiers.shift {
+                if self.selection_anchor.is_none() {
+                    self.selection_anchor = self.selected.clone();
+                }
+                self.move_cursor(id, cx);
+                self.select_range_to(ix, cx);
+                return;
+            }
+            if is_toggle_modifier(modifiers) {
+                self.toggle_selected(id, cx);
+                return;
+            }
+        }
+
+        self.select(id.clone(), cx);
+        if event.down.click_count == 2 {
+            self.confirm(id, cx);
+        }
+    }
+
+    /// Return true if the node can be dropped to the position of the target node.
+    fn can_drop(&self, id: &SharedString, target: &SharedString, position: DropPosition) -> bool {
+        let (Some(src), Some(dst)) = (find_node(&self.nodes, id), find_node(&self.nodes, target))
+        else {
+            return false;
+        };
+
+        !contains_node(src, target) && self.delegate.can_drop(src, dst, position)
+    }
+
+    /// Update the drop target by the position of the dragging node over the entry at `ix`.
+    fn on_entry_drag_move(
+        &mut self,
+        ix: usize,
+        event: &DragMoveEvent<DragItem<SharedString>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let drag = event.drag(cx).clone();
+        if !drag.is_from(cx.view().entity_id()) || !event.bounds.contains(&event.event.position) {
+            return;
+        }
+        let drag_id = drag.value;
+        let Some(entry) = self.entries.get(ix) else {
+            return;
+        };
+
+        // The top and bottom quarters are to insert as siblings, the middle is to move into.
+        let (y, bounds) = (event.event.position.y, event.bounds);
+        let edge = bounds.size.height / 4.;
+        let position = if y < bounds.top() + edge {
+            DropPosition::Before
+        } else if y > bounds.bottom() - edge {
+            DropPosition::After
+        } else {
+            DropPosition::Inside
+        };
+        let position =
+            if position == DropPosition::Inside && !self.can_drop(&drag_id, &entry.id, position) {
+                if y < bounds.center().y {
+                    DropPosition::Before
+                } else {
+                    DropPosition::After
+                }
+            } else {
+                position
+            };
+
+        // After an expanded node is before its first child.
+        let mut target = (entry.id.clone(), position);
+        if position == DropPosition::After && self.is_expanded(&entry.id) {
+            if let Some(next) = self.entries.get(ix + 1) {
+                target = if next.placeholder {
+                    (entry.id.clone(), DropPosition::Inside)
+                } else if next.parent_path() == entry.path {
+                    (next.id.clone(), DropPosition::Before)
+                } else {
+                    target
+                };
+            }
+        }
+
+        let drop_target = Some(target)
+            .filter(|(id, position)| id != &drag_id && self.can_drop(&drag_id, id, *position));
+        if self.drop_target == drop_target {
+            return;
+        }
+
+        // Expand the collapsed node after hovering it for a while.
+        self._auto_expand_task = Task::ready(());
+        if let Some((id, DropPosition::Inside)) = &drop_target {
+            if !self.is_expanded(id) {
+                let id = id.clone();
+                self._auto_expand_task = cx.spawn(|this, mut cx| async move {
+                    Timer::after(AUTO_EXPAND_DELAY).await;
+                    _ = this.update(&mut cx, |this, cx| {
+                        if cx.has_active_drag()
+                            && this.drop_target.as_ref().map(|(target, _)| target) == Some(&id)
+                        {
+                            this.set_expanded(id, true, cx);
+                        }
+                    });
+                });
+            }
+        }
+
+        self.drop_target = drop_target;
+        cx.notify();
+    }
+
+    /// Scroll the tree while dragging near the top or bottom edges.
+    fn on_drag_move_tree(
+        &mut self,
+        event: &DragMoveEvent<DragItem<SharedString>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if !event.drag(cx).is_from(cx.view().entity_id()) {
+            return;
+        }
+
+        self.auto_scroll.update(
+            Axis::Vertical,
+            event.event.position,
+            event.bounds,
+            cx,
+            |this, distance, _| {
+                let state = this.vertical_scroll_handle.0.borrow();
+                dnd::scroll_by(&state.base_handle, Axis::Vertical, distance);
+            },
+        );
+    }
+
+    fn on_drop(&mut self, drag: &DragItem<SharedString>, cx: &mut ViewContext<Self>) {
+        self._auto_expand_task = Task::ready(());
+        self.auto_scroll.stop();
+        let id = &drag.value;
+        let Some((target, position)) = self.drop_target.take() else {
+            return;
+        };
+        if !self.can_drop(id, &target, position) {
+            cx.notify();
+            return;
+        }
+
+        self.delegate.move_node(id, &target, position, cx);
+        if move_node(&mut self.nodes, id, &target, position) {
+            if position == DropPosition::Inside {
+                self.expanded.insert(target.clone());
+            }
+            self.update_entries();
+            self.select(id.clone(), cx);
+            cx.emit(TreeEvent::Moved {
+                id: id.clone(),
+                target,
+                position,
+            });
+        }
+        cx.notify();
+    }
+
+    fn render_chevron(&self, id: &SharedString, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let expanded = self.is_expanded(id);
+        let icon = Icon::new(IconName::ChevronRight)
+            .xsmall()
+            .text_color(cx.theme().muted_foreground);
+        let rotate = |t: f32| Transformation::rotate(radians(FRAC_PI_2 * t));
+
+        let view_id = id.clone();
+        div()
+            .id("chevron")
+            .flex()
+            .items_center()
+            .justify_center()
+            .size(INDENT_WIDTH)
+            .flex_shrink_0()
+            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+            .on_click(cx.listener(move |this, _, cx| {
+                cx.stop_propagation();
+                this.toggle(view_id.clone(), cx);
+            }))
+            .map(|this| {
+                if self.last_toggled.as_ref() == Some(id) {
+                    // Rotate the chevron to animate the toggle.
+                    this.child(icon.with_animation(
+                        SharedString::from(format!("chevron-{}-{}", id, expanded)),
+                        motion(TOGGLE_DURATION, ease_standard(), cx),
+                        move |this, delta| {
+                            this.transform(rotate(if expanded { delta } else { 1. - delta }))
+                        },
+                    ))
+                } else {
+                    this.child(icon.transform(rotate(if expanded { 1. } else { 0. })))
+                }
+            })
+    }
+
+    /// Render the indentation guides of the levels.
+    fn render_guides(depth: usize, cx: &mut ViewContext<Self>) -> impl Iterator<Item = Div> {
+        let color = cx.theme().border;
+        (0..depth).map(move |level| {
+            div()
+                .absolute()
+                .top_0()
+                .bottom_0()
+                .left(INDENT_WIDTH * level as f32 + INDENT_WIDTH / 2.)
+                .w(px(1.))
+                .bg(color)
+        })
+    }
+
+    /// Render the loading or error row of the lazy node.
+    fn render_placeholder(
+        &self,
+        ix: usize,
+        entry: &TreeEntry,
+        cx: &mut ViewContext<Self>,
+    ) -> AnyElement {
+        let id = entry.id.clone();
+
+        h_flex()
+            .id(ix)
+            .relative()
+            .w_full()
+            .h_7()
+            .pr_2()
+            .pl(INDENT_WIDTH * (entry.depth() + 1) as f32)
+            .gap_1p5()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .children(Self::render_guides(entry.depth(), cx))
+            .map(|this| match self.errors.get(&entry.id) {
+                Some(error) => this
+                    .child(
+                        Icon::new(IconName::TriangleAlert)
+                            .xsmall()
+                            .text_color(cx.theme().destructive),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .overflow_hidden()
+                            .text_ellipsis()
+                            .whitespace_nowrap()
+                            .child(error.clone()),
+                    )
+                    .child(
+                        Button::new("retry")
+                            .label(t!("Tree.retry"))
+                            .ghost()
+                            .xsmall()
+                            .on_click(cx.listener(move |this, _, cx| this.retry(id.clone(), cx))),
+                    ),
+                None => this
+                    .child(Indicator::new().xsmall())
+                    .child(t!("Tree.loading").to_string()),
+            })
+            .into_any_element()
+    }
+
+    fn render_entry(&self, ix: usize, cx: &mut ViewContext<Self>) -> AnyElement {
+        let Some(entry) = self.entries.get(ix) else {
+            return div().into_any_element();
+        };
+        if entry.placeholder {
+            return self.render_placeholder(ix, entry, cx);
+        }
+        let Some(node) = self.entry_node(ix) else {
+            return div().into_any_element();
+        };
+        let depth = entry.depth();
+        let selected = if self.is_multi_select() {
+            self.selected_ids.contains(&entry.id)
+        } else {
+            self.selected.as_ref() == Some(&entry.id)
+        };
+        let highlight_positions = self
+            .matches
+            .get(&entry.id)
+            .map_or(&[][..], |positions| positions.as_slice());
+        let expanded = self.is_expanded(&entry.id);
+        let drop_position = self
+            .drop_target
+            .as_ref()
+            .filter(|(id, _)| id == &entry.id)
+            .map(|(_, position)| *position);
+        // Fade in the children of the node that is just expanded.
+        let animated = self.last_toggled.as_ref().map_or(false, |id| {
+            self.is_expanded(id)
+                && node_at(&self.nodes, entry.parent_path()).map_or(false, |p| &p.id == id)
+        });
+
+        let el = h_flex()
+            .id(ix)
+            .relative()
+            .w_full()
+            .h_7()
+            .pr_2()
+            .pl(INDENT_WIDTH * depth as f32)
+            .text_sm()
+            .cursor_pointer()
+            .children(Self::render_guides(depth, cx))
+            .map(|this| {
+                if node.has_children() {
+                    this.child(self.render_chevron(&entry.id, cx))
+                } else {
+                    this.child(div().size(INDENT_WIDTH).flex_shrink_0())
+                }
+            })
+            .child(
+                self.delegate
+                    .render_node(node, expanded, highlight_positions, cx),
+            )
+            .when(selected, |this| this.bg(cx.theme().list_active))
+            .when(!selected, |this| {
+                this.hover(|this| this.bg(cx.theme().list_hover))
+            })
+            .on_click(cx.listener(move |this, event, cx| {
+                this.on_entry_click(ix, event, cx);
+            }))
+            .when(self.draggable, |this| {
+                let view = cx.view().downgrade();
+                let tree_id = cx.view().entity_id();
+                this.on_drag_item(DragItem::new(tree_id, entry.id.clone()), move |id, cx| {
+                    let node = view.upgrade().and_then(|view| {
+                        view.update(cx, |tree, cx| {
+                            let node = find_node(&tree.nodes, id)?.clone();
+                            Some(tree.delegate.render_node(&node, false, &[], cx))
+                        })
+                    });
+                    h_flex()
+                        .w_48()
+                        .h_7()
+                        .px_2()
+                        .children(node)
+                        .into_any_element()
+                })
+                .on_drag_move(cx.listener(
+                    move |this, event: &DragMoveEvent<DragItem<SharedString>>, cx| {
+                        this.on_entry_drag_move(ix, event, cx)
+                    },
+                ))
+                .on_drop_item(
+                    move |drag: &DragItem<SharedString>, _| drag.is_from(tree_id),
+                    cx.listener(Self::on_drop),
+                )
+            })
+            // The insertion indicator, or highlight the node to move into.
+            .map(|this| match drop_position {
+                Some(DropPosition::Inside) => this.bg(cx.theme().drop_target),
+                Some(position) => this.child(
+                    div()
+                        .absolute()
+                        .left(INDENT_WIDTH * depth as f32)
+                        .right_0()
+                        .h(px(2.))
+                        .bg(cx.theme().drag_border)
+                        .map(|this| match position {
+                            DropPosition::Before => this.top_0(),
+                            _ => this.bottom_0(),
+                        }),
+                ),
+                None => this,
+            });
+
+        if animated {
+            el.with_transition(
+                SharedString::from(format!("tree-node-{}", entry.id)),
+                transition(TransitionProperty::Opacity, TOGGLE_DURATION),
+                cx,
+            )
+            .into_any_element()
+        } else {
+            el.into_any_element()
+        }
+    }
+}
+
+impl<D> EventEmitter<TreeEvent> for TreeView<D> where D: TreeDelegate {}
+
+impl<D> FocusableView for TreeView<D>
+where
+    D: TreeDelegate,
+{
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl<D> Render for TreeView<D>
+where
+    D: TreeDelegate,
+{
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if !cx.has_active_drag() {
+            self.drop_target = None;
+        }
+        let view = cx.view().clone();
+
+        v_flex()
+            .key_context("Tree")
+            .id("tree")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .relative()
+            .overflow_hidden()
+            .on_action(cx.listener(Self::on_action_select_prev))
+            .on_action(cx.listener(Self::on_action_select_next))
+            .on_action(cx.listener(Self::on_action_select_first))
+            .on_action(cx.listener(Self::on_action_select_last))
+            .on_action(cx.listener(Self::on_action_extend_selection_prev))
+            .on_action(cx.listener(Self::on_action_extend_selection_next))
+            .on_action(cx.listener(Self::on_action_select_all))
+            .on_action(cx.listener(Self::on_action_collapse))
+            .on_action(cx.listener(Self::on_action_expand))
+            .on_action(cx.listener(Self::on_action_confirm))
+            .when(self.draggable, |this| {
+                this.on_drag_move(cx.listener(Self::on_drag_move_tree))
+            })
+            .child(
+                uniform_list(view, "tree-entries", self.entries.len(), {
+                    move |tree, visible_range, cx| {
+                        visible_range
+                            .map(|ix| tree.render_entry(ix, cx))
+                            .collect::<Vec<_>>()
+                    }
+                })
+                .flex_grow()
+                .track_scroll(self.vertical_scroll_handle.clone()),
+            )
+            .child(Scrollbar::uniform_scroll(
+                cx.view().entity_id(),
+                self.scrollbar_state.clone(),
+                self.vertical_scroll_handle.clone(),
+            ))
+    }
+}
@@ -2,9 +2,8 @@ pub mod dock;
 pub mod item;
 pub mod pane;
 pub mod pane_group;
-mod title_bar;
 mod util;
 mod workspace;
 
-pub use title_bar::TitleBar;
+pub use ui::title_bar::TitleBar;
 pub use workspace::*;